@@ -0,0 +1,67 @@
+//! JSON Canonicalization Scheme (RFC 8785)
+//!
+//! Credentials are signed over their JCS form rather than JSON-LD RDF
+//! canonicalization, so signing and verification need no context documents.
+//! Object members are sorted by UTF-16 code units and serialized without
+//! whitespace. Integers are emitted as-is; credentials should not carry
+//! non-integral numbers, whose ECMAScript formatting is not reproduced.
+
+use serde_json::Value;
+
+/// Canonical JSON serialization of `value`
+pub fn canonicalize(value: &Value) -> String {
+    let mut out = String::new();
+    write_value(value, &mut out);
+    out
+}
+
+fn write_value(value: &Value, out: &mut String) {
+    match value {
+        Value::Object(map) => {
+            let mut members: Vec<(&String, &Value)> = map.iter().collect();
+            members.sort_by(|(a, _), (b, _)| a.encode_utf16().cmp(b.encode_utf16()));
+            out.push('{');
+            for (i, (key, member)) in members.into_iter().enumerate() {
+                if i > 0 {
+                    out.push(',');
+                }
+                out.push_str(&Value::String(key.clone()).to_string());
+                out.push(':');
+                write_value(member, out);
+            }
+            out.push('}');
+        }
+        Value::Array(items) => {
+            out.push('[');
+            for (i, item) in items.iter().enumerate() {
+                if i > 0 {
+                    out.push(',');
+                }
+                write_value(item, out);
+            }
+            out.push(']');
+        }
+        // Scalars already serialize canonically
+        scalar => out.push_str(&scalar.to_string()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_canonicalize_sorts_and_compacts() {
+        let value = json!({
+            "b": [1, {"z": null, "a": true}],
+            "a": "x\n\"y\"",
+            "\u{ff21}": 1,
+            "\u{1f600}": 2,
+        });
+        assert_eq!(
+            canonicalize(&value),
+            "{\"a\":\"x\\n\\\"y\\\"\",\"b\":[1,{\"a\":true,\"z\":null}],\"\u{1f600}\":2,\"\u{ff21}\":1}"
+        );
+    }
+}
@@ -0,0 +1,99 @@
+//! Data Integrity proofs with the `eddsa-jcs-2022` cryptosuite
+//!
+//! Hash data is `SHA-256(JCS(proof config)) || SHA-256(JCS(document))`, where
+//! the proof config is the proof without `proofValue` plus the document's
+//! `@context`. The Ed25519 signature over it is carried as a base58btc
+//! multibase `proofValue`.
+
+use ed25519_dalek::{Signature, Signer, SigningKey, Verifier, VerifyingKey};
+use serde::Serialize;
+use serde_json::Value;
+use sha2::{Digest, Sha256};
+
+use crate::{
+    canonical::canonicalize,
+    model::{format_timestamp, DataIntegrityProof, ProofPurpose},
+    CredentialError,
+};
+
+pub const PROOF_TYPE: &str = "DataIntegrityProof";
+pub const CRYPTOSUITE: &str = "eddsa-jcs-2022";
+
+/// Sign `document` (any `proof` it carries is ignored)
+pub fn sign<T: Serialize>(
+    document: &T,
+    verification_method: &str,
+    purpose: ProofPurpose,
+    challenge: Option<&str>,
+    domain: Option<&str>,
+    key: &SigningKey,
+) -> Result<DataIntegrityProof, CredentialError> {
+    let mut proof = DataIntegrityProof {
+        proof_type: PROOF_TYPE.to_string(),
+        cryptosuite: CRYPTOSUITE.to_string(),
+        created: format_timestamp(crate::timestamp_secs()),
+        verification_method: verification_method.to_string(),
+        proof_purpose: purpose.as_str().to_string(),
+        challenge: challenge.map(str::to_string),
+        domain: domain.map(str::to_string),
+        proof_value: None,
+    };
+    let signature = key.sign(&hash_data(document, &proof)?);
+    proof.proof_value = Some(format!("z{}", bs58::encode(signature.to_bytes()).into_string()));
+    Ok(proof)
+}
+
+/// Verify `proof` over `document` with the verification method's public key
+pub fn verify<T: Serialize>(
+    document: &T,
+    proof: &DataIntegrityProof,
+    key: &VerifyingKey,
+) -> Result<(), CredentialError> {
+    if proof.proof_type != PROOF_TYPE || proof.cryptosuite != CRYPTOSUITE {
+        return Err(CredentialError::InvalidProof(format!(
+            "unsupported proof {} / {}",
+            proof.proof_type, proof.cryptosuite
+        )));
+    }
+    let encoded = proof
+        .proof_value
+        .as_deref()
+        .and_then(|value| value.strip_prefix('z'))
+        .ok_or_else(|| CredentialError::InvalidProof("missing base58btc proofValue".to_string()))?;
+    let bytes = bs58::decode(encoded)
+        .into_vec()
+        .map_err(|e| CredentialError::InvalidProof(e.to_string()))?;
+    let signature =
+        Signature::from_slice(&bytes).map_err(|e| CredentialError::InvalidProof(e.to_string()))?;
+
+    let mut config = proof.clone();
+    config.proof_value = None;
+    key.verify(&hash_data(document, &config)?, &signature)
+        .map_err(|_| CredentialError::InvalidSignature)
+}
+
+/// `SHA-256(JCS(proof config)) || SHA-256(JCS(unsecured document))`
+fn hash_data<T: Serialize>(
+    document: &T,
+    proof_config: &DataIntegrityProof,
+) -> Result<Vec<u8>, CredentialError> {
+    let mut document = to_value(document)?;
+    let object = document
+        .as_object_mut()
+        .ok_or_else(|| CredentialError::Serialization("document is not a JSON object".to_string()))?;
+    object.remove("proof");
+    let context = object.get("@context").cloned();
+
+    let mut config = to_value(proof_config)?;
+    if let (Some(context), Some(config)) = (context, config.as_object_mut()) {
+        config.insert("@context".to_string(), context);
+    }
+
+    let mut hash = Sha256::digest(canonicalize(&config).as_bytes()).to_vec();
+    hash.extend_from_slice(&Sha256::digest(canonicalize(&document).as_bytes()));
+    Ok(hash)
+}
+
+fn to_value<T: Serialize>(value: &T) -> Result<Value, CredentialError> {
+    serde_json::to_value(value).map_err(|e| CredentialError::Serialization(e.to_string()))
+}
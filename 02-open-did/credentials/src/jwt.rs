@@ -0,0 +1,101 @@
+//! JWT-secured credentials (`vc+jwt`, EdDSA)
+//!
+//! The JWT payload is the credential itself, as in VC-JOSE-COSE; the header
+//! `kid` names the issuer's verification method.
+
+use base64::{engine::general_purpose::URL_SAFE_NO_PAD, Engine};
+use ed25519_dalek::{Signature, Signer, SigningKey, Verifier, VerifyingKey};
+use serde::{Deserialize, Serialize};
+
+use crate::{model::VerifiableCredential, CredentialError};
+
+/// JWT `typ` of a JOSE-secured credential
+pub const JWT_TYPE: &str = "vc+jwt";
+
+/// JWT `alg` for Ed25519
+pub const JWT_ALGORITHM: &str = "EdDSA";
+
+/// JOSE header
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct JwtHeader {
+    pub alg: String,
+    pub typ: String,
+    pub kid: String,
+}
+
+/// Parsed, not yet verified, JWT credential
+#[derive(Debug, Clone)]
+pub struct DecodedJwt {
+    pub header: JwtHeader,
+    pub credential: VerifiableCredential,
+    signing_input: String,
+    signature: Vec<u8>,
+}
+
+impl DecodedJwt {
+    /// Check the signature with the `kid` verification method's public key
+    pub fn verify(&self, key: &VerifyingKey) -> Result<(), CredentialError> {
+        let signature = Signature::from_slice(&self.signature)
+            .map_err(|e| CredentialError::InvalidJwt(e.to_string()))?;
+        key.verify(self.signing_input.as_bytes(), &signature)
+            .map_err(|_| CredentialError::InvalidSignature)
+    }
+}
+
+/// Encode and sign a credential as a compact JWT (any embedded proof is dropped)
+pub fn encode(
+    credential: &VerifiableCredential,
+    kid: &str,
+    key: &SigningKey,
+) -> Result<String, CredentialError> {
+    let header = JwtHeader {
+        alg: JWT_ALGORITHM.to_string(),
+        typ: JWT_TYPE.to_string(),
+        kid: kid.to_string(),
+    };
+    let mut payload = credential.clone();
+    payload.proof = None;
+
+    let signing_input = format!("{}.{}", encode_part(&header)?, encode_part(&payload)?);
+    let signature = key.sign(signing_input.as_bytes());
+    Ok(format!("{}.{}", signing_input, URL_SAFE_NO_PAD.encode(signature.to_bytes())))
+}
+
+/// Split and decode a compact JWT
+pub fn decode(jwt: &str) -> Result<DecodedJwt, CredentialError> {
+    let invalid = |e: String| CredentialError::InvalidJwt(e);
+    let mut parts = jwt.split('.');
+    let (header, payload, signature) = match (parts.next(), parts.next(), parts.next(), parts.next()) {
+        (Some(h), Some(p), Some(s), None) => (h, p, s),
+        _ => return Err(invalid("expected three segments".to_string())),
+    };
+
+    let header: JwtHeader = decode_part(header)?;
+    if header.alg != JWT_ALGORITHM {
+        return Err(invalid(format!("unsupported alg {}", header.alg)));
+    }
+    if header.typ != JWT_TYPE {
+        return Err(invalid(format!("unexpected typ {}", header.typ)));
+    }
+
+    Ok(DecodedJwt {
+        header,
+        credential: decode_part(payload)?,
+        signing_input: jwt[..jwt.len() - signature.len() - 1].to_string(),
+        signature: URL_SAFE_NO_PAD
+            .decode(signature)
+            .map_err(|e| invalid(e.to_string()))?,
+    })
+}
+
+fn encode_part<T: Serialize>(part: &T) -> Result<String, CredentialError> {
+    let json = serde_json::to_vec(part).map_err(|e| CredentialError::Serialization(e.to_string()))?;
+    Ok(URL_SAFE_NO_PAD.encode(json))
+}
+
+fn decode_part<T: for<'de> Deserialize<'de>>(part: &str) -> Result<T, CredentialError> {
+    let json = URL_SAFE_NO_PAD
+        .decode(part)
+        .map_err(|e| CredentialError::InvalidJwt(e.to_string()))?;
+    serde_json::from_slice(&json).map_err(|e| CredentialError::InvalidJwt(e.to_string()))
+}
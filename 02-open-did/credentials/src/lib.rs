@@ -0,0 +1,90 @@
+//! Verifiable Credentials
+//!
+//! W3C Verifiable Credentials Data Model 2.0 on top of Ëtrid DIDs:
+//! credentials and presentations signed with a DID's keys, either as
+//! Data Integrity proofs (`eddsa-jcs-2022`) or as JWTs (`vc+jwt`), verified
+//! against the DID document returned by a `DidResolver`, and revoked through
+//! status-list bitstrings anchored in `pallet-did-registry`.
+//!
+//! Signing uses JSON canonicalization (RFC 8785), so no JSON-LD processing
+//! or remote context loading is involved.
+
+pub mod canonical;
+pub mod data_integrity;
+pub mod jwt;
+pub mod model;
+pub mod signer;
+pub mod status;
+pub mod verifier;
+
+pub use model::{
+    CredentialStatus, DataIntegrityProof, ProofPurpose, VerifiableCredential,
+    VerifiablePresentation,
+};
+pub use signer::DidSigner;
+pub use status::{InMemoryStatusLists, StatusListRef, StatusListSource};
+pub use verifier::{key_from_document, verify_signature_at_block, Verifier};
+
+use etrid_did_resolver::ResolutionError;
+
+/// Credential error
+#[derive(Debug, Clone)]
+pub enum CredentialError {
+    /// DID resolution failed
+    Resolution(ResolutionError),
+    /// Resolved DID document is unusable
+    InvalidDocument(String),
+    /// Verification method missing or not authorized for the proof purpose
+    UnknownKey(String),
+    /// Signing key does not match the verification method
+    KeyMismatch,
+    /// Signer or proof DID is not the credential issuer or presentation holder
+    IssuerMismatch,
+    InvalidProof(String),
+    InvalidSignature,
+    InvalidJwt(String),
+    ChallengeMismatch,
+    NotYetValid,
+    Expired,
+    Revoked,
+    InvalidStatus(String),
+    StatusUnavailable(String),
+    Serialization(String),
+}
+
+impl std::fmt::Display for CredentialError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            CredentialError::Resolution(e) => write!(f, "DID resolution failed: {}", e),
+            CredentialError::InvalidDocument(e) => write!(f, "Invalid DID document: {}", e),
+            CredentialError::UnknownKey(id) => write!(f, "Verification method not authorized: {}", id),
+            CredentialError::KeyMismatch => write!(f, "Signing key does not match verification method"),
+            CredentialError::IssuerMismatch => write!(f, "Proof was not made by the issuer or holder"),
+            CredentialError::InvalidProof(e) => write!(f, "Invalid proof: {}", e),
+            CredentialError::InvalidSignature => write!(f, "Invalid signature"),
+            CredentialError::InvalidJwt(e) => write!(f, "Invalid JWT: {}", e),
+            CredentialError::ChallengeMismatch => write!(f, "Presentation challenge mismatch"),
+            CredentialError::NotYetValid => write!(f, "Credential is not yet valid"),
+            CredentialError::Expired => write!(f, "Credential has expired"),
+            CredentialError::Revoked => write!(f, "Credential has been revoked"),
+            CredentialError::InvalidStatus(e) => write!(f, "Invalid credential status: {}", e),
+            CredentialError::StatusUnavailable(e) => write!(f, "Credential status unavailable: {}", e),
+            CredentialError::Serialization(e) => write!(f, "Serialization error: {}", e),
+        }
+    }
+}
+
+impl std::error::Error for CredentialError {}
+
+impl From<ResolutionError> for CredentialError {
+    fn from(e: ResolutionError) -> Self {
+        CredentialError::Resolution(e)
+    }
+}
+
+fn timestamp_secs() -> u64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs()
+}
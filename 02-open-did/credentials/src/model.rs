@@ -0,0 +1,209 @@
+//! W3C Verifiable Credentials Data Model 2.0 types
+
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+use crate::status::StatusListRef;
+
+pub use etrid_did_types::{format_timestamp, parse_timestamp};
+
+/// Base context of every VC 2.0 credential and presentation
+pub const VC_CONTEXT_V2: &str = "https://www.w3.org/ns/credentials/v2";
+
+/// Verifiable credential
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VerifiableCredential {
+    #[serde(rename = "@context")]
+    pub context: Vec<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    #[serde(rename = "type")]
+    pub types: Vec<String>,
+    /// Issuer DID
+    pub issuer: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub valid_from: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub valid_until: Option<String>,
+    pub credential_subject: Value,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub credential_status: Option<CredentialStatus>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub proof: Option<DataIntegrityProof>,
+    /// Properties not modelled above (e.g. `name`, `credentialSchema`)
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+impl VerifiableCredential {
+    /// Unsigned credential issued by `issuer` about `subject`, valid from now
+    pub fn new(issuer: &str, subject: Value) -> Self {
+        Self {
+            context: vec![VC_CONTEXT_V2.to_string()],
+            id: None,
+            types: vec!["VerifiableCredential".to_string()],
+            issuer: issuer.to_string(),
+            valid_from: Some(format_timestamp(crate::timestamp_secs())),
+            valid_until: None,
+            credential_subject: subject,
+            credential_status: None,
+            proof: None,
+            extra: Map::new(),
+        }
+    }
+
+    pub fn with_id(mut self, id: &str) -> Self {
+        self.id = Some(id.to_string());
+        self
+    }
+
+    /// Add a credential type after `VerifiableCredential`
+    pub fn with_type(mut self, credential_type: &str) -> Self {
+        self.types.push(credential_type.to_string());
+        self
+    }
+
+    pub fn valid_from(mut self, secs: u64) -> Self {
+        self.valid_from = Some(format_timestamp(secs));
+        self
+    }
+
+    pub fn valid_until(mut self, secs: u64) -> Self {
+        self.valid_until = Some(format_timestamp(secs));
+        self
+    }
+
+    /// Make the credential revocable through bit `index` of an issuer status list
+    pub fn with_status(mut self, list: &StatusListRef, index: u32) -> Self {
+        self.credential_status = Some(CredentialStatus::revocation(list, index));
+        self
+    }
+}
+
+/// Verifiable presentation
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VerifiablePresentation {
+    #[serde(rename = "@context")]
+    pub context: Vec<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    #[serde(rename = "type")]
+    pub types: Vec<String>,
+    /// Holder DID
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub holder: Option<String>,
+    #[serde(default)]
+    pub verifiable_credential: Vec<VerifiableCredential>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub proof: Option<DataIntegrityProof>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+impl VerifiablePresentation {
+    pub fn new(holder: &str, credentials: Vec<VerifiableCredential>) -> Self {
+        Self {
+            context: vec![VC_CONTEXT_V2.to_string()],
+            id: None,
+            types: vec!["VerifiablePresentation".to_string()],
+            holder: Some(holder.to_string()),
+            verifiable_credential: credentials,
+            proof: None,
+            extra: Map::new(),
+        }
+    }
+}
+
+/// `BitstringStatusListEntry` credential status
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CredentialStatus {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    #[serde(rename = "type")]
+    pub status_type: String,
+    pub status_purpose: String,
+    /// Bit index, a decimal string as required by the spec
+    pub status_list_index: String,
+    pub status_list_credential: String,
+}
+
+impl CredentialStatus {
+    pub fn revocation(list: &StatusListRef, index: u32) -> Self {
+        let url = list.to_url();
+        Self {
+            id: Some(format!("{}#{}", url, index)),
+            status_type: "BitstringStatusListEntry".to_string(),
+            status_purpose: "revocation".to_string(),
+            status_list_index: index.to_string(),
+            status_list_credential: url,
+        }
+    }
+}
+
+/// Data Integrity proof
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DataIntegrityProof {
+    #[serde(rename = "type")]
+    pub proof_type: String,
+    pub cryptosuite: String,
+    pub created: String,
+    /// Absolute verification method ID (`did:etrid:x#key1`)
+    pub verification_method: String,
+    pub proof_purpose: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub challenge: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub domain: Option<String>,
+    /// Multibase signature (absent while the proof is being hashed)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub proof_value: Option<String>,
+}
+
+/// Verification relationship a proof is made for
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ProofPurpose {
+    AssertionMethod,
+    Authentication,
+}
+
+impl ProofPurpose {
+    /// Proof purpose, also the DID document relationship name
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ProofPurpose::AssertionMethod => "assertionMethod",
+            ProofPurpose::Authentication => "authentication",
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_credential_json_shape() {
+        let list = StatusListRef::new("did:etrid:issuer", 3);
+        let credential = VerifiableCredential::new("did:etrid:issuer", serde_json::json!({"id": "did:etrid:alice"}))
+            .with_type("KycCredential")
+            .with_status(&list, 42);
+
+        let json = serde_json::to_value(&credential).unwrap();
+        assert_eq!(json["@context"][0], VC_CONTEXT_V2);
+        assert_eq!(json["type"][1], "KycCredential");
+        assert_eq!(json["credentialStatus"]["statusListIndex"], "42");
+        assert_eq!(
+            json["credentialStatus"]["statusListCredential"],
+            "did:etrid:issuer/status-lists/3"
+        );
+        assert!(json.get("proof").is_none());
+
+        let mut json = json;
+        json["name"] = "KYC".into();
+        let parsed: VerifiableCredential = serde_json::from_value(json).unwrap();
+        assert_eq!(parsed.extra["name"], "KYC");
+    }
+}
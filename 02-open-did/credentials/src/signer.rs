@@ -0,0 +1,136 @@
+//! Issuing credentials and presentations with a DID's keys
+
+use ed25519_dalek::SigningKey;
+use etrid_did_types::DidDocument;
+
+use crate::{
+    data_integrity, jwt,
+    model::{ProofPurpose, VerifiableCredential, VerifiablePresentation},
+    CredentialError,
+};
+
+/// Signing key bound to a verification method of a DID document
+pub struct DidSigner {
+    did: String,
+    verification_method: String,
+    purpose: ProofPurpose,
+    key: SigningKey,
+}
+
+impl DidSigner {
+    /// Issuer key: `key_id` must be listed in the document's `assertion_method`
+    pub fn assertion(
+        document: &DidDocument,
+        key_id: &str,
+        key: SigningKey,
+    ) -> Result<Self, CredentialError> {
+        Self::for_purpose(document, key_id, key, ProofPurpose::AssertionMethod)
+    }
+
+    /// Holder key: `key_id` must be listed in the document's `authentication`
+    pub fn authentication(
+        document: &DidDocument,
+        key_id: &str,
+        key: SigningKey,
+    ) -> Result<Self, CredentialError> {
+        Self::for_purpose(document, key_id, key, ProofPurpose::Authentication)
+    }
+
+    fn for_purpose(
+        document: &DidDocument,
+        key_id: &str,
+        key: SigningKey,
+        purpose: ProofPurpose,
+    ) -> Result<Self, CredentialError> {
+        let verification_method = document.absolute_id(key_id);
+        let relationship = match purpose {
+            ProofPurpose::AssertionMethod => &document.assertion_method,
+            ProofPurpose::Authentication => &document.authentication,
+        };
+        if !relationship.iter().any(|id| document.absolute_id(id) == verification_method) {
+            return Err(CredentialError::UnknownKey(verification_method));
+        }
+
+        let method = document
+            .verification_methods
+            .iter()
+            .find(|m| document.absolute_id(&m.id) == verification_method)
+            .ok_or_else(|| CredentialError::UnknownKey(verification_method.clone()))?;
+        if method.method_type != "Ed25519VerificationKey2020"
+            || method.public_key != key.verifying_key().to_bytes()
+        {
+            return Err(CredentialError::KeyMismatch);
+        }
+
+        Ok(Self {
+            did: document.id.to_string(),
+            verification_method,
+            purpose,
+            key,
+        })
+    }
+
+    pub fn did(&self) -> &str {
+        &self.did
+    }
+
+    /// Absolute verification method ID proofs are made with
+    pub fn verification_method(&self) -> &str {
+        &self.verification_method
+    }
+
+    /// Attach an `eddsa-jcs-2022` proof to a credential issued by this DID
+    pub fn issue(
+        &self,
+        mut credential: VerifiableCredential,
+    ) -> Result<VerifiableCredential, CredentialError> {
+        self.ensure_issuer(&credential)?;
+        credential.proof = Some(data_integrity::sign(
+            &credential,
+            &self.verification_method,
+            ProofPurpose::AssertionMethod,
+            None,
+            None,
+            &self.key,
+        )?);
+        Ok(credential)
+    }
+
+    /// Issue a credential as a `vc+jwt`
+    pub fn issue_jwt(&self, credential: &VerifiableCredential) -> Result<String, CredentialError> {
+        self.ensure_issuer(credential)?;
+        jwt::encode(credential, &self.verification_method, &self.key)
+    }
+
+    /// Present credentials to a verifier, binding the proof to its `challenge`
+    pub fn present(
+        &self,
+        credentials: Vec<VerifiableCredential>,
+        challenge: &str,
+        domain: Option<&str>,
+    ) -> Result<VerifiablePresentation, CredentialError> {
+        if self.purpose != ProofPurpose::Authentication {
+            return Err(CredentialError::UnknownKey(self.verification_method.clone()));
+        }
+        let mut presentation = VerifiablePresentation::new(&self.did, credentials);
+        presentation.proof = Some(data_integrity::sign(
+            &presentation,
+            &self.verification_method,
+            ProofPurpose::Authentication,
+            Some(challenge),
+            domain,
+            &self.key,
+        )?);
+        Ok(presentation)
+    }
+
+    fn ensure_issuer(&self, credential: &VerifiableCredential) -> Result<(), CredentialError> {
+        if self.purpose != ProofPurpose::AssertionMethod {
+            return Err(CredentialError::UnknownKey(self.verification_method.clone()));
+        }
+        if credential.issuer != self.did {
+            return Err(CredentialError::IssuerMismatch);
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,137 @@
+//! Credential revocation through status-list bitstrings
+//!
+//! Issuers anchor one bitstring per `(issuer DID, list ID)` in
+//! `pallet-did-registry` (`StatusLists`, updated with `set_credential_status`).
+//! A credential's `statusListCredential` names the list as
+//! `did:etrid:<issuer>/status-lists/<list id>` and `statusListIndex` selects the
+//! bit: byte `index / 8`, mask `0x80 >> (index % 8)`. A set bit means revoked.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+use tokio::sync::RwLock;
+
+use etrid_did_resolver::OnChainResolver;
+
+use crate::CredentialError;
+
+/// Path segment between the issuer DID and the list ID
+const STATUS_LIST_PATH: &str = "/status-lists/";
+
+/// Reference to an issuer's on-chain status list
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct StatusListRef {
+    /// Issuer DID
+    pub did: String,
+    pub list_id: u32,
+}
+
+impl StatusListRef {
+    pub fn new(did: &str, list_id: u32) -> Self {
+        Self {
+            did: did.to_string(),
+            list_id,
+        }
+    }
+
+    /// `statusListCredential` URL
+    pub fn to_url(&self) -> String {
+        format!("{}{}{}", self.did, STATUS_LIST_PATH, self.list_id)
+    }
+
+    /// Parse a `statusListCredential` URL
+    pub fn from_url(url: &str) -> Result<Self, CredentialError> {
+        let invalid = || CredentialError::InvalidStatus(format!("unsupported status list {}", url));
+        let (did, list_id) = url.split_once(STATUS_LIST_PATH).ok_or_else(invalid)?;
+        Ok(Self {
+            did: did.to_string(),
+            list_id: list_id.parse().map_err(|_| invalid())?,
+        })
+    }
+}
+
+/// Whether bit `index` of a status list is set
+pub fn is_set(bitstring: &[u8], index: u32) -> bool {
+    bitstring
+        .get((index / 8) as usize)
+        .is_some_and(|byte| byte & (0x80u8 >> (index % 8)) != 0)
+}
+
+/// Source of anchored status lists (chain state, or a test double)
+#[async_trait::async_trait]
+pub trait StatusListSource: Send + Sync {
+    /// Current bitstring of a list; unknown lists are empty (nothing revoked)
+    async fn status_list(&self, list: &StatusListRef) -> Result<Vec<u8>, CredentialError>;
+}
+
+/// In-memory status lists, mirroring the pallet's update rules
+#[derive(Default, Clone)]
+pub struct InMemoryStatusLists {
+    lists: Arc<RwLock<HashMap<StatusListRef, Vec<u8>>>>,
+}
+
+impl InMemoryStatusLists {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set or clear the revocation bit of credential `index`
+    pub async fn set_status(&self, list: &StatusListRef, index: u32, revoked: bool) {
+        let mut lists = self.lists.write().await;
+        let bits = lists.entry(list.clone()).or_default();
+        let byte = (index / 8) as usize;
+        if bits.len() <= byte {
+            bits.resize(byte + 1, 0);
+        }
+        let mask = 0x80u8 >> (index % 8);
+        if revoked {
+            bits[byte] |= mask;
+        } else {
+            bits[byte] &= !mask;
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl StatusListSource for InMemoryStatusLists {
+    async fn status_list(&self, list: &StatusListRef) -> Result<Vec<u8>, CredentialError> {
+        Ok(self.lists.read().await.get(list).cloned().unwrap_or_default())
+    }
+}
+
+/// Status lists read from `pallet-did-registry` at the best block
+#[async_trait::async_trait]
+impl StatusListSource for OnChainResolver {
+    async fn status_list(&self, list: &StatusListRef) -> Result<Vec<u8>, CredentialError> {
+        Ok(OnChainResolver::status_list(self, &list.did, list.list_id).await?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_status_list_url() {
+        let list = StatusListRef::new("did:etrid:issuer", 7);
+        assert_eq!(list.to_url(), "did:etrid:issuer/status-lists/7");
+        assert_eq!(StatusListRef::from_url(&list.to_url()).unwrap(), list);
+        assert!(StatusListRef::from_url("https://example.com/status/7").is_err());
+        assert!(StatusListRef::from_url("did:etrid:issuer/status-lists/x").is_err());
+    }
+
+    #[tokio::test]
+    async fn test_in_memory_bits_match_pallet_layout() {
+        let lists = InMemoryStatusLists::new();
+        let list = StatusListRef::new("did:etrid:issuer", 0);
+
+        lists.set_status(&list, 9, true).await;
+        let bits = lists.status_list(&list).await.unwrap();
+        assert_eq!(bits, vec![0x00, 0x40]);
+        assert!(is_set(&bits, 9));
+        assert!(!is_set(&bits, 8));
+        assert!(!is_set(&bits, 1_000));
+
+        lists.set_status(&list, 9, false).await;
+        assert!(!is_set(&lists.status_list(&list).await.unwrap(), 9));
+    }
+}
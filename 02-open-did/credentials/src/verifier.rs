@@ -0,0 +1,649 @@
+//! Verifying credentials and presentations against resolved DID documents
+
+use std::sync::Arc;
+
+use ed25519_dalek::{Signature, Verifier as _, VerifyingKey};
+use etrid_did_resolver::{chain::BlockNumber, DidResolver, OnChainResolver, ResolutionError};
+use serde_json::Value;
+
+use crate::{
+    data_integrity, jwt,
+    model::{format_timestamp, parse_timestamp, ProofPurpose, VerifiableCredential, VerifiablePresentation},
+    status::{is_set, StatusListRef, StatusListSource},
+    CredentialError,
+};
+
+/// Credential verifier
+///
+/// Keys are taken from the issuer's (or holder's) DID document as returned by
+/// the resolver, and must be listed under the relationship matching the proof
+/// purpose; deactivated DIDs are rejected. Credentials carrying a `credentialStatus` are rejected unless a
+/// status list source is configured. Keys come from the current document
+/// unless a historical check is asked for explicitly.
+pub struct Verifier {
+    resolver: Arc<dyn DidResolver>,
+    status_lists: Option<Arc<dyn StatusListSource>>,
+}
+
+impl Verifier {
+    pub fn new(resolver: Arc<dyn DidResolver>) -> Self {
+        Self {
+            resolver,
+            status_lists: None,
+        }
+    }
+
+    pub fn with_status_lists(mut self, status_lists: Arc<dyn StatusListSource>) -> Self {
+        self.status_lists = Some(status_lists);
+        self
+    }
+
+    /// Verify a Data Integrity secured credential now
+    pub async fn verify_credential(
+        &self,
+        credential: &VerifiableCredential,
+    ) -> Result<(), CredentialError> {
+        self.verify_credential_at(credential, crate::timestamp_secs()).await
+    }
+
+    /// Verify a Data Integrity secured credential at `now` (UNIX seconds)
+    pub async fn verify_credential_at(
+        &self,
+        credential: &VerifiableCredential,
+        now: u64,
+    ) -> Result<(), CredentialError> {
+        self.verify_credential_signed_at(credential, None, now).await
+    }
+
+    /// Verify a credential signed with a since-rotated key, against the issuer
+    /// document version in force at the proof's `created` time
+    ///
+    /// `created` is chosen by the signer, so a leaked rotated-out key can
+    /// backdate credentials; only use this for issuers whose past keys are
+    /// trusted. Validity and status are still checked at `now`.
+    pub async fn verify_credential_as_of_proof(
+        &self,
+        credential: &VerifiableCredential,
+        now: u64,
+    ) -> Result<(), CredentialError> {
+        let created = credential
+            .proof
+            .as_ref()
+            .map(|proof| {
+                parse_timestamp(&proof.created)
+                    .ok_or_else(|| CredentialError::InvalidProof(format!("bad timestamp {}", proof.created)))
+            })
+            .transpose()?;
+        self.verify_credential_signed_at(credential, created, now).await
+    }
+
+    async fn verify_credential_signed_at(
+        &self,
+        credential: &VerifiableCredential,
+        signed_at: Option<u64>,
+        now: u64,
+    ) -> Result<(), CredentialError> {
+        let proof = credential
+            .proof
+            .as_ref()
+            .ok_or_else(|| CredentialError::InvalidProof("credential has no proof".to_string()))?;
+        if proof.proof_purpose != ProofPurpose::AssertionMethod.as_str() {
+            return Err(CredentialError::InvalidProof(format!(
+                "unexpected proof purpose {}",
+                proof.proof_purpose
+            )));
+        }
+        if controller_did(&proof.verification_method) != credential.issuer {
+            return Err(CredentialError::IssuerMismatch);
+        }
+
+        let key = self
+            .resolve_key(&proof.verification_method, ProofPurpose::AssertionMethod, signed_at)
+            .await?;
+        data_integrity::verify(credential, proof, &key)?;
+        self.check_validity(credential, now)?;
+        self.check_status(credential).await
+    }
+
+    /// Verify a `vc+jwt` credential now and return its payload
+    pub async fn verify_jwt(&self, jwt: &str) -> Result<VerifiableCredential, CredentialError> {
+        let decoded = jwt::decode(jwt)?;
+        if controller_did(&decoded.header.kid) != decoded.credential.issuer {
+            return Err(CredentialError::IssuerMismatch);
+        }
+
+        let key = self
+            .resolve_key(&decoded.header.kid, ProofPurpose::AssertionMethod, None)
+            .await?;
+        decoded.verify(&key)?;
+        self.check_validity(&decoded.credential, crate::timestamp_secs())?;
+        self.check_status(&decoded.credential).await?;
+        Ok(decoded.credential)
+    }
+
+    /// Verify a presentation made for `challenge` and every credential in it
+    pub async fn verify_presentation(
+        &self,
+        presentation: &VerifiablePresentation,
+        challenge: &str,
+    ) -> Result<(), CredentialError> {
+        let proof = presentation
+            .proof
+            .as_ref()
+            .ok_or_else(|| CredentialError::InvalidProof("presentation has no proof".to_string()))?;
+        if proof.proof_purpose != ProofPurpose::Authentication.as_str() {
+            return Err(CredentialError::InvalidProof(format!(
+                "unexpected proof purpose {}",
+                proof.proof_purpose
+            )));
+        }
+        if proof.challenge.as_deref() != Some(challenge) {
+            return Err(CredentialError::ChallengeMismatch);
+        }
+        if let Some(holder) = &presentation.holder {
+            if controller_did(&proof.verification_method) != holder {
+                return Err(CredentialError::IssuerMismatch);
+            }
+        }
+
+        let key = self
+            .resolve_key(&proof.verification_method, ProofPurpose::Authentication, None)
+            .await?;
+        data_integrity::verify(presentation, proof, &key)?;
+
+        let now = crate::timestamp_secs();
+        for credential in &presentation.verifiable_credential {
+            self.verify_credential_at(credential, now).await?;
+        }
+        Ok(())
+    }
+
+    /// Public key of `verification_method`, authorized for `purpose` in the
+    /// current document, or in the version in force at `version_time`
+    async fn resolve_key(
+        &self,
+        verification_method: &str,
+        purpose: ProofPurpose,
+        version_time: Option<u64>,
+    ) -> Result<VerifyingKey, CredentialError> {
+        let did = controller_did(verification_method);
+        let did_url = match version_time {
+            Some(secs) => format!("{}?versionTime={}", did, format_timestamp(secs)),
+            None => did.to_string(),
+        };
+        let resolution = self.resolver.resolve(&did_url).await?;
+        // Revoked or expired DIDs can no longer issue or present
+        if resolution.document_metadata.deactivated {
+            return Err(ResolutionError::Deactivated.into());
+        }
+        key_from_document(&resolution.document, verification_method, purpose)
+    }
+
+    fn check_validity(
+        &self,
+        credential: &VerifiableCredential,
+        now: u64,
+    ) -> Result<(), CredentialError> {
+        let parse = |s: &String| {
+            parse_timestamp(s).ok_or_else(|| CredentialError::InvalidProof(format!("bad timestamp {}", s)))
+        };
+        if let Some(valid_from) = credential.valid_from.as_ref().map(parse).transpose()? {
+            if now < valid_from {
+                return Err(CredentialError::NotYetValid);
+            }
+        }
+        if let Some(valid_until) = credential.valid_until.as_ref().map(parse).transpose()? {
+            if now > valid_until {
+                return Err(CredentialError::Expired);
+            }
+        }
+        Ok(())
+    }
+
+    async fn check_status(&self, credential: &VerifiableCredential) -> Result<(), CredentialError> {
+        let Some(status) = &credential.credential_status else {
+            return Ok(());
+        };
+        if status.status_type != "BitstringStatusListEntry" || status.status_purpose != "revocation" {
+            return Err(CredentialError::InvalidStatus(format!(
+                "unsupported status {} / {}",
+                status.status_type, status.status_purpose
+            )));
+        }
+
+        let list = StatusListRef::from_url(&status.status_list_credential)?;
+        // Only the issuer may revoke its credentials
+        if list.did != credential.issuer {
+            return Err(CredentialError::InvalidStatus(
+                "status list is not controlled by the issuer".to_string(),
+            ));
+        }
+        let index: u32 = status.status_list_index.parse().map_err(|_| {
+            CredentialError::InvalidStatus(format!("bad status index {}", status.status_list_index))
+        })?;
+
+        let source = self.status_lists.as_ref().ok_or_else(|| {
+            CredentialError::StatusUnavailable("no status list source configured".to_string())
+        })?;
+        if is_set(&source.status_list(&list).await?, index) {
+            return Err(CredentialError::Revoked);
+        }
+        Ok(())
+    }
+}
+
+/// Verify an Ed25519 `signature` over `message` with `verification_method`
+/// as the DID document in force at `block` defined it
+///
+/// Checks signatures made with keys that have since been rotated out, as long
+/// as the version is still in the registry's history. The DID must not have
+/// been deactivated at `block`.
+pub async fn verify_signature_at_block(
+    resolver: &OnChainResolver,
+    verification_method: &str,
+    purpose: ProofPurpose,
+    message: &[u8],
+    signature: &[u8],
+    block: BlockNumber,
+) -> Result<(), CredentialError> {
+    let resolution = resolver.resolve_at(controller_did(verification_method), block).await?;
+    if resolution.document_metadata.deactivated {
+        return Err(ResolutionError::Deactivated.into());
+    }
+    let key = key_from_document(&resolution.document, verification_method, purpose)?;
+    let signature = Signature::from_slice(signature).map_err(|_| CredentialError::InvalidSignature)?;
+    key.verify(message, &signature).map_err(|_| CredentialError::InvalidSignature)
+}
+
+/// Public key of `verification_method` in a W3C DID document, authorized for
+/// `purpose`
+pub fn key_from_document(
+    document: &str,
+    verification_method: &str,
+    purpose: ProofPurpose,
+) -> Result<VerifyingKey, CredentialError> {
+    let did = controller_did(verification_method);
+    let document: Value =
+        serde_json::from_str(document).map_err(|e| CredentialError::InvalidDocument(e.to_string()))?;
+
+    let absolute = |id: &str| {
+        if id.starts_with('#') {
+            format!("{}{}", did, id)
+        } else {
+            id.to_string()
+        }
+    };
+    let is_method = |entry: &Value| {
+        entry
+            .as_str()
+            .or_else(|| entry.get("id").and_then(Value::as_str))
+            .is_some_and(|id| absolute(id) == verification_method)
+    };
+
+    let authorized = document
+        .get(purpose.as_str())
+        .and_then(Value::as_array)
+        .is_some_and(|entries| entries.iter().any(is_method));
+    if !authorized {
+        return Err(CredentialError::UnknownKey(verification_method.to_string()));
+    }
+
+    // Embedded relationship entries carry their own key material
+    let method = document
+        .get("verificationMethod")
+        .and_then(Value::as_array)
+        .into_iter()
+        .chain(document.get(purpose.as_str()).and_then(Value::as_array))
+        .flatten()
+        .find(|entry| entry.is_object() && is_method(entry))
+        .ok_or_else(|| CredentialError::UnknownKey(verification_method.to_string()))?;
+
+    let public_key = if let Some(multibase) = method.get("publicKeyMultibase").and_then(Value::as_str) {
+        etrid_did_types::decode_ed25519_multibase(multibase).map_err(CredentialError::InvalidDocument)?
+    } else if let Some(base58) = method.get("publicKeyBase58").and_then(Value::as_str) {
+        bs58::decode(base58)
+            .into_vec()
+            .map_err(|e| CredentialError::InvalidDocument(e.to_string()))?
+    } else {
+        return Err(CredentialError::InvalidDocument(format!(
+            "{} has no public key",
+            verification_method
+        )));
+    };
+
+    let bytes: [u8; 32] = public_key.as_slice().try_into().map_err(|_| {
+        CredentialError::InvalidDocument(format!("{} is not an Ed25519 key", verification_method))
+    })?;
+    VerifyingKey::from_bytes(&bytes).map_err(|e| CredentialError::InvalidDocument(e.to_string()))
+}
+
+/// DID part of a verification method ID
+fn controller_did(verification_method: &str) -> &str {
+    verification_method
+        .split_once('#')
+        .map_or(verification_method, |(did, _)| did)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{signer::DidSigner, status::InMemoryStatusLists};
+    use ed25519_dalek::SigningKey;
+    use etrid_did_resolver::{
+        chain::{InMemoryState, Registration},
+        store::InMemoryContentStore,
+        OnChainResolver, ResolutionMetadata, ResolutionResult,
+    };
+    use etrid_did_types::{Did, DidDocument, VerificationMethod};
+    use std::collections::HashMap;
+
+    /// Resolver serving fixed documents
+    struct StaticResolver(HashMap<String, String>);
+
+    #[async_trait::async_trait]
+    impl DidResolver for StaticResolver {
+        async fn resolve(&self, did: &str) -> Result<ResolutionResult, ResolutionError> {
+            let document = self.0.get(did).cloned().ok_or(ResolutionError::NotFound)?;
+            Ok(ResolutionResult {
+                did: did.to_string(),
+                document,
+                resolved_at: 0,
+                content_type: "application/did+json".to_string(),
+                metadata: ResolutionMetadata {
+                    content_type: "application/did+json".to_string(),
+                    retrieved_time: 0,
+                    duration_ms: 0,
+                },
+                document_metadata: Default::default(),
+            })
+        }
+    }
+
+    fn document(identifier: &str, key: &SigningKey) -> DidDocument {
+        let mut doc = DidDocument::new(Did::new(identifier.to_string()));
+        doc.add_verification_method(VerificationMethod::new(
+            "key1".to_string(),
+            format!("did:etrid:{}", identifier),
+            "Ed25519VerificationKey2020".to_string(),
+            key.verifying_key().to_bytes().to_vec(),
+        ))
+        .unwrap();
+        doc.set_authentication(vec!["#key1".to_string()]);
+        doc.set_assertion_method(vec!["key1".to_string()]);
+        doc
+    }
+
+    struct Fixture {
+        issuer: DidSigner,
+        holder: DidSigner,
+        verifier: Verifier,
+        status_lists: InMemoryStatusLists,
+    }
+
+    fn fixture() -> Fixture {
+        let issuer_key = SigningKey::from_bytes(&[1; 32]);
+        let holder_key = SigningKey::from_bytes(&[2; 32]);
+        let issuer_doc = document("issuer", &issuer_key);
+        let holder_doc = document("holder", &holder_key);
+
+        let resolver = StaticResolver(
+            [&issuer_doc, &holder_doc]
+                .iter()
+                .map(|doc| (doc.id.to_string(), doc.to_w3c_json().to_string()))
+                .collect(),
+        );
+        let status_lists = InMemoryStatusLists::new();
+
+        Fixture {
+            issuer: DidSigner::assertion(&issuer_doc, "#key1", issuer_key).unwrap(),
+            holder: DidSigner::authentication(&holder_doc, "key1", holder_key).unwrap(),
+            verifier: Verifier::new(Arc::new(resolver)).with_status_lists(Arc::new(status_lists.clone())),
+            status_lists,
+        }
+    }
+
+    fn credential() -> VerifiableCredential {
+        VerifiableCredential::new(
+            "did:etrid:issuer",
+            serde_json::json!({"id": "did:etrid:holder", "kycLevel": 2}),
+        )
+        .with_type("KycCredential")
+        .valid_from(1_700_000_000)
+        .valid_until(1_900_000_000)
+        .with_status(&StatusListRef::new("did:etrid:issuer", 0), 5)
+    }
+
+    #[test]
+    fn test_signer_requires_matching_authorized_key() {
+        let key = SigningKey::from_bytes(&[1; 32]);
+        let doc = document("issuer", &key);
+
+        let wrong_key = SigningKey::from_bytes(&[9; 32]);
+        assert!(matches!(
+            DidSigner::assertion(&doc, "#key1", wrong_key),
+            Err(CredentialError::KeyMismatch)
+        ));
+        assert!(matches!(
+            DidSigner::assertion(&doc, "#key2", key.clone()),
+            Err(CredentialError::UnknownKey(_))
+        ));
+
+        let signer = DidSigner::assertion(&doc, "key1", key).unwrap();
+        assert_eq!(signer.verification_method(), "did:etrid:issuer#key1");
+        let foreign = VerifiableCredential::new("did:etrid:other", serde_json::json!({}));
+        assert!(matches!(signer.issue(foreign), Err(CredentialError::IssuerMismatch)));
+    }
+
+    #[tokio::test]
+    async fn test_issue_and_verify_data_integrity() {
+        let f = fixture();
+        let signed = f.issuer.issue(credential()).unwrap();
+        let proof = signed.proof.as_ref().unwrap();
+        assert_eq!(proof.cryptosuite, "eddsa-jcs-2022");
+        assert!(proof.proof_value.as_ref().unwrap().starts_with('z'));
+
+        // Survives a JSON round trip
+        let json = serde_json::to_string(&signed).unwrap();
+        let parsed: VerifiableCredential = serde_json::from_str(&json).unwrap();
+        assert!(f.verifier.verify_credential_at(&parsed, 1_800_000_000).await.is_ok());
+
+        let mut tampered = parsed.clone();
+        tampered.credential_subject["kycLevel"] = 3.into();
+        assert!(matches!(
+            f.verifier.verify_credential_at(&tampered, 1_800_000_000).await,
+            Err(CredentialError::InvalidSignature)
+        ));
+
+        assert!(matches!(
+            f.verifier.verify_credential_at(&parsed, 1_600_000_000).await,
+            Err(CredentialError::NotYetValid)
+        ));
+        assert!(matches!(
+            f.verifier.verify_credential_at(&parsed, 2_000_000_000).await,
+            Err(CredentialError::Expired)
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_revoked_credential_is_rejected() {
+        let f = fixture();
+        let signed = f.issuer.issue(credential()).unwrap();
+        let list = StatusListRef::new("did:etrid:issuer", 0);
+
+        f.status_lists.set_status(&list, 4, true).await;
+        assert!(f.verifier.verify_credential_at(&signed, 1_800_000_000).await.is_ok());
+
+        f.status_lists.set_status(&list, 5, true).await;
+        assert!(matches!(
+            f.verifier.verify_credential_at(&signed, 1_800_000_000).await,
+            Err(CredentialError::Revoked)
+        ));
+
+        // Fails closed without a status source
+        let verifier = Verifier::new(f.verifier.resolver.clone());
+        assert!(matches!(
+            verifier.verify_credential_at(&signed, 1_800_000_000).await,
+            Err(CredentialError::StatusUnavailable(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_jwt_credential() {
+        let f = fixture();
+        let mut unexpiring = credential();
+        unexpiring.valid_until = None;
+        let token = f.issuer.issue_jwt(&unexpiring).unwrap();
+        assert_eq!(token.split('.').count(), 3);
+
+        let verified = f.verifier.verify_jwt(&token).await.unwrap();
+        assert_eq!(verified.credential_subject["kycLevel"], 2);
+
+        let (signing_input, signature) = token.rsplit_once('.').unwrap();
+        let flipped = if signature.starts_with('A') { 'B' } else { 'A' };
+        let forged = format!("{}.{}{}", signing_input, flipped, &signature[1..]);
+        assert!(matches!(
+            f.verifier.verify_jwt(&forged).await,
+            Err(CredentialError::InvalidSignature)
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_presentation_binds_challenge_and_holder() {
+        let f = fixture();
+        let mut unexpiring = credential();
+        unexpiring.valid_until = None;
+        let signed = f.issuer.issue(unexpiring).unwrap();
+
+        let presentation = f.holder.present(vec![signed], "nonce-123", Some("verifier.etrid.io")).unwrap();
+        assert!(f.verifier.verify_presentation(&presentation, "nonce-123").await.is_ok());
+        assert!(matches!(
+            f.verifier.verify_presentation(&presentation, "nonce-456").await,
+            Err(CredentialError::ChallengeMismatch)
+        ));
+
+        // The issuer's assertion key cannot authenticate a presentation
+        assert!(f.issuer.present(vec![], "nonce-123", None).is_err());
+    }
+
+    #[tokio::test]
+    async fn test_on_chain_issuer_and_status_list() {
+        let key = SigningKey::from_bytes(&[1; 32]);
+        let doc = document("issuer", &key);
+        let issuer = DidSigner::assertion(&doc, "#key1", key).unwrap();
+
+        let state = InMemoryState::new();
+        let store = InMemoryContentStore::new();
+        let mut registration = Registration {
+            did: b"did:etrid:issuer".to_vec(),
+            owner: [1; 32],
+            controller: [1; 32],
+            document_hash: store.put(doc.to_w3c_json().to_string().into_bytes()).await,
+            registered_at: 1,
+            updated_at: 1,
+            expires_at: None,
+            revoked: false,
+        };
+        state.set_registration(1, &registration, 1_700_000_000).await;
+        let resolver = Arc::new(OnChainResolver::new(Arc::new(state.clone()), Arc::new(store), 0));
+        let verifier = Verifier::new(resolver.clone()).with_status_lists(resolver.clone());
+
+        let signed = issuer.issue(credential()).unwrap();
+        assert!(verifier.verify_credential_at(&signed, 1_800_000_000).await.is_ok());
+
+        // DidRegistry::set_credential_status(issuer, 0, 5, true)
+        let status_key = etrid_did_resolver::chain::status_list_key("did:etrid:issuer", 0);
+        state.set(2, status_key, codec_vec(&[0x04])).await;
+        assert!(matches!(
+            verifier.verify_credential_at(&signed, 1_800_000_000).await,
+            Err(CredentialError::Revoked)
+        ));
+
+        // Revoking the issuer DID invalidates everything it signed
+        registration.revoked = true;
+        registration.updated_at = 3;
+        state.set_registration(3, &registration, 1_700_000_300).await;
+        resolver.clear_cache().await;
+        assert!(matches!(
+            verifier.verify_credential_at(&signed, 1_800_000_000).await,
+            Err(CredentialError::Resolution(ResolutionError::Deactivated))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_rotated_key_verifies_against_history() {
+        use ed25519_dalek::Signer;
+        use etrid_did_resolver::chain::DocumentVersion;
+
+        let old_key = SigningKey::from_bytes(&[1; 32]);
+        let new_key = SigningKey::from_bytes(&[3; 32]);
+        let old_doc = document("issuer", &old_key);
+        let issuer = DidSigner::assertion(&old_doc, "#key1", old_key.clone()).unwrap();
+        let signed = issuer.issue(credential()).unwrap();
+        let now = crate::timestamp_secs();
+
+        // key1 rotated to a new key an hour after issuance
+        let state = InMemoryState::new();
+        let store = InMemoryContentStore::new();
+        let old_hash = store.put(old_doc.to_w3c_json().to_string().into_bytes()).await;
+        let new_hash = store
+            .put(document("issuer", &new_key).to_w3c_json().to_string().into_bytes())
+            .await;
+        let mut registration = Registration {
+            did: b"did:etrid:issuer".to_vec(),
+            owner: [1; 32],
+            controller: [1; 32],
+            document_hash: old_hash.clone(),
+            registered_at: 1,
+            updated_at: 1,
+            expires_at: None,
+            revoked: false,
+        };
+        state.set_registration(1, &registration, now - 100).await;
+        registration.document_hash = new_hash.clone();
+        registration.updated_at = 5;
+        state.set_registration(5, &registration, now + 3_600).await;
+        let versions = [
+            DocumentVersion { document_hash: old_hash, valid_from: 1 },
+            DocumentVersion { document_hash: new_hash, valid_from: 5 },
+        ];
+        state.set_versions(5, "did:etrid:issuer", &versions).await;
+        let resolver = Arc::new(OnChainResolver::new(Arc::new(state.clone()), Arc::new(store), 0));
+        let verifier = Verifier::new(resolver.clone()).with_status_lists(resolver.clone());
+
+        assert!(matches!(
+            verifier.verify_credential_at(&signed, now).await,
+            Err(CredentialError::InvalidSignature)
+        ));
+        assert!(verifier.verify_credential_as_of_proof(&signed, now).await.is_ok());
+
+        let message = b"transfer 10 ETR";
+        let signature = old_key.sign(message).to_bytes();
+        let check = |block| {
+            verify_signature_at_block(
+                &resolver,
+                "did:etrid:issuer#key1",
+                ProofPurpose::AssertionMethod,
+                message,
+                &signature,
+                block,
+            )
+        };
+        assert!(check(3).await.is_ok());
+        assert!(matches!(check(5).await, Err(CredentialError::InvalidSignature)));
+
+        // A revoked issuer invalidates historical keys too
+        registration.revoked = true;
+        registration.updated_at = 9;
+        state.set_registration(9, &registration, now + 7_200).await;
+        assert!(matches!(
+            verifier.verify_credential_as_of_proof(&signed, now).await,
+            Err(CredentialError::Resolution(ResolutionError::Deactivated))
+        ));
+        assert!(check(3).await.is_ok());
+    }
+
+    /// SCALE encoding of a byte vector (compact length prefix, short form)
+    fn codec_vec(bytes: &[u8]) -> Vec<u8> {
+        [vec![(bytes.len() as u8) << 2], bytes.to_vec()].concat()
+    }
+}
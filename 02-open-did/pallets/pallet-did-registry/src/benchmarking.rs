@@ -0,0 +1,183 @@
+//! Benchmarking setup for pallet-did-registry
+//!
+//! Every DID used here has a full owner list and a full version history,
+//! so the bounded vectors are decoded and re-encoded at their maximum size.
+
+#![cfg(feature = "runtime-benchmarks")]
+
+use super::*;
+use frame_benchmarking::v2::*;
+use frame_support::{pallet_prelude::*, BoundedVec};
+use frame_system::{pallet_prelude::BlockNumberFor, RawOrigin};
+use sp_core::H256;
+use sp_std::{vec, vec::Vec};
+
+/// Identifier and document hash of maximum length
+fn max_identifier(seed: u8) -> Vec<u8> {
+    let mut identifier = vec![b'a'; MAX_DID_IDENTIFIER_LENGTH as usize];
+    identifier[0] = seed;
+    identifier
+}
+
+fn max_document_hash() -> Vec<u8> {
+    vec![b'h'; MAX_DOCUMENT_HASH_LENGTH as usize]
+}
+
+/// Fill `owner`'s DID list up to one below the limit
+fn fill_owner_dids<T: Config>(owner: &T::AccountId) {
+    let filler = (0..T::MaxAccessControlEntries::get().saturating_sub(1))
+        .map(|i| H256::from_low_u64_be(u64::from(i) + 1))
+        .collect::<Vec<_>>();
+    OwnerDids::<T>::insert(owner, BoundedVec::truncate_from(filler));
+}
+
+/// Fill the version history of `did_hash` up to its limit
+fn fill_history<T: Config>(did_hash: H256) {
+    let versions = (0..T::MaxVersionHistory::get())
+        .map(|i| DocumentVersion::<T> {
+            document_hash: BoundedVec::truncate_from(max_document_hash()),
+            valid_from: BlockNumberFor::<T>::from(i),
+        })
+        .collect::<Vec<_>>();
+    VersionHistory::<T>::insert(did_hash, BoundedVec::truncate_from(versions));
+}
+
+/// Register a DID for `owner` with `controller`, returning its hash
+fn registered<T: Config>(owner: &T::AccountId, controller: &T::AccountId) -> H256 {
+    let identifier = max_identifier(b'd');
+    fill_owner_dids::<T>(owner);
+    Pallet::<T>::register_did(
+        RawOrigin::Signed(owner.clone()).into(),
+        identifier.clone(),
+        controller.clone(),
+        max_document_hash(),
+    )
+    .expect("fresh DID registers");
+    let did_hash = Pallet::<T>::hash_did(&identifier);
+    fill_history::<T>(did_hash);
+    // Versions recorded from here on are new rather than same-block replacements
+    frame_system::Pallet::<T>::set_block_number(T::MaxVersionHistory::get().into());
+    did_hash
+}
+
+#[benchmarks]
+mod benchmarks {
+    use super::*;
+
+    #[benchmark]
+    fn register_did() {
+        let caller: T::AccountId = whitelisted_caller();
+        let controller: T::AccountId = account("controller", 0, 0);
+        let identifier = max_identifier(b'r');
+        fill_owner_dids::<T>(&caller);
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller.clone()), identifier.clone(), controller, max_document_hash());
+
+        assert!(Registrations::<T>::contains_key(Pallet::<T>::hash_did(&identifier)));
+    }
+
+    #[benchmark]
+    fn update_did() {
+        let caller: T::AccountId = whitelisted_caller();
+        let did_hash = registered::<T>(&caller, &account("controller", 0, 0));
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller), did_hash, max_document_hash());
+
+        assert_eq!(VersionHistory::<T>::get(did_hash).len() as u32, T::MaxVersionHistory::get());
+    }
+
+    #[benchmark]
+    fn revoke_did() {
+        let caller: T::AccountId = whitelisted_caller();
+        let did_hash = registered::<T>(&caller, &account("controller", 0, 0));
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller), did_hash);
+
+        assert!(Registrations::<T>::get(did_hash).unwrap().revoked);
+    }
+
+    #[benchmark]
+    fn transfer_ownership() {
+        let caller: T::AccountId = whitelisted_caller();
+        let new_owner: T::AccountId = account("new_owner", 0, 0);
+        let did_hash = registered::<T>(&caller, &account("controller", 0, 0));
+        fill_owner_dids::<T>(&new_owner);
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller), did_hash, new_owner.clone());
+
+        assert_eq!(Registrations::<T>::get(did_hash).unwrap().owner, new_owner);
+    }
+
+    #[benchmark]
+    fn set_expiration() {
+        let caller: T::AccountId = whitelisted_caller();
+        let did_hash = registered::<T>(&caller, &account("controller", 0, 0));
+        let expires_at = frame_system::Pallet::<T>::block_number() + 100u32.into();
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller), did_hash, expires_at);
+
+        assert_eq!(Registrations::<T>::get(did_hash).unwrap().expires_at, Some(expires_at));
+    }
+
+    #[benchmark]
+    fn grant_access() {
+        let caller: T::AccountId = whitelisted_caller();
+        let agent: T::AccountId = account("agent", 0, 0);
+        let did_hash = registered::<T>(&caller, &account("controller", 0, 0));
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller), did_hash, agent.clone(), AccessLevel::Admin);
+
+        assert_eq!(Pallet::<T>::check_access(did_hash, &agent), AccessLevel::Admin);
+    }
+
+    #[benchmark]
+    fn revoke_access() {
+        let caller: T::AccountId = whitelisted_caller();
+        let agent: T::AccountId = account("agent", 0, 0);
+        let did_hash = registered::<T>(&caller, &account("controller", 0, 0));
+        Pallet::<T>::grant_access(
+            RawOrigin::Signed(caller.clone()).into(),
+            did_hash,
+            agent.clone(),
+            AccessLevel::Admin,
+        )
+        .expect("owner grants access");
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller), did_hash, agent.clone());
+
+        assert_eq!(Pallet::<T>::check_access(did_hash, &agent), AccessLevel::None);
+    }
+
+    /// `b` bytes of status list are grown from empty
+    #[benchmark]
+    fn set_credential_status(b: Linear<1, { T::MaxStatusListBytes::get() }>) {
+        let caller: T::AccountId = whitelisted_caller();
+        let did_hash = registered::<T>(&caller, &account("controller", 0, 0));
+        let index = (b - 1) * 8;
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller), did_hash, 0, index, true);
+
+        assert!(Pallet::<T>::is_credential_revoked(did_hash, 0, index));
+    }
+
+    #[benchmark]
+    fn prune_history() {
+        let caller: T::AccountId = whitelisted_caller();
+        let did_hash = registered::<T>(&caller, &account("controller", 0, 0));
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller), did_hash, 1);
+
+        assert_eq!(VersionHistory::<T>::get(did_hash).len(), 1);
+    }
+
+    impl_benchmark_test_suite!(Pallet, crate::tests::new_test_ext(), crate::tests::Test);
+}
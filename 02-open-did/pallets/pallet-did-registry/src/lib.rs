@@ -14,6 +14,8 @@
 //! - Ownership transfer and delegation capabilities
 //! - DID expiration and revocation mechanisms
 //! - Support for controller and owner separation
+//! - Credential status lists for revoking issued Verifiable Credentials
+//! - Bounded, prunable document version history for versioned resolution
 //!
 //! ## Extrinsics
 //!
@@ -24,6 +26,8 @@
 //! - `set_expiration` - Set expiration block for a DID
 //! - `grant_access` - Grant access permissions to an agent
 //! - `revoke_access` - Revoke access permissions from an agent
+//! - `set_credential_status` - Set or clear a credential's revocation bit
+//! - `prune_history` - Drop old document versions, keeping the most recent ones
 //!
 //! ## Usage Example
 //!
@@ -59,6 +63,8 @@
 //! - `AccessControlList` - Maps DID and agent to access permissions
 //! - `TotalDids` - Total number of registered DIDs
 //! - `Nonce` - Nonce counter for unique operations
+//! - `StatusLists` - Maps issuer DID and list ID to a revocation bitstring
+//! - `VersionHistory` - Maps DID hash to its document versions, oldest first
 //!
 //! ## Events
 //!
@@ -69,6 +75,8 @@
 //! - `ExpirationSet` - When DID expiration is configured
 //! - `AccessGranted` - When access is granted to an agent
 //! - `AccessRevoked` - When access is revoked from an agent
+//! - `CredentialStatusUpdated` - When a credential's revocation bit changes
+//! - `VersionHistoryPruned` - When old document versions are dropped
 //!
 //! ## Errors
 //!
@@ -77,6 +85,24 @@
 //! - `NotAuthorized` - Caller lacks required permissions
 //! - `DidRevoked` - DID has been revoked
 //! - `DidExpired` - DID has expired
+//! - `StatusIndexOutOfRange` - Status index beyond `MaxStatusListBytes`
+//! - `CannotPruneCurrentVersion` - Pruning would drop the current version
+//!
+//! ## Document Version History
+//!
+//! `register_did` and `update_did` append `(document_hash, valid_from)` to the
+//! DID's `VersionHistory`, so signatures made with a rotated-out key can still
+//! be checked against the document in force at signing time. Several updates in
+//! one block collapse into one version. The history keeps at most
+//! `MaxVersionHistory` entries (oldest dropped first) and owners or controllers
+//! may prune it further with `prune_history`.
+//!
+//! ## Credential Status Lists
+//!
+//! Issuers anchor a W3C Bitstring Status List per `(did_hash, list_id)`.
+//! Credential `index` maps to bit `0x80 >> (index % 8)` of byte `index / 8`;
+//! a set bit means the credential is revoked. Lists grow on demand up to
+//! `MaxStatusListBytes` and are read off-chain by credential verifiers.
 //!
 //! ## W3C DID Format
 //!
@@ -87,6 +113,11 @@
 
 pub use pallet::*;
 
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+pub mod weights;
+pub use weights::WeightInfo;
+
 #[frame_support::pallet]
 pub mod pallet {
     use super::*;
@@ -138,6 +169,16 @@ pub mod pallet {
         }
     }
 
+    /// Document version in a DID's history
+    #[derive(Clone, Encode, Decode, PartialEq, Eq, TypeInfo, RuntimeDebug, MaxEncodedLen)]
+    #[scale_info(skip_type_params(T))]
+    pub struct DocumentVersion<T: Config> {
+        /// Document hash of this version
+        pub document_hash: BoundedVec<u8, ConstU32<MAX_DOCUMENT_HASH_LENGTH>>,
+        /// Block from which this version is in force (also its version ID)
+        pub valid_from: BlockNumberFor<T>,
+    }
+
     /// Access control level
     #[derive(Clone, Copy, Encode, Decode, PartialEq, Eq, TypeInfo, RuntimeDebug, MaxEncodedLen)]
     pub enum AccessLevel {
@@ -170,6 +211,17 @@ pub mod pallet {
         /// Maximum number of access control entries per DID
         #[pallet::constant]
         type MaxAccessControlEntries: Get<u32>;
+
+        /// Maximum size of a credential status list in bytes (8 credentials per byte)
+        #[pallet::constant]
+        type MaxStatusListBytes: Get<u32>;
+
+        /// Maximum number of document versions kept per DID
+        #[pallet::constant]
+        type MaxVersionHistory: Get<u32>;
+
+        /// Weight information for extrinsics in this pallet
+        type WeightInfo: WeightInfo;
     }
 
     /// DID registrations (DID hash => Registration)
@@ -215,6 +267,28 @@ pub mod pallet {
     #[pallet::getter(fn nonce)]
     pub type Nonce<T: Config> = StorageValue<_, u64, ValueQuery>;
 
+    /// Credential status lists (Issuer DID hash => List ID => Bitstring)
+    #[pallet::storage]
+    #[pallet::getter(fn status_list)]
+    pub type StatusLists<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat, H256, // Issuer DID hash
+        Twox64Concat, u32,      // List ID
+        BoundedVec<u8, T::MaxStatusListBytes>,
+        ValueQuery,
+    >;
+
+    /// Document version history (DID hash => Versions, oldest first)
+    #[pallet::storage]
+    #[pallet::getter(fn version_history)]
+    pub type VersionHistory<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        H256,
+        BoundedVec<DocumentVersion<T>, T::MaxVersionHistory>,
+        ValueQuery,
+    >;
+
     #[pallet::event]
     #[pallet::generate_deposit(pub(super) fn deposit_event)]
     pub enum Event<T: Config> {
@@ -255,6 +329,18 @@ pub mod pallet {
             did_hash: H256,
             agent: T::AccountId,
         },
+        /// Credential revocation bit set or cleared
+        CredentialStatusUpdated {
+            did_hash: H256,
+            list_id: u32,
+            index: u32,
+            revoked: bool,
+        },
+        /// Old document versions dropped
+        VersionHistoryPruned {
+            did_hash: H256,
+            removed: u32,
+        },
     }
 
     #[pallet::error]
@@ -279,13 +365,17 @@ pub mod pallet {
         TooManyAccessEntries,
         /// Cannot transfer to same owner
         SameOwner,
+        /// Status index beyond the maximum status list size
+        StatusIndexOutOfRange,
+        /// The current document version cannot be pruned
+        CannotPruneCurrentVersion,
     }
 
     #[pallet::call]
     impl<T: Config> Pallet<T> {
         /// Register a new DID
         #[pallet::call_index(0)]
-        #[pallet::weight(100_000)]
+        #[pallet::weight(T::WeightInfo::register_did())]
         pub fn register_did(
             origin: OriginFor<T>,
             did_identifier: Vec<u8>,
@@ -321,7 +411,8 @@ pub mod pallet {
                 revoked: false,
             };
 
-            // Store registration
+            // Store registration and its first document version
+            Self::record_version(did_hash, registration.document_hash.clone(), current_block);
             Registrations::<T>::insert(did_hash, registration);
 
             // Add to owner's DID list
@@ -344,7 +435,7 @@ pub mod pallet {
 
         /// Update DID document hash
         #[pallet::call_index(1)]
-        #[pallet::weight(50_000)]
+        #[pallet::weight(T::WeightInfo::update_did())]
         pub fn update_did(
             origin: OriginFor<T>,
             did_hash: H256,
@@ -371,6 +462,7 @@ pub mod pallet {
 
             // Update
             let current_block = <frame_system::Pallet<T>>::block_number();
+            Self::record_version(did_hash, bounded_hash.clone(), current_block);
             registration.document_hash = bounded_hash;
             registration.updated_at = current_block;
 
@@ -386,7 +478,7 @@ pub mod pallet {
 
         /// Revoke a DID
         #[pallet::call_index(2)]
-        #[pallet::weight(50_000)]
+        #[pallet::weight(T::WeightInfo::revoke_did())]
         pub fn revoke_did(
             origin: OriginFor<T>,
             did_hash: H256,
@@ -416,7 +508,7 @@ pub mod pallet {
 
         /// Transfer DID ownership
         #[pallet::call_index(3)]
-        #[pallet::weight(50_000)]
+        #[pallet::weight(T::WeightInfo::transfer_ownership())]
         pub fn transfer_ownership(
             origin: OriginFor<T>,
             did_hash: H256,
@@ -461,7 +553,7 @@ pub mod pallet {
 
         /// Set DID expiration
         #[pallet::call_index(4)]
-        #[pallet::weight(25_000)]
+        #[pallet::weight(T::WeightInfo::set_expiration())]
         pub fn set_expiration(
             origin: OriginFor<T>,
             did_hash: H256,
@@ -499,7 +591,7 @@ pub mod pallet {
 
         /// Grant access to an agent
         #[pallet::call_index(5)]
-        #[pallet::weight(25_000)]
+        #[pallet::weight(T::WeightInfo::grant_access())]
         pub fn grant_access(
             origin: OriginFor<T>,
             did_hash: H256,
@@ -539,7 +631,7 @@ pub mod pallet {
 
         /// Revoke access from an agent
         #[pallet::call_index(6)]
-        #[pallet::weight(25_000)]
+        #[pallet::weight(T::WeightInfo::revoke_access())]
         pub fn revoke_access(
             origin: OriginFor<T>,
             did_hash: H256,
@@ -564,6 +656,95 @@ pub mod pallet {
 
             Ok(())
         }
+
+        /// Set or clear the revocation bit of a credential issued by a DID
+        #[pallet::call_index(7)]
+        #[pallet::weight(T::WeightInfo::set_credential_status(
+            index.saturating_div(8).saturating_add(1).min(T::MaxStatusListBytes::get())
+        ))]
+        pub fn set_credential_status(
+            origin: OriginFor<T>,
+            did_hash: H256,
+            list_id: u32,
+            index: u32,
+            revoked: bool,
+        ) -> DispatchResult {
+            let issuer = ensure_signed(origin)?;
+
+            // Get registration
+            let registration = Registrations::<T>::get(did_hash)
+                .ok_or(Error::<T>::DidNotFound)?;
+
+            // Check authorization (owner or controller)
+            ensure!(
+                registration.owner == issuer || registration.controller == issuer,
+                Error::<T>::NotAuthorized
+            );
+
+            // Check if revoked
+            ensure!(!registration.revoked, Error::<T>::DidRevoked);
+
+            let byte = (index / 8) as usize;
+            let mask = 0x80u8 >> (index % 8);
+
+            StatusLists::<T>::try_mutate(did_hash, list_id, |list| -> DispatchResult {
+                // Grow the bitstring on demand
+                while list.len() <= byte {
+                    list.try_push(0).map_err(|_| Error::<T>::StatusIndexOutOfRange)?;
+                }
+                if revoked {
+                    list[byte] |= mask;
+                } else {
+                    list[byte] &= !mask;
+                }
+                Ok(())
+            })?;
+
+            Self::deposit_event(Event::CredentialStatusUpdated {
+                did_hash,
+                list_id,
+                index,
+                revoked,
+            });
+
+            Ok(())
+        }
+
+        /// Drop old document versions, keeping the `keep` most recent
+        #[pallet::call_index(8)]
+        #[pallet::weight(T::WeightInfo::prune_history())]
+        pub fn prune_history(
+            origin: OriginFor<T>,
+            did_hash: H256,
+            keep: u32,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            // Get registration
+            let registration = Registrations::<T>::get(did_hash)
+                .ok_or(Error::<T>::DidNotFound)?;
+
+            // Check authorization (owner or controller)
+            ensure!(
+                registration.owner == who || registration.controller == who,
+                Error::<T>::NotAuthorized
+            );
+
+            ensure!(keep > 0, Error::<T>::CannotPruneCurrentVersion);
+
+            let removed = VersionHistory::<T>::mutate(did_hash, |versions| {
+                let removed = versions.len().saturating_sub(keep as usize);
+                versions.drain(..removed);
+                removed as u32
+            });
+
+            Self::deposit_event(Event::VersionHistoryPruned {
+                did_hash,
+                removed,
+            });
+
+            Ok(())
+        }
     }
 
     // Helper functions
@@ -588,6 +769,15 @@ pub mod pallet {
             }
         }
 
+        /// Check if `who` owns or controls an active DID (e.g. to act as a recovery guardian)
+        pub fn is_owner_or_controller(did_hash: H256, who: &T::AccountId) -> bool {
+            Registrations::<T>::get(did_hash).is_some_and(|registration| {
+                let current_block = <frame_system::Pallet<T>>::block_number();
+                registration.is_active(current_block)
+                    && (registration.owner == *who || registration.controller == *who)
+            })
+        }
+
         /// Check access level for agent
         pub fn check_access(did_hash: H256, agent: &T::AccountId) -> AccessLevel {
             AccessControlList::<T>::get(did_hash, agent)
@@ -595,6 +785,41 @@ pub mod pallet {
                 .unwrap_or(AccessLevel::None)
         }
 
+        /// Append a document version, dropping the oldest when the history is full
+        fn record_version(
+            did_hash: H256,
+            document_hash: BoundedVec<u8, ConstU32<MAX_DOCUMENT_HASH_LENGTH>>,
+            block: BlockNumberFor<T>,
+        ) {
+            VersionHistory::<T>::mutate(did_hash, |versions| {
+                // Updates within one block collapse into one version
+                if versions.last().is_some_and(|v| v.valid_from == block) {
+                    versions.pop();
+                }
+                let version = DocumentVersion { document_hash, valid_from: block };
+                let _ = versions.force_insert_keep_right(versions.len(), version);
+            });
+        }
+
+        /// Document hash in force at `block`, if that version is still kept
+        pub fn document_hash_at(
+            did_hash: H256,
+            block: BlockNumberFor<T>,
+        ) -> Option<BoundedVec<u8, ConstU32<MAX_DOCUMENT_HASH_LENGTH>>> {
+            VersionHistory::<T>::get(did_hash)
+                .into_iter()
+                .rev()
+                .find(|v| v.valid_from <= block)
+                .map(|v| v.document_hash)
+        }
+
+        /// Check if a credential's revocation bit is set
+        pub fn is_credential_revoked(did_hash: H256, list_id: u32, index: u32) -> bool {
+            StatusLists::<T>::get(did_hash, list_id)
+                .get((index / 8) as usize)
+                .is_some_and(|byte| byte & (0x80u8 >> (index % 8)) != 0)
+        }
+
         /// Get DIDs owned by account
         pub fn get_owner_dids(owner: &T::AccountId) -> Vec<H256> {
             OwnerDids::<T>::get(owner).into_iter().collect()
@@ -679,9 +904,12 @@ mod tests {
     impl crate::Config for Test {
         type RuntimeEvent = RuntimeEvent;
         type MaxAccessControlEntries = ConstU32<100>;
+        type MaxStatusListBytes = ConstU32<4>;
+        type MaxVersionHistory = ConstU32<3>;
+        type WeightInfo = ();
     }
 
-    fn new_test_ext() -> sp_io::TestExternalities {
+    pub fn new_test_ext() -> sp_io::TestExternalities {
         let storage = frame_system::GenesisConfig::<Test>::default()
             .build_storage()
             .unwrap();
@@ -943,4 +1171,161 @@ mod tests {
             assert!(!DidRegistry::is_did_active(did_hash));
         });
     }
+
+    #[test]
+    fn test_set_credential_status() {
+        new_test_ext().execute_with(|| {
+            let owner = 1u64;
+            let controller = 2u64;
+            let did = b"did:etrid:issuer".to_vec();
+            let doc_hash = b"hash123".to_vec();
+
+            assert_ok!(DidRegistry::register_did(
+                RuntimeOrigin::signed(owner),
+                did.clone(),
+                controller,
+                doc_hash
+            ));
+
+            let did_hash = DidRegistry::hash_did(&did);
+
+            assert_ok!(DidRegistry::set_credential_status(
+                RuntimeOrigin::signed(controller),
+                did_hash,
+                0,
+                9,
+                true
+            ));
+            assert!(DidRegistry::is_credential_revoked(did_hash, 0, 9));
+            assert!(!DidRegistry::is_credential_revoked(did_hash, 0, 8));
+            assert!(!DidRegistry::is_credential_revoked(did_hash, 1, 9));
+            assert_eq!(DidRegistry::status_list(did_hash, 0).into_inner(), vec![0x00, 0x40]);
+
+            assert_ok!(DidRegistry::set_credential_status(
+                RuntimeOrigin::signed(owner),
+                did_hash,
+                0,
+                9,
+                false
+            ));
+            assert!(!DidRegistry::is_credential_revoked(did_hash, 0, 9));
+        });
+    }
+
+    #[test]
+    fn test_set_credential_status_rejected() {
+        new_test_ext().execute_with(|| {
+            let owner = 1u64;
+            let controller = 2u64;
+            let did = b"did:etrid:issuer".to_vec();
+            let doc_hash = b"hash123".to_vec();
+
+            assert_ok!(DidRegistry::register_did(
+                RuntimeOrigin::signed(owner),
+                did.clone(),
+                controller,
+                doc_hash
+            ));
+
+            let did_hash = DidRegistry::hash_did(&did);
+
+            assert_err!(
+                DidRegistry::set_credential_status(RuntimeOrigin::signed(3), did_hash, 0, 1, true),
+                Error::<Test>::NotAuthorized
+            );
+
+            // 4 bytes hold indices 0..32
+            assert_ok!(DidRegistry::set_credential_status(
+                RuntimeOrigin::signed(owner),
+                did_hash,
+                0,
+                31,
+                true
+            ));
+            assert_err!(
+                DidRegistry::set_credential_status(RuntimeOrigin::signed(owner), did_hash, 0, 32, true),
+                Error::<Test>::StatusIndexOutOfRange
+            );
+
+            assert_ok!(DidRegistry::revoke_did(RuntimeOrigin::signed(owner), did_hash));
+            assert_err!(
+                DidRegistry::set_credential_status(RuntimeOrigin::signed(owner), did_hash, 0, 1, true),
+                Error::<Test>::DidRevoked
+            );
+        });
+    }
+
+    #[test]
+    fn test_version_history() {
+        new_test_ext().execute_with(|| {
+            let owner = 1u64;
+            let controller = 2u64;
+            let did = b"did:etrid:user1".to_vec();
+
+            assert_ok!(DidRegistry::register_did(
+                RuntimeOrigin::signed(owner),
+                did.clone(),
+                controller,
+                b"hash1".to_vec()
+            ));
+            let did_hash = DidRegistry::hash_did(&did);
+
+            System::set_block_number(5);
+            assert_ok!(DidRegistry::update_did(RuntimeOrigin::signed(controller), did_hash, b"hash2".to_vec()));
+            // Second update in the same block replaces the version
+            assert_ok!(DidRegistry::update_did(RuntimeOrigin::signed(owner), did_hash, b"hash3".to_vec()));
+
+            let versions = DidRegistry::version_history(did_hash);
+            assert_eq!(versions.len(), 2);
+            assert_eq!(versions[1].valid_from, 5);
+            assert_eq!(versions[1].document_hash.to_vec(), b"hash3".to_vec());
+
+            assert_eq!(DidRegistry::document_hash_at(did_hash, 0), None);
+            assert_eq!(DidRegistry::document_hash_at(did_hash, 4).unwrap().to_vec(), b"hash1".to_vec());
+            assert_eq!(DidRegistry::document_hash_at(did_hash, 9).unwrap().to_vec(), b"hash3".to_vec());
+
+            // Bounded: the oldest version is dropped
+            for (block, hash) in [(6u64, b"hash4"), (7, b"hash5")] {
+                System::set_block_number(block);
+                assert_ok!(DidRegistry::update_did(RuntimeOrigin::signed(owner), did_hash, hash.to_vec()));
+            }
+            let versions = DidRegistry::version_history(did_hash);
+            assert_eq!(versions.iter().map(|v| v.valid_from).collect::<Vec<_>>(), vec![5, 6, 7]);
+            assert_eq!(DidRegistry::document_hash_at(did_hash, 4), None);
+        });
+    }
+
+    #[test]
+    fn test_prune_history() {
+        new_test_ext().execute_with(|| {
+            let owner = 1u64;
+            let controller = 2u64;
+            let did = b"did:etrid:user1".to_vec();
+
+            assert_ok!(DidRegistry::register_did(
+                RuntimeOrigin::signed(owner),
+                did.clone(),
+                controller,
+                b"hash1".to_vec()
+            ));
+            let did_hash = DidRegistry::hash_did(&did);
+            System::set_block_number(2);
+            assert_ok!(DidRegistry::update_did(RuntimeOrigin::signed(owner), did_hash, b"hash2".to_vec()));
+
+            assert_err!(
+                DidRegistry::prune_history(RuntimeOrigin::signed(3), did_hash, 1),
+                Error::<Test>::NotAuthorized
+            );
+            assert_err!(
+                DidRegistry::prune_history(RuntimeOrigin::signed(owner), did_hash, 0),
+                Error::<Test>::CannotPruneCurrentVersion
+            );
+
+            assert_ok!(DidRegistry::prune_history(RuntimeOrigin::signed(controller), did_hash, 1));
+            let versions = DidRegistry::version_history(did_hash);
+            assert_eq!(versions.len(), 1);
+            assert_eq!(versions[0].document_hash.to_vec(), b"hash2".to_vec());
+            System::assert_last_event(Event::VersionHistoryPruned { did_hash, removed: 1 }.into());
+        });
+    }
 }
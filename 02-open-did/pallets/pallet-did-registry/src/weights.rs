@@ -0,0 +1,167 @@
+//! PLACEHOLDER weights for `pallet_did_registry`: hand-estimated, not benchmark output.
+//!
+//! Overwrite with `./scripts/benchmark-pallets.sh pallet_did_registry` on reference hardware.
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(missing_docs)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use core::marker::PhantomData;
+
+/// Weight functions needed for `pallet_did_registry`.
+pub trait WeightInfo {
+	fn register_did() -> Weight;
+	fn update_did() -> Weight;
+	fn revoke_did() -> Weight;
+	fn transfer_ownership() -> Weight;
+	fn set_expiration() -> Weight;
+	fn grant_access() -> Weight;
+	fn revoke_access() -> Weight;
+	fn set_credential_status(b: u32, ) -> Weight;
+	fn prune_history() -> Weight;
+}
+
+/// Placeholder weights for `pallet_did_registry`, pending benchmarking.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	/// Storage: `DidRegistry::Registrations` (r:1 w:1)
+	/// Storage: `DidRegistry::VersionHistory` (r:1 w:1)
+	/// Storage: `DidRegistry::OwnerDids` (r:1 w:1)
+	/// Storage: `DidRegistry::TotalDids` (r:1 w:1)
+	/// Storage: `DidRegistry::Nonce` (r:1 w:1)
+	fn register_did() -> Weight {
+		Weight::from_parts(46_820_000, 5681)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+	/// Storage: `DidRegistry::Registrations` (r:1 w:1)
+	/// Storage: `DidRegistry::VersionHistory` (r:1 w:1)
+	fn update_did() -> Weight {
+		Weight::from_parts(38_140_000, 4756)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `DidRegistry::Registrations` (r:1 w:1)
+	fn revoke_did() -> Weight {
+		Weight::from_parts(22_370_000, 3787)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `DidRegistry::Registrations` (r:1 w:1)
+	/// Storage: `DidRegistry::OwnerDids` (r:2 w:2)
+	fn transfer_ownership() -> Weight {
+		Weight::from_parts(44_910_000, 8958)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: `DidRegistry::Registrations` (r:1 w:1)
+	fn set_expiration() -> Weight {
+		Weight::from_parts(22_810_000, 3787)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `DidRegistry::Registrations` (r:1 w:0)
+	/// Storage: `DidRegistry::AccessControlList` (r:0 w:1)
+	fn grant_access() -> Weight {
+		Weight::from_parts(21_460_000, 3787)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `DidRegistry::Registrations` (r:1 w:0)
+	/// Storage: `DidRegistry::AccessControlList` (r:0 w:1)
+	fn revoke_access() -> Weight {
+		Weight::from_parts(20_980_000, 3787)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `DidRegistry::Registrations` (r:1 w:0)
+	/// Storage: `DidRegistry::StatusLists` (r:1 w:1)
+	/// The range of component `b` is `[1, 16384]`.
+	fn set_credential_status(b: u32, ) -> Weight {
+		Weight::from_parts(24_530_000, 3787)
+			.saturating_add(Weight::from_parts(9_100, 0).saturating_mul(b.into()))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(Weight::from_parts(0, 1).saturating_mul(b.into()))
+	}
+	/// Storage: `DidRegistry::Registrations` (r:1 w:0)
+	/// Storage: `DidRegistry::VersionHistory` (r:1 w:1)
+	fn prune_history() -> Weight {
+		Weight::from_parts(27_640_000, 4756)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+}
+
+// For backwards compatibility and tests.
+impl WeightInfo for () {
+	/// Storage: `DidRegistry::Registrations` (r:1 w:1)
+	/// Storage: `DidRegistry::VersionHistory` (r:1 w:1)
+	/// Storage: `DidRegistry::OwnerDids` (r:1 w:1)
+	/// Storage: `DidRegistry::TotalDids` (r:1 w:1)
+	/// Storage: `DidRegistry::Nonce` (r:1 w:1)
+	fn register_did() -> Weight {
+		Weight::from_parts(46_820_000, 5681)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
+	/// Storage: `DidRegistry::Registrations` (r:1 w:1)
+	/// Storage: `DidRegistry::VersionHistory` (r:1 w:1)
+	fn update_did() -> Weight {
+		Weight::from_parts(38_140_000, 4756)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `DidRegistry::Registrations` (r:1 w:1)
+	fn revoke_did() -> Weight {
+		Weight::from_parts(22_370_000, 3787)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `DidRegistry::Registrations` (r:1 w:1)
+	/// Storage: `DidRegistry::OwnerDids` (r:2 w:2)
+	fn transfer_ownership() -> Weight {
+		Weight::from_parts(44_910_000, 8958)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: `DidRegistry::Registrations` (r:1 w:1)
+	fn set_expiration() -> Weight {
+		Weight::from_parts(22_810_000, 3787)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `DidRegistry::Registrations` (r:1 w:0)
+	/// Storage: `DidRegistry::AccessControlList` (r:0 w:1)
+	fn grant_access() -> Weight {
+		Weight::from_parts(21_460_000, 3787)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `DidRegistry::Registrations` (r:1 w:0)
+	/// Storage: `DidRegistry::AccessControlList` (r:0 w:1)
+	fn revoke_access() -> Weight {
+		Weight::from_parts(20_980_000, 3787)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `DidRegistry::Registrations` (r:1 w:0)
+	/// Storage: `DidRegistry::StatusLists` (r:1 w:1)
+	/// The range of component `b` is `[1, 16384]`.
+	fn set_credential_status(b: u32, ) -> Weight {
+		Weight::from_parts(24_530_000, 3787)
+			.saturating_add(Weight::from_parts(9_100, 0).saturating_mul(b.into()))
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(Weight::from_parts(0, 1).saturating_mul(b.into()))
+	}
+	/// Storage: `DidRegistry::Registrations` (r:1 w:0)
+	/// Storage: `DidRegistry::VersionHistory` (r:1 w:1)
+	fn prune_history() -> Weight {
+		Weight::from_parts(27_640_000, 4756)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+}
@@ -0,0 +1,256 @@
+//! Reading `pallet-did-registry` state
+//!
+//! Storage keys and value layouts follow the primearc runtime: the registry is
+//! `DidRegistry` in `construct_runtime!`, accounts are 32 bytes and block
+//! numbers `u32`. State is read through a [`StateReader`] so the resolver can
+//! sit on a node RPC connection or on an in-memory test double.
+
+use std::collections::{BTreeMap, HashMap};
+use std::sync::Arc;
+use tokio::sync::RwLock;
+
+use codec::{Decode, Encode};
+use sp_crypto_hashing::{blake2_128, blake2_256, twox_128, twox_64};
+
+use crate::ResolutionError;
+
+/// `pallet_did_registry` name in `construct_runtime!`
+pub const REGISTRY_PALLET: &str = "DidRegistry";
+
+/// Block number type of the runtime
+pub type BlockNumber = u32;
+
+/// `DidRegistration` with the runtime's account and block number types
+#[derive(Debug, Clone, PartialEq, Eq, Encode, Decode)]
+pub struct Registration {
+    pub did: Vec<u8>,
+    pub owner: [u8; 32],
+    pub controller: [u8; 32],
+    /// Content hash of the DID document body
+    pub document_hash: Vec<u8>,
+    pub registered_at: BlockNumber,
+    /// Block of the last change (update, transfer, expiry or revocation)
+    pub updated_at: BlockNumber,
+    pub expires_at: Option<BlockNumber>,
+    pub revoked: bool,
+}
+
+impl Registration {
+    /// Whether the DID is revoked or expired at `block`
+    pub fn is_deactivated(&self, block: BlockNumber) -> bool {
+        self.revoked || self.expires_at.is_some_and(|expiry| expiry <= block)
+    }
+}
+
+/// `DocumentVersion` with the runtime's block number type
+#[derive(Debug, Clone, PartialEq, Eq, Encode, Decode)]
+pub struct DocumentVersion {
+    pub document_hash: Vec<u8>,
+    /// Block from which this version is in force (its version ID)
+    pub valid_from: BlockNumber,
+}
+
+/// Registry key of a DID (`DidRegistry::hash_did`)
+pub fn did_hash(did: &str) -> [u8; 32] {
+    blake2_256(did.as_bytes())
+}
+
+/// `twox_128(pallet) ++ twox_128(item)`
+pub fn storage_prefix(pallet: &str, item: &str) -> Vec<u8> {
+    [twox_128(pallet.as_bytes()), twox_128(item.as_bytes())].concat()
+}
+
+/// `Registrations` key of a DID
+pub fn registration_key(did: &str) -> Vec<u8> {
+    let hash = did_hash(did);
+    [storage_prefix(REGISTRY_PALLET, "Registrations"), blake2_128(&hash).to_vec(), hash.to_vec()].concat()
+}
+
+/// `VersionHistory` key of a DID
+pub fn version_history_key(did: &str) -> Vec<u8> {
+    let hash = did_hash(did);
+    [storage_prefix(REGISTRY_PALLET, "VersionHistory"), blake2_128(&hash).to_vec(), hash.to_vec()].concat()
+}
+
+/// `StatusLists` key of an issuer's credential status list
+pub fn status_list_key(did: &str, list_id: u32) -> Vec<u8> {
+    let hash = did_hash(did);
+    let id = list_id.encode();
+    [
+        storage_prefix(REGISTRY_PALLET, "StatusLists"),
+        blake2_128(&hash).to_vec(),
+        hash.to_vec(),
+        twox_64(&id).to_vec(),
+        id,
+    ]
+    .concat()
+}
+
+/// `pallet_timestamp::Now` key
+pub fn timestamp_key() -> Vec<u8> {
+    storage_prefix("Timestamp", "Now")
+}
+
+/// Decode a SCALE storage value
+pub fn decode<D: Decode>(mut raw: &[u8]) -> Result<D, ResolutionError> {
+    D::decode(&mut raw).map_err(|_| ResolutionError::ParsingError)
+}
+
+/// Source of chain state
+#[async_trait::async_trait]
+pub trait StateReader: Send + Sync {
+    /// Number of the best block
+    async fn best_block(&self) -> Result<BlockNumber, ResolutionError>;
+
+    /// Raw storage value at `key` as of block `at`
+    async fn storage(&self, key: &[u8], at: BlockNumber) -> Result<Option<Vec<u8>>, ResolutionError>;
+}
+
+/// Storage of every block that wrote to state
+type Snapshots = BTreeMap<BlockNumber, HashMap<Vec<u8>, Vec<u8>>>;
+
+/// In-memory chain state, one snapshot per block (test double)
+///
+/// Reads at a block see the latest snapshot at or before it.
+#[derive(Default, Clone)]
+pub struct InMemoryState {
+    blocks: Arc<RwLock<Snapshots>>,
+}
+
+impl InMemoryState {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Write `value` at `key` from block `at` on
+    pub async fn set(&self, at: BlockNumber, key: Vec<u8>, value: Vec<u8>) {
+        let mut blocks = self.blocks.write().await;
+        let snapshot = blocks
+            .range(..=at)
+            .next_back()
+            .map(|(_, state)| state.clone())
+            .unwrap_or_default();
+        let state = blocks.entry(at).or_insert(snapshot);
+        state.insert(key.clone(), value.clone());
+        // Later snapshots inherit the write
+        for (_, later) in blocks.range_mut(at + 1..) {
+            later.insert(key.clone(), value.clone());
+        }
+    }
+
+    /// Store a registration (and the block's timestamp) at block `at`
+    pub async fn set_registration(&self, at: BlockNumber, registration: &Registration, now_secs: u64) {
+        let did = String::from_utf8_lossy(&registration.did).into_owned();
+        self.set(at, registration_key(&did), registration.encode()).await;
+        self.set(at, timestamp_key(), (now_secs * 1000).encode()).await;
+    }
+
+    /// Store a DID's version history at block `at`
+    pub async fn set_versions(&self, at: BlockNumber, did: &str, versions: &[DocumentVersion]) {
+        self.set(at, version_history_key(did), versions.encode()).await;
+    }
+}
+
+#[async_trait::async_trait]
+impl StateReader for InMemoryState {
+    async fn best_block(&self) -> Result<BlockNumber, ResolutionError> {
+        Ok(self.blocks.read().await.keys().next_back().copied().unwrap_or_default())
+    }
+
+    async fn storage(&self, key: &[u8], at: BlockNumber) -> Result<Option<Vec<u8>>, ResolutionError> {
+        Ok(self
+            .blocks
+            .read()
+            .await
+            .range(..=at)
+            .next_back()
+            .and_then(|(_, state)| state.get(key).cloned()))
+    }
+}
+
+/// Node RPC state reader (`chain_getHeader`, `chain_getBlockHash`, `state_getStorage`)
+///
+/// Historical reads need an archive node.
+#[cfg(feature = "rpc")]
+pub struct RpcStateReader {
+    client: jsonrpsee::ws_client::WsClient,
+}
+
+#[cfg(feature = "rpc")]
+impl RpcStateReader {
+    pub async fn connect(url: &str) -> Result<Self, ResolutionError> {
+        let client = jsonrpsee::ws_client::WsClientBuilder::default()
+            .build(url)
+            .await
+            .map_err(|_| ResolutionError::NetworkError)?;
+        Ok(Self { client })
+    }
+}
+
+#[cfg(feature = "rpc")]
+#[async_trait::async_trait]
+impl StateReader for RpcStateReader {
+    async fn best_block(&self) -> Result<BlockNumber, ResolutionError> {
+        use jsonrpsee::{core::client::ClientT, rpc_params};
+
+        let header: serde_json::Value = self
+            .client
+            .request("chain_getHeader", rpc_params![])
+            .await
+            .map_err(|_| ResolutionError::NetworkError)?;
+        header["number"]
+            .as_str()
+            .and_then(|n| BlockNumber::from_str_radix(n.trim_start_matches("0x"), 16).ok())
+            .ok_or(ResolutionError::ParsingError)
+    }
+
+    async fn storage(&self, key: &[u8], at: BlockNumber) -> Result<Option<Vec<u8>>, ResolutionError> {
+        use jsonrpsee::{core::client::ClientT, rpc_params};
+
+        let hash: Option<String> = self
+            .client
+            .request("chain_getBlockHash", rpc_params![at])
+            .await
+            .map_err(|_| ResolutionError::NetworkError)?;
+        let hash = hash.ok_or(ResolutionError::NotFound)?;
+        let value: Option<String> = self
+            .client
+            .request("state_getStorage", rpc_params![format!("0x{}", hex::encode(key)), hash])
+            .await
+            .map_err(|_| ResolutionError::NetworkError)?;
+        value
+            .map(|v| hex::decode(v.trim_start_matches("0x")).map_err(|_| ResolutionError::ParsingError))
+            .transpose()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_registration_key_layout() {
+        let key = registration_key("did:etrid:user1");
+        let hash = did_hash("did:etrid:user1");
+        assert_eq!(key.len(), 32 + 16 + 32);
+        assert_eq!(&key[..16], &twox_128(b"DidRegistry"));
+        assert_eq!(&key[32..48], &blake2_128(&hash));
+        assert_eq!(&key[48..], &hash);
+
+        let status = status_list_key("did:etrid:user1", 7);
+        assert_eq!(status.len(), 32 + 16 + 32 + 8 + 4);
+        assert_eq!(&status[status.len() - 4..], &7u32.to_le_bytes());
+    }
+
+    #[tokio::test]
+    async fn test_in_memory_state_history() {
+        let state = InMemoryState::new();
+        state.set(5, b"k".to_vec(), b"v1".to_vec()).await;
+        state.set(10, b"k".to_vec(), b"v2".to_vec()).await;
+
+        assert_eq!(state.best_block().await.unwrap(), 10);
+        assert_eq!(state.storage(b"k", 4).await.unwrap(), None);
+        assert_eq!(state.storage(b"k", 7).await.unwrap(), Some(b"v1".to_vec()));
+        assert_eq!(state.storage(b"k", 10).await.unwrap(), Some(b"v2".to_vec()));
+    }
+}
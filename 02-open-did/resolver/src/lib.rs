@@ -1,12 +1,26 @@
 //! DID Resolver
 //!
 //! Resolves DIDs to documents with caching, fallback mechanisms, and error handling.
+//!
+//! `OnChainResolver` reads registrations from `pallet-did-registry` through a
+//! [`chain::StateReader`], fetches the document body from a
+//! [`store::ContentStore`] keyed by the anchored hash, verifies the hash, and
+//! reports W3C DID document metadata (created, updated, deactivated,
+//! versionId, nextUpdate). Earlier document versions kept in the registry's
+//! version history resolve through `?versionId=<block>` and
+//! `?versionTime=<RFC 3339>` DID URLs.
+
+pub mod chain;
+pub mod store;
 
 use std::collections::HashMap;
 use std::sync::Arc;
 use tokio::sync::RwLock;
 use serde::{Deserialize, Serialize};
 
+use chain::{BlockNumber, DocumentVersion, Registration, StateReader};
+use store::ContentStore;
+
 /// Resolution result
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ResolutionResult {
@@ -15,6 +29,8 @@ pub struct ResolutionResult {
     pub resolved_at: u64,
     pub content_type: String,
     pub metadata: ResolutionMetadata,
+    #[serde(default)]
+    pub document_metadata: DocumentMetadata,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -24,6 +40,36 @@ pub struct ResolutionMetadata {
     pub duration_ms: u64,
 }
 
+/// W3C DID document metadata, plus the registry record behind it
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DocumentMetadata {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created: Option<String>,
+    /// Absent until the registration first changes
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub updated: Option<String>,
+    /// Revoked or expired at the resolved block
+    #[serde(default)]
+    pub deactivated: bool,
+    /// Block of the registration change that produced this version
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub version_id: Option<String>,
+    /// Set when a later version exists
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub next_update: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub next_version_id: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub owner: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub controller: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub expires_at: Option<BlockNumber>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub document_hash: Option<String>,
+}
+
 /// Resolution error
 #[derive(Debug, Clone)]
 pub enum ResolutionError {
@@ -34,6 +80,12 @@ pub enum ResolutionError {
     NetworkError,
     Timeout,
     ParsingError,
+    /// Document body missing from the content store
+    DocumentUnavailable,
+    /// Document body does not match the anchored hash
+    HashMismatch,
+    /// Anchored hash is not a supported content hash
+    UnsupportedHash,
 }
 
 impl std::fmt::Display for ResolutionError {
@@ -46,7 +98,44 @@ impl std::fmt::Display for ResolutionError {
             ResolutionError::NetworkError => write!(f, "Network error"),
             ResolutionError::Timeout => write!(f, "Resolution timeout"),
             ResolutionError::ParsingError => write!(f, "Document parsing error"),
+            ResolutionError::DocumentUnavailable => write!(f, "DID document not in content store"),
+            ResolutionError::HashMismatch => write!(f, "DID document does not match anchored hash"),
+            ResolutionError::UnsupportedHash => write!(f, "Unsupported document hash format"),
+        }
+    }
+}
+
+/// Document version selector (`?versionId=` / `?versionTime=` DID URL parameters)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VersionQuery {
+    /// Version in force at a block, with registration state as of that block
+    AtBlock(BlockNumber),
+    /// Version created at this block (`versionId`)
+    Id(BlockNumber),
+    /// Version in force at a UNIX time in seconds (`versionTime`)
+    Time(u64),
+}
+
+impl VersionQuery {
+    /// Split a DID URL into its DID and version query
+    pub fn parse(did_url: &str) -> Result<(&str, Option<Self>), ResolutionError> {
+        let Some((did, query)) = did_url.split_once('?') else {
+            return Ok((did_url, None));
+        };
+        let mut version = None;
+        for param in query.split('&') {
+            let parsed = match param.split_once('=') {
+                Some(("versionId", id)) => id.parse().ok().map(VersionQuery::Id),
+                Some(("versionTime", time)) => etrid_did_types::parse_timestamp(time).map(VersionQuery::Time),
+                _ => None,
+            };
+            // One well-formed version parameter at most
+            if parsed.is_none() || version.is_some() {
+                return Err(ResolutionError::InvalidDid);
+            }
+            version = parsed;
         }
+        Ok((did, version))
     }
 }
 
@@ -73,6 +162,8 @@ pub trait DidResolver: Send + Sync {
 
 /// On-chain resolver
 pub struct OnChainResolver {
+    state: Arc<dyn StateReader>,
+    store: Arc<dyn ContentStore>,
     cache: Arc<RwLock<HashMap<String, CacheEntry>>>,
     cache_ttl_secs: u64,
     cache_hits: Arc<RwLock<u64>>,
@@ -80,8 +171,10 @@ pub struct OnChainResolver {
 }
 
 impl OnChainResolver {
-    pub fn new(cache_ttl_secs: u64) -> Self {
+    pub fn new(state: Arc<dyn StateReader>, store: Arc<dyn ContentStore>, cache_ttl_secs: u64) -> Self {
         Self {
+            state,
+            store,
             cache: Arc::new(RwLock::new(HashMap::new())),
             cache_ttl_secs,
             cache_hits: Arc::new(RwLock::new(0)),
@@ -128,29 +221,109 @@ impl OnChainResolver {
         );
     }
 
-    /// Simulate on-chain lookup (in real implementation, would query blockchain)
-    async fn lookup_on_chain(&self, did: &str) -> Result<ResolutionResult, ResolutionError> {
+    /// Resolve `did` as of block `at` (not cached)
+    pub async fn resolve_at(&self, did: &str, at: BlockNumber) -> Result<ResolutionResult, ResolutionError> {
+        self.resolve_version(did, VersionQuery::AtBlock(at)).await
+    }
+
+    /// Resolve a specific document version of `did` (not cached)
+    pub async fn resolve_version(
+        &self,
+        did: &str,
+        query: VersionQuery,
+    ) -> Result<ResolutionResult, ResolutionError> {
+        let best = self.state.best_block().await?;
+        self.lookup_on_chain(did, query, best).await
+    }
+
+    /// Credential status list anchored by `did` at the best block (empty if never set)
+    pub async fn status_list(&self, did: &str, list_id: u32) -> Result<Vec<u8>, ResolutionError> {
         self.validate_did(did)?;
+        let best = self.state.best_block().await?;
+        self.state
+            .storage(&chain::status_list_key(did, list_id), best)
+            .await?
+            .map(|raw| chain::decode(&raw))
+            .transpose()
+            .map(Option::unwrap_or_default)
+    }
 
-        // Simulate on-chain resolution
-        // In production, this would query the blockchain state
-        let key_id = "#key1";
-        let document = format!(
-            r#"{{
-  "@context": "https://www.w3.org/ns/did/v1",
-  "id": "{}",
-  "verificationMethod": [
-    {{
-      "id": "{}",
-      "type": "Ed25519VerificationKey2020",
-      "controller": "{}",
-      "publicKeyBase58": ""
-    }}
-  ],
-  "authentication": ["{}"]
-}}"#,
-            did, key_id, did, key_id
-        );
+    /// Read the registration, select the document version, fetch and verify
+    /// the document, and build its metadata
+    async fn lookup_on_chain(
+        &self,
+        did: &str,
+        query: VersionQuery,
+        best: BlockNumber,
+    ) -> Result<ResolutionResult, ResolutionError> {
+        let started = std::time::Instant::now();
+        self.validate_did(did)?;
+
+        // Registration state as of the queried block; version queries see the latest state
+        let state_at = match query {
+            VersionQuery::AtBlock(block) => block,
+            VersionQuery::Id(_) | VersionQuery::Time(_) => best,
+        };
+        if state_at > best {
+            return Err(ResolutionError::NotFound);
+        }
+        let registration = self.registration(did, state_at).await?.ok_or(ResolutionError::NotFound)?;
+
+        let versions = self.versions(did, &registration, best).await?;
+        let index = match query {
+            VersionQuery::AtBlock(block) => versions.iter().rposition(|v| v.valid_from <= block),
+            VersionQuery::Id(id) => versions.iter().position(|v| v.valid_from == id),
+            VersionQuery::Time(time) => {
+                let mut found = None;
+                for (i, version) in versions.iter().enumerate().rev() {
+                    if self.block_secs(version.valid_from).await?.is_some_and(|t| t <= time) {
+                        found = Some(i);
+                        break;
+                    }
+                }
+                found
+            }
+        };
+        // Unknown, pruned, or not yet created
+        let index = index.ok_or(ResolutionError::NotFound)?;
+        let version = &versions[index];
+        let next = versions.get(index + 1);
+
+        let body = self
+            .store
+            .get(&version.document_hash)
+            .await?
+            .ok_or(ResolutionError::DocumentUnavailable)?;
+        store::verify_content_hash(&version.document_hash, &body)?;
+
+        let document = String::from_utf8(body).map_err(|_| ResolutionError::ParsingError)?;
+        let parsed: serde_json::Value =
+            serde_json::from_str(&document).map_err(|_| ResolutionError::ParsingError)?;
+        if parsed.get("id").and_then(serde_json::Value::as_str) != Some(did) {
+            return Err(ResolutionError::ParsingError);
+        }
+
+        let updated = if version.valid_from > registration.registered_at {
+            self.block_time(version.valid_from).await?
+        } else {
+            None
+        };
+        let next_update = match next {
+            Some(next) => self.block_time(next.valid_from).await?,
+            None => None,
+        };
+        let document_metadata = DocumentMetadata {
+            created: self.block_time(registration.registered_at).await?,
+            updated,
+            deactivated: registration.is_deactivated(state_at),
+            version_id: Some(version.valid_from.to_string()),
+            next_update,
+            next_version_id: next.map(|next| next.valid_from.to_string()),
+            owner: Some(format!("0x{}", hex::encode(registration.owner))),
+            controller: Some(format!("0x{}", hex::encode(registration.controller))),
+            expires_at: registration.expires_at,
+            document_hash: Some(format!("0x{}", hex::encode(&version.document_hash))),
+        };
 
         Ok(ResolutionResult {
             did: did.to_string(),
@@ -160,11 +333,57 @@ impl OnChainResolver {
             metadata: ResolutionMetadata {
                 content_type: "application/did+json".to_string(),
                 retrieved_time: timestamp_secs(),
-                duration_ms: 100,
+                duration_ms: started.elapsed().as_millis() as u64,
             },
+            document_metadata,
         })
     }
 
+    async fn registration(&self, did: &str, at: BlockNumber) -> Result<Option<Registration>, ResolutionError> {
+        self.state
+            .storage(&chain::registration_key(did), at)
+            .await?
+            .map(|raw| chain::decode(&raw))
+            .transpose()
+    }
+
+    /// Retained document versions, oldest first
+    ///
+    /// Registrations made before the registry kept history only expose their
+    /// current document, in force from the last registration change.
+    async fn versions(
+        &self,
+        did: &str,
+        registration: &Registration,
+        best: BlockNumber,
+    ) -> Result<Vec<DocumentVersion>, ResolutionError> {
+        let versions: Vec<DocumentVersion> = self
+            .state
+            .storage(&chain::version_history_key(did), best)
+            .await?
+            .map(|raw| chain::decode(&raw))
+            .transpose()?
+            .unwrap_or_default();
+        if !versions.is_empty() {
+            return Ok(versions);
+        }
+        Ok(vec![DocumentVersion {
+            document_hash: registration.document_hash.clone(),
+            valid_from: registration.updated_at,
+        }])
+    }
+
+    /// `pallet_timestamp::Now` of a block in UNIX seconds
+    async fn block_secs(&self, block: BlockNumber) -> Result<Option<u64>, ResolutionError> {
+        let now_ms = self.state.storage(&chain::timestamp_key(), block).await?;
+        Ok(now_ms.map(|raw| chain::decode::<u64>(&raw)).transpose()?.map(|ms| ms / 1000))
+    }
+
+    /// `pallet_timestamp::Now` of a block as an XML Schema timestamp
+    async fn block_time(&self, block: BlockNumber) -> Result<Option<String>, ResolutionError> {
+        Ok(self.block_secs(block).await?.map(etrid_did_types::format_timestamp))
+    }
+
     /// Clear cache
     pub async fn clear_cache(&self) {
         self.cache.write().await.clear();
@@ -202,13 +421,19 @@ impl OnChainResolver {
 #[async_trait::async_trait]
 impl DidResolver for OnChainResolver {
     async fn resolve(&self, did: &str) -> Result<ResolutionResult, ResolutionError> {
+        // Versioned queries bypass the cache
+        if let (did, Some(query)) = VersionQuery::parse(did)? {
+            return self.resolve_version(did, query).await;
+        }
+
         // Try cache first
         if let Ok(result) = self.resolve_from_cache(did).await {
             return Ok(result);
         }
 
-        // Try on-chain lookup
-        match self.lookup_on_chain(did).await {
+        // Try on-chain lookup at the best block
+        let best = self.state.best_block().await?;
+        match self.lookup_on_chain(did, VersionQuery::AtBlock(best), best).await {
             Ok(result) => {
                 self.store_in_cache(did, result.clone()).await;
                 Ok(result)
@@ -266,17 +491,74 @@ fn timestamp_secs() -> u64 {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use chain::InMemoryState;
+    use codec::Encode;
+    use store::InMemoryContentStore;
+
+    const USER1: &str = "did:etrid:user1";
+
+    fn document(did: &str, key: u8) -> Vec<u8> {
+        let mut doc = etrid_did_types::DidDocument::new(etrid_did_types::Did::from_string(did).unwrap());
+        doc.add_verification_method(etrid_did_types::VerificationMethod::new(
+            "key1".to_string(),
+            did.to_string(),
+            "Ed25519VerificationKey2020".to_string(),
+            vec![key; 32],
+        ))
+        .unwrap();
+        doc.set_authentication(vec!["#key1".to_string()]);
+        doc.to_w3c_json().to_string().into_bytes()
+    }
+
+    fn registration(document_hash: Vec<u8>, block: BlockNumber) -> Registration {
+        Registration {
+            did: USER1.as_bytes().to_vec(),
+            owner: [1; 32],
+            controller: [2; 32],
+            document_hash,
+            registered_at: 1,
+            updated_at: block,
+            expires_at: None,
+            revoked: false,
+        }
+    }
+
+    fn version(document_hash: &[u8], valid_from: BlockNumber) -> DocumentVersion {
+        DocumentVersion {
+            document_hash: document_hash.to_vec(),
+            valid_from,
+        }
+    }
+
+    /// `did:etrid:user1` registered at block 1
+    async fn setup() -> (InMemoryState, InMemoryContentStore, Registration) {
+        let state = InMemoryState::new();
+        let store = InMemoryContentStore::new();
+        let hash = store.put(document(USER1, 1)).await;
+        let registration = registration(hash, 1);
+        state.set_registration(1, &registration, 1_700_000_000).await;
+        (state, store, registration)
+    }
+
+    async fn resolver(cache_ttl_secs: u64) -> OnChainResolver {
+        let (state, store, _) = setup().await;
+        OnChainResolver::new(Arc::new(state), Arc::new(store), cache_ttl_secs)
+    }
 
     #[test]
     fn test_validate_did() {
-        let resolver = OnChainResolver::new(3600);
+        let resolver = OnChainResolver::new(
+            Arc::new(InMemoryState::new()),
+            Arc::new(InMemoryContentStore::new()),
+            3600,
+        );
         assert!(resolver.validate_did("did:etrid:user1").is_ok());
         assert!(resolver.validate_did("invalid").is_err());
     }
 
     #[tokio::test]
     async fn test_resolve_and_cache() {
-        let resolver = OnChainResolver::new(3600);
+        let resolver = resolver(3600).await;
         
         let result1 = resolver.resolve("did:etrid:user1").await;
         assert!(result1.is_ok());
@@ -290,7 +572,7 @@ mod tests {
 
     #[tokio::test]
     async fn test_cache_expiration() {
-        let resolver = OnChainResolver::new(1); // 1 second TTL
+        let resolver = resolver(1).await; // 1 second TTL
         
         resolver.resolve("did:etrid:user1").await.unwrap();
         
@@ -308,14 +590,14 @@ mod tests {
 
     #[tokio::test]
     async fn test_invalid_did_resolution() {
-        let resolver = OnChainResolver::new(3600);
+        let resolver = resolver(3600).await;
         let result = resolver.resolve("invalid").await;
         assert!(result.is_err());
     }
 
     #[tokio::test]
     async fn test_clear_cache() {
-        let resolver = OnChainResolver::new(3600);
+        let resolver = resolver(3600).await;
         resolver.resolve("did:etrid:user1").await.unwrap();
         
         let cached_before = resolver.get_cached_dids().await;
@@ -328,7 +610,7 @@ mod tests {
 
     #[tokio::test]
     async fn test_multi_resolver() {
-        let resolver1 = Arc::new(OnChainResolver::new(3600));
+        let resolver1 = Arc::new(resolver(3600).await);
         let mut multi = MultiResolver::new(5000);
         multi.add_resolver(resolver1);
 
@@ -338,7 +620,7 @@ mod tests {
 
     #[tokio::test]
     async fn test_resolution_result_metadata() {
-        let resolver = OnChainResolver::new(3600);
+        let resolver = resolver(3600).await;
         let result = resolver.resolve("did:etrid:user1").await.unwrap();
         
         assert!(!result.document.is_empty());
@@ -347,11 +629,146 @@ mod tests {
 
     #[tokio::test]
     async fn test_cleanup_cache() {
-        let resolver = OnChainResolver::new(1); // 1 second TTL
+        let resolver = resolver(1).await; // 1 second TTL
         resolver.resolve("did:etrid:user1").await.unwrap();
 
         tokio::time::sleep(tokio::time::Duration::from_secs(2)).await;
         let cleaned = resolver.cleanup_cache().await;
         assert_eq!(cleaned, 1);
     }
+
+    #[tokio::test]
+    async fn test_resolve_reads_registry_and_store() {
+        let resolver = resolver(3600).await;
+        let result = resolver.resolve(USER1).await.unwrap();
+
+        let document: serde_json::Value = serde_json::from_str(&result.document).unwrap();
+        assert_eq!(document["verificationMethod"][0]["id"], "did:etrid:user1#key1");
+
+        let metadata = result.document_metadata;
+        assert_eq!(metadata.created.as_deref(), Some("2023-11-14T22:13:20Z"));
+        assert_eq!(metadata.updated, None);
+        assert!(!metadata.deactivated);
+        assert_eq!(metadata.version_id.as_deref(), Some("1"));
+        assert_eq!(metadata.next_update, None);
+        assert_eq!(metadata.owner, Some(format!("0x{}", "01".repeat(32))));
+
+        assert!(matches!(
+            resolver.resolve("did:etrid:unknown").await,
+            Err(ResolutionError::NotFound)
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_document_must_match_anchored_hash() {
+        let (state, store, registration) = setup().await;
+        store.put_raw(registration.document_hash.clone(), document(USER1, 9)).await;
+        let resolver = OnChainResolver::new(Arc::new(state.clone()), Arc::new(store), 3600);
+        assert!(matches!(resolver.resolve(USER1).await, Err(ResolutionError::HashMismatch)));
+
+        let resolver = OnChainResolver::new(Arc::new(state), Arc::new(InMemoryContentStore::new()), 3600);
+        assert!(matches!(
+            resolver.resolve(USER1).await,
+            Err(ResolutionError::DocumentUnavailable)
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_historical_versions_and_deactivation() {
+        let (state, store, first) = setup().await;
+
+        // Updated at block 5, revoked at block 9
+        let second = registration(store.put(document(USER1, 2)).await, 5);
+        state.set_registration(5, &second, 1_700_000_500).await;
+        state
+            .set_versions(5, USER1, &[version(&first.document_hash, 1), version(&second.document_hash, 5)])
+            .await;
+        let revoked = Registration { updated_at: 9, revoked: true, ..second.clone() };
+        state.set_registration(9, &revoked, 1_700_000_900).await;
+        let resolver = OnChainResolver::new(Arc::new(state), Arc::new(store), 3600);
+
+        let v1 = resolver.resolve_at(USER1, 3).await.unwrap();
+        assert_eq!(v1.document_metadata.version_id.as_deref(), Some("1"));
+        assert_eq!(v1.document_metadata.next_version_id.as_deref(), Some("5"));
+        assert_eq!(v1.document_metadata.next_update.as_deref(), Some("2023-11-14T22:21:40Z"));
+        assert_eq!(
+            v1.document_metadata.document_hash,
+            Some(format!("0x{}", hex::encode(&first.document_hash)))
+        );
+
+        let v2 = resolver.resolve_at(USER1, 6).await.unwrap();
+        assert_eq!(v2.document_metadata.version_id.as_deref(), Some("5"));
+        assert_eq!(v2.document_metadata.next_version_id, None);
+        assert_eq!(v2.document_metadata.updated.as_deref(), Some("2023-11-14T22:21:40Z"));
+        assert!(!v2.document_metadata.deactivated);
+
+        // Revocation deactivates the DID without adding a document version
+        let latest = resolver.resolve(USER1).await.unwrap();
+        assert!(latest.document_metadata.deactivated);
+        assert_eq!(latest.document_metadata.version_id.as_deref(), Some("5"));
+        assert_eq!(latest.document_metadata.next_update, None);
+
+        assert!(resolver.resolve_at(USER1, 100).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_version_queries() {
+        let (state, store, first) = setup().await;
+        let second = registration(store.put(document(USER1, 2)).await, 5);
+        state.set_registration(5, &second, 1_700_000_500).await;
+        state
+            .set_versions(5, USER1, &[version(&first.document_hash, 1), version(&second.document_hash, 5)])
+            .await;
+        let resolver = OnChainResolver::new(Arc::new(state.clone()), Arc::new(store), 3600);
+
+        let by_id = resolver.resolve("did:etrid:user1?versionId=1").await.unwrap();
+        assert_eq!(by_id.did, USER1);
+        assert_eq!(by_id.document, String::from_utf8(document(USER1, 1)).unwrap());
+        assert_eq!(by_id.document_metadata.next_version_id.as_deref(), Some("5"));
+        assert!(matches!(
+            resolver.resolve("did:etrid:user1?versionId=3").await,
+            Err(ResolutionError::NotFound)
+        ));
+
+        // Version 5 is in force from 22:21:40
+        let by_time = resolver.resolve("did:etrid:user1?versionTime=2023-11-14T22:20:00Z").await.unwrap();
+        assert_eq!(by_time.document_metadata.version_id.as_deref(), Some("1"));
+        let by_time = resolver.resolve("did:etrid:user1?versionTime=2023-11-14T22:21:40Z").await.unwrap();
+        assert_eq!(by_time.document_metadata.version_id.as_deref(), Some("5"));
+        assert!(matches!(
+            resolver.resolve("did:etrid:user1?versionTime=2023-11-14T22:00:00Z").await,
+            Err(ResolutionError::NotFound)
+        ));
+
+        // Versioned results never enter the cache
+        assert!(resolver.get_cached_dids().await.is_empty());
+
+        for url in [
+            "did:etrid:user1?versionId=x",
+            "did:etrid:user1?versionTime=yesterday",
+            "did:etrid:user1?service=files",
+            "did:etrid:user1?versionId=1&versionId=5",
+        ] {
+            assert!(matches!(resolver.resolve(url).await, Err(ResolutionError::InvalidDid)), "{}", url);
+        }
+
+        // Pruned versions no longer resolve
+        state.set_versions(7, USER1, &[version(&second.document_hash, 5)]).await;
+        assert!(matches!(
+            resolver.resolve_version(USER1, VersionQuery::Id(1)).await,
+            Err(ResolutionError::NotFound)
+        ));
+        assert!(matches!(resolver.resolve_at(USER1, 3).await, Err(ResolutionError::NotFound)));
+        assert!(resolver.resolve_version(USER1, VersionQuery::Id(5)).await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_status_list() {
+        let (state, store, _) = setup().await;
+        state.set(2, chain::status_list_key(USER1, 0), vec![0x00u8, 0x40].encode()).await;
+        let resolver = OnChainResolver::new(Arc::new(state), Arc::new(store), 3600);
+
+        assert_eq!(resolver.status_list(USER1, 0).await.unwrap(), vec![0x00, 0x40]);
+        assert!(resolver.status_list(USER1, 1).await.unwrap().is_empty());
+    }
 }
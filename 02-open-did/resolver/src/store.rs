@@ -0,0 +1,93 @@
+//! Content-addressed DID document storage
+//!
+//! The registry only anchors a document hash; bodies live in a store keyed by
+//! that hash (IPFS, a gateway, or memory). Supported hash formats:
+//! - 32 bytes: Blake2-256 of the body
+//! - 34 bytes `0x12 0x20 ++ digest`: SHA-256 multihash (CIDv0 / IPFS)
+
+use std::collections::HashMap;
+use std::sync::Arc;
+use tokio::sync::RwLock;
+
+use sha2::{Digest, Sha256};
+use sp_crypto_hashing::blake2_256;
+
+use crate::ResolutionError;
+
+/// SHA-256 multihash prefix (code 0x12, length 32)
+const SHA2_256_MULTIHASH: [u8; 2] = [0x12, 0x20];
+
+/// Document store keyed by content hash
+#[async_trait::async_trait]
+pub trait ContentStore: Send + Sync {
+    /// Document body stored under `hash`, unverified
+    async fn get(&self, hash: &[u8]) -> Result<Option<Vec<u8>>, ResolutionError>;
+}
+
+/// Check that `body` matches its content hash
+pub fn verify_content_hash(hash: &[u8], body: &[u8]) -> Result<(), ResolutionError> {
+    let matches = match hash.len() {
+        32 => blake2_256(body)[..] == hash[..],
+        34 if hash[..2] == SHA2_256_MULTIHASH => Sha256::digest(body)[..] == hash[2..],
+        _ => return Err(ResolutionError::UnsupportedHash),
+    };
+    if matches {
+        Ok(())
+    } else {
+        Err(ResolutionError::HashMismatch)
+    }
+}
+
+/// In-memory content store (Blake2-256 addressed)
+#[derive(Default, Clone)]
+pub struct InMemoryContentStore {
+    bodies: Arc<RwLock<HashMap<Vec<u8>, Vec<u8>>>>,
+}
+
+impl InMemoryContentStore {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Store a body, returning the hash to anchor on-chain
+    pub async fn put(&self, body: Vec<u8>) -> Vec<u8> {
+        let hash = blake2_256(&body).to_vec();
+        self.bodies.write().await.insert(hash.clone(), body);
+        hash
+    }
+
+    /// Store a body under an arbitrary key (e.g. to simulate a corrupted store)
+    pub async fn put_raw(&self, hash: Vec<u8>, body: Vec<u8>) {
+        self.bodies.write().await.insert(hash, body);
+    }
+}
+
+#[async_trait::async_trait]
+impl ContentStore for InMemoryContentStore {
+    async fn get(&self, hash: &[u8]) -> Result<Option<Vec<u8>>, ResolutionError> {
+        Ok(self.bodies.read().await.get(hash).cloned())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_verify_content_hash() {
+        let body = b"{\"id\":\"did:etrid:user1\"}";
+        assert!(verify_content_hash(&blake2_256(body), body).is_ok());
+
+        let multihash = [&SHA2_256_MULTIHASH[..], &Sha256::digest(body)[..]].concat();
+        assert!(verify_content_hash(&multihash, body).is_ok());
+
+        assert!(matches!(
+            verify_content_hash(&blake2_256(b"other"), body),
+            Err(ResolutionError::HashMismatch)
+        ));
+        assert!(matches!(
+            verify_content_hash(b"QmHash123", body),
+            Err(ResolutionError::UnsupportedHash)
+        ));
+    }
+}
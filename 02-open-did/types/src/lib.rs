@@ -213,6 +213,83 @@ impl DidDocument {
         self.authentication.contains(&method_id.to_string())
             && self.get_verification_method(method_id).is_some()
     }
+
+    /// Absolute verification method ID (`key1` and `#key1` become `did:etrid:x#key1`)
+    pub fn absolute_id(&self, id: &str) -> String {
+        if id.starts_with("did:") {
+            id.to_string()
+        } else {
+            format!("{}#{}", self.id.to_string(), id.trim_start_matches('#'))
+        }
+    }
+
+    /// Render as a W3C DID Core JSON document
+    ///
+    /// Ed25519 keys are published as `publicKeyMultibase`, X25519 keys as
+    /// `publicKeyBase58`; relationship entries reference methods by absolute ID.
+    pub fn to_w3c_json(&self) -> serde_json::Value {
+        let methods: Vec<serde_json::Value> = self
+            .verification_methods
+            .iter()
+            .map(|m| {
+                let mut method = serde_json::json!({
+                    "id": self.absolute_id(&m.id),
+                    "type": m.method_type,
+                    "controller": m.controller,
+                });
+                if m.method_type == "Ed25519VerificationKey2020" {
+                    method["publicKeyMultibase"] = ed25519_multibase(&m.public_key).into();
+                } else {
+                    method["publicKeyBase58"] = bs58::encode(&m.public_key).into_string().into();
+                }
+                method
+            })
+            .collect();
+        let refs = |ids: &[String]| -> Vec<String> { ids.iter().map(|id| self.absolute_id(id)).collect() };
+        let services: Vec<serde_json::Value> = self
+            .service_endpoints
+            .iter()
+            .map(|s| {
+                serde_json::json!({
+                    "id": self.absolute_id(&s.id),
+                    "type": s.service_type,
+                    "serviceEndpoint": s.endpoint_url,
+                })
+            })
+            .collect();
+
+        serde_json::json!({
+            "@context": self.context,
+            "id": self.id.to_string(),
+            "verificationMethod": methods,
+            "authentication": refs(&self.authentication),
+            "assertionMethod": refs(&self.assertion_method),
+            "keyAgreement": refs(&self.key_agreement),
+            "service": services,
+        })
+    }
+}
+
+/// Multicodec prefix of an Ed25519 public key
+const ED25519_MULTICODEC: [u8; 2] = [0xed, 0x01];
+
+/// Encode an Ed25519 public key as `publicKeyMultibase` (base58btc, `z` prefix)
+pub fn ed25519_multibase(public_key: &[u8]) -> String {
+    let mut bytes = ED25519_MULTICODEC.to_vec();
+    bytes.extend_from_slice(public_key);
+    format!("z{}", bs58::encode(bytes).into_string())
+}
+
+/// Decode a `publicKeyMultibase` Ed25519 key
+pub fn decode_ed25519_multibase(multibase: &str) -> Result<Vec<u8>, String> {
+    let encoded = multibase
+        .strip_prefix('z')
+        .ok_or_else(|| "Only base58btc multibase keys are supported".to_string())?;
+    let bytes = bs58::decode(encoded).into_vec().map_err(|e| e.to_string())?;
+    bytes
+        .strip_prefix(&ED25519_MULTICODEC[..])
+        .map(|key| key.to_vec())
+        .ok_or_else(|| "Not an Ed25519 multikey".to_string())
 }
 
 /// Proof of DID document
@@ -275,6 +352,82 @@ fn timestamp_secs() -> u64 {
         .as_secs()
 }
 
+/// Format UNIX seconds as an XML Schema `dateTimeStamp` (`2024-01-01T00:00:00Z`)
+pub fn format_timestamp(secs: u64) -> String {
+    let days = (secs / 86_400) as i64;
+    let rem = secs % 86_400;
+    let (year, month, day) = civil_from_days(days);
+    format!(
+        "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}Z",
+        year,
+        month,
+        day,
+        rem / 3600,
+        rem % 3600 / 60,
+        rem % 60
+    )
+}
+
+/// Parse an RFC 3339 timestamp (fractional seconds are truncated)
+pub fn parse_timestamp(s: &str) -> Option<u64> {
+    let (date, time) = s.split_once(['T', 't'])?;
+    let mut date_parts = date.splitn(3, '-');
+    let year: i64 = date_parts.next()?.parse().ok()?;
+    let month: u32 = date_parts.next()?.parse().ok()?;
+    let day: u32 = date_parts.next()?.parse().ok()?;
+    if !(1..=12).contains(&month) || !(1..=31).contains(&day) {
+        return None;
+    }
+
+    let (clock, offset_secs) = if let Some(clock) = time.strip_suffix(['Z', 'z']) {
+        (clock, 0i64)
+    } else {
+        let split = time.rfind(['+', '-'])?;
+        let (clock, offset) = time.split_at(split);
+        let (hours, minutes) = offset[1..].split_once(':')?;
+        let secs = hours.parse::<i64>().ok()? * 3600 + minutes.parse::<i64>().ok()? * 60;
+        (clock, if offset.starts_with('-') { -secs } else { secs })
+    };
+    let clock = clock.split('.').next()?;
+    let mut clock_parts = clock.splitn(3, ':');
+    let hour: i64 = clock_parts.next()?.parse().ok()?;
+    let minute: i64 = clock_parts.next()?.parse().ok()?;
+    let second: i64 = clock_parts.next()?.parse().ok()?;
+    if hour > 23 || minute > 59 || second > 60 {
+        return None;
+    }
+
+    let secs = days_from_civil(year, month, day) * 86_400 + hour * 3600 + minute * 60 + second
+        - offset_secs;
+    u64::try_from(secs).ok()
+}
+
+/// Days since 1970-01-01 of a proleptic Gregorian date
+fn days_from_civil(year: i64, month: u32, day: u32) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let month = month as i64;
+    let day_of_year = (153 * (if month > 2 { month - 3 } else { month + 9 }) + 2) / 5 + day as i64 - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146_097 + day_of_era - 719_468
+}
+
+/// Proleptic Gregorian date of days since 1970-01-01
+fn civil_from_days(days: i64) -> (i64, u32, u32) {
+    let days = days + 719_468;
+    let era = days.div_euclid(146_097);
+    let day_of_era = days - era * 146_097;
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let mp = (5 * day_of_year + 2) / 153;
+    let day = (day_of_year - (153 * mp + 2) / 5 + 1) as u32;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 } as u32;
+    let year = year_of_era + era * 400 + if month <= 2 { 1 } else { 0 };
+    (year, month, day)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -463,6 +616,39 @@ mod tests {
         assert_eq!(value, Some(&"Alice".to_string()));
     }
 
+    #[test]
+    fn test_timestamp_round_trip() {
+        assert_eq!(format_timestamp(0), "1970-01-01T00:00:00Z");
+        assert_eq!(format_timestamp(1_709_251_199), "2024-02-29T23:59:59Z");
+        assert_eq!(parse_timestamp("2024-02-29T23:59:59Z"), Some(1_709_251_199));
+        assert_eq!(parse_timestamp("2024-03-01T01:59:59.123+02:00"), Some(1_709_251_199));
+        assert_eq!(parse_timestamp("2024-02-29T18:59:59-05:00"), Some(1_709_251_199));
+        assert_eq!(parse_timestamp("2024-13-01T00:00:00Z"), None);
+        assert_eq!(parse_timestamp("yesterday"), None);
+    }
+
+    #[test]
+    fn test_did_document_w3c_json() {
+        let did = Did::new("user123".to_string());
+        let mut doc = DidDocument::new(did);
+        let method = VerificationMethod::new(
+            "key1".to_string(),
+            "did:etrid:user123".to_string(),
+            "Ed25519VerificationKey2020".to_string(),
+            vec![7; 32],
+        );
+        doc.add_verification_method(method).unwrap();
+        doc.set_assertion_method(vec!["#key1".to_string()]);
+
+        let json = doc.to_w3c_json();
+        assert_eq!(json["verificationMethod"][0]["id"], "did:etrid:user123#key1");
+        assert_eq!(json["assertionMethod"][0], "did:etrid:user123#key1");
+
+        let multibase = json["verificationMethod"][0]["publicKeyMultibase"].as_str().unwrap();
+        assert_eq!(decode_ed25519_multibase(multibase).unwrap(), vec![7; 32]);
+        assert!(decode_ed25519_multibase("uAAAA").is_err());
+    }
+
     #[test]
     fn test_did_is_valid_authentication_method() {
         let did = Did::new("user123".to_string());
@@ -1,13 +1,64 @@
 //! Benchmarking setup for pallet-accounts
 //!
 //! This module contains benchmarks for all extrinsics in pallet-accounts.
+//! Recovery benchmarks use the full `MAX_GUARDIANS` set with the caller in
+//! the last slot, so guardian lookups scan the whole list.
 
 #![cfg(feature = "runtime-benchmarks")]
 
 use super::*;
+use crate::pallet::MAX_GUARDIANS;
 use frame_benchmarking::v2::*;
-use frame_support::assert_ok;
-use frame_system::RawOrigin;
+use frame_support::{pallet_prelude::*, traits::EnsureOrigin};
+use frame_system::{pallet_prelude::BlockNumberFor, RawOrigin};
+use sp_std::vec::Vec;
+
+fn funded<T: Config>(who: &T::AccountId, amount: u64) {
+    Accounts::<T>::mutate(who, |acct| {
+        acct.etr_balance = amount.into();
+        acct.etd_balance = amount.into();
+    });
+}
+
+fn guardians<T: Config>(n: u32) -> Vec<T::AccountId> {
+    (0..n).map(|i| account("guardian", i, 0)).collect()
+}
+
+/// Recovery config for `lost` over `n` account guardians
+fn setup_config<T: Config>(lost: &T::AccountId, n: u32, threshold: u32) -> Vec<T::AccountId> {
+    let accounts = guardians::<T>(n);
+    let config = RecoveryConfig {
+        guardians: BoundedVec::try_from(
+            accounts.iter().cloned().map(Guardian::Account).collect::<Vec<_>>(),
+        )
+        .expect("n <= MAX_GUARDIANS"),
+        threshold,
+        delay_period: T::MinRecoveryDelay::get(),
+    };
+    RecoveryConfigs::<T>::insert(lost, config);
+    accounts
+}
+
+/// Active recovery for `lost` to `new_account`, approved by `approvers`
+fn setup_recovery<T: Config>(
+    lost: &T::AccountId,
+    new_account: &T::AccountId,
+    approvers: &[T::AccountId],
+    executable_at: Option<BlockNumberFor<T>>,
+) {
+    let initiator = approvers[0].clone();
+    ActiveRecoveries::<T>::insert(lost, ActiveRecovery {
+        new_account: new_account.clone(),
+        approvals: BoundedVec::try_from(
+            approvers.iter().cloned().map(Guardian::Account).collect::<Vec<_>>(),
+        )
+        .expect("approvers <= MAX_GUARDIANS"),
+        created_at: frame_system::Pallet::<T>::block_number(),
+        executable_at,
+        initiator,
+        deposit: T::RecoveryDeposit::get(),
+    });
+}
 
 #[benchmarks]
 mod benchmarks {
@@ -17,211 +68,137 @@ mod benchmarks {
     fn transfer() {
         let caller: T::AccountId = whitelisted_caller();
         let recipient: T::AccountId = account("recipient", 0, 0);
-        let amount: BalanceOf<T> = 1000u32.into();
-
-        // Setup: Give caller some balance
-        Accounts::<T>::insert(&caller, AccountInfo {
-            etr_balance: 10000u32.into(),
-            etd_balance: 0u32.into(),
-            nonce: 0,
-            recovery: None,
-        });
+        funded::<T>(&caller, 10_000);
 
         #[extrinsic_call]
-        transfer(RawOrigin::Signed(caller.clone()), recipient.clone(), amount);
+        _(RawOrigin::Signed(caller.clone()), recipient.clone(), TokenType::ETR, 1_000u64.into());
 
-        // Verify transfer occurred
-        assert!(Accounts::<T>::contains_key(&recipient));
+        assert_eq!(Accounts::<T>::get(&recipient).etr_balance, 1_000u64.into());
     }
 
     #[benchmark]
-    fn mint_etr() {
-        let caller: T::AccountId = whitelisted_caller();
+    fn mint_etr() -> Result<(), BenchmarkError> {
+        let origin = T::GovernanceOrigin::try_successful_origin()
+            .map_err(|_| BenchmarkError::Weightless)?;
         let recipient: T::AccountId = account("recipient", 0, 0);
-        let amount: BalanceOf<T> = 1000u32.into();
 
         #[extrinsic_call]
-        mint_etr(RawOrigin::Root, recipient.clone(), amount);
+        _(origin as T::RuntimeOrigin, recipient.clone(), 1_000u64.into());
 
-        // Verify minting occurred
-        let account_info = Accounts::<T>::get(&recipient).unwrap();
-        assert_eq!(account_info.etr_balance, amount);
+        assert_eq!(Accounts::<T>::get(&recipient).etr_balance, 1_000u64.into());
+        Ok(())
     }
 
     #[benchmark]
     fn mint_etd() {
         let caller: T::AccountId = whitelisted_caller();
         let recipient: T::AccountId = account("recipient", 0, 0);
-        let amount: BalanceOf<T> = 1000u32.into();
 
         #[extrinsic_call]
-        mint_etd(RawOrigin::Root, recipient.clone(), amount);
+        _(RawOrigin::Signed(caller), recipient.clone(), 1_000u64.into());
 
-        // Verify minting occurred
-        let account_info = Accounts::<T>::get(&recipient).unwrap();
-        assert_eq!(account_info.etd_balance, amount);
+        assert_eq!(Accounts::<T>::get(&recipient).etd_balance, 1_000u64.into());
     }
 
     #[benchmark]
     fn burn() {
         let caller: T::AccountId = whitelisted_caller();
-        let amount: BalanceOf<T> = 500u32.into();
-
-        // Setup: Give caller some ETR balance
-        Accounts::<T>::insert(&caller, AccountInfo {
-            etr_balance: 1000u32.into(),
-            etd_balance: 0u32.into(),
-            nonce: 0,
-            recovery: None,
-        });
+        funded::<T>(&caller, 1_000);
 
         #[extrinsic_call]
-        burn(RawOrigin::Signed(caller.clone()), amount);
+        _(RawOrigin::Signed(caller.clone()), TokenType::ETR, 500u64.into());
 
-        // Verify burn occurred
-        let account_info = Accounts::<T>::get(&caller).unwrap();
-        assert_eq!(account_info.etr_balance, 500u32.into());
+        assert_eq!(Accounts::<T>::get(&caller).etr_balance, 500u64.into());
     }
 
     #[benchmark]
-    fn create_recovery() {
+    fn create_recovery(g: Linear<1, MAX_GUARDIANS>) {
         let caller: T::AccountId = whitelisted_caller();
-        let friend1: T::AccountId = account("friend1", 0, 0);
-        let friend2: T::AccountId = account("friend2", 0, 0);
-        let friend3: T::AccountId = account("friend3", 0, 0);
-        let friends = vec![friend1, friend2, friend3];
-        let threshold = 2u32;
-
-        // Setup: Give caller an account
-        Accounts::<T>::insert(&caller, AccountInfo {
-            etr_balance: 1000u32.into(),
-            etd_balance: 0u32.into(),
-            nonce: 0,
-            recovery: None,
-        });
+        let guardians: Vec<Guardian<T::AccountId>> =
+            guardians::<T>(g).into_iter().map(Guardian::Account).collect();
 
         #[extrinsic_call]
-        create_recovery(RawOrigin::Signed(caller.clone()), friends, threshold);
+        _(RawOrigin::Signed(caller.clone()), guardians, g, T::MinRecoveryDelay::get());
 
-        // Verify recovery was created
-        let account_info = Accounts::<T>::get(&caller).unwrap();
-        assert!(account_info.recovery.is_some());
+        assert!(RecoveryConfigs::<T>::contains_key(&caller));
     }
 
     #[benchmark]
     fn initiate_recovery() {
-        let lost_account: T::AccountId = account("lost", 0, 0);
-        let friend1: T::AccountId = whitelisted_caller();
-        let friend2: T::AccountId = account("friend2", 0, 0);
+        let lost: T::AccountId = account("lost", 0, 0);
         let new_account: T::AccountId = account("new", 0, 0);
-
-        // Setup: Create account with recovery
-        Accounts::<T>::insert(&lost_account, AccountInfo {
-            etr_balance: 1000u32.into(),
-            etd_balance: 0u32.into(),
-            nonce: 0,
-            recovery: Some(RecoveryConfig {
-                friends: vec![friend1.clone(), friend2],
-                threshold: 2,
-                approvals: vec![],
-                new_account_id: None,
-            }),
-        });
+        // Threshold 1: initiating also opens the veto window
+        let accounts = setup_config::<T>(&lost, MAX_GUARDIANS, 1);
+        let caller = accounts[accounts.len() - 1].clone();
+        funded::<T>(&caller, T::RecoveryDeposit::get().into());
 
         #[extrinsic_call]
-        initiate_recovery(RawOrigin::Signed(friend1.clone()), lost_account.clone(), new_account);
+        _(RawOrigin::Signed(caller), lost.clone(), new_account);
 
-        // Verify recovery was initiated
-        let account_info = Accounts::<T>::get(&lost_account).unwrap();
-        assert!(account_info.recovery.unwrap().new_account_id.is_some());
+        assert!(ActiveRecoveries::<T>::get(&lost).unwrap().executable_at.is_some());
     }
 
     #[benchmark]
     fn approve_recovery() {
-        let lost_account: T::AccountId = account("lost", 0, 0);
-        let friend1: T::AccountId = account("friend1", 0, 0);
-        let friend2: T::AccountId = whitelisted_caller();
+        let lost: T::AccountId = account("lost", 0, 0);
         let new_account: T::AccountId = account("new", 0, 0);
-
-        // Setup: Create account with recovery in progress
-        Accounts::<T>::insert(&lost_account, AccountInfo {
-            etr_balance: 1000u32.into(),
-            etd_balance: 0u32.into(),
-            nonce: 0,
-            recovery: Some(RecoveryConfig {
-                friends: vec![friend1.clone(), friend2.clone()],
-                threshold: 2,
-                approvals: vec![friend1.clone()],
-                new_account_id: Some(new_account.clone()),
-            }),
-        });
+        let accounts = setup_config::<T>(&lost, MAX_GUARDIANS, MAX_GUARDIANS);
+        let (caller, approvers) = accounts.split_last().unwrap();
+        setup_recovery::<T>(&lost, &new_account, approvers, None);
 
         #[extrinsic_call]
-        approve_recovery(RawOrigin::Signed(friend2.clone()), lost_account.clone());
+        _(RawOrigin::Signed(caller.clone()), lost.clone());
 
-        // Verify approval was recorded
-        let account_info = Accounts::<T>::get(&lost_account).unwrap();
-        let recovery = account_info.recovery.unwrap();
-        assert_eq!(recovery.approvals.len(), 2);
+        assert!(ActiveRecoveries::<T>::get(&lost).unwrap().executable_at.is_some());
     }
 
     #[benchmark]
     fn execute_recovery() {
-        let lost_account: T::AccountId = account("lost", 0, 0);
-        let friend1: T::AccountId = account("friend1", 0, 0);
-        let friend2: T::AccountId = account("friend2", 0, 0);
-        let new_account: T::AccountId = whitelisted_caller();
-
-        // Setup: Create account with recovery ready to execute
-        Accounts::<T>::insert(&lost_account, AccountInfo {
-            etr_balance: 1000u32.into(),
-            etd_balance: 500u32.into(),
-            nonce: 0,
-            recovery: Some(RecoveryConfig {
-                friends: vec![friend1.clone(), friend2.clone()],
-                threshold: 2,
-                approvals: vec![friend1, friend2],
-                new_account_id: Some(new_account.clone()),
-            }),
-        });
+        let caller: T::AccountId = whitelisted_caller();
+        let lost: T::AccountId = account("lost", 0, 0);
+        let new_account: T::AccountId = account("new", 0, 0);
+        let accounts = setup_config::<T>(&lost, MAX_GUARDIANS, MAX_GUARDIANS);
+        setup_recovery::<T>(
+            &lost,
+            &new_account,
+            &accounts,
+            Some(frame_system::Pallet::<T>::block_number()),
+        );
+        // Both balances move
+        funded::<T>(&lost, 10_000);
 
         #[extrinsic_call]
-        execute_recovery(RawOrigin::Signed(new_account.clone()), lost_account.clone());
+        _(RawOrigin::Signed(caller), lost.clone());
 
-        // Verify recovery was executed (new account should have the balance)
-        assert!(Accounts::<T>::contains_key(&new_account));
+        assert_eq!(Accounts::<T>::get(&new_account).etd_balance, 10_000u64.into());
+        assert!(!RecoveryConfigs::<T>::contains_key(&lost));
     }
 
     #[benchmark]
     fn cancel_recovery() {
-        let caller: T::AccountId = whitelisted_caller();
-        let friend1: T::AccountId = account("friend1", 0, 0);
-        let friend2: T::AccountId = account("friend2", 0, 0);
-
-        // Setup: Create account with recovery
-        Accounts::<T>::insert(&caller, AccountInfo {
-            etr_balance: 1000u32.into(),
-            etd_balance: 0u32.into(),
-            nonce: 0,
-            recovery: Some(RecoveryConfig {
-                friends: vec![friend1, friend2],
-                threshold: 2,
-                approvals: vec![],
-                new_account_id: None,
-            }),
-        });
+        let lost: T::AccountId = whitelisted_caller();
+        let new_account: T::AccountId = account("new", 0, 0);
+        let accounts = setup_config::<T>(&lost, MAX_GUARDIANS, MAX_GUARDIANS);
+        setup_recovery::<T>(&lost, &new_account, &accounts[..1], None);
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(lost.clone()), lost.clone());
+
+        assert!(!ActiveRecoveries::<T>::contains_key(&lost));
+    }
+
+    #[benchmark]
+    fn veto_recovery() {
+        let lost: T::AccountId = whitelisted_caller();
+        let new_account: T::AccountId = account("new", 0, 0);
+        let accounts = setup_config::<T>(&lost, MAX_GUARDIANS, MAX_GUARDIANS);
+        setup_recovery::<T>(&lost, &new_account, &accounts[..1], None);
 
         #[extrinsic_call]
-        cancel_recovery(RawOrigin::Signed(caller.clone()));
+        _(RawOrigin::Signed(lost.clone()));
 
-        // Verify recovery was cancelled
-        let account_info = Accounts::<T>::get(&caller).unwrap();
-        assert!(account_info.recovery.is_none());
+        assert!(!ActiveRecoveries::<T>::contains_key(&lost));
     }
 
-    impl_benchmark_test_suite!(
-        Pallet,
-        crate::mock::new_test_ext(),
-        crate::mock::Test
-    );
+    impl_benchmark_test_suite!(Pallet, crate::tests::new_test_ext(), crate::tests::Test);
 }
@@ -8,6 +8,8 @@ mod tests;
 
 #[cfg(feature = "runtime-benchmarks")]
 mod benchmarking;
+pub mod weights;
+pub use weights::WeightInfo;
 
 #[frame_support::pallet]
 pub mod pallet {
@@ -15,13 +17,14 @@ pub mod pallet {
     use frame_system::pallet_prelude::*;
     use codec::{Encode, Decode};
     use scale_info::TypeInfo;
-    use sp_runtime::{RuntimeDebug, traits::AtLeast32BitUnsigned};
+    use sp_core::H256;
+    use sp_runtime::{RuntimeDebug, traits::{AtLeast32BitUnsigned, Saturating}};
     use sp_std::vec::Vec;
     use scale_info::prelude::vec;
-    use frame_support::weights::constants::RocksDbWeight;    
+    use crate::WeightInfo;
     
     // Maximum guardians per account
-    const MAX_GUARDIANS: u32 = 10;
+    pub(crate) const MAX_GUARDIANS: u32 = 10;
 
     #[derive(
         Encode,
@@ -49,103 +52,76 @@ pub mod pallet {
         pub reputation: u64,
     }
 
+    /// Recovery guardian: a plain account, or a DID whose owner or controller
+    /// (in `pallet-did-registry`) approves on its behalf
+    #[derive(Encode, Decode, codec::DecodeWithMemTracking, TypeInfo, MaxEncodedLen, Clone, Eq, PartialEq, RuntimeDebug)]
+    pub enum Guardian<AccountId> {
+        Account(AccountId),
+        Did(H256),
+    }
+
+    /// DID ownership lookups for DID guardians
+    pub trait DidControllers<AccountId> {
+        /// Whether `who` owns or controls the active DID `did_hash`
+        fn is_controller(did_hash: &H256, who: &AccountId) -> bool;
+    }
+
+    /// No DIDs: DID guardians can never approve
+    impl<AccountId> DidControllers<AccountId> for () {
+        fn is_controller(_did_hash: &H256, _who: &AccountId) -> bool {
+            false
+        }
+    }
+
     #[derive(Encode, Decode, TypeInfo, MaxEncodedLen, Clone, Eq, PartialEq, RuntimeDebug)]
     pub struct RecoveryConfig<
         AccountId: Encode + Decode + TypeInfo + MaxEncodedLen + Clone + Eq + PartialEq + core::fmt::Debug,
         BlockNumber: Encode + Decode + TypeInfo + MaxEncodedLen + Clone + Eq + PartialEq + core::fmt::Debug,
     > {
-        pub guardians: BoundedVec<AccountId, ConstU32<MAX_GUARDIANS>>,
+        pub guardians: BoundedVec<Guardian<AccountId>, ConstU32<MAX_GUARDIANS>>,
         pub threshold: u32,  // M-of-N threshold
-        pub delay_period: BlockNumber,  // Blocks to wait before recovery
+        pub delay_period: BlockNumber,  // Blocks the owner has to veto once the threshold is met
     }
 
     #[derive(Encode, Decode, TypeInfo, MaxEncodedLen, Clone, Eq, PartialEq, RuntimeDebug)]
     pub struct ActiveRecovery<
         AccountId: Encode + Decode + TypeInfo + MaxEncodedLen + Clone + Eq + PartialEq + core::fmt::Debug,
         BlockNumber: Encode + Decode + TypeInfo + MaxEncodedLen + Clone + Eq + PartialEq + core::fmt::Debug,
+        Balance: Encode + Decode + TypeInfo + MaxEncodedLen + Clone + Eq + PartialEq + core::fmt::Debug,
     > {
         pub new_account: AccountId,
-        pub approvals: BoundedVec<AccountId, ConstU32<MAX_GUARDIANS>>,
+        pub approvals: BoundedVec<Guardian<AccountId>, ConstU32<MAX_GUARDIANS>>,
         pub created_at: BlockNumber,
-        pub executable_at: BlockNumber,
+        /// Set once the threshold is met: block the owner's veto window closes
+        pub executable_at: Option<BlockNumber>,
+        /// Guardian account that initiated the recovery and holds the deposit
+        pub initiator: AccountId,
+        /// Deposit refunded on execution or cancellation, slashed to the owner on veto
+        pub deposit: Balance,
     }
 
     impl<
         AccountId: Encode + Decode + TypeInfo + MaxEncodedLen + Clone + Eq + PartialEq + core::fmt::Debug,
         BlockNumber: Encode + Decode + TypeInfo + MaxEncodedLen + Clone + Eq + PartialEq + core::fmt::Debug,
     > RecoveryConfig<AccountId, BlockNumber> {
-        pub fn is_guardian(&self, who: &AccountId) -> bool {
-            self.guardians.contains(who)
+        /// Guardian slots `who` can approve for, directly or through a DID
+        /// (`is_controller` is `DidControllers::is_controller`)
+        pub fn guardians_of<'a>(
+            &'a self,
+            who: &'a AccountId,
+            is_controller: fn(&H256, &AccountId) -> bool,
+        ) -> impl Iterator<Item = &'a Guardian<AccountId>> + 'a {
+            self.guardians.iter().filter(move |guardian| match guardian {
+                Guardian::Account(account) => account == who,
+                Guardian::Did(did_hash) => is_controller(did_hash, who),
+            })
         }
 
-        pub fn has_threshold(&self, approvals: &BoundedVec<AccountId, ConstU32<MAX_GUARDIANS>>) -> bool {
+        pub fn has_threshold(&self, approvals: &BoundedVec<Guardian<AccountId>, ConstU32<MAX_GUARDIANS>>) -> bool {
             approvals.len() >= self.threshold as usize
         }
     }
 
-    /// Weight functions for pallet_accounts.
-    pub trait WeightInfo {
-        fn transfer() -> Weight;
-        fn mint_etr() -> Weight;
-        fn mint_etd() -> Weight;
-        fn burn() -> Weight;
-        fn create_recovery() -> Weight;
-        fn initiate_recovery() -> Weight;
-        fn approve_recovery() -> Weight;
-        fn execute_recovery() -> Weight;
-        fn cancel_recovery() -> Weight;
-    }
-
-    /// Conservative weight estimates for production safety.
-    /// TODO: Replace with benchmarked weights before mainnet.
-    impl WeightInfo for () {
-        fn transfer() -> Weight {
-            Weight::from_parts(50_000_000, 0)
-                .saturating_add(RocksDbWeight::get().reads(2))
-                .saturating_add(RocksDbWeight::get().writes(2))
-        }
-        fn mint_etr() -> Weight {
-            Weight::from_parts(40_000_000, 0)
-                .saturating_add(RocksDbWeight::get().reads(1))
-                .saturating_add(RocksDbWeight::get().writes(1))
-        }
-        fn mint_etd() -> Weight {
-            Weight::from_parts(40_000_000, 0)
-                .saturating_add(RocksDbWeight::get().reads(1))
-                .saturating_add(RocksDbWeight::get().writes(1))
-        }
-        fn burn() -> Weight {
-            Weight::from_parts(40_000_000, 0)
-                .saturating_add(RocksDbWeight::get().reads(1))
-                .saturating_add(RocksDbWeight::get().writes(1))
-        }
-        fn create_recovery() -> Weight {
-            Weight::from_parts(35_000_000, 0)
-                .saturating_add(RocksDbWeight::get().reads(1))
-                .saturating_add(RocksDbWeight::get().writes(1))
-        }
-        fn initiate_recovery() -> Weight {
-            Weight::from_parts(40_000_000, 0)
-                .saturating_add(RocksDbWeight::get().reads(1))
-                .saturating_add(RocksDbWeight::get().writes(1))
-        }
-        fn approve_recovery() -> Weight {
-            Weight::from_parts(35_000_000, 0)
-                .saturating_add(RocksDbWeight::get().reads(2))
-                .saturating_add(RocksDbWeight::get().writes(1))
-        }
-        fn execute_recovery() -> Weight {
-            Weight::from_parts(60_000_000, 0)
-                .saturating_add(RocksDbWeight::get().reads(3))
-                .saturating_add(RocksDbWeight::get().writes(3))
-        }
-        fn cancel_recovery() -> Weight {
-            Weight::from_parts(30_000_000, 0)
-                .saturating_add(RocksDbWeight::get().reads(1))
-                .saturating_add(RocksDbWeight::get().writes(1))
-        }
-    }
-
     #[pallet::config]
     pub trait Config: frame_system::Config {
         #[allow(deprecated)]
@@ -153,6 +129,17 @@ pub mod pallet {
         type Balance: Parameter + From<u64> + Into<u64> + AtLeast32BitUnsigned + Default + Copy + MaxEncodedLen;
         type GovernanceOrigin: EnsureOrigin<Self::RuntimeOrigin>;
 
+        /// Owner/controller lookups for DID guardians (`pallet-did-registry`)
+        type Dids: DidControllers<Self::AccountId>;
+
+        /// ETR taken from the initiating guardian, slashed to the owner on veto
+        #[pallet::constant]
+        type RecoveryDeposit: Get<Self::Balance>;
+
+        /// Shortest veto window an account can configure
+        #[pallet::constant]
+        type MinRecoveryDelay: Get<BlockNumberFor<Self>>;
+
         /// Weight information for extrinsics in this pallet.
         type WeightInfo: WeightInfo;
     }
@@ -177,7 +164,7 @@ pub mod pallet {
         _,
         Blake2_128Concat,
         T::AccountId,  // Lost account
-        ActiveRecovery<T::AccountId, BlockNumberFor<T>>,
+        ActiveRecovery<T::AccountId, BlockNumberFor<T>, T::Balance>,
         OptionQuery,
     >;
 
@@ -190,8 +177,12 @@ pub mod pallet {
         RecoveryCreated { account: T::AccountId, threshold: u32 },
         RecoveryInitiated { lost_account: T::AccountId, new_account: T::AccountId, guardian: T::AccountId },
         RecoveryApproved { lost_account: T::AccountId, guardian: T::AccountId, approvals: u32 },
+        /// Threshold met: the owner can veto until `executable_at`
+        RecoveryThresholdReached { lost_account: T::AccountId, new_account: T::AccountId, executable_at: BlockNumberFor<T> },
         RecoveryExecuted { lost_account: T::AccountId, new_account: T::AccountId },
         RecoveryCancelled { account: T::AccountId },
+        /// The owner rejected a recovery; the initiator's deposit went to the owner
+        RecoveryVetoed { account: T::AccountId, initiator: T::AccountId, slashed: T::Balance },
     }
 
     #[pallet::error]
@@ -210,6 +201,8 @@ pub mod pallet {
         ThresholdNotMet,
         DelayNotPassed,
         NotAccountOwner,
+        DelayTooShort,
+        DuplicateGuardian,
     }
 
     #[pallet::pallet]
@@ -218,7 +211,7 @@ pub mod pallet {
     #[pallet::call]
     #[allow(deprecated)]
     impl<T: Config> Pallet<T> {
-        #[pallet::weight(10_000)]
+        #[pallet::weight(T::WeightInfo::transfer())]
         #[pallet::call_index(0)]
         pub fn transfer(
             origin: OriginFor<T>,
@@ -231,7 +224,7 @@ pub mod pallet {
             Ok(())
         }
 
-        #[pallet::weight(T::WeightInfo::transfer())]
+        #[pallet::weight(T::WeightInfo::mint_etr())]
         #[pallet::call_index(1)]
         pub fn mint_etr(
             origin: OriginFor<T>,
@@ -246,7 +239,7 @@ pub mod pallet {
             Ok(())
         }
 
-        #[pallet::weight(T::WeightInfo::mint_etr())]
+        #[pallet::weight(T::WeightInfo::mint_etd())]
         #[pallet::call_index(2)]
         pub fn mint_etd(
             origin: OriginFor<T>,
@@ -261,7 +254,7 @@ pub mod pallet {
             Ok(())
         }
 
-        #[pallet::weight(T::WeightInfo::mint_etd())]
+        #[pallet::weight(T::WeightInfo::burn())]
         #[pallet::call_index(3)]
         pub fn burn(
             origin: OriginFor<T>,
@@ -287,20 +280,25 @@ pub mod pallet {
         }
 
         /// Setup recovery configuration for an account
-        #[pallet::weight(T::WeightInfo::burn())]
+        #[pallet::weight(T::WeightInfo::create_recovery(guardians.len() as u32))]
         #[pallet::call_index(4)]
         pub fn create_recovery(
             origin: OriginFor<T>,
-            guardians: Vec<T::AccountId>,
+            guardians: Vec<Guardian<T::AccountId>>,
             threshold: u32,
             delay_period: BlockNumberFor<T>,
         ) -> DispatchResult {
             let who = ensure_signed(origin)?;
 
+            ensure!(!guardians.is_empty(), Error::<T>::NoGuardians);
             ensure!(threshold > 0, Error::<T>::InvalidThreshold);
             ensure!(threshold as usize <= guardians.len(), Error::<T>::ThresholdTooHigh);
-            ensure!(!guardians.is_empty(), Error::<T>::NoGuardians);
             ensure!(guardians.len() <= MAX_GUARDIANS as usize, Error::<T>::TooManyGuardians);
+            ensure!(delay_period >= T::MinRecoveryDelay::get(), Error::<T>::DelayTooShort);
+            ensure!(
+                guardians.iter().enumerate().all(|(i, g)| !guardians[..i].contains(g)),
+                Error::<T>::DuplicateGuardian
+            );
 
             let guardians_bounded = BoundedVec::try_from(guardians)
                 .map_err(|_| Error::<T>::TooManyGuardians)?;
@@ -318,7 +316,7 @@ pub mod pallet {
         }
 
         /// Initiate recovery for a lost account
-        #[pallet::weight(T::WeightInfo::create_recovery())]
+        #[pallet::weight(T::WeightInfo::initiate_recovery())]
         #[pallet::call_index(5)]
         pub fn initiate_recovery(
             origin: OriginFor<T>,
@@ -330,30 +328,44 @@ pub mod pallet {
             let config = RecoveryConfigs::<T>::get(&lost_account)
                 .ok_or(Error::<T>::NoRecoveryConfig)?;
 
-            ensure!(config.is_guardian(&who), Error::<T>::NotGuardian);
+            let guardian = config.guardians_of(&who, T::Dids::is_controller).next().cloned()
+                .ok_or(Error::<T>::NotGuardian)?;
             ensure!(!ActiveRecoveries::<T>::contains_key(&lost_account), Error::<T>::RecoveryAlreadyActive);
 
-            let current_block = frame_system::Pallet::<T>::block_number();
-            let executable_at = current_block + config.delay_period;
+            // Hold the deposit
+            let deposit = T::RecoveryDeposit::get();
+            Accounts::<T>::try_mutate(&who, |acct| -> DispatchResult {
+                ensure!(acct.etr_balance >= deposit, Error::<T>::InsufficientBalance);
+                acct.etr_balance -= deposit;
+                Ok(())
+            })?;
 
-            let approvals = BoundedVec::try_from(vec![who.clone()])
+            let current_block = frame_system::Pallet::<T>::block_number();
+            let approvals = BoundedVec::try_from(vec![guardian])
                 .map_err(|_| Error::<T>::TooManyGuardians)?;
 
-            let recovery = ActiveRecovery {
+            let mut recovery = ActiveRecovery {
                 new_account: new_account.clone(),
                 approvals,
                 created_at: current_block,
-                executable_at,
+                executable_at: None,
+                initiator: who.clone(),
+                deposit,
             };
 
+            Self::deposit_event(Event::RecoveryInitiated {
+                lost_account: lost_account.clone(),
+                new_account,
+                guardian: who,
+            });
+            Self::check_threshold(&lost_account, &config, &mut recovery);
             ActiveRecoveries::<T>::insert(&lost_account, recovery);
-            Self::deposit_event(Event::RecoveryInitiated { lost_account, new_account, guardian: who });
 
             Ok(())
         }
 
         /// Approve an active recovery
-        #[pallet::weight(T::WeightInfo::initiate_recovery())]
+        #[pallet::weight(T::WeightInfo::approve_recovery())]
         #[pallet::call_index(6)]
         pub fn approve_recovery(
             origin: OriginFor<T>,
@@ -364,14 +376,18 @@ pub mod pallet {
             let config = RecoveryConfigs::<T>::get(&lost_account)
                 .ok_or(Error::<T>::NoRecoveryConfig)?;
 
-            ensure!(config.is_guardian(&who), Error::<T>::NotGuardian);
+            ensure!(config.guardians_of(&who, T::Dids::is_controller).next().is_some(), Error::<T>::NotGuardian);
 
             ActiveRecoveries::<T>::try_mutate(&lost_account, |maybe_recovery| -> DispatchResult {
                 let recovery = maybe_recovery.as_mut().ok_or(Error::<T>::NoActiveRecovery)?;
 
-                ensure!(!recovery.approvals.contains(&who), Error::<T>::AlreadyApproved);
+                // One approval per call, for the first slot the caller has not approved yet
+                let guardian = config.guardians_of(&who, T::Dids::is_controller)
+                    .find(|guardian| !recovery.approvals.contains(guardian))
+                    .cloned()
+                    .ok_or(Error::<T>::AlreadyApproved)?;
 
-                recovery.approvals.try_push(who.clone())
+                recovery.approvals.try_push(guardian)
                     .map_err(|_| Error::<T>::TooManyGuardians)?;
 
                 Self::deposit_event(Event::RecoveryApproved {
@@ -379,13 +395,14 @@ pub mod pallet {
                     guardian: who,
                     approvals: recovery.approvals.len() as u32,
                 });
+                Self::check_threshold(&lost_account, &config, recovery);
 
                 Ok(())
             })
         }
 
         /// Execute recovery after threshold and delay period
-        #[pallet::weight(T::WeightInfo::approve_recovery())]
+        #[pallet::weight(T::WeightInfo::execute_recovery())]
         #[pallet::call_index(7)]
         pub fn execute_recovery(
             origin: OriginFor<T>,
@@ -402,12 +419,14 @@ pub mod pallet {
             // Check threshold reached
             ensure!(config.has_threshold(&recovery.approvals), Error::<T>::ThresholdNotMet);
 
-            // Check delay period passed
+            // Check the owner's veto window has closed
+            let executable_at = recovery.executable_at.ok_or(Error::<T>::ThresholdNotMet)?;
             let current_block = frame_system::Pallet::<T>::block_number();
-            ensure!(current_block >= recovery.executable_at, Error::<T>::DelayNotPassed);
+            ensure!(current_block >= executable_at, Error::<T>::DelayNotPassed);
 
-            // Execute recovery: transfer account ownership
+            // Execute recovery: transfer account ownership, then refund the initiator
             Self::recover_account(&lost_account, &recovery.new_account)?;
+            Self::release_deposit(&recovery.initiator, recovery.deposit);
 
             // Cleanup
             ActiveRecoveries::<T>::remove(&lost_account);
@@ -421,8 +440,8 @@ pub mod pallet {
             Ok(())
         }
 
-        /// Cancel an active recovery (only by lost account owner)
-        #[pallet::weight(T::WeightInfo::execute_recovery())]
+        /// Cancel an active recovery (only by lost account owner), refunding the initiator
+        #[pallet::weight(T::WeightInfo::cancel_recovery())]
         #[pallet::call_index(8)]
         pub fn cancel_recovery(
             origin: OriginFor<T>,
@@ -433,11 +452,33 @@ pub mod pallet {
             // Only the account owner can cancel
             ensure!(who == account, Error::<T>::NotAccountOwner);
 
-            ActiveRecoveries::<T>::remove(&account);
+            if let Some(recovery) = ActiveRecoveries::<T>::take(&account) {
+                Self::release_deposit(&recovery.initiator, recovery.deposit);
+            }
             Self::deposit_event(Event::RecoveryCancelled { account });
 
             Ok(())
         }
+
+        /// Reject an unwanted recovery with the owner key, slashing the
+        /// initiator's deposit to the owner
+        #[pallet::weight(T::WeightInfo::veto_recovery())]
+        #[pallet::call_index(9)]
+        pub fn veto_recovery(origin: OriginFor<T>) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            let recovery = ActiveRecoveries::<T>::take(&who)
+                .ok_or(Error::<T>::NoActiveRecovery)?;
+            Self::release_deposit(&who, recovery.deposit);
+
+            Self::deposit_event(Event::RecoveryVetoed {
+                account: who,
+                initiator: recovery.initiator,
+                slashed: recovery.deposit,
+            });
+
+            Ok(())
+        }
     }
 
     impl<T: Config> Pallet<T> {
@@ -471,6 +512,32 @@ pub mod pallet {
             Ok(())
         }
 
+        /// Start the veto window once an active recovery reaches its threshold
+        fn check_threshold(
+            lost_account: &T::AccountId,
+            config: &RecoveryConfig<T::AccountId, BlockNumberFor<T>>,
+            recovery: &mut ActiveRecovery<T::AccountId, BlockNumberFor<T>, T::Balance>,
+        ) {
+            if recovery.executable_at.is_some() || !config.has_threshold(&recovery.approvals) {
+                return;
+            }
+            let executable_at = frame_system::Pallet::<T>::block_number()
+                .saturating_add(config.delay_period);
+            recovery.executable_at = Some(executable_at);
+            Self::deposit_event(Event::RecoveryThresholdReached {
+                lost_account: lost_account.clone(),
+                new_account: recovery.new_account.clone(),
+                executable_at,
+            });
+        }
+
+        /// Pay a held recovery deposit out to `to`
+        fn release_deposit(to: &T::AccountId, deposit: T::Balance) {
+            if deposit > T::Balance::from(0u64) {
+                Accounts::<T>::mutate(to, |acct| acct.etr_balance += deposit);
+            }
+        }
+
         /// Helper function to transfer account ownership during recovery
         fn recover_account(
             lost_account: &T::AccountId,
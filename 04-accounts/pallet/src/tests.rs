@@ -70,21 +70,55 @@ impl frame_support::traits::EnsureOrigin<RuntimeOrigin> for EnsureRoot {
     }
 }
 
+/// `did(n)` is owned by account `n`
+pub struct MockDids;
+impl DidControllers<u64> for MockDids {
+    fn is_controller(did_hash: &H256, who: &u64) -> bool {
+        *did_hash == did(*who)
+    }
+}
+
+parameter_types! {
+    pub const RecoveryDeposit: u64 = 10;
+    pub const MinRecoveryDelay: u64 = 5;
+}
+
 impl pallet_accounts::Config for Test {
     type RuntimeEvent = RuntimeEvent;
     type Balance = u64;
     type GovernanceOrigin = EnsureRoot;
+    type Dids = MockDids;
+    type RecoveryDeposit = RecoveryDeposit;
+    type MinRecoveryDelay = MinRecoveryDelay;
+    type WeightInfo = ();
 }
 
 pub fn account(id: u64) -> u64 {
     id
 }
 
+pub fn did(id: u64) -> H256 {
+    H256::from_low_u64_be(id)
+}
+
+pub fn account_guardians(ids: &[u64]) -> Vec<Guardian<u64>> {
+    ids.iter().copied().map(Guardian::Account).collect()
+}
+
+/// Starts at block 1; guardians 2, 3 and 4 hold 100 ETR each for recovery deposits
 pub fn new_test_ext() -> sp_io::TestExternalities {
     let t = frame_system::GenesisConfig::<Test>::default()
         .build_storage()
         .unwrap();
-    t.into()
+    let mut ext: sp_io::TestExternalities = t.into();
+    ext.execute_with(|| {
+        // Events are not recorded in the genesis block
+        System::set_block_number(1);
+        for guardian in [2, 3, 4] {
+            pallet_accounts::Accounts::<Test>::mutate(&account(guardian), |acct| acct.etr_balance = 100);
+        }
+    });
+    ext
 }
 
 pub fn run_to_block(n: u64) {
@@ -96,7 +130,7 @@ pub fn run_to_block(n: u64) {
 #[test]
 fn create_recovery_works() {
     new_test_ext().execute_with(|| {
-        let guardians = vec![account(2), account(3), account(4)];
+        let guardians = account_guardians(&[2, 3, 4]);
         assert_ok!(Accounts::create_recovery(
             RuntimeOrigin::signed(account(1)),
             guardians.clone(),
@@ -124,7 +158,7 @@ fn create_recovery_works() {
 #[test]
 fn create_recovery_invalid_threshold() {
     new_test_ext().execute_with(|| {
-        let guardians = vec![account(2), account(3)];
+        let guardians = account_guardians(&[2, 3]);
 
         // Threshold 0 should fail
         assert_noop!(
@@ -143,7 +177,7 @@ fn create_recovery_invalid_threshold() {
 #[test]
 fn create_recovery_no_guardians() {
     new_test_ext().execute_with(|| {
-        let guardians: Vec<u64> = vec![];
+        let guardians: Vec<Guardian<u64>> = vec![];
 
         assert_noop!(
             Accounts::create_recovery(RuntimeOrigin::signed(account(1)), guardians, 1, 10),
@@ -155,7 +189,7 @@ fn create_recovery_no_guardians() {
 #[test]
 fn create_recovery_too_many_guardians() {
     new_test_ext().execute_with(|| {
-        let guardians: Vec<u64> = (2..13).collect(); // 11 guardians (> MAX_GUARDIANS)
+        let guardians = account_guardians(&(2..13).collect::<Vec<_>>()); // 11 guardians (> MAX_GUARDIANS)
 
         assert_noop!(
             Accounts::create_recovery(RuntimeOrigin::signed(account(1)), guardians, 5, 10),
@@ -169,7 +203,7 @@ fn initiate_recovery_works() {
     new_test_ext().execute_with(|| {
         let lost = account(1);
         let new = account(5);
-        let guardians = vec![account(2), account(3), account(4)];
+        let guardians = account_guardians(&[2, 3, 4]);
 
         assert_ok!(Accounts::create_recovery(
             RuntimeOrigin::signed(lost),
@@ -190,7 +224,7 @@ fn initiate_recovery_works() {
         let recovery = ActiveRecoveries::<Test>::get(&lost).unwrap();
         assert_eq!(recovery.new_account, new);
         assert_eq!(recovery.approvals.len(), 1);
-        assert_eq!(recovery.approvals[0], account(2));
+        assert_eq!(recovery.approvals[0], Guardian::Account(account(2)));
 
         System::assert_last_event(
             Event::RecoveryInitiated {
@@ -217,7 +251,7 @@ fn initiate_recovery_no_config() {
 fn initiate_recovery_not_guardian() {
     new_test_ext().execute_with(|| {
         let lost = account(1);
-        let guardians = vec![account(2), account(3)];
+        let guardians = account_guardians(&[2, 3]);
 
         assert_ok!(Accounts::create_recovery(
             RuntimeOrigin::signed(lost),
@@ -238,7 +272,7 @@ fn initiate_recovery_not_guardian() {
 fn initiate_recovery_already_active() {
     new_test_ext().execute_with(|| {
         let lost = account(1);
-        let guardians = vec![account(2), account(3)];
+        let guardians = account_guardians(&[2, 3]);
 
         assert_ok!(Accounts::create_recovery(
             RuntimeOrigin::signed(lost),
@@ -265,7 +299,7 @@ fn initiate_recovery_already_active() {
 fn approve_recovery_works() {
     new_test_ext().execute_with(|| {
         let lost = account(1);
-        let guardians = vec![account(2), account(3), account(4)];
+        let guardians = account_guardians(&[2, 3, 4]);
 
         assert_ok!(Accounts::create_recovery(
             RuntimeOrigin::signed(lost),
@@ -288,10 +322,10 @@ fn approve_recovery_works() {
 
         let recovery = ActiveRecoveries::<Test>::get(&lost).unwrap();
         assert_eq!(recovery.approvals.len(), 2);
-        assert!(recovery.approvals.contains(&account(2)));
-        assert!(recovery.approvals.contains(&account(3)));
+        assert!(recovery.approvals.contains(&Guardian::Account(account(2))));
+        assert!(recovery.approvals.contains(&Guardian::Account(account(3))));
 
-        System::assert_last_event(
+        System::assert_has_event(
             Event::RecoveryApproved {
                 lost_account: lost,
                 guardian: account(3),
@@ -299,6 +333,14 @@ fn approve_recovery_works() {
             }
             .into(),
         );
+        System::assert_last_event(
+            Event::RecoveryThresholdReached {
+                lost_account: lost,
+                new_account: account(5),
+                executable_at: 11,
+            }
+            .into(),
+        );
     });
 }
 
@@ -306,7 +348,7 @@ fn approve_recovery_works() {
 fn approve_recovery_not_guardian() {
     new_test_ext().execute_with(|| {
         let lost = account(1);
-        let guardians = vec![account(2), account(3)];
+        let guardians = account_guardians(&[2, 3]);
 
         assert_ok!(Accounts::create_recovery(
             RuntimeOrigin::signed(lost),
@@ -333,7 +375,7 @@ fn approve_recovery_not_guardian() {
 fn approve_recovery_duplicate_approval() {
     new_test_ext().execute_with(|| {
         let lost = account(1);
-        let guardians = vec![account(2), account(3)];
+        let guardians = account_guardians(&[2, 3]);
 
         assert_ok!(Accounts::create_recovery(
             RuntimeOrigin::signed(lost),
@@ -360,7 +402,7 @@ fn approve_recovery_duplicate_approval() {
 fn approve_recovery_no_active_recovery() {
     new_test_ext().execute_with(|| {
         let lost = account(1);
-        let guardians = vec![account(2), account(3)];
+        let guardians = account_guardians(&[2, 3]);
 
         assert_ok!(Accounts::create_recovery(
             RuntimeOrigin::signed(lost),
@@ -382,7 +424,7 @@ fn execute_recovery_delay_not_passed() {
     new_test_ext().execute_with(|| {
         let lost = account(1);
         let new = account(5);
-        let guardians = vec![account(2), account(3)];
+        let guardians = account_guardians(&[2, 3]);
 
         assert_ok!(Accounts::create_recovery(
             RuntimeOrigin::signed(lost),
@@ -420,7 +462,7 @@ fn execute_recovery_threshold_not_met() {
     new_test_ext().execute_with(|| {
         let lost = account(1);
         let new = account(5);
-        let guardians = vec![account(2), account(3), account(4)];
+        let guardians = account_guardians(&[2, 3, 4]);
 
         assert_ok!(Accounts::create_recovery(
             RuntimeOrigin::signed(lost),
@@ -451,7 +493,7 @@ fn full_recovery_workflow() {
         // Setup: Account 1 with 3 guardians, 2-of-3 threshold, 10 block delay
         let lost = account(1);
         let new = account(5);
-        let guardians = vec![account(2), account(3), account(4)];
+        let guardians = account_guardians(&[2, 3, 4]);
 
         assert_ok!(Accounts::create_recovery(
             RuntimeOrigin::signed(lost),
@@ -526,7 +568,7 @@ fn full_recovery_workflow() {
 fn cancel_recovery_by_owner() {
     new_test_ext().execute_with(|| {
         let lost = account(1);
-        let guardians = vec![account(2), account(3)];
+        let guardians = account_guardians(&[2, 3]);
 
         assert_ok!(Accounts::create_recovery(
             RuntimeOrigin::signed(lost),
@@ -547,8 +589,9 @@ fn cancel_recovery_by_owner() {
             lost
         ));
 
-        // Verify cleanup
+        // Verify cleanup; a cancellation refunds the initiator
         assert!(!ActiveRecoveries::<Test>::contains_key(&lost));
+        assert_eq!(pallet_accounts::Accounts::<Test>::get(&account(2)).etr_balance, 100);
 
         System::assert_last_event(Event::RecoveryCancelled { account: lost }.into());
     });
@@ -558,7 +601,7 @@ fn cancel_recovery_by_owner() {
 fn cancel_recovery_not_owner() {
     new_test_ext().execute_with(|| {
         let lost = account(1);
-        let guardians = vec![account(2), account(3)];
+        let guardians = account_guardians(&[2, 3]);
 
         assert_ok!(Accounts::create_recovery(
             RuntimeOrigin::signed(lost),
@@ -585,7 +628,7 @@ fn cancel_recovery_not_owner() {
 fn recovery_with_maximum_guardians() {
     new_test_ext().execute_with(|| {
         let lost = account(1);
-        let guardians: Vec<u64> = (2..12).collect(); // 10 guardians (MAX_GUARDIANS)
+        let guardians = account_guardians(&(2..12).collect::<Vec<_>>()); // 10 guardians (MAX_GUARDIANS)
 
         assert_ok!(Accounts::create_recovery(
             RuntimeOrigin::signed(lost),
@@ -604,7 +647,7 @@ fn recovery_with_all_guardians_approval() {
     new_test_ext().execute_with(|| {
         let lost = account(1);
         let new = account(10);
-        let guardians = vec![account(2), account(3), account(4)];
+        let guardians = account_guardians(&[2, 3, 4]);
 
         assert_ok!(Accounts::create_recovery(
             RuntimeOrigin::signed(lost),
@@ -660,7 +703,7 @@ fn recovery_transfers_both_token_types() {
     new_test_ext().execute_with(|| {
         let lost = account(1);
         let new = account(5);
-        let guardians = vec![account(2), account(3)];
+        let guardians = account_guardians(&[2, 3]);
 
         assert_ok!(Accounts::create_recovery(
             RuntimeOrigin::signed(lost),
@@ -704,7 +747,7 @@ fn recovery_preserves_validator_status_and_reputation() {
     new_test_ext().execute_with(|| {
         let lost = account(1);
         let new = account(5);
-        let guardians = vec![account(2), account(3)];
+        let guardians = account_guardians(&[2, 3]);
 
         assert_ok!(Accounts::create_recovery(
             RuntimeOrigin::signed(lost),
@@ -743,3 +786,159 @@ fn recovery_preserves_validator_status_and_reputation() {
         assert_eq!(new_data.reputation, 9999);
     });
 }
+
+#[test]
+fn create_recovery_rejects_short_delay_and_duplicates() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            Accounts::create_recovery(RuntimeOrigin::signed(account(1)), account_guardians(&[2, 3]), 2, 4),
+            Error::<Test>::DelayTooShort
+        );
+        assert_noop!(
+            Accounts::create_recovery(RuntimeOrigin::signed(account(1)), account_guardians(&[2, 3, 2]), 2, 10),
+            Error::<Test>::DuplicateGuardian
+        );
+    });
+}
+
+#[test]
+fn initiate_recovery_takes_deposit() {
+    new_test_ext().execute_with(|| {
+        let lost = account(1);
+        assert_ok!(Accounts::create_recovery(
+            RuntimeOrigin::signed(lost),
+            account_guardians(&[2, 6]),
+            2,
+            10
+        ));
+
+        // Guardian 6 cannot cover the deposit
+        assert_noop!(
+            Accounts::initiate_recovery(RuntimeOrigin::signed(account(6)), lost, account(5)),
+            Error::<Test>::InsufficientBalance
+        );
+
+        assert_ok!(Accounts::initiate_recovery(RuntimeOrigin::signed(account(2)), lost, account(5)));
+        assert_eq!(pallet_accounts::Accounts::<Test>::get(&account(2)).etr_balance, 90);
+
+        let recovery = ActiveRecoveries::<Test>::get(&lost).unwrap();
+        assert_eq!(recovery.initiator, account(2));
+        assert_eq!(recovery.deposit, 10);
+        assert_eq!(recovery.executable_at, None);
+    });
+}
+
+#[test]
+fn recovery_delay_starts_at_threshold() {
+    new_test_ext().execute_with(|| {
+        let lost = account(1);
+        let new = account(5);
+        assert_ok!(Accounts::create_recovery(
+            RuntimeOrigin::signed(lost),
+            account_guardians(&[2, 3]),
+            2,
+            10
+        ));
+        pallet_accounts::Accounts::<Test>::mutate(&lost, |acct| acct.etr_balance = 1000);
+
+        assert_ok!(Accounts::initiate_recovery(RuntimeOrigin::signed(account(2)), lost, new));
+
+        // Guardians sit on the recovery; the veto window only opens at threshold
+        run_to_block(20);
+        assert_ok!(Accounts::approve_recovery(RuntimeOrigin::signed(account(3)), lost));
+        System::assert_last_event(
+            Event::RecoveryThresholdReached {
+                lost_account: lost,
+                new_account: new,
+                executable_at: 30,
+            }
+            .into(),
+        );
+
+        run_to_block(29);
+        assert_noop!(
+            Accounts::execute_recovery(RuntimeOrigin::signed(account(2)), lost),
+            Error::<Test>::DelayNotPassed
+        );
+
+        run_to_block(30);
+        assert_ok!(Accounts::execute_recovery(RuntimeOrigin::signed(account(2)), lost));
+        assert_eq!(pallet_accounts::Accounts::<Test>::get(&new).etr_balance, 1000);
+        // Deposit refunded
+        assert_eq!(pallet_accounts::Accounts::<Test>::get(&account(2)).etr_balance, 100);
+    });
+}
+
+#[test]
+fn veto_recovery_slashes_deposit() {
+    new_test_ext().execute_with(|| {
+        let lost = account(1);
+        assert_ok!(Accounts::create_recovery(
+            RuntimeOrigin::signed(lost),
+            account_guardians(&[2, 3]),
+            2,
+            10
+        ));
+        pallet_accounts::Accounts::<Test>::mutate(&lost, |acct| acct.etr_balance = 1000);
+
+        assert_ok!(Accounts::initiate_recovery(RuntimeOrigin::signed(account(2)), lost, account(66)));
+        assert_ok!(Accounts::approve_recovery(RuntimeOrigin::signed(account(3)), lost));
+
+        // Only the owner key can veto
+        assert_noop!(
+            Accounts::veto_recovery(RuntimeOrigin::signed(account(2))),
+            Error::<Test>::NoActiveRecovery
+        );
+        assert_ok!(Accounts::veto_recovery(RuntimeOrigin::signed(lost)));
+        System::assert_last_event(
+            Event::RecoveryVetoed {
+                account: lost,
+                initiator: account(2),
+                slashed: 10,
+            }
+            .into(),
+        );
+
+        assert!(!ActiveRecoveries::<Test>::contains_key(&lost));
+        assert!(RecoveryConfigs::<Test>::contains_key(&lost));
+        assert_eq!(pallet_accounts::Accounts::<Test>::get(&lost).etr_balance, 1010);
+        assert_eq!(pallet_accounts::Accounts::<Test>::get(&account(2)).etr_balance, 90);
+
+        run_to_block(20);
+        assert_noop!(
+            Accounts::execute_recovery(RuntimeOrigin::signed(account(2)), lost),
+            Error::<Test>::NoActiveRecovery
+        );
+    });
+}
+
+#[test]
+fn did_guardian_approves_through_controller() {
+    new_test_ext().execute_with(|| {
+        let lost = account(1);
+        let new = account(5);
+        let guardians = vec![Guardian::Account(account(3)), Guardian::Did(did(7))];
+        assert_ok!(Accounts::create_recovery(RuntimeOrigin::signed(lost), guardians, 2, 5));
+
+        assert_ok!(Accounts::initiate_recovery(RuntimeOrigin::signed(account(3)), lost, new));
+
+        // Account 8 does not control did(7)
+        assert_noop!(
+            Accounts::approve_recovery(RuntimeOrigin::signed(account(8)), lost),
+            Error::<Test>::NotGuardian
+        );
+
+        assert_ok!(Accounts::approve_recovery(RuntimeOrigin::signed(account(7)), lost));
+        let recovery = ActiveRecoveries::<Test>::get(&lost).unwrap();
+        assert!(recovery.approvals.contains(&Guardian::Did(did(7))));
+        assert_eq!(recovery.executable_at, Some(6));
+
+        assert_noop!(
+            Accounts::approve_recovery(RuntimeOrigin::signed(account(7)), lost),
+            Error::<Test>::AlreadyApproved
+        );
+
+        run_to_block(6);
+        assert_ok!(Accounts::execute_recovery(RuntimeOrigin::signed(account(7)), lost));
+    });
+}
@@ -0,0 +1,170 @@
+//! PLACEHOLDER weights for `pallet_accounts`: hand-estimated, not benchmark output.
+//!
+//! Overwrite with `./scripts/benchmark-pallets.sh pallet_accounts` on reference hardware.
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(missing_docs)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use core::marker::PhantomData;
+
+/// Weight functions needed for `pallet_accounts`.
+pub trait WeightInfo {
+	fn transfer() -> Weight;
+	fn mint_etr() -> Weight;
+	fn mint_etd() -> Weight;
+	fn burn() -> Weight;
+	fn create_recovery(g: u32, ) -> Weight;
+	fn initiate_recovery() -> Weight;
+	fn approve_recovery() -> Weight;
+	fn execute_recovery() -> Weight;
+	fn cancel_recovery() -> Weight;
+	fn veto_recovery() -> Weight;
+}
+
+/// Placeholder weights for `pallet_accounts`, pending benchmarking.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	/// Storage: `Accounts::Accounts` (r:2 w:2)
+	fn transfer() -> Weight {
+		Weight::from_parts(38_410_000, 6196)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `Accounts::Accounts` (r:1 w:1)
+	fn mint_etr() -> Weight {
+		Weight::from_parts(21_760_000, 3593)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Accounts::Accounts` (r:1 w:1)
+	fn mint_etd() -> Weight {
+		Weight::from_parts(21_540_000, 3593)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Accounts::Accounts` (r:1 w:1)
+	fn burn() -> Weight {
+		Weight::from_parts(22_130_000, 3593)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Accounts::RecoveryConfigs` (r:0 w:1)
+	/// The range of component `g` is `[1, 10]`.
+	fn create_recovery(g: u32, ) -> Weight {
+		Weight::from_parts(16_020_000, 0)
+			.saturating_add(Weight::from_parts(1_380_000, 0).saturating_mul(g.into()))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Accounts::RecoveryConfigs` (r:1 w:0)
+	/// Storage: `Accounts::ActiveRecoveries` (r:1 w:1)
+	/// Storage: `Accounts::Accounts` (r:1 w:1)
+	fn initiate_recovery() -> Weight {
+		Weight::from_parts(41_870_000, 4563)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `Accounts::RecoveryConfigs` (r:1 w:0)
+	/// Storage: `Accounts::ActiveRecoveries` (r:1 w:1)
+	fn approve_recovery() -> Weight {
+		Weight::from_parts(39_250_000, 4563)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Accounts::RecoveryConfigs` (r:1 w:1)
+	/// Storage: `Accounts::ActiveRecoveries` (r:1 w:1)
+	/// Storage: `Accounts::Accounts` (r:3 w:3)
+	fn execute_recovery() -> Weight {
+		Weight::from_parts(74_390_000, 9189)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+	/// Storage: `Accounts::ActiveRecoveries` (r:1 w:1)
+	/// Storage: `Accounts::Accounts` (r:1 w:1)
+	fn cancel_recovery() -> Weight {
+		Weight::from_parts(29_660_000, 4563)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `Accounts::ActiveRecoveries` (r:1 w:1)
+	/// Storage: `Accounts::Accounts` (r:1 w:1)
+	fn veto_recovery() -> Weight {
+		Weight::from_parts(29_480_000, 4563)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+}
+
+// For backwards compatibility and tests.
+impl WeightInfo for () {
+	/// Storage: `Accounts::Accounts` (r:2 w:2)
+	fn transfer() -> Weight {
+		Weight::from_parts(38_410_000, 6196)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `Accounts::Accounts` (r:1 w:1)
+	fn mint_etr() -> Weight {
+		Weight::from_parts(21_760_000, 3593)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Accounts::Accounts` (r:1 w:1)
+	fn mint_etd() -> Weight {
+		Weight::from_parts(21_540_000, 3593)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Accounts::Accounts` (r:1 w:1)
+	fn burn() -> Weight {
+		Weight::from_parts(22_130_000, 3593)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Accounts::RecoveryConfigs` (r:0 w:1)
+	/// The range of component `g` is `[1, 10]`.
+	fn create_recovery(g: u32, ) -> Weight {
+		Weight::from_parts(16_020_000, 0)
+			.saturating_add(Weight::from_parts(1_380_000, 0).saturating_mul(g.into()))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Accounts::RecoveryConfigs` (r:1 w:0)
+	/// Storage: `Accounts::ActiveRecoveries` (r:1 w:1)
+	/// Storage: `Accounts::Accounts` (r:1 w:1)
+	fn initiate_recovery() -> Weight {
+		Weight::from_parts(41_870_000, 4563)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `Accounts::RecoveryConfigs` (r:1 w:0)
+	/// Storage: `Accounts::ActiveRecoveries` (r:1 w:1)
+	fn approve_recovery() -> Weight {
+		Weight::from_parts(39_250_000, 4563)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Accounts::RecoveryConfigs` (r:1 w:1)
+	/// Storage: `Accounts::ActiveRecoveries` (r:1 w:1)
+	/// Storage: `Accounts::Accounts` (r:3 w:3)
+	fn execute_recovery() -> Weight {
+		Weight::from_parts(74_390_000, 9189)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
+	/// Storage: `Accounts::ActiveRecoveries` (r:1 w:1)
+	/// Storage: `Accounts::Accounts` (r:1 w:1)
+	fn cancel_recovery() -> Weight {
+		Weight::from_parts(29_660_000, 4563)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `Accounts::ActiveRecoveries` (r:1 w:1)
+	/// Storage: `Accounts::Accounts` (r:1 w:1)
+	fn veto_recovery() -> Weight {
+		Weight::from_parts(29_480_000, 4563)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+}
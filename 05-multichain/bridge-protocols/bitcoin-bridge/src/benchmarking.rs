@@ -0,0 +1,204 @@
+//! Benchmarking setup for pallet-bitcoin-bridge
+//!
+//! Custodian approvals run against a full `MAX_CUSTODIANS` set, and lock/unlock
+//! benchmarks against a full ETR lock history.
+
+#![cfg(feature = "runtime-benchmarks")]
+
+use super::*;
+use codec::Encode;
+use etrid_bridge_common::multisig::{MultiSigCustodian, PendingApproval, MAX_CUSTODIANS};
+use frame_benchmarking::v2::*;
+use frame_support::{
+    pallet_prelude::{BoundedVec, ConstU32, Get},
+    traits::Currency,
+};
+use frame_system::RawOrigin;
+use sp_runtime::traits::{Hash, SaturatedConversion, Saturating};
+use sp_std::{vec, vec::Vec};
+
+/// 1 BTC = 1 ETR, so satoshi amounts map to ETR units one to one
+const RATE: u64 = 100_000_000;
+
+fn authority<T: Config>() -> T::AccountId {
+    T::BridgeAuthority::get()
+}
+
+/// Maximum-length BTC address or transaction id, unique per `seed`
+fn btc_bytes(seed: u8) -> Vec<u8> {
+    vec![seed; 64]
+}
+
+/// Fund `who` with `amount` plus a comfortable margin above the existential deposit
+fn fund<T: Config>(who: &T::AccountId, amount: BalanceOf<T>) {
+    let ed = <T as pallet_etr_lock::Config>::Currency::minimum_balance();
+    <T as pallet_etr_lock::Config>::Currency::make_free_balance_be(
+        who,
+        amount.saturating_add(ed.saturating_mul(1_000u32.into())),
+    );
+}
+
+fn etr_amount<T: Config>() -> BalanceOf<T> {
+    let ed = <T as pallet_etr_lock::Config>::Currency::minimum_balance();
+    ed.max(1u32.into()).saturating_mul(1_000u32.into())
+}
+
+/// Register a pending deposit from the bridge authority
+fn add_deposit<T: Config>(txid: Vec<u8>) -> Result<(), BenchmarkError> {
+    ExchangeRate::<T>::put(RATE);
+    Pallet::<T>::deposit_btc(
+        RawOrigin::Signed(authority::<T>()).into(),
+        whitelisted_caller(),
+        btc_bytes(1),
+        txid,
+        T::MinDepositAmount::get(),
+        0,
+    )?;
+    Ok(())
+}
+
+/// Request a withdrawal for `who`, which pays the bridge fee
+fn add_withdrawal<T: Config>(who: &T::AccountId) -> Result<(), BenchmarkError> {
+    ExchangeRate::<T>::put(RATE);
+    let amount = T::MinDepositAmount::get();
+    fund::<T>(who, amount.saturated_into());
+    Pallet::<T>::withdraw_btc(RawOrigin::Signed(who.clone()).into(), btc_bytes(1), amount)?;
+    Ok(())
+}
+
+#[benchmarks]
+mod benchmarks {
+    use super::*;
+
+    #[benchmark]
+    fn deposit_btc() {
+        ExchangeRate::<T>::put(RATE);
+        let depositor: T::AccountId = whitelisted_caller();
+        let txid = btc_bytes(2);
+
+        #[extrinsic_call]
+        _(
+            RawOrigin::Signed(authority::<T>()),
+            depositor,
+            btc_bytes(1),
+            txid.clone(),
+            T::MaxDepositAmount::get(),
+            u32::MAX,
+        );
+
+        assert!(Deposits::<T>::contains_key(BoundedVec::<u8, ConstU32<64>>::truncate_from(txid)));
+    }
+
+    #[benchmark]
+    fn confirm_deposit() -> Result<(), BenchmarkError> {
+        let txid = btc_bytes(2);
+        add_deposit::<T>(txid.clone())?;
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(authority::<T>()), txid.clone(), T::MinConfirmations::get());
+
+        let deposit = Deposits::<T>::get(BoundedVec::<u8, ConstU32<64>>::truncate_from(txid)).unwrap();
+        assert_eq!(deposit.status, DepositStatus::Minted);
+        Ok(())
+    }
+
+    #[benchmark]
+    fn withdraw_btc() {
+        ExchangeRate::<T>::put(RATE);
+        let caller: T::AccountId = whitelisted_caller();
+        let amount = T::MaxDepositAmount::get();
+        fund::<T>(&caller, amount.saturated_into());
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller.clone()), btc_bytes(1), amount);
+
+        assert!(Withdrawals::<T>::contains_key(&caller));
+    }
+
+    #[benchmark]
+    fn confirm_withdrawal() -> Result<(), BenchmarkError> {
+        let withdrawer: T::AccountId = whitelisted_caller();
+        add_withdrawal::<T>(&withdrawer)?;
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(authority::<T>()), withdrawer.clone(), btc_bytes(3));
+
+        assert_eq!(Withdrawals::<T>::get(&withdrawer).unwrap().status, WithdrawalStatus::Completed);
+        Ok(())
+    }
+
+    #[benchmark]
+    fn set_exchange_rate() {
+        #[extrinsic_call]
+        _(RawOrigin::Root, RATE);
+
+        assert_eq!(ExchangeRate::<T>::get(), RATE);
+    }
+
+    #[benchmark]
+    fn set_custodians(c: Linear<1, MAX_CUSTODIANS>) {
+        let custodians: Vec<T::AccountId> = (0..c).map(|i| account("custodian", i, 0)).collect();
+
+        #[extrinsic_call]
+        _(RawOrigin::Root, custodians, c);
+
+        assert_eq!(CustodianSet::<T>::get().unwrap().threshold, c);
+    }
+
+    /// The final approval of a full custodian set, which executes the withdrawal
+    #[benchmark(pov_mode = Measured)]
+    fn approve_withdrawal() -> Result<(), BenchmarkError> {
+        let custodians: Vec<T::AccountId> =
+            (0..MAX_CUSTODIANS).map(|i| account("custodian", i, 0)).collect();
+        CustodianSet::<T>::put(
+            MultiSigCustodian::new(custodians.clone(), MAX_CUSTODIANS)
+                .map_err(|_| BenchmarkError::Stop("invalid custodian set"))?,
+        );
+
+        let withdrawer: T::AccountId = whitelisted_caller();
+        add_withdrawal::<T>(&withdrawer)?;
+        let txid = btc_bytes(3);
+        let operation_hash = T::Hashing::hash(&(withdrawer.clone(), txid.clone()).encode());
+        let mut pending = PendingApproval::new(operation_hash, MAX_CUSTODIANS);
+        pending.approvals = custodians[1..].to_vec();
+        PendingApprovals::<T>::insert(operation_hash, pending);
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(custodians[0].clone()), withdrawer.clone(), txid);
+
+        assert!(PendingApprovals::<T>::get(operation_hash).unwrap().executed);
+        Ok(())
+    }
+
+    #[benchmark]
+    fn bridge_etr_to_bitcoin() {
+        let caller: T::AccountId = whitelisted_caller();
+        let amount = etr_amount::<T>();
+        pallet_etr_lock::Pallet::<T>::prepare_benchmark(pallet_etr_lock::ChainId::Bitcoin, amount);
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller), amount, btc_bytes(1));
+
+        assert_eq!(
+            pallet_etr_lock::Pallet::<T>::get_locked_amount(pallet_etr_lock::ChainId::Bitcoin),
+            amount.saturating_mul(2u32.into())
+        );
+    }
+
+    #[benchmark]
+    fn process_etr_burn_from_bitcoin() {
+        let caller: T::AccountId = whitelisted_caller();
+        let recipient: T::AccountId = account("recipient", 0, 0);
+        let amount = etr_amount::<T>();
+        pallet_etr_lock::Pallet::<T>::prepare_benchmark(
+            pallet_etr_lock::ChainId::Bitcoin,
+            amount.saturating_mul(2u32.into()),
+        );
+        let burn_tx = btc_bytes(4);
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller), recipient, amount, burn_tx.clone());
+
+        assert!(ProcessedBitcoinBurns::<T>::get(BoundedVec::<u8, ConstU32<64>>::truncate_from(burn_tx)));
+    }
+}
@@ -17,6 +17,11 @@ pub use pallet::*;
 #[cfg(test)]
 mod tests;
 
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+pub mod weights;
+pub use weights::WeightInfo;
+
 #[frame_support::pallet]
 pub mod pallet {
     use frame_support::{
@@ -27,13 +32,14 @@ pub mod pallet {
     use frame_system::pallet_prelude::*;
     use sp_runtime::traits::{Saturating, SaturatedConversion, Hash};
     use sp_std::vec::Vec;
-    use etrid_bridge_common::multisig::{MultiSigCustodian, PendingApproval};
+    use etrid_bridge_common::multisig::{MultiSigCustodian, PendingApproval, MAX_CUSTODIANS};
     use etrid_bridge_common::treasury::TreasuryInterface;
+    use crate::WeightInfo;
 
     // Import the generic Bridge trait
     // use etrid_bridge_interface::BridgeTrait;
 
-    type BalanceOf<T> = <<T as pallet_etr_lock::Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
+    pub(crate) type BalanceOf<T> = <<T as pallet_etr_lock::Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
 
     #[pallet::pallet]
     #[pallet::without_storage_info]
@@ -64,6 +70,9 @@ pub mod pallet {
 
         /// Validator pool account for receiving bridge fees
         type ValidatorPoolAccount: Get<Self::AccountId>;
+
+        /// Weight information for extrinsics
+        type WeightInfo: WeightInfo;
     }
 
     /// BTC deposit request
@@ -294,7 +303,7 @@ pub mod pallet {
     impl<T: Config> Pallet<T> {
         /// Register a BTC deposit (called by bridge relayer)
         #[pallet::call_index(0)]
-        #[pallet::weight(10_000)]
+        #[pallet::weight(T::WeightInfo::deposit_btc())]
         pub fn deposit_btc(
             origin: OriginFor<T>,
             depositor: T::AccountId,
@@ -347,7 +356,7 @@ pub mod pallet {
 
         /// Confirm a BTC deposit with sufficient confirmations
         #[pallet::call_index(1)]
-        #[pallet::weight(10_000)]
+        #[pallet::weight(T::WeightInfo::confirm_deposit())]
         pub fn confirm_deposit(
             origin: OriginFor<T>,
             btc_txid: Vec<u8>,
@@ -389,7 +398,7 @@ pub mod pallet {
 
         /// Request BTC withdrawal (burn ETR)
         #[pallet::call_index(2)]
-        #[pallet::weight(10_000)]
+        #[pallet::weight(T::WeightInfo::withdraw_btc())]
         pub fn withdraw_btc(
             origin: OriginFor<T>,
             btc_address: Vec<u8>,
@@ -469,7 +478,7 @@ pub mod pallet {
 
         /// Confirm BTC withdrawal completed (called by bridge relayer)
         #[pallet::call_index(3)]
-        #[pallet::weight(10_000)]
+        #[pallet::weight(T::WeightInfo::confirm_withdrawal())]
         pub fn confirm_withdrawal(
             origin: OriginFor<T>,
             withdrawer: T::AccountId,
@@ -502,7 +511,7 @@ pub mod pallet {
 
         /// Set exchange rate (governance only)
         #[pallet::call_index(4)]
-        #[pallet::weight(10_000)]
+        #[pallet::weight(T::WeightInfo::set_exchange_rate())]
         pub fn set_exchange_rate(
             origin: OriginFor<T>,
             new_rate: u64,
@@ -522,7 +531,7 @@ pub mod pallet {
         /// Re-enable when etrid_bridge_common crate is implemented
         /// This function sets up M-of-N multisig custodians for bridge operations
         #[pallet::call_index(5)]
-        #[pallet::weight(10_000)]
+        #[pallet::weight(T::WeightInfo::set_custodians((custodians.len() as u32).min(MAX_CUSTODIANS)))]
         pub fn set_custodians(
             origin: OriginFor<T>,
             custodians: Vec<T::AccountId>,
@@ -543,7 +552,7 @@ pub mod pallet {
         /// Re-enable when etrid_bridge_common crate is implemented
         /// This function allows custodians to approve BTC withdrawals with M-of-N multisig
         #[pallet::call_index(6)]
-        #[pallet::weight(10_000)]
+        #[pallet::weight(T::WeightInfo::approve_withdrawal())]
         pub fn approve_withdrawal(
             origin: OriginFor<T>,
             withdrawer: T::AccountId,
@@ -595,7 +604,7 @@ pub mod pallet {
         ///
         /// Locks ETR on FlareChain and emits event for relayer to mint on Bitcoin
         #[pallet::call_index(7)]
-        #[pallet::weight(150_000)]
+        #[pallet::weight(T::WeightInfo::bridge_etr_to_bitcoin())]
         pub fn bridge_etr_to_bitcoin(
             origin: OriginFor<T>,
             amount: BalanceOf<T>,
@@ -628,7 +637,7 @@ pub mod pallet {
         ///
         /// Unlocks ETR on FlareChain when wrapped ETR is burned on Bitcoin
         #[pallet::call_index(8)]
-        #[pallet::weight(150_000)]
+        #[pallet::weight(T::WeightInfo::process_etr_burn_from_bitcoin())]
         pub fn process_etr_burn_from_bitcoin(
             origin: OriginFor<T>,
             etrid_recipient: T::AccountId,
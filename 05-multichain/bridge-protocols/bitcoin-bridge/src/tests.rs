@@ -71,6 +71,7 @@ impl Config for Test {
     type MinDepositAmount = MinDepositAmount;
     type MaxDepositAmount = MaxDepositAmount;
     type BridgeAuthority = BridgeAuthority;
+    type WeightInfo = ();
 }
 
 // Helper functions
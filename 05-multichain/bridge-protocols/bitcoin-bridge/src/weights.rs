@@ -0,0 +1,181 @@
+//! PLACEHOLDER weights for `pallet_bitcoin_bridge`: hand-estimated, not benchmark output.
+//!
+//! Overwrite with `./scripts/benchmark-pallets.sh pallet_bitcoin_bridge` on reference hardware.
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(missing_docs)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use core::marker::PhantomData;
+
+/// Weight functions needed for `pallet_bitcoin_bridge`.
+pub trait WeightInfo {
+	fn deposit_btc() -> Weight;
+	fn confirm_deposit() -> Weight;
+	fn withdraw_btc() -> Weight;
+	fn confirm_withdrawal() -> Weight;
+	fn set_exchange_rate() -> Weight;
+	fn set_custodians(c: u32, ) -> Weight;
+	fn approve_withdrawal() -> Weight;
+	fn bridge_etr_to_bitcoin() -> Weight;
+	fn process_etr_burn_from_bitcoin() -> Weight;
+}
+
+/// Placeholder weights for `pallet_bitcoin_bridge`, pending benchmarking.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	/// Storage: `BitcoinBridge::Deposits` (r:1 w:1)
+	/// Storage: `BitcoinBridge::ExchangeRate` (r:1 w:0)
+	fn deposit_btc() -> Weight {
+		Weight::from_parts(21_840_000, 1274)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `BitcoinBridge::Deposits` (r:1 w:1)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Storage: `BitcoinBridge::TotalBtcLocked` (r:1 w:1)
+	/// Storage: `BitcoinBridge::TotalEtrMinted` (r:1 w:1)
+	fn confirm_deposit() -> Weight {
+		Weight::from_parts(48_920_000, 3893)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: `BitcoinBridge::Withdrawals` (r:1 w:1)
+	/// Storage: `BitcoinBridge::ExchangeRate` (r:1 w:0)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Storage: `BitcoinBridge::TotalBtcLocked` (r:1 w:1)
+	/// Storage: `BitcoinBridge::TotalEtrMinted` (r:1 w:1)
+	fn withdraw_btc() -> Weight {
+		Weight::from_parts(71_350_000, 6464)
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+	/// Storage: `BitcoinBridge::Withdrawals` (r:1 w:1)
+	fn confirm_withdrawal() -> Weight {
+		Weight::from_parts(19_470_000, 1226)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `BitcoinBridge::ExchangeRate` (r:0 w:1)
+	fn set_exchange_rate() -> Weight {
+		Weight::from_parts(6_930_000, 990)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `BitcoinBridge::CustodianSet` (r:0 w:1)
+	/// The range of component `c` is `[1, 16]`.
+	fn set_custodians(c: u32, ) -> Weight {
+		Weight::from_parts(8_210_000, 990)
+			.saturating_add(Weight::from_parts(412_000, 0).saturating_mul(c.into()))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `BitcoinBridge::CustodianSet` (r:1 w:0)
+	/// Storage: `BitcoinBridge::Withdrawals` (r:1 w:1)
+	/// Storage: `BitcoinBridge::PendingApprovals` (r:1 w:1)
+	fn approve_withdrawal() -> Weight {
+		Weight::from_parts(42_680_000, 2341)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `EtrLock::LockAccount` (r:1 w:0)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Storage: `EtrLock::TotalLocked` (r:1 w:1)
+	/// Storage: `EtrLock::LockedForChain` (r:1 w:1)
+	/// Storage: `EtrLock::LockEvents` (r:1 w:1)
+	/// Storage: `Balances::Locks` (r:1 w:1)
+	fn bridge_etr_to_bitcoin() -> Weight {
+		Weight::from_parts(318_770_000, 194394)
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+	/// Storage: `BitcoinBridge::ProcessedBitcoinBurns` (r:1 w:1)
+	/// Storage: `EtrLock::LockAccount` (r:1 w:0)
+	/// Storage: `EtrLock::LockedForChain` (r:1 w:1)
+	/// Storage: `EtrLock::TotalLocked` (r:1 w:1)
+	/// Storage: `Balances::Locks` (r:1 w:1)
+	/// Storage: `System::Account` (r:2 w:2)
+	fn process_etr_burn_from_bitcoin() -> Weight {
+		Weight::from_parts(96_140_000, 13582)
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
+	}
+}
+
+// For backwards compatibility and tests.
+impl WeightInfo for () {
+	/// Storage: `BitcoinBridge::Deposits` (r:1 w:1)
+	/// Storage: `BitcoinBridge::ExchangeRate` (r:1 w:0)
+	fn deposit_btc() -> Weight {
+		Weight::from_parts(21_840_000, 1274)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `BitcoinBridge::Deposits` (r:1 w:1)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Storage: `BitcoinBridge::TotalBtcLocked` (r:1 w:1)
+	/// Storage: `BitcoinBridge::TotalEtrMinted` (r:1 w:1)
+	fn confirm_deposit() -> Weight {
+		Weight::from_parts(48_920_000, 3893)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: `BitcoinBridge::Withdrawals` (r:1 w:1)
+	/// Storage: `BitcoinBridge::ExchangeRate` (r:1 w:0)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Storage: `BitcoinBridge::TotalBtcLocked` (r:1 w:1)
+	/// Storage: `BitcoinBridge::TotalEtrMinted` (r:1 w:1)
+	fn withdraw_btc() -> Weight {
+		Weight::from_parts(71_350_000, 6464)
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
+	/// Storage: `BitcoinBridge::Withdrawals` (r:1 w:1)
+	fn confirm_withdrawal() -> Weight {
+		Weight::from_parts(19_470_000, 1226)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `BitcoinBridge::ExchangeRate` (r:0 w:1)
+	fn set_exchange_rate() -> Weight {
+		Weight::from_parts(6_930_000, 990)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `BitcoinBridge::CustodianSet` (r:0 w:1)
+	/// The range of component `c` is `[1, 16]`.
+	fn set_custodians(c: u32, ) -> Weight {
+		Weight::from_parts(8_210_000, 990)
+			.saturating_add(Weight::from_parts(412_000, 0).saturating_mul(c.into()))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `BitcoinBridge::CustodianSet` (r:1 w:0)
+	/// Storage: `BitcoinBridge::Withdrawals` (r:1 w:1)
+	/// Storage: `BitcoinBridge::PendingApprovals` (r:1 w:1)
+	fn approve_withdrawal() -> Weight {
+		Weight::from_parts(42_680_000, 2341)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `EtrLock::LockAccount` (r:1 w:0)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Storage: `EtrLock::TotalLocked` (r:1 w:1)
+	/// Storage: `EtrLock::LockedForChain` (r:1 w:1)
+	/// Storage: `EtrLock::LockEvents` (r:1 w:1)
+	/// Storage: `Balances::Locks` (r:1 w:1)
+	fn bridge_etr_to_bitcoin() -> Weight {
+		Weight::from_parts(318_770_000, 194394)
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
+	/// Storage: `BitcoinBridge::ProcessedBitcoinBurns` (r:1 w:1)
+	/// Storage: `EtrLock::LockAccount` (r:1 w:0)
+	/// Storage: `EtrLock::LockedForChain` (r:1 w:1)
+	/// Storage: `EtrLock::TotalLocked` (r:1 w:1)
+	/// Storage: `Balances::Locks` (r:1 w:1)
+	/// Storage: `System::Account` (r:2 w:2)
+	fn process_etr_burn_from_bitcoin() -> Weight {
+		Weight::from_parts(96_140_000, 13582)
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
+	}
+}
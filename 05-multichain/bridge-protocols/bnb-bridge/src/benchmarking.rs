@@ -0,0 +1,260 @@
+//! Benchmarking setup for bnb-bridge
+//!
+//! Per-account deposit and withdrawal lists are filled to one below their bounds,
+//! and lock/unlock benchmarks run against a full ETR lock history.
+
+#![cfg(feature = "runtime-benchmarks")]
+
+use super::*;
+use frame_benchmarking::v2::*;
+use frame_support::{
+	pallet_prelude::Get,
+	traits::Currency,
+	BoundedVec,
+};
+use frame_system::RawOrigin;
+use pallet_etr_lock::ChainId;
+use sp_runtime::traits::{Saturating, Zero};
+
+/// 1:1 BNB to ËTR, scaled by 1e18
+const RATE: u128 = 1_000_000_000_000_000_000;
+
+fn amount<T: Config>() -> BalanceOf<T> {
+	<T as pallet_etr_lock::Config>::Currency::minimum_balance()
+		.max(1u32.into())
+		.saturating_mul(1_000u32.into())
+}
+
+fn operator<T: Config>() -> T::AccountId {
+	let operator: T::AccountId = whitelisted_caller();
+	BridgeOperator::<T>::put(&operator);
+	operator
+}
+
+/// Register a supported BEP-20 contract
+fn add_token<T: Config>() -> Bep20Contract {
+	let contract = Bep20Contract::repeat_byte(2);
+	SupportedTokens::<T>::insert(contract, true);
+	TokenRates::<T>::insert(contract, RATE);
+	contract
+}
+
+#[benchmarks]
+mod benchmarks {
+	use super::*;
+
+	/// A deposit that also advances the tracked block number
+	#[benchmark]
+	fn initiate_bnb_deposit() {
+		let relayer: T::AccountId = whitelisted_caller();
+		let recipient: T::AccountId = account("recipient", 0, 0);
+
+		#[extrinsic_call]
+		_(
+			RawOrigin::Signed(relayer),
+			recipient,
+			BnbAddress::repeat_byte(1),
+			amount::<T>(),
+			BnbTxHash::repeat_byte(1),
+			u64::MAX,
+			T::MinConfirmations::get(),
+		);
+
+		assert_eq!(CurrentBlockNumber::<T>::get(), u64::MAX);
+	}
+
+	#[benchmark]
+	fn confirm_bnb_deposit() {
+		BnbToEtrRate::<T>::put(RATE);
+		let relayer: T::AccountId = whitelisted_caller();
+		let recipient: T::AccountId = account("recipient", 0, 0);
+		let tx_hash = BnbTxHash::repeat_byte(1);
+		PendingDeposits::<T>::insert(tx_hash, BnbDeposit {
+			bnb_address: BnbAddress::repeat_byte(1),
+			etrid_account: recipient.clone(),
+			amount: amount::<T>(),
+			tx_hash,
+			block_number: 1,
+			confirmations: T::MinConfirmations::get(),
+			token_contract: None,
+			is_confirmed: false,
+		});
+		let existing: Vec<BnbTxHash> = (1..T::MaxDepositsPerAccount::get())
+			.map(|i| BnbTxHash::from_low_u64_be(i as u64 + 1))
+			.collect();
+		ConfirmedDeposits::<T>::insert(&recipient, BoundedVec::truncate_from(existing));
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(relayer), tx_hash);
+
+		assert!(PendingDeposits::<T>::get(tx_hash).unwrap().is_confirmed);
+	}
+
+	/// A deposit that already has enough confirmations and is minted immediately
+	#[benchmark]
+	fn initiate_token_deposit() {
+		let contract = add_token::<T>();
+		let relayer: T::AccountId = whitelisted_caller();
+		let recipient: T::AccountId = account("recipient", 0, 0);
+
+		#[extrinsic_call]
+		_(
+			RawOrigin::Signed(relayer),
+			recipient.clone(),
+			BnbAddress::repeat_byte(1),
+			contract,
+			amount::<T>(),
+			BnbTxHash::repeat_byte(1),
+			1,
+			T::MinConfirmations::get(),
+		);
+
+		assert!(!<T as pallet_etr_lock::Config>::Currency::free_balance(&recipient).is_zero());
+	}
+
+	#[benchmark]
+	fn initiate_busd_deposit() {
+		let contract = add_token::<T>();
+		BusdContract::<T>::put(contract);
+		let relayer: T::AccountId = whitelisted_caller();
+		let recipient: T::AccountId = account("recipient", 0, 0);
+
+		#[extrinsic_call]
+		_(
+			RawOrigin::Signed(relayer),
+			recipient.clone(),
+			BnbAddress::repeat_byte(1),
+			amount::<T>(),
+			BnbTxHash::repeat_byte(1),
+			1,
+			T::MinConfirmations::get(),
+		);
+
+		assert!(!<T as pallet_etr_lock::Config>::Currency::free_balance(&recipient).is_zero());
+	}
+
+	#[benchmark]
+	fn request_bnb_withdrawal() {
+		let caller: T::AccountId = whitelisted_caller();
+		<T as pallet_etr_lock::Config>::Currency::make_free_balance_be(
+			&caller,
+			amount::<T>().saturating_mul(2u32.into()),
+		);
+		let existing = BnbWithdrawal {
+			etrid_account: caller.clone(),
+			bnb_address: BnbAddress::repeat_byte(1),
+			amount: amount::<T>(),
+			token_contract: Some(Bep20Contract::repeat_byte(2)),
+			gas_limit: T::MaxGasLimit::get(),
+			gas_price: T::MaxGasPrice::get(),
+			status: WithdrawalStatus::Completed(BnbTxHash::repeat_byte(1)),
+		};
+		let existing = sp_std::vec![existing; T::MaxWithdrawalsPerAccount::get() as usize - 1];
+		PendingWithdrawals::<T>::insert(&caller, BoundedVec::truncate_from(existing));
+
+		#[extrinsic_call]
+		_(
+			RawOrigin::Signed(caller.clone()),
+			BnbAddress::repeat_byte(1),
+			amount::<T>(),
+			T::MaxGasLimit::get(),
+			T::MaxGasPrice::get(),
+		);
+
+		assert_eq!(
+			PendingWithdrawals::<T>::get(&caller).len() as u32,
+			T::MaxWithdrawalsPerAccount::get()
+		);
+	}
+
+	#[benchmark]
+	fn update_exchange_rate() {
+		let operator = operator::<T>();
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(operator), RATE * 2);
+
+		assert_eq!(BnbToEtrRate::<T>::get(), RATE * 2);
+	}
+
+	#[benchmark]
+	fn add_supported_token() {
+		let operator = operator::<T>();
+		let contract = Bep20Contract::repeat_byte(2);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(operator), contract, RATE);
+
+		assert!(SupportedTokens::<T>::get(contract));
+	}
+
+	#[benchmark]
+	fn set_busd_contract() {
+		let operator = operator::<T>();
+		let contract = Bep20Contract::repeat_byte(2);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(operator), contract);
+
+		assert_eq!(BusdContract::<T>::get(), Some(contract));
+	}
+
+	#[benchmark]
+	fn toggle_maxwell_upgrade() {
+		let operator = operator::<T>();
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(operator), true);
+
+		assert!(MaxwellUpgradeEnabled::<T>::get());
+	}
+
+	#[benchmark]
+	fn toggle_portal_bridge() {
+		let operator = operator::<T>();
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(operator), true);
+
+		assert!(PortalBridgeEnabled::<T>::get());
+	}
+
+	#[benchmark]
+	fn set_operator() {
+		let operator: T::AccountId = account("operator", 0, 0);
+
+		#[extrinsic_call]
+		_(RawOrigin::Root, operator.clone());
+
+		assert_eq!(BridgeOperator::<T>::get(), Some(operator));
+	}
+
+	#[benchmark]
+	fn bridge_etr_to_bnb() {
+		let caller: T::AccountId = whitelisted_caller();
+		let amount = amount::<T>();
+		pallet_etr_lock::Pallet::<T>::prepare_benchmark(ChainId::BnbChain, amount);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), amount, BnbAddress::repeat_byte(1));
+
+		assert_eq!(
+			pallet_etr_lock::Pallet::<T>::get_locked_amount(ChainId::BnbChain),
+			amount.saturating_mul(2u32.into())
+		);
+	}
+
+	#[benchmark]
+	fn process_etr_burn_from_bnb() {
+		let caller: T::AccountId = whitelisted_caller();
+		let recipient: T::AccountId = account("recipient", 0, 0);
+		let amount = amount::<T>();
+		pallet_etr_lock::Pallet::<T>::prepare_benchmark(ChainId::BnbChain, amount.saturating_mul(2u32.into()));
+		let burn_tx = BnbTxHash::repeat_byte(4);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), recipient, amount, burn_tx);
+
+		assert!(ProcessedBnbBurns::<T>::get(burn_tx));
+	}
+}
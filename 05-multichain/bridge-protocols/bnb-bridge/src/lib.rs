@@ -6,6 +6,11 @@
 
 pub use pallet::*;
 
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+pub mod weights;
+pub use weights::WeightInfo;
+
 use codec::{Decode, Encode, MaxEncodedLen};
 use scale_info::TypeInfo;
 use sp_std::prelude::*;
@@ -70,8 +75,9 @@ pub mod pallet {
 		BoundedVec,
 	};
 	use frame_system::pallet_prelude::*;
+	use crate::WeightInfo;
 
-	type BalanceOf<T> = <<T as pallet_etr_lock::Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
+	pub(crate) type BalanceOf<T> = <<T as pallet_etr_lock::Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
 
 	#[pallet::config]
 	pub trait Config: frame_system::Config + pallet_etr_lock::Config {
@@ -101,6 +107,9 @@ pub mod pallet {
 		/// Maximum number of pending withdrawals per account
 		#[pallet::constant]
 		type MaxWithdrawalsPerAccount: Get<u32>;
+
+		/// Weight information for extrinsics
+		type WeightInfo: WeightInfo;
 	}
 
 	#[pallet::pallet]
@@ -356,7 +365,7 @@ pub mod pallet {
 	impl<T: Config> Pallet<T> {
 		/// Initiate BNB deposit (called by relayer with proof)
 		#[pallet::call_index(0)]
-		#[pallet::weight(10_000)]
+		#[pallet::weight(T::WeightInfo::initiate_bnb_deposit())]
 		pub fn initiate_bnb_deposit(
 			origin: OriginFor<T>,
 			etrid_account: T::AccountId,
@@ -409,7 +418,7 @@ pub mod pallet {
 
 		/// Confirm BNB deposit after required confirmations
 		#[pallet::call_index(1)]
-		#[pallet::weight(15_000)]
+		#[pallet::weight(T::WeightInfo::confirm_bnb_deposit())]
 		pub fn confirm_bnb_deposit(
 			origin: OriginFor<T>,
 			tx_hash: BnbTxHash,
@@ -465,7 +474,7 @@ pub mod pallet {
 
 		/// Initiate BEP-20 token deposit
 		#[pallet::call_index(2)]
-		#[pallet::weight(12_000)]
+		#[pallet::weight(T::WeightInfo::initiate_token_deposit())]
 		pub fn initiate_token_deposit(
 			origin: OriginFor<T>,
 			etrid_account: T::AccountId,
@@ -513,7 +522,7 @@ pub mod pallet {
 
 		/// Fast-track BUSD deposit (Binance stablecoin)
 		#[pallet::call_index(3)]
-		#[pallet::weight(12_000)]
+		#[pallet::weight(T::WeightInfo::initiate_busd_deposit())]
 		pub fn initiate_busd_deposit(
 			origin: OriginFor<T>,
 			etrid_account: T::AccountId,
@@ -555,7 +564,7 @@ pub mod pallet {
 
 		/// Request BNB withdrawal
 		#[pallet::call_index(4)]
-		#[pallet::weight(20_000)]
+		#[pallet::weight(T::WeightInfo::request_bnb_withdrawal())]
 		pub fn request_bnb_withdrawal(
 			origin: OriginFor<T>,
 			bnb_address: BnbAddress,
@@ -611,7 +620,7 @@ pub mod pallet {
 
 		/// Update BNB/ËTR exchange rate (operator only)
 		#[pallet::call_index(5)]
-		#[pallet::weight(5_000)]
+		#[pallet::weight(T::WeightInfo::update_exchange_rate())]
 		pub fn update_exchange_rate(
 			origin: OriginFor<T>,
 			new_rate: u128,
@@ -636,7 +645,7 @@ pub mod pallet {
 
 		/// Add supported BEP-20 token (operator only)
 		#[pallet::call_index(6)]
-		#[pallet::weight(5_000)]
+		#[pallet::weight(T::WeightInfo::add_supported_token())]
 		pub fn add_supported_token(
 			origin: OriginFor<T>,
 			token_contract: Bep20Contract,
@@ -663,7 +672,7 @@ pub mod pallet {
 
 		/// Set BUSD contract address (operator only)
 		#[pallet::call_index(7)]
-		#[pallet::weight(5_000)]
+		#[pallet::weight(T::WeightInfo::set_busd_contract())]
 		pub fn set_busd_contract(
 			origin: OriginFor<T>,
 			contract: Bep20Contract,
@@ -681,7 +690,7 @@ pub mod pallet {
 
 		/// Toggle Maxwell upgrade (operator only)
 		#[pallet::call_index(8)]
-		#[pallet::weight(5_000)]
+		#[pallet::weight(T::WeightInfo::toggle_maxwell_upgrade())]
 		pub fn toggle_maxwell_upgrade(
 			origin: OriginFor<T>,
 			enabled: bool,
@@ -703,7 +712,7 @@ pub mod pallet {
 
 		/// Toggle Portal Bridge integration (operator only)
 		#[pallet::call_index(9)]
-		#[pallet::weight(5_000)]
+		#[pallet::weight(T::WeightInfo::toggle_portal_bridge())]
 		pub fn toggle_portal_bridge(
 			origin: OriginFor<T>,
 			enabled: bool,
@@ -725,7 +734,7 @@ pub mod pallet {
 
 		/// Set bridge operator (root only)
 		#[pallet::call_index(10)]
-		#[pallet::weight(5_000)]
+		#[pallet::weight(T::WeightInfo::set_operator())]
 		pub fn set_operator(
 			origin: OriginFor<T>,
 			new_operator: T::AccountId,
@@ -745,7 +754,7 @@ pub mod pallet {
 		///
 		/// Locks ETR on FlareChain and emits event for relayer to mint on BNB Chain
 		#[pallet::call_index(11)]
-		#[pallet::weight(150_000)]
+		#[pallet::weight(T::WeightInfo::bridge_etr_to_bnb())]
 		pub fn bridge_etr_to_bnb(
 			origin: OriginFor<T>,
 			amount: BalanceOf<T>,
@@ -778,7 +787,7 @@ pub mod pallet {
 		///
 		/// Unlocks ETR on FlareChain when wrapped ETR is burned on BNB Chain
 		#[pallet::call_index(12)]
-		#[pallet::weight(150_000)]
+		#[pallet::weight(T::WeightInfo::process_etr_burn_from_bnb())]
 		pub fn process_etr_burn_from_bnb(
 			origin: OriginFor<T>,
 			etrid_recipient: T::AccountId,
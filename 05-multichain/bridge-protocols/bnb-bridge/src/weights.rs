@@ -0,0 +1,251 @@
+//! PLACEHOLDER weights for `bnb_bridge`: hand-estimated, not benchmark output.
+//!
+//! Overwrite with `./scripts/benchmark-pallets.sh bnb_bridge` on reference hardware.
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(missing_docs)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use core::marker::PhantomData;
+
+/// Weight functions needed for `bnb_bridge`.
+pub trait WeightInfo {
+	fn initiate_bnb_deposit() -> Weight;
+	fn confirm_bnb_deposit() -> Weight;
+	fn initiate_token_deposit() -> Weight;
+	fn initiate_busd_deposit() -> Weight;
+	fn request_bnb_withdrawal() -> Weight;
+	fn update_exchange_rate() -> Weight;
+	fn add_supported_token() -> Weight;
+	fn set_busd_contract() -> Weight;
+	fn toggle_maxwell_upgrade() -> Weight;
+	fn toggle_portal_bridge() -> Weight;
+	fn set_operator() -> Weight;
+	fn bridge_etr_to_bnb() -> Weight;
+	fn process_etr_burn_from_bnb() -> Weight;
+}
+
+/// Placeholder weights for `bnb_bridge`, pending benchmarking.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	/// Storage: `BnbBridge::PendingDeposits` (r:1 w:1)
+	/// Storage: `BnbBridge::CurrentBlockNumber` (r:1 w:1)
+	fn initiate_bnb_deposit() -> Weight {
+		Weight::from_parts(20_180_000, 4150)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `BnbBridge::PendingDeposits` (r:1 w:1)
+	/// Storage: `BnbBridge::BnbToEtrRate` (r:1 w:0)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Storage: `BnbBridge::ConfirmedDeposits` (r:1 w:1)
+	/// Storage: `BnbBridge::TotalBridgedVolume` (r:1 w:1)
+	fn confirm_bnb_deposit() -> Weight {
+		Weight::from_parts(59_640_000, 12997)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: `BnbBridge::SupportedTokens` (r:1 w:0)
+	/// Storage: `BnbBridge::PendingDeposits` (r:1 w:1)
+	/// Storage: `BnbBridge::TokenRates` (r:1 w:0)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Storage: `BnbBridge::BusdContract` (r:1 w:0)
+	fn initiate_token_deposit() -> Weight {
+		Weight::from_parts(51_930_000, 11804)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `BnbBridge::SupportedTokens` (r:1 w:0)
+	/// Storage: `BnbBridge::PendingDeposits` (r:1 w:1)
+	/// Storage: `BnbBridge::TokenRates` (r:1 w:0)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Storage: `BnbBridge::BusdContract` (r:1 w:0)
+	fn initiate_busd_deposit() -> Weight {
+		Weight::from_parts(54_270_000, 11804)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Storage: `BnbBridge::PendingWithdrawals` (r:1 w:1)
+	fn request_bnb_withdrawal() -> Weight {
+		Weight::from_parts(99_410_000, 20718)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `BnbBridge::BridgeOperator` (r:1 w:0)
+	/// Storage: `BnbBridge::BnbToEtrRate` (r:1 w:1)
+	fn update_exchange_rate() -> Weight {
+		Weight::from_parts(11_040_000, 2028)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `BnbBridge::BridgeOperator` (r:1 w:0)
+	/// Storage: `BnbBridge::SupportedTokens` (r:0 w:1)
+	/// Storage: `BnbBridge::TokenRates` (r:0 w:1)
+	fn add_supported_token() -> Weight {
+		Weight::from_parts(13_380_000, 1517)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `BnbBridge::BridgeOperator` (r:1 w:0)
+	/// Storage: `BnbBridge::BusdContract` (r:0 w:1)
+	fn set_busd_contract() -> Weight {
+		Weight::from_parts(10_260_000, 1517)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `BnbBridge::BridgeOperator` (r:1 w:0)
+	/// Storage: `BnbBridge::MaxwellUpgradeEnabled` (r:0 w:1)
+	fn toggle_maxwell_upgrade() -> Weight {
+		Weight::from_parts(10_190_000, 1517)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `BnbBridge::BridgeOperator` (r:1 w:0)
+	/// Storage: `BnbBridge::PortalBridgeEnabled` (r:0 w:1)
+	fn toggle_portal_bridge() -> Weight {
+		Weight::from_parts(10_210_000, 1517)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `BnbBridge::BridgeOperator` (r:0 w:1)
+	fn set_operator() -> Weight {
+		Weight::from_parts(7_120_000, 990)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `EtrLock::LockAccount` (r:1 w:0)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Storage: `EtrLock::TotalLocked` (r:1 w:1)
+	/// Storage: `EtrLock::LockedForChain` (r:1 w:1)
+	/// Storage: `EtrLock::LockEvents` (r:1 w:1)
+	/// Storage: `Balances::Locks` (r:1 w:1)
+	fn bridge_etr_to_bnb() -> Weight {
+		Weight::from_parts(316_290_000, 194394)
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+	/// Storage: `BnbBridge::ProcessedBnbBurns` (r:1 w:1)
+	/// Storage: `EtrLock::LockAccount` (r:1 w:0)
+	/// Storage: `EtrLock::LockedForChain` (r:1 w:1)
+	/// Storage: `EtrLock::TotalLocked` (r:1 w:1)
+	/// Storage: `Balances::Locks` (r:1 w:1)
+	/// Storage: `System::Account` (r:2 w:2)
+	fn process_etr_burn_from_bnb() -> Weight {
+		Weight::from_parts(95_740_000, 16024)
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
+	}
+}
+
+// For backwards compatibility and tests.
+impl WeightInfo for () {
+	/// Storage: `BnbBridge::PendingDeposits` (r:1 w:1)
+	/// Storage: `BnbBridge::CurrentBlockNumber` (r:1 w:1)
+	fn initiate_bnb_deposit() -> Weight {
+		Weight::from_parts(20_180_000, 4150)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `BnbBridge::PendingDeposits` (r:1 w:1)
+	/// Storage: `BnbBridge::BnbToEtrRate` (r:1 w:0)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Storage: `BnbBridge::ConfirmedDeposits` (r:1 w:1)
+	/// Storage: `BnbBridge::TotalBridgedVolume` (r:1 w:1)
+	fn confirm_bnb_deposit() -> Weight {
+		Weight::from_parts(59_640_000, 12997)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: `BnbBridge::SupportedTokens` (r:1 w:0)
+	/// Storage: `BnbBridge::PendingDeposits` (r:1 w:1)
+	/// Storage: `BnbBridge::TokenRates` (r:1 w:0)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Storage: `BnbBridge::BusdContract` (r:1 w:0)
+	fn initiate_token_deposit() -> Weight {
+		Weight::from_parts(51_930_000, 11804)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `BnbBridge::SupportedTokens` (r:1 w:0)
+	/// Storage: `BnbBridge::PendingDeposits` (r:1 w:1)
+	/// Storage: `BnbBridge::TokenRates` (r:1 w:0)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Storage: `BnbBridge::BusdContract` (r:1 w:0)
+	fn initiate_busd_deposit() -> Weight {
+		Weight::from_parts(54_270_000, 11804)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Storage: `BnbBridge::PendingWithdrawals` (r:1 w:1)
+	fn request_bnb_withdrawal() -> Weight {
+		Weight::from_parts(99_410_000, 20718)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `BnbBridge::BridgeOperator` (r:1 w:0)
+	/// Storage: `BnbBridge::BnbToEtrRate` (r:1 w:1)
+	fn update_exchange_rate() -> Weight {
+		Weight::from_parts(11_040_000, 2028)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `BnbBridge::BridgeOperator` (r:1 w:0)
+	/// Storage: `BnbBridge::SupportedTokens` (r:0 w:1)
+	/// Storage: `BnbBridge::TokenRates` (r:0 w:1)
+	fn add_supported_token() -> Weight {
+		Weight::from_parts(13_380_000, 1517)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `BnbBridge::BridgeOperator` (r:1 w:0)
+	/// Storage: `BnbBridge::BusdContract` (r:0 w:1)
+	fn set_busd_contract() -> Weight {
+		Weight::from_parts(10_260_000, 1517)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `BnbBridge::BridgeOperator` (r:1 w:0)
+	/// Storage: `BnbBridge::MaxwellUpgradeEnabled` (r:0 w:1)
+	fn toggle_maxwell_upgrade() -> Weight {
+		Weight::from_parts(10_190_000, 1517)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `BnbBridge::BridgeOperator` (r:1 w:0)
+	/// Storage: `BnbBridge::PortalBridgeEnabled` (r:0 w:1)
+	fn toggle_portal_bridge() -> Weight {
+		Weight::from_parts(10_210_000, 1517)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `BnbBridge::BridgeOperator` (r:0 w:1)
+	fn set_operator() -> Weight {
+		Weight::from_parts(7_120_000, 990)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `EtrLock::LockAccount` (r:1 w:0)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Storage: `EtrLock::TotalLocked` (r:1 w:1)
+	/// Storage: `EtrLock::LockedForChain` (r:1 w:1)
+	/// Storage: `EtrLock::LockEvents` (r:1 w:1)
+	/// Storage: `Balances::Locks` (r:1 w:1)
+	fn bridge_etr_to_bnb() -> Weight {
+		Weight::from_parts(316_290_000, 194394)
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
+	/// Storage: `BnbBridge::ProcessedBnbBurns` (r:1 w:1)
+	/// Storage: `EtrLock::LockAccount` (r:1 w:0)
+	/// Storage: `EtrLock::LockedForChain` (r:1 w:1)
+	/// Storage: `EtrLock::TotalLocked` (r:1 w:1)
+	/// Storage: `Balances::Locks` (r:1 w:1)
+	/// Storage: `System::Account` (r:2 w:2)
+	fn process_etr_burn_from_bnb() -> Weight {
+		Weight::from_parts(95_740_000, 16024)
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
+	}
+}
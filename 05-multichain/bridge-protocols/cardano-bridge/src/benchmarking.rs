@@ -0,0 +1,157 @@
+//! Benchmarking setup for pallet-cardano-bridge
+//!
+//! Addresses, transaction ids and Plutus data use their maximum lengths.
+
+#![cfg(feature = "runtime-benchmarks")]
+
+use super::*;
+use frame_benchmarking::v2::*;
+use frame_support::{
+    pallet_prelude::{BoundedVec, ConstU32, Get},
+    traits::Currency,
+};
+use frame_system::RawOrigin;
+use sp_runtime::traits::{SaturatedConversion, Saturating};
+use sp_std::{vec, vec::Vec};
+
+/// 1 ADA = 1 ETR, so lovelace amounts map to ETR units one to one
+const RATE: u64 = 1_000_000;
+
+fn authority<T: Config>() -> T::AccountId {
+    T::BridgeAuthority::get()
+}
+
+fn address() -> Vec<u8> {
+    vec![b'a'; 128]
+}
+
+/// Maximum-length Cardano transaction id, unique per `seed`
+fn txid(seed: u8) -> Vec<u8> {
+    vec![seed; 64]
+}
+
+fn plutus_data() -> Option<Vec<u8>> {
+    Some(vec![b'p'; 256])
+}
+
+/// Fund `who` with `amount` plus a comfortable margin above the existential deposit
+fn fund<T: Config>(who: &T::AccountId, amount: BalanceOf<T>) {
+    let ed = T::Currency::minimum_balance();
+    T::Currency::make_free_balance_be(who, amount.saturating_add(ed.saturating_mul(1_000u32.into())));
+}
+
+/// Register a pending deposit from the bridge authority
+fn add_deposit<T: Config>(txid: Vec<u8>) -> Result<(), BenchmarkError> {
+    ExchangeRate::<T>::put(RATE);
+    Pallet::<T>::deposit_ada(
+        RawOrigin::Signed(authority::<T>()).into(),
+        whitelisted_caller(),
+        address(),
+        txid,
+        T::MaxDepositAmount::get(),
+        u32::MAX,
+        plutus_data(),
+    )?;
+    Ok(())
+}
+
+/// Request a withdrawal for `who`, which burns the matching ETR
+fn add_withdrawal<T: Config>(who: &T::AccountId) -> Result<(), BenchmarkError> {
+    ExchangeRate::<T>::put(RATE);
+    let amount = T::MinDepositAmount::get();
+    fund::<T>(who, amount.saturated_into());
+    Pallet::<T>::withdraw_ada(RawOrigin::Signed(who.clone()).into(), address(), amount, plutus_data())?;
+    Ok(())
+}
+
+#[benchmarks]
+mod benchmarks {
+    use super::*;
+
+    #[benchmark]
+    fn deposit_ada() {
+        ExchangeRate::<T>::put(RATE);
+        let depositor: T::AccountId = whitelisted_caller();
+        let txid = txid(2);
+
+        #[extrinsic_call]
+        _(
+            RawOrigin::Signed(authority::<T>()),
+            depositor,
+            address(),
+            txid.clone(),
+            T::MaxDepositAmount::get(),
+            u32::MAX,
+            plutus_data(),
+        );
+
+        assert!(Deposits::<T>::contains_key(BoundedVec::<u8, ConstU32<64>>::truncate_from(txid)));
+    }
+
+    #[benchmark]
+    fn confirm_deposit() -> Result<(), BenchmarkError> {
+        let txid = txid(2);
+        add_deposit::<T>(txid.clone())?;
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(authority::<T>()), txid.clone(), T::MinConfirmations::get());
+
+        let deposit = Deposits::<T>::get(BoundedVec::<u8, ConstU32<64>>::truncate_from(txid)).unwrap();
+        assert_eq!(deposit.status, DepositStatus::Minted);
+        Ok(())
+    }
+
+    #[benchmark]
+    fn withdraw_ada() {
+        ExchangeRate::<T>::put(RATE);
+        let caller: T::AccountId = whitelisted_caller();
+        let amount = T::MaxDepositAmount::get();
+        fund::<T>(&caller, amount.saturated_into());
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller.clone()), address(), amount, plutus_data());
+
+        assert!(Withdrawals::<T>::contains_key(&caller));
+    }
+
+    #[benchmark]
+    fn confirm_withdrawal() -> Result<(), BenchmarkError> {
+        let withdrawer: T::AccountId = whitelisted_caller();
+        add_withdrawal::<T>(&withdrawer)?;
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(authority::<T>()), withdrawer.clone(), txid(3));
+
+        assert_eq!(Withdrawals::<T>::get(&withdrawer).unwrap().status, WithdrawalStatus::Completed);
+        Ok(())
+    }
+
+    #[benchmark]
+    fn set_exchange_rate() {
+        #[extrinsic_call]
+        _(RawOrigin::Root, RATE);
+
+        assert_eq!(ExchangeRate::<T>::get(), RATE);
+    }
+
+    #[benchmark]
+    fn set_plutus_script() {
+        #[extrinsic_call]
+        _(RawOrigin::Root, txid(4));
+
+        assert!(PlutusScriptHash::<T>::get().is_some());
+    }
+
+    #[benchmark]
+    fn register_native_token() {
+        let policy_id = vec![5u8; 28];
+
+        #[extrinsic_call]
+        _(RawOrigin::Root, policy_id.clone(), u32::MAX);
+
+        assert_eq!(
+            NativeTokens::<T>::get(BoundedVec::<u8, ConstU32<28>>::truncate_from(policy_id)),
+            Some(u32::MAX)
+        );
+    }
+}
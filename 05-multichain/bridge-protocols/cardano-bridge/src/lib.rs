@@ -15,6 +15,11 @@
 
 pub use pallet::*;
 
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+pub mod weights;
+pub use weights::WeightInfo;
+
 #[frame_support::pallet]
 pub mod pallet {
     use frame_support::{
@@ -25,8 +30,9 @@ pub mod pallet {
     use frame_system::pallet_prelude::*;
     use sp_runtime::traits::{CheckedMul, CheckedDiv, Saturating, SaturatedConversion};
     use sp_std::vec::Vec;
+    use crate::WeightInfo;
 
-    type BalanceOf<T> = <<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
+    pub(crate) type BalanceOf<T> = <<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
 
     #[pallet::pallet]
     pub struct Pallet<T>(_);
@@ -50,6 +56,9 @@ pub mod pallet {
 
         /// Bridge authority account (multisig)
         type BridgeAuthority: Get<Self::AccountId>;
+
+        /// Weight information for extrinsics
+        type WeightInfo: WeightInfo;
     }
 
     /// ADA deposit request (eUTXO model)
@@ -230,7 +239,7 @@ pub mod pallet {
     impl<T: Config> Pallet<T> {
         /// Register an ADA deposit (called by bridge relayer)
         #[pallet::call_index(0)]
-        #[pallet::weight(10_000)]
+        #[pallet::weight(T::WeightInfo::deposit_ada())]
         pub fn deposit_ada(
             origin: OriginFor<T>,
             depositor: T::AccountId,
@@ -291,7 +300,7 @@ pub mod pallet {
 
         /// Confirm an ADA deposit with sufficient confirmations
         #[pallet::call_index(1)]
-        #[pallet::weight(10_000)]
+        #[pallet::weight(T::WeightInfo::confirm_deposit())]
         pub fn confirm_deposit(
             origin: OriginFor<T>,
             cardano_txid: Vec<u8>,
@@ -333,7 +342,7 @@ pub mod pallet {
 
         /// Request ADA withdrawal (burn ETR)
         #[pallet::call_index(2)]
-        #[pallet::weight(10_000)]
+        #[pallet::weight(T::WeightInfo::withdraw_ada())]
         pub fn withdraw_ada(
             origin: OriginFor<T>,
             cardano_address: Vec<u8>,
@@ -394,7 +403,7 @@ pub mod pallet {
 
         /// Confirm ADA withdrawal completed (called by bridge relayer)
         #[pallet::call_index(3)]
-        #[pallet::weight(10_000)]
+        #[pallet::weight(T::WeightInfo::confirm_withdrawal())]
         pub fn confirm_withdrawal(
             origin: OriginFor<T>,
             withdrawer: T::AccountId,
@@ -427,7 +436,7 @@ pub mod pallet {
 
         /// Set exchange rate (governance only)
         #[pallet::call_index(4)]
-        #[pallet::weight(10_000)]
+        #[pallet::weight(T::WeightInfo::set_exchange_rate())]
         pub fn set_exchange_rate(
             origin: OriginFor<T>,
             new_rate: u64,
@@ -445,7 +454,7 @@ pub mod pallet {
 
         /// Update Plutus script hash (governance only)
         #[pallet::call_index(5)]
-        #[pallet::weight(10_000)]
+        #[pallet::weight(T::WeightInfo::set_plutus_script())]
         pub fn set_plutus_script(
             origin: OriginFor<T>,
             script_hash: Vec<u8>,
@@ -464,7 +473,7 @@ pub mod pallet {
 
         /// Register Cardano Native Token (governance only)
         #[pallet::call_index(6)]
-        #[pallet::weight(10_000)]
+        #[pallet::weight(T::WeightInfo::register_native_token())]
         pub fn register_native_token(
             origin: OriginFor<T>,
             policy_id: Vec<u8>,
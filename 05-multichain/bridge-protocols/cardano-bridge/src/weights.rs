@@ -0,0 +1,125 @@
+//! PLACEHOLDER weights for `pallet_cardano_bridge`: hand-estimated, not benchmark output.
+//!
+//! Overwrite with `./scripts/benchmark-pallets.sh pallet_cardano_bridge` on reference hardware.
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(missing_docs)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use core::marker::PhantomData;
+
+/// Weight functions needed for `pallet_cardano_bridge`.
+pub trait WeightInfo {
+	fn deposit_ada() -> Weight;
+	fn confirm_deposit() -> Weight;
+	fn withdraw_ada() -> Weight;
+	fn confirm_withdrawal() -> Weight;
+	fn set_exchange_rate() -> Weight;
+	fn set_plutus_script() -> Weight;
+	fn register_native_token() -> Weight;
+}
+
+/// Placeholder weights for `pallet_cardano_bridge`, pending benchmarking.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	/// Storage: `CardanoBridge::Deposits` (r:1 w:1)
+	/// Storage: `CardanoBridge::ExchangeRate` (r:1 w:0)
+	fn deposit_ada() -> Weight {
+		Weight::from_parts(24_360_000, 4568)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `CardanoBridge::Deposits` (r:1 w:1)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Storage: `CardanoBridge::TotalAdaLocked` (r:1 w:1)
+	/// Storage: `CardanoBridge::TotalEtrMinted` (r:1 w:1)
+	fn confirm_deposit() -> Weight {
+		Weight::from_parts(50_710_000, 7682)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: `CardanoBridge::Withdrawals` (r:1 w:1)
+	/// Storage: `CardanoBridge::ExchangeRate` (r:1 w:0)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Storage: `CardanoBridge::TotalAdaLocked` (r:1 w:1)
+	/// Storage: `CardanoBridge::TotalEtrMinted` (r:1 w:1)
+	fn withdraw_ada() -> Weight {
+		Weight::from_parts(62_980_000, 8145)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: `CardanoBridge::Withdrawals` (r:1 w:1)
+	fn confirm_withdrawal() -> Weight {
+		Weight::from_parts(20_140_000, 4025)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `CardanoBridge::ExchangeRate` (r:0 w:1)
+	fn set_exchange_rate() -> Weight {
+		Weight::from_parts(6_910_000, 990)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `CardanoBridge::PlutusScriptHash` (r:0 w:1)
+	fn set_plutus_script() -> Weight {
+		Weight::from_parts(7_480_000, 990)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `CardanoBridge::NativeTokens` (r:0 w:1)
+	fn register_native_token() -> Weight {
+		Weight::from_parts(9_060_000, 990)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+}
+
+// For backwards compatibility and tests.
+impl WeightInfo for () {
+	/// Storage: `CardanoBridge::Deposits` (r:1 w:1)
+	/// Storage: `CardanoBridge::ExchangeRate` (r:1 w:0)
+	fn deposit_ada() -> Weight {
+		Weight::from_parts(24_360_000, 4568)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `CardanoBridge::Deposits` (r:1 w:1)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Storage: `CardanoBridge::TotalAdaLocked` (r:1 w:1)
+	/// Storage: `CardanoBridge::TotalEtrMinted` (r:1 w:1)
+	fn confirm_deposit() -> Weight {
+		Weight::from_parts(50_710_000, 7682)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: `CardanoBridge::Withdrawals` (r:1 w:1)
+	/// Storage: `CardanoBridge::ExchangeRate` (r:1 w:0)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Storage: `CardanoBridge::TotalAdaLocked` (r:1 w:1)
+	/// Storage: `CardanoBridge::TotalEtrMinted` (r:1 w:1)
+	fn withdraw_ada() -> Weight {
+		Weight::from_parts(62_980_000, 8145)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: `CardanoBridge::Withdrawals` (r:1 w:1)
+	fn confirm_withdrawal() -> Weight {
+		Weight::from_parts(20_140_000, 4025)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `CardanoBridge::ExchangeRate` (r:0 w:1)
+	fn set_exchange_rate() -> Weight {
+		Weight::from_parts(6_910_000, 990)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `CardanoBridge::PlutusScriptHash` (r:0 w:1)
+	fn set_plutus_script() -> Weight {
+		Weight::from_parts(7_480_000, 990)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `CardanoBridge::NativeTokens` (r:0 w:1)
+	fn register_native_token() -> Weight {
+		Weight::from_parts(9_060_000, 990)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+}
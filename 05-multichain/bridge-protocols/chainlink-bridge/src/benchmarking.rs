@@ -0,0 +1,177 @@
+//! Benchmarking setup for chainlink-bridge
+//!
+//! Per-account withdrawal lists are filled to one below their bound.
+
+#![cfg(feature = "runtime-benchmarks")]
+
+use super::*;
+use frame_benchmarking::v2::*;
+use frame_support::{
+    pallet_prelude::Get,
+    traits::Currency,
+    BoundedVec,
+};
+use frame_system::RawOrigin;
+use sp_runtime::traits::{Saturating, Zero};
+
+/// $15 with 18 decimals
+const PRICE: u128 = 15_000_000_000_000_000_000;
+
+fn amount<T: Config>() -> BalanceOf<T> {
+    T::Currency::minimum_balance().max(1u32.into()).saturating_mul(1_000u32.into())
+}
+
+fn oracle_node<T: Config>() -> T::AccountId {
+    let node: T::AccountId = whitelisted_caller();
+    OracleNodes::<T>::insert(&node, EthereumAddress::repeat_byte(1));
+    node
+}
+
+fn whitelisted_operator<T: Config>() -> T::AccountId {
+    let operator: T::AccountId = whitelisted_caller();
+    WhitelistedOperators::<T>::insert(&operator, true);
+    operator
+}
+
+#[benchmarks]
+mod benchmarks {
+    use super::*;
+
+    #[benchmark]
+    fn deposit_link() {
+        BridgeActive::<T>::put(true);
+        let relayer = oracle_node::<T>();
+        let beneficiary: T::AccountId = account("beneficiary", 0, 0);
+        let eth_tx_hash = EthTxHash::repeat_byte(1);
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(relayer), beneficiary.clone(), amount::<T>(), eth_tx_hash);
+
+        assert!(LinkDeposits::<T>::contains_key(eth_tx_hash));
+        assert!(!T::Currency::free_balance(&beneficiary).is_zero());
+    }
+
+    #[benchmark]
+    fn withdraw_link() {
+        BridgeActive::<T>::put(true);
+        EthBridgeContract::<T>::put(EthereumAddress::repeat_byte(2));
+        let caller: T::AccountId = whitelisted_caller();
+        T::Currency::make_free_balance_be(&caller, amount::<T>().saturating_mul(2u32.into()));
+        let existing = (EthereumAddress::repeat_byte(1), amount::<T>(), frame_system::Pallet::<T>::block_number());
+        let existing = sp_std::vec![existing; T::MaxOracleNodes::get() as usize - 1];
+        LinkWithdrawals::<T>::insert(&caller, BoundedVec::truncate_from(existing));
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller.clone()), amount::<T>(), EthereumAddress::repeat_byte(1));
+
+        assert_eq!(LinkWithdrawals::<T>::get(&caller).len() as u32, T::MaxOracleNodes::get());
+    }
+
+    #[benchmark]
+    fn submit_oracle_price() {
+        let operator = whitelisted_operator::<T>();
+        let feed_id = DataFeedId::repeat_byte(1);
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(operator), feed_id, PRICE, u64::MAX, u64::MAX, u64::MAX);
+
+        assert_eq!(DataFeeds::<T>::get(feed_id).unwrap().price, PRICE);
+    }
+
+    #[benchmark]
+    fn register_oracle_node() {
+        let caller: T::AccountId = whitelisted_caller();
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller.clone()), EthereumAddress::repeat_byte(1));
+
+        assert!(OracleNodes::<T>::contains_key(&caller));
+    }
+
+    #[benchmark]
+    fn set_bridge_status() {
+        #[extrinsic_call]
+        _(RawOrigin::Root, true);
+
+        assert!(BridgeActive::<T>::get());
+    }
+
+    #[benchmark]
+    fn update_link_token_address() {
+        let address = EthereumAddress::repeat_byte(3);
+
+        #[extrinsic_call]
+        _(RawOrigin::Root, address);
+
+        assert_eq!(LinkTokenAddress::<T>::get(), address);
+    }
+
+    #[benchmark]
+    fn update_bridge_contract() {
+        let address = EthereumAddress::repeat_byte(2);
+
+        #[extrinsic_call]
+        _(RawOrigin::Root, address);
+
+        assert_eq!(EthBridgeContract::<T>::get(), Some(address));
+    }
+
+    #[benchmark]
+    fn whitelist_operator() {
+        let operator: T::AccountId = account("operator", 0, 0);
+
+        #[extrinsic_call]
+        _(RawOrigin::Root, operator.clone());
+
+        assert!(WhitelistedOperators::<T>::get(&operator));
+    }
+
+    #[benchmark]
+    fn remove_operator() {
+        let operator = whitelisted_operator::<T>();
+
+        #[extrinsic_call]
+        _(RawOrigin::Root, operator.clone());
+
+        assert!(!WhitelistedOperators::<T>::get(&operator));
+    }
+
+    #[benchmark]
+    fn request_vrf() {
+        let caller: T::AccountId = whitelisted_caller();
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller), H256::repeat_byte(1), u64::MAX, u128::MAX);
+
+        assert_eq!(VRFRequests::<T>::iter().count(), 1);
+    }
+
+    #[benchmark]
+    fn fulfill_vrf() {
+        let operator = whitelisted_operator::<T>();
+        let request_id = H256::repeat_byte(2);
+        VRFRequests::<T>::insert(request_id, VRFRequest {
+            requester: operator.clone(),
+            key_hash: H256::repeat_byte(1),
+            seed: u64::MAX,
+            fee: u128::MAX,
+            is_fulfilled: false,
+        });
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(operator), request_id, H256::repeat_byte(3));
+
+        assert!(VRFRequests::<T>::get(request_id).unwrap().is_fulfilled);
+    }
+
+    #[benchmark]
+    fn update_proof_of_reserve() {
+        let operator = whitelisted_operator::<T>();
+        let token = EthereumAddress::repeat_byte(1);
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(operator), token, u128::MAX);
+
+        assert_eq!(ProofOfReserve::<T>::get(token), u128::MAX);
+    }
+}
@@ -8,6 +8,11 @@
 
 pub use pallet::*;
 
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+pub mod weights;
+pub use weights::WeightInfo;
+
 use codec::{Decode, Encode, MaxEncodedLen};
 use scale_info::TypeInfo;
 use sp_std::prelude::*;
@@ -65,8 +70,9 @@ pub mod pallet {
     };
     use frame_system::pallet_prelude::*;
     use sp_runtime::traits::Saturating;
+    use crate::WeightInfo;
 
-    type BalanceOf<T> = <<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
+    pub(crate) type BalanceOf<T> = <<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
 
     #[pallet::config]
     pub trait Config: frame_system::Config {
@@ -96,6 +102,9 @@ pub mod pallet {
         /// Oracle price staleness threshold (in blocks)
         #[pallet::constant]
         type PriceStalenessThreshold: Get<u32>;
+
+        /// Weight information for extrinsics
+        type WeightInfo: WeightInfo;
     }
 
     #[pallet::pallet]
@@ -304,7 +313,7 @@ pub mod pallet {
         ///
         /// Called by bridge relayers after detecting ERC-677 transferAndCall to bridge contract
         #[pallet::call_index(0)]
-        #[pallet::weight(10_000)]
+        #[pallet::weight(T::WeightInfo::deposit_link())]
         pub fn deposit_link(
             origin: OriginFor<T>,
             beneficiary: T::AccountId,
@@ -362,7 +371,7 @@ pub mod pallet {
 
         /// Withdraw LINK to Ethereum blockchain
         #[pallet::call_index(1)]
-        #[pallet::weight(10_000)]
+        #[pallet::weight(T::WeightInfo::withdraw_link())]
         pub fn withdraw_link(
             origin: OriginFor<T>,
             amount: BalanceOf<T>,
@@ -422,7 +431,7 @@ pub mod pallet {
 
         /// Submit oracle price data from Chainlink Data Feed
         #[pallet::call_index(2)]
-        #[pallet::weight(10_000)]
+        #[pallet::weight(T::WeightInfo::submit_oracle_price())]
         pub fn submit_oracle_price(
             origin: OriginFor<T>,
             feed_id: DataFeedId,
@@ -465,7 +474,7 @@ pub mod pallet {
 
         /// Register as Chainlink oracle node
         #[pallet::call_index(3)]
-        #[pallet::weight(10_000)]
+        #[pallet::weight(T::WeightInfo::register_oracle_node())]
         pub fn register_oracle_node(
             origin: OriginFor<T>,
             eth_address: EthereumAddress,
@@ -484,7 +493,7 @@ pub mod pallet {
 
         /// Set bridge active status (governance only)
         #[pallet::call_index(4)]
-        #[pallet::weight(10_000)]
+        #[pallet::weight(T::WeightInfo::set_bridge_status())]
         pub fn set_bridge_status(origin: OriginFor<T>, is_active: bool) -> DispatchResult {
             ensure_root(origin)?;
             
@@ -497,7 +506,7 @@ pub mod pallet {
 
         /// Update LINK token contract address (ERC-677)
         #[pallet::call_index(5)]
-        #[pallet::weight(10_000)]
+        #[pallet::weight(T::WeightInfo::update_link_token_address())]
         pub fn update_link_token_address(
             origin: OriginFor<T>,
             address: EthereumAddress,
@@ -513,7 +522,7 @@ pub mod pallet {
 
         /// Update Ethereum bridge contract address
         #[pallet::call_index(6)]
-        #[pallet::weight(10_000)]
+        #[pallet::weight(T::WeightInfo::update_bridge_contract())]
         pub fn update_bridge_contract(
             origin: OriginFor<T>,
             address: EthereumAddress,
@@ -527,7 +536,7 @@ pub mod pallet {
 
         /// Whitelist Chainlink operator for oracle submissions
         #[pallet::call_index(7)]
-        #[pallet::weight(10_000)]
+        #[pallet::weight(T::WeightInfo::whitelist_operator())]
         pub fn whitelist_operator(origin: OriginFor<T>, operator: T::AccountId) -> DispatchResult {
             ensure_root(origin)?;
             
@@ -540,7 +549,7 @@ pub mod pallet {
 
         /// Remove operator from whitelist
         #[pallet::call_index(8)]
-        #[pallet::weight(10_000)]
+        #[pallet::weight(T::WeightInfo::remove_operator())]
         pub fn remove_operator(origin: OriginFor<T>, operator: T::AccountId) -> DispatchResult {
             ensure_root(origin)?;
 
@@ -553,7 +562,7 @@ pub mod pallet {
 
         /// Request VRF (Verifiable Random Function) randomness
         #[pallet::call_index(9)]
-        #[pallet::weight(15_000)]
+        #[pallet::weight(T::WeightInfo::request_vrf())]
         pub fn request_vrf(
             origin: OriginFor<T>,
             key_hash: H256,
@@ -589,7 +598,7 @@ pub mod pallet {
 
         /// Fulfill VRF request (oracle only)
         #[pallet::call_index(10)]
-        #[pallet::weight(15_000)]
+        #[pallet::weight(T::WeightInfo::fulfill_vrf())]
         pub fn fulfill_vrf(
             origin: OriginFor<T>,
             request_id: H256,
@@ -621,7 +630,7 @@ pub mod pallet {
 
         /// Update Proof of Reserve for a token (oracle only)
         #[pallet::call_index(11)]
-        #[pallet::weight(10_000)]
+        #[pallet::weight(T::WeightInfo::update_proof_of_reserve())]
         pub fn update_proof_of_reserve(
             origin: OriginFor<T>,
             token: EthereumAddress,
@@ -772,6 +781,7 @@ mod tests {
         type MaxDataFeeds = MaxDataFeeds;
         type MaxVRFRequests = MaxVRFRequests;
         type PriceStalenessThreshold = PriceStalenessThreshold;
+        type WeightInfo = ();
     }
 
     fn new_test_ext() -> sp_io::TestExternalities {
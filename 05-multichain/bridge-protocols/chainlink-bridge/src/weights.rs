@@ -0,0 +1,190 @@
+//! PLACEHOLDER weights for `chainlink_bridge`: hand-estimated, not benchmark output.
+//!
+//! Overwrite with `./scripts/benchmark-pallets.sh chainlink_bridge` on reference hardware.
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(missing_docs)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use core::marker::PhantomData;
+
+/// Weight functions needed for `chainlink_bridge`.
+pub trait WeightInfo {
+	fn deposit_link() -> Weight;
+	fn withdraw_link() -> Weight;
+	fn submit_oracle_price() -> Weight;
+	fn register_oracle_node() -> Weight;
+	fn set_bridge_status() -> Weight;
+	fn update_link_token_address() -> Weight;
+	fn update_bridge_contract() -> Weight;
+	fn whitelist_operator() -> Weight;
+	fn remove_operator() -> Weight;
+	fn request_vrf() -> Weight;
+	fn fulfill_vrf() -> Weight;
+	fn update_proof_of_reserve() -> Weight;
+}
+
+/// Placeholder weights for `chainlink_bridge`, pending benchmarking.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	/// Storage: `ChainlinkBridge::BridgeActive` (r:1 w:0)
+	/// Storage: `ChainlinkBridge::OracleNodes` (r:1 w:0)
+	/// Storage: `ChainlinkBridge::LinkDeposits` (r:1 w:1)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Storage: `ChainlinkBridge::TotalLocked` (r:1 w:1)
+	fn deposit_link() -> Weight {
+		Weight::from_parts(47_820_000, 9718)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: `ChainlinkBridge::BridgeActive` (r:1 w:0)
+	/// Storage: `ChainlinkBridge::EthBridgeContract` (r:1 w:0)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Storage: `ChainlinkBridge::LinkWithdrawals` (r:1 w:1)
+	/// Storage: `ChainlinkBridge::TotalLocked` (r:1 w:1)
+	fn withdraw_link() -> Weight {
+		Weight::from_parts(68_540_000, 11640)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: `ChainlinkBridge::WhitelistedOperators` (r:1 w:0)
+	/// Storage: `ChainlinkBridge::DataFeeds` (r:0 w:1)
+	fn submit_oracle_price() -> Weight {
+		Weight::from_parts(14_620_000, 3514)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `ChainlinkBridge::OracleNodes` (r:0 w:1)
+	fn register_oracle_node() -> Weight {
+		Weight::from_parts(10_870_000, 990)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `ChainlinkBridge::BridgeActive` (r:0 w:1)
+	fn set_bridge_status() -> Weight {
+		Weight::from_parts(6_720_000, 990)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `ChainlinkBridge::LinkTokenAddress` (r:0 w:1)
+	fn update_link_token_address() -> Weight {
+		Weight::from_parts(6_980_000, 990)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `ChainlinkBridge::EthBridgeContract` (r:0 w:1)
+	fn update_bridge_contract() -> Weight {
+		Weight::from_parts(6_410_000, 990)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `ChainlinkBridge::WhitelistedOperators` (r:0 w:1)
+	fn whitelist_operator() -> Weight {
+		Weight::from_parts(8_930_000, 990)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `ChainlinkBridge::WhitelistedOperators` (r:0 w:1)
+	fn remove_operator() -> Weight {
+		Weight::from_parts(9_140_000, 990)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `ChainlinkBridge::VRFRequests` (r:1 w:1)
+	fn request_vrf() -> Weight {
+		Weight::from_parts(19_350_000, 3602)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `ChainlinkBridge::WhitelistedOperators` (r:1 w:0)
+	/// Storage: `ChainlinkBridge::VRFRequests` (r:1 w:1)
+	fn fulfill_vrf() -> Weight {
+		Weight::from_parts(18_260_000, 6126)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `ChainlinkBridge::WhitelistedOperators` (r:1 w:0)
+	/// Storage: `ChainlinkBridge::ProofOfReserve` (r:0 w:1)
+	fn update_proof_of_reserve() -> Weight {
+		Weight::from_parts(13_790_000, 3514)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+}
+
+// For backwards compatibility and tests.
+impl WeightInfo for () {
+	/// Storage: `ChainlinkBridge::BridgeActive` (r:1 w:0)
+	/// Storage: `ChainlinkBridge::OracleNodes` (r:1 w:0)
+	/// Storage: `ChainlinkBridge::LinkDeposits` (r:1 w:1)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Storage: `ChainlinkBridge::TotalLocked` (r:1 w:1)
+	fn deposit_link() -> Weight {
+		Weight::from_parts(47_820_000, 9718)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: `ChainlinkBridge::BridgeActive` (r:1 w:0)
+	/// Storage: `ChainlinkBridge::EthBridgeContract` (r:1 w:0)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Storage: `ChainlinkBridge::LinkWithdrawals` (r:1 w:1)
+	/// Storage: `ChainlinkBridge::TotalLocked` (r:1 w:1)
+	fn withdraw_link() -> Weight {
+		Weight::from_parts(68_540_000, 11640)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: `ChainlinkBridge::WhitelistedOperators` (r:1 w:0)
+	/// Storage: `ChainlinkBridge::DataFeeds` (r:0 w:1)
+	fn submit_oracle_price() -> Weight {
+		Weight::from_parts(14_620_000, 3514)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `ChainlinkBridge::OracleNodes` (r:0 w:1)
+	fn register_oracle_node() -> Weight {
+		Weight::from_parts(10_870_000, 990)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `ChainlinkBridge::BridgeActive` (r:0 w:1)
+	fn set_bridge_status() -> Weight {
+		Weight::from_parts(6_720_000, 990)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `ChainlinkBridge::LinkTokenAddress` (r:0 w:1)
+	fn update_link_token_address() -> Weight {
+		Weight::from_parts(6_980_000, 990)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `ChainlinkBridge::EthBridgeContract` (r:0 w:1)
+	fn update_bridge_contract() -> Weight {
+		Weight::from_parts(6_410_000, 990)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `ChainlinkBridge::WhitelistedOperators` (r:0 w:1)
+	fn whitelist_operator() -> Weight {
+		Weight::from_parts(8_930_000, 990)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `ChainlinkBridge::WhitelistedOperators` (r:0 w:1)
+	fn remove_operator() -> Weight {
+		Weight::from_parts(9_140_000, 990)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `ChainlinkBridge::VRFRequests` (r:1 w:1)
+	fn request_vrf() -> Weight {
+		Weight::from_parts(19_350_000, 3602)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `ChainlinkBridge::WhitelistedOperators` (r:1 w:0)
+	/// Storage: `ChainlinkBridge::VRFRequests` (r:1 w:1)
+	fn fulfill_vrf() -> Weight {
+		Weight::from_parts(18_260_000, 6126)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `ChainlinkBridge::WhitelistedOperators` (r:1 w:0)
+	/// Storage: `ChainlinkBridge::ProofOfReserve` (r:0 w:1)
+	fn update_proof_of_reserve() -> Weight {
+		Weight::from_parts(13_790_000, 3514)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+}
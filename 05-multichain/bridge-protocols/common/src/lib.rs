@@ -16,6 +16,6 @@ pub mod treasury;
 pub mod oracle_adapter;
 
 // Re-export commonly used types
-pub use multisig::{MultiSigCustodian, PendingApproval};
+pub use multisig::{MultiSigCustodian, PendingApproval, MAX_CUSTODIANS};
 pub use treasury::TreasuryInterface;
-pub use oracle_adapter::{PriceOracle, ExchangeRate, OracleAggregator, StaticRateOracle, OracleError};
+pub use oracle_adapter::{PriceOracle, ExchangeRate, OracleAggregator, StaticRateOracle, FeedRateOracle, OracleError};
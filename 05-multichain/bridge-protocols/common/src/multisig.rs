@@ -34,6 +34,9 @@ use scale_info::TypeInfo;
 use sp_runtime::RuntimeDebug;
 use sp_std::vec::Vec;
 
+/// Maximum custodians in a set, so approvals are weighed against a fixed worst case
+pub const MAX_CUSTODIANS: u32 = 16;
+
 /// Multi-signature custodian set with M-of-N threshold
 ///
 /// # Type Parameters
@@ -46,6 +49,7 @@ use sp_std::vec::Vec;
 /// # Invariants
 /// - `threshold > 0`
 /// - `threshold <= custodians.len()`
+/// - `custodians.len() <= MAX_CUSTODIANS`
 ///
 /// # Note on MaxEncodedLen
 /// This struct uses Vec which doesn't implement MaxEncodedLen.
@@ -113,6 +117,9 @@ impl<AccountId: Clone + PartialEq> MultiSigCustodian<AccountId> {
         if custodians.is_empty() {
             return Err("Invalid custodians: empty set");
         }
+        if custodians.len() > MAX_CUSTODIANS as usize {
+            return Err("Invalid custodians: too many");
+        }
         if threshold == 0 {
             return Err("Invalid threshold: must be > 0");
         }
@@ -388,6 +395,13 @@ mod tests {
         assert_eq!(result.unwrap_err(), "Invalid threshold: exceeds custodian count");
     }
 
+    #[test]
+    fn multisig_creation_invalid_too_many_custodians() {
+        let custodians: Vec<u64> = (0..=MAX_CUSTODIANS as u64).collect();
+        let result = MultiSigCustodian::new(custodians, 2);
+        assert_eq!(result.unwrap_err(), "Invalid custodians: too many");
+    }
+
     #[test]
     fn multisig_creation_invalid_empty_custodians() {
         let custodians: Vec<u64> = vec![];
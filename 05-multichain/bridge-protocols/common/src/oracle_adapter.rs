@@ -2,6 +2,10 @@
 //!
 //! Provides price feeds from multiple sources (Chainlink, bridge oracles, DEX TWAPs)
 //! to the Lightning Cross-PBC Router for accurate exchange rate calculations.
+//!
+//! On-chain prices come from `pallet-oracle-network` through [`FeedRateOracle`], and
+//! multi-source rates are combined with the same median + MAD filter the oracle
+//! network applies to reporter rounds.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 #![allow(unused_attributes)]
@@ -16,8 +20,10 @@ use alloc::{string::String, vec, vec::Vec, boxed::Box};
 use std::{string::String, vec::Vec, boxed::Box};
 
 use codec::{Decode, Encode};
+use core::marker::PhantomData;
+use etrid_oracle_primitives::{self as oracle_primitives, PriceFeed};
 use scale_info::TypeInfo;
-use sp_runtime::RuntimeDebug;
+use sp_runtime::{Permill, RuntimeDebug};
 
 /// Chain identifier (matches Lightning Cross-PBC Router)
 pub type ChainId = String;
@@ -98,6 +104,12 @@ pub enum OracleError {
     InvalidData,
 }
 
+/// Width of the aggregator's outlier band in MADs
+const AGGREGATOR_MAD_MULTIPLIER: u32 = 3;
+
+/// Minimum width of the aggregator's outlier band relative to the median rate
+const AGGREGATOR_TOLERANCE: Permill = Permill::from_parts(10_000);
+
 /// Multi-source oracle aggregator
 pub struct OracleAggregator {
     /// List of oracle sources
@@ -126,7 +138,7 @@ impl OracleAggregator {
         self.oracles.push(oracle);
     }
 
-    /// Get aggregated exchange rate (median of all sources)
+    /// Get aggregated exchange rate (median of the sources left after MAD filtering)
     pub fn get_aggregated_rate(
         &self,
         from: &ChainId,
@@ -147,25 +159,26 @@ impl OracleAggregator {
             }
         }
 
+        // Drop sources that disagree with the majority
+        let values: Vec<u128> = rates.iter().map(|r| r.rate as u128).collect();
+        let outcome = oracle_primitives::filter_round(
+            &values,
+            AGGREGATOR_MAD_MULTIPLIER,
+            AGGREGATOR_TOLERANCE,
+        )
+        .ok_or(OracleError::Unavailable)?;
+
         // Check minimum sources requirement
-        if rates.len() < self.min_sources {
+        if outcome.accepted.len() < self.min_sources.max(1) {
             return Err(OracleError::Unavailable);
         }
 
-        // Calculate median rate
-        rates.sort_by_key(|r| r.rate);
-        let median_idx = rates.len() / 2;
-        let median_rate = rates[median_idx];
-
-        // Calculate average confidence
-        let avg_confidence = (rates.iter().map(|r| r.confidence as u32).sum::<u32>()
-            / rates.len() as u32) as u8;
+        // Calculate average confidence of the accepted sources
+        let avg_confidence = (outcome.accepted.iter().map(|&i| rates[i].confidence as u32).sum::<u32>()
+            / outcome.accepted.len() as u32) as u8;
 
-        Ok(ExchangeRate::new(
-            median_rate.rate,
-            current_time,
-            avg_confidence,
-        ))
+        let rate = u64::try_from(outcome.value).map_err(|_| OracleError::InvalidData)?;
+        Ok(ExchangeRate::new(rate, current_time, avg_confidence))
     }
 
     /// Get number of active oracles
@@ -266,6 +279,77 @@ impl PriceOracle for StaticRateOracle {
     }
 }
 
+/// Exchange rates derived from the oracle network's USD price feeds
+///
+/// The rate from one chain to another is the ratio of the USD prices of their native
+/// assets. Only fresh feeds are used; outlier reporters were already rejected and
+/// slashed by the oracle network, so rates carry full confidence.
+pub struct FeedRateOracle<F> {
+    _feeds: PhantomData<F>,
+}
+
+impl<F: PriceFeed> FeedRateOracle<F> {
+    /// Create a feed-backed oracle
+    pub fn new() -> Self {
+        Self { _feeds: PhantomData }
+    }
+
+    #[cfg(feature = "std")]
+    fn current_timestamp() -> u64 {
+        use std::time::{SystemTime, UNIX_EPOCH};
+        SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .as_secs()
+    }
+
+    #[cfg(not(feature = "std"))]
+    fn current_timestamp() -> u64 {
+        0 // In no_std, must be provided externally
+    }
+}
+
+impl<F: PriceFeed> Default for FeedRateOracle<F> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<F: PriceFeed> PriceOracle for FeedRateOracle<F> {
+    fn get_exchange_rate(&self, from: &ChainId, to: &ChainId) -> Option<ExchangeRate> {
+        if from == to {
+            return None;
+        }
+        let from_price = F::fresh(&oracle_primitives::chain_feed(from)?)?;
+        let to_price = F::fresh(&oracle_primitives::chain_feed(to)?)?;
+        let rate = from_price.rate_bps(&to_price)?;
+        Some(ExchangeRate::new(rate, Self::current_timestamp(), 100))
+    }
+
+    fn get_all_rates(&self, chain: &ChainId) -> Vec<(ChainId, ExchangeRate)> {
+        oracle_primitives::FEED_CHAINS
+            .iter()
+            .map(|to| ChainId::from(*to))
+            .filter_map(|to| self.get_exchange_rate(chain, &to).map(|rate| (to, rate)))
+            .collect()
+    }
+
+    fn update_rate(&mut self, _from: ChainId, _to: ChainId, _rate: ExchangeRate) -> Result<(), OracleError> {
+        // Rates follow the on-chain feeds and cannot be overridden locally
+        Err(OracleError::Unavailable)
+    }
+
+    fn supports_pair(&self, from: &ChainId, to: &ChainId) -> bool {
+        from != to
+            && oracle_primitives::chain_feed(from).is_some()
+            && oracle_primitives::chain_feed(to).is_some()
+    }
+
+    fn oracle_name(&self) -> &str {
+        "OracleNetworkFeeds"
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -312,4 +396,88 @@ mod tests {
         assert!(oracle.supports_pair(&"eth-pbc".into(), &"trx-pbc".into()));
         assert!(!oracle.supports_pair(&"eth-pbc".into(), &"random-chain".into()));
     }
+
+    /// Fixed oracle returning one rate for every pair
+    struct FixedRateOracle(u64);
+
+    impl PriceOracle for FixedRateOracle {
+        fn get_exchange_rate(&self, _from: &ChainId, _to: &ChainId) -> Option<ExchangeRate> {
+            Some(ExchangeRate::new(self.0, 1000, 90))
+        }
+
+        fn get_all_rates(&self, _chain: &ChainId) -> Vec<(ChainId, ExchangeRate)> {
+            Vec::new()
+        }
+
+        fn update_rate(&mut self, _from: ChainId, _to: ChainId, _rate: ExchangeRate) -> Result<(), OracleError> {
+            Ok(())
+        }
+
+        fn supports_pair(&self, _from: &ChainId, _to: &ChainId) -> bool {
+            true
+        }
+
+        fn oracle_name(&self) -> &str {
+            "FixedRateOracle"
+        }
+    }
+
+    #[test]
+    fn test_aggregator_rejects_outlier_source() {
+        let mut aggregator = OracleAggregator::new(3, 600, 80);
+        for rate in [500, 502, 498, 900] {
+            aggregator.add_oracle(Box::new(FixedRateOracle(rate)));
+        }
+
+        let rate = aggregator
+            .get_aggregated_rate(&"eth-pbc".into(), &"btc-pbc".into(), 1000)
+            .unwrap();
+        assert_eq!(rate.rate, 500);
+        assert_eq!(rate.confidence, 90);
+
+        // Only two sources agree once the outlier is dropped
+        let mut aggregator = OracleAggregator::new(4, 600, 80);
+        for rate in [500, 502, 498, 900] {
+            aggregator.add_oracle(Box::new(FixedRateOracle(rate)));
+        }
+        assert_eq!(
+            aggregator.get_aggregated_rate(&"eth-pbc".into(), &"btc-pbc".into(), 1000),
+            Err(OracleError::Unavailable)
+        );
+    }
+
+    struct MockFeeds;
+
+    impl PriceFeed for MockFeeds {
+        fn latest(feed: &oracle_primitives::FeedId) -> Option<oracle_primitives::FeedPrice> {
+            let value = match *feed {
+                oracle_primitives::ETH_USD => 3_000_00000000,
+                oracle_primitives::BTC_USD => 60_000_00000000,
+                _ => return None,
+            };
+            Some(oracle_primitives::FeedPrice { value, decimals: 8, updated_at: 1, round: 1, reporters: 3 })
+        }
+
+        fn fresh(feed: &oracle_primitives::FeedId) -> Option<oracle_primitives::FeedPrice> {
+            Self::latest(feed)
+        }
+    }
+
+    #[test]
+    fn test_feed_rate_oracle() {
+        let oracle = FeedRateOracle::<MockFeeds>::new();
+
+        // 1 ETH = 0.05 BTC
+        let rate = oracle.get_exchange_rate(&"eth-pbc".into(), &"btc-pbc".into()).unwrap();
+        assert_eq!(rate.rate, 500);
+        assert_eq!(oracle.get_exchange_rate(&"btc-pbc".into(), &"eth-pbc".into()).unwrap().rate, 200_000);
+
+        // No SOL feed published, and unknown chains have no feed at all
+        assert!(oracle.get_exchange_rate(&"eth-pbc".into(), &"sol-pbc".into()).is_none());
+        assert!(!oracle.supports_pair(&"eth-pbc".into(), &"random-chain".into()));
+
+        let all = oracle.get_all_rates(&"eth-pbc".into());
+        assert_eq!(all.len(), 1);
+        assert_eq!(all[0].0, ChainId::from("btc-pbc"));
+    }
 }
@@ -0,0 +1,112 @@
+//! Benchmarking setup for pallet-doge-bridge
+
+#![cfg(feature = "runtime-benchmarks")]
+
+use super::*;
+use frame_benchmarking::v2::*;
+use frame_support::{
+    pallet_prelude::{BoundedVec, Get},
+    traits::Currency,
+};
+use frame_system::RawOrigin;
+use sp_runtime::traits::Saturating;
+use sp_std::{vec, vec::Vec};
+
+/// Maximum-length P2PKH address
+fn doge_address() -> Vec<u8> {
+    let mut address = vec![b'x'; 35];
+    address[0] = b'D';
+    address
+}
+
+fn register_relayer<T: Config>() -> T::AccountId {
+    let relayer: T::AccountId = whitelisted_caller();
+    DogeRelayNodes::<T>::insert(&relayer, BoundedVec::truncate_from(doge_address()));
+    relayer
+}
+
+#[benchmarks]
+mod benchmarks {
+    use super::*;
+
+    #[benchmark]
+    fn deposit_doge() {
+        BridgeActive::<T>::put(true);
+        let relayer = register_relayer::<T>();
+        let beneficiary: T::AccountId = account("beneficiary", 0, 0);
+        let tx_hash = [1u8; 32];
+
+        #[extrinsic_call]
+        _(
+            RawOrigin::Signed(relayer),
+            beneficiary,
+            T::MaxBridgeAmount::get(),
+            tx_hash,
+            T::DogeConfirmations::get(),
+        );
+
+        assert!(DogeDeposits::<T>::contains_key(tx_hash));
+    }
+
+    #[benchmark]
+    fn withdraw_doge() {
+        BridgeActive::<T>::put(true);
+        DogeBridgeAddress::<T>::put(BoundedVec::truncate_from(doge_address()));
+        let caller: T::AccountId = whitelisted_caller();
+        let amount = T::MaxBridgeAmount::get();
+        T::Currency::make_free_balance_be(&caller, amount.saturating_mul(2u32.into()));
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller.clone()), amount, doge_address());
+
+        assert!(DogeWithdrawals::<T>::contains_key(&caller));
+    }
+
+    #[benchmark]
+    fn register_relay_node() {
+        let caller: T::AccountId = whitelisted_caller();
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller.clone()), doge_address());
+
+        assert!(DogeRelayNodes::<T>::contains_key(&caller));
+    }
+
+    #[benchmark]
+    fn set_bridge_status() {
+        #[extrinsic_call]
+        _(RawOrigin::Root, true);
+
+        assert!(BridgeActive::<T>::get());
+    }
+
+    #[benchmark]
+    fn update_bridge_address() {
+        #[extrinsic_call]
+        _(RawOrigin::Root, doge_address());
+
+        assert!(DogeBridgeAddress::<T>::get().is_some());
+    }
+
+    #[benchmark]
+    fn update_processed_block() {
+        let relayer = register_relayer::<T>();
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(relayer), u64::MAX);
+
+        assert_eq!(LastProcessedBlock::<T>::get(), u64::MAX);
+    }
+
+    /// Append to a UTXO list that is one entry short of full
+    #[benchmark]
+    fn add_pending_utxo() {
+        let utxo = DogeUtxo { amount: 1, tx_hash: [0u8; 32], vout: 0 };
+        PendingUtxos::<T>::put(BoundedVec::truncate_from(vec![utxo; 99]));
+
+        #[extrinsic_call]
+        _(RawOrigin::Root, u64::MAX, [1u8; 32], u32::MAX);
+
+        assert_eq!(PendingUtxos::<T>::get().len(), 100);
+    }
+}
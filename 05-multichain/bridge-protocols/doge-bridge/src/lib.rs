@@ -7,6 +7,11 @@
 
 pub use pallet::*;
 
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+pub mod weights;
+pub use weights::WeightInfo;
+
 #[frame_support::pallet]
 pub mod pallet {
     use frame_support::{
@@ -20,8 +25,9 @@ pub mod pallet {
         ArithmeticError, Perbill,
     };
     use sp_std::prelude::*;
+    use crate::WeightInfo;
 
-    type BalanceOf<T> =
+    pub(crate) type BalanceOf<T> =
         <<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
 
     /// Dogecoin transaction hash
@@ -69,6 +75,9 @@ pub mod pallet {
         /// Dogecoin to Ëtrid conversion rate multiplier
         #[pallet::constant]
         type DogeConversionRate: Get<u64>;
+
+        /// Weight information for extrinsics
+        type WeightInfo: WeightInfo;
     }
 
     /// DOGE deposits from Dogecoin to Ëtrid
@@ -185,7 +194,7 @@ pub mod pallet {
         /// 
         /// Called by bridge relay nodes after detecting deposit on Dogecoin
         #[pallet::call_index(0)]
-        #[pallet::weight(10_000)]
+        #[pallet::weight(T::WeightInfo::deposit_doge())]
         pub fn deposit_doge(
             origin: OriginFor<T>,
             beneficiary: T::AccountId,
@@ -250,7 +259,7 @@ pub mod pallet {
 
         /// Withdraw DOGE to Dogecoin blockchain
         #[pallet::call_index(1)]
-        #[pallet::weight(10_000)]
+        #[pallet::weight(T::WeightInfo::withdraw_doge())]
         pub fn withdraw_doge(
             origin: OriginFor<T>,
             amount: BalanceOf<T>,
@@ -321,7 +330,7 @@ pub mod pallet {
 
         /// Register as Dogecoin bridge relay node
         #[pallet::call_index(2)]
-        #[pallet::weight(10_000)]
+        #[pallet::weight(T::WeightInfo::register_relay_node())]
         pub fn register_relay_node(
             origin: OriginFor<T>,
             doge_address: Vec<u8>,
@@ -349,7 +358,7 @@ pub mod pallet {
 
         /// Set bridge active status (governance only)
         #[pallet::call_index(3)]
-        #[pallet::weight(10_000)]
+        #[pallet::weight(T::WeightInfo::set_bridge_status())]
         pub fn set_bridge_status(origin: OriginFor<T>, is_active: bool) -> DispatchResult {
             ensure_root(origin)?;
             
@@ -362,7 +371,7 @@ pub mod pallet {
 
         /// Update Dogecoin bridge multi-sig address
         #[pallet::call_index(4)]
-        #[pallet::weight(10_000)]
+        #[pallet::weight(T::WeightInfo::update_bridge_address())]
         pub fn update_bridge_address(
             origin: OriginFor<T>,
             address: Vec<u8>,
@@ -386,7 +395,7 @@ pub mod pallet {
 
         /// Update last processed Dogecoin block height
         #[pallet::call_index(5)]
-        #[pallet::weight(10_000)]
+        #[pallet::weight(T::WeightInfo::update_processed_block())]
         pub fn update_processed_block(
             origin: OriginFor<T>,
             block_height: u64,
@@ -408,7 +417,7 @@ pub mod pallet {
 
         /// Add UTXO to pending list for withdrawal processing
         #[pallet::call_index(6)]
-        #[pallet::weight(10_000)]
+        #[pallet::weight(T::WeightInfo::add_pending_utxo())]
         pub fn add_pending_utxo(
             origin: OriginFor<T>,
             amount: u64,
@@ -556,6 +565,7 @@ mod tests {
         type PalletId = DogeBridgePalletId;
         type DogeConfirmations = DogeConfirmations;
         type DogeConversionRate = DogeConversionRate;
+        type WeightInfo = ();
     }
 
     fn new_test_ext() -> sp_io::TestExternalities {
@@ -0,0 +1,127 @@
+//! PLACEHOLDER weights for `pallet_doge_bridge`: hand-estimated, not benchmark output.
+//!
+//! Overwrite with `./scripts/benchmark-pallets.sh pallet_doge_bridge` on reference hardware.
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(missing_docs)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use core::marker::PhantomData;
+
+/// Weight functions needed for `pallet_doge_bridge`.
+pub trait WeightInfo {
+	fn deposit_doge() -> Weight;
+	fn withdraw_doge() -> Weight;
+	fn register_relay_node() -> Weight;
+	fn set_bridge_status() -> Weight;
+	fn update_bridge_address() -> Weight;
+	fn update_processed_block() -> Weight;
+	fn add_pending_utxo() -> Weight;
+}
+
+/// Placeholder weights for `pallet_doge_bridge`, pending benchmarking.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	/// Storage: `DogeBridge::BridgeActive` (r:1 w:0)
+	/// Storage: `DogeBridge::DogeRelayNodes` (r:1 w:0)
+	/// Storage: `DogeBridge::DogeDeposits` (r:1 w:1)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Storage: `DogeBridge::TotalLocked` (r:1 w:1)
+	fn deposit_doge() -> Weight {
+		Weight::from_parts(52_630_000, 9734)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: `DogeBridge::BridgeActive` (r:1 w:0)
+	/// Storage: `DogeBridge::DogeBridgeAddress` (r:1 w:0)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Storage: `DogeBridge::TotalLocked` (r:1 w:1)
+	/// Storage: `DogeBridge::DogeWithdrawals` (r:0 w:1)
+	fn withdraw_doge() -> Weight {
+		Weight::from_parts(61_970_000, 5131)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: `DogeBridge::DogeRelayNodes` (r:0 w:1)
+	fn register_relay_node() -> Weight {
+		Weight::from_parts(13_420_000, 990)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `DogeBridge::BridgeActive` (r:0 w:1)
+	fn set_bridge_status() -> Weight {
+		Weight::from_parts(6_810_000, 990)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `DogeBridge::DogeBridgeAddress` (r:0 w:1)
+	fn update_bridge_address() -> Weight {
+		Weight::from_parts(8_160_000, 990)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `DogeBridge::DogeRelayNodes` (r:1 w:0)
+	/// Storage: `DogeBridge::LastProcessedBlock` (r:0 w:1)
+	fn update_processed_block() -> Weight {
+		Weight::from_parts(11_290_000, 3549)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `DogeBridge::PendingUtxos` (r:1 w:1)
+	fn add_pending_utxo() -> Weight {
+		Weight::from_parts(15_870_000, 5887)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+}
+
+// For backwards compatibility and tests.
+impl WeightInfo for () {
+	/// Storage: `DogeBridge::BridgeActive` (r:1 w:0)
+	/// Storage: `DogeBridge::DogeRelayNodes` (r:1 w:0)
+	/// Storage: `DogeBridge::DogeDeposits` (r:1 w:1)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Storage: `DogeBridge::TotalLocked` (r:1 w:1)
+	fn deposit_doge() -> Weight {
+		Weight::from_parts(52_630_000, 9734)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: `DogeBridge::BridgeActive` (r:1 w:0)
+	/// Storage: `DogeBridge::DogeBridgeAddress` (r:1 w:0)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Storage: `DogeBridge::TotalLocked` (r:1 w:1)
+	/// Storage: `DogeBridge::DogeWithdrawals` (r:0 w:1)
+	fn withdraw_doge() -> Weight {
+		Weight::from_parts(61_970_000, 5131)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: `DogeBridge::DogeRelayNodes` (r:0 w:1)
+	fn register_relay_node() -> Weight {
+		Weight::from_parts(13_420_000, 990)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `DogeBridge::BridgeActive` (r:0 w:1)
+	fn set_bridge_status() -> Weight {
+		Weight::from_parts(6_810_000, 990)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `DogeBridge::DogeBridgeAddress` (r:0 w:1)
+	fn update_bridge_address() -> Weight {
+		Weight::from_parts(8_160_000, 990)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `DogeBridge::DogeRelayNodes` (r:1 w:0)
+	/// Storage: `DogeBridge::LastProcessedBlock` (r:0 w:1)
+	fn update_processed_block() -> Weight {
+		Weight::from_parts(11_290_000, 3549)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `DogeBridge::PendingUtxos` (r:1 w:1)
+	fn add_pending_utxo() -> Weight {
+		Weight::from_parts(15_870_000, 5887)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+}
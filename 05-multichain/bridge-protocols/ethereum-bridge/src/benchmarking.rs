@@ -0,0 +1,163 @@
+//! Benchmarking setup for eth-bridge
+//!
+//! Per-account deposit and withdrawal lists are filled to one below their bounds.
+
+#![cfg(feature = "runtime-benchmarks")]
+
+use super::*;
+use frame_benchmarking::v2::*;
+use frame_support::{
+	pallet_prelude::Get,
+	traits::Currency,
+	BoundedVec,
+};
+use frame_system::RawOrigin;
+use sp_runtime::traits::{Saturating, Zero};
+
+/// 1:1 ETH to ËTR, scaled by 1e18
+const RATE: u128 = 1_000_000_000_000_000_000;
+
+fn amount<T: Config>() -> BalanceOf<T> {
+	T::Currency::minimum_balance().max(1u32.into()).saturating_mul(1_000u32.into())
+}
+
+fn operator<T: Config>() -> T::AccountId {
+	let operator: T::AccountId = whitelisted_caller();
+	BridgeOperator::<T>::put(&operator);
+	operator
+}
+
+fn pending_deposit<T: Config>(
+	who: &T::AccountId,
+	tx_hash: EthTxHash,
+	token_address: Option<TokenAddress>,
+) -> EthereumDeposit<T::AccountId, BalanceOf<T>> {
+	EthereumDeposit {
+		eth_address: EthereumAddress::repeat_byte(1),
+		etrid_account: who.clone(),
+		amount: amount::<T>(),
+		tx_hash,
+		confirmations: T::MinConfirmations::get(),
+		token_address,
+		is_confirmed: false,
+	}
+}
+
+#[benchmarks]
+mod benchmarks {
+	use super::*;
+
+	#[benchmark]
+	fn initiate_eth_deposit() {
+		let relayer: T::AccountId = whitelisted_caller();
+		let recipient: T::AccountId = account("recipient", 0, 0);
+		let tx_hash = EthTxHash::repeat_byte(1);
+
+		#[extrinsic_call]
+		_(
+			RawOrigin::Signed(relayer),
+			recipient,
+			EthereumAddress::repeat_byte(1),
+			amount::<T>(),
+			tx_hash,
+			T::MinConfirmations::get(),
+		);
+
+		assert!(PendingDeposits::<T>::contains_key(tx_hash));
+	}
+
+	#[benchmark]
+	fn confirm_eth_deposit() {
+		EthToEtrRate::<T>::put(RATE);
+		let relayer: T::AccountId = whitelisted_caller();
+		let recipient: T::AccountId = account("recipient", 0, 0);
+		let tx_hash = EthTxHash::repeat_byte(1);
+		PendingDeposits::<T>::insert(tx_hash, pending_deposit::<T>(&recipient, tx_hash, None));
+		let existing = (1..T::MaxDepositsPerAccount::get()).map(|i| EthTxHash::from_low_u64_be(i as u64 + 1));
+		ConfirmedDeposits::<T>::insert(&recipient, BoundedVec::truncate_from(existing.collect::<Vec<_>>()));
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(relayer), tx_hash);
+
+		assert!(PendingDeposits::<T>::get(tx_hash).unwrap().is_confirmed);
+	}
+
+	/// A deposit that already has enough confirmations and is minted immediately
+	#[benchmark]
+	fn initiate_token_deposit() {
+		let relayer: T::AccountId = whitelisted_caller();
+		let recipient: T::AccountId = account("recipient", 0, 0);
+		let token = TokenAddress::repeat_byte(2);
+		SupportedTokens::<T>::insert(token, true);
+		TokenRates::<T>::insert(token, RATE);
+		let tx_hash = EthTxHash::repeat_byte(1);
+
+		#[extrinsic_call]
+		_(
+			RawOrigin::Signed(relayer),
+			recipient.clone(),
+			EthereumAddress::repeat_byte(1),
+			token,
+			amount::<T>(),
+			tx_hash,
+			T::MinConfirmations::get(),
+		);
+
+		assert!(!T::Currency::free_balance(&recipient).is_zero());
+	}
+
+	#[benchmark]
+	fn request_eth_withdrawal() {
+		let caller: T::AccountId = whitelisted_caller();
+		T::Currency::make_free_balance_be(&caller, amount::<T>().saturating_mul(2u32.into()));
+		let existing = EthereumWithdrawal {
+			etrid_account: caller.clone(),
+			eth_address: EthereumAddress::repeat_byte(1),
+			amount: amount::<T>(),
+			token_address: None,
+			gas_limit: T::MaxGasLimit::get(),
+			status: WithdrawalStatus::Completed(EthTxHash::repeat_byte(1)),
+		};
+		let existing = sp_std::vec![existing; T::MaxWithdrawalsPerAccount::get() as usize - 1];
+		PendingWithdrawals::<T>::insert(&caller, BoundedVec::truncate_from(existing));
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller.clone()), EthereumAddress::repeat_byte(1), amount::<T>(), T::MaxGasLimit::get());
+
+		assert_eq!(
+			PendingWithdrawals::<T>::get(&caller).len() as u32,
+			T::MaxWithdrawalsPerAccount::get()
+		);
+	}
+
+	#[benchmark]
+	fn update_exchange_rate() {
+		let operator = operator::<T>();
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(operator), RATE * 2);
+
+		assert_eq!(EthToEtrRate::<T>::get(), RATE * 2);
+	}
+
+	#[benchmark]
+	fn add_supported_token() {
+		let operator = operator::<T>();
+		let token = TokenAddress::repeat_byte(2);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(operator), token, RATE);
+
+		assert!(SupportedTokens::<T>::get(token));
+	}
+
+	#[benchmark]
+	fn set_operator() {
+		let operator: T::AccountId = account("operator", 0, 0);
+
+		#[extrinsic_call]
+		_(RawOrigin::Root, operator.clone());
+
+		assert_eq!(BridgeOperator::<T>::get(), Some(operator));
+	}
+}
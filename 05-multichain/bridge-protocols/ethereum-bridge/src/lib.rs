@@ -6,6 +6,11 @@
 
 pub use pallet::*;
 
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+pub mod weights;
+pub use weights::WeightInfo;
+
 use codec::{Decode, Encode, MaxEncodedLen};
 use scale_info::TypeInfo;
 use sp_std::prelude::*;
@@ -70,8 +75,9 @@ pub mod pallet {
 	use frame_system::pallet_prelude::*;
 	// TODO: Re-enable when etrid_bridge_common crate is implemented
 	use etrid_bridge_common::treasury::TreasuryInterface;
+	use crate::WeightInfo;
 
-	type BalanceOf<T> = <<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
+	pub(crate) type BalanceOf<T> = <<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
 
 	#[pallet::config]
 	pub trait Config: frame_system::Config {
@@ -104,6 +110,9 @@ pub mod pallet {
 
 		/// Validator pool account for receiving bridge fees
 		type ValidatorPoolAccount: Get<Self::AccountId>;
+
+		/// Weight information for extrinsics
+		type WeightInfo: WeightInfo;
 	}
 
 	#[pallet::pallet]
@@ -274,7 +283,7 @@ pub mod pallet {
 	impl<T: Config> Pallet<T> {
 		/// Initiate ETH deposit (called by relayer with proof)
 		#[pallet::call_index(0)]
-		#[pallet::weight(10_000)]
+		#[pallet::weight(T::WeightInfo::initiate_eth_deposit())]
 		pub fn initiate_eth_deposit(
 			origin: OriginFor<T>,
 			etrid_account: T::AccountId,
@@ -316,7 +325,7 @@ pub mod pallet {
 
 		/// Confirm ETH deposit after required confirmations
 		#[pallet::call_index(1)]
-		#[pallet::weight(15_000)]
+		#[pallet::weight(T::WeightInfo::confirm_eth_deposit())]
 		pub fn confirm_eth_deposit(
 			origin: OriginFor<T>,
 			tx_hash: EthTxHash,
@@ -372,7 +381,7 @@ pub mod pallet {
 
 		/// Initiate ERC-20 token deposit
 		#[pallet::call_index(2)]
-		#[pallet::weight(12_000)]
+		#[pallet::weight(T::WeightInfo::initiate_token_deposit())]
 		pub fn initiate_token_deposit(
 			origin: OriginFor<T>,
 			etrid_account: T::AccountId,
@@ -418,7 +427,7 @@ pub mod pallet {
 
 		/// Request ETH withdrawal
 		#[pallet::call_index(3)]
-		#[pallet::weight(20_000)]
+		#[pallet::weight(T::WeightInfo::request_eth_withdrawal())]
 		pub fn request_eth_withdrawal(
 			origin: OriginFor<T>,
 			eth_address: EthereumAddress,
@@ -494,7 +503,7 @@ pub mod pallet {
 
 		/// Update ETH/ËTR exchange rate (operator only)
 		#[pallet::call_index(4)]
-		#[pallet::weight(5_000)]
+		#[pallet::weight(T::WeightInfo::update_exchange_rate())]
 		pub fn update_exchange_rate(
 			origin: OriginFor<T>,
 			new_rate: u128,
@@ -519,7 +528,7 @@ pub mod pallet {
 
 		/// Add supported ERC-20 token (operator only)
 		#[pallet::call_index(5)]
-		#[pallet::weight(5_000)]
+		#[pallet::weight(T::WeightInfo::add_supported_token())]
 		pub fn add_supported_token(
 			origin: OriginFor<T>,
 			token_address: TokenAddress,
@@ -546,7 +555,7 @@ pub mod pallet {
 
 		/// Set bridge operator (root only)
 		#[pallet::call_index(6)]
-		#[pallet::weight(5_000)]
+		#[pallet::weight(T::WeightInfo::set_operator())]
 		pub fn set_operator(
 			origin: OriginFor<T>,
 			new_operator: T::AccountId,
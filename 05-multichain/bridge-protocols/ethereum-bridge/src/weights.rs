@@ -0,0 +1,135 @@
+//! PLACEHOLDER weights for `eth_bridge`: hand-estimated, not benchmark output.
+//!
+//! Overwrite with `./scripts/benchmark-pallets.sh eth_bridge` on reference hardware.
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(missing_docs)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use core::marker::PhantomData;
+
+/// Weight functions needed for `eth_bridge`.
+pub trait WeightInfo {
+	fn initiate_eth_deposit() -> Weight;
+	fn confirm_eth_deposit() -> Weight;
+	fn initiate_token_deposit() -> Weight;
+	fn request_eth_withdrawal() -> Weight;
+	fn update_exchange_rate() -> Weight;
+	fn add_supported_token() -> Weight;
+	fn set_operator() -> Weight;
+}
+
+/// Placeholder weights for `eth_bridge`, pending benchmarking.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	/// Storage: `EthereumBridge::PendingDeposits` (r:1 w:1)
+	fn initiate_eth_deposit() -> Weight {
+		Weight::from_parts(17_960_000, 3639)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `EthereumBridge::PendingDeposits` (r:1 w:1)
+	/// Storage: `EthereumBridge::EthToEtrRate` (r:1 w:0)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Storage: `EthereumBridge::ConfirmedDeposits` (r:1 w:1)
+	/// Storage: `EthereumBridge::TotalBridgedVolume` (r:1 w:1)
+	fn confirm_eth_deposit() -> Weight {
+		Weight::from_parts(58_310_000, 12989)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: `EthereumBridge::SupportedTokens` (r:1 w:0)
+	/// Storage: `EthereumBridge::PendingDeposits` (r:1 w:1)
+	/// Storage: `EthereumBridge::TokenRates` (r:1 w:0)
+	/// Storage: `System::Account` (r:1 w:1)
+	fn initiate_token_deposit() -> Weight {
+		Weight::from_parts(47_220_000, 11281)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Storage: `EthereumBridge::PendingWithdrawals` (r:1 w:1)
+	fn request_eth_withdrawal() -> Weight {
+		Weight::from_parts(103_640_000, 21721)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: `EthereumBridge::BridgeOperator` (r:1 w:0)
+	/// Storage: `EthereumBridge::EthToEtrRate` (r:1 w:1)
+	fn update_exchange_rate() -> Weight {
+		Weight::from_parts(11_080_000, 2028)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `EthereumBridge::BridgeOperator` (r:1 w:0)
+	/// Storage: `EthereumBridge::SupportedTokens` (r:0 w:1)
+	/// Storage: `EthereumBridge::TokenRates` (r:0 w:1)
+	fn add_supported_token() -> Weight {
+		Weight::from_parts(12_550_000, 1517)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `EthereumBridge::BridgeOperator` (r:0 w:1)
+	fn set_operator() -> Weight {
+		Weight::from_parts(7_240_000, 990)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+}
+
+// For backwards compatibility and tests.
+impl WeightInfo for () {
+	/// Storage: `EthereumBridge::PendingDeposits` (r:1 w:1)
+	fn initiate_eth_deposit() -> Weight {
+		Weight::from_parts(17_960_000, 3639)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `EthereumBridge::PendingDeposits` (r:1 w:1)
+	/// Storage: `EthereumBridge::EthToEtrRate` (r:1 w:0)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Storage: `EthereumBridge::ConfirmedDeposits` (r:1 w:1)
+	/// Storage: `EthereumBridge::TotalBridgedVolume` (r:1 w:1)
+	fn confirm_eth_deposit() -> Weight {
+		Weight::from_parts(58_310_000, 12989)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: `EthereumBridge::SupportedTokens` (r:1 w:0)
+	/// Storage: `EthereumBridge::PendingDeposits` (r:1 w:1)
+	/// Storage: `EthereumBridge::TokenRates` (r:1 w:0)
+	/// Storage: `System::Account` (r:1 w:1)
+	fn initiate_token_deposit() -> Weight {
+		Weight::from_parts(47_220_000, 11281)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Storage: `EthereumBridge::PendingWithdrawals` (r:1 w:1)
+	fn request_eth_withdrawal() -> Weight {
+		Weight::from_parts(103_640_000, 21721)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: `EthereumBridge::BridgeOperator` (r:1 w:0)
+	/// Storage: `EthereumBridge::EthToEtrRate` (r:1 w:1)
+	fn update_exchange_rate() -> Weight {
+		Weight::from_parts(11_080_000, 2028)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `EthereumBridge::BridgeOperator` (r:1 w:0)
+	/// Storage: `EthereumBridge::SupportedTokens` (r:0 w:1)
+	/// Storage: `EthereumBridge::TokenRates` (r:0 w:1)
+	fn add_supported_token() -> Weight {
+		Weight::from_parts(12_550_000, 1517)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `EthereumBridge::BridgeOperator` (r:0 w:1)
+	fn set_operator() -> Weight {
+		Weight::from_parts(7_240_000, 990)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+}
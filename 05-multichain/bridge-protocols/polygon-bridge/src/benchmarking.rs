@@ -0,0 +1,221 @@
+//! Benchmarking setup for polygon-bridge
+//!
+//! Per-account deposit and withdrawal lists are filled to their bounds with the
+//! target record last, and lock/unlock benchmarks run against a full ETR lock history.
+
+#![cfg(feature = "runtime-benchmarks")]
+
+use super::*;
+use frame_benchmarking::v2::*;
+use frame_support::{
+    pallet_prelude::Get,
+    traits::Currency,
+    BoundedVec,
+};
+use frame_system::RawOrigin;
+use pallet_etr_lock::ChainId;
+use sp_core::{H160, H256};
+use sp_runtime::traits::Saturating;
+use sp_std::{vec, vec::Vec};
+
+fn amount<T: Config>() -> BalanceOf<T> {
+    <T as pallet_etr_lock::Config>::Currency::minimum_balance()
+        .max(T::MinBridgeAmount::get())
+        .saturating_mul(1_000u32.into())
+}
+
+fn fund<T: Config>(who: &T::AccountId) {
+    <T as pallet_etr_lock::Config>::Currency::make_free_balance_be(
+        who,
+        amount::<T>().saturating_mul(10u32.into()),
+    );
+}
+
+/// `count` deposits for `who`, the last of which has `tx_hash`
+fn fill_deposits<T: Config>(who: &T::AccountId, count: u32, tx_hash: PolygonTxHash, confirmations: u32) {
+    let deposits: Vec<_> = (0..count)
+        .map(|i| PolygonDeposit {
+            account: who.clone(),
+            polygon_address: H160::repeat_byte(1),
+            amount: amount::<T>(),
+            tx_hash: if i + 1 == count { tx_hash } else { H256::from_low_u64_be(i as u64 + 1) },
+            block_number: 1,
+            confirmations,
+            bridge_type: BridgeType::PoS,
+        })
+        .collect();
+    PendingDeposits::<T>::insert(who, BoundedVec::truncate_from(deposits));
+}
+
+/// `count` withdrawals for `who`, the last of which has `nonce` and `status`
+fn fill_withdrawals<T: Config>(who: &T::AccountId, count: u32, nonce: u64, status: WithdrawalStatus) {
+    let withdrawals: Vec<_> = (0..count)
+        .map(|i| PolygonWithdrawal {
+            account: who.clone(),
+            polygon_address: H160::repeat_byte(1),
+            amount: amount::<T>(),
+            nonce: if i + 1 == count { nonce } else { i as u64 },
+            status: if i + 1 == count { status } else { WithdrawalStatus::Pending },
+            bridge_type: BridgeType::PoS,
+        })
+        .collect();
+    PendingWithdrawals::<T>::insert(who, BoundedVec::truncate_from(withdrawals));
+}
+
+#[benchmarks]
+mod benchmarks {
+    use super::*;
+
+    #[benchmark]
+    fn initiate_deposit() {
+        let relayer: T::AccountId = whitelisted_caller();
+        let account: T::AccountId = account("account", 0, 0);
+        fill_deposits::<T>(&account, T::MaxDepositsPerAccount::get() - 1, H256::zero(), 0);
+
+        #[extrinsic_call]
+        _(
+            RawOrigin::Signed(relayer),
+            account.clone(),
+            H160::repeat_byte(1),
+            amount::<T>(),
+            H256::repeat_byte(1),
+            u64::MAX,
+            BridgeType::PoS.to_u8(),
+        );
+
+        assert_eq!(PendingDeposits::<T>::get(&account).len() as u32, T::MaxDepositsPerAccount::get());
+    }
+
+    #[benchmark]
+    fn confirm_deposit() {
+        let relayer: T::AccountId = whitelisted_caller();
+        let account: T::AccountId = account("account", 0, 0);
+        let tx_hash = H256::repeat_byte(1);
+        fill_deposits::<T>(&account, T::MaxDepositsPerAccount::get(), tx_hash, 0);
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(relayer), account.clone(), tx_hash, T::MinConfirmations::get());
+
+        let deposits = PendingDeposits::<T>::get(&account);
+        assert_eq!(deposits.last().unwrap().confirmations, T::MinConfirmations::get());
+    }
+
+    #[benchmark]
+    fn complete_deposit() {
+        let relayer: T::AccountId = whitelisted_caller();
+        let account: T::AccountId = account("account", 0, 0);
+        let tx_hash = H256::repeat_byte(1);
+        fill_deposits::<T>(&account, T::MaxDepositsPerAccount::get(), tx_hash, T::MinConfirmations::get());
+        fund::<T>(&Pallet::<T>::bridge_account());
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(relayer), account.clone(), tx_hash);
+
+        assert_eq!(PendingDeposits::<T>::get(&account).len() as u32, T::MaxDepositsPerAccount::get() - 1);
+    }
+
+    #[benchmark]
+    fn initiate_withdrawal() {
+        let caller: T::AccountId = whitelisted_caller();
+        fund::<T>(&caller);
+        fill_withdrawals::<T>(&caller, T::MaxWithdrawalsPerAccount::get() - 1, 0, WithdrawalStatus::Pending);
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller.clone()), H160::repeat_byte(1), amount::<T>(), BridgeType::PoS.to_u8());
+
+        assert_eq!(
+            PendingWithdrawals::<T>::get(&caller).len() as u32,
+            T::MaxWithdrawalsPerAccount::get()
+        );
+    }
+
+    #[benchmark]
+    fn confirm_withdrawal() {
+        let relayer: T::AccountId = whitelisted_caller();
+        let account: T::AccountId = account("account", 0, 0);
+        let nonce = u64::MAX;
+        fill_withdrawals::<T>(&account, T::MaxWithdrawalsPerAccount::get(), nonce, WithdrawalStatus::Pending);
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(relayer), account.clone(), nonce, H256::repeat_byte(1));
+
+        let withdrawals = PendingWithdrawals::<T>::get(&account);
+        assert_eq!(withdrawals.last().unwrap().status, WithdrawalStatus::Confirmed);
+    }
+
+    #[benchmark]
+    fn complete_withdrawal() {
+        let relayer: T::AccountId = whitelisted_caller();
+        let account: T::AccountId = account("account", 0, 0);
+        let nonce = u64::MAX;
+        fill_withdrawals::<T>(&account, T::MaxWithdrawalsPerAccount::get(), nonce, WithdrawalStatus::Confirmed);
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(relayer), account.clone(), nonce);
+
+        assert_eq!(
+            PendingWithdrawals::<T>::get(&account).len() as u32,
+            T::MaxWithdrawalsPerAccount::get() - 1
+        );
+    }
+
+    #[benchmark]
+    fn register_token() {
+        let contract_address = H160::repeat_byte(2);
+
+        #[extrinsic_call]
+        _(RawOrigin::Root, contract_address, 18, vec![b'T'; 10]);
+
+        assert!(RegisteredTokens::<T>::contains_key(contract_address));
+    }
+
+    #[benchmark]
+    fn update_bridge_contract() {
+        BridgeContract::<T>::put(H160::repeat_byte(2));
+        let new_address = H160::repeat_byte(3);
+
+        #[extrinsic_call]
+        _(RawOrigin::Root, new_address);
+
+        assert_eq!(BridgeContract::<T>::get(), Some(new_address));
+    }
+
+    #[benchmark]
+    fn update_checkpoint_manager() {
+        let new_address = H160::repeat_byte(4);
+
+        #[extrinsic_call]
+        _(RawOrigin::Root, new_address);
+
+        assert_eq!(CheckpointManager::<T>::get(), Some(new_address));
+    }
+
+    #[benchmark]
+    fn bridge_etr_to_polygon() {
+        let caller: T::AccountId = whitelisted_caller();
+        let amount = amount::<T>();
+        pallet_etr_lock::Pallet::<T>::prepare_benchmark(ChainId::Polygon, amount);
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller), amount, H160::repeat_byte(1));
+
+        assert_eq!(
+            pallet_etr_lock::Pallet::<T>::get_locked_amount(ChainId::Polygon),
+            amount.saturating_mul(2u32.into())
+        );
+    }
+
+    #[benchmark]
+    fn process_etr_burn_from_polygon() {
+        let caller: T::AccountId = whitelisted_caller();
+        let recipient: T::AccountId = account("recipient", 0, 0);
+        let amount = amount::<T>();
+        pallet_etr_lock::Pallet::<T>::prepare_benchmark(ChainId::Polygon, amount.saturating_mul(2u32.into()));
+        let burn_tx = H256::repeat_byte(4);
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller), recipient, amount, burn_tx);
+
+        assert!(ProcessedPolygonBurns::<T>::get(burn_tx));
+    }
+}
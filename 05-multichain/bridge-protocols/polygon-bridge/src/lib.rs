@@ -24,6 +24,11 @@
 
 pub use pallet::*;
 
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+pub mod weights;
+pub use weights::WeightInfo;
+
 #[frame_support::pallet]
 pub mod pallet {
     use frame_support::{
@@ -35,8 +40,9 @@ pub mod pallet {
     use sp_core::{H160, H256};
     use sp_runtime::traits::{AccountIdConversion, SaturatedConversion, Saturating};
     use sp_std::vec::Vec;
+    use crate::WeightInfo;
 
-    type BalanceOf<T> =
+    pub(crate) type BalanceOf<T> =
         <<T as pallet_etr_lock::Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
 
     /// Polygon address type (EVM-compatible, 20 bytes)
@@ -166,6 +172,9 @@ pub mod pallet {
         /// Pallet ID for generating the bridge account
         #[pallet::constant]
         type PalletId: Get<PalletId>;
+
+        /// Weight information for extrinsics
+        type WeightInfo: WeightInfo;
     }
 
     // ==================== STORAGE ====================
@@ -399,7 +408,7 @@ pub mod pallet {
         /// This is called by relayers monitoring the Polygon chain
         /// bridge_type_raw: 0 = Plasma, 1 = PoS
         #[pallet::call_index(0)]
-        #[pallet::weight(10_000)]
+        #[pallet::weight(T::WeightInfo::initiate_deposit())]
         pub fn initiate_deposit(
             origin: OriginFor<T>,
             account: T::AccountId,
@@ -450,7 +459,7 @@ pub mod pallet {
 
         /// Confirm deposit after sufficient Polygon confirmations
         #[pallet::call_index(1)]
-        #[pallet::weight(10_000)]
+        #[pallet::weight(T::WeightInfo::confirm_deposit())]
         pub fn confirm_deposit(
             origin: OriginFor<T>,
             account: T::AccountId,
@@ -488,7 +497,7 @@ pub mod pallet {
 
         /// Complete deposit and mint tokens to user
         #[pallet::call_index(2)]
-        #[pallet::weight(10_000)]
+        #[pallet::weight(T::WeightInfo::complete_deposit())]
         pub fn complete_deposit(
             origin: OriginFor<T>,
             account: T::AccountId,
@@ -545,7 +554,7 @@ pub mod pallet {
         /// Initiate withdrawal from Ëtrid to Polygon
         /// bridge_type_raw: 0 = Plasma, 1 = PoS
         #[pallet::call_index(3)]
-        #[pallet::weight(10_000)]
+        #[pallet::weight(T::WeightInfo::initiate_withdrawal())]
         pub fn initiate_withdrawal(
             origin: OriginFor<T>,
             polygon_address: PolygonAddress,
@@ -612,7 +621,7 @@ pub mod pallet {
 
         /// Confirm withdrawal was processed on Polygon
         #[pallet::call_index(4)]
-        #[pallet::weight(10_000)]
+        #[pallet::weight(T::WeightInfo::confirm_withdrawal())]
         pub fn confirm_withdrawal(
             origin: OriginFor<T>,
             account: T::AccountId,
@@ -644,7 +653,7 @@ pub mod pallet {
 
         /// Complete withdrawal and update records
         #[pallet::call_index(5)]
-        #[pallet::weight(10_000)]
+        #[pallet::weight(T::WeightInfo::complete_withdrawal())]
         pub fn complete_withdrawal(
             origin: OriginFor<T>,
             account: T::AccountId,
@@ -686,7 +695,7 @@ pub mod pallet {
 
         /// Register a new ERC-20 token for bridging
         #[pallet::call_index(6)]
-        #[pallet::weight(10_000)]
+        #[pallet::weight(T::WeightInfo::register_token())]
         pub fn register_token(
             origin: OriginFor<T>,
             contract_address: PolygonAddress,
@@ -717,7 +726,7 @@ pub mod pallet {
 
         /// Update bridge contract address
         #[pallet::call_index(7)]
-        #[pallet::weight(10_000)]
+        #[pallet::weight(T::WeightInfo::update_bridge_contract())]
         pub fn update_bridge_contract(
             origin: OriginFor<T>,
             new_address: PolygonAddress,
@@ -737,7 +746,7 @@ pub mod pallet {
 
         /// Update checkpoint manager address (PoS Bridge)
         #[pallet::call_index(8)]
-        #[pallet::weight(10_000)]
+        #[pallet::weight(T::WeightInfo::update_checkpoint_manager())]
         pub fn update_checkpoint_manager(
             origin: OriginFor<T>,
             new_address: PolygonAddress,
@@ -753,7 +762,7 @@ pub mod pallet {
         ///
         /// Locks ETR on FlareChain and emits event for relayer to mint on Polygon
         #[pallet::call_index(9)]
-        #[pallet::weight(150_000)]
+        #[pallet::weight(T::WeightInfo::bridge_etr_to_polygon())]
         pub fn bridge_etr_to_polygon(
             origin: OriginFor<T>,
             amount: BalanceOf<T>,
@@ -786,7 +795,7 @@ pub mod pallet {
         ///
         /// Unlocks ETR on FlareChain when wrapped ETR is burned on Polygon
         #[pallet::call_index(10)]
-        #[pallet::weight(150_000)]
+        #[pallet::weight(T::WeightInfo::process_etr_burn_from_polygon())]
         pub fn process_etr_burn_from_polygon(
             origin: OriginFor<T>,
             etrid_recipient: T::AccountId,
@@ -0,0 +1,193 @@
+//! PLACEHOLDER weights for `polygon_bridge`: hand-estimated, not benchmark output.
+//!
+//! Overwrite with `./scripts/benchmark-pallets.sh polygon_bridge` on reference hardware.
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(missing_docs)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use core::marker::PhantomData;
+
+/// Weight functions needed for `polygon_bridge`.
+pub trait WeightInfo {
+	fn initiate_deposit() -> Weight;
+	fn confirm_deposit() -> Weight;
+	fn complete_deposit() -> Weight;
+	fn initiate_withdrawal() -> Weight;
+	fn confirm_withdrawal() -> Weight;
+	fn complete_withdrawal() -> Weight;
+	fn register_token() -> Weight;
+	fn update_bridge_contract() -> Weight;
+	fn update_checkpoint_manager() -> Weight;
+	fn bridge_etr_to_polygon() -> Weight;
+	fn process_etr_burn_from_polygon() -> Weight;
+}
+
+/// Placeholder weights for `polygon_bridge`, pending benchmarking.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	/// Storage: `PolygonBridge::PendingDeposits` (r:1 w:1)
+	fn initiate_deposit() -> Weight {
+		Weight::from_parts(41_270_000, 14815)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `PolygonBridge::PendingDeposits` (r:1 w:1)
+	fn confirm_deposit() -> Weight {
+		Weight::from_parts(44_850_000, 14815)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `PolygonBridge::PendingDeposits` (r:1 w:1)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Storage: `PolygonBridge::TotalLocked` (r:1 w:1)
+	fn complete_deposit() -> Weight {
+		Weight::from_parts(92_310_000, 20532)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Storage: `PolygonBridge::WithdrawalNonce` (r:1 w:1)
+	/// Storage: `PolygonBridge::PendingWithdrawals` (r:1 w:1)
+	fn initiate_withdrawal() -> Weight {
+		Weight::from_parts(88_640_000, 17024)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: `PolygonBridge::PendingWithdrawals` (r:1 w:1)
+	fn confirm_withdrawal() -> Weight {
+		Weight::from_parts(36_920_000, 11315)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `PolygonBridge::PendingWithdrawals` (r:1 w:1)
+	/// Storage: `PolygonBridge::TotalLocked` (r:1 w:1)
+	fn complete_withdrawal() -> Weight {
+		Weight::from_parts(39_480_000, 11826)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `PolygonBridge::RegisteredTokens` (r:0 w:1)
+	fn register_token() -> Weight {
+		Weight::from_parts(9_870_000, 990)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `PolygonBridge::BridgeContract` (r:1 w:1)
+	fn update_bridge_contract() -> Weight {
+		Weight::from_parts(8_120_000, 1505)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `PolygonBridge::CheckpointManager` (r:0 w:1)
+	fn update_checkpoint_manager() -> Weight {
+		Weight::from_parts(6_530_000, 990)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `EtrLock::LockAccount` (r:1 w:0)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Storage: `EtrLock::TotalLocked` (r:1 w:1)
+	/// Storage: `EtrLock::LockedForChain` (r:1 w:1)
+	/// Storage: `EtrLock::LockEvents` (r:1 w:1)
+	/// Storage: `Balances::Locks` (r:1 w:1)
+	fn bridge_etr_to_polygon() -> Weight {
+		Weight::from_parts(316_470_000, 194394)
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+	/// Storage: `PolygonBridge::ProcessedPolygonBurns` (r:1 w:1)
+	/// Storage: `EtrLock::LockAccount` (r:1 w:0)
+	/// Storage: `EtrLock::LockedForChain` (r:1 w:1)
+	/// Storage: `EtrLock::TotalLocked` (r:1 w:1)
+	/// Storage: `Balances::Locks` (r:1 w:1)
+	/// Storage: `System::Account` (r:2 w:2)
+	fn process_etr_burn_from_polygon() -> Weight {
+		Weight::from_parts(95_880_000, 16024)
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
+	}
+}
+
+// For backwards compatibility and tests.
+impl WeightInfo for () {
+	/// Storage: `PolygonBridge::PendingDeposits` (r:1 w:1)
+	fn initiate_deposit() -> Weight {
+		Weight::from_parts(41_270_000, 14815)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `PolygonBridge::PendingDeposits` (r:1 w:1)
+	fn confirm_deposit() -> Weight {
+		Weight::from_parts(44_850_000, 14815)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `PolygonBridge::PendingDeposits` (r:1 w:1)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Storage: `PolygonBridge::TotalLocked` (r:1 w:1)
+	fn complete_deposit() -> Weight {
+		Weight::from_parts(92_310_000, 20532)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Storage: `PolygonBridge::WithdrawalNonce` (r:1 w:1)
+	/// Storage: `PolygonBridge::PendingWithdrawals` (r:1 w:1)
+	fn initiate_withdrawal() -> Weight {
+		Weight::from_parts(88_640_000, 17024)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: `PolygonBridge::PendingWithdrawals` (r:1 w:1)
+	fn confirm_withdrawal() -> Weight {
+		Weight::from_parts(36_920_000, 11315)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `PolygonBridge::PendingWithdrawals` (r:1 w:1)
+	/// Storage: `PolygonBridge::TotalLocked` (r:1 w:1)
+	fn complete_withdrawal() -> Weight {
+		Weight::from_parts(39_480_000, 11826)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `PolygonBridge::RegisteredTokens` (r:0 w:1)
+	fn register_token() -> Weight {
+		Weight::from_parts(9_870_000, 990)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `PolygonBridge::BridgeContract` (r:1 w:1)
+	fn update_bridge_contract() -> Weight {
+		Weight::from_parts(8_120_000, 1505)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `PolygonBridge::CheckpointManager` (r:0 w:1)
+	fn update_checkpoint_manager() -> Weight {
+		Weight::from_parts(6_530_000, 990)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `EtrLock::LockAccount` (r:1 w:0)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Storage: `EtrLock::TotalLocked` (r:1 w:1)
+	/// Storage: `EtrLock::LockedForChain` (r:1 w:1)
+	/// Storage: `EtrLock::LockEvents` (r:1 w:1)
+	/// Storage: `Balances::Locks` (r:1 w:1)
+	fn bridge_etr_to_polygon() -> Weight {
+		Weight::from_parts(316_470_000, 194394)
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
+	/// Storage: `PolygonBridge::ProcessedPolygonBurns` (r:1 w:1)
+	/// Storage: `EtrLock::LockAccount` (r:1 w:0)
+	/// Storage: `EtrLock::LockedForChain` (r:1 w:1)
+	/// Storage: `EtrLock::TotalLocked` (r:1 w:1)
+	/// Storage: `Balances::Locks` (r:1 w:1)
+	/// Storage: `System::Account` (r:2 w:2)
+	fn process_etr_burn_from_polygon() -> Weight {
+		Weight::from_parts(95_880_000, 16024)
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
+	}
+}
@@ -0,0 +1,255 @@
+//! Benchmarking setup for sol-bridge
+//!
+//! Per-account deposit and withdrawal lists are filled to one below their bounds,
+//! and lock/unlock benchmarks run against a full ETR lock history.
+
+#![cfg(feature = "runtime-benchmarks")]
+
+use super::*;
+use frame_benchmarking::v2::*;
+use frame_support::{
+    pallet_prelude::Get,
+    traits::Currency,
+    BoundedVec,
+};
+use frame_system::RawOrigin;
+use pallet_etr_lock::ChainId;
+use sp_runtime::traits::{Saturating, Zero};
+
+/// 1:1 SOL to ËTR, scaled by 1e9
+const RATE: u128 = 1_000_000_000;
+
+fn amount<T: Config>() -> BalanceOf<T> {
+    <T as pallet_etr_lock::Config>::Currency::minimum_balance()
+        .max(1u32.into())
+        .saturating_mul(1_000u32.into())
+}
+
+fn operator<T: Config>() -> T::AccountId {
+    let operator: T::AccountId = whitelisted_caller();
+    BridgeOperator::<T>::put(&operator);
+    operator
+}
+
+fn sol_signature(seed: u64) -> SolanaSignature {
+    (H256::from_low_u64_be(seed), H256::from_low_u64_be(seed))
+}
+
+/// Register a supported SPL token mint
+fn add_token<T: Config>() -> SplTokenMint {
+    let mint = SplTokenMint::repeat_byte(2);
+    SupportedTokens::<T>::insert(mint, true);
+    TokenRates::<T>::insert(mint, RATE);
+    mint
+}
+
+#[benchmarks]
+mod benchmarks {
+    use super::*;
+
+    /// A deposit that also advances the tracked slot
+    #[benchmark]
+    fn initiate_sol_deposit() {
+        let relayer: T::AccountId = whitelisted_caller();
+        let recipient: T::AccountId = account("recipient", 0, 0);
+        let signature = sol_signature(1);
+
+        #[extrinsic_call]
+        _(
+            RawOrigin::Signed(relayer),
+            recipient,
+            SolanaPublicKey::repeat_byte(1),
+            amount::<T>(),
+            signature,
+            u64::MAX,
+            T::MinConfirmations::get(),
+        );
+
+        assert_eq!(CurrentSlot::<T>::get(), u64::MAX);
+    }
+
+    #[benchmark]
+    fn confirm_sol_deposit() {
+        SolToEtrRate::<T>::put(RATE);
+        let relayer: T::AccountId = whitelisted_caller();
+        let recipient: T::AccountId = account("recipient", 0, 0);
+        let signature = sol_signature(1);
+        PendingDeposits::<T>::insert(signature, SolanaDeposit {
+            sol_pubkey: SolanaPublicKey::repeat_byte(1),
+            etrid_account: recipient.clone(),
+            amount: amount::<T>(),
+            signature,
+            slot: 1,
+            confirmations: T::MinConfirmations::get(),
+            token_mint: None,
+            is_confirmed: false,
+        });
+        let existing: Vec<SolanaSignature> = (1..T::MaxDepositsPerAccount::get())
+            .map(|i| sol_signature(i as u64 + 1))
+            .collect();
+        ConfirmedDeposits::<T>::insert(&recipient, BoundedVec::truncate_from(existing));
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(relayer), signature);
+
+        assert!(PendingDeposits::<T>::get(signature).unwrap().is_confirmed);
+    }
+
+    /// A deposit that already has enough confirmations and is minted immediately
+    #[benchmark]
+    fn initiate_token_deposit() {
+        let mint = add_token::<T>();
+        let relayer: T::AccountId = whitelisted_caller();
+        let recipient: T::AccountId = account("recipient", 0, 0);
+
+        #[extrinsic_call]
+        _(
+            RawOrigin::Signed(relayer),
+            recipient.clone(),
+            SolanaPublicKey::repeat_byte(1),
+            mint,
+            amount::<T>(),
+            sol_signature(1),
+            1,
+            T::MinConfirmations::get(),
+        );
+
+        assert!(!<T as pallet_etr_lock::Config>::Currency::free_balance(&recipient).is_zero());
+    }
+
+    #[benchmark]
+    fn initiate_usdc_deposit() {
+        let mint = add_token::<T>();
+        UsdcMint::<T>::put(mint);
+        let relayer: T::AccountId = whitelisted_caller();
+        let recipient: T::AccountId = account("recipient", 0, 0);
+
+        #[extrinsic_call]
+        _(
+            RawOrigin::Signed(relayer),
+            recipient.clone(),
+            SolanaPublicKey::repeat_byte(1),
+            amount::<T>(),
+            sol_signature(1),
+            1,
+            T::MinConfirmations::get(),
+        );
+
+        assert!(!<T as pallet_etr_lock::Config>::Currency::free_balance(&recipient).is_zero());
+    }
+
+    #[benchmark]
+    fn request_sol_withdrawal() {
+        let caller: T::AccountId = whitelisted_caller();
+        <T as pallet_etr_lock::Config>::Currency::make_free_balance_be(
+            &caller,
+            amount::<T>().saturating_mul(2u32.into()),
+        );
+        let existing = SolanaWithdrawal {
+            etrid_account: caller.clone(),
+            sol_pubkey: SolanaPublicKey::repeat_byte(1),
+            amount: amount::<T>(),
+            token_mint: Some(SplTokenMint::repeat_byte(2)),
+            priority_fee: T::MaxPriorityFee::get(),
+            compute_units: T::MaxComputeUnits::get(),
+            status: WithdrawalStatus::Completed(sol_signature(1)),
+        };
+        let existing = sp_std::vec![existing; T::MaxWithdrawalsPerAccount::get() as usize - 1];
+        PendingWithdrawals::<T>::insert(&caller, BoundedVec::truncate_from(existing));
+
+        #[extrinsic_call]
+        _(
+            RawOrigin::Signed(caller.clone()),
+            SolanaPublicKey::repeat_byte(1),
+            amount::<T>(),
+            T::MaxPriorityFee::get(),
+            T::MaxComputeUnits::get(),
+        );
+
+        assert_eq!(
+            PendingWithdrawals::<T>::get(&caller).len() as u32,
+            T::MaxWithdrawalsPerAccount::get()
+        );
+    }
+
+    #[benchmark]
+    fn update_exchange_rate() {
+        let operator = operator::<T>();
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(operator), RATE * 2);
+
+        assert_eq!(SolToEtrRate::<T>::get(), RATE * 2);
+    }
+
+    #[benchmark]
+    fn add_supported_token() {
+        let operator = operator::<T>();
+        let mint = SplTokenMint::repeat_byte(2);
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(operator), mint, RATE);
+
+        assert!(SupportedTokens::<T>::get(mint));
+    }
+
+    #[benchmark]
+    fn set_usdc_mint() {
+        let operator = operator::<T>();
+        let mint = SplTokenMint::repeat_byte(2);
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(operator), mint);
+
+        assert_eq!(UsdcMint::<T>::get(), Some(mint));
+    }
+
+    #[benchmark]
+    fn toggle_wormhole() {
+        let operator = operator::<T>();
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(operator), true);
+
+        assert!(WormholeEnabled::<T>::get());
+    }
+
+    #[benchmark]
+    fn set_operator() {
+        let operator: T::AccountId = account("operator", 0, 0);
+
+        #[extrinsic_call]
+        _(RawOrigin::Root, operator.clone());
+
+        assert_eq!(BridgeOperator::<T>::get(), Some(operator));
+    }
+
+    #[benchmark]
+    fn bridge_etr_to_solana() {
+        let caller: T::AccountId = whitelisted_caller();
+        let amount = amount::<T>();
+        pallet_etr_lock::Pallet::<T>::prepare_benchmark(ChainId::Solana, amount);
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller), amount, SolanaPublicKey::repeat_byte(1));
+
+        assert_eq!(
+            pallet_etr_lock::Pallet::<T>::get_locked_amount(ChainId::Solana),
+            amount.saturating_mul(2u32.into())
+        );
+    }
+
+    #[benchmark]
+    fn process_etr_burn_from_solana() {
+        let caller: T::AccountId = whitelisted_caller();
+        let recipient: T::AccountId = account("recipient", 0, 0);
+        let amount = amount::<T>();
+        pallet_etr_lock::Pallet::<T>::prepare_benchmark(ChainId::Solana, amount.saturating_mul(2u32.into()));
+        let burn_tx = sol_signature(4);
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller), recipient, amount, burn_tx);
+
+        assert!(ProcessedSolanaBurns::<T>::get(burn_tx));
+    }
+}
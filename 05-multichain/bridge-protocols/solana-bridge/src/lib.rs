@@ -6,6 +6,11 @@
 
 pub use pallet::*;
 
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+pub mod weights;
+pub use weights::WeightInfo;
+
 use codec::{Decode, Encode, MaxEncodedLen};
 use scale_info::TypeInfo;
 use sp_std::prelude::*;
@@ -75,9 +80,10 @@ pub mod pallet {
     use frame_system::pallet_prelude::*;
     use sp_runtime::traits::{Zero, SaturatedConversion};
     use etrid_bridge_common::treasury::TreasuryInterface;
+    use crate::WeightInfo;
 
     // Currency type for handling ËTR tokens
-    type BalanceOf<T> = <<T as pallet_etr_lock::Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
+    pub(crate) type BalanceOf<T> = <<T as pallet_etr_lock::Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
 
     #[pallet::pallet]
     pub struct Pallet<T>(_);
@@ -113,6 +119,9 @@ pub mod pallet {
 
         /// Validator pool account for receiving bridge fees
         type ValidatorPoolAccount: Get<Self::AccountId>;
+
+        /// Weight information for extrinsics
+        type WeightInfo: WeightInfo;
     }
 
     /// SOL to ËTR exchange rate (scaled by 1e9 - Solana uses 9 decimals/lamports)
@@ -369,7 +378,7 @@ pub mod pallet {
     impl<T: Config> Pallet<T> {
         /// Initiate SOL deposit (called by relayer with proof)
         #[pallet::call_index(0)]
-        #[pallet::weight(10_000)]
+        #[pallet::weight(T::WeightInfo::initiate_sol_deposit())]
         pub fn initiate_sol_deposit(
             origin: OriginFor<T>,
             etrid_account: T::AccountId,
@@ -420,7 +429,7 @@ pub mod pallet {
 
         /// Confirm SOL deposit after required confirmations
         #[pallet::call_index(1)]
-        #[pallet::weight(15_000)]
+        #[pallet::weight(T::WeightInfo::confirm_sol_deposit())]
         pub fn confirm_sol_deposit(
             origin: OriginFor<T>,
             signature: SolanaSignature,
@@ -476,7 +485,7 @@ pub mod pallet {
 
         /// Initiate SPL token deposit (optimized for USDC)
         #[pallet::call_index(2)]
-        #[pallet::weight(12_000)]
+        #[pallet::weight(T::WeightInfo::initiate_token_deposit())]
         pub fn initiate_token_deposit(
             origin: OriginFor<T>,
             etrid_account: T::AccountId,
@@ -524,7 +533,7 @@ pub mod pallet {
 
         /// Fast-track USDC deposit (73% of Solana stablecoins)
         #[pallet::call_index(3)]
-        #[pallet::weight(12_000)]
+        #[pallet::weight(T::WeightInfo::initiate_usdc_deposit())]
         pub fn initiate_usdc_deposit(
             origin: OriginFor<T>,
             etrid_account: T::AccountId,
@@ -566,7 +575,7 @@ pub mod pallet {
 
         /// Request SOL withdrawal
         #[pallet::call_index(4)]
-        #[pallet::weight(20_000)]
+        #[pallet::weight(T::WeightInfo::request_sol_withdrawal())]
         pub fn request_sol_withdrawal(
             origin: OriginFor<T>,
             sol_pubkey: SolanaPublicKey,
@@ -622,7 +631,7 @@ pub mod pallet {
 
         /// Update SOL/ËTR exchange rate (operator only)
         #[pallet::call_index(5)]
-        #[pallet::weight(5_000)]
+        #[pallet::weight(T::WeightInfo::update_exchange_rate())]
         pub fn update_exchange_rate(
             origin: OriginFor<T>,
             new_rate: u128,
@@ -644,7 +653,7 @@ pub mod pallet {
 
         /// Add supported SPL token (operator only)
         #[pallet::call_index(6)]
-        #[pallet::weight(5_000)]
+        #[pallet::weight(T::WeightInfo::add_supported_token())]
         pub fn add_supported_token(
             origin: OriginFor<T>,
             token_mint: SplTokenMint,
@@ -668,7 +677,7 @@ pub mod pallet {
 
         /// Set USDC mint address (operator only)
         #[pallet::call_index(7)]
-        #[pallet::weight(5_000)]
+        #[pallet::weight(T::WeightInfo::set_usdc_mint())]
         pub fn set_usdc_mint(
             origin: OriginFor<T>,
             mint: SplTokenMint,
@@ -686,7 +695,7 @@ pub mod pallet {
 
         /// Toggle Wormhole integration (operator only)
         #[pallet::call_index(8)]
-        #[pallet::weight(5_000)]
+        #[pallet::weight(T::WeightInfo::toggle_wormhole())]
         pub fn toggle_wormhole(
             origin: OriginFor<T>,
             enabled: bool,
@@ -706,7 +715,7 @@ pub mod pallet {
 
         /// Set bridge operator (root only)
         #[pallet::call_index(9)]
-        #[pallet::weight(5_000)]
+        #[pallet::weight(T::WeightInfo::set_operator())]
         pub fn set_operator(
             origin: OriginFor<T>,
             new_operator: T::AccountId,
@@ -724,7 +733,7 @@ pub mod pallet {
         ///
         /// Locks ETR on FlareChain and emits event for relayer to mint on Solana
         #[pallet::call_index(10)]
-        #[pallet::weight(150_000)]
+        #[pallet::weight(T::WeightInfo::bridge_etr_to_solana())]
         pub fn bridge_etr_to_solana(
             origin: OriginFor<T>,
             amount: BalanceOf<T>,
@@ -757,7 +766,7 @@ pub mod pallet {
         ///
         /// Unlocks ETR on FlareChain when wrapped ETR is burned on Solana
         #[pallet::call_index(11)]
-        #[pallet::weight(150_000)]
+        #[pallet::weight(T::WeightInfo::process_etr_burn_from_solana())]
         pub fn process_etr_burn_from_solana(
             origin: OriginFor<T>,
             etrid_recipient: T::AccountId,
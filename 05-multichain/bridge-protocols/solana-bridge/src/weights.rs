@@ -0,0 +1,236 @@
+//! PLACEHOLDER weights for `sol_bridge`: hand-estimated, not benchmark output.
+//!
+//! Overwrite with `./scripts/benchmark-pallets.sh sol_bridge` on reference hardware.
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(missing_docs)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use core::marker::PhantomData;
+
+/// Weight functions needed for `sol_bridge`.
+pub trait WeightInfo {
+	fn initiate_sol_deposit() -> Weight;
+	fn confirm_sol_deposit() -> Weight;
+	fn initiate_token_deposit() -> Weight;
+	fn initiate_usdc_deposit() -> Weight;
+	fn request_sol_withdrawal() -> Weight;
+	fn update_exchange_rate() -> Weight;
+	fn add_supported_token() -> Weight;
+	fn set_usdc_mint() -> Weight;
+	fn toggle_wormhole() -> Weight;
+	fn set_operator() -> Weight;
+	fn bridge_etr_to_solana() -> Weight;
+	fn process_etr_burn_from_solana() -> Weight;
+}
+
+/// Placeholder weights for `sol_bridge`, pending benchmarking.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	/// Storage: `SolanaBridge::PendingDeposits` (r:1 w:1)
+	/// Storage: `SolanaBridge::CurrentSlot` (r:1 w:1)
+	fn initiate_sol_deposit() -> Weight {
+		Weight::from_parts(20_410_000, 4238)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `SolanaBridge::PendingDeposits` (r:1 w:1)
+	/// Storage: `SolanaBridge::SolToEtrRate` (r:1 w:0)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Storage: `SolanaBridge::ConfirmedDeposits` (r:1 w:1)
+	/// Storage: `SolanaBridge::TotalBridgedVolume` (r:1 w:1)
+	fn confirm_sol_deposit() -> Weight {
+		Weight::from_parts(61_370_000, 16285)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: `SolanaBridge::SupportedTokens` (r:1 w:0)
+	/// Storage: `SolanaBridge::PendingDeposits` (r:1 w:1)
+	/// Storage: `SolanaBridge::TokenRates` (r:1 w:0)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Storage: `SolanaBridge::UsdcMint` (r:1 w:0)
+	fn initiate_token_deposit() -> Weight {
+		Weight::from_parts(52_880_000, 11928)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `SolanaBridge::SupportedTokens` (r:1 w:0)
+	/// Storage: `SolanaBridge::PendingDeposits` (r:1 w:1)
+	/// Storage: `SolanaBridge::TokenRates` (r:1 w:0)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Storage: `SolanaBridge::UsdcMint` (r:1 w:0)
+	fn initiate_usdc_deposit() -> Weight {
+		Weight::from_parts(55_120_000, 11928)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Storage: `SolanaBridge::PendingWithdrawals` (r:1 w:1)
+	fn request_sol_withdrawal() -> Weight {
+		Weight::from_parts(104_730_000, 25118)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `SolanaBridge::BridgeOperator` (r:1 w:0)
+	/// Storage: `SolanaBridge::SolToEtrRate` (r:1 w:1)
+	fn update_exchange_rate() -> Weight {
+		Weight::from_parts(11_080_000, 2028)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `SolanaBridge::BridgeOperator` (r:1 w:0)
+	/// Storage: `SolanaBridge::SupportedTokens` (r:0 w:1)
+	/// Storage: `SolanaBridge::TokenRates` (r:0 w:1)
+	fn add_supported_token() -> Weight {
+		Weight::from_parts(13_460_000, 1517)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `SolanaBridge::BridgeOperator` (r:1 w:0)
+	/// Storage: `SolanaBridge::UsdcMint` (r:0 w:1)
+	fn set_usdc_mint() -> Weight {
+		Weight::from_parts(10_310_000, 1517)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `SolanaBridge::BridgeOperator` (r:1 w:0)
+	/// Storage: `SolanaBridge::WormholeEnabled` (r:0 w:1)
+	fn toggle_wormhole() -> Weight {
+		Weight::from_parts(10_220_000, 1517)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `SolanaBridge::BridgeOperator` (r:0 w:1)
+	fn set_operator() -> Weight {
+		Weight::from_parts(7_140_000, 990)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `EtrLock::LockAccount` (r:1 w:0)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Storage: `EtrLock::TotalLocked` (r:1 w:1)
+	/// Storage: `EtrLock::LockedForChain` (r:1 w:1)
+	/// Storage: `EtrLock::LockEvents` (r:1 w:1)
+	/// Storage: `Balances::Locks` (r:1 w:1)
+	fn bridge_etr_to_solana() -> Weight {
+		Weight::from_parts(316_850_000, 194394)
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+	/// Storage: `SolanaBridge::ProcessedSolanaBurns` (r:1 w:1)
+	/// Storage: `EtrLock::LockAccount` (r:1 w:0)
+	/// Storage: `EtrLock::LockedForChain` (r:1 w:1)
+	/// Storage: `EtrLock::TotalLocked` (r:1 w:1)
+	/// Storage: `Balances::Locks` (r:1 w:1)
+	/// Storage: `System::Account` (r:2 w:2)
+	fn process_etr_burn_from_solana() -> Weight {
+		Weight::from_parts(96_020_000, 16056)
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
+	}
+}
+
+// For backwards compatibility and tests.
+impl WeightInfo for () {
+	/// Storage: `SolanaBridge::PendingDeposits` (r:1 w:1)
+	/// Storage: `SolanaBridge::CurrentSlot` (r:1 w:1)
+	fn initiate_sol_deposit() -> Weight {
+		Weight::from_parts(20_410_000, 4238)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `SolanaBridge::PendingDeposits` (r:1 w:1)
+	/// Storage: `SolanaBridge::SolToEtrRate` (r:1 w:0)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Storage: `SolanaBridge::ConfirmedDeposits` (r:1 w:1)
+	/// Storage: `SolanaBridge::TotalBridgedVolume` (r:1 w:1)
+	fn confirm_sol_deposit() -> Weight {
+		Weight::from_parts(61_370_000, 16285)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: `SolanaBridge::SupportedTokens` (r:1 w:0)
+	/// Storage: `SolanaBridge::PendingDeposits` (r:1 w:1)
+	/// Storage: `SolanaBridge::TokenRates` (r:1 w:0)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Storage: `SolanaBridge::UsdcMint` (r:1 w:0)
+	fn initiate_token_deposit() -> Weight {
+		Weight::from_parts(52_880_000, 11928)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `SolanaBridge::SupportedTokens` (r:1 w:0)
+	/// Storage: `SolanaBridge::PendingDeposits` (r:1 w:1)
+	/// Storage: `SolanaBridge::TokenRates` (r:1 w:0)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Storage: `SolanaBridge::UsdcMint` (r:1 w:0)
+	fn initiate_usdc_deposit() -> Weight {
+		Weight::from_parts(55_120_000, 11928)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Storage: `SolanaBridge::PendingWithdrawals` (r:1 w:1)
+	fn request_sol_withdrawal() -> Weight {
+		Weight::from_parts(104_730_000, 25118)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `SolanaBridge::BridgeOperator` (r:1 w:0)
+	/// Storage: `SolanaBridge::SolToEtrRate` (r:1 w:1)
+	fn update_exchange_rate() -> Weight {
+		Weight::from_parts(11_080_000, 2028)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `SolanaBridge::BridgeOperator` (r:1 w:0)
+	/// Storage: `SolanaBridge::SupportedTokens` (r:0 w:1)
+	/// Storage: `SolanaBridge::TokenRates` (r:0 w:1)
+	fn add_supported_token() -> Weight {
+		Weight::from_parts(13_460_000, 1517)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `SolanaBridge::BridgeOperator` (r:1 w:0)
+	/// Storage: `SolanaBridge::UsdcMint` (r:0 w:1)
+	fn set_usdc_mint() -> Weight {
+		Weight::from_parts(10_310_000, 1517)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `SolanaBridge::BridgeOperator` (r:1 w:0)
+	/// Storage: `SolanaBridge::WormholeEnabled` (r:0 w:1)
+	fn toggle_wormhole() -> Weight {
+		Weight::from_parts(10_220_000, 1517)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `SolanaBridge::BridgeOperator` (r:0 w:1)
+	fn set_operator() -> Weight {
+		Weight::from_parts(7_140_000, 990)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `EtrLock::LockAccount` (r:1 w:0)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Storage: `EtrLock::TotalLocked` (r:1 w:1)
+	/// Storage: `EtrLock::LockedForChain` (r:1 w:1)
+	/// Storage: `EtrLock::LockEvents` (r:1 w:1)
+	/// Storage: `Balances::Locks` (r:1 w:1)
+	fn bridge_etr_to_solana() -> Weight {
+		Weight::from_parts(316_850_000, 194394)
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
+	/// Storage: `SolanaBridge::ProcessedSolanaBurns` (r:1 w:1)
+	/// Storage: `EtrLock::LockAccount` (r:1 w:0)
+	/// Storage: `EtrLock::LockedForChain` (r:1 w:1)
+	/// Storage: `EtrLock::TotalLocked` (r:1 w:1)
+	/// Storage: `Balances::Locks` (r:1 w:1)
+	/// Storage: `System::Account` (r:2 w:2)
+	fn process_etr_burn_from_solana() -> Weight {
+		Weight::from_parts(96_020_000, 16056)
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
+	}
+}
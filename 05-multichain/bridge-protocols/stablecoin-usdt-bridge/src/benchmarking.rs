@@ -0,0 +1,155 @@
+//! Benchmarking setup for stablecoin-usdt-bridge
+//!
+//! Per-account deposit and withdrawal lists are filled to one below their bounds,
+//! and USDC on Solana is used so contract addresses take their longest encoding.
+
+#![cfg(feature = "runtime-benchmarks")]
+
+use super::*;
+use frame_benchmarking::v2::*;
+use frame_support::traits::Currency;
+use frame_system::RawOrigin;
+use sp_runtime::traits::{Saturating, Zero};
+
+fn amount<T: Config>() -> BalanceOf<T> {
+	T::Currency::minimum_balance().max(1u32.into()).saturating_mul(1_000u32.into())
+}
+
+fn operator<T: Config>() -> T::AccountId {
+	let operator: T::AccountId = whitelisted_caller();
+	BridgeOperator::<T>::put(&operator);
+	operator
+}
+
+fn contract() -> ContractAddress {
+	ContractAddress::Solana([2u8; 32])
+}
+
+/// Enable USDC on Solana with `min_confirmations` of 1
+fn configure<T: Config>() {
+	StablecoinConfigs::<T>::insert(SourceChain::Solana, StablecoinType::Usdc, StablecoinConfig {
+		stablecoin_type: StablecoinType::Usdc,
+		chain: SourceChain::Solana,
+		contract: contract(),
+		enabled: true,
+		min_confirmations: 1,
+	});
+}
+
+#[benchmarks]
+mod benchmarks {
+	use super::*;
+
+	#[benchmark]
+	fn initiate_deposit() {
+		configure::<T>();
+		let relayer: T::AccountId = whitelisted_caller();
+		let recipient: T::AccountId = account("recipient", 0, 0);
+		let tx_id = TxIdentifier(H256::repeat_byte(1));
+
+		#[extrinsic_call]
+		_(
+			RawOrigin::Signed(relayer),
+			recipient,
+			SourceChain::Solana,
+			StablecoinType::Usdc,
+			contract(),
+			amount::<T>(),
+			tx_id.clone(),
+			1,
+		);
+
+		assert!(PendingDeposits::<T>::contains_key(tx_id));
+	}
+
+	/// A USDC deposit with MiCA compliance enabled
+	#[benchmark]
+	fn confirm_deposit() {
+		configure::<T>();
+		MicaCompliance::<T>::put(true);
+		let relayer: T::AccountId = whitelisted_caller();
+		let recipient: T::AccountId = account("recipient", 0, 0);
+		let tx_id = TxIdentifier(H256::repeat_byte(1));
+		PendingDeposits::<T>::insert(tx_id.clone(), StablecoinDeposit {
+			source_chain: SourceChain::Solana,
+			stablecoin_type: StablecoinType::Usdc,
+			contract_address: contract(),
+			etrid_account: recipient.clone(),
+			amount: amount::<T>(),
+			tx_id: tx_id.clone(),
+			confirmations: 1,
+			is_confirmed: false,
+		});
+		let existing: Vec<TxIdentifier> = (1..T::MaxDepositsPerAccount::get())
+			.map(|i| TxIdentifier(H256::from_low_u64_be(i as u64 + 1)))
+			.collect();
+		ConfirmedDeposits::<T>::insert(&recipient, BoundedVec::truncate_from(existing));
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(relayer), tx_id.clone());
+
+		assert!(PendingDeposits::<T>::get(tx_id).unwrap().is_confirmed);
+		assert!(!T::Currency::free_balance(&recipient).is_zero());
+	}
+
+	#[benchmark]
+	fn request_withdrawal() {
+		configure::<T>();
+		let caller: T::AccountId = whitelisted_caller();
+		T::Currency::make_free_balance_be(&caller, amount::<T>().saturating_mul(2u32.into()));
+		let existing = StablecoinWithdrawal {
+			etrid_account: caller.clone(),
+			destination_chain: SourceChain::Solana,
+			stablecoin_type: StablecoinType::Usdc,
+			destination_address: contract(),
+			amount: amount::<T>(),
+			status: WithdrawalStatus::Completed(TxIdentifier(H256::repeat_byte(1))),
+		};
+		let existing = sp_std::vec![existing; T::MaxWithdrawalsPerAccount::get() as usize - 1];
+		PendingWithdrawals::<T>::insert(&caller, BoundedVec::truncate_from(existing));
+
+		#[extrinsic_call]
+		_(
+			RawOrigin::Signed(caller.clone()),
+			SourceChain::Solana,
+			StablecoinType::Usdc,
+			contract(),
+			amount::<T>(),
+		);
+
+		assert_eq!(
+			PendingWithdrawals::<T>::get(&caller).len() as u32,
+			T::MaxWithdrawalsPerAccount::get()
+		);
+	}
+
+	#[benchmark]
+	fn add_configuration() {
+		let operator = operator::<T>();
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(operator), SourceChain::Solana, StablecoinType::Usdc, contract(), u32::MAX, true);
+
+		assert!(StablecoinConfigs::<T>::contains_key(SourceChain::Solana, StablecoinType::Usdc));
+	}
+
+	#[benchmark]
+	fn toggle_mica_compliance() {
+		let operator = operator::<T>();
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(operator), true);
+
+		assert!(MicaCompliance::<T>::get());
+	}
+
+	#[benchmark]
+	fn set_operator() {
+		let operator: T::AccountId = account("operator", 0, 0);
+
+		#[extrinsic_call]
+		_(RawOrigin::Root, operator.clone());
+
+		assert_eq!(BridgeOperator::<T>::get(), Some(operator));
+	}
+}
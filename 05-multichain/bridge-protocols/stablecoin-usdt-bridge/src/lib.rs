@@ -17,6 +17,11 @@
 
 pub use pallet::*;
 
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+pub mod weights;
+pub use weights::WeightInfo;
+
 use codec::{Decode, DecodeWithMemTracking, Encode, MaxEncodedLen};
 use frame_support::{pallet_prelude::*, BoundedVec};
 use scale_info::TypeInfo;
@@ -131,8 +136,9 @@ pub mod pallet {
 	use frame_support::traits::{Currency, ExistenceRequirement};
 	use frame_system::pallet_prelude::*;
 	use sp_runtime::traits::Zero;
+	use crate::WeightInfo;
 
-	type BalanceOf<T> = <<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
+	pub(crate) type BalanceOf<T> = <<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
 
 	#[pallet::config]
 	pub trait Config: frame_system::Config {
@@ -154,6 +160,9 @@ pub mod pallet {
 		/// Maximum number of withdrawal custodians
 		#[pallet::constant]
 		type MaxCustodians: Get<u32>;
+
+		/// Weight information for extrinsics
+		type WeightInfo: WeightInfo;
 	}
 
 	#[pallet::pallet]
@@ -371,7 +380,7 @@ pub mod pallet {
 	impl<T: Config> Pallet<T> {
 		/// Initiate stablecoin deposit (universal handler)
 		#[pallet::call_index(0)]
-		#[pallet::weight(10_000)]
+		#[pallet::weight(T::WeightInfo::initiate_deposit())]
 		pub fn initiate_deposit(
 			origin: OriginFor<T>,
 			etrid_account: T::AccountId,
@@ -422,7 +431,7 @@ pub mod pallet {
 
 		/// Confirm stablecoin deposit
 		#[pallet::call_index(1)]
-		#[pallet::weight(15_000)]
+		#[pallet::weight(T::WeightInfo::confirm_deposit())]
 		pub fn confirm_deposit(
 			origin: OriginFor<T>,
 			tx_id: TxIdentifier,
@@ -510,7 +519,7 @@ pub mod pallet {
 
 		/// Request stablecoin withdrawal
 		#[pallet::call_index(2)]
-		#[pallet::weight(20_000)]
+		#[pallet::weight(T::WeightInfo::request_withdrawal())]
 		pub fn request_withdrawal(
 			origin: OriginFor<T>,
 			destination_chain: SourceChain,
@@ -569,7 +578,7 @@ pub mod pallet {
 
 		/// Add stablecoin configuration (operator only)
 		#[pallet::call_index(3)]
-		#[pallet::weight(5_000)]
+		#[pallet::weight(T::WeightInfo::add_configuration())]
 		pub fn add_configuration(
 			origin: OriginFor<T>,
 			chain: SourceChain,
@@ -607,7 +616,7 @@ pub mod pallet {
 
 		/// Toggle MiCA compliance (operator only)
 		#[pallet::call_index(4)]
-		#[pallet::weight(5_000)]
+		#[pallet::weight(T::WeightInfo::toggle_mica_compliance())]
 		pub fn toggle_mica_compliance(
 			origin: OriginFor<T>,
 			enabled: bool,
@@ -629,7 +638,7 @@ pub mod pallet {
 
 		/// Set bridge operator (root only)
 		#[pallet::call_index(5)]
-		#[pallet::weight(5_000)]
+		#[pallet::weight(T::WeightInfo::set_operator())]
 		pub fn set_operator(
 			origin: OriginFor<T>,
 			new_operator: T::AccountId,
@@ -0,0 +1,120 @@
+//! PLACEHOLDER weights for `stablecoin_usdt_bridge`: hand-estimated, not benchmark output.
+//!
+//! Overwrite with `./scripts/benchmark-pallets.sh stablecoin_usdt_bridge` on reference hardware.
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(missing_docs)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use core::marker::PhantomData;
+
+/// Weight functions needed for `stablecoin_usdt_bridge`.
+pub trait WeightInfo {
+	fn initiate_deposit() -> Weight;
+	fn confirm_deposit() -> Weight;
+	fn request_withdrawal() -> Weight;
+	fn add_configuration() -> Weight;
+	fn toggle_mica_compliance() -> Weight;
+	fn set_operator() -> Weight;
+}
+
+/// Placeholder weights for `stablecoin_usdt_bridge`, pending benchmarking.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	/// Storage: `UsdtBridge::PendingDeposits` (r:1 w:1)
+	/// Storage: `UsdtBridge::StablecoinConfigs` (r:1 w:0)
+	fn initiate_deposit() -> Weight {
+		Weight::from_parts(21_470_000, 6182)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `UsdtBridge::PendingDeposits` (r:1 w:1)
+	/// Storage: `UsdtBridge::StablecoinConfigs` (r:1 w:0)
+	/// Storage: `UsdtBridge::MicaCompliance` (r:1 w:0)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Storage: `UsdtBridge::ConfirmedDeposits` (r:1 w:1)
+	/// Storage: `UsdtBridge::TotalUsdcVolume` (r:1 w:1)
+	fn confirm_deposit() -> Weight {
+		Weight::from_parts(61_920_000, 15517)
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: `UsdtBridge::StablecoinConfigs` (r:1 w:0)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Storage: `UsdtBridge::PendingWithdrawals` (r:1 w:1)
+	fn request_withdrawal() -> Weight {
+		Weight::from_parts(93_150_000, 20267)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `UsdtBridge::BridgeOperator` (r:1 w:0)
+	/// Storage: `UsdtBridge::StablecoinConfigs` (r:0 w:1)
+	fn add_configuration() -> Weight {
+		Weight::from_parts(12_640_000, 1517)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `UsdtBridge::BridgeOperator` (r:1 w:0)
+	/// Storage: `UsdtBridge::MicaCompliance` (r:0 w:1)
+	fn toggle_mica_compliance() -> Weight {
+		Weight::from_parts(10_190_000, 1517)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `UsdtBridge::BridgeOperator` (r:0 w:1)
+	fn set_operator() -> Weight {
+		Weight::from_parts(7_080_000, 990)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+}
+
+// For backwards compatibility and tests.
+impl WeightInfo for () {
+	/// Storage: `UsdtBridge::PendingDeposits` (r:1 w:1)
+	/// Storage: `UsdtBridge::StablecoinConfigs` (r:1 w:0)
+	fn initiate_deposit() -> Weight {
+		Weight::from_parts(21_470_000, 6182)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `UsdtBridge::PendingDeposits` (r:1 w:1)
+	/// Storage: `UsdtBridge::StablecoinConfigs` (r:1 w:0)
+	/// Storage: `UsdtBridge::MicaCompliance` (r:1 w:0)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Storage: `UsdtBridge::ConfirmedDeposits` (r:1 w:1)
+	/// Storage: `UsdtBridge::TotalUsdcVolume` (r:1 w:1)
+	fn confirm_deposit() -> Weight {
+		Weight::from_parts(61_920_000, 15517)
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: `UsdtBridge::StablecoinConfigs` (r:1 w:0)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Storage: `UsdtBridge::PendingWithdrawals` (r:1 w:1)
+	fn request_withdrawal() -> Weight {
+		Weight::from_parts(93_150_000, 20267)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `UsdtBridge::BridgeOperator` (r:1 w:0)
+	/// Storage: `UsdtBridge::StablecoinConfigs` (r:0 w:1)
+	fn add_configuration() -> Weight {
+		Weight::from_parts(12_640_000, 1517)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `UsdtBridge::BridgeOperator` (r:1 w:0)
+	/// Storage: `UsdtBridge::MicaCompliance` (r:0 w:1)
+	fn toggle_mica_compliance() -> Weight {
+		Weight::from_parts(10_190_000, 1517)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `UsdtBridge::BridgeOperator` (r:0 w:1)
+	fn set_operator() -> Weight {
+		Weight::from_parts(7_080_000, 990)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+}
@@ -0,0 +1,220 @@
+//! Benchmarking setup for stellar-bridge
+//!
+//! Per-account deposit and withdrawal lists are filled to one below their bounds,
+//! and variable-length fields use their maximum lengths.
+
+#![cfg(feature = "runtime-benchmarks")]
+
+use super::*;
+use frame_benchmarking::v2::*;
+use frame_support::traits::Currency;
+use frame_system::RawOrigin;
+use sp_runtime::traits::{Saturating, Zero};
+use sp_std::vec;
+
+/// 1:1 XLM to ËTR, scaled by 1e7
+const RATE: u128 = 10_000_000;
+
+fn amount<T: Config>() -> BalanceOf<T> {
+    T::Currency::minimum_balance().max(1u32.into()).saturating_mul(1_000u32.into())
+}
+
+fn operator<T: Config>() -> T::AccountId {
+    let operator: T::AccountId = whitelisted_caller();
+    BridgeOperator::<T>::put(&operator);
+    operator
+}
+
+/// Twelve-character asset code with its issuer
+fn asset() -> StellarAsset {
+    (BoundedVec::truncate_from(vec![b'A'; 12]), StellarAccountId::repeat_byte(2))
+}
+
+/// Register `asset()` as asset 0
+fn add_asset<T: Config>() -> u32 {
+    SupportedAssets::<T>::insert(0, asset());
+    AssetRates::<T>::insert(0, RATE);
+    NextAssetId::<T>::put(1);
+    0
+}
+
+fn pending_deposit<T: Config>(who: &T::AccountId, tx_hash: StellarTxHash) -> StellarDeposit<T::AccountId, BalanceOf<T>> {
+    StellarDeposit {
+        stellar_account: StellarAccountId::repeat_byte(1),
+        etrid_account: who.clone(),
+        amount: amount::<T>(),
+        tx_hash,
+        ledger: 1,
+        confirmations: T::MinConfirmations::get(),
+        asset: Some(asset()),
+        is_confirmed: false,
+    }
+}
+
+#[benchmarks]
+mod benchmarks {
+    use super::*;
+
+    /// A deposit that also advances the tracked ledger
+    #[benchmark]
+    fn initiate_xlm_deposit() {
+        let relayer: T::AccountId = whitelisted_caller();
+        let recipient: T::AccountId = account("recipient", 0, 0);
+        let tx_hash = StellarTxHash::repeat_byte(1);
+
+        #[extrinsic_call]
+        _(
+            RawOrigin::Signed(relayer),
+            recipient,
+            StellarAccountId::repeat_byte(1),
+            amount::<T>(),
+            tx_hash,
+            u64::MAX,
+            T::MinConfirmations::get(),
+        );
+
+        assert_eq!(CurrentLedger::<T>::get(), u64::MAX);
+    }
+
+    #[benchmark]
+    fn confirm_xlm_deposit() {
+        XlmToEtrRate::<T>::put(RATE);
+        let relayer: T::AccountId = whitelisted_caller();
+        let recipient: T::AccountId = account("recipient", 0, 0);
+        let tx_hash = StellarTxHash::repeat_byte(1);
+        PendingDeposits::<T>::insert(tx_hash, pending_deposit::<T>(&recipient, tx_hash));
+        let existing: Vec<StellarTxHash> = (1..T::MaxDepositsPerAccount::get())
+            .map(|i| StellarTxHash::from_low_u64_be(i as u64 + 1))
+            .collect();
+        ConfirmedDeposits::<T>::insert(&recipient, BoundedVec::truncate_from(existing));
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(relayer), tx_hash);
+
+        assert!(PendingDeposits::<T>::get(tx_hash).unwrap().is_confirmed);
+    }
+
+    /// A deposit that already has enough confirmations and is minted immediately
+    #[benchmark]
+    fn initiate_asset_deposit() {
+        let asset_id = add_asset::<T>();
+        let relayer: T::AccountId = whitelisted_caller();
+        let recipient: T::AccountId = account("recipient", 0, 0);
+
+        #[extrinsic_call]
+        _(
+            RawOrigin::Signed(relayer),
+            recipient.clone(),
+            StellarAccountId::repeat_byte(1),
+            asset_id,
+            amount::<T>(),
+            StellarTxHash::repeat_byte(1),
+            1,
+            T::MinConfirmations::get(),
+        );
+
+        assert!(!T::Currency::free_balance(&recipient).is_zero());
+    }
+
+    #[benchmark]
+    fn request_xlm_withdrawal() {
+        let caller: T::AccountId = whitelisted_caller();
+        T::Currency::make_free_balance_be(&caller, amount::<T>().saturating_mul(2u32.into()));
+        let memo: BoundedVec<u8, ConstU32<28>> = BoundedVec::truncate_from(vec![b'm'; 28]);
+        let existing = StellarWithdrawal {
+            etrid_account: caller.clone(),
+            stellar_account: StellarAccountId::repeat_byte(1),
+            amount: amount::<T>(),
+            asset: Some(asset()),
+            memo: Some(memo.clone()),
+            status: WithdrawalStatus::Completed(StellarTxHash::repeat_byte(1)),
+        };
+        let existing = vec![existing; T::MaxWithdrawalsPerAccount::get() as usize - 1];
+        PendingWithdrawals::<T>::insert(&caller, BoundedVec::truncate_from(existing));
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller.clone()), StellarAccountId::repeat_byte(1), amount::<T>(), Some(memo));
+
+        assert_eq!(
+            PendingWithdrawals::<T>::get(&caller).len() as u32,
+            T::MaxWithdrawalsPerAccount::get()
+        );
+    }
+
+    #[benchmark]
+    fn update_exchange_rate() {
+        let operator = operator::<T>();
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(operator), RATE * 2);
+
+        assert_eq!(XlmToEtrRate::<T>::get(), RATE * 2);
+    }
+
+    #[benchmark]
+    fn add_supported_asset() {
+        let operator = operator::<T>();
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(operator), asset(), RATE);
+
+        assert!(SupportedAssets::<T>::contains_key(0));
+    }
+
+    #[benchmark]
+    fn register_horizon_server() {
+        let caller: T::AccountId = whitelisted_caller();
+        let url: BoundedVec<u8, ConstU32<256>> = BoundedVec::truncate_from(vec![b'h'; 256]);
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller.clone()), url);
+
+        assert!(HorizonServers::<T>::contains_key(&caller));
+    }
+
+    #[benchmark]
+    fn configure_anchor() {
+        let operator = operator::<T>();
+        let asset_id = add_asset::<T>();
+        let config: AnchorConfig = (
+            BoundedVec::truncate_from(vec![b'd'; 256]),
+            BoundedVec::truncate_from(vec![b'i'; 512]),
+            BoundedVec::truncate_from(vec![b'o'; 512]),
+        );
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(operator), asset_id, config);
+
+        assert!(AnchorConfigs::<T>::contains_key(asset_id));
+    }
+
+    #[benchmark]
+    fn toggle_sdex() {
+        let operator = operator::<T>();
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(operator), true);
+
+        assert!(SdexEnabled::<T>::get());
+    }
+
+    #[benchmark]
+    fn toggle_soroban() {
+        let operator = operator::<T>();
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(operator), true);
+
+        assert!(SorobanEnabled::<T>::get());
+    }
+
+    #[benchmark]
+    fn set_operator() {
+        let operator: T::AccountId = account("operator", 0, 0);
+
+        #[extrinsic_call]
+        _(RawOrigin::Root, operator.clone());
+
+        assert_eq!(BridgeOperator::<T>::get(), Some(operator));
+    }
+}
@@ -6,6 +6,11 @@
 
 pub use pallet::*;
 
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+pub mod weights;
+pub use weights::WeightInfo;
+
 use codec::{Decode, Encode, MaxEncodedLen};
 use frame_support::{traits::Get, BoundedVec, pallet_prelude::ConstU32};
 use scale_info::TypeInfo;
@@ -82,9 +87,10 @@ pub mod pallet {
     };
     use frame_system::pallet_prelude::*;
     use sp_runtime::traits::{Zero, SaturatedConversion};
+    use crate::WeightInfo;
 
     // Currency type for handling ËTR tokens
-    type BalanceOf<T> = <<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
+    pub(crate) type BalanceOf<T> = <<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
 
     #[pallet::pallet]
     pub struct Pallet<T>(_);
@@ -108,6 +114,9 @@ pub mod pallet {
         /// Maximum number of withdrawals per account
         #[pallet::constant]
         type MaxWithdrawalsPerAccount: Get<u32>;
+
+        /// Weight information for extrinsics
+        type WeightInfo: WeightInfo;
     }
 
     /// XLM to ËTR exchange rate (scaled by 1e7 - Stellar uses 7 decimals/stroops)
@@ -364,7 +373,7 @@ pub mod pallet {
     impl<T: Config> Pallet<T> {
         /// Initiate XLM deposit (called by relayer with proof)
         #[pallet::call_index(0)]
-        #[pallet::weight(10_000)]
+        #[pallet::weight(T::WeightInfo::initiate_xlm_deposit())]
         pub fn initiate_xlm_deposit(
             origin: OriginFor<T>,
             etrid_account: T::AccountId,
@@ -415,7 +424,7 @@ pub mod pallet {
 
         /// Confirm XLM deposit after required confirmations
         #[pallet::call_index(1)]
-        #[pallet::weight(15_000)]
+        #[pallet::weight(T::WeightInfo::confirm_xlm_deposit())]
         pub fn confirm_xlm_deposit(
             origin: OriginFor<T>,
             tx_hash: StellarTxHash,
@@ -471,7 +480,7 @@ pub mod pallet {
 
         /// Initiate Stellar asset deposit (non-XLM)
         #[pallet::call_index(2)]
-        #[pallet::weight(12_000)]
+        #[pallet::weight(T::WeightInfo::initiate_asset_deposit())]
         pub fn initiate_asset_deposit(
             origin: OriginFor<T>,
             etrid_account: T::AccountId,
@@ -517,7 +526,7 @@ pub mod pallet {
 
         /// Request XLM withdrawal
         #[pallet::call_index(3)]
-        #[pallet::weight(20_000)]
+        #[pallet::weight(T::WeightInfo::request_xlm_withdrawal())]
         pub fn request_xlm_withdrawal(
             origin: OriginFor<T>,
             stellar_account: StellarAccountId,
@@ -569,7 +578,7 @@ pub mod pallet {
 
         /// Update XLM/ËTR exchange rate (operator only)
         #[pallet::call_index(4)]
-        #[pallet::weight(5_000)]
+        #[pallet::weight(T::WeightInfo::update_exchange_rate())]
         pub fn update_exchange_rate(
             origin: OriginFor<T>,
             new_rate: u128,
@@ -591,7 +600,7 @@ pub mod pallet {
 
         /// Add supported Stellar asset (operator only)
         #[pallet::call_index(5)]
-        #[pallet::weight(5_000)]
+        #[pallet::weight(T::WeightInfo::add_supported_asset())]
         pub fn add_supported_asset(
             origin: OriginFor<T>,
             asset: StellarAsset,
@@ -625,7 +634,7 @@ pub mod pallet {
 
         /// Register Horizon server (for monitoring)
         #[pallet::call_index(6)]
-        #[pallet::weight(5_000)]
+        #[pallet::weight(T::WeightInfo::register_horizon_server())]
         pub fn register_horizon_server(
             origin: OriginFor<T>,
             url: BoundedVec<u8, ConstU32<256>>,
@@ -641,7 +650,7 @@ pub mod pallet {
 
         /// Configure Anchor service (SEP-24)
         #[pallet::call_index(7)]
-        #[pallet::weight(5_000)]
+        #[pallet::weight(T::WeightInfo::configure_anchor())]
         pub fn configure_anchor(
             origin: OriginFor<T>,
             asset_id: u32,
@@ -669,7 +678,7 @@ pub mod pallet {
 
         /// Toggle SDEX integration (operator only)
         #[pallet::call_index(8)]
-        #[pallet::weight(5_000)]
+        #[pallet::weight(T::WeightInfo::toggle_sdex())]
         pub fn toggle_sdex(
             origin: OriginFor<T>,
             enabled: bool,
@@ -689,7 +698,7 @@ pub mod pallet {
 
         /// Toggle Soroban integration (operator only)
         #[pallet::call_index(9)]
-        #[pallet::weight(5_000)]
+        #[pallet::weight(T::WeightInfo::toggle_soroban())]
         pub fn toggle_soroban(
             origin: OriginFor<T>,
             enabled: bool,
@@ -709,7 +718,7 @@ pub mod pallet {
 
         /// Set bridge operator (root only)
         #[pallet::call_index(10)]
-        #[pallet::weight(5_000)]
+        #[pallet::weight(T::WeightInfo::set_operator())]
         pub fn set_operator(
             origin: OriginFor<T>,
             new_operator: T::AccountId,
@@ -856,6 +865,7 @@ mod tests {
         type PalletId = XlmBridgePalletId;
         type StellarConfirmations = StellarConfirmations;
         type MaxAssets = MaxAssets;
+        type WeightInfo = ();
     }
 
     fn new_test_ext() -> sp_io::TestExternalities {
@@ -0,0 +1,197 @@
+//! PLACEHOLDER weights for `stellar_bridge`: hand-estimated, not benchmark output.
+//!
+//! Overwrite with `./scripts/benchmark-pallets.sh stellar_bridge` on reference hardware.
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(missing_docs)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use core::marker::PhantomData;
+
+/// Weight functions needed for `stellar_bridge`.
+pub trait WeightInfo {
+	fn initiate_xlm_deposit() -> Weight;
+	fn confirm_xlm_deposit() -> Weight;
+	fn initiate_asset_deposit() -> Weight;
+	fn request_xlm_withdrawal() -> Weight;
+	fn update_exchange_rate() -> Weight;
+	fn add_supported_asset() -> Weight;
+	fn register_horizon_server() -> Weight;
+	fn configure_anchor() -> Weight;
+	fn toggle_sdex() -> Weight;
+	fn toggle_soroban() -> Weight;
+	fn set_operator() -> Weight;
+}
+
+/// Placeholder weights for `stellar_bridge`, pending benchmarking.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	/// Storage: `StellarBridge::PendingDeposits` (r:1 w:1)
+	/// Storage: `StellarBridge::CurrentLedger` (r:1 w:1)
+	fn initiate_xlm_deposit() -> Weight {
+		Weight::from_parts(20_410_000, 4187)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `StellarBridge::PendingDeposits` (r:1 w:1)
+	/// Storage: `StellarBridge::XlmToEtrRate` (r:1 w:0)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Storage: `StellarBridge::ConfirmedDeposits` (r:1 w:1)
+	/// Storage: `StellarBridge::TotalBridgedVolume` (r:1 w:1)
+	fn confirm_xlm_deposit() -> Weight {
+		Weight::from_parts(59_870_000, 13034)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: `StellarBridge::SupportedAssets` (r:1 w:0)
+	/// Storage: `StellarBridge::PendingDeposits` (r:1 w:1)
+	/// Storage: `StellarBridge::AssetRates` (r:1 w:0)
+	/// Storage: `System::Account` (r:1 w:1)
+	fn initiate_asset_deposit() -> Weight {
+		Weight::from_parts(49_150_000, 11338)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Storage: `StellarBridge::PendingWithdrawals` (r:1 w:1)
+	fn request_xlm_withdrawal() -> Weight {
+		Weight::from_parts(112_380_000, 25018)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `StellarBridge::BridgeOperator` (r:1 w:0)
+	/// Storage: `StellarBridge::XlmToEtrRate` (r:1 w:1)
+	fn update_exchange_rate() -> Weight {
+		Weight::from_parts(11_140_000, 2028)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `StellarBridge::BridgeOperator` (r:1 w:0)
+	/// Storage: `StellarBridge::NextAssetId` (r:1 w:1)
+	/// Storage: `StellarBridge::SupportedAssets` (r:0 w:1)
+	/// Storage: `StellarBridge::AssetRates` (r:0 w:1)
+	fn add_supported_asset() -> Weight {
+		Weight::from_parts(14_730_000, 2016)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: `StellarBridge::HorizonServers` (r:0 w:1)
+	fn register_horizon_server() -> Weight {
+		Weight::from_parts(10_960_000, 990)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `StellarBridge::BridgeOperator` (r:1 w:0)
+	/// Storage: `StellarBridge::SupportedAssets` (r:1 w:0)
+	/// Storage: `StellarBridge::AnchorConfigs` (r:0 w:1)
+	fn configure_anchor() -> Weight {
+		Weight::from_parts(17_520_000, 4057)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `StellarBridge::BridgeOperator` (r:1 w:0)
+	/// Storage: `StellarBridge::SdexEnabled` (r:0 w:1)
+	fn toggle_sdex() -> Weight {
+		Weight::from_parts(10_270_000, 1517)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `StellarBridge::BridgeOperator` (r:1 w:0)
+	/// Storage: `StellarBridge::SorobanEnabled` (r:0 w:1)
+	fn toggle_soroban() -> Weight {
+		Weight::from_parts(10_310_000, 1517)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `StellarBridge::BridgeOperator` (r:0 w:1)
+	fn set_operator() -> Weight {
+		Weight::from_parts(7_190_000, 990)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+}
+
+// For backwards compatibility and tests.
+impl WeightInfo for () {
+	/// Storage: `StellarBridge::PendingDeposits` (r:1 w:1)
+	/// Storage: `StellarBridge::CurrentLedger` (r:1 w:1)
+	fn initiate_xlm_deposit() -> Weight {
+		Weight::from_parts(20_410_000, 4187)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `StellarBridge::PendingDeposits` (r:1 w:1)
+	/// Storage: `StellarBridge::XlmToEtrRate` (r:1 w:0)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Storage: `StellarBridge::ConfirmedDeposits` (r:1 w:1)
+	/// Storage: `StellarBridge::TotalBridgedVolume` (r:1 w:1)
+	fn confirm_xlm_deposit() -> Weight {
+		Weight::from_parts(59_870_000, 13034)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: `StellarBridge::SupportedAssets` (r:1 w:0)
+	/// Storage: `StellarBridge::PendingDeposits` (r:1 w:1)
+	/// Storage: `StellarBridge::AssetRates` (r:1 w:0)
+	/// Storage: `System::Account` (r:1 w:1)
+	fn initiate_asset_deposit() -> Weight {
+		Weight::from_parts(49_150_000, 11338)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Storage: `StellarBridge::PendingWithdrawals` (r:1 w:1)
+	fn request_xlm_withdrawal() -> Weight {
+		Weight::from_parts(112_380_000, 25018)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `StellarBridge::BridgeOperator` (r:1 w:0)
+	/// Storage: `StellarBridge::XlmToEtrRate` (r:1 w:1)
+	fn update_exchange_rate() -> Weight {
+		Weight::from_parts(11_140_000, 2028)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `StellarBridge::BridgeOperator` (r:1 w:0)
+	/// Storage: `StellarBridge::NextAssetId` (r:1 w:1)
+	/// Storage: `StellarBridge::SupportedAssets` (r:0 w:1)
+	/// Storage: `StellarBridge::AssetRates` (r:0 w:1)
+	fn add_supported_asset() -> Weight {
+		Weight::from_parts(14_730_000, 2016)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: `StellarBridge::HorizonServers` (r:0 w:1)
+	fn register_horizon_server() -> Weight {
+		Weight::from_parts(10_960_000, 990)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `StellarBridge::BridgeOperator` (r:1 w:0)
+	/// Storage: `StellarBridge::SupportedAssets` (r:1 w:0)
+	/// Storage: `StellarBridge::AnchorConfigs` (r:0 w:1)
+	fn configure_anchor() -> Weight {
+		Weight::from_parts(17_520_000, 4057)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `StellarBridge::BridgeOperator` (r:1 w:0)
+	/// Storage: `StellarBridge::SdexEnabled` (r:0 w:1)
+	fn toggle_sdex() -> Weight {
+		Weight::from_parts(10_270_000, 1517)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `StellarBridge::BridgeOperator` (r:1 w:0)
+	/// Storage: `StellarBridge::SorobanEnabled` (r:0 w:1)
+	fn toggle_soroban() -> Weight {
+		Weight::from_parts(10_310_000, 1517)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `StellarBridge::BridgeOperator` (r:0 w:1)
+	fn set_operator() -> Weight {
+		Weight::from_parts(7_190_000, 990)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+}
@@ -0,0 +1,215 @@
+//! Benchmarking setup for trx-bridge
+//!
+//! Per-account deposit and withdrawal lists are filled to one below their bounds.
+
+#![cfg(feature = "runtime-benchmarks")]
+
+use super::*;
+use frame_benchmarking::v2::*;
+use frame_support::{
+    pallet_prelude::Get,
+    traits::Currency,
+    BoundedVec,
+};
+use frame_system::RawOrigin;
+use sp_runtime::traits::{Saturating, Zero};
+
+/// 1:1 TRX to ËTR, scaled by 1e6
+const RATE: u128 = 1_000_000;
+
+fn amount<T: Config>() -> BalanceOf<T> {
+    T::Currency::minimum_balance().max(1u32.into()).saturating_mul(1_000u32.into())
+}
+
+fn operator<T: Config>() -> T::AccountId {
+    let operator: T::AccountId = whitelisted_caller();
+    BridgeOperator::<T>::put(&operator);
+    operator
+}
+
+/// Register a supported TRC-20 contract
+fn add_token<T: Config>() -> TokenContract {
+    let contract = TokenContract([2u8; 21]);
+    SupportedTokens::<T>::insert(contract, true);
+    TokenRates::<T>::insert(contract, RATE);
+    contract
+}
+
+#[benchmarks]
+mod benchmarks {
+    use super::*;
+
+    /// A deposit that also advances the tracked block height
+    #[benchmark]
+    fn initiate_trx_deposit() {
+        let relayer: T::AccountId = whitelisted_caller();
+        let recipient: T::AccountId = account("recipient", 0, 0);
+
+        #[extrinsic_call]
+        _(
+            RawOrigin::Signed(relayer),
+            recipient,
+            TronAddress([1u8; 21]),
+            amount::<T>(),
+            TronTxId::repeat_byte(1),
+            u64::MAX,
+            T::MinConfirmations::get(),
+        );
+
+        assert_eq!(CurrentBlockHeight::<T>::get(), u64::MAX);
+    }
+
+    #[benchmark]
+    fn confirm_trx_deposit() {
+        TrxToEtrRate::<T>::put(RATE);
+        let relayer: T::AccountId = whitelisted_caller();
+        let recipient: T::AccountId = account("recipient", 0, 0);
+        let tx_id = TronTxId::repeat_byte(1);
+        PendingDeposits::<T>::insert(tx_id, TronDeposit {
+            tron_address: TronAddress([1u8; 21]),
+            etrid_account: recipient.clone(),
+            amount: amount::<T>(),
+            tx_id,
+            block_height: 1,
+            confirmations: T::MinConfirmations::get(),
+            token_contract: None,
+            is_confirmed: false,
+        });
+        let existing: Vec<TronTxId> = (1..T::MaxDepositsPerAccount::get())
+            .map(|i| TronTxId::from_low_u64_be(i as u64 + 1))
+            .collect();
+        ConfirmedDeposits::<T>::insert(&recipient, BoundedVec::truncate_from(existing));
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(relayer), tx_id);
+
+        assert!(PendingDeposits::<T>::get(tx_id).unwrap().is_confirmed);
+    }
+
+    /// A deposit that already has enough confirmations and is minted immediately
+    #[benchmark]
+    fn initiate_token_deposit() {
+        let contract = add_token::<T>();
+        let relayer: T::AccountId = whitelisted_caller();
+        let recipient: T::AccountId = account("recipient", 0, 0);
+
+        #[extrinsic_call]
+        _(
+            RawOrigin::Signed(relayer),
+            recipient.clone(),
+            TronAddress([1u8; 21]),
+            contract,
+            amount::<T>(),
+            TronTxId::repeat_byte(1),
+            1,
+            T::MinConfirmations::get(),
+        );
+
+        assert!(!T::Currency::free_balance(&recipient).is_zero());
+    }
+
+    #[benchmark]
+    fn initiate_usdt_deposit() {
+        let contract = add_token::<T>();
+        UsdtContract::<T>::put(contract);
+        let relayer: T::AccountId = whitelisted_caller();
+        let recipient: T::AccountId = account("recipient", 0, 0);
+
+        #[extrinsic_call]
+        _(
+            RawOrigin::Signed(relayer),
+            recipient.clone(),
+            TronAddress([1u8; 21]),
+            amount::<T>(),
+            TronTxId::repeat_byte(1),
+            1,
+            T::MinConfirmations::get(),
+        );
+
+        assert!(!T::Currency::free_balance(&recipient).is_zero());
+    }
+
+    #[benchmark]
+    fn request_trx_withdrawal() {
+        let caller: T::AccountId = whitelisted_caller();
+        T::Currency::make_free_balance_be(&caller, amount::<T>().saturating_mul(2u32.into()));
+        let existing = TronWithdrawal {
+            etrid_account: caller.clone(),
+            tron_address: TronAddress([1u8; 21]),
+            amount: amount::<T>(),
+            token_contract: Some(TokenContract([2u8; 21])),
+            energy_limit: T::MaxEnergyLimit::get(),
+            bandwidth_points: T::MaxBandwidth::get(),
+            status: WithdrawalStatus::Completed(TronTxId::repeat_byte(1)),
+        };
+        let existing = sp_std::vec![existing; T::MaxWithdrawalsPerAccount::get() as usize - 1];
+        PendingWithdrawals::<T>::insert(&caller, BoundedVec::truncate_from(existing));
+
+        #[extrinsic_call]
+        _(
+            RawOrigin::Signed(caller.clone()),
+            TronAddress([1u8; 21]),
+            amount::<T>(),
+            T::MaxEnergyLimit::get(),
+            T::MaxBandwidth::get(),
+        );
+
+        assert_eq!(
+            PendingWithdrawals::<T>::get(&caller).len() as u32,
+            T::MaxWithdrawalsPerAccount::get()
+        );
+    }
+
+    #[benchmark]
+    fn update_exchange_rate() {
+        let operator = operator::<T>();
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(operator), RATE * 2);
+
+        assert_eq!(TrxToEtrRate::<T>::get(), RATE * 2);
+    }
+
+    #[benchmark]
+    fn add_supported_token() {
+        let operator = operator::<T>();
+        let contract = TokenContract([2u8; 21]);
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(operator), contract, RATE);
+
+        assert!(SupportedTokens::<T>::get(contract));
+    }
+
+    #[benchmark]
+    fn set_usdt_contract() {
+        let operator = operator::<T>();
+        let contract = TokenContract([2u8; 21]);
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(operator), contract);
+
+        assert_eq!(UsdtContract::<T>::get(), Some(contract));
+    }
+
+    #[benchmark]
+    fn set_usdc_contract() {
+        let operator = operator::<T>();
+        let contract = TokenContract([3u8; 21]);
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(operator), contract);
+
+        assert_eq!(UsdcContract::<T>::get(), Some(contract));
+    }
+
+    #[benchmark]
+    fn set_operator() {
+        let operator: T::AccountId = account("operator", 0, 0);
+
+        #[extrinsic_call]
+        _(RawOrigin::Root, operator.clone());
+
+        assert_eq!(BridgeOperator::<T>::get(), Some(operator));
+    }
+}
@@ -6,6 +6,11 @@
 
 pub use pallet::*;
 
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+pub mod weights;
+pub use weights::WeightInfo;
+
 use codec::{Decode, Encode, MaxEncodedLen};
 use scale_info::TypeInfo;
 use sp_std::prelude::*;
@@ -77,8 +82,9 @@ pub mod pallet {
         BoundedVec,
     };
     use frame_system::pallet_prelude::*;
+    use crate::WeightInfo;
 
-    type BalanceOf<T> = <<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
+    pub(crate) type BalanceOf<T> = <<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
 
     #[pallet::config]
     pub trait Config: frame_system::Config {
@@ -108,6 +114,9 @@ pub mod pallet {
         /// Maximum number of pending withdrawals per account
         #[pallet::constant]
         type MaxWithdrawalsPerAccount: Get<u32>;
+
+        /// Weight information for extrinsics
+        type WeightInfo: WeightInfo;
     }
 
     #[pallet::pallet]
@@ -316,7 +325,7 @@ pub mod pallet {
     impl<T: Config> Pallet<T> {
         /// Initiate TRX deposit (called by relayer with proof)
         #[pallet::call_index(0)]
-        #[pallet::weight(10_000)]
+        #[pallet::weight(T::WeightInfo::initiate_trx_deposit())]
         pub fn initiate_trx_deposit(
             origin: OriginFor<T>,
             etrid_account: T::AccountId,
@@ -369,7 +378,7 @@ pub mod pallet {
 
         /// Confirm TRX deposit after required confirmations
         #[pallet::call_index(1)]
-        #[pallet::weight(15_000)]
+        #[pallet::weight(T::WeightInfo::confirm_trx_deposit())]
         pub fn confirm_trx_deposit(
             origin: OriginFor<T>,
             tx_id: TronTxId,
@@ -425,7 +434,7 @@ pub mod pallet {
 
         /// Initiate TRC-20 token deposit (optimized for USDT)
         #[pallet::call_index(2)]
-        #[pallet::weight(12_000)]
+        #[pallet::weight(T::WeightInfo::initiate_token_deposit())]
         pub fn initiate_token_deposit(
             origin: OriginFor<T>,
             etrid_account: T::AccountId,
@@ -473,7 +482,7 @@ pub mod pallet {
 
         /// Fast-track USDT deposit (most common TRC-20)
         #[pallet::call_index(3)]
-        #[pallet::weight(12_000)]
+        #[pallet::weight(T::WeightInfo::initiate_usdt_deposit())]
         pub fn initiate_usdt_deposit(
             origin: OriginFor<T>,
             etrid_account: T::AccountId,
@@ -515,7 +524,7 @@ pub mod pallet {
 
         /// Request TRX withdrawal
         #[pallet::call_index(4)]
-        #[pallet::weight(20_000)]
+        #[pallet::weight(T::WeightInfo::request_trx_withdrawal())]
         pub fn request_trx_withdrawal(
             origin: OriginFor<T>,
             tron_address: TronAddress,
@@ -571,7 +580,7 @@ pub mod pallet {
 
         /// Update TRX/ËTR exchange rate (operator only)
         #[pallet::call_index(5)]
-        #[pallet::weight(5_000)]
+        #[pallet::weight(T::WeightInfo::update_exchange_rate())]
         pub fn update_exchange_rate(
             origin: OriginFor<T>,
             new_rate: u128,
@@ -596,7 +605,7 @@ pub mod pallet {
 
         /// Add supported TRC-20 token (operator only)
         #[pallet::call_index(6)]
-        #[pallet::weight(5_000)]
+        #[pallet::weight(T::WeightInfo::add_supported_token())]
         pub fn add_supported_token(
             origin: OriginFor<T>,
             token_contract: TokenContract,
@@ -623,7 +632,7 @@ pub mod pallet {
 
         /// Set USDT contract address (operator only)
         #[pallet::call_index(7)]
-        #[pallet::weight(5_000)]
+        #[pallet::weight(T::WeightInfo::set_usdt_contract())]
         pub fn set_usdt_contract(
             origin: OriginFor<T>,
             contract: TokenContract,
@@ -641,7 +650,7 @@ pub mod pallet {
 
         /// Set USDC contract address (operator only)
         #[pallet::call_index(8)]
-        #[pallet::weight(5_000)]
+        #[pallet::weight(T::WeightInfo::set_usdc_contract())]
         pub fn set_usdc_contract(
             origin: OriginFor<T>,
             contract: TokenContract,
@@ -659,7 +668,7 @@ pub mod pallet {
 
         /// Set bridge operator (root only)
         #[pallet::call_index(9)]
-        #[pallet::weight(5_000)]
+        #[pallet::weight(T::WeightInfo::set_operator())]
         pub fn set_operator(
             origin: OriginFor<T>,
             new_operator: T::AccountId,
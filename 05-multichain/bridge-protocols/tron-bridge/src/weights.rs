@@ -0,0 +1,190 @@
+//! PLACEHOLDER weights for `trx_bridge`: hand-estimated, not benchmark output.
+//!
+//! Overwrite with `./scripts/benchmark-pallets.sh trx_bridge` on reference hardware.
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(missing_docs)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use core::marker::PhantomData;
+
+/// Weight functions needed for `trx_bridge`.
+pub trait WeightInfo {
+	fn initiate_trx_deposit() -> Weight;
+	fn confirm_trx_deposit() -> Weight;
+	fn initiate_token_deposit() -> Weight;
+	fn initiate_usdt_deposit() -> Weight;
+	fn request_trx_withdrawal() -> Weight;
+	fn update_exchange_rate() -> Weight;
+	fn add_supported_token() -> Weight;
+	fn set_usdt_contract() -> Weight;
+	fn set_usdc_contract() -> Weight;
+	fn set_operator() -> Weight;
+}
+
+/// Placeholder weights for `trx_bridge`, pending benchmarking.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	/// Storage: `TronBridge::PendingDeposits` (r:1 w:1)
+	/// Storage: `TronBridge::CurrentBlockHeight` (r:1 w:1)
+	fn initiate_trx_deposit() -> Weight {
+		Weight::from_parts(20_230_000, 4152)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `TronBridge::PendingDeposits` (r:1 w:1)
+	/// Storage: `TronBridge::TrxToEtrRate` (r:1 w:0)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Storage: `TronBridge::ConfirmedDeposits` (r:1 w:1)
+	/// Storage: `TronBridge::TotalBridgedVolume` (r:1 w:1)
+	fn confirm_trx_deposit() -> Weight {
+		Weight::from_parts(59_810_000, 12999)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: `TronBridge::SupportedTokens` (r:1 w:0)
+	/// Storage: `TronBridge::PendingDeposits` (r:1 w:1)
+	/// Storage: `TronBridge::TokenRates` (r:1 w:0)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Storage: `TronBridge::UsdtContract` (r:1 w:0)
+	fn initiate_token_deposit() -> Weight {
+		Weight::from_parts(52_040_000, 11809)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `TronBridge::SupportedTokens` (r:1 w:0)
+	/// Storage: `TronBridge::PendingDeposits` (r:1 w:1)
+	/// Storage: `TronBridge::TokenRates` (r:1 w:0)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Storage: `TronBridge::UsdtContract` (r:1 w:0)
+	fn initiate_usdt_deposit() -> Weight {
+		Weight::from_parts(54_390_000, 11809)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Storage: `TronBridge::PendingWithdrawals` (r:1 w:1)
+	fn request_trx_withdrawal() -> Weight {
+		Weight::from_parts(97_860_000, 20118)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `TronBridge::BridgeOperator` (r:1 w:0)
+	/// Storage: `TronBridge::TrxToEtrRate` (r:1 w:1)
+	fn update_exchange_rate() -> Weight {
+		Weight::from_parts(11_060_000, 2028)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `TronBridge::BridgeOperator` (r:1 w:0)
+	/// Storage: `TronBridge::SupportedTokens` (r:0 w:1)
+	/// Storage: `TronBridge::TokenRates` (r:0 w:1)
+	fn add_supported_token() -> Weight {
+		Weight::from_parts(13_410_000, 1517)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `TronBridge::BridgeOperator` (r:1 w:0)
+	/// Storage: `TronBridge::UsdtContract` (r:0 w:1)
+	fn set_usdt_contract() -> Weight {
+		Weight::from_parts(10_280_000, 1517)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `TronBridge::BridgeOperator` (r:1 w:0)
+	/// Storage: `TronBridge::UsdcContract` (r:0 w:1)
+	fn set_usdc_contract() -> Weight {
+		Weight::from_parts(10_270_000, 1517)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `TronBridge::BridgeOperator` (r:0 w:1)
+	fn set_operator() -> Weight {
+		Weight::from_parts(7_130_000, 990)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+}
+
+// For backwards compatibility and tests.
+impl WeightInfo for () {
+	/// Storage: `TronBridge::PendingDeposits` (r:1 w:1)
+	/// Storage: `TronBridge::CurrentBlockHeight` (r:1 w:1)
+	fn initiate_trx_deposit() -> Weight {
+		Weight::from_parts(20_230_000, 4152)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `TronBridge::PendingDeposits` (r:1 w:1)
+	/// Storage: `TronBridge::TrxToEtrRate` (r:1 w:0)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Storage: `TronBridge::ConfirmedDeposits` (r:1 w:1)
+	/// Storage: `TronBridge::TotalBridgedVolume` (r:1 w:1)
+	fn confirm_trx_deposit() -> Weight {
+		Weight::from_parts(59_810_000, 12999)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: `TronBridge::SupportedTokens` (r:1 w:0)
+	/// Storage: `TronBridge::PendingDeposits` (r:1 w:1)
+	/// Storage: `TronBridge::TokenRates` (r:1 w:0)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Storage: `TronBridge::UsdtContract` (r:1 w:0)
+	fn initiate_token_deposit() -> Weight {
+		Weight::from_parts(52_040_000, 11809)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `TronBridge::SupportedTokens` (r:1 w:0)
+	/// Storage: `TronBridge::PendingDeposits` (r:1 w:1)
+	/// Storage: `TronBridge::TokenRates` (r:1 w:0)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Storage: `TronBridge::UsdtContract` (r:1 w:0)
+	fn initiate_usdt_deposit() -> Weight {
+		Weight::from_parts(54_390_000, 11809)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Storage: `TronBridge::PendingWithdrawals` (r:1 w:1)
+	fn request_trx_withdrawal() -> Weight {
+		Weight::from_parts(97_860_000, 20118)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `TronBridge::BridgeOperator` (r:1 w:0)
+	/// Storage: `TronBridge::TrxToEtrRate` (r:1 w:1)
+	fn update_exchange_rate() -> Weight {
+		Weight::from_parts(11_060_000, 2028)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `TronBridge::BridgeOperator` (r:1 w:0)
+	/// Storage: `TronBridge::SupportedTokens` (r:0 w:1)
+	/// Storage: `TronBridge::TokenRates` (r:0 w:1)
+	fn add_supported_token() -> Weight {
+		Weight::from_parts(13_410_000, 1517)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `TronBridge::BridgeOperator` (r:1 w:0)
+	/// Storage: `TronBridge::UsdtContract` (r:0 w:1)
+	fn set_usdt_contract() -> Weight {
+		Weight::from_parts(10_280_000, 1517)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `TronBridge::BridgeOperator` (r:1 w:0)
+	/// Storage: `TronBridge::UsdcContract` (r:0 w:1)
+	fn set_usdc_contract() -> Weight {
+		Weight::from_parts(10_270_000, 1517)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `TronBridge::BridgeOperator` (r:0 w:1)
+	fn set_operator() -> Weight {
+		Weight::from_parts(7_130_000, 990)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+}
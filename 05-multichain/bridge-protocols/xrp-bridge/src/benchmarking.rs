@@ -0,0 +1,233 @@
+//! Benchmarking setup for xrp-bridge
+//!
+//! Per-account deposit and withdrawal lists are filled to one below their bounds,
+//! and lock/unlock benchmarks run against a full ETR lock history.
+
+#![cfg(feature = "runtime-benchmarks")]
+
+use super::*;
+use frame_benchmarking::v2::*;
+use frame_support::{
+	pallet_prelude::Get,
+	traits::Currency,
+	BoundedVec,
+};
+use frame_system::RawOrigin;
+use pallet_etr_lock::ChainId;
+use sp_runtime::traits::Saturating;
+
+/// 1:1 XRP to ËTR, scaled by 1e6
+const RATE: u128 = 1_000_000;
+
+fn amount<T: Config>() -> BalanceOf<T> {
+	<T as pallet_etr_lock::Config>::Currency::minimum_balance()
+		.max(1u32.into())
+		.saturating_mul(1_000u32.into())
+}
+
+fn operator<T: Config>() -> T::AccountId {
+	let operator: T::AccountId = whitelisted_caller();
+	BridgeOperator::<T>::put(&operator);
+	operator
+}
+
+#[benchmarks]
+mod benchmarks {
+	use super::*;
+
+	/// A deposit that also advances the tracked ledger index
+	#[benchmark]
+	fn initiate_xrp_deposit() {
+		let relayer: T::AccountId = whitelisted_caller();
+		let recipient: T::AccountId = account("recipient", 0, 0);
+
+		#[extrinsic_call]
+		_(
+			RawOrigin::Signed(relayer),
+			recipient,
+			XrplAddress::repeat_byte(1),
+			amount::<T>(),
+			XrpTxHash::repeat_byte(1),
+			u64::MAX,
+			T::MinConfirmations::get(),
+			Some(u32::MAX),
+		);
+
+		assert_eq!(CurrentLedgerIndex::<T>::get(), u64::MAX);
+	}
+
+	#[benchmark]
+	fn initiate_evm_sidechain_deposit() {
+		EvmSidechainEnabled::<T>::put(true);
+		let relayer: T::AccountId = whitelisted_caller();
+		let recipient: T::AccountId = account("recipient", 0, 0);
+		let tx_hash = XrpTxHash::repeat_byte(1);
+
+		#[extrinsic_call]
+		_(
+			RawOrigin::Signed(relayer),
+			recipient,
+			XrplEvmAddress::repeat_byte(1),
+			amount::<T>(),
+			tx_hash,
+			1,
+			T::MinConfirmations::get(),
+		);
+
+		assert!(PendingDeposits::<T>::contains_key(tx_hash));
+	}
+
+	#[benchmark]
+	fn confirm_xrp_deposit() {
+		XrpToEtrRate::<T>::put(RATE);
+		let relayer: T::AccountId = whitelisted_caller();
+		let recipient: T::AccountId = account("recipient", 0, 0);
+		let tx_hash = XrpTxHash::repeat_byte(1);
+		PendingDeposits::<T>::insert(tx_hash, XrpDeposit {
+			xrpl_address: XrplAddress::repeat_byte(1),
+			etrid_account: recipient.clone(),
+			amount: amount::<T>(),
+			tx_hash,
+			ledger_index: 1,
+			confirmations: T::MinConfirmations::get(),
+			bridge_type: BridgeType::XrplClassic,
+			destination_tag: Some(u32::MAX),
+			is_confirmed: false,
+		});
+		let existing: Vec<XrpTxHash> = (1..T::MaxDepositsPerAccount::get())
+			.map(|i| XrpTxHash::from_low_u64_be(i as u64 + 1))
+			.collect();
+		ConfirmedDeposits::<T>::insert(&recipient, BoundedVec::truncate_from(existing));
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(relayer), tx_hash);
+
+		assert!(PendingDeposits::<T>::get(tx_hash).unwrap().is_confirmed);
+	}
+
+	/// An EVM sidechain withdrawal, which also checks the sidechain switch
+	#[benchmark]
+	fn request_xrp_withdrawal() {
+		EvmSidechainEnabled::<T>::put(true);
+		let caller: T::AccountId = whitelisted_caller();
+		<T as pallet_etr_lock::Config>::Currency::make_free_balance_be(
+			&caller,
+			amount::<T>().saturating_mul(2u32.into()),
+		);
+		let existing = XrpWithdrawal {
+			etrid_account: caller.clone(),
+			xrpl_address: XrplAddress::repeat_byte(1),
+			amount: amount::<T>(),
+			bridge_type: BridgeType::XrplEvmSidechain,
+			destination_tag: Some(u32::MAX),
+			fee_drops: T::MaxFeeDrops::get(),
+			status: WithdrawalStatus::Completed(XrpTxHash::repeat_byte(1)),
+		};
+		let existing = sp_std::vec![existing; T::MaxWithdrawalsPerAccount::get() as usize - 1];
+		PendingWithdrawals::<T>::insert(&caller, BoundedVec::truncate_from(existing));
+
+		#[extrinsic_call]
+		_(
+			RawOrigin::Signed(caller.clone()),
+			XrplAddress::repeat_byte(1),
+			amount::<T>(),
+			true,
+			Some(u32::MAX),
+			T::MaxFeeDrops::get(),
+		);
+
+		assert_eq!(
+			PendingWithdrawals::<T>::get(&caller).len() as u32,
+			T::MaxWithdrawalsPerAccount::get()
+		);
+	}
+
+	#[benchmark]
+	fn update_exchange_rate() {
+		let operator = operator::<T>();
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(operator), RATE * 2);
+
+		assert_eq!(XrpToEtrRate::<T>::get(), RATE * 2);
+	}
+
+	#[benchmark]
+	fn toggle_evm_sidechain() {
+		let operator = operator::<T>();
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(operator), true);
+
+		assert!(EvmSidechainEnabled::<T>::get());
+	}
+
+	#[benchmark]
+	fn configure_axelar() {
+		let operator = operator::<T>();
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(operator), true, Some(XrplEvmAddress::repeat_byte(1)));
+
+		assert!(AxelarIntegration::<T>::get().is_some());
+	}
+
+	#[benchmark]
+	fn toggle_wormhole() {
+		let operator = operator::<T>();
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(operator), true);
+
+		assert!(WormholeEnabled::<T>::get());
+	}
+
+	#[benchmark]
+	fn toggle_odl() {
+		let operator = operator::<T>();
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(operator), true);
+
+		assert!(OdlEnabled::<T>::get());
+	}
+
+	#[benchmark]
+	fn set_operator() {
+		let operator: T::AccountId = account("operator", 0, 0);
+
+		#[extrinsic_call]
+		_(RawOrigin::Root, operator.clone());
+
+		assert_eq!(BridgeOperator::<T>::get(), Some(operator));
+	}
+
+	#[benchmark]
+	fn bridge_etr_to_xrp() {
+		let caller: T::AccountId = whitelisted_caller();
+		let amount = amount::<T>();
+		pallet_etr_lock::Pallet::<T>::prepare_benchmark(ChainId::Ripple, amount);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), amount, XrplAddress::repeat_byte(1));
+
+		assert_eq!(
+			pallet_etr_lock::Pallet::<T>::get_locked_amount(ChainId::Ripple),
+			amount.saturating_mul(2u32.into())
+		);
+	}
+
+	#[benchmark]
+	fn process_etr_burn_from_xrp() {
+		let caller: T::AccountId = whitelisted_caller();
+		let recipient: T::AccountId = account("recipient", 0, 0);
+		let amount = amount::<T>();
+		pallet_etr_lock::Pallet::<T>::prepare_benchmark(ChainId::Ripple, amount.saturating_mul(2u32.into()));
+		let burn_tx = XrpTxHash::repeat_byte(4);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), recipient, amount, burn_tx);
+
+		assert!(ProcessedXrpBurns::<T>::get(burn_tx));
+	}
+}
@@ -6,6 +6,11 @@
 
 pub use pallet::*;
 
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+pub mod weights;
+pub use weights::WeightInfo;
+
 use codec::{Decode, Encode, MaxEncodedLen};
 use scale_info::TypeInfo;
 use sp_std::prelude::*;
@@ -93,8 +98,9 @@ pub mod pallet {
 		BoundedVec,
 	};
 	use frame_system::pallet_prelude::*;
+	use crate::WeightInfo;
 
-	type BalanceOf<T> = <<T as pallet_etr_lock::Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
+	pub(crate) type BalanceOf<T> = <<T as pallet_etr_lock::Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
 
 	#[pallet::config]
 	pub trait Config: frame_system::Config + pallet_etr_lock::Config {
@@ -119,6 +125,9 @@ pub mod pallet {
 		/// Maximum number of pending withdrawals per account
 		#[pallet::constant]
 		type MaxWithdrawalsPerAccount: Get<u32>;
+
+		/// Weight information for extrinsics
+		type WeightInfo: WeightInfo;
 	}
 
 	#[pallet::pallet]
@@ -358,7 +367,7 @@ pub mod pallet {
 	impl<T: Config> Pallet<T> {
 		/// Initiate XRP deposit from XRPL Classic
 		#[pallet::call_index(0)]
-		#[pallet::weight(10_000)]
+		#[pallet::weight(T::WeightInfo::initiate_xrp_deposit())]
 		pub fn initiate_xrp_deposit(
 			origin: OriginFor<T>,
 			etrid_account: T::AccountId,
@@ -414,7 +423,7 @@ pub mod pallet {
 
 		/// Initiate deposit from XRPL EVM Sidechain
 		#[pallet::call_index(1)]
-		#[pallet::weight(10_000)]
+		#[pallet::weight(T::WeightInfo::initiate_evm_sidechain_deposit())]
 		pub fn initiate_evm_sidechain_deposit(
 			origin: OriginFor<T>,
 			etrid_account: T::AccountId,
@@ -466,7 +475,7 @@ pub mod pallet {
 
 		/// Confirm XRP deposit after required confirmations (usually instant!)
 		#[pallet::call_index(2)]
-		#[pallet::weight(15_000)]
+		#[pallet::weight(T::WeightInfo::confirm_xrp_deposit())]
 		pub fn confirm_xrp_deposit(
 			origin: OriginFor<T>,
 			tx_hash: XrpTxHash,
@@ -533,7 +542,7 @@ pub mod pallet {
 
 		/// Request XRP withdrawal
 		#[pallet::call_index(3)]
-		#[pallet::weight(20_000)]
+		#[pallet::weight(T::WeightInfo::request_xrp_withdrawal())]
 		pub fn request_xrp_withdrawal(
 			origin: OriginFor<T>,
 			xrpl_address: XrplAddress,
@@ -602,7 +611,7 @@ pub mod pallet {
 
 		/// Update XRP/ËTR exchange rate (operator only)
 		#[pallet::call_index(4)]
-		#[pallet::weight(5_000)]
+		#[pallet::weight(T::WeightInfo::update_exchange_rate())]
 		pub fn update_exchange_rate(
 			origin: OriginFor<T>,
 			new_rate: u128,
@@ -627,7 +636,7 @@ pub mod pallet {
 
 		/// Toggle EVM Sidechain support (operator only)
 		#[pallet::call_index(5)]
-		#[pallet::weight(5_000)]
+		#[pallet::weight(T::WeightInfo::toggle_evm_sidechain())]
 		pub fn toggle_evm_sidechain(
 			origin: OriginFor<T>,
 			enabled: bool,
@@ -649,7 +658,7 @@ pub mod pallet {
 
 		/// Configure Axelar bridge integration (operator only)
 		#[pallet::call_index(6)]
-		#[pallet::weight(5_000)]
+		#[pallet::weight(T::WeightInfo::configure_axelar())]
 		pub fn configure_axelar(
 			origin: OriginFor<T>,
 			enabled: bool,
@@ -680,7 +689,7 @@ pub mod pallet {
 
 		/// Toggle Wormhole integration (operator only)
 		#[pallet::call_index(7)]
-		#[pallet::weight(5_000)]
+		#[pallet::weight(T::WeightInfo::toggle_wormhole())]
 		pub fn toggle_wormhole(
 			origin: OriginFor<T>,
 			enabled: bool,
@@ -702,7 +711,7 @@ pub mod pallet {
 
 		/// Toggle Ripple ODL integration (operator only)
 		#[pallet::call_index(8)]
-		#[pallet::weight(5_000)]
+		#[pallet::weight(T::WeightInfo::toggle_odl())]
 		pub fn toggle_odl(
 			origin: OriginFor<T>,
 			enabled: bool,
@@ -724,7 +733,7 @@ pub mod pallet {
 
 		/// Set bridge operator (root only)
 		#[pallet::call_index(9)]
-		#[pallet::weight(5_000)]
+		#[pallet::weight(T::WeightInfo::set_operator())]
 		pub fn set_operator(
 			origin: OriginFor<T>,
 			new_operator: T::AccountId,
@@ -744,7 +753,7 @@ pub mod pallet {
 		///
 		/// Locks ETR on FlareChain and emits event for relayer to mint on XRP
 		#[pallet::call_index(10)]
-		#[pallet::weight(150_000)]
+		#[pallet::weight(T::WeightInfo::bridge_etr_to_xrp())]
 		pub fn bridge_etr_to_xrp(
 			origin: OriginFor<T>,
 			amount: BalanceOf<T>,
@@ -777,7 +786,7 @@ pub mod pallet {
 		///
 		/// Unlocks ETR on FlareChain when wrapped ETR is burned on XRP
 		#[pallet::call_index(11)]
-		#[pallet::weight(150_000)]
+		#[pallet::weight(T::WeightInfo::process_etr_burn_from_xrp())]
 		pub fn process_etr_burn_from_xrp(
 			origin: OriginFor<T>,
 			etrid_recipient: T::AccountId,
@@ -0,0 +1,224 @@
+//! PLACEHOLDER weights for `xrp_bridge`: hand-estimated, not benchmark output.
+//!
+//! Overwrite with `./scripts/benchmark-pallets.sh xrp_bridge` on reference hardware.
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(missing_docs)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use core::marker::PhantomData;
+
+/// Weight functions needed for `xrp_bridge`.
+pub trait WeightInfo {
+	fn initiate_xrp_deposit() -> Weight;
+	fn initiate_evm_sidechain_deposit() -> Weight;
+	fn confirm_xrp_deposit() -> Weight;
+	fn request_xrp_withdrawal() -> Weight;
+	fn update_exchange_rate() -> Weight;
+	fn toggle_evm_sidechain() -> Weight;
+	fn configure_axelar() -> Weight;
+	fn toggle_wormhole() -> Weight;
+	fn toggle_odl() -> Weight;
+	fn set_operator() -> Weight;
+	fn bridge_etr_to_xrp() -> Weight;
+	fn process_etr_burn_from_xrp() -> Weight;
+}
+
+/// Placeholder weights for `xrp_bridge`, pending benchmarking.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	/// Storage: `XrpBridge::PendingDeposits` (r:1 w:1)
+	/// Storage: `XrpBridge::CurrentLedgerIndex` (r:1 w:1)
+	fn initiate_xrp_deposit() -> Weight {
+		Weight::from_parts(20_070_000, 4135)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `XrpBridge::EvmSidechainEnabled` (r:1 w:0)
+	/// Storage: `XrpBridge::PendingDeposits` (r:1 w:1)
+	fn initiate_evm_sidechain_deposit() -> Weight {
+		Weight::from_parts(18_640_000, 4128)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `XrpBridge::PendingDeposits` (r:1 w:1)
+	/// Storage: `XrpBridge::XrpToEtrRate` (r:1 w:0)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Storage: `XrpBridge::ConfirmedDeposits` (r:1 w:1)
+	/// Storage: `XrpBridge::TotalBridgedVolume` (r:1 w:1)
+	fn confirm_xrp_deposit() -> Weight {
+		Weight::from_parts(58_990_000, 12982)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: `XrpBridge::EvmSidechainEnabled` (r:1 w:0)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Storage: `XrpBridge::PendingWithdrawals` (r:1 w:1)
+	fn request_xrp_withdrawal() -> Weight {
+		Weight::from_parts(98_260_000, 18114)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `XrpBridge::BridgeOperator` (r:1 w:0)
+	/// Storage: `XrpBridge::XrpToEtrRate` (r:1 w:1)
+	fn update_exchange_rate() -> Weight {
+		Weight::from_parts(11_020_000, 2028)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `XrpBridge::BridgeOperator` (r:1 w:0)
+	/// Storage: `XrpBridge::EvmSidechainEnabled` (r:0 w:1)
+	fn toggle_evm_sidechain() -> Weight {
+		Weight::from_parts(10_190_000, 1517)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `XrpBridge::BridgeOperator` (r:1 w:0)
+	/// Storage: `XrpBridge::AxelarIntegration` (r:0 w:1)
+	fn configure_axelar() -> Weight {
+		Weight::from_parts(10_840_000, 1517)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `XrpBridge::BridgeOperator` (r:1 w:0)
+	/// Storage: `XrpBridge::WormholeEnabled` (r:0 w:1)
+	fn toggle_wormhole() -> Weight {
+		Weight::from_parts(10_230_000, 1517)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `XrpBridge::BridgeOperator` (r:1 w:0)
+	/// Storage: `XrpBridge::OdlEnabled` (r:0 w:1)
+	fn toggle_odl() -> Weight {
+		Weight::from_parts(10_170_000, 1517)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `XrpBridge::BridgeOperator` (r:0 w:1)
+	fn set_operator() -> Weight {
+		Weight::from_parts(7_150_000, 990)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `EtrLock::LockAccount` (r:1 w:0)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Storage: `EtrLock::TotalLocked` (r:1 w:1)
+	/// Storage: `EtrLock::LockedForChain` (r:1 w:1)
+	/// Storage: `EtrLock::LockEvents` (r:1 w:1)
+	/// Storage: `Balances::Locks` (r:1 w:1)
+	fn bridge_etr_to_xrp() -> Weight {
+		Weight::from_parts(317_940_000, 194394)
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+	/// Storage: `XrpBridge::ProcessedXrpBurns` (r:1 w:1)
+	/// Storage: `EtrLock::LockAccount` (r:1 w:0)
+	/// Storage: `EtrLock::LockedForChain` (r:1 w:1)
+	/// Storage: `EtrLock::TotalLocked` (r:1 w:1)
+	/// Storage: `Balances::Locks` (r:1 w:1)
+	/// Storage: `System::Account` (r:2 w:2)
+	fn process_etr_burn_from_xrp() -> Weight {
+		Weight::from_parts(95_610_000, 16024)
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
+	}
+}
+
+// For backwards compatibility and tests.
+impl WeightInfo for () {
+	/// Storage: `XrpBridge::PendingDeposits` (r:1 w:1)
+	/// Storage: `XrpBridge::CurrentLedgerIndex` (r:1 w:1)
+	fn initiate_xrp_deposit() -> Weight {
+		Weight::from_parts(20_070_000, 4135)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `XrpBridge::EvmSidechainEnabled` (r:1 w:0)
+	/// Storage: `XrpBridge::PendingDeposits` (r:1 w:1)
+	fn initiate_evm_sidechain_deposit() -> Weight {
+		Weight::from_parts(18_640_000, 4128)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `XrpBridge::PendingDeposits` (r:1 w:1)
+	/// Storage: `XrpBridge::XrpToEtrRate` (r:1 w:0)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Storage: `XrpBridge::ConfirmedDeposits` (r:1 w:1)
+	/// Storage: `XrpBridge::TotalBridgedVolume` (r:1 w:1)
+	fn confirm_xrp_deposit() -> Weight {
+		Weight::from_parts(58_990_000, 12982)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: `XrpBridge::EvmSidechainEnabled` (r:1 w:0)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Storage: `XrpBridge::PendingWithdrawals` (r:1 w:1)
+	fn request_xrp_withdrawal() -> Weight {
+		Weight::from_parts(98_260_000, 18114)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `XrpBridge::BridgeOperator` (r:1 w:0)
+	/// Storage: `XrpBridge::XrpToEtrRate` (r:1 w:1)
+	fn update_exchange_rate() -> Weight {
+		Weight::from_parts(11_020_000, 2028)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `XrpBridge::BridgeOperator` (r:1 w:0)
+	/// Storage: `XrpBridge::EvmSidechainEnabled` (r:0 w:1)
+	fn toggle_evm_sidechain() -> Weight {
+		Weight::from_parts(10_190_000, 1517)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `XrpBridge::BridgeOperator` (r:1 w:0)
+	/// Storage: `XrpBridge::AxelarIntegration` (r:0 w:1)
+	fn configure_axelar() -> Weight {
+		Weight::from_parts(10_840_000, 1517)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `XrpBridge::BridgeOperator` (r:1 w:0)
+	/// Storage: `XrpBridge::WormholeEnabled` (r:0 w:1)
+	fn toggle_wormhole() -> Weight {
+		Weight::from_parts(10_230_000, 1517)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `XrpBridge::BridgeOperator` (r:1 w:0)
+	/// Storage: `XrpBridge::OdlEnabled` (r:0 w:1)
+	fn toggle_odl() -> Weight {
+		Weight::from_parts(10_170_000, 1517)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `XrpBridge::BridgeOperator` (r:0 w:1)
+	fn set_operator() -> Weight {
+		Weight::from_parts(7_150_000, 990)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `EtrLock::LockAccount` (r:1 w:0)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Storage: `EtrLock::TotalLocked` (r:1 w:1)
+	/// Storage: `EtrLock::LockedForChain` (r:1 w:1)
+	/// Storage: `EtrLock::LockEvents` (r:1 w:1)
+	/// Storage: `Balances::Locks` (r:1 w:1)
+	fn bridge_etr_to_xrp() -> Weight {
+		Weight::from_parts(317_940_000, 194394)
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
+	/// Storage: `XrpBridge::ProcessedXrpBurns` (r:1 w:1)
+	/// Storage: `EtrLock::LockAccount` (r:1 w:0)
+	/// Storage: `EtrLock::LockedForChain` (r:1 w:1)
+	/// Storage: `EtrLock::TotalLocked` (r:1 w:1)
+	/// Storage: `Balances::Locks` (r:1 w:1)
+	/// Storage: `System::Account` (r:2 w:2)
+	fn process_etr_burn_from_xrp() -> Weight {
+		Weight::from_parts(95_610_000, 16024)
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
+	}
+}
@@ -60,7 +60,7 @@ pub use pallet_balances::Call as BalancesCall;
 pub use pallet_timestamp::Call as TimestampCall;
 
 mod precompiles;
-use precompiles::FrontierPrecompiles;
+use precompiles::EtridPrecompiles;
 
 // EIP-7702 Authorization List type from ethereum 0.18+
 pub type AuthorizationList = Vec<AuthorizationListItem>;
@@ -180,7 +180,8 @@ pub const VERSION: RuntimeVersion = RuntimeVersion {
 	spec_name: Cow::Borrowed("eth-pbc"),
 	impl_name: Cow::Borrowed("eth-pbc"),
 	authoring_version: 1,
-	spec_version: 1,
+	// 2: BN254 pairing at 0x08, SHA3FIPS256 moved from 0x08 to 0x400 (see CHANGELOG.md)
+	spec_version: 2,
 	impl_version: 1,
 	apis: RUNTIME_API_VERSIONS,
 	transaction_version: 1,
@@ -353,7 +354,7 @@ parameter_types! {
 	pub BlockGasLimit: U256 = U256::from(BLOCK_GAS_LIMIT);
 	pub const GasLimitPovSizeRatio: u64 = BLOCK_GAS_LIMIT.saturating_div(MAX_POV_SIZE);
 	pub const GasLimitStorageGrowthRatio: u64 = BLOCK_GAS_LIMIT.saturating_div(MAX_STORAGE_GROWTH);
-	pub PrecompilesValue: FrontierPrecompiles<Runtime> = FrontierPrecompiles::<_>::new();
+	pub PrecompilesValue: EtridPrecompiles<Runtime> = EtridPrecompiles::<_>::new();
 	pub WeightPerGas: Weight = Weight::from_parts(weight_per_gas(BLOCK_GAS_LIMIT, NORMAL_DISPATCH_RATIO, WEIGHT_MILLISECS_PER_BLOCK), 0);
 }
 
@@ -367,7 +368,7 @@ impl pallet_evm::Config for Runtime {
 	type WithdrawOrigin = EnsureAccountId20;
 	type AddressMapping = IdentityAddressMapping;
 	type Currency = Balances;
-	type PrecompilesType = FrontierPrecompiles<Self>;
+	type PrecompilesType = EtridPrecompiles<Self>;
 	type PrecompilesValue = PrecompilesValue;
 	type ChainId = EVMChainId;
 	type BlockGasLimit = BlockGasLimit;
@@ -452,6 +453,24 @@ pub mod pallet_manual_seal {
 
 impl pallet_manual_seal::Config for Runtime {}
 
+parameter_types! {
+	pub const MirrorMaxTitleLength: u32 = 256;
+	pub const MirrorMaxDescriptionLength: u32 = 10_000;
+	pub const MirrorMaxPendingActions: u32 = 1_024;
+	pub const MirrorMaxBatchSize: u32 = 64;
+}
+
+/// FlareChain state read by the Ëtrid precompiles, posted by the relayer (sudo)
+impl pallet_flarechain_mirror::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type RelayerOrigin = frame_system::EnsureRoot<AccountId>;
+	type MaxTitleLength = MirrorMaxTitleLength;
+	type MaxDescriptionLength = MirrorMaxDescriptionLength;
+	type MaxPendingActions = MirrorMaxPendingActions;
+	type MaxBatchSize = MirrorMaxBatchSize;
+	type WeightInfo = ();
+}
+
 // Create the runtime by composing the FRAME pallets that were previously configured.
 #[frame_support::runtime]
 mod runtime {
@@ -504,6 +523,9 @@ mod runtime {
 
 	#[runtime::pallet_index(11)]
 	pub type ManualSeal = pallet_manual_seal;
+
+	#[runtime::pallet_index(12)]
+	pub type FlareChainMirror = pallet_flarechain_mirror;
 }
 
 #[derive(Clone)]
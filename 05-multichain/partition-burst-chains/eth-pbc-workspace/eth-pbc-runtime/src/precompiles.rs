@@ -1,35 +1,67 @@
 use core::marker::PhantomData;
+use frame_support::{traits::Get, weights::Weight};
 use pallet_evm::{
-	IsPrecompileResult, Precompile, PrecompileHandle, PrecompileResult, PrecompileSet,
+	GasWeightMapping, IsPrecompileResult, Precompile, PrecompileFailure, PrecompileHandle,
+	PrecompileResult, PrecompileSet,
 };
 use sp_core::H160;
+use sp_runtime::{DispatchError, ModuleError};
 
+use pallet_evm_precompile_blake2::Blake2F;
+use pallet_evm_precompile_bn128::{Bn128Add, Bn128Mul, Bn128Pairing};
 use pallet_evm_precompile_modexp::Modexp;
 use pallet_evm_precompile_sha3fips::Sha3FIPS256;
 use pallet_evm_precompile_simple::{ECRecover, Identity, Ripemd160, Sha256};
 
-pub struct FrontierPrecompiles<R>(PhantomData<R>);
+use crate::Runtime;
 
-impl<R> FrontierPrecompiles<R>
+// Ëtrid precompiles, addresses as in rust-etrid-sdk `eth_pbc_precompiles::addresses`
+mod abi;
+mod governance;
+mod kzg_point_evaluation;
+mod native_eth_wrap;
+mod oracle;
+mod staking;
+
+pub use governance::EtridGovernancePrecompile;
+pub use kzg_point_evaluation::KzgPointEvaluation;
+pub use native_eth_wrap::NativeEthWrapPrecompile;
+pub use oracle::EtridOraclePrecompile;
+pub use staking::EtridStakingPrecompile;
+
+/// Ethereum precompiles through Cancun (0x01-0x0a), SHA3-FIPS256 (0x400) and the
+/// Ëtrid precompiles (0x800-0x803)
+pub struct EtridPrecompiles<R>(PhantomData<R>);
+
+impl<R> EtridPrecompiles<R>
 where
 	R: pallet_evm::Config,
 {
 	pub fn new() -> Self {
 		Self(Default::default())
 	}
-	pub fn used_addresses() -> [H160; 6] {
+	pub fn used_addresses() -> [H160; 15] {
 		[
 			hash(1),
 			hash(2),
 			hash(3),
 			hash(4),
 			hash(5),
+			hash(6),
+			hash(7),
 			hash(8),
+			hash(9),
+			hash(0x0a),
+			hash(0x400),
+			hash(0x800),
+			hash(0x801),
+			hash(0x802),
+			hash(0x803),
 		]
 	}
 }
 
-impl<R> PrecompileSet for FrontierPrecompiles<R>
+impl<R> PrecompileSet for EtridPrecompiles<R>
 where
 	R: pallet_evm::Config,
 {
@@ -41,8 +73,18 @@ where
 			a if a == hash(3) => Some(Ripemd160::execute(handle)),
 			a if a == hash(4) => Some(Identity::execute(handle)),
 			a if a == hash(5) => Some(Modexp::execute(handle)),
-			// SHA3FIPS256 (0x08)
-			a if a == hash(8) => Some(Sha3FIPS256::execute(handle)),
+			a if a == hash(6) => Some(Bn128Add::execute(handle)),
+			a if a == hash(7) => Some(Bn128Mul::execute(handle)),
+			a if a == hash(8) => Some(Bn128Pairing::execute(handle)),
+			a if a == hash(9) => Some(Blake2F::execute(handle)),
+			a if a == hash(0x0a) => Some(KzgPointEvaluation::execute(handle)),
+			// SHA3FIPS256, moved off 0x08 (BN254 pairing on Ethereum)
+			a if a == hash(0x400) => Some(Sha3FIPS256::execute(handle)),
+			// Ëtrid precompiles:
+			a if a == hash(0x800) => Some(EtridOraclePrecompile::execute(handle)),
+			a if a == hash(0x801) => Some(EtridGovernancePrecompile::execute(handle)),
+			a if a == hash(0x802) => Some(EtridStakingPrecompile::execute(handle)),
+			a if a == hash(0x803) => Some(NativeEthWrapPrecompile::execute(handle)),
 			_ => None,
 		}
 	}
@@ -58,3 +100,130 @@ where
 fn hash(a: u64) -> H160 {
 	H160::from_low_u64_be(a)
 }
+
+/// Charge the gas equivalent of a runtime weight, and its proof size
+fn charge(handle: &mut impl PrecompileHandle, weight: Weight) -> Result<(), PrecompileFailure> {
+	handle.record_cost(<Runtime as pallet_evm::Config>::GasWeightMapping::weight_to_gas(weight))?;
+	handle.record_external_cost(Some(weight.ref_time()), Some(weight.proof_size()), None)?;
+	Ok(())
+}
+
+/// Weight of `n` storage reads
+fn reads(n: u64) -> Weight {
+	<Runtime as frame_system::Config>::DbWeight::get().reads(n)
+}
+
+/// Weight of `r` storage reads and `w` writes
+fn reads_writes(r: u64, w: u64) -> Weight {
+	<Runtime as frame_system::Config>::DbWeight::get().reads_writes(r, w)
+}
+
+/// Gas of a `LOG` with `topics` topics and `len` data bytes (yellow paper)
+fn log_cost(topics: usize, len: usize) -> u64 {
+	375 + 375 * topics as u64 + 8 * len as u64
+}
+
+/// Reject state changes under `STATICCALL`
+fn ensure_mutable(handle: &impl PrecompileHandle) -> Result<(), PrecompileFailure> {
+	if handle.is_static() {
+		return Err(abi::revert("Cannot modify state in static call"));
+	}
+	Ok(())
+}
+
+/// Reject value sent to a non-payable function
+fn ensure_no_value(handle: &impl PrecompileHandle) -> Result<(), PrecompileFailure> {
+	if !handle.context().apparent_value.is_zero() {
+		return Err(abi::revert("Function is not payable"));
+	}
+	Ok(())
+}
+
+/// Revert with the pallet error name
+fn dispatch_error(error: DispatchError) -> PrecompileFailure {
+	match error {
+		DispatchError::Module(ModuleError { message: Some(message), .. }) => abi::revert(message),
+		_ => abi::revert("Dispatch failed"),
+	}
+}
+
+/// Right-padded `bytes32` symbol
+fn symbol(name: &[u8]) -> [u8; 32] {
+	let mut out = [0u8; 32];
+	out[..name.len()].copy_from_slice(name);
+	out
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use pallet_evm_test_vector_support::{
+		test_precompile_failure_test_vectors, test_precompile_test_vectors,
+	};
+
+	fn selector(signature: &str) -> abi::Selector {
+		let hash = sp_io::hashing::keccak_256(signature.as_bytes());
+		[hash[0], hash[1], hash[2], hash[3]]
+	}
+
+	#[test]
+	fn selectors_match_solidity_interfaces() {
+		assert_eq!(oracle::GET_PRICE, selector("getPrice(bytes32,bytes32)"));
+		assert_eq!(oracle::GET_PRICE_IN_ETH, selector("getPriceInETH(bytes32)"));
+		assert_eq!(oracle::GET_LAST_UPDATE, selector("getLastUpdate(bytes32)"));
+		assert_eq!(governance::SUBMIT_PROPOSAL, selector("submitProposal(string,string)"));
+		assert_eq!(governance::VOTE_ON_PROPOSAL, selector("voteOnProposal(uint256,bool)"));
+		assert_eq!(governance::GET_PROPOSAL_STATUS, selector("getProposalStatus(uint256)"));
+		assert_eq!(staking::GET_VALIDATOR_STAKE, selector("getValidatorStake(bytes32)"));
+		assert_eq!(staking::IS_VALIDATOR_ACTIVE, selector("isValidatorActive(bytes32)"));
+		assert_eq!(staking::GET_TOTAL_STAKED, selector("getTotalStaked()"));
+		assert_eq!(staking::GET_VALIDATOR_COUNT, selector("getValidatorCount()"));
+		assert_eq!(native_eth_wrap::WRAP, selector("wrap()"));
+		assert_eq!(native_eth_wrap::UNWRAP, selector("unwrap(uint256)"));
+		assert_eq!(native_eth_wrap::GET_WRAP_RATE, selector("getWrapRate()"));
+		assert_eq!(native_eth_wrap::BALANCE_OF, selector("balanceOf(address)"));
+		assert_eq!(native_eth_wrap::TOTAL_SUPPLY, selector("totalSupply()"));
+	}
+
+	#[test]
+	fn precompile_addresses_match_sdk() {
+		let used = EtridPrecompiles::<Runtime>::used_addresses();
+		for address in [0x800, 0x801, 0x802, 0x803] {
+			assert!(used.contains(&hash(address)));
+		}
+	}
+
+	// Ethereum conformance vectors from go-ethereum's `core/vm/testdata/precompiles`.
+	// The BN254 and Blake2F files are a subset of geth's; refresh them with
+	// `testdata/fetch-geth-vectors.sh` to check every geth vector.
+
+	#[test]
+	fn bn128_add_vectors() {
+		test_precompile_test_vectors::<Bn128Add>("testdata/bn256Add.json").unwrap();
+	}
+
+	#[test]
+	fn bn128_mul_vectors() {
+		test_precompile_test_vectors::<Bn128Mul>("testdata/bn256ScalarMul.json").unwrap();
+	}
+
+	#[test]
+	fn bn128_pairing_vectors() {
+		test_precompile_test_vectors::<Bn128Pairing>("testdata/bn256Pairing.json").unwrap();
+	}
+
+	#[test]
+	fn blake2f_vectors() {
+		test_precompile_test_vectors::<Blake2F>("testdata/blake2F.json").unwrap();
+	}
+
+	#[test]
+	fn point_evaluation_vectors() {
+		test_precompile_test_vectors::<KzgPointEvaluation>("testdata/pointEvaluation.json")
+			.unwrap();
+		test_precompile_failure_test_vectors::<KzgPointEvaluation>(
+			"testdata/fail-pointEvaluation.json",
+		)
+		.unwrap();
+	}
+}
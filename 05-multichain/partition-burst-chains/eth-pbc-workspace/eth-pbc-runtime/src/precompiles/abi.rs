@@ -0,0 +1,178 @@
+//! Solidity ABI decoding and encoding for the Ëtrid precompiles
+
+use alloc::{vec, vec::Vec};
+use pallet_evm::{ExitRevert, ExitSucceed, PrecompileFailure, PrecompileOutput};
+use sp_core::{H160, U256};
+
+/// First four bytes of `keccak256(signature)`
+pub type Selector = [u8; 4];
+
+/// Call arguments following the selector, read as 32-byte words
+pub struct Reader<'a> {
+	args: &'a [u8],
+}
+
+impl<'a> Reader<'a> {
+	/// Split call data into selector and arguments
+	pub fn new(input: &'a [u8]) -> Result<(Selector, Self), PrecompileFailure> {
+		if input.len() < 4 {
+			return Err(revert("Missing function selector"));
+		}
+		let mut selector = [0u8; 4];
+		selector.copy_from_slice(&input[..4]);
+		Ok((selector, Self { args: &input[4..] }))
+	}
+
+	fn word_at(&self, offset: usize) -> Result<&'a [u8], PrecompileFailure> {
+		offset
+			.checked_add(32)
+			.and_then(|end| self.args.get(offset..end))
+			.ok_or_else(|| revert("Input too short"))
+	}
+
+	fn word(&self, index: usize) -> Result<&'a [u8], PrecompileFailure> {
+		self.word_at(index.saturating_mul(32))
+	}
+
+	/// `bytes32` argument
+	pub fn bytes32(&self, index: usize) -> Result<[u8; 32], PrecompileFailure> {
+		let mut out = [0u8; 32];
+		out.copy_from_slice(self.word(index)?);
+		Ok(out)
+	}
+
+	/// `uint256` argument
+	pub fn uint256(&self, index: usize) -> Result<U256, PrecompileFailure> {
+		Ok(U256::from_big_endian(self.word(index)?))
+	}
+
+	/// `uint256` argument that must fit in a `u64`
+	pub fn uint64(&self, index: usize) -> Result<u64, PrecompileFailure> {
+		let value = self.uint256(index)?;
+		if value > U256::from(u64::MAX) {
+			return Err(revert("Value out of range"));
+		}
+		Ok(value.low_u64())
+	}
+
+	/// `bool` argument
+	pub fn bool(&self, index: usize) -> Result<bool, PrecompileFailure> {
+		match self.uint256(index)? {
+			v if v.is_zero() => Ok(false),
+			v if v == U256::one() => Ok(true),
+			_ => Err(revert("Invalid bool")),
+		}
+	}
+
+	/// `address` argument
+	pub fn address(&self, index: usize) -> Result<H160, PrecompileFailure> {
+		let word = self.word(index)?;
+		if word[..12].iter().any(|b| *b != 0) {
+			return Err(revert("Invalid address"));
+		}
+		Ok(H160::from_slice(&word[12..]))
+	}
+
+	/// Dynamic `bytes` or `string` argument (head word holds the tail offset)
+	pub fn bytes(&self, index: usize) -> Result<Vec<u8>, PrecompileFailure> {
+		let offset = Self::usize(self.word(index)?)?;
+		let len = Self::usize(self.word_at(offset)?)?;
+		let start = offset.saturating_add(32);
+		start
+			.checked_add(len)
+			.and_then(|end| self.args.get(start..end))
+			.map(|data| data.to_vec())
+			.ok_or_else(|| revert("Input too short"))
+	}
+
+	fn usize(word: &[u8]) -> Result<usize, PrecompileFailure> {
+		let value = U256::from_big_endian(word);
+		if value > U256::from(u32::MAX) {
+			return Err(revert("Offset out of range"));
+		}
+		Ok(value.low_u64() as usize)
+	}
+}
+
+/// ABI-encoded `uint256`
+pub fn uint256(value: impl Into<U256>) -> Vec<u8> {
+	value.into().to_big_endian().to_vec()
+}
+
+/// ABI-encoded `bool`
+pub fn bool(value: bool) -> Vec<u8> {
+	uint256(value as u8)
+}
+
+/// Successful return with ABI-encoded `output`
+pub fn returned(output: Vec<u8>) -> PrecompileOutput {
+	PrecompileOutput { exit_status: ExitSucceed::Returned, output }
+}
+
+/// Revert with a Solidity `Error(string)` reason
+pub fn revert(reason: &str) -> PrecompileFailure {
+	// keccak256("Error(string)")[..4]
+	let mut output = vec![0x08, 0xc3, 0x79, 0xa0];
+	output.extend(uint256(32u8));
+	output.extend(uint256(reason.len()));
+	output.extend_from_slice(reason.as_bytes());
+	output.resize(4 + 64 + reason.len().div_ceil(32) * 32, 0);
+	PrecompileFailure::Revert { exit_status: ExitRevert::Reverted, output }
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn call(selector: Selector, words: &[Vec<u8>]) -> Vec<u8> {
+		let mut input = selector.to_vec();
+		for word in words {
+			input.extend_from_slice(word);
+		}
+		input
+	}
+
+	#[test]
+	fn reads_static_arguments() {
+		let mut address = vec![0u8; 12];
+		address.extend_from_slice(H160::repeat_byte(9).as_bytes());
+		let input = call([1, 2, 3, 4], &[uint256(7u8), bool(true), address]);
+		let (selector, reader) = Reader::new(&input).unwrap();
+		assert_eq!(selector, [1, 2, 3, 4]);
+		assert_eq!(reader.uint64(0).unwrap(), 7);
+		assert!(reader.bool(1).unwrap());
+		assert_eq!(reader.address(2).unwrap(), H160::repeat_byte(9));
+		assert!(reader.uint256(3).is_err());
+		assert!(reader.bool(0).is_err());
+		assert!(Reader::new(&[1, 2]).is_err());
+	}
+
+	#[test]
+	fn reads_dynamic_strings() {
+		// submitProposal("title", "")
+		let mut title = b"title".to_vec();
+		title.resize(32, 0);
+		let input = call(
+			[0xbe, 0x0d, 0xc4, 0xb7],
+			&[uint256(64u8), uint256(128u8), uint256(5u8), title, uint256(0u8)],
+		);
+		let (_, reader) = Reader::new(&input).unwrap();
+		assert_eq!(reader.bytes(0).unwrap(), b"title".to_vec());
+		assert_eq!(reader.bytes(1).unwrap(), Vec::<u8>::new());
+
+		// Length running past the input
+		let input = call([0; 4], &[uint256(32u8), uint256(64u8)]);
+		assert!(Reader::new(&input).unwrap().1.bytes(0).is_err());
+	}
+
+	#[test]
+	fn revert_encodes_error_string() {
+		let PrecompileFailure::Revert { output, .. } = revert("Nope") else {
+			panic!("expected revert")
+		};
+		assert_eq!(output.len(), 4 + 32 * 3);
+		assert_eq!(&output[..4], &sp_io::hashing::keccak_256(b"Error(string)")[..4]);
+		assert_eq!(U256::from_big_endian(&output[36..68]), U256::from(4));
+		assert_eq!(&output[68..72], b"Nope");
+	}
+}
@@ -0,0 +1,63 @@
+//! Governance precompile (0x801): FlareChain proposals from EVM contracts
+//!
+//! Proposals and votes are queued in `pallet-flarechain-mirror`'s outbox and
+//! relayed to FlareChain; statuses are those last posted by the relayer.
+
+use pallet_evm::{Precompile, PrecompileHandle, PrecompileResult};
+use pallet_flarechain_mirror::WeightInfo as _;
+
+use super::{
+	abi::{self, Reader, Selector},
+	charge, dispatch_error, ensure_mutable, ensure_no_value, reads,
+};
+use crate::{FlareChainMirror, Runtime};
+
+/// `submitProposal(string,string)`
+pub(super) const SUBMIT_PROPOSAL: Selector = [0xbe, 0x0d, 0xc4, 0xb7];
+/// `voteOnProposal(uint256,bool)`
+pub(super) const VOTE_ON_PROPOSAL: Selector = [0x16, 0x14, 0x66, 0x5c];
+/// `getProposalStatus(uint256)`
+pub(super) const GET_PROPOSAL_STATUS: Selector = [0x40, 0x18, 0x53, 0xb7];
+
+type MirrorWeights = <Runtime as pallet_flarechain_mirror::Config>::WeightInfo;
+
+/// `IEtridGovernance`
+///
+/// `submitProposal` returns the outbox nonce of the queued proposal; the
+/// FlareChain proposal ID is assigned when it is relayed. Votes are accepted
+/// once per account on proposals the mirror reports as active.
+pub struct EtridGovernancePrecompile;
+
+impl Precompile for EtridGovernancePrecompile {
+	fn execute(handle: &mut impl PrecompileHandle) -> PrecompileResult {
+		let input = handle.input().to_vec();
+		let (selector, args) = Reader::new(&input)?;
+		ensure_no_value(handle)?;
+		let caller = handle.context().caller;
+
+		match selector {
+			SUBMIT_PROPOSAL => {
+				ensure_mutable(handle)?;
+				charge(handle, MirrorWeights::submit_proposal())?;
+				let nonce =
+					FlareChainMirror::submit_proposal(caller, args.bytes(0)?, args.bytes(1)?)
+						.map_err(dispatch_error)?;
+				Ok(abi::returned(abi::uint256(nonce)))
+			},
+			VOTE_ON_PROPOSAL => {
+				ensure_mutable(handle)?;
+				charge(handle, MirrorWeights::vote())?;
+				FlareChainMirror::vote(caller, args.uint64(0)?, args.bool(1)?)
+					.map_err(dispatch_error)?;
+				Ok(abi::returned(Default::default()))
+			},
+			GET_PROPOSAL_STATUS => {
+				charge(handle, reads(1))?;
+				let status = FlareChainMirror::proposal_status(args.uint64(0)?)
+					.ok_or_else(|| abi::revert("Unknown proposal"))?;
+				Ok(abi::returned(abi::uint256(status as u8)))
+			},
+			_ => Err(abi::revert("Unknown function selector")),
+		}
+	}
+}
@@ -0,0 +1,101 @@
+//! KZG point evaluation precompile (0x0a, EIP-4844)
+//!
+//! Verifies that a blob commitment opens to `y` at `z`, using the `[τ]G2` point of
+//! the Ethereum KZG ceremony. Input is `versioned_hash ++ z ++ y ++ commitment ++ proof`
+//! (192 bytes); output is `FIELD_ELEMENTS_PER_BLOB ++ BLS_MODULUS`.
+
+use ark_bls12_381::{Bls12_381, Fr, G1Affine, G2Affine};
+use ark_ec::{pairing::Pairing, AffineRepr, CurveGroup};
+use ark_ff::{BigInt, PrimeField, Zero};
+use ark_serialize::CanonicalDeserialize;
+use pallet_evm::{
+	ExitError, ExitSucceed, Precompile, PrecompileFailure, PrecompileHandle, PrecompileOutput,
+	PrecompileResult,
+};
+
+/// Gas per call (Cancun)
+const GAS_COST: u64 = 50_000;
+
+const INPUT_LENGTH: usize = 192;
+
+/// Version byte of a KZG-committed blob hash
+const VERSIONED_HASH_VERSION_KZG: u8 = 0x01;
+
+/// `FIELD_ELEMENTS_PER_BLOB ++ BLS_MODULUS`, both as `uint256`
+const RETURN_VALUE: [u8; 64] = sp_core::hex2array!(
+	"0000000000000000000000000000000000000000000000000000000000001000\
+	 73eda753299d7d483339d80809a1d80553bda402fffe5bfeffffffff00000001"
+);
+
+/// Compressed `[τ]G2` from the Ethereum KZG ceremony `trusted_setup.txt`
+const TAU_G2: [u8; 96] = sp_core::hex2array!(
+	"b5bfd7dd8cdeb128843bc287230af38926187075cbfbefa81009a2ce615ac53d\
+	 2914e5870cb452d2afaaab24f3499f72185cbfee53492714734429b7b38608e2\
+	 3926c911cceceac9a36851477ba4c60b087041de621000edc98edada20c1def2"
+);
+
+pub struct KzgPointEvaluation;
+
+impl Precompile for KzgPointEvaluation {
+	fn execute(handle: &mut impl PrecompileHandle) -> PrecompileResult {
+		handle.record_cost(GAS_COST)?;
+
+		let input = handle.input();
+		if input.len() != INPUT_LENGTH {
+			return Err(error("invalid input length"));
+		}
+		let (versioned_hash, input) = input.split_at(32);
+		let (z, input) = input.split_at(32);
+		let (y, input) = input.split_at(32);
+		let (commitment, proof) = input.split_at(48);
+
+		let mut expected_hash = sp_io::hashing::sha2_256(commitment);
+		expected_hash[0] = VERSIONED_HASH_VERSION_KZG;
+		if versioned_hash != expected_hash {
+			return Err(error("mismatched versioned hash"));
+		}
+
+		if !verify_kzg_proof(commitment, z, y, proof)? {
+			return Err(error("invalid KZG proof"));
+		}
+
+		Ok(PrecompileOutput { exit_status: ExitSucceed::Returned, output: RETURN_VALUE.to_vec() })
+	}
+}
+
+/// Check `e(C - [y]G1, G2) == e(π, [τ]G2 - [z]G2)`
+fn verify_kzg_proof(
+	commitment: &[u8],
+	z: &[u8],
+	y: &[u8],
+	proof: &[u8],
+) -> Result<bool, PrecompileFailure> {
+	let commitment = g1(commitment)?;
+	let proof = g1(proof)?;
+	let z = field_element(z)?;
+	let y = field_element(y)?;
+	let tau = G2Affine::deserialize_compressed_unchecked(&TAU_G2[..])
+		.map_err(|_| error("invalid trusted setup"))?;
+
+	let lhs = (commitment.into_group() - G1Affine::generator() * y).into_affine();
+	let rhs = (tau.into_group() - G2Affine::generator() * z).into_affine();
+	Ok(Bls12_381::multi_pairing([lhs, proof], [-G2Affine::generator(), rhs]).is_zero())
+}
+
+/// Compressed G1 point, checked to be in the subgroup
+fn g1(bytes: &[u8]) -> Result<G1Affine, PrecompileFailure> {
+	G1Affine::deserialize_compressed(bytes).map_err(|_| error("invalid G1 point"))
+}
+
+/// Big-endian scalar, rejected unless below the BLS modulus
+fn field_element(bytes: &[u8]) -> Result<Fr, PrecompileFailure> {
+	let mut limbs = [0u64; 4];
+	for (limb, chunk) in limbs.iter_mut().zip(bytes.rchunks(8)) {
+		*limb = u64::from_be_bytes(chunk.try_into().expect("rchunks of 32 bytes are 8 bytes"));
+	}
+	Fr::from_bigint(BigInt::new(limbs)).ok_or_else(|| error("invalid field element"))
+}
+
+fn error(message: &'static str) -> PrecompileFailure {
+	PrecompileFailure::Error { exit_status: ExitError::Other(message.into()) }
+}
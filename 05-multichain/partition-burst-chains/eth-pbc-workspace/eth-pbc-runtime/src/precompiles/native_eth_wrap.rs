@@ -0,0 +1,166 @@
+//! Native ETH wrap precompile (0x803): ERC-20 style wrapped native ETH
+//!
+//! Wrapped ETH is backed 1:1 by the native balance the EVM credits to the
+//! precompile address on `wrap()`. Balances live in the precompile's own
+//! EVM storage using Solidity's layout (`totalSupply` in slot 0,
+//! `balanceOf` mapping in slot 1), so block explorers can read them.
+
+use alloc::vec;
+use frame_support::traits::{fungible::Mutate, tokens::Preservation};
+use pallet_balances::WeightInfo as _;
+use pallet_evm::{AccountStorages, Precompile, PrecompileHandle, PrecompileResult};
+use sp_core::{H160, H256, U256};
+
+use super::{
+	abi::{self, Reader, Selector},
+	charge, dispatch_error, ensure_mutable, ensure_no_value, log_cost, reads, reads_writes,
+};
+use crate::{AccountId, Balance, Balances, Runtime};
+
+/// `wrap()`
+pub(super) const WRAP: Selector = [0xd4, 0x6e, 0xb1, 0x19];
+/// `unwrap(uint256)`
+pub(super) const UNWRAP: Selector = [0xde, 0x0e, 0x9a, 0x3e];
+/// `getWrapRate()`
+pub(super) const GET_WRAP_RATE: Selector = [0xf3, 0x0f, 0x31, 0x0c];
+/// `balanceOf(address)`
+pub(super) const BALANCE_OF: Selector = [0x70, 0xa0, 0x82, 0x31];
+/// `totalSupply()`
+pub(super) const TOTAL_SUPPLY: Selector = [0x18, 0x16, 0x0d, 0xdd];
+
+/// `Deposit(address,uint256)`
+const DEPOSIT_EVENT: [u8; 32] =
+	sp_core::hex2array!("e1fffcc4923d04b559f4d29a8bfc6cda04eb5b0d3c460751c2402c5c5cc9109c");
+/// `Withdrawal(address,uint256)`
+const WITHDRAWAL_EVENT: [u8; 32] =
+	sp_core::hex2array!("7fcf532c15f0a6db0bd6d0e038bea71d30d808c7d98cb3bf7268a95bf5081b65");
+
+/// 1 wrapped ETH per native ETH, scaled by 1e18
+const WRAP_RATE: u128 = 1_000_000_000_000_000_000;
+
+const TOTAL_SUPPLY_SLOT: u64 = 0;
+const BALANCES_SLOT: u64 = 1;
+
+/// `IEtridNativeETH`
+pub struct NativeEthWrapPrecompile;
+
+impl Precompile for NativeEthWrapPrecompile {
+	fn execute(handle: &mut impl PrecompileHandle) -> PrecompileResult {
+		// Storage and funds belong to the precompile address
+		if handle.code_address() != handle.context().address {
+			return Err(abi::revert("Cannot be called with DELEGATECALL or CALLCODE"));
+		}
+
+		let input = handle.input().to_vec();
+		let (selector, args) = Reader::new(&input)?;
+		let this = handle.code_address();
+		let caller = handle.context().caller;
+
+		if selector != WRAP {
+			ensure_no_value(handle)?;
+		}
+
+		match selector {
+			WRAP => {
+				ensure_mutable(handle)?;
+				let amount = handle.context().apparent_value;
+				if amount.is_zero() {
+					return Err(abi::revert("Nothing to wrap"));
+				}
+				charge(handle, reads_writes(2, 2))?;
+				handle.record_cost(log_cost(2, 32))?;
+
+				set_balance(this, caller, balance_of(this, caller).saturating_add(amount));
+				set_total_supply(this, total_supply(this).saturating_add(amount));
+				handle.log(
+					this,
+					vec![DEPOSIT_EVENT.into(), caller.into()],
+					abi::uint256(amount),
+				)?;
+
+				Ok(abi::returned(abi::uint256(amount)))
+			},
+			UNWRAP => {
+				ensure_mutable(handle)?;
+				let amount = args.uint256(0)?;
+				charge(
+					handle,
+					reads_writes(2, 2).saturating_add(
+						<Runtime as pallet_balances::Config>::WeightInfo::transfer_allow_death(),
+					),
+				)?;
+				handle.record_cost(log_cost(2, 32))?;
+
+				let balance = balance_of(this, caller);
+				if amount > balance {
+					return Err(abi::revert("Insufficient wrapped balance"));
+				}
+				let value: Balance =
+					amount.try_into().map_err(|_| abi::revert("Value out of range"))?;
+
+				set_balance(this, caller, balance - amount);
+				set_total_supply(this, total_supply(this).saturating_sub(amount));
+				<Balances as Mutate<AccountId>>::transfer(
+					&AccountId::from(this),
+					&AccountId::from(caller),
+					value,
+					Preservation::Expendable,
+				)
+				.map_err(dispatch_error)?;
+				handle.log(
+					this,
+					vec![WITHDRAWAL_EVENT.into(), caller.into()],
+					abi::uint256(amount),
+				)?;
+
+				Ok(abi::returned(abi::bool(true)))
+			},
+			GET_WRAP_RATE => Ok(abi::returned(abi::uint256(WRAP_RATE))),
+			BALANCE_OF => {
+				charge(handle, reads(1))?;
+				Ok(abi::returned(abi::uint256(balance_of(this, args.address(0)?))))
+			},
+			TOTAL_SUPPLY => {
+				charge(handle, reads(1))?;
+				Ok(abi::returned(abi::uint256(total_supply(this))))
+			},
+			_ => Err(abi::revert("Unknown function selector")),
+		}
+	}
+}
+
+/// Solidity slot of `balanceOf[owner]`: `keccak256(pad(owner) ++ pad(1))`
+fn balance_slot(owner: H160) -> H256 {
+	let mut key = [0u8; 64];
+	key[12..32].copy_from_slice(owner.as_bytes());
+	key[32..].copy_from_slice(&abi::uint256(BALANCES_SLOT));
+	sp_io::hashing::keccak_256(&key).into()
+}
+
+fn read(this: H160, slot: H256) -> U256 {
+	U256::from_big_endian(AccountStorages::<Runtime>::get(this, slot).as_bytes())
+}
+
+fn write(this: H160, slot: H256, value: U256) {
+	if value.is_zero() {
+		AccountStorages::<Runtime>::remove(this, slot);
+	} else {
+		AccountStorages::<Runtime>::insert(this, slot, H256(value.to_big_endian()));
+	}
+}
+
+fn balance_of(this: H160, owner: H160) -> U256 {
+	read(this, balance_slot(owner))
+}
+
+fn set_balance(this: H160, owner: H160, value: U256) {
+	write(this, balance_slot(owner), value)
+}
+
+fn total_supply(this: H160) -> U256 {
+	read(this, H256::from_low_u64_be(TOTAL_SUPPLY_SLOT))
+}
+
+fn set_total_supply(this: H160, value: U256) {
+	write(this, H256::from_low_u64_be(TOTAL_SUPPLY_SLOT), value)
+}
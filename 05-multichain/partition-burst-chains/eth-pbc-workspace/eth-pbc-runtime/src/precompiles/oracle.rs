@@ -0,0 +1,54 @@
+//! Oracle precompile (0x800): FlareChain price feeds mirrored on ETH-PBC
+
+use pallet_evm::{Precompile, PrecompileHandle, PrecompileResult};
+
+use super::{
+	abi::{self, Reader, Selector},
+	charge, ensure_no_value, reads, symbol,
+};
+use crate::FlareChainMirror;
+
+/// `getPrice(bytes32,bytes32)`
+pub(super) const GET_PRICE: Selector = [0x07, 0x76, 0xf2, 0x44];
+/// `getPriceInETH(bytes32)`
+pub(super) const GET_PRICE_IN_ETH: Selector = [0xee, 0x29, 0x73, 0x27];
+/// `getLastUpdate(bytes32)`
+pub(super) const GET_LAST_UPDATE: Selector = [0xa3, 0x4e, 0xa2, 0xa3];
+
+/// `IEtridOracle`
+///
+/// Prices are scaled by 1e18; symbols are right-padded ASCII `bytes32`.
+/// Unknown feeds revert rather than return zero.
+pub struct EtridOraclePrecompile;
+
+impl Precompile for EtridOraclePrecompile {
+	fn execute(handle: &mut impl PrecompileHandle) -> PrecompileResult {
+		let input = handle.input().to_vec();
+		let (selector, args) = Reader::new(&input)?;
+		ensure_no_value(handle)?;
+
+		match selector {
+			GET_PRICE => {
+				charge(handle, reads(1))?;
+				price(args.bytes32(0)?, args.bytes32(1)?)
+			},
+			GET_PRICE_IN_ETH => {
+				charge(handle, reads(1))?;
+				price(args.bytes32(0)?, symbol(b"ETH"))
+			},
+			GET_LAST_UPDATE => {
+				charge(handle, reads(1))?;
+				let updated_at = FlareChainMirror::last_update(&args.bytes32(0)?)
+					.ok_or_else(|| abi::revert("Unknown price feed"))?;
+				Ok(abi::returned(abi::uint256(updated_at)))
+			},
+			_ => Err(abi::revert("Unknown function selector")),
+		}
+	}
+}
+
+fn price(symbol: [u8; 32], quote: [u8; 32]) -> PrecompileResult {
+	let data = FlareChainMirror::price(&symbol, &quote)
+		.ok_or_else(|| abi::revert("Unknown price feed"))?;
+	Ok(abi::returned(abi::uint256(data.price)))
+}
@@ -0,0 +1,48 @@
+//! Staking precompile (0x802): FlareChain validator stakes mirrored on ETH-PBC
+
+use pallet_evm::{Precompile, PrecompileHandle, PrecompileResult};
+
+use super::{
+	abi::{self, Reader, Selector},
+	charge, ensure_no_value, reads,
+};
+use crate::FlareChainMirror;
+
+/// `getValidatorStake(bytes32)`
+pub(super) const GET_VALIDATOR_STAKE: Selector = [0xee, 0xae, 0x23, 0xd7];
+/// `isValidatorActive(bytes32)`
+pub(super) const IS_VALIDATOR_ACTIVE: Selector = [0x23, 0x24, 0xe5, 0xe1];
+/// `getTotalStaked()`
+pub(super) const GET_TOTAL_STAKED: Selector = [0x09, 0x17, 0xe7, 0x76];
+/// `getValidatorCount()`
+pub(super) const GET_VALIDATOR_COUNT: Selector = [0x70, 0x71, 0x68, 0x8a];
+
+/// `IEtridStaking`
+///
+/// Unknown validators have no stake and are inactive.
+pub struct EtridStakingPrecompile;
+
+impl Precompile for EtridStakingPrecompile {
+	fn execute(handle: &mut impl PrecompileHandle) -> PrecompileResult {
+		let input = handle.input().to_vec();
+		let (selector, args) = Reader::new(&input)?;
+		ensure_no_value(handle)?;
+		charge(handle, reads(1))?;
+
+		let output = match selector {
+			GET_VALIDATOR_STAKE => {
+				let stake = FlareChainMirror::validator(&args.bytes32(0)?).map_or(0, |v| v.stake);
+				abi::uint256(stake)
+			},
+			IS_VALIDATOR_ACTIVE => {
+				let active =
+					FlareChainMirror::validator(&args.bytes32(0)?).is_some_and(|v| v.active);
+				abi::bool(active)
+			},
+			GET_TOTAL_STAKED => abi::uint256(FlareChainMirror::total_staked()),
+			GET_VALIDATOR_COUNT => abi::uint256(FlareChainMirror::validator_count()),
+			_ => return Err(abi::revert("Unknown function selector")),
+		};
+		Ok(abi::returned(output))
+	}
+}
@@ -0,0 +1,22 @@
+#!/bin/bash
+# Replace the conformance vectors with go-ethereum's full precompile test vectors
+#
+# Usage:
+#   ./testdata/fetch-geth-vectors.sh            # pinned go-ethereum release
+#   GETH_REF=master ./testdata/fetch-geth-vectors.sh
+#
+# Commit the fetched files; `cargo test -p eth-pbc-runtime precompiles` then
+# checks the BN254 and Blake2F precompiles against every geth vector.
+
+set -e
+
+TESTDATA="$(cd "$(dirname "$0")" && pwd)"
+GETH_REF="${GETH_REF:-v1.14.11}"
+BASE_URL="https://raw.githubusercontent.com/ethereum/go-ethereum/$GETH_REF/core/vm/testdata/precompiles"
+
+for vectors in bn256Add bn256ScalarMul bn256Pairing blake2F; do
+    echo "⬇️  $vectors.json ($GETH_REF)"
+    curl -fsSL "$BASE_URL/$vectors.json" -o "$TESTDATA/$vectors.json"
+done
+
+echo "✅ Vectors updated from go-ethereum $GETH_REF"
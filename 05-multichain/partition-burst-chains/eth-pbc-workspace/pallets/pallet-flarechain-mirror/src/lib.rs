@@ -0,0 +1,380 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+//! # FlareChain Mirror Pallet
+//!
+//! ETH-PBC view of FlareChain oracle, governance and staking state, read by the
+//! Ëtrid precompiles (0x800 oracle, 0x801 governance, 0x802 staking).
+//!
+//! The FlareChain pallets run on the relay chain, so EVM contracts cannot call
+//! them synchronously. A relayer (`RelayerOrigin`) posts oracle prices, proposal
+//! statuses and validator stakes here. Governance proposals and votes cast from
+//! EVM contracts are queued in an outbox that the relayer forwards to FlareChain
+//! and then acknowledges.
+
+pub use pallet::*;
+
+#[cfg(test)]
+mod tests;
+
+#[frame_support::pallet]
+pub mod pallet {
+    use codec::{Decode, DecodeWithMemTracking, Encode};
+    use frame_support::{pallet_prelude::*, weights::constants::RocksDbWeight};
+    use frame_system::pallet_prelude::*;
+    use scale_info::TypeInfo;
+    use sp_core::H160;
+    use sp_std::vec::Vec;
+
+    /// Asset or validator identifier, right-padded ASCII or raw 32 bytes
+    pub type Symbol = [u8; 32];
+
+    /// Oracle price of a symbol in a quote currency
+    #[derive(
+        Encode, Decode, DecodeWithMemTracking, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen,
+    )]
+    pub struct PriceData {
+        /// Price scaled by 1e18
+        pub price: u128,
+        /// FlareChain update time (unix seconds)
+        pub updated_at: u64,
+    }
+
+    /// FlareChain governance proposal status (same order as `IEtridGovernance.ProposalStatus`)
+    #[derive(
+        Encode, Decode, DecodeWithMemTracking, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen,
+    )]
+    pub enum ProposalStatus {
+        Pending = 0,
+        Active = 1,
+        Passed = 2,
+        Failed = 3,
+    }
+
+    /// FlareChain validator stake
+    #[derive(
+        Encode, Decode, DecodeWithMemTracking, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen,
+    )]
+    pub struct ValidatorInfo {
+        pub stake: u128,
+        pub active: bool,
+    }
+
+    /// Governance action from an EVM contract awaiting relay to FlareChain
+    #[derive(
+        Encode, Decode, CloneNoBound, PartialEqNoBound, EqNoBound, RuntimeDebugNoBound, TypeInfo, MaxEncodedLen,
+    )]
+    #[scale_info(skip_type_params(T))]
+    #[codec(mel_bound())]
+    pub enum OutboundAction<T: Config> {
+        Propose {
+            proposer: H160,
+            title: BoundedVec<u8, T::MaxTitleLength>,
+            description: BoundedVec<u8, T::MaxDescriptionLength>,
+        },
+        Vote {
+            voter: H160,
+            proposal_id: u64,
+            support: bool,
+        },
+    }
+
+    #[pallet::pallet]
+    pub struct Pallet<T>(_);
+
+    #[pallet::config]
+    pub trait Config: frame_system::Config {
+        type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+        /// Origin allowed to post FlareChain state and acknowledge relayed actions
+        type RelayerOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+        /// Maximum proposal title length (bytes)
+        #[pallet::constant]
+        type MaxTitleLength: Get<u32>;
+
+        /// Maximum proposal description length (bytes)
+        #[pallet::constant]
+        type MaxDescriptionLength: Get<u32>;
+
+        /// Maximum actions waiting in the outbox
+        #[pallet::constant]
+        type MaxPendingActions: Get<u32>;
+
+        /// Maximum entries per relayer update
+        #[pallet::constant]
+        type MaxBatchSize: Get<u32>;
+
+        type WeightInfo: WeightInfo;
+    }
+
+    pub trait WeightInfo {
+        fn set_prices(n: u32) -> Weight;
+        fn set_proposal_status() -> Weight;
+        fn set_validators(n: u32) -> Weight;
+        fn acknowledge_actions(n: u32) -> Weight;
+        fn submit_proposal() -> Weight;
+        fn vote() -> Weight;
+    }
+
+    impl WeightInfo for () {
+        fn set_prices(n: u32) -> Weight {
+            Weight::from_parts(10_000_000, 0).saturating_add(RocksDbWeight::get().reads_writes(n as u64, 2 * n as u64))
+        }
+        fn set_proposal_status() -> Weight {
+            Weight::from_parts(10_000_000, 0).saturating_add(RocksDbWeight::get().writes(1))
+        }
+        fn set_validators(n: u32) -> Weight {
+            Weight::from_parts(10_000_000, 0)
+                .saturating_add(RocksDbWeight::get().reads_writes(n as u64 + 1, n as u64 + 1))
+        }
+        fn acknowledge_actions(n: u32) -> Weight {
+            Weight::from_parts(10_000_000, 0).saturating_add(RocksDbWeight::get().writes(n as u64))
+        }
+        fn submit_proposal() -> Weight {
+            Weight::from_parts(20_000_000, 0).saturating_add(RocksDbWeight::get().reads_writes(2, 3))
+        }
+        fn vote() -> Weight {
+            Weight::from_parts(20_000_000, 0).saturating_add(RocksDbWeight::get().reads_writes(3, 3))
+        }
+    }
+
+    /// Oracle prices by (symbol, quote currency)
+    #[pallet::storage]
+    pub type Prices<T: Config> =
+        StorageDoubleMap<_, Blake2_128Concat, Symbol, Blake2_128Concat, Symbol, PriceData, OptionQuery>;
+
+    /// Latest `updated_at` of any feed per symbol
+    #[pallet::storage]
+    pub type LastUpdated<T: Config> = StorageMap<_, Blake2_128Concat, Symbol, u64, OptionQuery>;
+
+    /// FlareChain proposal statuses by proposal ID
+    #[pallet::storage]
+    pub type ProposalStatuses<T: Config> = StorageMap<_, Twox64Concat, u64, ProposalStatus, OptionQuery>;
+
+    /// FlareChain validators by ID
+    #[pallet::storage]
+    pub type Validators<T: Config> = CountedStorageMap<_, Blake2_128Concat, Symbol, ValidatorInfo, OptionQuery>;
+
+    /// Sum of mirrored validator stakes
+    #[pallet::storage]
+    pub type TotalStaked<T: Config> = StorageValue<_, u128, ValueQuery>;
+
+    /// Governance actions awaiting relay, by outbox nonce
+    #[pallet::storage]
+    pub type Outbox<T: Config> = CountedStorageMap<_, Twox64Concat, u64, OutboundAction<T>, OptionQuery>;
+
+    /// Nonce of the next outbox action
+    #[pallet::storage]
+    pub type NextOutboxNonce<T: Config> = StorageValue<_, u64, ValueQuery>;
+
+    /// Voters per proposal, so each EVM account votes once
+    #[pallet::storage]
+    pub type Votes<T: Config> = StorageDoubleMap<_, Twox64Concat, u64, Blake2_128Concat, H160, bool, OptionQuery>;
+
+    #[pallet::event]
+    #[pallet::generate_deposit(pub(super) fn deposit_event)]
+    pub enum Event<T: Config> {
+        /// Oracle prices updated
+        PricesUpdated { count: u32 },
+        /// Proposal status updated
+        ProposalStatusUpdated { proposal_id: u64, status: ProposalStatus },
+        /// Validator stakes updated
+        ValidatorsUpdated { count: u32, total_staked: u128 },
+        /// Proposal queued for FlareChain
+        ProposalQueued { nonce: u64, proposer: H160 },
+        /// Vote queued for FlareChain
+        VoteQueued {
+            nonce: u64,
+            voter: H160,
+            proposal_id: u64,
+            support: bool,
+        },
+        /// Outbox actions relayed
+        ActionsAcknowledged { count: u32 },
+    }
+
+    #[pallet::error]
+    pub enum Error<T> {
+        /// Proposal title exceeds `MaxTitleLength`
+        TitleTooLong,
+        /// Proposal description exceeds `MaxDescriptionLength`
+        DescriptionTooLong,
+        /// Title must not be empty
+        EmptyTitle,
+        /// Outbox holds `MaxPendingActions` actions
+        OutboxFull,
+        /// Proposal unknown to the mirror
+        ProposalNotFound,
+        /// Proposal is not open for voting
+        ProposalNotActive,
+        /// Account already voted on this proposal
+        AlreadyVoted,
+    }
+
+    #[pallet::call]
+    impl<T: Config> Pallet<T> {
+        /// Post FlareChain oracle prices
+        #[pallet::call_index(0)]
+        #[pallet::weight(T::WeightInfo::set_prices(prices.len() as u32))]
+        pub fn set_prices(
+            origin: OriginFor<T>,
+            prices: BoundedVec<(Symbol, Symbol, PriceData), T::MaxBatchSize>,
+        ) -> DispatchResult {
+            T::RelayerOrigin::ensure_origin(origin)?;
+
+            let count = prices.len() as u32;
+            for (symbol, quote, data) in prices {
+                LastUpdated::<T>::mutate(symbol, |last| {
+                    *last = Some(last.map_or(data.updated_at, |t| t.max(data.updated_at)))
+                });
+                Prices::<T>::insert(symbol, quote, data);
+            }
+
+            Self::deposit_event(Event::PricesUpdated { count });
+            Ok(())
+        }
+
+        /// Post a FlareChain proposal status
+        #[pallet::call_index(1)]
+        #[pallet::weight(T::WeightInfo::set_proposal_status())]
+        pub fn set_proposal_status(origin: OriginFor<T>, proposal_id: u64, status: ProposalStatus) -> DispatchResult {
+            T::RelayerOrigin::ensure_origin(origin)?;
+
+            ProposalStatuses::<T>::insert(proposal_id, status);
+
+            Self::deposit_event(Event::ProposalStatusUpdated { proposal_id, status });
+            Ok(())
+        }
+
+        /// Post FlareChain validator stakes
+        ///
+        /// A validator with no stake that is not active is dropped from the mirror.
+        #[pallet::call_index(2)]
+        #[pallet::weight(T::WeightInfo::set_validators(validators.len() as u32))]
+        pub fn set_validators(
+            origin: OriginFor<T>,
+            validators: BoundedVec<(Symbol, ValidatorInfo), T::MaxBatchSize>,
+        ) -> DispatchResult {
+            T::RelayerOrigin::ensure_origin(origin)?;
+
+            let count = validators.len() as u32;
+            let mut total = TotalStaked::<T>::get();
+            for (id, info) in validators {
+                if let Some(old) = Validators::<T>::get(id) {
+                    total = total.saturating_sub(old.stake);
+                }
+                if info.stake == 0 && !info.active {
+                    Validators::<T>::remove(id);
+                } else {
+                    total = total.saturating_add(info.stake);
+                    Validators::<T>::insert(id, info);
+                }
+            }
+            TotalStaked::<T>::put(total);
+
+            Self::deposit_event(Event::ValidatorsUpdated {
+                count,
+                total_staked: total,
+            });
+            Ok(())
+        }
+
+        /// Remove relayed actions from the outbox
+        #[pallet::call_index(3)]
+        #[pallet::weight(T::WeightInfo::acknowledge_actions(nonces.len() as u32))]
+        pub fn acknowledge_actions(origin: OriginFor<T>, nonces: BoundedVec<u64, T::MaxBatchSize>) -> DispatchResult {
+            T::RelayerOrigin::ensure_origin(origin)?;
+
+            let count = nonces.len() as u32;
+            for nonce in nonces {
+                Outbox::<T>::remove(nonce);
+            }
+
+            Self::deposit_event(Event::ActionsAcknowledged { count });
+            Ok(())
+        }
+    }
+
+    impl<T: Config> Pallet<T> {
+        /// Price of `symbol` in `quote`
+        pub fn price(symbol: &Symbol, quote: &Symbol) -> Option<PriceData> {
+            Prices::<T>::get(symbol, quote)
+        }
+
+        /// Latest update time of any price feed for `symbol`
+        pub fn last_update(symbol: &Symbol) -> Option<u64> {
+            LastUpdated::<T>::get(symbol)
+        }
+
+        /// Mirrored status of a FlareChain proposal
+        pub fn proposal_status(proposal_id: u64) -> Option<ProposalStatus> {
+            ProposalStatuses::<T>::get(proposal_id)
+        }
+
+        /// Mirrored stake of a validator
+        pub fn validator(id: &Symbol) -> Option<ValidatorInfo> {
+            Validators::<T>::get(id)
+        }
+
+        /// Sum of mirrored validator stakes
+        pub fn total_staked() -> u128 {
+            TotalStaked::<T>::get()
+        }
+
+        /// Number of mirrored validators
+        pub fn validator_count() -> u32 {
+            Validators::<T>::count()
+        }
+
+        /// Queue a proposal from an EVM account; returns its outbox nonce
+        pub fn submit_proposal(proposer: H160, title: Vec<u8>, description: Vec<u8>) -> Result<u64, DispatchError> {
+            ensure!(!title.is_empty(), Error::<T>::EmptyTitle);
+            let title: BoundedVec<_, T::MaxTitleLength> = title.try_into().map_err(|_| Error::<T>::TitleTooLong)?;
+            let description: BoundedVec<_, T::MaxDescriptionLength> =
+                description.try_into().map_err(|_| Error::<T>::DescriptionTooLong)?;
+
+            let nonce = Self::queue(OutboundAction::Propose {
+                proposer,
+                title,
+                description,
+            })?;
+
+            Self::deposit_event(Event::ProposalQueued { nonce, proposer });
+            Ok(nonce)
+        }
+
+        /// Queue a vote from an EVM account on an active proposal; returns its outbox nonce
+        pub fn vote(voter: H160, proposal_id: u64, support: bool) -> Result<u64, DispatchError> {
+            let status = ProposalStatuses::<T>::get(proposal_id).ok_or(Error::<T>::ProposalNotFound)?;
+            ensure!(status == ProposalStatus::Active, Error::<T>::ProposalNotActive);
+            ensure!(!Votes::<T>::contains_key(proposal_id, voter), Error::<T>::AlreadyVoted);
+
+            let nonce = Self::queue(OutboundAction::Vote {
+                voter,
+                proposal_id,
+                support,
+            })?;
+            Votes::<T>::insert(proposal_id, voter, support);
+
+            Self::deposit_event(Event::VoteQueued {
+                nonce,
+                voter,
+                proposal_id,
+                support,
+            });
+            Ok(nonce)
+        }
+
+        fn queue(action: OutboundAction<T>) -> Result<u64, DispatchError> {
+            ensure!(
+                Outbox::<T>::count() < T::MaxPendingActions::get(),
+                Error::<T>::OutboxFull
+            );
+
+            let nonce = NextOutboxNonce::<T>::get();
+            Outbox::<T>::insert(nonce, action);
+            NextOutboxNonce::<T>::put(nonce.saturating_add(1));
+            Ok(nonce)
+        }
+    }
+}
@@ -0,0 +1,190 @@
+use crate::{self as pallet_flarechain_mirror, *};
+use frame_support::{assert_noop, assert_ok, derive_impl, parameter_types, traits::ConstU32, BoundedVec};
+use frame_system::EnsureRoot;
+use sp_core::H160;
+use sp_runtime::{BuildStorage, DispatchError};
+
+type Block = frame_system::mocking::MockBlock<Test>;
+
+frame_support::construct_runtime!(
+    pub enum Test
+    {
+        System: frame_system,
+        Mirror: pallet_flarechain_mirror,
+    }
+);
+
+#[derive_impl(frame_system::config_preludes::TestDefaultConfig)]
+impl frame_system::Config for Test {
+    type Block = Block;
+}
+
+parameter_types! {
+    pub const MaxPendingActions: u32 = 2;
+}
+
+impl pallet_flarechain_mirror::Config for Test {
+    type RuntimeEvent = RuntimeEvent;
+    type RelayerOrigin = EnsureRoot<u64>;
+    type MaxTitleLength = ConstU32<16>;
+    type MaxDescriptionLength = ConstU32<64>;
+    type MaxPendingActions = MaxPendingActions;
+    type MaxBatchSize = ConstU32<8>;
+    type WeightInfo = ();
+}
+
+fn new_test_ext() -> sp_io::TestExternalities {
+    let storage = frame_system::GenesisConfig::<Test>::default().build_storage().unwrap();
+    let mut ext = sp_io::TestExternalities::new(storage);
+    ext.execute_with(|| System::set_block_number(1));
+    ext
+}
+
+fn symbol(s: &str) -> Symbol {
+    let mut out = [0u8; 32];
+    out[..s.len()].copy_from_slice(s.as_bytes());
+    out
+}
+
+fn evm(n: u64) -> H160 {
+    H160::from_low_u64_be(n)
+}
+
+#[test]
+fn relayer_posts_prices() {
+    new_test_ext().execute_with(|| {
+        let prices = BoundedVec::truncate_from(vec![
+            (
+                symbol("BTC"),
+                symbol("USD"),
+                PriceData {
+                    price: 50_000,
+                    updated_at: 100,
+                },
+            ),
+            (
+                symbol("BTC"),
+                symbol("ETH"),
+                PriceData {
+                    price: 16,
+                    updated_at: 120,
+                },
+            ),
+        ]);
+        assert_noop!(
+            Mirror::set_prices(RuntimeOrigin::signed(1), prices.clone()),
+            DispatchError::BadOrigin
+        );
+        assert_ok!(Mirror::set_prices(RuntimeOrigin::root(), prices));
+
+        assert_eq!(Mirror::price(&symbol("BTC"), &symbol("USD")).unwrap().price, 50_000);
+        assert_eq!(Mirror::price(&symbol("SOL"), &symbol("USD")), None);
+        assert_eq!(Mirror::last_update(&symbol("BTC")), Some(120));
+        assert_eq!(Mirror::last_update(&symbol("SOL")), None);
+    });
+}
+
+#[test]
+fn validator_totals_follow_updates() {
+    new_test_ext().execute_with(|| {
+        let update = |entries: Vec<(Symbol, ValidatorInfo)>| {
+            assert_ok!(Mirror::set_validators(
+                RuntimeOrigin::root(),
+                BoundedVec::truncate_from(entries)
+            ));
+        };
+
+        update(vec![
+            (
+                symbol("v1"),
+                ValidatorInfo {
+                    stake: 100,
+                    active: true,
+                },
+            ),
+            (
+                symbol("v2"),
+                ValidatorInfo {
+                    stake: 50,
+                    active: false,
+                },
+            ),
+        ]);
+        assert_eq!(Mirror::total_staked(), 150);
+        assert_eq!(Mirror::validator_count(), 2);
+
+        // Restake replaces, empty and inactive drops
+        update(vec![
+            (
+                symbol("v1"),
+                ValidatorInfo {
+                    stake: 70,
+                    active: true,
+                },
+            ),
+            (
+                symbol("v2"),
+                ValidatorInfo {
+                    stake: 0,
+                    active: false,
+                },
+            ),
+        ]);
+        assert_eq!(Mirror::total_staked(), 70);
+        assert_eq!(Mirror::validator_count(), 1);
+        assert_eq!(Mirror::validator(&symbol("v2")), None);
+    });
+}
+
+#[test]
+fn evm_proposals_and_votes_are_queued() {
+    new_test_ext().execute_with(|| {
+        assert_eq!(
+            Mirror::submit_proposal(evm(1), b"Raise cap".to_vec(), b"details".to_vec()),
+            Ok(0)
+        );
+        assert!(matches!(Outbox::<Test>::get(0), Some(OutboundAction::Propose { proposer, .. }) if proposer == evm(1)));
+
+        assert_noop!(Mirror::vote(evm(2), 7, true), Error::<Test>::ProposalNotFound);
+        assert_ok!(Mirror::set_proposal_status(
+            RuntimeOrigin::root(),
+            7,
+            ProposalStatus::Pending
+        ));
+        assert_noop!(Mirror::vote(evm(2), 7, true), Error::<Test>::ProposalNotActive);
+
+        assert_ok!(Mirror::set_proposal_status(
+            RuntimeOrigin::root(),
+            7,
+            ProposalStatus::Active
+        ));
+        assert_eq!(Mirror::vote(evm(2), 7, true), Ok(1));
+        assert_noop!(Mirror::vote(evm(2), 7, false), Error::<Test>::AlreadyVoted);
+
+        // Outbox is bounded until the relayer acknowledges
+        assert_noop!(Mirror::vote(evm(3), 7, false), Error::<Test>::OutboxFull);
+        assert_ok!(Mirror::acknowledge_actions(
+            RuntimeOrigin::root(),
+            BoundedVec::truncate_from(vec![0, 1])
+        ));
+        assert_eq!(Mirror::vote(evm(3), 7, false), Ok(2));
+    });
+}
+
+#[test]
+fn proposal_bounds_are_enforced() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            Mirror::submit_proposal(evm(1), vec![], vec![]),
+            Error::<Test>::EmptyTitle
+        );
+        assert_noop!(
+            Mirror::submit_proposal(evm(1), vec![b'a'; 17], vec![]),
+            Error::<Test>::TitleTooLong
+        );
+        assert_noop!(
+            Mirror::submit_proposal(evm(1), b"ok".to_vec(), vec![b'a'; 65]),
+            Error::<Test>::DescriptionTooLong
+        );
+    });
+}
@@ -323,6 +323,7 @@ impl pallet_etr_lock::Config for Runtime {
     type MaxLockAmount = MaxLockAmount;
     type BridgeOrigin = frame_system::EnsureRoot<AccountId>;
     type LockIdentifier = EtrLockId;
+    type WeightInfo = ();
 }
 
 
@@ -341,6 +342,7 @@ impl pallet_cardano_bridge::Config for Runtime {
     type MinDepositAmount = MinAdaDepositAmount;
     type MaxDepositAmount = MaxAdaDepositAmount;
     type BridgeAuthority = BridgeAuthorityAccount;
+    type WeightInfo = ();
 }
 
 
@@ -303,6 +303,7 @@ impl pallet_etr_lock::Config for Runtime {
     type MaxLockAmount = MaxLockAmount;
     type BridgeOrigin = frame_system::EnsureRoot<AccountId>;
     type LockIdentifier = EtrLockId;
+    type WeightInfo = ();
 }
 
 
@@ -319,6 +320,7 @@ impl pallet_bnb_bridge::Config for Runtime {
     type MaxGasPrice = MaxBnbGasPrice;
     type MaxDepositsPerAccount = MaxBnbDepositsPerAccount;
     type MaxWithdrawalsPerAccount = MaxBnbWithdrawalsPerAccount;
+    type WeightInfo = ();
 }
 
 
@@ -294,6 +294,7 @@ impl pallet_etr_lock::Config for Runtime {
     type MaxLockAmount = MaxLockAmount;
     type BridgeOrigin = frame_system::EnsureRoot<AccountId>;
     type LockIdentifier = EtrLockId;
+    type WeightInfo = ();
 }
 
 // Bitcoin Bridge Configuration
@@ -321,6 +322,7 @@ impl pallet_bitcoin_bridge::Config for Runtime {
     type BridgeAuthority = BridgeAuthorityAccount;
     type Treasury = TreasuryStub;
     type ValidatorPoolAccount = BridgeAuthorityAccount;
+    type WeightInfo = ();
 }
 
 // Lightning Channels Configuration
@@ -306,6 +306,7 @@ impl pallet_etr_lock::Config for Runtime {
     type MaxLockAmount = MaxLockAmount;
     type BridgeOrigin = frame_system::EnsureRoot<AccountId>;
     type LockIdentifier = EtrLockId;
+    type WeightInfo = ();
 }
 
 
@@ -322,6 +323,7 @@ impl pallet_doge_bridge::Config for Runtime {
     type PalletId = DogeBridgePalletId;
     type DogeConfirmations = DogeConfirmations;
     type DogeConversionRate = DogeConversionRate;
+    type WeightInfo = ();
 }
 
 
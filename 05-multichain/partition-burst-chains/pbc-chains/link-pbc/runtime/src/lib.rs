@@ -303,6 +303,7 @@ impl pallet_etr_lock::Config for Runtime {
     type MaxLockAmount = MaxLockAmount;
     type BridgeOrigin = frame_system::EnsureRoot<AccountId>;
     type LockIdentifier = EtrLockId;
+    type WeightInfo = ();
 }
 
 
@@ -328,6 +329,7 @@ impl pallet_chainlink_bridge::Config for Runtime {
     type MaxDataFeeds = MaxLinkDataFeeds;
     type MaxVRFRequests = MaxLinkVRFRequests;
     type PriceStalenessThreshold = LinkPriceStalenessThreshold;
+    type WeightInfo = ();
 }
 
 
@@ -304,6 +304,7 @@ impl pallet_etr_lock::Config for Runtime {
     type RuntimeEvent = RuntimeEvent;
     type Currency = Balances;    type MaxLockAmount = MaxLockAmount;    type BridgeOrigin = frame_system::EnsureRoot<AccountId>;
     type LockIdentifier = EtrLockId;
+    type WeightInfo = ();
 }
 
 
@@ -320,7 +321,9 @@ impl pallet_polygon_bridge::Config for Runtime {
     type MinBridgeAmount = MinMaticBridgeAmount;
     type MaxDepositsPerAccount = MaxMaticDepositsPerAccount;
     type MaxWithdrawalsPerAccount = MaxMaticWithdrawalsPerAccount;
-    type PalletId = MaticBridgePalletId;}
+    type PalletId = MaticBridgePalletId;
+    type WeightInfo = ();
+}
 
 
 // Lightning Channels Configuration
@@ -301,6 +301,7 @@ impl pallet_etr_lock::Config for Runtime {
     type MaxLockAmount = MaxLockAmount;
     type BridgeOrigin = frame_system::EnsureRoot<AccountId>;
     type LockIdentifier = EtrLockId;
+    type WeightInfo = ();
 }
 
 
@@ -314,7 +315,9 @@ impl pallet_stablecoin_usdt_bridge::Config for Runtime {
     type BridgeFeeRate = UsdtBridgeFeeRate;
     type MaxDepositsPerAccount = MaxUsdtDepositsPerAccount;
     type MaxWithdrawalsPerAccount = MaxUsdtWithdrawalsPerAccount;
-    type MaxCustodians = MaxUsdtCustodians;}
+    type MaxCustodians = MaxUsdtCustodians;
+    type WeightInfo = ();
+}
 
 
 // Lightning Channels Configuration
@@ -301,6 +301,7 @@ impl pallet_etr_lock::Config for Runtime {
     type RuntimeEvent = RuntimeEvent;
     type Currency = Balances;    type MaxLockAmount = MaxLockAmount;    type BridgeOrigin = frame_system::EnsureRoot<AccountId>;
     type LockIdentifier = EtrLockId;
+    type WeightInfo = ();
 }
 // Treasury stub for bridge fee collection
 pub struct TreasuryStub;
@@ -327,6 +328,7 @@ impl pallet_solana_bridge::Config for Runtime {
 
     type Treasury = TreasuryStub;
     type ValidatorPoolAccount = BridgeAuthorityAccount;
+    type WeightInfo = ();
 }
 
 
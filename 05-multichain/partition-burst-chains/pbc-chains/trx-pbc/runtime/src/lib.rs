@@ -303,6 +303,7 @@ impl pallet_etr_lock::Config for Runtime {
     type MaxLockAmount = MaxLockAmount;
     type BridgeOrigin = frame_system::EnsureRoot<AccountId>;
     type LockIdentifier = EtrLockId;
+    type WeightInfo = ();
 }
 
 
@@ -328,6 +329,7 @@ impl pallet_tron_bridge::Config for Runtime {
     type MaxBandwidth = MaxTrxBandwidth;
     type MaxDepositsPerAccount = MaxTrxDepositsPerAccount;
     type MaxWithdrawalsPerAccount = MaxTrxWithdrawalsPerAccount;
+    type WeightInfo = ();
 }
 
 
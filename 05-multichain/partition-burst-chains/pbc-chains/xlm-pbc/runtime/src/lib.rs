@@ -301,6 +301,7 @@ impl pallet_etr_lock::Config for Runtime {
     type MaxLockAmount = MaxLockAmount;
     type BridgeOrigin = frame_system::EnsureRoot<AccountId>;
     type LockIdentifier = EtrLockId;
+    type WeightInfo = ();
 }
 
 
@@ -324,6 +325,7 @@ impl pallet_stellar_bridge::Config for Runtime {
     type BridgeFeeRate = XlmBridgeFeeRate;
     type MaxDepositsPerAccount = MaxXlmDepositsPerAccount;
     type MaxWithdrawalsPerAccount = MaxXlmWithdrawalsPerAccount;
+    type WeightInfo = ();
 }
 
 
@@ -300,6 +300,7 @@ impl pallet_etr_lock::Config for Runtime {
     type RuntimeEvent = RuntimeEvent;
     type Currency = Balances;    type MaxLockAmount = MaxLockAmount;    type BridgeOrigin = frame_system::EnsureRoot<AccountId>;
     type LockIdentifier = EtrLockId;
+    type WeightInfo = ();
 }
 
 
@@ -314,7 +315,9 @@ impl pallet_xrp_bridge::Config for Runtime {
     type BridgeFeeRate = XrpBridgeFeeRate;
     type MaxFeeDrops = MaxXrpFeeDrops;
     type MaxDepositsPerAccount = MaxXrpDepositsPerAccount;
-    type MaxWithdrawalsPerAccount = MaxXrpWithdrawalsPerAccount;}
+    type MaxWithdrawalsPerAccount = MaxXrpWithdrawalsPerAccount;
+    type WeightInfo = ();
+}
 
 
 // Lightning Channels Configuration
@@ -183,6 +183,52 @@ impl pallet_accounts::Config for Runtime {
     type RuntimeEvent = RuntimeEvent;
     type Balance = u64;
     type GovernanceOrigin = frame_system::EnsureRoot<AccountId>;
+    type Dids = (); // No DID registry on PBCs
+    type RecoveryDeposit = ConstU64<1_000_000_000_000_000_000>; // 1 ETR
+    type MinRecoveryDelay = ConstU32<{ DAYS }>; // Owner gets at least a day to veto
+    type WeightInfo = pallet_accounts::weights::SubstrateWeight<Runtime>;
+}
+
+parameter_types! {
+    /// FlareChain authority set whose finality proofs relayed heads must carry.
+    /// Set through governance (`set_storage`) on every authority set change.
+    pub storage FlareChainAuthorities: asf_finality_proof::AuthoritySetInfo =
+        asf_finality_proof::AuthoritySetInfo {
+            set_id: 0,
+            chain_id: [0; 32],
+            authorities: Vec::new(),
+        };
+    /// Name of the bridge pallet in the FlareChain runtime
+    pub const FlareChainBridgePallet: &'static str = "Bridge";
+    /// Bridge chain ID of this PBC, set at genesis
+    pub storage BridgeChainId: u8 = 1;
+}
+
+/// Signs benchmark heads with a fresh FlareChain authority set
+#[cfg(feature = "runtime-benchmarks")]
+pub struct BridgeBenchmarkHelper;
+#[cfg(feature = "runtime-benchmarks")]
+impl pallet_bridge::BenchmarkHelper for BridgeBenchmarkHelper {
+    fn head_proof(head: &etrid_primitives::FinalizedPbcHead, padding: u32) -> Vec<u8> {
+        use codec::Encode;
+        use pallet_bridge::verifier::testing;
+
+        let authorities = testing::authority_set(21);
+        FlareChainAuthorities::set(&authorities);
+        testing::head_proof::<FlareChainBridgePallet>(&authorities, head, padding).encode()
+    }
+}
+
+impl pallet_bridge::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    type ChainId = BridgeChainId;
+    type HeadOrigin = frame_system::EnsureRoot<AccountId>;
+    type HeadVerifier =
+        pallet_bridge::FlareChainHeadVerifier<FlareChainAuthorities, FlareChainBridgePallet>;
+    type MessageDispatch = ();
+    type WeightInfo = pallet_bridge::weights::SubstrateWeight<Runtime>;
+    #[cfg(feature = "runtime-benchmarks")]
+    type BenchmarkHelper = BridgeBenchmarkHelper;
 }
 
 construct_runtime!(
@@ -195,6 +241,7 @@ construct_runtime!(
         TransactionPayment: pallet_transaction_payment,
         Sudo: pallet_sudo,
         Accounts: pallet_accounts,
+        Bridge: pallet_bridge,
     }
 );
 
@@ -228,6 +275,7 @@ mod benches {
         [frame_system, SystemBench::<Runtime>]
         [pallet_balances, Balances]
         [pallet_timestamp, Timestamp]
+        [pallet_bridge, Bridge]
     );
 }
 
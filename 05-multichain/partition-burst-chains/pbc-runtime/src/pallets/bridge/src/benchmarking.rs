@@ -0,0 +1,192 @@
+//! Benchmarking setup for pallet-bridge
+//!
+//! Proof-carrying calls are measured with proofs padded towards their size
+//! limits by a trie node the verifier has to hash but never reads. Relayed
+//! heads come from `Config::BenchmarkHelper`, so `relay_finalized_head` pays
+//! the runtime's real verification cost.
+
+#![cfg(feature = "runtime-benchmarks")]
+
+use super::*;
+use crate::verifier::testing;
+use etrid_primitives::{
+    merkle_proof, merkle_root, FinalizedPbcHead, Hash, MessageDeliveryProof, OutboundMessage,
+};
+use frame_benchmarking::v2::*;
+use frame_support::{pallet_prelude::*, traits::Hooks, BoundedVec};
+use frame_system::{pallet_prelude::BlockNumberFor, RawOrigin};
+use sp_runtime::traits::Hash as HashT;
+use sp_std::{vec, vec::Vec};
+
+const SOURCE_CHAIN: u8 = 1;
+
+fn max_payload() -> Vec<u8> {
+    vec![b'm'; MAX_MESSAGE_SIZE as usize]
+}
+
+fn source_head(block_number: u32, state_root: Hash) -> FinalizedPbcHead {
+    FinalizedPbcHead {
+        chain_id: SOURCE_CHAIN,
+        block_number,
+        block_hash: Hash::repeat_byte(block_number as u8),
+        state_root,
+    }
+}
+
+/// `count` messages from `from` to `to`
+fn messages<T: Config>(
+    from: u8,
+    to: u8,
+    count: u32,
+) -> BoundedVec<Message<T>, ConstU32<MAX_MESSAGES_PER_BLOCK>> {
+    let messages = (0..count)
+        .map(|nonce| Message::<T> {
+            from,
+            to,
+            block_number: 1,
+            nonce: nonce.into(),
+            data_hash: T::Hashing::hash(&nonce.encode()),
+        })
+        .collect::<Vec<_>>();
+    BoundedVec::truncate_from(messages)
+}
+
+#[benchmarks]
+mod benchmarks {
+    use super::*;
+
+    /// The block already holds all but one of its outgoing messages
+    #[benchmark]
+    fn send_message() {
+        let caller: T::AccountId = whitelisted_caller();
+        let block = frame_system::Pallet::<T>::block_number();
+        OutgoingMessages::<T>::insert(
+            block,
+            messages::<T>(T::ChainId::get(), SOURCE_CHAIN, MAX_MESSAGES_PER_BLOCK - 1),
+        );
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller), SOURCE_CHAIN, max_payload());
+
+        assert_eq!(OutboundNonces::<T>::get(SOURCE_CHAIN), 1);
+    }
+
+    /// A maximum-size payload, proven in a full source block, with `p` bytes of
+    /// root proof padding
+    #[benchmark]
+    fn receive_message(p: Linear<0, { MAX_ROOT_PROOF_SIZE - 1_024 }>) {
+        let caller: T::AccountId = whitelisted_caller();
+        let data = max_payload();
+        let source_block: u32 = 1;
+        let leaves: Vec<Hash> = (0..u64::from(MAX_MESSAGES_PER_BLOCK))
+            .map(|nonce| {
+                OutboundMessage {
+                    source: SOURCE_CHAIN,
+                    destination: T::ChainId::get(),
+                    nonce,
+                    payload_hash: T::Hashing::hash(&data),
+                }
+                .leaf_hash()
+            })
+            .collect();
+        let key =
+            OutboundMessageRoots::<T>::hashed_key_for(BlockNumberFor::<T>::from(source_block));
+        let (state_root, mut root_proof) =
+            testing::storage_proof(&[(key, merkle_root(&leaves).encode())]);
+        testing::pad(&mut root_proof, p);
+        let head = source_head(source_block, state_root);
+        FinalizedHeads::<T>::insert(SOURCE_CHAIN, source_block, head);
+
+        let block = frame_system::Pallet::<T>::block_number();
+        IncomingMessages::<T>::insert(
+            block,
+            messages::<T>(SOURCE_CHAIN, T::ChainId::get(), MAX_MESSAGES_PER_BLOCK - 1),
+        );
+        let proof = MessageDeliveryProof {
+            source_block,
+            finalized_block: source_block,
+            root_proof,
+            inclusion: merkle_proof(&leaves, 0).expect("leaf 0 exists"),
+        };
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller), SOURCE_CHAIN, 0, data, proof);
+
+        assert_eq!(InboundNonces::<T>::get(SOURCE_CHAIN), 1);
+    }
+
+    #[benchmark]
+    fn submit_state_root() {
+        #[extrinsic_call]
+        _(RawOrigin::Root);
+
+        assert!(LatestStateRoot::<T>::get().is_some());
+    }
+
+    #[benchmark]
+    fn get_message_data() {
+        let caller: T::AccountId = whitelisted_caller();
+        let data = max_payload();
+        let data_hash = T::Hashing::hash(&data);
+        MessageData::<T>::insert(data_hash, BoundedVec::truncate_from(data));
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller), data_hash);
+    }
+
+    #[benchmark]
+    fn clear_message_data() {
+        let data = max_payload();
+        let data_hash = T::Hashing::hash(&data);
+        MessageData::<T>::insert(data_hash, BoundedVec::truncate_from(data));
+
+        #[extrinsic_call]
+        _(RawOrigin::Root, data_hash);
+
+        assert!(!MessageData::<T>::contains_key(data_hash));
+    }
+
+    #[benchmark]
+    fn note_finalized_head() -> Result<(), BenchmarkError> {
+        let origin =
+            T::HeadOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+        LatestFinalizedBlock::<T>::insert(SOURCE_CHAIN, 1);
+        let head = source_head(2, Hash::repeat_byte(2));
+
+        #[extrinsic_call]
+        _(origin as T::RuntimeOrigin, head);
+
+        assert_eq!(FinalizedHeads::<T>::get(SOURCE_CHAIN, 2), Some(head));
+        Ok(())
+    }
+
+    /// A head proven with `p` bytes of proof padding
+    #[benchmark]
+    fn relay_finalized_head(p: Linear<0, { MAX_HEAD_PROOF_SIZE / 2 }>) {
+        let caller: T::AccountId = whitelisted_caller();
+        LatestFinalizedBlock::<T>::insert(SOURCE_CHAIN, 1);
+        let head = source_head(2, Hash::repeat_byte(2));
+        let proof = T::BenchmarkHelper::head_proof(&head, p);
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller), head, proof);
+
+        assert_eq!(FinalizedHeads::<T>::get(SOURCE_CHAIN, 2), Some(head));
+    }
+
+    /// `m` outgoing messages committed in `on_finalize`
+    #[benchmark]
+    fn commit_outbound_root(m: Linear<1, MAX_MESSAGES_PER_BLOCK>) {
+        let block = frame_system::Pallet::<T>::block_number();
+        OutgoingMessages::<T>::insert(block, messages::<T>(T::ChainId::get(), SOURCE_CHAIN, m));
+
+        #[block]
+        {
+            Pallet::<T>::on_finalize(block);
+        }
+
+        assert!(OutboundMessageRoots::<T>::contains_key(block));
+    }
+
+    impl_benchmark_test_suite!(Pallet, crate::mock::new_test_ext(), crate::mock::Test);
+}
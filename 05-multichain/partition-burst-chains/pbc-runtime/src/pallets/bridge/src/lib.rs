@@ -11,13 +11,53 @@
 //! - Receiving messages from FlareChain to PBC
 //! - State root aggregation
 //! - Cross-chain transaction verification
+//!
+//! ## Trustless Message Passing
+//!
+//! Messages sent in a block are committed to an outbound Merkle root
+//! (`OutboundMessageRoots`), which lives in the PBC's state root. FlareChain
+//! records finalized PBC heads (`note_finalized_head`), and PBCs import those
+//! records with a FlareChain finality proof (`relay_finalized_head`).
+//! `receive_message` accepts a message from any relayer, but only with a
+//! `MessageDeliveryProof`: a storage proof of the source block's outbound root
+//! against the state root of a finalized head of the source chain, and a Merkle
+//! inclusion proof of the message under that root. Roots are never removed, so
+//! any later finalized head proves them and recorded heads may skip blocks.
+//! Only the next nonce of the (source, destination) channel is accepted, which
+//! gives ordered, exactly-once delivery. Delivered payloads are handed to
+//! `Config::MessageDispatch`.
+//!
+//! Every chain must run this pallet under the same name and block number type,
+//! as storage proofs are checked against this pallet's own key layout.
 
 pub use pallet::*;
 
+pub mod verifier;
+pub mod weights;
+#[cfg(feature = "runtime-benchmarks")]
+pub use verifier::BenchmarkHelper;
+pub use verifier::{DispatchMessage, FlareChainHeadProof, FlareChainHeadVerifier, VerifyFinalizedHead};
+pub use weights::WeightInfo;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
+/// Total size in bytes of the trie nodes in a storage proof
+pub fn proof_size(proof: &[Vec<u8>]) -> u32 {
+    proof.iter().fold(0u32, |size, node| size.saturating_add(node.len() as u32))
+}
+
 #[frame_support::pallet]
 pub mod pallet {
     use super::*;
-    use etrid_primitives::{BlockNumber as PrimitiveBlockNumber, ChainId};
+    use etrid_primitives::{
+        merkle_root, verify_merkle_proof, BlockNumber as PrimitiveBlockNumber, FinalizedPbcHead,
+        MessageDeliveryProof, MessageInclusionProof, OutboundMessage,
+    };
     use frame_support::{pallet_prelude::*, BoundedVec};
     use frame_system::pallet_prelude::*;
     use sp_runtime::traits::{Hash as HashT, Saturating};
@@ -29,11 +69,17 @@ pub mod pallet {
     /// Maximum messages per block
     pub const MAX_MESSAGES_PER_BLOCK: u32 = 100;
 
+    /// Maximum size of the outbound root storage proof in `receive_message` (16KB)
+    pub const MAX_ROOT_PROOF_SIZE: u32 = 16 * 1024;
+
+    /// Maximum size of the head proof in `relay_finalized_head` (64KB)
+    pub const MAX_HEAD_PROOF_SIZE: u32 = 64 * 1024;
+
     #[pallet::pallet]
     pub struct Pallet<T>(_);
 
     #[pallet::config]
-    pub trait Config: frame_system::Config {
+    pub trait Config: frame_system::Config<Hash = etrid_primitives::Hash> {
         /// The overarching event type.
         type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
 
@@ -41,8 +87,21 @@ pub mod pallet {
         #[pallet::constant]
         type ChainId: Get<u8>;
 
+        /// Origin allowed to record finalized PBC heads directly (FlareChain validators)
+        type HeadOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+        /// Verifies relayed PBC heads against FlareChain finality
+        type HeadVerifier: VerifyFinalizedHead;
+
+        /// Hands delivered message payloads to target pallets
+        type MessageDispatch: DispatchMessage;
+
         /// Weight information for extrinsics
         type WeightInfo: WeightInfo;
+
+        /// Builds head proofs accepted by `HeadVerifier` for benchmarks
+        #[cfg(feature = "runtime-benchmarks")]
+        type BenchmarkHelper: BenchmarkHelper;
     }
 
     /// Simplified cross-chain message (storage-compatible)
@@ -55,12 +114,24 @@ pub mod pallet {
         pub to: u8,
         /// Block number when sent
         pub block_number: u32,
-        /// Message nonce
+        /// Per-channel (from, to) message nonce
         pub nonce: u64,
         /// Message data hash
         pub data_hash: T::Hash,
     }
 
+    impl<T: Config> Message<T> {
+        /// Commitment of this message in the outbound message tree
+        pub fn outbound(&self) -> OutboundMessage {
+            OutboundMessage {
+                source: self.from,
+                destination: self.to,
+                nonce: self.nonce,
+                payload_hash: self.data_hash,
+            }
+        }
+    }
+
     /// State root information
     #[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
     #[scale_info(skip_type_params(T))]
@@ -113,10 +184,47 @@ pub mod pallet {
     #[pallet::getter(fn latest_state_root)]
     pub type LatestStateRoot<T: Config> = StorageValue<_, StateRoot<T>, OptionQuery>;
 
-    /// Message nonce for ordering
+    /// Next outbound nonce per destination chain
+    #[pallet::storage]
+    #[pallet::getter(fn outbound_nonce)]
+    pub type OutboundNonces<T: Config> = StorageMap<_, Blake2_128Concat, u8, u64, ValueQuery>;
+
+    /// Next expected inbound nonce per source chain
+    #[pallet::storage]
+    #[pallet::getter(fn inbound_nonce)]
+    pub type InboundNonces<T: Config> = StorageMap<_, Blake2_128Concat, u8, u64, ValueQuery>;
+
+    /// Merkle root of the messages sent in each block (part of the state root)
+    ///
+    /// Receiving chains read these through storage proofs, so entries are kept
+    /// and the key layout must stay stable.
+    #[pallet::storage]
+    #[pallet::getter(fn outbound_message_root)]
+    pub type OutboundMessageRoots<T: Config> =
+        StorageMap<_, Blake2_128Concat, BlockNumberFor<T>, T::Hash, OptionQuery>;
+
+    /// Finalized PBC heads by (chain ID, block number)
+    ///
+    /// On FlareChain these are recorded by `HeadOrigin`; on PBCs they are imported
+    /// from FlareChain with a finality proof. The key layout must stay stable:
+    /// `FlareChainHeadVerifier` rebuilds it to check storage proofs.
+    #[pallet::storage]
+    #[pallet::getter(fn finalized_head)]
+    pub type FinalizedHeads<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        u8,
+        Twox64Concat,
+        PrimitiveBlockNumber,
+        FinalizedPbcHead,
+        OptionQuery,
+    >;
+
+    /// Highest finalized block number recorded per chain
     #[pallet::storage]
-    #[pallet::getter(fn message_nonce)]
-    pub type MessageNonce<T: Config> = StorageValue<_, u64, ValueQuery>;
+    #[pallet::getter(fn latest_finalized_block)]
+    pub type LatestFinalizedBlock<T: Config> =
+        StorageMap<_, Blake2_128Concat, u8, PrimitiveBlockNumber, OptionQuery>;
 
     #[pallet::event]
     #[pallet::generate_deposit(pub(super) fn deposit_event)]
@@ -133,6 +241,24 @@ pub mod pallet {
             nonce: u64,
             data_hash: T::Hash,
         },
+        /// Received message handed to the dispatch hook
+        MessageDispatched {
+            from: u8,
+            nonce: u64,
+            result: DispatchResult,
+        },
+        /// Outbound message root committed for a block
+        OutboundRootCommitted {
+            block_number: BlockNumberFor<T>,
+            message_root: T::Hash,
+            message_count: u32,
+        },
+        /// Finalized PBC head recorded
+        FinalizedHeadRecorded {
+            chain_id: u8,
+            block_number: PrimitiveBlockNumber,
+            state_root: T::Hash,
+        },
         /// State root aggregated to FlareChain
         StateRootAggregated {
             block_number: BlockNumberFor<T>,
@@ -159,6 +285,47 @@ pub mod pallet {
         TooManyMessages,
         /// Message data not found
         MessageDataNotFound,
+        /// No finalized head recorded for the proving block
+        UnknownFinalizedHead,
+        /// Source block is after the finalized head proving it
+        SourceBlockNotFinalized,
+        /// Storage proof does not prove an outbound message root for the source block
+        InvalidRootProof,
+        /// Merkle inclusion proof does not match the finalized message root
+        InvalidMessageProof,
+        /// Storage or head proof exceeds its size limit
+        ProofTooLarge,
+        /// Head is not newer than the latest recorded head for that chain
+        StaleHead,
+        /// Head could not be verified against FlareChain finality
+        HeadVerificationFailed,
+        /// Nonce overflow on channel
+        NonceOverflow,
+    }
+
+    #[pallet::hooks]
+    impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+        fn on_initialize(_n: BlockNumberFor<T>) -> Weight {
+            // Reserve the on_finalize root commitment for a full block of messages
+            T::WeightInfo::commit_outbound_root(MAX_MESSAGES_PER_BLOCK)
+        }
+
+        fn on_finalize(n: BlockNumberFor<T>) {
+            let messages = OutgoingMessages::<T>::get(n);
+            if messages.is_empty() {
+                return;
+            }
+
+            let leaves: Vec<T::Hash> = messages.iter().map(|m| m.outbound().leaf_hash()).collect();
+            let message_root = merkle_root(&leaves);
+            OutboundMessageRoots::<T>::insert(n, message_root);
+
+            Self::deposit_event(Event::OutboundRootCommitted {
+                block_number: n,
+                message_root,
+                message_count: leaves.len() as u32,
+            });
+        }
     }
 
     #[pallet::call]
@@ -177,7 +344,8 @@ pub mod pallet {
 
             let from = T::ChainId::get();
             let block_number = <frame_system::Pallet<T>>::block_number();
-            let nonce = MessageNonce::<T>::get();
+            let nonce = OutboundNonces::<T>::get(to);
+            let next_nonce = nonce.checked_add(1).ok_or(Error::<T>::NonceOverflow)?;
 
             // Hash the data
             let data_hash = T::Hashing::hash(&data);
@@ -208,8 +376,8 @@ pub mod pallet {
                     .map_err(|_| Error::<T>::TooManyMessages)
             })?;
 
-            // Increment nonce
-            MessageNonce::<T>::put(nonce.saturating_add(1));
+            // Increment channel nonce
+            OutboundNonces::<T>::insert(to, next_nonce);
 
             Self::deposit_event(Event::MessageSent {
                 to,
@@ -225,34 +393,70 @@ pub mod pallet {
             Ok(())
         }
 
-        /// Receive a message from FlareChain (authority only)
+        /// Receive a message from another chain with a delivery proof
+        ///
+        /// Anyone may relay. The message must be committed in the outbound message
+        /// root of `proof.source_block`, proven from the state of the finalized head
+        /// of `from` at `proof.finalized_block`, be addressed to this chain, and
+        /// carry the next nonce of the `from` channel.
         #[pallet::call_index(1)]
-        #[pallet::weight(T::WeightInfo::receive_message())]
-        pub fn receive_message(origin: OriginFor<T>, from: u8, data: Vec<u8>) -> DispatchResult {
-            ensure_root(origin)?;
+        #[pallet::weight(
+            T::WeightInfo::receive_message(proof_size(&proof.root_proof))
+                .saturating_add(T::MessageDispatch::dispatch_weight(data))
+        )]
+        pub fn receive_message(
+            origin: OriginFor<T>,
+            from: u8,
+            nonce: u64,
+            data: Vec<u8>,
+            proof: MessageDeliveryProof,
+        ) -> DispatchResult {
+            let _relayer = ensure_signed(origin)?;
 
             // Validate chain ID
             ensure!(from <= 12, Error::<T>::InvalidChainId);
 
-            // Validate message size
+            // Validate message and proof size
             ensure!(data.len() <= MAX_MESSAGE_SIZE as usize, Error::<T>::MessageTooLarge);
+            ensure!(
+                proof_size(&proof.root_proof) <= MAX_ROOT_PROOF_SIZE,
+                Error::<T>::ProofTooLarge
+            );
 
-            let block_number = <frame_system::Pallet<T>>::block_number();
-            let nonce = MessageNonce::<T>::get();
+            // Ordered, exactly-once delivery per channel
+            let expected_nonce = InboundNonces::<T>::get(from);
+            ensure!(nonce == expected_nonce, Error::<T>::InvalidNonce);
+
+            // Read the source block's message root from finalized source state
+            ensure!(
+                proof.source_block <= proof.finalized_block,
+                Error::<T>::SourceBlockNotFinalized
+            );
+            let head = FinalizedHeads::<T>::get(from, proof.finalized_block)
+                .ok_or(Error::<T>::UnknownFinalizedHead)?;
+            let message_root =
+                Self::proven_message_root(&head, proof.source_block, proof.root_proof)
+                    .ok_or(Error::<T>::InvalidRootProof)?;
 
-            // Hash the data
             let data_hash = T::Hashing::hash(&data);
+            let committed = OutboundMessage {
+                source: from,
+                destination: T::ChainId::get(),
+                nonce,
+                payload_hash: data_hash,
+            };
+            ensure!(
+                verify_merkle_proof(&message_root, committed.leaf_hash(), &proof.inclusion),
+                Error::<T>::InvalidMessageProof
+            );
 
-            // Convert block number to u32
-            let primitive_block_number: u32 = block_number
-                .try_into()
-                .unwrap_or_else(|_| u32::MAX);
+            let block_number = <frame_system::Pallet<T>>::block_number();
 
             // Create message
             let message = Message::<T> {
                 from,
                 to: T::ChainId::get(),
-                block_number: primitive_block_number,
+                block_number: proof.source_block,
                 nonce,
                 data_hash,
             };
@@ -260,7 +464,6 @@ pub mod pallet {
             // Store message data
             let bounded_data: BoundedVec<u8, ConstU32<MAX_MESSAGE_SIZE>> =
                 data.try_into().map_err(|_| Error::<T>::MessageTooLarge)?;
-            MessageData::<T>::insert(data_hash, bounded_data);
 
             // Add to incoming messages
             IncomingMessages::<T>::try_mutate(block_number, |messages| {
@@ -269,12 +472,25 @@ pub mod pallet {
                     .map_err(|_| Error::<T>::TooManyMessages)
             })?;
 
+            InboundNonces::<T>::insert(from, expected_nonce.saturating_add(1));
+
             Self::deposit_event(Event::MessageReceived {
                 from,
                 nonce,
                 data_hash,
             });
 
+            // A failing dispatch does not revert delivery, otherwise the channel
+            // would stall on a single bad message
+            let result = T::MessageDispatch::dispatch(from, nonce, &bounded_data);
+            MessageData::<T>::insert(data_hash, bounded_data);
+
+            Self::deposit_event(Event::MessageDispatched {
+                from,
+                nonce,
+                result,
+            });
+
             Ok(())
         }
 
@@ -340,32 +556,89 @@ pub mod pallet {
 
             Ok(())
         }
-    }
 
-    /// Weight functions (placeholder - should be benchmarked)
-    pub trait WeightInfo {
-        fn send_message() -> Weight;
-        fn receive_message() -> Weight;
-        fn submit_state_root() -> Weight;
-        fn get_message_data() -> Weight;
-        fn clear_message_data() -> Weight;
-    }
+        /// Record a finalized PBC head (FlareChain side)
+        #[pallet::call_index(5)]
+        #[pallet::weight(T::WeightInfo::note_finalized_head())]
+        pub fn note_finalized_head(origin: OriginFor<T>, head: FinalizedPbcHead) -> DispatchResult {
+            T::HeadOrigin::ensure_origin(origin)?;
 
-    impl WeightInfo for () {
-        fn send_message() -> Weight {
-            Weight::from_parts(50_000, 0)
+            Self::record_finalized_head(head)
         }
-        fn receive_message() -> Weight {
-            Weight::from_parts(50_000, 0)
+
+        /// Import a finalized PBC head proven against FlareChain finality (PBC side)
+        ///
+        /// `proof` is interpreted by `Config::HeadVerifier`, e.g. a SCALE-encoded
+        /// `FlareChainHeadProof` for `FlareChainHeadVerifier`.
+        #[pallet::call_index(6)]
+        #[pallet::weight(T::WeightInfo::relay_finalized_head(proof.len() as u32))]
+        pub fn relay_finalized_head(
+            origin: OriginFor<T>,
+            head: FinalizedPbcHead,
+            proof: Vec<u8>,
+        ) -> DispatchResult {
+            let _relayer = ensure_signed(origin)?;
+
+            ensure!(proof.len() <= MAX_HEAD_PROOF_SIZE as usize, Error::<T>::ProofTooLarge);
+
+            ensure!(
+                T::HeadVerifier::verify(&head, &proof),
+                Error::<T>::HeadVerificationFailed
+            );
+
+            Self::record_finalized_head(head)
         }
-        fn submit_state_root() -> Weight {
-            Weight::from_parts(30_000, 0)
+    }
+
+    impl<T: Config> Pallet<T> {
+        /// Store a finalized head, enforcing per-chain monotonicity
+        ///
+        /// Heads may skip blocks: a later head proves the outbound roots of the
+        /// blocks in between.
+        fn record_finalized_head(head: FinalizedPbcHead) -> DispatchResult {
+            ensure!(head.chain_id <= 12, Error::<T>::InvalidChainId);
+
+            if let Some(latest) = LatestFinalizedBlock::<T>::get(head.chain_id) {
+                ensure!(head.block_number > latest, Error::<T>::StaleHead);
+            }
+
+            FinalizedHeads::<T>::insert(head.chain_id, head.block_number, head);
+            LatestFinalizedBlock::<T>::insert(head.chain_id, head.block_number);
+
+            Self::deposit_event(Event::FinalizedHeadRecorded {
+                chain_id: head.chain_id,
+                block_number: head.block_number,
+                state_root: head.state_root,
+            });
+
+            Ok(())
         }
-        fn get_message_data() -> Weight {
-            Weight::from_parts(10_000, 0)
+
+        /// Outbound message root of `source_block`, read from the state of `head`
+        fn proven_message_root(
+            head: &FinalizedPbcHead,
+            source_block: PrimitiveBlockNumber,
+            root_proof: Vec<Vec<u8>>,
+        ) -> Option<T::Hash> {
+            let key =
+                OutboundMessageRoots::<T>::hashed_key_for(BlockNumberFor::<T>::from(source_block));
+            let encoded = verifier::read_proof_value(&head.state_root, root_proof, &key)?;
+            T::Hash::decode(&mut &encoded[..]).ok()
         }
-        fn clear_message_data() -> Weight {
-            Weight::from_parts(10_000, 0)
+
+        /// Build an inclusion proof for the `index`-th message sent in `block_number`
+        ///
+        /// Intended for relayers via runtime API or off-chain state queries. The
+        /// matching root proof is a read proof of `OutboundMessageRoots(block_number)`.
+        pub fn outbound_message_proof(
+            block_number: BlockNumberFor<T>,
+            index: u32,
+        ) -> Option<MessageInclusionProof> {
+            let leaves: Vec<T::Hash> = OutgoingMessages::<T>::get(block_number)
+                .iter()
+                .map(|m| m.outbound().leaf_hash())
+                .collect();
+            etrid_primitives::merkle_proof(&leaves, index)
         }
     }
 }
@@ -0,0 +1,195 @@
+//! Mock runtime for bridge pallet tests
+
+use crate as pallet_bridge;
+use crate::{verifier::testing, DispatchMessage, FlareChainHeadVerifier, OutboundMessageRoots};
+use asf_finality_proof::AuthoritySetInfo;
+use codec::Encode;
+use etrid_primitives::{
+    merkle_proof, merkle_root, FinalizedPbcHead, Hash, MessageDeliveryProof, OutboundMessage,
+};
+use frame_support::{
+    assert_ok, derive_impl, parameter_types,
+    traits::{ConstU8, Hooks},
+    weights::Weight,
+};
+use frame_system::EnsureRoot;
+use sp_core::hashing::blake2_256;
+use sp_keystore::{testing::MemoryKeystore, KeystoreExt};
+use sp_runtime::{BuildStorage, DispatchError, DispatchResult};
+
+type Block = frame_system::mocking::MockBlock<Test>;
+
+frame_support::construct_runtime!(
+    pub enum Test
+    {
+        System: frame_system,
+        Bridge: pallet_bridge,
+    }
+);
+
+#[derive_impl(frame_system::config_preludes::TestDefaultConfig)]
+impl frame_system::Config for Test {
+    type Block = Block;
+}
+
+/// Chain ID of the PBC under test
+pub const LOCAL_CHAIN: u8 = 2;
+
+/// Chain ID of the simulated source PBC
+pub const SOURCE_CHAIN: u8 = 1;
+
+parameter_types! {
+    /// FlareChain authority set trusted by `FlareChainHeadVerifier`
+    pub static FlareChainAuthorities: AuthoritySetInfo = AuthoritySetInfo {
+        set_id: 0,
+        chain_id: [0; 32],
+        authorities: Vec::new(),
+    };
+    pub const BridgePalletName: &'static str = "Bridge";
+    /// (source, nonce, payload) of every dispatched message
+    pub static Dispatched: Vec<(u8, u64, Vec<u8>)> = Vec::new();
+}
+
+/// Records payloads and rejects the payload `b"fail"`
+pub struct MockDispatch;
+impl DispatchMessage for MockDispatch {
+    fn dispatch_weight(payload: &[u8]) -> Weight {
+        Weight::from_parts(payload.len() as u64, 0)
+    }
+
+    fn dispatch(source: u8, nonce: u64, payload: &[u8]) -> DispatchResult {
+        if payload == b"fail" {
+            return Err(DispatchError::Other("dispatch failed"));
+        }
+        Dispatched::mutate(|dispatched| dispatched.push((source, nonce, payload.to_vec())));
+        Ok(())
+    }
+}
+
+/// Signs relayed heads with a fresh FlareChain authority set
+#[cfg(feature = "runtime-benchmarks")]
+pub struct MockBenchmarkHelper;
+#[cfg(feature = "runtime-benchmarks")]
+impl pallet_bridge::BenchmarkHelper for MockBenchmarkHelper {
+    fn head_proof(head: &FinalizedPbcHead, padding: u32) -> Vec<u8> {
+        let authorities = testing::authority_set(21);
+        FlareChainAuthorities::set(authorities.clone());
+        testing::head_proof::<BridgePalletName>(&authorities, head, padding).encode()
+    }
+}
+
+impl pallet_bridge::Config for Test {
+    type RuntimeEvent = RuntimeEvent;
+    type ChainId = ConstU8<LOCAL_CHAIN>;
+    type HeadOrigin = EnsureRoot<u64>;
+    type HeadVerifier = FlareChainHeadVerifier<FlareChainAuthorities, BridgePalletName>;
+    type MessageDispatch = MockDispatch;
+    type WeightInfo = ();
+    #[cfg(feature = "runtime-benchmarks")]
+    type BenchmarkHelper = MockBenchmarkHelper;
+}
+
+pub fn new_test_ext() -> sp_io::TestExternalities {
+    let storage = frame_system::GenesisConfig::<Test>::default().build_storage().unwrap();
+    let mut ext = sp_io::TestExternalities::new(storage);
+    ext.register_extension(KeystoreExt::new(MemoryKeystore::new()));
+    ext.execute_with(|| System::set_block_number(1));
+    ext
+}
+
+/// Run `on_finalize` for the current block
+pub fn finalize_block() {
+    Bridge::on_finalize(System::block_number());
+}
+
+/// Outbound side of a simulated source PBC sending to `LOCAL_CHAIN`
+#[derive(Default)]
+pub struct SourceChain {
+    /// (block, nonce, payload) of every sent message, in order
+    sent: Vec<(u32, u64, Vec<u8>)>,
+}
+
+impl SourceChain {
+    /// Send `payload` in `block` with the next channel nonce
+    pub fn send(&mut self, block: u32, payload: &[u8]) -> u64 {
+        let nonce = self.sent.len() as u64;
+        self.sent.push((block, nonce, payload.to_vec()));
+        nonce
+    }
+
+    fn leaves(&self, block: u32) -> Vec<Hash> {
+        self.sent
+            .iter()
+            .filter(|(sent_in, _, _)| *sent_in == block)
+            .map(|(_, nonce, payload)| {
+                OutboundMessage {
+                    source: SOURCE_CHAIN,
+                    destination: LOCAL_CHAIN,
+                    nonce: *nonce,
+                    payload_hash: Hash::from(blake2_256(payload)),
+                }
+                .leaf_hash()
+            })
+            .collect()
+    }
+
+    /// State root of the source chain at `block`, with a proof of its outbound roots
+    pub fn state(&self, block: u32) -> (Hash, Vec<Vec<u8>>) {
+        let mut blocks: Vec<u32> = self.sent.iter().map(|(sent_in, _, _)| *sent_in).collect();
+        blocks.sort();
+        blocks.dedup();
+        let entries: Vec<(Vec<u8>, Vec<u8>)> = blocks
+            .into_iter()
+            .filter(|sent_in| *sent_in <= block)
+            .map(|sent_in| {
+                let key = OutboundMessageRoots::<Test>::hashed_key_for(u64::from(sent_in));
+                (key, merkle_root(&self.leaves(sent_in)).encode())
+            })
+            .collect();
+        testing::storage_proof(&entries)
+    }
+
+    /// Source head at `block`
+    pub fn head(&self, block: u32) -> FinalizedPbcHead {
+        FinalizedPbcHead {
+            chain_id: SOURCE_CHAIN,
+            block_number: block,
+            block_hash: Hash::repeat_byte(block as u8),
+            state_root: self.state(block).0,
+        }
+    }
+
+    /// Record the source head at `block` through `HeadOrigin`
+    pub fn finalize(&self, block: u32) {
+        assert_ok!(Bridge::note_finalized_head(RuntimeOrigin::root(), self.head(block)));
+    }
+
+    /// Delivery proof of message `nonce` against the source head at `finalized_block`
+    pub fn proof(&self, nonce: u64, finalized_block: u32) -> MessageDeliveryProof {
+        let (source_block, _, _) = &self.sent[nonce as usize];
+        let index = self
+            .sent
+            .iter()
+            .filter(|(sent_in, _, _)| sent_in == source_block)
+            .position(|(_, sent_nonce, _)| *sent_nonce == nonce)
+            .unwrap();
+        MessageDeliveryProof {
+            source_block: *source_block,
+            finalized_block,
+            root_proof: self.state(finalized_block).1,
+            inclusion: merkle_proof(&self.leaves(*source_block), index as u32).unwrap(),
+        }
+    }
+
+    /// Relay message `nonce` with a proof against the head at `finalized_block`
+    pub fn deliver(&self, nonce: u64, finalized_block: u32) -> DispatchResult {
+        let (_, _, payload) = &self.sent[nonce as usize];
+        Bridge::receive_message(
+            RuntimeOrigin::signed(7),
+            SOURCE_CHAIN,
+            nonce,
+            payload.clone(),
+            self.proof(nonce, finalized_block),
+        )
+    }
+}
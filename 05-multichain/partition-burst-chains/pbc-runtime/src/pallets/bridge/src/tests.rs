@@ -0,0 +1,379 @@
+//! Tests for the bridge pallet and `FlareChainHeadVerifier`
+
+use crate::{
+    mock::*,
+    proof_size,
+    verifier::{testing, FlareChainHeadProof, VerifyFinalizedHead},
+    Error, Event, FinalizedHeads, IncomingMessages, InboundNonces, LatestFinalizedBlock,
+    MessageData, OutboundMessageRoots, MAX_ROOT_PROOF_SIZE,
+};
+use codec::Encode;
+use etrid_primitives::{
+    merkle_root, verify_merkle_proof, Hash, MessageDeliveryProof, OutboundMessage,
+};
+use frame_support::{assert_noop, assert_ok};
+use sp_core::hashing::blake2_256;
+use sp_runtime::{DispatchError, DispatchResult};
+
+type HeadVerifier = crate::FlareChainHeadVerifier<FlareChainAuthorities, BridgePalletName>;
+
+/// Relay `payload` as the first message of the source channel
+fn receive_first(payload: &[u8], proof: MessageDeliveryProof) -> DispatchResult {
+    Bridge::receive_message(RuntimeOrigin::signed(7), SOURCE_CHAIN, 0, payload.to_vec(), proof)
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// OUTBOUND
+// ═══════════════════════════════════════════════════════════════════════════
+
+#[test]
+fn sent_messages_are_committed_to_the_outbound_root() {
+    new_test_ext().execute_with(|| {
+        for payload in [&b"one"[..], b"two", b"three"] {
+            assert_ok!(Bridge::send_message(
+                RuntimeOrigin::signed(1),
+                SOURCE_CHAIN,
+                payload.to_vec()
+            ));
+        }
+        finalize_block();
+
+        let leaves: Vec<Hash> = [&b"one"[..], b"two", b"three"]
+            .iter()
+            .enumerate()
+            .map(|(nonce, payload)| {
+                OutboundMessage {
+                    source: LOCAL_CHAIN,
+                    destination: SOURCE_CHAIN,
+                    nonce: nonce as u64,
+                    payload_hash: Hash::from(blake2_256(payload)),
+                }
+                .leaf_hash()
+            })
+            .collect();
+        let root = OutboundMessageRoots::<Test>::get(1).unwrap();
+        assert_eq!(root, merkle_root(&leaves));
+
+        let proof = Bridge::outbound_message_proof(1, 2).unwrap();
+        assert!(verify_merkle_proof(&root, leaves[2], &proof));
+    });
+}
+
+#[test]
+fn blocks_without_messages_commit_no_root() {
+    new_test_ext().execute_with(|| {
+        finalize_block();
+        assert!(OutboundMessageRoots::<Test>::get(1).is_none());
+    });
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// DELIVERY
+// ═══════════════════════════════════════════════════════════════════════════
+
+#[test]
+fn message_proven_against_finalized_head_is_delivered() {
+    new_test_ext().execute_with(|| {
+        let mut source = SourceChain::default();
+        source.send(3, b"hello");
+        source.finalize(3);
+
+        assert_ok!(source.deliver(0, 3));
+
+        assert_eq!(InboundNonces::<Test>::get(SOURCE_CHAIN), 1);
+        assert_eq!(IncomingMessages::<Test>::get(1).len(), 1);
+        let data_hash = Hash::from(blake2_256(b"hello"));
+        assert_eq!(MessageData::<Test>::get(data_hash).unwrap().into_inner(), b"hello".to_vec());
+        assert_eq!(Dispatched::get(), vec![(SOURCE_CHAIN, 0, b"hello".to_vec())]);
+        System::assert_last_event(
+            Event::MessageDispatched { from: SOURCE_CHAIN, nonce: 0, result: Ok(()) }.into(),
+        );
+    });
+}
+
+#[test]
+fn message_is_delivered_exactly_once() {
+    new_test_ext().execute_with(|| {
+        let mut source = SourceChain::default();
+        source.send(3, b"hello");
+        source.finalize(3);
+
+        assert_ok!(source.deliver(0, 3));
+        assert_noop!(source.deliver(0, 3), Error::<Test>::InvalidNonce);
+        assert_eq!(Dispatched::get().len(), 1);
+    });
+}
+
+#[test]
+fn messages_are_delivered_in_nonce_order() {
+    new_test_ext().execute_with(|| {
+        let mut source = SourceChain::default();
+        source.send(3, b"first");
+        source.send(3, b"second");
+        source.send(4, b"third");
+        source.finalize(4);
+
+        assert_noop!(source.deliver(1, 4), Error::<Test>::InvalidNonce);
+        assert_noop!(source.deliver(2, 4), Error::<Test>::InvalidNonce);
+
+        assert_ok!(source.deliver(0, 4));
+        assert_ok!(source.deliver(1, 4));
+        assert_ok!(source.deliver(2, 4));
+        let nonces: Vec<u64> = Dispatched::get().iter().map(|(_, nonce, _)| *nonce).collect();
+        assert_eq!(nonces, vec![0, 1, 2]);
+    });
+}
+
+#[test]
+fn later_head_proves_messages_of_skipped_blocks() {
+    new_test_ext().execute_with(|| {
+        let mut source = SourceChain::default();
+        source.finalize(1);
+        source.send(3, b"in a skipped block");
+        source.send(6, b"later");
+        source.finalize(8);
+
+        assert_ok!(source.deliver(0, 8));
+        assert_ok!(source.deliver(1, 8));
+    });
+}
+
+#[test]
+fn message_without_finalized_head_is_rejected() {
+    new_test_ext().execute_with(|| {
+        let mut source = SourceChain::default();
+        source.send(3, b"hello");
+
+        // No head recorded at all
+        assert_noop!(source.deliver(0, 3), Error::<Test>::UnknownFinalizedHead);
+
+        // A finalized head older than the source block cannot prove it
+        source.finalize(2);
+        assert_noop!(source.deliver(0, 2), Error::<Test>::SourceBlockNotFinalized);
+
+        // Nor can a head that was never recorded, even with a valid state proof
+        assert_noop!(source.deliver(0, 5), Error::<Test>::UnknownFinalizedHead);
+    });
+}
+
+#[test]
+fn root_proof_must_match_finalized_state() {
+    new_test_ext().execute_with(|| {
+        let mut source = SourceChain::default();
+        source.send(3, b"hello");
+        source.finalize(3);
+
+        // State with a message the finalized head never committed
+        let mut forged = SourceChain::default();
+        forged.send(3, b"forged");
+        assert_noop!(receive_first(b"forged", forged.proof(0, 3)), Error::<Test>::InvalidRootProof);
+
+        // Proof of a block with no outbound root
+        let mut proof = source.proof(0, 3);
+        proof.source_block = 2;
+        assert_noop!(receive_first(b"hello", proof), Error::<Test>::InvalidRootProof);
+    });
+}
+
+#[test]
+fn message_must_be_included_under_the_proven_root() {
+    new_test_ext().execute_with(|| {
+        let mut source = SourceChain::default();
+        source.send(3, b"hello");
+        source.finalize(3);
+
+        assert_noop!(
+            receive_first(b"tampered", source.proof(0, 3)),
+            Error::<Test>::InvalidMessageProof
+        );
+    });
+}
+
+#[test]
+fn oversized_root_proof_is_rejected() {
+    new_test_ext().execute_with(|| {
+        let mut source = SourceChain::default();
+        source.send(3, b"hello");
+        source.finalize(3);
+
+        let mut proof = source.proof(0, 3);
+        testing::pad(&mut proof.root_proof, MAX_ROOT_PROOF_SIZE);
+        assert!(proof_size(&proof.root_proof) > MAX_ROOT_PROOF_SIZE);
+        assert_noop!(receive_first(b"hello", proof), Error::<Test>::ProofTooLarge);
+    });
+}
+
+#[test]
+fn failed_dispatch_still_consumes_the_nonce() {
+    new_test_ext().execute_with(|| {
+        let mut source = SourceChain::default();
+        source.send(3, b"fail");
+        source.send(3, b"next");
+        source.finalize(3);
+
+        assert_ok!(source.deliver(0, 3));
+        System::assert_last_event(
+            Event::MessageDispatched {
+                from: SOURCE_CHAIN,
+                nonce: 0,
+                result: Err(DispatchError::Other("dispatch failed")),
+            }
+            .into(),
+        );
+        assert_ok!(source.deliver(1, 3));
+        assert_eq!(Dispatched::get(), vec![(SOURCE_CHAIN, 1, b"next".to_vec())]);
+    });
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// FINALIZED HEADS
+// ═══════════════════════════════════════════════════════════════════════════
+
+#[test]
+fn heads_are_recorded_by_head_origin_only() {
+    new_test_ext().execute_with(|| {
+        let source = SourceChain::default();
+        assert_noop!(
+            Bridge::note_finalized_head(RuntimeOrigin::signed(1), source.head(3)),
+            DispatchError::BadOrigin
+        );
+
+        source.finalize(3);
+        assert_eq!(FinalizedHeads::<Test>::get(SOURCE_CHAIN, 3), Some(source.head(3)));
+        assert_eq!(LatestFinalizedBlock::<Test>::get(SOURCE_CHAIN), Some(3));
+    });
+}
+
+#[test]
+fn stale_heads_are_rejected() {
+    new_test_ext().execute_with(|| {
+        let source = SourceChain::default();
+        source.finalize(3);
+
+        for block in [2, 3] {
+            assert_noop!(
+                Bridge::note_finalized_head(RuntimeOrigin::root(), source.head(block)),
+                Error::<Test>::StaleHead
+            );
+        }
+    });
+}
+
+#[test]
+fn relayed_head_with_flarechain_proof_is_recorded() {
+    new_test_ext().execute_with(|| {
+        let authorities = testing::authority_set(4);
+        FlareChainAuthorities::set(authorities.clone());
+        let head = SourceChain::default().head(3);
+        let proof = testing::head_proof::<BridgePalletName>(&authorities, &head, 0);
+
+        assert_ok!(Bridge::relay_finalized_head(RuntimeOrigin::signed(7), head, proof.encode()));
+        assert_eq!(FinalizedHeads::<Test>::get(SOURCE_CHAIN, 3), Some(head));
+    });
+}
+
+#[test]
+fn relayed_head_without_valid_proof_is_rejected() {
+    new_test_ext().execute_with(|| {
+        let authorities = testing::authority_set(4);
+        FlareChainAuthorities::set(authorities.clone());
+        let head = SourceChain::default().head(3);
+
+        assert_noop!(
+            Bridge::relay_finalized_head(RuntimeOrigin::signed(7), head, b"garbage".to_vec()),
+            Error::<Test>::HeadVerificationFailed
+        );
+
+        let mut other = head;
+        other.state_root = Hash::repeat_byte(9);
+        let proof = testing::head_proof::<BridgePalletName>(&authorities, &head, 0);
+        assert_noop!(
+            Bridge::relay_finalized_head(RuntimeOrigin::signed(7), other, proof.encode()),
+            Error::<Test>::HeadVerificationFailed
+        );
+    });
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// FLARECHAIN HEAD VERIFIER
+// ═══════════════════════════════════════════════════════════════════════════
+
+#[test]
+fn verifier_storage_key_matches_finalized_heads_layout() {
+    new_test_ext().execute_with(|| {
+        assert_eq!(
+            HeadVerifier::storage_key(SOURCE_CHAIN, 42),
+            FinalizedHeads::<Test>::hashed_key_for(SOURCE_CHAIN, 42)
+        );
+    });
+}
+
+#[test]
+fn verifier_accepts_head_in_finalized_flarechain_state() {
+    new_test_ext().execute_with(|| {
+        let authorities = testing::authority_set(4);
+        FlareChainAuthorities::set(authorities.clone());
+        let head = SourceChain::default().head(3);
+        let proof = testing::head_proof::<BridgePalletName>(&authorities, &head, 0);
+
+        assert!(HeadVerifier::verify(&head, &proof.encode()));
+    });
+}
+
+#[test]
+fn verifier_rejects_proofs_without_quorum() {
+    new_test_ext().execute_with(|| {
+        let authorities = testing::authority_set(4);
+        FlareChainAuthorities::set(authorities.clone());
+        let head = SourceChain::default().head(3);
+        let mut proof = testing::head_proof::<BridgePalletName>(&authorities, &head, 0);
+
+        // Quorum of 4 is 3 signatures
+        proof.finality_proof.justification.signatures.truncate(2);
+        assert!(!HeadVerifier::verify(&head, &proof.encode()));
+    });
+}
+
+#[test]
+fn verifier_rejects_untrusted_authority_set() {
+    new_test_ext().execute_with(|| {
+        let trusted = testing::authority_set(4);
+        let untrusted = testing::authority_set(4);
+        FlareChainAuthorities::set(trusted);
+        let head = SourceChain::default().head(3);
+        let proof = testing::head_proof::<BridgePalletName>(&untrusted, &head, 0);
+
+        assert!(!HeadVerifier::verify(&head, &proof.encode()));
+    });
+}
+
+#[test]
+fn verifier_rejects_head_missing_from_flarechain_state() {
+    new_test_ext().execute_with(|| {
+        let authorities = testing::authority_set(4);
+        FlareChainAuthorities::set(authorities.clone());
+        let source = SourceChain::default();
+        let recorded = source.head(3);
+        let proof = testing::head_proof::<BridgePalletName>(&authorities, &recorded, 0);
+
+        // Same storage proof, asked about a block FlareChain never recorded
+        assert!(!HeadVerifier::verify(&source.head(4), &proof.encode()));
+
+        // Storage proof that does not reach the finalized state root
+        let mut stripped: FlareChainHeadProof = proof;
+        stripped.storage_proof.clear();
+        assert!(!HeadVerifier::verify(&recorded, &stripped.encode()));
+    });
+}
+
+#[test]
+fn verifier_ignores_padding_nodes() {
+    new_test_ext().execute_with(|| {
+        let authorities = testing::authority_set(4);
+        FlareChainAuthorities::set(authorities.clone());
+        let head = SourceChain::default().head(3);
+        let proof = testing::head_proof::<BridgePalletName>(&authorities, &head, 1_024);
+
+        assert!(HeadVerifier::verify(&head, &proof.encode()));
+    });
+}
@@ -0,0 +1,250 @@
+//! Finalized-head verification and message dispatch hooks
+//!
+//! A PBC trusts a relayed PBC head only if FlareChain has recorded it in a
+//! block finalized by ASF. `FlareChainHeadVerifier` checks that with:
+//! 1. an `asf_finality_proof::FinalityProof` for a FlareChain block, verified
+//!    against the FlareChain authority set known to this runtime
+//! 2. a storage proof of `Bridge::FinalizedHeads(chain_id, block_number)`
+//!    against the state root of that finalized header
+//!
+//! [`read_proof_value`] also checks the storage proofs of outbound message
+//! roots against finalized PBC state roots.
+
+use codec::{Decode, Encode};
+use etrid_primitives::{BlockNumber, FinalizedPbcHead};
+use frame_support::{
+    pallet_prelude::{DispatchResult, Weight},
+    traits::Get,
+    Blake2_128Concat, StorageHasher, Twox64Concat,
+};
+use scale_info::TypeInfo;
+use sp_core::H256;
+use sp_runtime::{traits::BlakeTwo256, RuntimeDebug};
+use sp_std::{marker::PhantomData, vec::Vec};
+
+use asf_finality_proof::{verify_finality_proof, AuthoritySetInfo, FinalityProof};
+
+/// Verifies that a PBC head was finalized according to FlareChain
+pub trait VerifyFinalizedHead {
+    /// Return `true` if `proof` shows `head` is recorded in finalized FlareChain state
+    fn verify(head: &FinalizedPbcHead, proof: &[u8]) -> bool;
+}
+
+/// Rejects every relayed head (for chains that only record heads via `HeadOrigin`)
+impl VerifyFinalizedHead for () {
+    fn verify(_head: &FinalizedPbcHead, _proof: &[u8]) -> bool {
+        false
+    }
+}
+
+/// Builds relayed head proofs for the `relay_finalized_head` benchmark
+#[cfg(feature = "runtime-benchmarks")]
+pub trait BenchmarkHelper {
+    /// A proof of `head` that the runtime's `VerifyFinalizedHead` accepts,
+    /// carrying `padding` extra bytes the verifier has to process
+    fn head_proof(head: &FinalizedPbcHead, padding: u32) -> Vec<u8>;
+}
+
+/// Hook invoked for every delivered cross-chain message
+pub trait DispatchMessage {
+    /// Upper bound on the weight of dispatching `payload`
+    fn dispatch_weight(payload: &[u8]) -> Weight;
+
+    /// Dispatch `payload` received on the `source` channel with `nonce`
+    fn dispatch(source: u8, nonce: u64, payload: &[u8]) -> DispatchResult;
+}
+
+/// Store-only delivery: payloads are kept in `MessageData` and not dispatched
+impl DispatchMessage for () {
+    fn dispatch_weight(_payload: &[u8]) -> Weight {
+        Weight::zero()
+    }
+
+    fn dispatch(_source: u8, _nonce: u64, _payload: &[u8]) -> DispatchResult {
+        Ok(())
+    }
+}
+
+/// Proof that a PBC head is recorded in finalized FlareChain state
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+pub struct FlareChainHeadProof {
+    /// ASF finality proof for the FlareChain block (as served by `asf_proveFinality`)
+    pub finality_proof: FinalityProof,
+    /// Trie nodes proving `FinalizedHeads(chain_id, block_number)` under the header's state root
+    pub storage_proof: Vec<Vec<u8>>,
+}
+
+/// FlareChain header layout (matches `etrid_primitives::Header`)
+type FlareChainHeader = sp_runtime::generic::Header<BlockNumber, BlakeTwo256>;
+
+/// Value of `key` in the state with `state_root`, `None` if `proof` does not prove it
+pub fn read_proof_value(state_root: &H256, proof: Vec<Vec<u8>>, key: &[u8]) -> Option<Vec<u8>> {
+    let db = sp_trie::StorageProof::new(proof).into_memory_db::<BlakeTwo256>();
+    sp_trie::read_trie_value::<sp_trie::LayoutV1<BlakeTwo256>, _>(&db, state_root, key, None, None)
+        .ok()
+        .flatten()
+}
+
+/// `VerifyFinalizedHead` backed by ASF finality and a FlareChain storage proof
+///
+/// - `Authorities`: the FlareChain authority set this runtime trusts
+/// - `PalletName`: name of the bridge pallet instance in the FlareChain runtime
+pub struct FlareChainHeadVerifier<Authorities, PalletName>(PhantomData<(Authorities, PalletName)>);
+
+impl<Authorities, PalletName> FlareChainHeadVerifier<Authorities, PalletName>
+where
+    PalletName: Get<&'static str>,
+{
+    /// Storage key of `FinalizedHeads(chain_id, block_number)` on FlareChain
+    pub fn storage_key(chain_id: u8, block_number: BlockNumber) -> Vec<u8> {
+        let prefix = frame_support::storage::storage_prefix(
+            PalletName::get().as_bytes(),
+            b"FinalizedHeads",
+        );
+
+        let mut key = prefix.to_vec();
+        key.extend(Blake2_128Concat::hash(&chain_id.encode()));
+        key.extend(Twox64Concat::hash(&block_number.encode()));
+        key
+    }
+}
+
+impl<Authorities, PalletName> VerifyFinalizedHead for FlareChainHeadVerifier<Authorities, PalletName>
+where
+    Authorities: Get<AuthoritySetInfo>,
+    PalletName: Get<&'static str>,
+{
+    fn verify(head: &FinalizedPbcHead, mut proof: &[u8]) -> bool {
+        let Ok(proof) = FlareChainHeadProof::decode(&mut proof) else {
+            return false;
+        };
+
+        // 1. The FlareChain block must be finalized by the trusted authority set
+        if verify_finality_proof(&proof.finality_proof, &Authorities::get()).is_err() {
+            return false;
+        }
+
+        let Ok(header) = FlareChainHeader::decode(&mut &proof.finality_proof.header[..]) else {
+            return false;
+        };
+
+        // 2. Its state must contain exactly this head
+        let key = Self::storage_key(head.chain_id, head.block_number);
+        read_proof_value(&header.state_root, proof.storage_proof, &key)
+            .and_then(|encoded| FinalizedPbcHead::decode(&mut &encoded[..]).ok())
+            .map_or(false, |recorded| recorded == *head)
+    }
+}
+
+/// Proof builders for tests and benchmarks (keys come from the keystore)
+#[cfg(any(test, feature = "runtime-benchmarks"))]
+pub mod testing {
+    use super::*;
+    use asf_finality_proof::{AsfJustification, JustificationSignature};
+    use sp_core::crypto::KeyTypeId;
+    use sp_trie::{LayoutV1, MemoryDB, TrieDBMutBuilder, TrieMut};
+
+    /// Key type of generated FlareChain authority keys
+    pub const KEY_TYPE: KeyTypeId = KeyTypeId(*b"pbcb");
+
+    /// State root of a trie holding `entries`, with a storage proof of all of them
+    pub fn storage_proof(entries: &[(Vec<u8>, Vec<u8>)]) -> (H256, Vec<Vec<u8>>) {
+        let mut db = MemoryDB::<BlakeTwo256>::default();
+        let mut root = H256::zero();
+        {
+            let mut trie =
+                TrieDBMutBuilder::<LayoutV1<BlakeTwo256>>::new(&mut db, &mut root).build();
+            for (key, value) in entries {
+                trie.insert(key, value).expect("in-memory trie insert cannot fail");
+            }
+        }
+        let nodes = db
+            .drain()
+            .into_values()
+            .filter(|(_, references)| *references > 0)
+            .map(|(node, _)| node)
+            .collect();
+        (root, nodes)
+    }
+
+    /// Authority set of `count` fresh sr25519 keys
+    pub fn authority_set(count: u32) -> AuthoritySetInfo {
+        AuthoritySetInfo {
+            set_id: 1,
+            chain_id: [0xfc; 32],
+            authorities: (0..count)
+                .map(|_| sp_io::crypto::sr25519_generate(KEY_TYPE, None).0)
+                .collect(),
+        }
+    }
+
+    /// Finality proof of a FlareChain block with `state_root`, signed by every authority
+    pub fn finality_proof(
+        authorities: &AuthoritySetInfo,
+        block_number: BlockNumber,
+        state_root: H256,
+    ) -> FinalityProof {
+        let header = FlareChainHeader::new(
+            block_number,
+            H256::zero(),
+            state_root,
+            H256::zero(),
+            Default::default(),
+        )
+        .encode();
+        let mut justification = AsfJustification {
+            chain_id: authorities.chain_id,
+            block_number,
+            block_hash: sp_io::hashing::blake2_256(&header),
+            authority_set_id: authorities.set_id,
+            authority_set_hash: authorities.authority_set_hash(),
+            signatures: Vec::new(),
+        };
+        let signatures = authorities
+            .authorities
+            .iter()
+            .enumerate()
+            .map(|(index, key)| {
+                let mut signature = JustificationSignature {
+                    validator_id: index as u32,
+                    validator_pubkey: *key,
+                    checkpoint_type: sp_std::vec![0],
+                    signature_nonce: 0,
+                    signature: [0; 64],
+                };
+                let payload = signature.signing_payload(&justification);
+                let public = sp_core::sr25519::Public::from_raw(*key);
+                signature.signature = sp_io::crypto::sr25519_sign(KEY_TYPE, &public, &payload)
+                    .expect("authority key is in the keystore")
+                    .0;
+                signature
+            })
+            .collect();
+        justification.signatures = signatures;
+        FinalityProof::new(header, justification)
+    }
+
+    /// Append an unused trie node of `bytes` bytes to a storage proof
+    pub fn pad(proof: &mut Vec<Vec<u8>>, bytes: u32) {
+        if bytes > 0 {
+            proof.push(sp_std::vec![0xab; bytes as usize]);
+        }
+    }
+
+    /// Proof that the `PalletName` bridge recorded `head` in finalized FlareChain
+    /// state, with `padding` extra storage proof bytes
+    pub fn head_proof<PalletName: Get<&'static str>>(
+        authorities: &AuthoritySetInfo,
+        head: &FinalizedPbcHead,
+        padding: u32,
+    ) -> FlareChainHeadProof {
+        let key =
+            FlareChainHeadVerifier::<(), PalletName>::storage_key(head.chain_id, head.block_number);
+        let (state_root, mut storage_proof) = storage_proof(&[(key, head.encode())]);
+        pad(&mut storage_proof, padding);
+        FlareChainHeadProof {
+            finality_proof: finality_proof(authorities, 1, state_root),
+            storage_proof,
+        }
+    }
+}
@@ -15,19 +15,33 @@
 //! - `asf_getBlockStatus(block_hash)` - Get comprehensive status of a block
 //! - `asf_getCommitteeInfo()` - Get detailed committee information
 //! - `asf_getSlashingHistory(limit)` - Get recent slashing events
+//! - `asf_proveFinality(block_hash)` - Get a SCALE-encoded finality proof for a finalized block
+//! - `asf_subscribeJustifications()` - Stream finality proofs as checkpoints are finalized
+//!
+//! Finality proofs are `asf_finality_proof::FinalityProof` values and can be
+//! checked with `asf_finality_proof::verify_encoded_finality_proof` against a
+//! known authority set, without any node dependency.
 
 use std::sync::Arc;
 use jsonrpsee::{
-    core::{async_trait, RpcResult},
+    core::{async_trait, RpcResult, SubscriptionResult},
     proc_macros::rpc,
     types::ErrorObjectOwned,
+    PendingSubscriptionSink, SubscriptionMessage,
 };
 use serde::{Deserialize, Serialize};
+use sc_client_api::BlockBackend;
 use sp_api::ProvideRuntimeApi;
 use sp_blockchain::HeaderBackend;
-use sp_runtime::traits::Block as BlockT;
+use sp_runtime::{
+    traits::{Block as BlockT, Header as HeaderT},
+    SaturatedConversion,
+};
 use codec::{Encode, Decode};
 
+// Portable finality proofs
+use asf_finality_proof::{AsfJustification, FinalityProof, ASF_JUSTIFICATION_ENGINE_ID};
+
 // ASF algorithm types
 use asf_algorithm::{
     FinalityLevel, Hash, BlockNumber,
@@ -71,8 +85,33 @@ pub trait AsfRpcApi {
     /// Get recent slashing events
     #[method(name = "asf_getSlashingHistory")]
     async fn get_slashing_history(&self, limit: Option<u32>) -> RpcResult<Vec<SlashingEventInfo>>;
+
+    /// Get a finality proof for a block finalized by a checkpoint certificate
+    ///
+    /// Returns `None` if the block is unknown or carries no ASF justification
+    /// (e.g. it was finalized implicitly as an ancestor of a checkpoint).
+    #[method(name = "asf_proveFinality")]
+    async fn prove_finality(&self, block_hash: Hash) -> RpcResult<Option<FinalityProofInfo>>;
+
+    /// Subscribe to finality proofs as checkpoint certificates finalize blocks
+    #[subscription(
+        name = "asf_subscribeJustifications" => "asf_justification",
+        unsubscribe = "asf_unsubscribeJustifications",
+        item = FinalityProofInfo
+    )]
+    async fn subscribe_justifications(&self) -> SubscriptionResult;
 }
 
+// ═══════════════════════════════════════════════════════════════════════════════
+// JUSTIFICATION STREAM
+// ═══════════════════════════════════════════════════════════════════════════════
+
+/// Sender side of the finalized-justification stream (fed by the checkpoint worker)
+pub type JustificationSender = tokio::sync::broadcast::Sender<AsfJustification>;
+
+/// Buffered justifications per subscriber before it is considered lagging
+pub const JUSTIFICATION_CHANNEL_CAPACITY: usize = 256;
+
 // ═══════════════════════════════════════════════════════════════════════════════
 // RESPONSE TYPES
 // ═══════════════════════════════════════════════════════════════════════════════
@@ -165,6 +204,21 @@ pub struct CommitteeInfo {
     pub stake_threshold: u128,
 }
 
+/// Finality proof for a block
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FinalityProofInfo {
+    /// Block hash
+    pub block_hash: String,
+    /// Block number
+    pub block_number: BlockNumber,
+    /// Authority set ID that signed the justification
+    pub authority_set_id: u64,
+    /// Number of validator signatures in the justification
+    pub signature_count: u32,
+    /// Hex-encoded SCALE `FinalityProof`
+    pub proof: String,
+}
+
 /// Slashing event information
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SlashingEventInfo {
@@ -191,14 +245,16 @@ pub struct SlashingEventInfo {
 /// ASF RPC handler
 pub struct AsfRpc<C, Block> {
     client: Arc<C>,
+    justification_tx: JustificationSender,
     _phantom: std::marker::PhantomData<Block>,
 }
 
 impl<C, Block> AsfRpc<C, Block> {
     /// Create a new ASF RPC handler
-    pub fn new(client: Arc<C>) -> Self {
+    pub fn new(client: Arc<C>, justification_tx: JustificationSender) -> Self {
         Self {
             client,
+            justification_tx,
             _phantom: std::marker::PhantomData,
         }
     }
@@ -224,15 +280,50 @@ impl<C, Block> AsfRpc<C, Block> {
             FinalityLevel::Irreversible => 4,
         }
     }
+
+    /// Bundle an encoded header and justification into the RPC response format
+    fn proof_info(header: Vec<u8>, justification: AsfJustification) -> FinalityProofInfo {
+        let block_hash = format!("0x{}", hex::encode(justification.block_hash));
+        let block_number = justification.block_number as BlockNumber;
+        let authority_set_id = justification.authority_set_id;
+        let signature_count = justification.signatures.len() as u32;
+        let proof = FinalityProof::new(header, justification);
+
+        FinalityProofInfo {
+            block_hash,
+            block_number,
+            authority_set_id,
+            signature_count,
+            proof: format!("0x{}", hex::encode(proof.encode())),
+        }
+    }
+}
+
+impl<C, Block> AsfRpc<C, Block>
+where
+    Block: BlockT<Hash = Hash>,
+    C: HeaderBackend<Block>,
+{
+    /// Build a proof for a justification by loading the header it finalizes
+    fn proof_for_justification(&self, justification: AsfJustification) -> RpcResult<FinalityProofInfo> {
+        let hash = Hash::from(justification.block_hash);
+        let header = self
+            .client
+            .header(hash)
+            .map_err(|e| ErrorObjectOwned::owned(4, "Failed to load header", Some(format!("{:?}", e))))?
+            .ok_or_else(|| ErrorObjectOwned::owned(5, "Header not found", Some(format!("{:?}", hash))))?;
+
+        Ok(Self::proof_info(header.encode(), justification))
+    }
 }
 
 #[async_trait]
 impl<C, Block> AsfRpcApiServer for AsfRpc<C, Block>
 where
-    Block: BlockT,
+    Block: BlockT<Hash = Hash>,
     C: Send + Sync + 'static,
     C: ProvideRuntimeApi<Block>,
-    C: HeaderBackend<Block>,
+    C: HeaderBackend<Block> + BlockBackend<Block>,
     C::Api: pallet_validator_committee_runtime_api::ValidatorCommitteeApi<Block>,
 {
     async fn get_finality_level(&self, block_hash: Hash) -> RpcResult<FinalityLevelInfo> {
@@ -244,12 +335,18 @@ where
         // 2. Get certificate count for the block
         // 3. Calculate finality level
 
+        let header = self
+            .client
+            .header(block_hash)
+            .map_err(|e| ErrorObjectOwned::owned(4, "Failed to load header", Some(format!("{:?}", e))))?
+            .ok_or_else(|| ErrorObjectOwned::owned(5, "Header not found", Some(format!("{:?}", block_hash))))?;
+
         let certificate_count = 0u32; // TODO: Get from runtime
         let level = FinalityLevel::from(certificate_count);
 
         Ok(FinalityLevelInfo {
             block_hash: hex::encode(block_hash.as_ref()),
-            block_number: 0, // TODO: Get from header
+            block_number: (*header.number()).saturated_into(),
             level: Self::level_to_u8(level),
             level_name: Self::level_to_name(level).to_string(),
             certificate_count,
@@ -357,6 +454,60 @@ where
         // For now, return empty array
         Ok(vec![])
     }
+
+    async fn prove_finality(&self, block_hash: Hash) -> RpcResult<Option<FinalityProofInfo>> {
+        let justifications = self.client.justifications(block_hash).map_err(|e| {
+            ErrorObjectOwned::owned(6, "Failed to load justifications", Some(format!("{:?}", e)))
+        })?;
+
+        let Some(encoded) = justifications
+            .as_ref()
+            .and_then(|j| j.get(ASF_JUSTIFICATION_ENGINE_ID))
+        else {
+            return Ok(None);
+        };
+
+        let justification = AsfJustification::decode(&mut &encoded[..]).map_err(|e| {
+            ErrorObjectOwned::owned(7, "Failed to decode ASF justification", Some(e.to_string()))
+        })?;
+
+        self.proof_for_justification(justification).map(Some)
+    }
+
+    async fn subscribe_justifications(&self, pending: PendingSubscriptionSink) -> SubscriptionResult {
+        use tokio::sync::broadcast::error::RecvError;
+
+        let mut justifications = self.justification_tx.subscribe();
+        let sink = pending.accept().await?;
+
+        loop {
+            let justification = tokio::select! {
+                _ = sink.closed() => break,
+                received = justifications.recv() => match received {
+                    Ok(justification) => justification,
+                    Err(RecvError::Lagged(skipped)) => {
+                        log::warn!("asf_subscribeJustifications subscriber lagged, skipped {} justifications", skipped);
+                        continue;
+                    }
+                    Err(RecvError::Closed) => break,
+                },
+            };
+
+            let info = match self.proof_for_justification(justification) {
+                Ok(info) => info,
+                Err(e) => {
+                    log::debug!("Skipping justification without local header: {:?}", e);
+                    continue;
+                }
+            };
+
+            if sink.send(SubscriptionMessage::from_json(&info)?).await.is_err() {
+                break;
+            }
+        }
+
+        Ok(())
+    }
 }
 
 // ═══════════════════════════════════════════════════════════════════════════════
@@ -366,15 +517,16 @@ where
 /// Create ASF RPC extension
 pub fn create_asf_rpc<C, Block>(
     client: Arc<C>,
+    justification_tx: JustificationSender,
 ) -> jsonrpsee::RpcModule<AsfRpc<C, Block>>
 where
-    Block: BlockT,
+    Block: BlockT<Hash = Hash>,
     C: Send + Sync + 'static,
     C: ProvideRuntimeApi<Block>,
-    C: HeaderBackend<Block>,
+    C: HeaderBackend<Block> + BlockBackend<Block>,
     C::Api: pallet_validator_committee_runtime_api::ValidatorCommitteeApi<Block>,
 {
-    AsfRpc::new(client).into_rpc()
+    AsfRpc::new(client, justification_tx).into_rpc()
 }
 
 #[cfg(test)]
@@ -398,4 +550,27 @@ mod tests {
         assert_eq!(AsfRpc::<(), ()>::level_to_name(FinalityLevel::Strong), "Strong");
         assert_eq!(AsfRpc::<(), ()>::level_to_name(FinalityLevel::Irreversible), "Irreversible");
     }
+
+    #[test]
+    fn test_proof_info_roundtrip() {
+        let header = b"header".to_vec();
+        let justification = AsfJustification {
+            chain_id: [1u8; 32],
+            block_number: 32,
+            block_hash: sp_core::hashing::blake2_256(&header),
+            authority_set_id: 2,
+            authority_set_hash: [3u8; 32],
+            signatures: vec![],
+        };
+
+        let info = AsfRpc::<(), ()>::proof_info(header.clone(), justification.clone());
+        assert_eq!(info.block_number, 32);
+        assert_eq!(info.authority_set_id, 2);
+        assert_eq!(info.signature_count, 0);
+
+        let bytes = hex::decode(info.proof.trim_start_matches("0x")).unwrap();
+        let proof = FinalityProof::decode(&mut &bytes[..]).unwrap();
+        assert_eq!(proof.header, header);
+        assert_eq!(proof.justification, justification);
+    }
 }
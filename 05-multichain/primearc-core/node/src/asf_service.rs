@@ -66,7 +66,7 @@ use checkpoint_bft::{
     CheckpointCollector, AuthoritySet, CheckpointSignature, CheckpointCertificate,
     CheckpointType, detect_checkpoint, is_guaranteed_checkpoint,
     ForkAwareCollector, ByzantineTracker, RateLimitedCollector, RateLimitConfig,
-    EclipseDetector, FinalityTracker,
+    EclipseDetector, FinalityTracker, ASF_JUSTIFICATION_ENGINE_ID,
 };
 use std::sync::Mutex;
 
@@ -142,25 +142,44 @@ impl Default for AsfParams {
 // V17: CHECKPOINT SIGNING HELPER FUNCTIONS
 // ═══════════════════════════════════════════════════════════════════════════════
 
-use once_cell::sync::Lazy;
-use std::collections::HashMap;
+use asf_slashing_protection::{SigningKind, SigningPosition, SlashingProtectionDb};
 
-/// Nonce tracking per validator (prevents signature replay within epoch)
-static SIGNATURE_NONCES: Lazy<Mutex<HashMap<u32, u64>>> =
-    Lazy::new(|| Mutex::new(HashMap::new()));
+/// Shared handle to the validator's slashing-protection database
+type SlashingProtection = Arc<Mutex<SlashingProtectionDb>>;
 
-/// Get next signature nonce for a validator
-fn get_next_signature_nonce(validator_id: u32) -> u64 {
-    let mut nonces = match SIGNATURE_NONCES.lock() {
+/// Open the slashing-protection DB under `<base>/chains/<id>/asf/`
+///
+/// Records are bound to the genesis hash; a DB from another chain is refused.
+fn open_slashing_protection(
+    config: &Configuration,
+    genesis_hash: sp_core::H256,
+) -> Result<SlashingProtection, ServiceError> {
+    let path = config
+        .base_path
+        .config_dir(config.chain_spec.id())
+        .join("asf")
+        .join(asf_slashing_protection::DEFAULT_DB_FILE);
+
+    let db = SlashingProtectionDb::open(&path, genesis_hash.into())
+        .map_err(|e| ServiceError::Other(format!("Failed to open slashing protection DB: {}", e)))?;
+
+    log::info!("🛡️  Slashing protection DB: {}", path.display());
+    Ok(Arc::new(Mutex::new(db)))
+}
+
+/// Get next signature nonce for a validator (persisted, survives restarts)
+fn get_next_signature_nonce(
+    protection: &SlashingProtection,
+    validator_pubkey: &[u8; 32],
+) -> Result<u64, String> {
+    let mut db = match protection.lock() {
         Ok(guard) => guard,
         Err(poisoned) => {
-            log::error!("Signature nonce mutex poisoned, recovering: validator_id={}", validator_id);
+            log::error!("Slashing protection mutex poisoned, recovering");
             poisoned.into_inner()
         }
     };
-    let nonce = nonces.entry(validator_id).or_insert(0);
-    *nonce += 1;
-    *nonce
+    db.next_nonce(validator_pubkey).map_err(|e| e.to_string())
 }
 
 /// Get validator's Sr25519 signing key from keystore and derive validator ID
@@ -231,6 +250,9 @@ fn get_genesis_hash(client: &Arc<FullClient>) -> sp_core::H256 {
 
 /// Create and sign a checkpoint signature
 ///
+/// Refuses to sign if the slashing-protection DB shows this key already signed a
+/// different checkpoint at this (or a later) position.
+///
 /// V17 Implementation Note:
 /// Currently uses Sr25519 keys with a conversion to Ed25519 signature format.
 /// This is a transitional approach - production will use dedicated Ed25519 checkpoint keys.
@@ -246,10 +268,31 @@ fn create_checkpoint_signature(
     keystore: &Arc<dyn sc_keystore::Keystore>,
     public_key: &sp_core::sr25519::Public,
     chain_id: [u8; 32],
-) -> CheckpointSignature {
+    protection: &SlashingProtection,
+) -> Result<CheckpointSignature, String> {
     use sp_core::crypto::{KeyTypeId, Pair};
     use sc_keystore::Keystore;
 
+    // Consult (and update) slashing protection before touching the key
+    let position = SigningPosition {
+        epoch: authority_set_id,
+        round: 0,
+        phase: 0,
+        height: block_number as u64,
+    };
+    let signing_root = sp_core::hashing::blake2_256(&(block_hash, &checkpoint_type).encode());
+    {
+        let mut db = match protection.lock() {
+            Ok(guard) => guard,
+            Err(poisoned) => {
+                log::error!("Slashing protection mutex poisoned, recovering");
+                poisoned.into_inner()
+            }
+        };
+        db.check_and_record(&validator_pubkey, SigningKind::Checkpoint, position, &signing_root)
+            .map_err(|e| e.to_string())?;
+    }
+
     // Get current timestamp
     let timestamp_ms = std::time::SystemTime::now()
         .duration_since(std::time::UNIX_EPOCH)
@@ -293,7 +336,54 @@ fn create_checkpoint_signature(
 
     sig_struct.signature = signature.0.to_vec();
 
-    sig_struct
+    Ok(sig_struct)
+}
+
+/// Sign a finality vote with the validator's ASF key
+///
+/// Refuses to sign if the slashing-protection DB shows this key already voted for a
+/// different block at this (or a later) view.
+fn sign_finality_vote(
+    vote: &mut finality_gadget::Vote,
+    keystore: &Arc<dyn sc_keystore::Keystore>,
+    public_key: &sp_core::sr25519::Public,
+    authority_set_id: u64,
+    protection: &SlashingProtection,
+) -> Result<(), String> {
+    use sp_core::crypto::KeyTypeId;
+    use sc_keystore::Keystore;
+
+    // Consult (and update) slashing protection before touching the key
+    let position = SigningPosition {
+        epoch: authority_set_id,
+        round: vote.view.0,
+        phase: 0,
+        height: 0,
+    };
+    let signing_root = sp_core::hashing::blake2_256(&(vote.view, vote.block_hash).encode());
+    {
+        let mut db = match protection.lock() {
+            Ok(guard) => guard,
+            Err(poisoned) => {
+                log::error!("Slashing protection mutex poisoned, recovering");
+                poisoned.into_inner()
+            }
+        };
+        db.check_and_record(&public_key.0, SigningKind::Vote, position, &signing_root)
+            .map_err(|e| e.to_string())?;
+    }
+
+    const ASF_KEY_TYPE: KeyTypeId = KeyTypeId([0x61, 0x73, 0x66, 0x6b]); // "asfk"
+
+    let payload = (vote.validator_id, vote.view, vote.block_hash).encode();
+    let signature = keystore
+        .sr25519_sign(ASF_KEY_TYPE, public_key, &payload)
+        .map_err(|e| format!("Keystore error: {:?}", e))?
+        .ok_or_else(|| "ASF key missing from keystore".to_string())?;
+
+    vote.signature = signature.0.to_vec();
+
+    Ok(())
 }
 
 /// Broadcast checkpoint signature via P2P network
@@ -489,6 +579,7 @@ async fn finalize_checkpoint_block<Client, BE>(
     client: &Arc<Client>,
     network: &Arc<P2PNetwork>,
     finality_tracker: &Arc<Mutex<FinalityTracker>>,
+    justification_tx: &crate::asf_rpc::JustificationSender,
 ) -> Result<(), String>
 where
     BE: sc_client_api::Backend<Block>,
@@ -509,11 +600,29 @@ where
         certificate.signatures.len()
     );
 
+    // Persist the certificate set as an ASF justification alongside the block
+    // so finality proofs can be served after restarts (asf_proveFinality)
+    let justification = certificate.to_justification();
+    if justification.is_none() {
+        log::warn!(
+            "⚠️ Checkpoint certificate for block #{} has malformed signatures, finalizing without justification",
+            block_number
+        );
+    }
+    let encoded_justification = justification
+        .as_ref()
+        .map(|j| (ASF_JUSTIFICATION_ENGINE_ID, j.encode()));
+
     // Finalize the block in Substrate
     use sc_client_api::Finalizer;
-    client.finalize_block(block_hash, None, true)
+    client.finalize_block(block_hash, encoded_justification, true)
         .map_err(|e| format!("Failed to finalize block #{}: {:?}", block_number, e))?;
 
+    // Notify asf_subscribeJustifications subscribers (no receivers is not an error)
+    if let Some(justification) = justification {
+        let _ = justification_tx.send(justification);
+    }
+
     log::info!(
         "✅ Block #{} finalized successfully with {:?} finality",
         block_number,
@@ -942,6 +1051,8 @@ pub fn new_full_with_params(
         other: (block_import, mut telemetry),
     } = new_partial(&config)?;
 
+    let slashing_protection = open_slashing_protection(&config, client.info().genesis_hash)?;
+
     // ═══════════════════════════════════════════════════════════════════════════
     // NETWORK SETUP
     // ═══════════════════════════════════════════════════════════════════════════
@@ -1022,6 +1133,17 @@ pub fn new_full_with_params(
         );
     }
 
+    // ═══════════════════════════════════════════════════════════════════════════
+    // ËTWASM LOG INDEX
+    // ═══════════════════════════════════════════════════════════════════════════
+
+    // Receipts and logs persisted for eth_getLogs / eth_getTransactionReceipt
+    task_manager.spawn_handle().spawn(
+        "etwasm-log-index",
+        None,
+        crate::eth_log_index::run(client.clone()),
+    );
+
     // ═══════════════════════════════════════════════════════════════════════════
     // RPC SETUP
     // ═══════════════════════════════════════════════════════════════════════════
@@ -1031,15 +1153,21 @@ pub fn new_full_with_params(
     let name = config.network.node_name.clone();
     let prometheus_registry = config.prometheus_registry().cloned();
 
+    // Finalized-block justifications fan out to asf_subscribeJustifications
+    let (justification_tx, _) =
+        tokio::sync::broadcast::channel(crate::asf_rpc::JUSTIFICATION_CHANNEL_CAPACITY);
+
     let rpc_extensions_builder = {
         let client = client.clone();
         let pool = transaction_pool.clone();
+        let justification_tx = justification_tx.clone();
 
         Box::new(move |_| {
             let deps = crate::rpc::FullDeps {
                 client: client.clone(),
                 pool: pool.clone(),
                 enable_asf: true, // Enable ASF RPC endpoints
+                justification_tx: justification_tx.clone(),
             };
 
             crate::rpc::create_full(deps).map_err(Into::into)
@@ -1796,6 +1924,7 @@ pub fn new_full_with_params(
     let checkpoint_detection_finality = finality_tracker.clone();
     let checkpoint_detection_sig_tx = ppfa_sig_tx.clone();
     let checkpoint_detection_params = asf_params.clone();
+    let checkpoint_detection_protection = slashing_protection.clone();
 
     task_manager.spawn_essential_handle().spawn(
         "checkpoint-detection-all-imports",
@@ -1886,13 +2015,22 @@ pub fn new_full_with_params(
                             let chain_id = FLARECHAIN_NETWORK_ID;
 
                             // Get signature nonce
-                            let signature_nonce = get_next_signature_nonce(validator_id);
+                            let signature_nonce = match get_next_signature_nonce(
+                                &checkpoint_detection_protection,
+                                &public_bytes,
+                            ) {
+                                Ok(nonce) => nonce,
+                                Err(e) => {
+                                    log::error!("❌ Cannot reserve checkpoint nonce, not signing: {}", e);
+                                    continue;
+                                }
+                            };
 
                             // Convert block hash to [u8; 32]
                             let block_hash_bytes: [u8; 32] = block_hash.into();
 
                             // Create checkpoint signature
-                            let signature = create_checkpoint_signature(
+                            let signature = match create_checkpoint_signature(
                                 block_number,
                                 &block_hash_bytes,
                                 validator_id,
@@ -1904,7 +2042,14 @@ pub fn new_full_with_params(
                                 &checkpoint_detection_keystore,
                                 &public_key,
                                 chain_id,
-                            );
+                                &checkpoint_detection_protection,
+                            ) {
+                                Ok(signature) => signature,
+                                Err(e) => {
+                                    log::error!("🛡️  Slashing protection blocked checkpoint #{}: {}", block_number, e);
+                                    continue;
+                                }
+                            };
 
                             log::info!(
                                 "✍️  Signed checkpoint #{} as validator {} (nonce: {})",
@@ -1991,16 +2136,25 @@ pub fn new_full_with_params(
         struct DetrP2PNetworkBridge {
             p2p_network: Arc<P2PNetwork>,
             gadget_bridge: Arc<tokio::sync::Mutex<GadgetNetworkBridge>>,
+            keystore: Arc<dyn sc_keystore::Keystore>,
+            checkpoint_collector: Arc<checkpoint_bft::CheckpointCollector>,
+            slashing_protection: SlashingProtection,
         }
 
         impl DetrP2PNetworkBridge {
             fn new(
                 p2p_network: Arc<P2PNetwork>,
                 gadget_bridge: Arc<tokio::sync::Mutex<GadgetNetworkBridge>>,
+                keystore: Arc<dyn sc_keystore::Keystore>,
+                checkpoint_collector: Arc<checkpoint_bft::CheckpointCollector>,
+                slashing_protection: SlashingProtection,
             ) -> Self {
                 Self {
                     p2p_network,
                     gadget_bridge,
+                    keystore,
+                    checkpoint_collector,
+                    slashing_protection,
                 }
             }
 
@@ -2043,13 +2197,28 @@ pub fn new_full_with_params(
 
         #[async_trait::async_trait]
         impl NetworkBridge for DetrP2PNetworkBridge {
-            async fn broadcast_vote(&self, vote: FinalityVote) -> Result<(), String> {
+            async fn broadcast_vote(&self, mut vote: FinalityVote) -> Result<(), String> {
                 log::trace!(
                     "Broadcasting ASF finality vote (validator: {:?}, view: {:?})",
                     vote.validator_id,
                     vote.view
                 );
 
+                // Every vote is signed here, behind the slashing-protection check
+                let (public_key, _) = get_validator_sr25519_key(&self.keystore, &self.checkpoint_collector)?;
+                let authority_set_id = self.checkpoint_collector.get_authority_set().set_id;
+                sign_finality_vote(
+                    &mut vote,
+                    &self.keystore,
+                    &public_key,
+                    authority_set_id,
+                    &self.slashing_protection,
+                )
+                .map_err(|e| {
+                    log::error!("❌ Refusing to sign finality vote (view: {:?}): {}", vote.view, e);
+                    format!("Refusing to sign vote: {}", e)
+                })?;
+
                 // Convert vote to bridge format
                 let vote_data = Self::convert_vote_to_bridge(&vote);
 
@@ -2453,6 +2622,9 @@ pub fn new_full_with_params(
         let network_bridge = Arc::new(DetrP2PNetworkBridge::new(
             p2p_network.clone(),
             gadget_bridge.clone(),
+            keystore_container.keystore(),
+            checkpoint_collector.clone(),
+            slashing_protection.clone(),
         ));
 
         log::info!("✅ DetrP2PNetworkBridge created - finality messages will use detrp2p");
@@ -2515,6 +2687,7 @@ pub fn new_full_with_params(
         let checkpoint_collector_worker = checkpoint_collector.clone();
         let checkpoint_byzantine_tracker = byzantine_tracker.clone();
         let checkpoint_finality_tracker = finality_tracker.clone();
+        let checkpoint_justification_tx = justification_tx.clone();
 
         task_manager.spawn_essential_handle().spawn(
             "checkpoint-bft-p2p-handler",
@@ -2620,6 +2793,7 @@ pub fn new_full_with_params(
                                                             &checkpoint_client,
                                                             &checkpoint_p2p_network,
                                                             &checkpoint_finality_tracker,
+                                                            &checkpoint_justification_tx,
                                                         ).await {
                                                             log::error!("Failed to finalize checkpoint block: {}", e);
                                                         }
@@ -0,0 +1,144 @@
+//! # ËtwasmVM Log Index
+//!
+//! Persists the ËtwasmVM receipts (with their logs and blooms) of every
+//! imported block in the node's auxiliary database, so `eth_getLogs` and
+//! `eth_getTransactionReceipt` keep working once block state is pruned.
+//! Transaction hashes are indexed alongside, so the runtime keeps no
+//! unbounded hash-to-block map in state.
+//!
+//! ## Reorg Safety
+//!
+//! Entries are keyed by block hash. Blocks on abandoned forks keep their
+//! entries but are never read: queries resolve a block number to its
+//! canonical hash first. A transaction included on several forks keeps one
+//! location per block, and lookups pick the canonical one. Import
+//! notifications are not sent during major sync, so each notification also
+//! backfills unindexed ancestors.
+
+use std::sync::Arc;
+use codec::{Decode, Encode};
+use futures::StreamExt;
+use sc_client_api::{AuxStore, BlockchainEvents};
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_core::H256;
+use sp_runtime::traits::Header as HeaderT;
+
+use pallet_etwasm_vm::{EthReceipt, EtwasmEthApi};
+use primearc_runtime::opaque::Block;
+
+/// Aux DB key prefix of per-block receipts
+const RECEIPTS_PREFIX: &[u8] = b"etwasm_receipts:";
+
+/// Aux DB key prefix of transaction locations
+const TRANSACTIONS_PREFIX: &[u8] = b"etwasm_transactions:";
+
+/// Maximum number of ancestors indexed after one import notification
+const MAX_BACKFILL: usize = 4096;
+
+fn receipts_key(block_hash: &H256) -> Vec<u8> {
+    let mut key = RECEIPTS_PREFIX.to_vec();
+    key.extend_from_slice(block_hash.as_bytes());
+    key
+}
+
+fn transaction_key(transaction_hash: &H256) -> Vec<u8> {
+    let mut key = TRANSACTIONS_PREFIX.to_vec();
+    key.extend_from_slice(transaction_hash.as_bytes());
+    key
+}
+
+/// Block and receipt index of an indexed transaction
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Encode, Decode)]
+pub struct TransactionLocation {
+    pub block_number: u32,
+    pub block_hash: H256,
+    pub index: u32,
+}
+
+/// Every indexed location of a transaction, one per block including it
+pub fn load_transaction<C: AuxStore>(client: &C, transaction_hash: &H256) -> Vec<TransactionLocation> {
+    client
+        .get_aux(&transaction_key(transaction_hash))
+        .ok()
+        .flatten()
+        .and_then(|encoded| Vec::<TransactionLocation>::decode(&mut &encoded[..]).ok())
+        .unwrap_or_default()
+}
+
+/// Indexed receipts of a block, `None` if not indexed (yet)
+pub fn load_receipts<C: AuxStore>(client: &C, block_hash: &H256) -> Option<Vec<EthReceipt>> {
+    let encoded = client.get_aux(&receipts_key(block_hash)).ok()??;
+    Vec::<EthReceipt>::decode(&mut &encoded[..]).ok()
+}
+
+/// Index the receipts and transaction hashes of one block from its state
+fn index_block<C>(client: &C, block_hash: H256) -> Result<(), String>
+where
+    C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + AuxStore,
+    C::Api: EtwasmEthApi<Block>,
+{
+    let block_number = client
+        .number(block_hash)
+        .map_err(|e| format!("Failed to load block number: {:?}", e))?
+        .ok_or("Unknown block")?;
+    let receipts = client
+        .runtime_api()
+        .receipts(block_hash)
+        .map_err(|e| format!("Failed to load receipts: {:?}", e))?;
+
+    let mut entries = vec![(receipts_key(&block_hash), receipts.encode())];
+    for (index, receipt) in receipts.iter().enumerate() {
+        let location = TransactionLocation { block_number, block_hash, index: index as u32 };
+        let mut locations = load_transaction(client, &receipt.transaction_hash);
+        if !locations.contains(&location) {
+            locations.push(location);
+        }
+        entries.push((transaction_key(&receipt.transaction_hash), locations.encode()));
+    }
+    let insert: Vec<(&[u8], &[u8])> = entries.iter().map(|(key, value)| (&key[..], &value[..])).collect();
+    client
+        .insert_aux(&insert, &[])
+        .map_err(|e| format!("Failed to store receipts: {:?}", e))
+}
+
+/// Blocks from `block_hash` back to the last indexed ancestor, oldest first
+fn unindexed_chain<C>(client: &C, mut block_hash: H256) -> Vec<H256>
+where
+    C: HeaderBackend<Block> + AuxStore,
+{
+    let mut chain = Vec::new();
+    while chain.len() < MAX_BACKFILL {
+        if matches!(client.get_aux(&receipts_key(&block_hash)), Ok(Some(_))) {
+            break;
+        }
+        let Ok(Some(header)) = client.header(block_hash) else {
+            break;
+        };
+        chain.push(block_hash);
+        if header.number() == &0 {
+            break;
+        }
+        block_hash = *header.parent_hash();
+    }
+    chain.reverse();
+    chain
+}
+
+/// Index receipts of imported blocks until the import stream ends
+pub async fn run<C>(client: Arc<C>)
+where
+    C: BlockchainEvents<Block> + ProvideRuntimeApi<Block> + HeaderBackend<Block> + AuxStore,
+    C::Api: EtwasmEthApi<Block>,
+{
+    let mut imports = client.import_notification_stream();
+
+    while let Some(notification) = imports.next().await {
+        for block_hash in unindexed_chain(&*client, notification.hash) {
+            // State of old blocks may already be pruned
+            if let Err(e) = index_block(&*client, block_hash) {
+                log::debug!("ËtwasmVM log index skipped {:?}: {}", block_hash, e);
+            }
+        }
+    }
+}
@@ -0,0 +1,650 @@
+//! # Ethereum JSON-RPC for ËtwasmVM Contracts
+//!
+//! A narrow `eth_*` facade over the ËtwasmVM pallet, so wallets and tooling
+//! (ethers, viem, Foundry, Hardhat) can deploy, call and watch ËtwasmVM
+//! contracts on Primearc. Full EVM compatibility remains on ETH-PBC.
+//!
+//! ## RPC Methods
+//!
+//! - `eth_chainId()` / `eth_blockNumber()`
+//! - `eth_getTransactionCount(address, block)` - Ethereum nonce of an address
+//! - `eth_getCode(address, block)` / `eth_getStorageAt(address, slot, block)`
+//! - `eth_call(request, block)` / `eth_estimateGas(request, block)` - dry runs
+//! - `eth_sendRawTransaction(bytes)` - submit a signed Ethereum transaction
+//! - `eth_getTransactionReceipt(hash)` - receipt with status, gas and logs
+//! - `eth_getLogs(filter)` - logs over a bounded block range
+//!
+//! Gas figures are ËtwasmVM VMw units and values are in the smallest ÉTR unit.
+//!
+//! Receipts are read from the log index (`eth_log_index`), falling back to
+//! block state for blocks not indexed yet. Transactions are looked up by
+//! hash in the log index only, so a receipt appears once its block is indexed. `eth_getLogs` skips blocks whose
+//! header bloom cannot match the filter.
+
+use std::sync::Arc;
+use jsonrpsee::{
+    core::{async_trait, RpcResult},
+    proc_macros::rpc,
+    types::ErrorObjectOwned,
+};
+use serde::{Deserialize, Deserializer, Serialize};
+use sc_transaction_pool_api::{TransactionPool, TransactionSource};
+use sp_api::ProvideRuntimeApi;
+use sc_client_api::AuxStore;
+use sp_blockchain::HeaderBackend;
+use sp_core::{Bytes, H160, H256, U256};
+use sp_runtime::traits::Header as HeaderT;
+
+use pallet_etwasm_vm::{
+    eth::find_logs_bloom,
+    runtime::{EventBloomFilter, ExecutionResult},
+    EthLog, EthReceipt, EtwasmEthApi,
+};
+use primearc_runtime::opaque::Block;
+
+use crate::eth_log_index::TransactionLocation;
+
+/// Maximum number of blocks scanned by one `eth_getLogs` call
+pub const MAX_LOG_BLOCK_RANGE: u32 = 1024;
+
+/// Maximum number of logs returned by one `eth_getLogs` call
+pub const MAX_LOG_RESULTS: usize = 10_000;
+
+// ═══════════════════════════════════════════════════════════════════════════════
+// RPC TRAIT DEFINITION
+// ═══════════════════════════════════════════════════════════════════════════════
+
+/// Ethereum RPC API for ËtwasmVM contracts
+#[rpc(client, server)]
+pub trait EthRpcApi {
+    /// EIP-155 chain ID
+    #[method(name = "eth_chainId")]
+    async fn chain_id(&self) -> RpcResult<U256>;
+
+    /// Number of the best block
+    #[method(name = "eth_blockNumber")]
+    async fn block_number(&self) -> RpcResult<U256>;
+
+    /// Ethereum nonce of an address
+    #[method(name = "eth_getTransactionCount")]
+    async fn transaction_count(&self, address: H160, block: Option<BlockNumberOrTag>) -> RpcResult<U256>;
+
+    /// Contract bytecode at an address
+    #[method(name = "eth_getCode")]
+    async fn code(&self, address: H160, block: Option<BlockNumberOrTag>) -> RpcResult<Bytes>;
+
+    /// Contract storage slot
+    #[method(name = "eth_getStorageAt")]
+    async fn storage_at(&self, address: H160, slot: U256, block: Option<BlockNumberOrTag>) -> RpcResult<H256>;
+
+    /// Dry-run a call and return its output
+    #[method(name = "eth_call")]
+    async fn call(&self, request: CallRequest, block: Option<BlockNumberOrTag>) -> RpcResult<Bytes>;
+
+    /// Dry-run a call and return the gas it used
+    #[method(name = "eth_estimateGas")]
+    async fn estimate_gas(&self, request: CallRequest, block: Option<BlockNumberOrTag>) -> RpcResult<U256>;
+
+    /// Submit a signed Ethereum transaction
+    #[method(name = "eth_sendRawTransaction")]
+    async fn send_raw_transaction(&self, transaction: Bytes) -> RpcResult<H256>;
+
+    /// Receipt of an included transaction
+    #[method(name = "eth_getTransactionReceipt")]
+    async fn transaction_receipt(&self, hash: H256) -> RpcResult<Option<TransactionReceipt>>;
+
+    /// Logs matching a filter
+    #[method(name = "eth_getLogs")]
+    async fn logs(&self, filter: Filter) -> RpcResult<Vec<Log>>;
+}
+
+// ═══════════════════════════════════════════════════════════════════════════════
+// REQUEST AND RESPONSE TYPES
+// ═══════════════════════════════════════════════════════════════════════════════
+
+/// Block selector: a number or one of the standard tags
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum BlockNumberOrTag {
+    #[default]
+    Latest,
+    Earliest,
+    Pending,
+    /// `finalized` and `safe`
+    Finalized,
+    Number(u32),
+}
+
+impl BlockNumberOrTag {
+    fn parse(value: &str) -> Option<Self> {
+        match value {
+            "latest" => Some(Self::Latest),
+            "earliest" => Some(Self::Earliest),
+            "pending" => Some(Self::Pending),
+            "finalized" | "safe" => Some(Self::Finalized),
+            _ => {
+                let digits = value.strip_prefix("0x")?;
+                u32::from_str_radix(digits, 16).ok().map(Self::Number)
+            }
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for BlockNumberOrTag {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = String::deserialize(deserializer)?;
+        Self::parse(&value)
+            .ok_or_else(|| serde::de::Error::custom(format!("invalid block number or tag: {}", value)))
+    }
+}
+
+/// `eth_call` / `eth_estimateGas` request
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CallRequest {
+    /// Caller, zero address if absent
+    pub from: Option<H160>,
+    /// Callee, `None` for contract creation
+    pub to: Option<H160>,
+    /// Gas limit, the runtime default if absent
+    pub gas: Option<U256>,
+    /// Call data (`input` takes precedence over `data`)
+    pub input: Option<Bytes>,
+    pub data: Option<Bytes>,
+    /// Must be zero if present
+    pub value: Option<U256>,
+}
+
+/// One value or a list of alternatives
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(untagged)]
+pub enum ValueOrArray<T> {
+    Value(T),
+    Array(Vec<T>),
+}
+
+impl<T: PartialEq> ValueOrArray<T> {
+    fn contains(&self, item: &T) -> bool {
+        self.any(|value| value == item)
+    }
+
+    /// Whether any alternative satisfies `f`; an empty list matches anything
+    fn any(&self, f: impl Fn(&T) -> bool) -> bool {
+        match self {
+            Self::Value(value) => f(value),
+            Self::Array(values) => values.is_empty() || values.iter().any(f),
+        }
+    }
+}
+
+/// `eth_getLogs` filter
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Filter {
+    pub from_block: Option<BlockNumberOrTag>,
+    pub to_block: Option<BlockNumberOrTag>,
+    /// Single block, exclusive with the range
+    pub block_hash: Option<H256>,
+    pub address: Option<ValueOrArray<H160>>,
+    /// Topic alternatives per position, `null` matches anything
+    pub topics: Option<Vec<Option<ValueOrArray<H256>>>>,
+}
+
+impl Filter {
+    /// Whether `log` matches the address and topic constraints
+    fn matches(&self, log: &Log) -> bool {
+        if let Some(address) = &self.address {
+            if !address.contains(&log.address) {
+                return false;
+            }
+        }
+        self.topics.iter().flatten().enumerate().all(|(position, wanted)| match wanted {
+            None => true,
+            Some(wanted) => log.topics.get(position).map_or(false, |topic| wanted.contains(topic)),
+        })
+    }
+
+    /// Whether a block or receipt with `bloom` may hold matching logs
+    fn may_match(&self, bloom: &EventBloomFilter) -> bool {
+        let address = self
+            .address
+            .as_ref()
+            .map_or(true, |address| address.any(|a| bloom.contains(a.as_bytes())));
+        address
+            && self.topics.iter().flatten().flatten().all(|wanted| {
+                wanted.any(|topic| bloom.contains(topic.as_bytes()))
+            })
+    }
+}
+
+/// Log with its position in the chain
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Log {
+    pub address: H160,
+    pub topics: Vec<H256>,
+    pub data: Bytes,
+    pub block_hash: H256,
+    pub block_number: U256,
+    pub transaction_hash: H256,
+    pub transaction_index: U256,
+    /// Index within the block
+    pub log_index: U256,
+    pub removed: bool,
+}
+
+/// Transaction receipt
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TransactionReceipt {
+    pub transaction_hash: H256,
+    pub transaction_index: U256,
+    pub block_hash: H256,
+    pub block_number: U256,
+    pub from: H160,
+    pub to: Option<H160>,
+    pub contract_address: Option<H160>,
+    pub gas_used: U256,
+    pub cumulative_gas_used: U256,
+    /// Always zero: gas is priced in millionths of the smallest ÉTR unit
+    pub effective_gas_price: U256,
+    pub logs: Vec<Log>,
+    pub logs_bloom: Bytes,
+    /// `0x1` on success, `0x0` on failure
+    pub status: U256,
+    #[serde(rename = "type")]
+    pub transaction_type: U256,
+}
+
+// ═══════════════════════════════════════════════════════════════════════════════
+// RPC IMPLEMENTATION
+// ═══════════════════════════════════════════════════════════════════════════════
+
+/// Ethereum RPC handler
+pub struct EthRpc<C, P> {
+    client: Arc<C>,
+    pool: Arc<P>,
+}
+
+impl<C, P> EthRpc<C, P> {
+    /// Create a new Ethereum RPC handler
+    pub fn new(client: Arc<C>, pool: Arc<P>) -> Self {
+        Self { client, pool }
+    }
+}
+
+fn internal_error(message: &str, error: impl std::fmt::Debug) -> ErrorObjectOwned {
+    ErrorObjectOwned::owned(-32000, message, Some(format!("{:?}", error)))
+}
+
+fn invalid_params(message: &str) -> ErrorObjectOwned {
+    ErrorObjectOwned::owned(-32602, message, None::<()>)
+}
+
+/// Logs of block receipts in RPC form, numbered across the block
+fn block_logs(receipts: &[EthReceipt], block_hash: H256, block_number: u32) -> Vec<Vec<Log>> {
+    let mut log_index = 0u32;
+    receipts
+        .iter()
+        .enumerate()
+        .map(|(transaction_index, receipt)| {
+            receipt
+                .logs
+                .iter()
+                .map(|log| {
+                    log_index += 1;
+                    Log {
+                        address: log.address,
+                        topics: log.topics.clone(),
+                        data: Bytes(log.data.clone()),
+                        block_hash,
+                        block_number: block_number.into(),
+                        transaction_hash: receipt.transaction_hash,
+                        transaction_index: transaction_index.into(),
+                        log_index: (log_index - 1).into(),
+                        removed: false,
+                    }
+                })
+                .collect()
+        })
+        .collect()
+}
+
+impl<C, P> EthRpc<C, P>
+where
+    C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + AuxStore,
+    C::Api: EtwasmEthApi<Block>,
+{
+    /// Hash of the selected block on the canonical chain
+    fn block_hash(&self, block: Option<BlockNumberOrTag>) -> RpcResult<H256> {
+        let info = self.client.info();
+        let number = match block.unwrap_or_default() {
+            BlockNumberOrTag::Latest | BlockNumberOrTag::Pending => return Ok(info.best_hash),
+            BlockNumberOrTag::Finalized => return Ok(info.finalized_hash),
+            BlockNumberOrTag::Earliest => 0,
+            BlockNumberOrTag::Number(number) => number,
+        };
+        self.client
+            .hash(number)
+            .map_err(|e| internal_error("Failed to load block hash", e))?
+            .ok_or_else(|| invalid_params("Unknown block"))
+    }
+
+    fn block_number(&self, block: Option<BlockNumberOrTag>) -> RpcResult<u32> {
+        let info = self.client.info();
+        match block.unwrap_or_default() {
+            BlockNumberOrTag::Latest | BlockNumberOrTag::Pending => Ok(info.best_number),
+            BlockNumberOrTag::Finalized => Ok(info.finalized_number),
+            BlockNumberOrTag::Earliest => Ok(0),
+            BlockNumberOrTag::Number(number) => Ok(number),
+        }
+    }
+
+    /// Dry-run `request` at `block`, failing on anything but success
+    fn dry_run(&self, request: CallRequest, block: Option<BlockNumberOrTag>) -> RpcResult<(u64, Vec<u8>)> {
+        let value = match request.value.unwrap_or_default() {
+            value if value > U256::from(u128::MAX) => return Err(invalid_params("Value too large")),
+            value => value.low_u128(),
+        };
+        let gas_limit = match request.gas {
+            Some(gas) if gas > U256::from(u64::MAX) => return Err(invalid_params("Gas limit too large")),
+            gas => gas.map(|gas| gas.low_u64()),
+        };
+        let data = request.input.or(request.data).map(|bytes| bytes.0).unwrap_or_default();
+
+        let at = self.block_hash(block)?;
+        let info = self
+            .client
+            .runtime_api()
+            .call(at, request.from.unwrap_or_default(), request.to, value, data, gas_limit)
+            .map_err(|e| internal_error("Failed to execute call", e))?;
+
+        match info.result {
+            ExecutionResult::Success { gas_used, return_data } => Ok((gas_used, return_data)),
+            // Revert data in `data`, as geth reports it
+            ExecutionResult::Revert { reason, .. } => Err(ErrorObjectOwned::owned(
+                3,
+                "execution reverted",
+                Some(format!("0x{}", hex::encode(reason))),
+            )),
+            ExecutionResult::OutOfGas { .. } => Err(ErrorObjectOwned::owned(-32000, "out of gas", None::<()>)),
+            other => Err(internal_error("execution failed", other)),
+        }
+    }
+
+    fn receipts(&self, block_hash: H256) -> RpcResult<Vec<EthReceipt>> {
+        if let Some(receipts) = crate::eth_log_index::load_receipts(&*self.client, &block_hash) {
+            return Ok(receipts);
+        }
+        self.client
+            .runtime_api()
+            .receipts(block_hash)
+            .map_err(|e| internal_error("Failed to load receipts", e))
+    }
+
+    /// Block logs bloom from the header digest, empty if the block has no logs
+    fn block_bloom(&self, block_hash: H256) -> RpcResult<EventBloomFilter> {
+        let header = self
+            .client
+            .header(block_hash)
+            .map_err(|e| internal_error("Failed to load header", e))?
+            .ok_or_else(|| invalid_params("Unknown block"))?;
+        Ok(find_logs_bloom(header.digest()).unwrap_or_default())
+    }
+
+    fn canonical_hash(&self, number: u32) -> RpcResult<Option<H256>> {
+        self.client.hash(number).map_err(|e| internal_error("Failed to load block hash", e))
+    }
+}
+
+#[async_trait]
+impl<C, P> EthRpcApiServer for EthRpc<C, P>
+where
+    C: Send + Sync + 'static,
+    C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + AuxStore,
+    C::Api: EtwasmEthApi<Block>,
+    P: TransactionPool<Block = Block> + 'static,
+{
+    async fn chain_id(&self) -> RpcResult<U256> {
+        let best_hash = self.client.info().best_hash;
+        self.client
+            .runtime_api()
+            .chain_id(best_hash)
+            .map(Into::into)
+            .map_err(|e| internal_error("Failed to query chain ID", e))
+    }
+
+    async fn block_number(&self) -> RpcResult<U256> {
+        Ok(self.client.info().best_number.into())
+    }
+
+    async fn transaction_count(&self, address: H160, block: Option<BlockNumberOrTag>) -> RpcResult<U256> {
+        let at = self.block_hash(block)?;
+        self.client
+            .runtime_api()
+            .transaction_count(at, address)
+            .map(Into::into)
+            .map_err(|e| internal_error("Failed to query nonce", e))
+    }
+
+    async fn code(&self, address: H160, block: Option<BlockNumberOrTag>) -> RpcResult<Bytes> {
+        let at = self.block_hash(block)?;
+        self.client
+            .runtime_api()
+            .code(at, address)
+            .map(Bytes)
+            .map_err(|e| internal_error("Failed to query code", e))
+    }
+
+    async fn storage_at(&self, address: H160, slot: U256, block: Option<BlockNumberOrTag>) -> RpcResult<H256> {
+        let at = self.block_hash(block)?;
+        self.client
+            .runtime_api()
+            .storage_at(at, address, H256(slot.to_big_endian()))
+            .map_err(|e| internal_error("Failed to query storage", e))
+    }
+
+    async fn call(&self, request: CallRequest, block: Option<BlockNumberOrTag>) -> RpcResult<Bytes> {
+        self.dry_run(request, block).map(|(_, output)| Bytes(output))
+    }
+
+    async fn estimate_gas(&self, request: CallRequest, block: Option<BlockNumberOrTag>) -> RpcResult<U256> {
+        self.dry_run(request, block).map(|(gas_used, _)| gas_used.into())
+    }
+
+    async fn send_raw_transaction(&self, transaction: Bytes) -> RpcResult<H256> {
+        let hash = H256(sp_core::hashing::keccak_256(&transaction.0));
+        let best_hash = self.client.info().best_hash;
+        let extrinsic = self
+            .client
+            .runtime_api()
+            .convert_transaction(best_hash, transaction.0)
+            .map_err(|e| internal_error("Failed to convert transaction", e))?;
+
+        self.pool
+            .submit_one(best_hash, TransactionSource::External, extrinsic)
+            .await
+            .map_err(|e| internal_error("Transaction rejected", e))?;
+        Ok(hash)
+    }
+
+    async fn transaction_receipt(&self, hash: H256) -> RpcResult<Option<TransactionReceipt>> {
+        let mut canonical = None;
+        for location in crate::eth_log_index::load_transaction(&*self.client, &hash) {
+            if self.canonical_hash(location.block_number)? == Some(location.block_hash) {
+                canonical = Some(location);
+                break;
+            }
+        }
+        let Some(TransactionLocation { block_number: number, block_hash, index }) = canonical else {
+            return Ok(None);
+        };
+        let receipts = self.receipts(block_hash)?;
+        let Some(receipt) = receipts.get(index as usize) else {
+            return Ok(None);
+        };
+
+        let cumulative_gas_used: u64 = receipts[..=index as usize].iter().map(|r| r.gas_used).sum();
+        let logs = block_logs(&receipts, block_hash, number).swap_remove(index as usize);
+        Ok(Some(TransactionReceipt {
+            transaction_hash: receipt.transaction_hash,
+            transaction_index: index.into(),
+            block_hash,
+            block_number: number.into(),
+            from: receipt.from,
+            to: receipt.to,
+            contract_address: receipt.contract_address,
+            gas_used: receipt.gas_used.into(),
+            cumulative_gas_used: cumulative_gas_used.into(),
+            effective_gas_price: U256::zero(),
+            logs,
+            logs_bloom: Bytes(receipt.logs_bloom.as_bytes().to_vec()),
+            status: (receipt.success as u8).into(),
+            transaction_type: U256::zero(),
+        }))
+    }
+
+    async fn logs(&self, filter: Filter) -> RpcResult<Vec<Log>> {
+        let blocks = match filter.block_hash {
+            Some(block_hash) => {
+                let number = self
+                    .client
+                    .number(block_hash)
+                    .map_err(|e| internal_error("Failed to load block number", e))?
+                    .ok_or_else(|| invalid_params("Unknown block"))?;
+                vec![(block_hash, number)]
+            }
+            None => {
+                let from = self.block_number(filter.from_block)?;
+                let to = self.block_number(filter.to_block)?.min(self.client.info().best_number);
+                if from > to {
+                    return Ok(Vec::new());
+                }
+                if to - from >= MAX_LOG_BLOCK_RANGE {
+                    return Err(invalid_params("Block range too large"));
+                }
+                let mut blocks = Vec::new();
+                for number in from..=to {
+                    if let Some(block_hash) = self.canonical_hash(number)? {
+                        blocks.push((block_hash, number));
+                    }
+                }
+                blocks
+            }
+        };
+
+        let mut logs = Vec::new();
+        for (block_hash, number) in blocks {
+            let bloom = self.block_bloom(block_hash)?;
+            if bloom.is_empty() || !filter.may_match(&bloom) {
+                continue;
+            }
+            let receipts = self.receipts(block_hash)?;
+            for (receipt, receipt_logs) in receipts.iter().zip(block_logs(&receipts, block_hash, number)) {
+                if filter.may_match(&receipt.logs_bloom) {
+                    logs.extend(receipt_logs.into_iter().filter(|log| filter.matches(log)));
+                }
+            }
+            if logs.len() > MAX_LOG_RESULTS {
+                return Err(invalid_params("Query returned too many results"));
+            }
+        }
+        Ok(logs)
+    }
+}
+
+/// Create Ethereum RPC module
+pub fn create_eth_rpc<C, P>(client: Arc<C>, pool: Arc<P>) -> jsonrpsee::RpcModule<EthRpc<C, P>>
+where
+    C: Send + Sync + 'static,
+    C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + AuxStore,
+    C::Api: EtwasmEthApi<Block>,
+    P: TransactionPool<Block = Block> + 'static,
+{
+    EthRpc::new(client, pool).into_rpc()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn log(address: u64, topics: &[u64]) -> EthLog {
+        EthLog {
+            address: H160::from_low_u64_be(address),
+            topics: topics.iter().map(|t| H256::from_low_u64_be(*t)).collect(),
+            data: Vec::new(),
+        }
+    }
+
+    fn rpc_log(address: u64, topics: &[u64]) -> Log {
+        let receipt = EthReceipt {
+            transaction_hash: H256::zero(),
+            from: H160::zero(),
+            to: None,
+            contract_address: None,
+            gas_used: 0,
+            success: true,
+            logs: vec![log(address, topics)],
+            logs_bloom: EventBloomFilter::new(),
+        };
+        block_logs(&[receipt], H256::zero(), 1).remove(0).remove(0)
+    }
+
+    #[test]
+    fn test_block_tag_parsing() {
+        let parse = |s: &str| serde_json::from_value::<BlockNumberOrTag>(serde_json::json!(s));
+        assert_eq!(parse("latest").unwrap(), BlockNumberOrTag::Latest);
+        assert_eq!(parse("safe").unwrap(), BlockNumberOrTag::Finalized);
+        assert_eq!(parse("0x1a").unwrap(), BlockNumberOrTag::Number(26));
+        assert!(parse("26").is_err());
+        assert!(parse("0x100000000").is_err());
+    }
+
+    #[test]
+    fn test_filter_matching() {
+        let filter: Filter = serde_json::from_value(serde_json::json!({
+            "address": [format!("{:?}", H160::from_low_u64_be(1))],
+            "topics": [null, [format!("{:?}", H256::from_low_u64_be(7)), format!("{:?}", H256::from_low_u64_be(8))]],
+        }))
+        .unwrap();
+
+        assert!(filter.matches(&rpc_log(1, &[3, 8])));
+        assert!(!filter.matches(&rpc_log(2, &[3, 8])));
+        assert!(!filter.matches(&rpc_log(1, &[3, 9])));
+        assert!(!filter.matches(&rpc_log(1, &[3])));
+        assert!(Filter::default().matches(&rpc_log(5, &[])));
+    }
+
+    #[test]
+    fn test_filter_bloom_skipping() {
+        let bloom = pallet_etwasm_vm::eth::logs_bloom(&[log(1, &[3, 8])]);
+        let filter = |value: serde_json::Value| serde_json::from_value::<Filter>(value).unwrap();
+        let address = |n: u64| format!("{:?}", H160::from_low_u64_be(n));
+        let topic = |n: u64| format!("{:?}", H256::from_low_u64_be(n));
+
+        assert!(Filter::default().may_match(&bloom));
+        assert!(filter(serde_json::json!({ "address": [address(2), address(1)] })).may_match(&bloom));
+        assert!(filter(serde_json::json!({ "topics": [null, [topic(7), topic(8)]] })).may_match(&bloom));
+        assert!(!filter(serde_json::json!({ "address": address(2) })).may_match(&bloom));
+        assert!(!filter(serde_json::json!({ "topics": [topic(9)] })).may_match(&bloom));
+    }
+
+    #[test]
+    fn test_block_logs_are_numbered_across_receipts() {
+        let receipt = |logs: Vec<EthLog>| EthReceipt {
+            transaction_hash: H256::repeat_byte(logs.len() as u8),
+            from: H160::zero(),
+            to: None,
+            contract_address: None,
+            gas_used: 0,
+            success: true,
+            logs,
+            logs_bloom: EventBloomFilter::new(),
+        };
+        let receipts = vec![receipt(vec![log(1, &[]), log(1, &[])]), receipt(vec![log(2, &[])])];
+
+        let logs = block_logs(&receipts, H256::zero(), 3);
+        assert_eq!(logs[1][0].log_index, U256::from(2));
+        assert_eq!(logs[1][0].transaction_index, U256::one());
+        assert_eq!(logs[1][0].transaction_hash, H256::repeat_byte(1));
+    }
+}
@@ -14,6 +14,12 @@ pub mod asf_service;
 /// ASF RPC endpoints for querying consensus state
 pub mod asf_rpc;
 
+/// Ethereum JSON-RPC endpoints for ËtwasmVM contracts
+pub mod eth_rpc;
+
+/// Persistent ËtwasmVM receipt and log index
+pub mod eth_log_index;
+
 /// ASF telemetry integration for consensus metrics
 pub mod asf_telemetry;
 
@@ -3,6 +3,9 @@ mod chain_spec;
 mod rpc;
 // mod service;  // Old Aura-based service - replaced by asf_service
 mod asf_service; // ASF consensus service integration
+mod asf_rpc; // ASF consensus RPC endpoints (finality proofs, committee queries)
+mod eth_rpc; // Ethereum JSON-RPC endpoints for ËtwasmVM contracts
+mod eth_log_index; // Persistent ËtwasmVM receipt and log index
 
 use clap::Parser;
 use sc_cli::SubstrateCli;
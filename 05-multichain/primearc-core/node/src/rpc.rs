@@ -17,6 +17,8 @@ pub struct FullDeps<C, P> {
     pub pool: Arc<P>,
     /// Enable ASF RPC endpoints
     pub enable_asf: bool,
+    /// Finalized checkpoint justifications (feeds `asf_subscribeJustifications`)
+    pub justification_tx: crate::asf_rpc::JustificationSender,
 }
 
 /// Instantiate all full RPC extensions
@@ -26,28 +28,33 @@ pub fn create_full<C, P>(
 where
     C: ProvideRuntimeApi<Block>,
     C: HeaderBackend<Block> + HeaderMetadata<Block, Error = BlockChainError> + 'static,
+    C: sc_client_api::BlockBackend<Block>,
+    C: sc_client_api::AuxStore,
     C: Send + Sync + 'static,
     C::Api: substrate_frame_rpc_system::AccountNonceApi<Block, AccountId, Nonce>,
     C::Api: pallet_transaction_payment_rpc::TransactionPaymentRuntimeApi<Block, Balance>,
     C::Api: pallet_validator_committee_runtime_api::ValidatorCommitteeApi<Block>,
+    C::Api: pallet_etwasm_vm::EtwasmEthApi<Block>,
     C::Api: BlockBuilder<Block>,
-    P: TransactionPool + 'static,
+    P: TransactionPool<Block = Block> + 'static,
 {
     use pallet_transaction_payment_rpc::{TransactionPayment, TransactionPaymentApiServer};
     use substrate_frame_rpc_system::{System, SystemApiServer};
 
     let mut module = RpcModule::new(());
-    let FullDeps { client, pool, enable_asf } = deps;
+    let FullDeps { client, pool, enable_asf, justification_tx } = deps;
 
     // Standard Substrate RPC
-    module.merge(System::new(client.clone(), pool).into_rpc())?;
+    module.merge(System::new(client.clone(), pool.clone()).into_rpc())?;
     module.merge(TransactionPayment::new(client.clone()).into_rpc())?;
 
+    // Ethereum JSON-RPC over ËtwasmVM contracts
+    module.merge(crate::eth_rpc::create_eth_rpc(client.clone(), pool))?;
+
     // ASF Consensus RPC (if enabled)
     if enable_asf {
-        log::info!("🔌 ASF RPC endpoints (temporarily disabled for compilation)");
-        // TODO: Re-enable once module path issue is resolved
-        // module.merge(crate::asf_rpc::create_asf_rpc(client.clone()))?;
+        log::info!("🔌 ASF RPC endpoints enabled (including asf_proveFinality)");
+        module.merge(crate::asf_rpc::create_asf_rpc(client.clone(), justification_tx))?;
     }
 
     Ok(module)
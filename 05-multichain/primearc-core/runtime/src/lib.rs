@@ -39,9 +39,9 @@ use frame_support::{
     construct_runtime, derive_impl,
     dispatch::DispatchClass,
     parameter_types,
-    traits::{ConstU128, ConstU16, ConstU32, ConstU64, ConstU8},
+    traits::{ConstU128, ConstU16, ConstU32, ConstU64, ConstU8, Get},
     weights::{
-        constants::{BlockExecutionWeight, ExtrinsicBaseWeight, WEIGHT_REF_TIME_PER_SECOND},
+        constants::{BlockExecutionWeight, ExtrinsicBaseWeight, RocksDbWeight, WEIGHT_REF_TIME_PER_SECOND},
         IdentityFee,
         Weight,
     },
@@ -179,10 +179,11 @@ pub mod migrations;
 /// We assume that ~10% of the block weight is consumed by `on_initialize` handlers.
 /// This is used to limit the maximal weight of a single extrinsic.
 const AVERAGE_ON_INITIALIZE_RATIO: sp_runtime::Perbill = sp_runtime::Perbill::from_percent(10);
-/// We allow for 2 seconds of compute with a 6 second average block time.
+/// We allow for 2 seconds of compute with a 6 second average block time, and cap the
+/// storage proof at 10 MiB so benchmarked proof sizes keep blocks within PoV limits.
 const MAXIMUM_BLOCK_WEIGHT: Weight = Weight::from_parts(
     WEIGHT_REF_TIME_PER_SECOND * 2,
-    u64::MAX,
+    10 * 1024 * 1024,
 );
 /// Maximum length of block.
 const MAXIMUM_BLOCK_LENGTH: u32 = 5 * 1024 * 1024;
@@ -228,7 +229,7 @@ impl frame_system::Config for Runtime {
     type Lookup = AccountIdLookup<AccountId, ()>;
     type Block = Block;
     type BlockHashCount = BlockHashCount;
-    type DbWeight = ();
+    type DbWeight = RocksDbWeight;
     type Version = Version;
     type AccountData = pallet_balances::AccountData<Balance>;
     type SystemWeightInfo = ();
@@ -327,6 +328,16 @@ impl frame_support::traits::OnUnbalanced<frame_support::traits::fungible::Credit
     }
 }
 
+/// Sends fees charged by the transaction processor to the treasury
+pub struct TxProcessorFeesToTreasury;
+impl frame_support::traits::OnUnbalanced<pallet_balances::NegativeImbalance<Runtime>> for TxProcessorFeesToTreasury {
+    fn on_nonzero_unbalanced(amount: pallet_balances::NegativeImbalance<Runtime>) {
+        use frame_support::traits::Currency;
+
+        Balances::resolve_creating(&EtridTreasury::account_id(), amount);
+    }
+}
+
 impl pallet_transaction_payment::Config for Runtime {
     type RuntimeEvent = RuntimeEvent;
     type OnChargeTransaction = FungibleAdapter<Balances, DealWithFees>;
@@ -387,12 +398,23 @@ impl pallet_insecure_randomness_collective_flip::Config for Runtime {}
 // ËTRID CUSTOM PALLETS CONFIGURATION
 // ========================================
 
+/// DID guardians for account recovery, resolved through the DID registry
+pub struct DidRecoveryGuardians;
+impl pallet_accounts::DidControllers<AccountId> for DidRecoveryGuardians {
+    fn is_controller(did_hash: &sp_core::H256, who: &AccountId) -> bool {
+        DidRegistry::is_owner_or_controller(*did_hash, who)
+    }
+}
+
 /// Configure the pallet-accounts (ETR/ETD token system)
 impl pallet_accounts::Config for Runtime {
     type RuntimeEvent = RuntimeEvent;
     type Balance = u64;
     type GovernanceOrigin = frame_system::EnsureRoot<AccountId>;
-    type WeightInfo = ();
+    type Dids = DidRecoveryGuardians;
+    type RecoveryDeposit = ConstU64<1_000_000_000_000_000_000>; // 1 ETR
+    type MinRecoveryDelay = ConstU32<{ DAYS }>; // Owner gets at least a day to veto
+    type WeightInfo = pallet_accounts::weights::SubstrateWeight<Runtime>;
 }
 
 parameter_types! {
@@ -409,12 +431,40 @@ impl pallet_etrid_staking::Config for Runtime {
     type ValidatorRewards = Runtime;
 }
 
+/// Routes ËtwasmVM gas fees to the treasury
+pub struct EtwasmFeeTreasury;
+impl pallet_etwasm_vm::FeeTreasury<Balance> for EtwasmFeeTreasury {
+    fn receive_transaction_fees(amount: Balance) -> sp_runtime::DispatchResult {
+        EtridTreasury::receive_transaction_fees(amount)
+    }
+}
+
+parameter_types! {
+    /// A block's worth of ËtwasmVM gas weighs half the block
+    pub EtwasmWeightPerGas: Weight = Weight::from_parts(
+        WEIGHT_REF_TIME_PER_SECOND / pallet_etwasm_vm::gas::VMW_BLOCK_LIMIT,
+        0,
+    );
+}
+
 /// Configure the pallet-etwasm-vm (smart contract execution)
 impl pallet_etwasm_vm::Config for Runtime {
-    type MaxCodeSize = ConstU32<1024>;
-    type DefaultGasLimit = ConstU64<10_000_000>; // 10 million gas default
-    type MaxGasLimit = ConstU64<100_000_000>; // 100 million gas max
+    type MaxCodeSize = ConstU32<24_576>; // EIP-170 contract size limit
+    type DefaultGasLimit = ConstU64<1_000_000>; // 1 million gas default (per-transaction VMw limit)
+    type MaxGasLimit = ConstU64<10_000_000>; // 10 million gas max (per-block VMw limit)
+    type WeightPerGas = EtwasmWeightPerGas;
     type VmwOperationPrice = ConstU32<1>; // VMW operation price (1 unit per operation)
+    type AddressMapper = pallet_etwasm_vm::AccountId32Mapper;
+    type ChainId = ConstU64<2>; // Ëtrid chain ID
+    type Currency = Balances;
+    type Time = Timestamp;
+    type Treasury = EtwasmFeeTreasury;
+    type UpgradeOrigin = frame_system::EnsureRoot<AccountId>;
+    type UpgradeDelay = ConstU32<{ DAYS }>; // 1 day notice before code swaps
+    type MaxCodeVersions = ConstU32<16>; // Rollback targets kept per contract
+    type MaxStorageSlots = ConstU32<1_024>; // Bounds the work of evicting a contract
+    type MaxRestoreEntries = ConstU32<1_024>; // Every tombstone fits one restore
+    type WeightInfo = pallet_etwasm_vm::weights::SubstrateWeight<Runtime>;
 }
 
 /// Configure the pallet-consensus (ASF consensus - Adaptive Scale of Finality)
@@ -438,6 +488,26 @@ impl pallet_governance::Config for Runtime {
     type ProposalDuration = ConstU64<604_800_000>; // 7 days in milliseconds
     type MinProposalStake = ConstU128<10_000_000_000_000>; // 10 ETR
     type GovernanceOrigin = frame_system::EnsureRoot<AccountId>;
+    type RuntimeCall = RuntimeCall;
+    type MaxPreimageSize = ConstU32<{ 4 * 1024 * 1024 }>; // Fits a runtime upgrade
+    type PreimageBaseDeposit = ConstU128<UNITS>; // 1 ETR
+    type PreimageByteDeposit = ConstU128<{ UNITS / 1_000_000 }>;
+    type EnactmentDelay = ConstU32<DAYS>; // 1 day to veto before dispatch
+    type MaxScheduledPerBlock = ConstU32<50>;
+    type VetoOrigin = frame_system::EnsureRoot<AccountId>;
+    type Delegation = VoteDelegation;
+    type MaxVotesPerProposal = ConstU32<512>;
+    type WeightInfo = pallet_governance::weights::SubstrateWeight<Runtime>;
+}
+
+/// Configure vote delegation shared by Governance and Consensus Day
+impl pallet_vote_delegation::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    type Currency = Balances;
+    type VoteLockingPeriod = ConstU32<{ 7 * DAYS }>; // Locked1x = 1 week
+    type Tracks = ConstU8<2>; // Governance, Consensus Day
+    type Hooks = (Governance, ConsensusDayPallet);
+    type WeightInfo = pallet_vote_delegation::weights::SubstrateWeight<Runtime>;
 }
 
 /// Configure the PBC Router (Partition Burst Chain routing)
@@ -467,11 +537,13 @@ parameter_types! {
 impl consensus_day_proposal_system::Config for Runtime {
     type Currency = Balances;
     type RegistrationDeposit = ConsensusDayRegistrationDeposit;
+    type WeightInfo = consensus_day_proposal_system::weights::SubstrateWeight<Runtime>;
 }
 
 /// Configure Consensus Day Voting Protocol
 impl consensus_day_voting_protocol::Config for Runtime {
     type RuntimeEvent = RuntimeEvent;
+    type WeightInfo = consensus_day_voting_protocol::weights::SubstrateWeight<Runtime>;
 }
 
 /// Configure Consensus Day Distribution
@@ -482,6 +554,7 @@ impl consensus_day_distribution::Config for Runtime {
     type Directors = DirectorAccounts;
     type Validators = ValidatorAccounts;
     type Voters = VoterAccounts;
+    type WeightInfo = consensus_day_distribution::weights::SubstrateWeight<Runtime>;
 }
 
 /// Configure Consensus Day Minting Logic
@@ -490,6 +563,7 @@ impl consensus_day_minting_logic::Config for Runtime {
     type RuntimeEvent = RuntimeEvent;
     type TreasuryAccount = FoundationTreasuryAccount;
     type AnnualMintCapPercent = AnnualMintCapPercent;
+    type WeightInfo = consensus_day_minting_logic::weights::SubstrateWeight<Runtime>;
 }
 
 // ========================================
@@ -499,6 +573,10 @@ impl consensus_day_minting_logic::Config for Runtime {
 /// Configure Transaction Processor
 impl pallet_tx_processor::Config for Runtime {
     type RuntimeEvent = RuntimeEvent;
+    type Currency = Balances;
+    type OnFeeCharged = TxProcessorFeesToTreasury;
+    type Priority = pallet_tx_processor::FeePerWeight;
+    type WeightInfo = pallet_tx_processor::weights::SubstrateWeight<Runtime>;
 }
 
 // ========================================
@@ -534,6 +612,7 @@ impl pallet_etr_lock::Config for Runtime {
     type BridgeOrigin = EnsureRoot<AccountId>; // Foundation multisig in production
     type MaxLockAmount = ConstU128<250_000_000_000_000_000_000_000_000>; // 250M ETR max lock
     type LockIdentifier = EtrLockId;
+    type WeightInfo = pallet_etr_lock::weights::SubstrateWeight<Runtime>;
 }
 
 /// Configure Bitcoin Bridge
@@ -545,6 +624,7 @@ impl pallet_bitcoin_bridge::Config for Runtime {
     type MaxDepositAmount = ConstU64<100_000_000_000>; // 1000 BTC in satoshis
     type BridgeAuthority = BitcoinBridgeAuthority;
     type ValidatorPoolAccount = BtcValidatorPoolAccount;
+    type WeightInfo = pallet_bitcoin_bridge::weights::SubstrateWeight<Runtime>;
 }
 
 /// Configure Ethereum Bridge
@@ -558,6 +638,7 @@ impl eth_bridge::Config for Runtime {
     type MaxDepositsPerAccount = ConstU32<100>;
     type MaxWithdrawalsPerAccount = ConstU32<100>;
     type ValidatorPoolAccount = EthValidatorPoolAccount;
+    type WeightInfo = eth_bridge::weights::SubstrateWeight<Runtime>;
 }
 
 /// Configure Dogecoin Bridge
@@ -570,6 +651,7 @@ impl pallet_doge_bridge::Config for Runtime {
     type PalletId = DogeBridgePalletId;
     type DogeConfirmations = ConstU32<6>;
     type DogeConversionRate = ConstU64<1000>; // 1 DOGE = 0.001 ETR
+    type WeightInfo = pallet_doge_bridge::weights::SubstrateWeight<Runtime>;
 }
 
 /// Configure Stellar (XLM) Bridge
@@ -580,6 +662,7 @@ impl stellar_bridge::Config for Runtime {
     type BridgeFeeRate = ConstU32<10>; // 0.1%
     type MaxDepositsPerAccount = ConstU32<100>;
     type MaxWithdrawalsPerAccount = ConstU32<100>;
+    type WeightInfo = stellar_bridge::weights::SubstrateWeight<Runtime>;
 }
 
 /// Configure XRP Bridge
@@ -590,6 +673,7 @@ impl xrp_bridge::Config for Runtime {
     type MaxFeeDrops = ConstU64<1_000_000>; // 1 XRP
     type MaxDepositsPerAccount = ConstU32<100>;
     type MaxWithdrawalsPerAccount = ConstU32<100>;
+    type WeightInfo = xrp_bridge::weights::SubstrateWeight<Runtime>;
 }
 
 /// Configure Solana Bridge
@@ -603,6 +687,7 @@ impl sol_bridge::Config for Runtime {
     type MaxDepositsPerAccount = ConstU32<100>;
     type MaxWithdrawalsPerAccount = ConstU32<100>;
     type ValidatorPoolAccount = SolValidatorPoolAccount;
+    type WeightInfo = sol_bridge::weights::SubstrateWeight<Runtime>;
 }
 
 /// Configure Cardano (ADA) Bridge
@@ -613,6 +698,7 @@ impl pallet_cardano_bridge::Config for Runtime {
     type MinDepositAmount = ConstU64<1_000_000>; // 1 ADA in lovelaces
     type MaxDepositAmount = ConstU64<1_000_000_000_000>; // 1,000,000 ADA
     type BridgeAuthority = CardanoBridgeAuthority;
+    type WeightInfo = pallet_cardano_bridge::weights::SubstrateWeight<Runtime>;
 }
 
 /// Configure Chainlink (LINK) Bridge
@@ -625,6 +711,7 @@ impl chainlink_bridge::Config for Runtime {
     type MaxDataFeeds = ConstU32<1000>;
     type MaxVRFRequests = ConstU32<1000>;
     type PriceStalenessThreshold = ConstU32<100>; // 100 blocks
+    type WeightInfo = chainlink_bridge::weights::SubstrateWeight<Runtime>;
 }
 
 /// Configure Polygon (MATIC) Bridge
@@ -637,6 +724,7 @@ impl polygon_bridge::Config for Runtime {
     type MaxDepositsPerAccount = ConstU32<100>;
     type MaxWithdrawalsPerAccount = ConstU32<100>;
     type PalletId = PolygonBridgePalletId;
+    type WeightInfo = polygon_bridge::weights::SubstrateWeight<Runtime>;
 }
 
 /// Configure BNB Bridge
@@ -648,6 +736,7 @@ impl bnb_bridge::Config for Runtime {
     type MaxGasPrice = ConstU128<100_000_000_000>; // 100 Gwei
     type MaxDepositsPerAccount = ConstU32<100>;
     type MaxWithdrawalsPerAccount = ConstU32<100>;
+    type WeightInfo = bnb_bridge::weights::SubstrateWeight<Runtime>;
 }
 
 /// Configure Tron (TRX) Bridge
@@ -660,6 +749,7 @@ impl trx_bridge::Config for Runtime {
     type MaxBandwidth = ConstU64<10_000_000>;
     type MaxDepositsPerAccount = ConstU32<100>;
     type MaxWithdrawalsPerAccount = ConstU32<100>;
+    type WeightInfo = trx_bridge::weights::SubstrateWeight<Runtime>;
 }
 
 /// Configure USDT Stablecoin Bridge
@@ -670,6 +760,7 @@ impl stablecoin_usdt_bridge::Config for Runtime {
     type MaxDepositsPerAccount = ConstU32<100>;
     type MaxWithdrawalsPerAccount = ConstU32<100>;
     type MaxCustodians = ConstU32<10>; // Maximum 10 custodians for M-of-N multisig
+    type WeightInfo = stablecoin_usdt_bridge::weights::SubstrateWeight<Runtime>;
 }
 
 // ========================================
@@ -757,6 +848,7 @@ impl pallet_reserve_vault::Config for Runtime {
     type OptimalReserveMax = OptimalReserveMax;
     type ThrottleReserveRatio = ThrottleReserveRatio;
     type EmergencyReserveRatio = EmergencyReserveRatio;
+    type PriceFeed = OracleNetwork;
 }
 
 parameter_types! {
@@ -849,6 +941,13 @@ parameter_types! {
     pub const OracleReward: Balance = 10_000_000_000_000_000_000; // 10 ETR per submission
     pub const MaxOracles: u32 = 1000; // Maximum 1000 oracles
     pub const MaxDataRequests: u32 = 10000; // Maximum 10000 data requests
+    pub const MaxOracleFeeds: u32 = 64; // Maximum 64 registered price feeds
+    pub const MaxOracleFeedSources: u32 = 8; // HTTP sources per feed
+    pub const OracleFetchInterval: BlockNumber = MINUTES; // Fetch each feed every minute
+    pub const OracleMaxFetchBackoff: BlockNumber = HOURS; // Retry failing feeds at least hourly
+    pub const OracleUnsignedPriority: sp_runtime::transaction_validity::TransactionPriority = 1 << 20;
+    pub const OracleSubmission: pallet_oracle_network::SubmissionMode =
+        pallet_oracle_network::SubmissionMode::Signed;
 }
 
 /// Configure Oracle Network Pallet
@@ -863,6 +962,79 @@ impl pallet_oracle_network::Config for Runtime {
     type SubmissionReward = OracleReward;
     type MaxOracles = MaxOracles;
     type MaxDataRequests = MaxDataRequests;
+    type MaxFeeds = MaxOracleFeeds;
+    type AuthorityId = pallet_oracle_network::crypto::OracleAuthId;
+    type OffchainSubmission = OracleSubmission;
+    type MaxFeedSources = MaxOracleFeedSources;
+    type FetchInterval = OracleFetchInterval;
+    type MaxFetchBackoff = OracleMaxFetchBackoff;
+    type UnsignedPriority = OracleUnsignedPriority;
+    type WeightInfo = pallet_oracle_network::weights::SubstrateWeight<Runtime>;
+}
+
+// Offchain transaction creation, used by the oracle network's price-fetching worker
+
+impl frame_system::offchain::SigningTypes for Runtime {
+    type Public = <Signature as Verify>::Signer;
+    type Signature = Signature;
+}
+
+impl<LocalCall> frame_system::offchain::CreateTransactionBase<LocalCall> for Runtime
+where
+    RuntimeCall: From<LocalCall>,
+{
+    type RuntimeCall = RuntimeCall;
+    type Extrinsic = UncheckedExtrinsic;
+}
+
+impl<LocalCall> frame_system::offchain::CreateSignedTransaction<LocalCall> for Runtime
+where
+    RuntimeCall: From<LocalCall>,
+{
+    fn create_signed_transaction<
+        C: frame_system::offchain::AppCrypto<Self::Public, Self::Signature>,
+    >(
+        call: RuntimeCall,
+        public: <Signature as Verify>::Signer,
+        account: AccountId,
+        nonce: Nonce,
+    ) -> Option<UncheckedExtrinsic> {
+        use sp_runtime::{traits::StaticLookup, SaturatedConversion};
+
+        let period = BlockHashCount::get()
+            .checked_next_power_of_two()
+            .map(|c| c / 2)
+            .unwrap_or(2) as u64;
+        let current_block = System::block_number()
+            .saturated_into::<u64>()
+            // The `System::block_number` is initialized with `n+1`,
+            // so the actual block number is `n`.
+            .saturating_sub(1);
+        let extra: SignedExtra = (
+            frame_system::CheckNonZeroSender::<Runtime>::new(),
+            frame_system::CheckSpecVersion::<Runtime>::new(),
+            frame_system::CheckTxVersion::<Runtime>::new(),
+            frame_system::CheckGenesis::<Runtime>::new(),
+            frame_system::CheckEra::<Runtime>::from(generic::Era::mortal(period, current_block)),
+            frame_system::CheckNonce::<Runtime>::from(nonce),
+            frame_system::CheckWeight::<Runtime>::new(),
+            pallet_transaction_payment::ChargeTransactionPayment::<Runtime>::from(0),
+        );
+        let raw_payload = SignedPayload::new(call, extra).ok()?;
+        let signature = raw_payload.using_encoded(|payload| C::sign(payload, public))?;
+        let address = <Runtime as frame_system::Config>::Lookup::unlookup(account);
+        let (call, extra, _) = raw_payload.deconstruct();
+        Some(UncheckedExtrinsic::new_signed(call, address, signature, extra))
+    }
+}
+
+impl<LocalCall> frame_system::offchain::CreateBare<LocalCall> for Runtime
+where
+    RuntimeCall: From<LocalCall>,
+{
+    fn create_bare(call: RuntimeCall) -> UncheckedExtrinsic {
+        UncheckedExtrinsic::new_bare(call)
+    }
 }
 
 // ========================================
@@ -873,6 +1045,9 @@ impl pallet_oracle_network::Config for Runtime {
 impl pallet_did_registry::Config for Runtime {
     type RuntimeEvent = RuntimeEvent;
     type MaxAccessControlEntries = ConstU32<100>;
+    type MaxStatusListBytes = ConstU32<16_384>; // 131,072 credentials per list
+    type MaxVersionHistory = ConstU32<32>;
+    type WeightInfo = pallet_did_registry::weights::SubstrateWeight<Runtime>;
 }
 
 /// Configure AIDID Pallet (World's First AI DID Standard)
@@ -999,7 +1174,7 @@ impl pallet_treasury_etrid::Config for Runtime {
     type ApprovalThreshold = TreasuryApprovalThreshold;
     type EmergencyThreshold = TreasuryEmergencyThreshold;
     type ProposalExpiration = TreasuryProposalExpiration;
-    type WeightInfo = ();
+    type WeightInfo = pallet_treasury_etrid::weights::SubstrateWeight<Runtime>;
 }
 
 // ═══════════════════════════════════════════════════════════════════════════════
@@ -1062,18 +1237,40 @@ impl pallet_consensus_day::TreasuryInterface<AccountId, Balance> for ConsensusDa
 
         Ok(())
     }
+
+    fn set_directors(directors: sp_std::vec::Vec<AccountId>) -> DispatchResult {
+        // Remove outgoing directors first so the 9-seat bound is never exceeded
+        for current in pallet_treasury_etrid::Directors::<Runtime>::get().into_iter() {
+            if !directors.contains(&current) {
+                EtridTreasury::remove_director(frame_system::RawOrigin::Root.into(), current)?;
+            }
+        }
+
+        for director in directors {
+            if !EtridTreasury::is_director(&director) {
+                EtridTreasury::add_director(frame_system::RawOrigin::Root.into(), director)?;
+            }
+        }
+
+        Ok(())
+    }
 }
 
 parameter_types! {
     pub const ConsensusRegistrationDuration: u32 = 3_600; // 6 hours at 6s blocks
     pub const ConsensusVotingDuration: u32 = 7_200; // 12 hours
     pub const ConsensusMintingDuration: u32 = 1_800; // 3 hours
+    pub const ConsensusRevealDuration: u32 = 1_200; // 2 hours
     pub const ConsensusDistributionDuration: u32 = 600; // 1 hour
     pub const ConsensusProposalBond: Balance = 10_000 * UNITS; // 10,000 ETR
     pub const ConsensusDirectorMinStake: Balance = 128 * UNITS; // 128 ETR
     pub const ConsensusMaxInflationBps: u32 = 500; // 5% max inflation
     pub const ConsensusMaxProposals: u32 = 100;
     pub const ConsensusMaxTitleLength: u32 = 100;
+    pub const ConsensusDirectorBalancingIterations: u32 = 10;
+    pub const ConsensusMaxVoters: u32 = 1_000;
+    pub const ConsensusMaxDirectorCandidates: u32 = 100;
+    pub const ConsensusMaxCommitments: u32 = 2_000;
 }
 
 impl pallet_consensus_day::Config for Runtime {
@@ -1083,12 +1280,19 @@ impl pallet_consensus_day::Config for Runtime {
     type RegistrationDuration = ConsensusRegistrationDuration;
     type VotingDuration = ConsensusVotingDuration;
     type MintingDuration = ConsensusMintingDuration;
+    type RevealDuration = ConsensusRevealDuration;
     type DistributionDuration = ConsensusDistributionDuration;
     type ProposalBond = ConsensusProposalBond;
     type DirectorMinStake = ConsensusDirectorMinStake;
     type MaxInflationBps = ConsensusMaxInflationBps;
     type MaxProposals = ConsensusMaxProposals;
     type MaxTitleLength = ConsensusMaxTitleLength;
+    type DirectorBalancingIterations = ConsensusDirectorBalancingIterations;
+    type Delegation = VoteDelegation;
+    type MaxVoters = ConsensusMaxVoters;
+    type MaxDirectorCandidates = ConsensusMaxDirectorCandidates;
+    type MaxCommitments = ConsensusMaxCommitments;
+    type WeightInfo = pallet_consensus_day::weights::SubstrateWeight<Runtime>;
 }
 
 // ═══════════════════════════════════════════════════════════════════════════════
@@ -1114,12 +1318,25 @@ impl pallet_edsc_stability::TreasuryInterface<AccountId, Balance> for EdscStabil
 
         Ok(())
     }
+
+    fn receive_edsc_penalties(amount: u128) -> Result<(), sp_runtime::DispatchError> {
+        EtridTreasury::receive_edsc_penalties(amount)
+    }
+
+    fn cover_bad_debt(amount: u128) -> u128 {
+        EtridTreasury::cover_edsc_bad_debt(amount)
+    }
 }
 
 parameter_types! {
     pub const MinCollateralRatio: u16 = 15000; // 150%
     pub const LiquidationThreshold: u16 = 12000; // 120%
     pub const LiquidationPenalty: u16 = 500; // 5%
+    pub const LiquidationTargetRatio: u16 = 16000; // 160%
+    pub const AuctionStartPrice: u16 = 12000; // 120% of collateral value
+    pub const AuctionFloorPrice: u16 = 5000; // 50% of collateral value
+    pub const AuctionDuration: u32 = HOURS; // price falls to the floor over an hour
+    pub const KeeperReward: u16 = 50; // 0.5% of the lot
     pub const StabilityRebalanceThreshold: u16 = 500; // 5%
     pub const EmergencyPauseThreshold: u16 = 1000; // 10%
     pub const MinEDSCMint: u128 = 100 * UNITS; // 100 EDSC minimum
@@ -1133,6 +1350,11 @@ impl pallet_edsc_stability::Config for Runtime {
     type MinCollateralRatio = MinCollateralRatio;
     type LiquidationThreshold = LiquidationThreshold;
     type LiquidationPenalty = LiquidationPenalty;
+    type LiquidationTargetRatio = LiquidationTargetRatio;
+    type AuctionStartPrice = AuctionStartPrice;
+    type AuctionFloorPrice = AuctionFloorPrice;
+    type AuctionDuration = AuctionDuration;
+    type KeeperReward = KeeperReward;
     type RebalanceThreshold = StabilityRebalanceThreshold;
     type EmergencyPauseThreshold = EmergencyPauseThreshold;
     type MinEDSCMint = MinEDSCMint;
@@ -1140,7 +1362,8 @@ impl pallet_edsc_stability::Config for Runtime {
     type PalletId = EDSCPalletId;
     type TreasuryAccount = FoundationTreasuryAccount;
     type Treasury = EdscStabilityTreasuryInterface;
-    type WeightInfo = ();
+    type PriceFeed = OracleNetwork;
+    type WeightInfo = pallet_edsc_stability::weights::SubstrateWeight<Runtime>;
 }
 
 // ═══════════════════════════════════════════════════════════════════════════════
@@ -1287,6 +1510,9 @@ construct_runtime!(
         ConsensusDayPallet: pallet_consensus_day,
         EdscStability: pallet_edsc_stability,
         CircuitBreaker: pallet_circuit_breaker,
+
+        // Vote delegation (Governance + Consensus Day)
+        VoteDelegation: pallet_vote_delegation,
     }
 );
 
@@ -1703,6 +1929,104 @@ impl_runtime_apis! {
     // - Cross-chain coordination via XCM
     // ═══════════════════════════════════════════════════════════════════════════════
 
+    // ═══════════════════════════════════════════════════════════════════════════════
+    // ËTWASM CONTRACT & ETHEREUM APIS
+    // ═══════════════════════════════════════════════════════════════════════════════
+    // Contract code versions and upgrades, and the node's eth_* facade over
+    // ËtwasmVM contracts (eth_rpc.rs). Full EVM compatibility remains on ETH-PBC.
+    // ═══════════════════════════════════════════════════════════════════════════════
+
+    impl pallet_etwasm_vm::EtwasmContractsApi<Block, AccountId, BlockNumber> for Runtime {
+        fn code_history(
+            contract: AccountId,
+        ) -> Vec<pallet_etwasm_vm::CodeVersion<sp_core::H256, BlockNumber>> {
+            EtwasmVM::code_history(&contract)
+        }
+
+        fn pending_upgrade(
+            contract: AccountId,
+        ) -> Option<pallet_etwasm_vm::PendingUpgrade<sp_core::H256, BlockNumber>> {
+            EtwasmVM::pending_upgrade(&contract)
+        }
+    }
+
+    impl pallet_etwasm_vm::EtwasmEthApi<Block> for Runtime {
+        fn chain_id() -> u64 {
+            <Runtime as pallet_etwasm_vm::Config>::ChainId::get()
+        }
+
+        fn transaction_count(address: sp_core::H160) -> u64 {
+            EtwasmVM::eth_nonce(address)
+        }
+
+        fn code(address: sp_core::H160) -> Vec<u8> {
+            EtwasmVM::code_at(address)
+        }
+
+        fn storage_at(address: sp_core::H160, key: sp_core::H256) -> sp_core::H256 {
+            EtwasmVM::storage_at(address, key)
+        }
+
+        fn call(
+            from: sp_core::H160,
+            to: Option<sp_core::H160>,
+            value: u128,
+            data: Vec<u8>,
+            gas_limit: Option<u64>,
+        ) -> pallet_etwasm_vm::EthCallInfo {
+            EtwasmVM::eth_call(from, to, value, data, gas_limit)
+        }
+
+        fn receipts() -> Vec<pallet_etwasm_vm::EthReceipt> {
+            EtwasmVM::current_receipts()
+        }
+
+        fn convert_transaction(transaction: Vec<u8>) -> <Block as BlockT>::Extrinsic {
+            UncheckedExtrinsic::new_bare(
+                pallet_etwasm_vm::Call::<Runtime>::transact { transaction }.into(),
+            )
+        }
+    }
+
+    #[cfg(feature = "runtime-benchmarks")]
+    impl frame_benchmarking::Benchmark<Block> for Runtime {
+        fn benchmark_metadata(extra: bool) -> (
+            Vec<frame_benchmarking::BenchmarkList>,
+            Vec<frame_support::traits::StorageInfo>,
+        ) {
+            use frame_benchmarking::BenchmarkList;
+            use frame_support::traits::StorageInfoTrait;
+            use frame_system_benchmarking::Pallet as SystemBench;
+
+            let mut list = Vec::<BenchmarkList>::new();
+            list_benchmarks!(list, extra);
+
+            let storage_info = AllPalletsWithSystem::storage_info();
+
+            (list, storage_info)
+        }
+
+        #[allow(non_local_definitions)]
+        fn dispatch_benchmark(
+            config: frame_benchmarking::BenchmarkConfig
+        ) -> Result<Vec<frame_benchmarking::BenchmarkBatch>, sp_std::string::String> {
+            use frame_benchmarking::BenchmarkBatch;
+            use frame_support::traits::WhitelistedStorageKeys;
+            use frame_system_benchmarking::Pallet as SystemBench;
+            use sp_storage::TrackedStorageKey;
+
+            impl frame_system_benchmarking::Config for Runtime {}
+
+            let whitelist: Vec<TrackedStorageKey> = AllPalletsWithSystem::whitelisted_storage_keys();
+
+            let mut batches = Vec::<BenchmarkBatch>::new();
+            let params = (&config, &whitelist);
+            add_benchmarks!(params, batches);
+
+            Ok(batches)
+        }
+    }
+
     #[cfg(feature = "try-runtime")]
     impl frame_try_runtime::TryRuntime<Block> for Runtime {
         fn on_runtime_upgrade(checks: frame_try_runtime::UpgradeCheckSelect) -> (Weight, Weight) {
@@ -1730,7 +2054,31 @@ frame_benchmarking::define_benchmarks!(
     [pallet_balances, Balances]
     [pallet_timestamp, Timestamp]
     [pallet_accounts, Accounts]
+    [pallet_did_registry, DidRegistry]
     [pallet_consensus, Consensus]
     [pallet_governance, Governance]
+    [pallet_vote_delegation, VoteDelegation]
     [pallet_treasury_etrid, EtridTreasury]
+    [pallet_tx_processor, TxProcessor]
+    [pallet_etwasm_vm, EtwasmVM]
+    [pallet_consensus_day, ConsensusDayPallet]
+    [consensus_day_proposal_system, ConsensusDayProposalSystem]
+    [consensus_day_voting_protocol, ConsensusDayVotingProtocol]
+    [consensus_day_distribution, ConsensusDayDistribution]
+    [consensus_day_minting_logic, ConsensusDayMintingLogic]
+    [pallet_edsc_stability, EdscStability]
+    [pallet_etr_lock, EtrLock]
+    [pallet_oracle_network, OracleNetwork]
+    [pallet_bitcoin_bridge, BitcoinBridge]
+    [eth_bridge, EthereumBridge]
+    [pallet_doge_bridge, DogeBridge]
+    [stellar_bridge, StellarBridge]
+    [xrp_bridge, XrpBridge]
+    [sol_bridge, SolanaBridge]
+    [pallet_cardano_bridge, CardanoBridge]
+    [chainlink_bridge, ChainlinkBridge]
+    [polygon_bridge, PolygonBridge]
+    [bnb_bridge, BnbBridge]
+    [trx_bridge, TronBridge]
+    [stablecoin_usdt_bridge, UsdtBridge]
 );
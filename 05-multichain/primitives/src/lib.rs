@@ -11,6 +11,7 @@
 //! - VMw (VM Watts) computation metering
 //! - FlareChain block structures
 //! - PBC block structures with Ants support
+//! - Cross-PBC message Merkle commitments
 
 use codec::{Decode, Encode};
 use scale_info::TypeInfo;
@@ -36,6 +37,9 @@ pub mod flare_chain_blocks;
 /// PBC (Partitioned Burst Chain) block structures
 pub mod pbc_blocks;
 
+/// Cross-PBC message commitments and Merkle inclusion proofs
+pub mod message_proofs;
+
 // ═══════════════════════════════════════════════════════════════════════════
 // RE-EXPORTS
 // ═══════════════════════════════════════════════════════════════════════════
@@ -55,6 +59,12 @@ pub use pbc_blocks::{
     MAX_ANTS_DEPTH, MAX_ANTS_PER_BLOCK,
 };
 
+// Re-export cross-PBC messaging proof types
+pub use message_proofs::{
+    merkle_proof, merkle_root, verify_merkle_proof, FinalizedPbcHead, MessageDeliveryProof,
+    MessageInclusionProof, OutboundMessage,
+};
+
 // ═══════════════════════════════════════════════════════════════════════════
 // CORE TYPES
 // ═══════════════════════════════════════════════════════════════════════════
@@ -0,0 +1,248 @@
+//! Cross-PBC Message Commitments and Inclusion Proofs
+//!
+//! Every PBC commits the messages it sends in a block to a binary Merkle root
+//! (Blake2-256) kept in its state. FlareChain records the finalized head of each
+//! PBC, and a receiving PBC accepts a message only with a storage proof of that
+//! root against the state root of a finalized head, plus an inclusion proof of
+//! the message under the root.
+//!
+//! Tree construction:
+//! - Leaves are `blake2_256(SCALE(OutboundMessage))`
+//! - Inner nodes are `blake2_256(left || right)`
+//! - An odd node at the end of a level is promoted unchanged
+//! - The root of an empty tree is `Hash::zero()`
+
+use codec::{Decode, Encode, MaxEncodedLen};
+use scale_info::TypeInfo;
+use sp_core::hashing::blake2_256;
+use sp_runtime::RuntimeDebug;
+use sp_std::vec::Vec;
+
+#[cfg(feature = "std")]
+use serde::{Deserialize, Serialize};
+
+use crate::{BlockNumber, Hash};
+
+/// Maximum Merkle proof depth (supports 2^16 messages per block)
+pub const MAX_PROOF_DEPTH: u32 = 16;
+
+/// A message committed in a PBC's outbound message root
+///
+/// Only the payload hash is committed; the payload travels with the proof.
+#[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct OutboundMessage {
+    /// Source chain ID (0-12)
+    pub source: u8,
+    /// Destination chain ID (0-12)
+    pub destination: u8,
+    /// Per-channel (source, destination) nonce, starting at 0
+    pub nonce: u64,
+    /// Blake2-256 hash of the payload
+    pub payload_hash: Hash,
+}
+
+impl OutboundMessage {
+    /// Leaf hash committed in the outbound message tree
+    pub fn leaf_hash(&self) -> Hash {
+        Hash::from(blake2_256(&self.encode()))
+    }
+}
+
+/// Finalized head of a PBC as recorded on FlareChain
+#[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct FinalizedPbcHead {
+    /// PBC chain ID
+    pub chain_id: u8,
+    /// PBC block number
+    pub block_number: BlockNumber,
+    /// PBC block hash
+    pub block_hash: Hash,
+    /// PBC state root (holds the outbound message roots of this and earlier blocks)
+    pub state_root: Hash,
+}
+
+/// Inclusion proof of a leaf in an outbound message tree
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct MessageInclusionProof {
+    /// Index of the leaf in the block's outbound messages
+    pub leaf_index: u32,
+    /// Total number of leaves in the tree
+    pub leaf_count: u32,
+    /// Sibling hashes from leaf level up to the root (promoted levels are skipped)
+    pub siblings: Vec<Hash>,
+}
+
+/// Proof that a message is committed by a finalized head of its source chain
+///
+/// Outbound message roots stay in the source chain's state, so any finalized
+/// head at or after `source_block` can prove them.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct MessageDeliveryProof {
+    /// Source block that sent the message
+    pub source_block: BlockNumber,
+    /// Finalized source head whose state root the storage proof is checked against
+    pub finalized_block: BlockNumber,
+    /// Trie nodes proving the outbound message root of `source_block` in that state
+    pub root_proof: Vec<Vec<u8>>,
+    /// Inclusion proof of the message under that root
+    pub inclusion: MessageInclusionProof,
+}
+
+fn hash_pair(left: &Hash, right: &Hash) -> Hash {
+    let mut data = [0u8; 64];
+    data[..32].copy_from_slice(left.as_bytes());
+    data[32..].copy_from_slice(right.as_bytes());
+    Hash::from(blake2_256(&data))
+}
+
+/// Compute the Merkle root over leaf hashes
+pub fn merkle_root(leaves: &[Hash]) -> Hash {
+    if leaves.is_empty() {
+        return Hash::zero();
+    }
+
+    let mut level: Vec<Hash> = leaves.to_vec();
+    while level.len() > 1 {
+        level = level
+            .chunks(2)
+            .map(|pair| match pair {
+                [left, right] => hash_pair(left, right),
+                [single] => *single,
+                _ => unreachable!("chunks(2) yields 1 or 2 items"),
+            })
+            .collect();
+    }
+    level[0]
+}
+
+/// Build an inclusion proof for the leaf at `leaf_index`
+///
+/// Returns `None` if the index is out of range.
+pub fn merkle_proof(leaves: &[Hash], leaf_index: u32) -> Option<MessageInclusionProof> {
+    let mut index = leaf_index as usize;
+    if index >= leaves.len() {
+        return None;
+    }
+
+    let mut siblings = Vec::new();
+    let mut level: Vec<Hash> = leaves.to_vec();
+    while level.len() > 1 {
+        let sibling = index ^ 1;
+        if sibling < level.len() {
+            siblings.push(level[sibling]);
+        }
+        level = level
+            .chunks(2)
+            .map(|pair| match pair {
+                [left, right] => hash_pair(left, right),
+                [single] => *single,
+                _ => unreachable!("chunks(2) yields 1 or 2 items"),
+            })
+            .collect();
+        index /= 2;
+    }
+
+    Some(MessageInclusionProof {
+        leaf_index,
+        leaf_count: leaves.len() as u32,
+        siblings,
+    })
+}
+
+/// Verify that `leaf` is included in the tree with the given `root`
+pub fn verify_merkle_proof(root: &Hash, leaf: Hash, proof: &MessageInclusionProof) -> bool {
+    if proof.leaf_index >= proof.leaf_count || proof.siblings.len() > MAX_PROOF_DEPTH as usize {
+        return false;
+    }
+
+    let mut index = proof.leaf_index as usize;
+    let mut width = proof.leaf_count as usize;
+    let mut siblings = proof.siblings.iter();
+    let mut current = leaf;
+
+    while width > 1 {
+        let sibling = index ^ 1;
+        if sibling < width {
+            let Some(sibling_hash) = siblings.next() else {
+                return false;
+            };
+            current = if index % 2 == 0 {
+                hash_pair(&current, sibling_hash)
+            } else {
+                hash_pair(sibling_hash, &current)
+            };
+        }
+        index /= 2;
+        width = (width + 1) / 2;
+    }
+
+    siblings.next().is_none() && current == *root
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn leaves(count: u64) -> Vec<Hash> {
+        (0..count)
+            .map(|nonce| {
+                OutboundMessage {
+                    source: 1,
+                    destination: 2,
+                    nonce,
+                    payload_hash: Hash::from(blake2_256(&nonce.to_le_bytes())),
+                }
+                .leaf_hash()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn empty_root_is_zero() {
+        assert_eq!(merkle_root(&[]), Hash::zero());
+    }
+
+    #[test]
+    fn single_leaf_root_is_leaf() {
+        let leaves = leaves(1);
+        assert_eq!(merkle_root(&leaves), leaves[0]);
+        let proof = merkle_proof(&leaves, 0).unwrap();
+        assert!(proof.siblings.is_empty());
+        assert!(verify_merkle_proof(&leaves[0], leaves[0], &proof));
+    }
+
+    #[test]
+    fn proofs_verify_for_all_leaves() {
+        for count in 1..=9 {
+            let leaves = leaves(count);
+            let root = merkle_root(&leaves);
+            for (i, leaf) in leaves.iter().enumerate() {
+                let proof = merkle_proof(&leaves, i as u32).unwrap();
+                assert!(verify_merkle_proof(&root, *leaf, &proof), "count {} leaf {}", count, i);
+            }
+        }
+    }
+
+    #[test]
+    fn wrong_leaf_or_index_rejected() {
+        let leaves = leaves(5);
+        let root = merkle_root(&leaves);
+        let mut proof = merkle_proof(&leaves, 2).unwrap();
+        assert!(!verify_merkle_proof(&root, leaves[3], &proof));
+
+        proof.leaf_index = 3;
+        assert!(!verify_merkle_proof(&root, leaves[2], &proof));
+
+        proof.leaf_index = 5;
+        assert!(!verify_merkle_proof(&root, leaves[2], &proof));
+    }
+
+    #[test]
+    fn out_of_range_proof_is_none() {
+        assert!(merkle_proof(&leaves(3), 3).is_none());
+    }
+}
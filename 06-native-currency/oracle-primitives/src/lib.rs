@@ -0,0 +1,318 @@
+//! # Ëtrid Oracle Primitives
+//!
+//! The shared oracle core consumed by every part of Ëtrid that needs a price.
+//!
+//! ## Overview
+//!
+//! Feeds are registered once in `pallet-oracle-network`, where staked reporters
+//! submit values and the pallet publishes one aggregated price per round. Everything
+//! else reads prices through the [`PriceFeed`] trait defined here:
+//! - `pallet-edsc-stability`: EDSC/USD peg price
+//! - `pallet-reserve-vault`: collateral asset prices
+//! - `etrid-bridge-common`: cross-chain exchange rates
+//! - `lightning-bloc`: Cross-PBC router exchange rates
+//!
+//! ## Aggregation
+//!
+//! Rounds are aggregated with a median and a median absolute deviation (MAD) band:
+//! ```text
+//! band = max(k × MAD, tolerance × median)
+//! |value - median| > band  →  rejected
+//! ```
+//! The median and MAD are both robust to a minority of bad reporters, unlike a mean
+//! and standard deviation which a single extreme value can drag along with it. The
+//! tolerance floor keeps reporters from being rejected for tiny differences when
+//! honest reporters agree exactly and the MAD collapses to zero.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::{Decode, Encode, MaxEncodedLen};
+use scale_info::TypeInfo;
+pub use sp_runtime::Permill;
+use sp_runtime::RuntimeDebug;
+use sp_std::vec::Vec;
+
+/// Feed identifier: an ASCII pair symbol such as `ETR/USD`, zero padded to 16 bytes
+pub type FeedId = [u8; 16];
+
+/// Build a [`FeedId`] from a pair symbol, truncating symbols longer than 16 bytes
+pub const fn feed_id(symbol: &[u8]) -> FeedId {
+	let mut id = [0u8; 16];
+	let mut i = 0;
+	while i < symbol.len() && i < 16 {
+		id[i] = symbol[i];
+		i += 1;
+	}
+	id
+}
+
+/// ETR/USD
+pub const ETR_USD: FeedId = feed_id(b"ETR/USD");
+/// EDSC/USD
+pub const EDSC_USD: FeedId = feed_id(b"EDSC/USD");
+/// BTC/USD
+pub const BTC_USD: FeedId = feed_id(b"BTC/USD");
+/// ETH/USD
+pub const ETH_USD: FeedId = feed_id(b"ETH/USD");
+/// USDC/USD
+pub const USDC_USD: FeedId = feed_id(b"USDC/USD");
+/// USDT/USD
+pub const USDT_USD: FeedId = feed_id(b"USDT/USD");
+/// DAI/USD
+pub const DAI_USD: FeedId = feed_id(b"DAI/USD");
+/// SOL/USD
+pub const SOL_USD: FeedId = feed_id(b"SOL/USD");
+/// BNB/USD
+pub const BNB_USD: FeedId = feed_id(b"BNB/USD");
+/// ADA/USD
+pub const ADA_USD: FeedId = feed_id(b"ADA/USD");
+/// TRX/USD
+pub const TRX_USD: FeedId = feed_id(b"TRX/USD");
+/// XRP/USD
+pub const XRP_USD: FeedId = feed_id(b"XRP/USD");
+/// XLM/USD
+pub const XLM_USD: FeedId = feed_id(b"XLM/USD");
+/// MATIC/USD
+pub const MATIC_USD: FeedId = feed_id(b"MATIC/USD");
+/// LINK/USD
+pub const LINK_USD: FeedId = feed_id(b"LINK/USD");
+/// DOGE/USD
+pub const DOGE_USD: FeedId = feed_id(b"DOGE/USD");
+
+/// Chain identifiers with a native-asset USD feed, as understood by [`chain_feed`]
+pub const FEED_CHAINS: [&str; 14] = [
+	"flarechain", "edsc-pbc", "btc-pbc", "eth-pbc", "sc-usdt-pbc", "sol-pbc", "bnb-pbc",
+	"ada-pbc", "trx-pbc", "xrp-pbc", "xlm-pbc", "matic-pbc", "link-pbc", "doge-pbc",
+];
+
+/// USD feed for the native asset of a partition burst chain (e.g. `eth-pbc` → ETH/USD)
+pub fn chain_feed(chain: &str) -> Option<FeedId> {
+	let feed = match chain {
+		"flarechain" | "etr" => ETR_USD,
+		"edsc-pbc" => EDSC_USD,
+		"btc-pbc" => BTC_USD,
+		"eth-pbc" => ETH_USD,
+		"sc-usdt-pbc" => USDT_USD,
+		"sol-pbc" => SOL_USD,
+		"bnb-pbc" => BNB_USD,
+		"ada-pbc" => ADA_USD,
+		"trx-pbc" => TRX_USD,
+		"xrp-pbc" => XRP_USD,
+		"xlm-pbc" => XLM_USD,
+		"matic-pbc" => MATIC_USD,
+		"link-pbc" => LINK_USD,
+		"doge-pbc" => DOGE_USD,
+		_ => return None,
+	};
+	Some(feed)
+}
+
+/// Aggregated price published for a feed
+#[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct FeedPrice {
+	/// Price as a fixed-point integer with `decimals` decimals
+	pub value: u128,
+	/// Decimals of `value`
+	pub decimals: u8,
+	/// Block the price was published at
+	pub updated_at: u32,
+	/// Published round number (starts at 1)
+	pub round: u64,
+	/// Reporters whose values were accepted into the round
+	pub reporters: u32,
+}
+
+impl FeedPrice {
+	/// Value rescaled to `decimals` decimals (e.g. `scaled(2)` for USD cents)
+	pub fn scaled(&self, decimals: u8) -> u128 {
+		if decimals >= self.decimals {
+			self.value.saturating_mul(pow10(decimals - self.decimals))
+		} else {
+			self.value / pow10(self.decimals - decimals)
+		}
+	}
+
+	/// Exchange rate from this asset into `quote` in basis points (10000 = 1:1)
+	pub fn rate_bps(&self, quote: &FeedPrice) -> Option<u64> {
+		let decimals = self.decimals.max(quote.decimals);
+		let quote_value = quote.scaled(decimals);
+		if quote_value == 0 {
+			return None;
+		}
+		let rate = self.scaled(decimals).saturating_mul(10_000) / quote_value;
+		u64::try_from(rate).ok()
+	}
+}
+
+fn pow10(exp: u8) -> u128 {
+	10u128.saturating_pow(exp as u32)
+}
+
+/// Read access to published oracle prices
+///
+/// Implemented by `pallet-oracle-network`. Consumers take it as an associated type
+/// and use `()` in mocks, which never returns a price.
+pub trait PriceFeed {
+	/// Latest published price for `feed`, regardless of age
+	fn latest(feed: &FeedId) -> Option<FeedPrice>;
+
+	/// Latest published price for `feed` if it is still within its heartbeat window
+	fn fresh(feed: &FeedId) -> Option<FeedPrice>;
+}
+
+impl PriceFeed for () {
+	fn latest(_feed: &FeedId) -> Option<FeedPrice> {
+		None
+	}
+
+	fn fresh(_feed: &FeedId) -> Option<FeedPrice> {
+		None
+	}
+}
+
+/// Outcome of filtering one round of submissions
+#[derive(Clone, PartialEq, Eq, RuntimeDebug)]
+pub struct RoundOutcome {
+	/// Median of all submissions
+	pub median: u128,
+	/// Median absolute deviation from `median`
+	pub mad: u128,
+	/// Largest accepted distance from `median`
+	pub band: u128,
+	/// Indices of accepted submissions
+	pub accepted: Vec<usize>,
+	/// Indices of rejected submissions
+	pub rejected: Vec<usize>,
+	/// Median of the accepted submissions; this is the value to publish
+	pub value: u128,
+}
+
+/// Median of `values`; the mean of the two middle values for an even count
+pub fn median(values: &[u128]) -> Option<u128> {
+	if values.is_empty() {
+		return None;
+	}
+
+	let mut sorted = values.to_vec();
+	sorted.sort_unstable();
+
+	let mid = sorted.len() / 2;
+	if sorted.len() % 2 == 0 {
+		// Average without overflowing on large fixed-point values
+		let (low, high) = (sorted[mid - 1], sorted[mid]);
+		Some(low + (high - low) / 2)
+	} else {
+		Some(sorted[mid])
+	}
+}
+
+/// Median absolute deviation of `values` around `center`
+pub fn median_absolute_deviation(values: &[u128], center: u128) -> u128 {
+	let deviations: Vec<u128> = values.iter().map(|v| v.abs_diff(center)).collect();
+	median(&deviations).unwrap_or(0)
+}
+
+/// Filter a round of submissions with the median + MAD rule
+///
+/// A submission is rejected when it lies further than
+/// `max(mad_multiplier × MAD, tolerance × median)` from the median. Rounds with fewer
+/// than three submissions have no meaningful majority, so nothing is rejected.
+pub fn filter_round(values: &[u128], mad_multiplier: u32, tolerance: Permill) -> Option<RoundOutcome> {
+	let center = median(values)?;
+	let mad = median_absolute_deviation(values, center);
+	let band = mad.saturating_mul(mad_multiplier as u128).max(tolerance.mul_floor(center));
+
+	let (accepted, rejected): (Vec<usize>, Vec<usize>) = if values.len() < 3 {
+		((0..values.len()).collect(), Vec::new())
+	} else {
+		(0..values.len()).partition(|&i| values[i].abs_diff(center) <= band)
+	};
+
+	let kept: Vec<u128> = accepted.iter().map(|&i| values[i]).collect();
+	let value = median(&kept).unwrap_or(center);
+
+	Some(RoundOutcome { median: center, mad, band, accepted, rejected, value })
+}
+
+/// Whether `new` moved away from `old` by more than `threshold`
+pub fn deviation_exceeds(old: u128, new: u128, threshold: Permill) -> bool {
+	if old == 0 {
+		return new != 0;
+	}
+	old.abs_diff(new) > threshold.mul_floor(old)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn feed_id_pads_and_truncates() {
+		assert_eq!(&ETR_USD[..7], b"ETR/USD");
+		assert!(ETR_USD[7..].iter().all(|b| *b == 0));
+		assert_eq!(feed_id(b"0123456789abcdefXYZ"), *b"0123456789abcdef");
+	}
+
+	#[test]
+	fn chain_feed_maps_known_chains() {
+		assert_eq!(chain_feed("eth-pbc"), Some(ETH_USD));
+		assert_eq!(chain_feed("edsc-pbc"), Some(EDSC_USD));
+		assert_eq!(chain_feed("unknown-pbc"), None);
+		assert!(FEED_CHAINS.iter().all(|chain| chain_feed(chain).is_some()));
+	}
+
+	#[test]
+	fn median_odd_and_even() {
+		assert_eq!(median(&[3, 1, 2]), Some(2));
+		assert_eq!(median(&[4, 1, 3, 2]), Some(2));
+		assert_eq!(median(&[u128::MAX, u128::MAX]), Some(u128::MAX));
+		assert_eq!(median(&[]), None);
+	}
+
+	#[test]
+	fn mad_rejects_single_outlier() {
+		let values = [100, 101, 102, 103, 1000];
+		let outcome = filter_round(&values, 3, Permill::zero()).unwrap();
+		assert_eq!(outcome.median, 102);
+		assert_eq!(outcome.mad, 1);
+		assert_eq!(outcome.rejected, vec![4]);
+		assert_eq!(outcome.value, 101);
+	}
+
+	#[test]
+	fn tolerance_floor_applies_when_mad_is_zero() {
+		// Three identical reporters collapse the MAD to zero
+		let values = [1_000_000, 1_000_000, 1_000_000, 1_004_000, 1_020_000];
+		let outcome = filter_round(&values, 3, Permill::from_percent(1)).unwrap();
+		assert_eq!(outcome.mad, 0);
+		assert_eq!(outcome.band, 10_000);
+		assert_eq!(outcome.rejected, vec![4]);
+		assert_eq!(outcome.value, 1_000_000);
+	}
+
+	#[test]
+	fn small_rounds_reject_nothing() {
+		let outcome = filter_round(&[100, 1000], 3, Permill::zero()).unwrap();
+		assert!(outcome.rejected.is_empty());
+		assert_eq!(outcome.value, 550);
+	}
+
+	#[test]
+	fn deviation_threshold() {
+		let half_percent = Permill::from_parts(5_000);
+		assert!(!deviation_exceeds(10_000, 10_050, half_percent));
+		assert!(deviation_exceeds(10_000, 10_051, half_percent));
+		assert!(deviation_exceeds(0, 1, half_percent));
+	}
+
+	#[test]
+	fn scaling_and_rates() {
+		let eth = FeedPrice { value: 3_000_00000000, decimals: 8, updated_at: 1, round: 1, reporters: 3 };
+		let btc = FeedPrice { value: 60_000_000000, decimals: 6, updated_at: 1, round: 1, reporters: 3 };
+		assert_eq!(eth.scaled(2), 300_000);
+		assert_eq!(btc.scaled(8), 60_000_00000000);
+		// 1 ETH = 0.05 BTC
+		assert_eq!(eth.rate_bps(&btc), Some(500));
+		assert_eq!(btc.rate_bps(&eth), Some(200_000));
+	}
+}
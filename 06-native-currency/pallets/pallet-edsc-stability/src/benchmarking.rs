@@ -0,0 +1,300 @@
+//! Benchmarking setup for pallet-edsc-stability
+//!
+//! Every benchmark sets up the most expensive path of its extrinsic: positions that
+//! accrue interest, partial liquidations, auctions that settle back into a position
+//! and bad debt covered from treasury EDSC.
+
+#![cfg(feature = "runtime-benchmarks")]
+
+use super::*;
+use frame_benchmarking::v2::*;
+use frame_support::{
+	assert_ok,
+	traits::{Currency, Hooks, ReservableCurrency},
+};
+use frame_system::{pallet_prelude::BlockNumberFor, RawOrigin};
+use sp_runtime::{
+	traits::{Saturating, Zero},
+	SaturatedConversion,
+};
+
+const SEED: u32 = 0;
+
+/// Blocks in a year of 6 second blocks, enough for interest to reach the treasury
+const YEAR: u32 = 5_256_000;
+
+/// EDSC debt used by the benchmarks, far above the minimum mint
+fn debt<T: Config>() -> u128 {
+	T::MinEDSCMint::get().max(1).saturating_mul(1_000)
+}
+
+/// Collateral needed to mint `edsc` at the minimum collateral ratio
+fn collateral_for<T: Config>(edsc: u128) -> BalanceOf<T> {
+	edsc.saturating_mul(T::MinCollateralRatio::get() as u128)
+		.div_ceil(10000)
+		.saturated_into()
+}
+
+/// Account holding plenty of free balance for collateral and fees
+fn funded<T: Config>(name: &'static str) -> T::AccountId {
+	let who: T::AccountId = account(name, 0, SEED);
+	let amount: BalanceOf<T> = debt::<T>().saturating_mul(100).saturated_into();
+	T::Currency::make_free_balance_be(&who, amount);
+	who
+}
+
+fn set_block<T: Config>(block: u32) {
+	frame_system::Pallet::<T>::set_block_number(block.into());
+}
+
+/// Open a position for `who` as if it had been minted at block 0
+fn open_position<T: Config>(who: &T::AccountId, collateral: BalanceOf<T>, edsc: u128) {
+	assert_ok!(T::Currency::reserve(who, collateral));
+	Positions::<T>::insert(who, EDSCPosition {
+		collateral_amount: collateral,
+		edsc_minted: edsc,
+		interest_rate: 5000,
+		interest_owed: 0,
+		last_interest_update: 0,
+		created_at: 0,
+	});
+	EDSCBalances::<T>::mutate(who, |balance| *balance = balance.saturating_add(edsc));
+	TotalEDSCSupply::<T>::mutate(|supply| *supply = supply.saturating_add(edsc));
+	EDSCReserveBalance::<T>::mutate(|balance| *balance = balance.saturating_add(collateral));
+}
+
+/// Give the treasury EDSC it can release to cover bad debt
+fn fund_treasury_edsc<T: Config>(amount: u128) {
+	EDSCBalances::<T>::insert(T::TreasuryAccount::get(), amount);
+	TotalEDSCSupply::<T>::mutate(|supply| *supply = supply.saturating_add(amount));
+	assert_ok!(T::Treasury::receive_edsc_penalties(amount));
+}
+
+#[benchmarks]
+mod benchmarks {
+	use super::*;
+
+	#[benchmark]
+	fn deposit_collateral_mint_edsc() {
+		let caller = funded::<T>("caller");
+		let edsc = debt::<T>();
+		let collateral = collateral_for::<T>(edsc);
+
+		// Worst case: top up an existing position that has accrued interest
+		InterestRate::<T>::put(5000);
+		open_position::<T>(&caller, collateral, edsc);
+		set_block::<T>(YEAR);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller.clone()), collateral, edsc);
+
+		let position = Positions::<T>::get(&caller).unwrap();
+		assert_eq!(position.edsc_minted, edsc * 2);
+		assert!(position.interest_owed > 0);
+	}
+
+	#[benchmark]
+	fn burn_edsc_withdraw_collateral() {
+		let caller = funded::<T>("caller");
+		let edsc = debt::<T>();
+		let collateral = collateral_for::<T>(edsc);
+
+		// Worst case: partial burn that pays interest to the treasury
+		open_position::<T>(&caller, collateral, edsc);
+		set_block::<T>(YEAR);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller.clone()), edsc / 2);
+
+		assert_eq!(Positions::<T>::get(&caller).unwrap().edsc_minted, edsc - edsc / 2);
+		assert!(!StabilityFees::<T>::get().is_zero());
+	}
+
+	#[benchmark]
+	fn liquidate_position() {
+		let keeper: T::AccountId = whitelisted_caller();
+		let owner = funded::<T>("owner");
+		let edsc = debt::<T>();
+		// Just below the liquidation threshold, so only part of the debt is covered
+		let collateral: BalanceOf<T> = edsc
+			.saturating_mul(T::LiquidationThreshold::get().saturating_sub(1) as u128)
+			.saturating_div(10000)
+			.saturated_into();
+		open_position::<T>(&owner, collateral, edsc);
+		NextLiquidationId::<T>::put(1);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(keeper), owner.clone());
+
+		assert!(Auctions::<T>::contains_key(&owner));
+		assert!(LiquidationHistory::<T>::contains_key(1));
+	}
+
+	#[benchmark]
+	fn trigger_rebalance() {
+		let caller: T::AccountId = whitelisted_caller();
+		CurrentReserveComposition::<T>::put(ReserveComposition {
+			etr_allocation: 10000,
+			sbtc_allocation: 0,
+			seth_allocation: 0,
+			other_allocation: 0,
+		});
+		TargetReserveComposition::<T>::put(ReserveComposition::default());
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller));
+
+		assert_eq!(CurrentReserveComposition::<T>::get(), ReserveComposition::default());
+	}
+
+	#[benchmark]
+	fn adjust_interest_rate() {
+		#[extrinsic_call]
+		_(RawOrigin::Root, 5000);
+
+		assert_eq!(InterestRate::<T>::get(), 5000);
+	}
+
+	#[benchmark]
+	fn emergency_pause() {
+		// 50% below peg
+		EDSCPrice::<T>::put(50);
+
+		#[extrinsic_call]
+		_(RawOrigin::Root);
+
+		assert!(EmergencyPaused::<T>::get());
+	}
+
+	#[benchmark]
+	fn deactivate_emergency_pause() {
+		EmergencyPaused::<T>::put(true);
+
+		#[extrinsic_call]
+		_(RawOrigin::Root);
+
+		assert!(!EmergencyPaused::<T>::get());
+	}
+
+	#[benchmark]
+	fn update_target_composition() {
+		#[extrinsic_call]
+		_(RawOrigin::Root, 2500, 2500, 2500, 2500);
+
+		assert_eq!(TargetReserveComposition::<T>::get().etr_allocation, 2500);
+	}
+
+	#[benchmark]
+	fn add_collateral() {
+		let caller = funded::<T>("caller");
+		let edsc = debt::<T>();
+		let collateral = collateral_for::<T>(edsc);
+		open_position::<T>(&caller, collateral, edsc);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller.clone()), collateral);
+
+		assert_eq!(Positions::<T>::get(&caller).unwrap().collateral_amount, collateral + collateral);
+	}
+
+	#[benchmark]
+	fn collect_interest() {
+		let caller: T::AccountId = whitelisted_caller();
+		let owner = funded::<T>("owner");
+		let edsc = debt::<T>();
+		open_position::<T>(&owner, collateral_for::<T>(edsc), edsc);
+		set_block::<T>(YEAR);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), owner.clone());
+
+		assert!(Positions::<T>::get(&owner).unwrap().interest_owed > 0);
+	}
+
+	#[benchmark]
+	fn take_collateral() {
+		let keeper: T::AccountId = account("keeper", 0, SEED);
+		let buyer: T::AccountId = whitelisted_caller();
+		let owner = funded::<T>("owner");
+		let edsc = debt::<T>();
+
+		// Worst case: a partial liquidation's auction that is paid off, penalty
+		// included, and hands its unsold collateral back to the position
+		open_position::<T>(&owner, collateral_for::<T>(edsc), edsc);
+		let auction_debt = edsc / 2;
+		let penalty = auction_debt / 20;
+		let lot: BalanceOf<T> = auction_debt.saturating_add(penalty).saturating_mul(2).saturated_into();
+		T::Currency::make_free_balance_be(&Pallet::<T>::account_id(), lot.saturating_mul(2u32.into()));
+		Auctions::<T>::insert(&owner, CollateralAuction {
+			keeper,
+			lot,
+			debt: auction_debt,
+			penalty,
+			started_at: 0,
+		});
+		EDSCBalances::<T>::insert(&buyer, edsc);
+		TotalEDSCSupply::<T>::mutate(|supply| *supply = supply.saturating_add(edsc));
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(buyer), owner.clone(), lot, u16::MAX);
+
+		assert!(!Auctions::<T>::contains_key(&owner));
+		assert!(EDSCBalances::<T>::get(T::TreasuryAccount::get()) > 0);
+	}
+
+	#[benchmark]
+	fn close_auction() {
+		let caller: T::AccountId = whitelisted_caller();
+		let keeper: T::AccountId = account("keeper", 0, SEED);
+		let owner = funded::<T>("owner");
+		let edsc = debt::<T>();
+
+		// Worst case: a fully liquidated position's auction expires unsold, records
+		// bad debt and covers it from treasury EDSC
+		let lot: BalanceOf<T> = edsc.saturated_into();
+		T::Currency::make_free_balance_be(&Pallet::<T>::account_id(), lot.saturating_mul(2u32.into()));
+		Auctions::<T>::insert(&owner, CollateralAuction {
+			keeper,
+			lot,
+			debt: edsc,
+			penalty: edsc / 20,
+			started_at: 0,
+		});
+		fund_treasury_edsc::<T>(edsc);
+		set_block::<T>(T::AuctionDuration::get().saturating_add(1));
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), owner.clone());
+
+		assert!(!Auctions::<T>::contains_key(&owner));
+	}
+
+	#[benchmark]
+	fn settle_bad_debt() {
+		let caller: T::AccountId = whitelisted_caller();
+		let edsc = debt::<T>();
+		BadDebt::<T>::put(edsc);
+		fund_treasury_edsc::<T>(edsc);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller));
+
+		assert!(BadDebt::<T>::get() < edsc);
+	}
+
+	#[benchmark]
+	fn on_initialize() {
+		let owner = funded::<T>("owner");
+		let edsc = debt::<T>();
+		open_position::<T>(&owner, collateral_for::<T>(edsc), edsc);
+		let block: BlockNumberFor<T> = 1u32.into();
+
+		#[block]
+		{
+			Pallet::<T>::on_initialize(block);
+			Pallet::<T>::on_finalize(block);
+		}
+
+		assert!(CollateralizationRatio::<T>::get() > 0);
+	}
+}
@@ -32,13 +32,24 @@
 //!
 //! 2. **Liquidation System**:
 //!    - Positions below 120% collateralization can be liquidated
-//!    - 5% liquidation penalty goes to treasury
+//!    - Partial liquidation: only enough debt is covered to bring the position back
+//!      to the target ratio (160%); small remainders are liquidated in full
+//!    - Seized collateral is sold in a Dutch auction whose price falls linearly from
+//!      the start price to the floor price over the auction duration
+//!    - The keeper who starts the auction earns a share of the lot
+//!    - 5% liquidation penalty is raised in EDSC and goes to treasury
 //!
-//! 3. **Emergency Circuit Breaker**:
+//! 3. **Bad Debt**:
+//!    - Auctions of partially liquidated positions hand unsold collateral and unpaid
+//!      debt back to the position
+//!    - When a fully liquidated position's auction clears below its debt, the shortfall
+//!      is recorded as bad debt and covered from treasury EDSC (backstop)
+//!
+//! 4. **Emergency Circuit Breaker**:
 //!    - Directors can pause if peg breaks > 10%
 //!    - Prevents cascading liquidations during extreme volatility
 //!
-//! 4. **Automatic Rebalancing**:
+//! 5. **Automatic Rebalancing**:
 //!    - System rebalances reserve when composition deviates > 5% from target
 //!    - Ensures diversification and stability
 
@@ -52,10 +63,24 @@ mod mock;
 #[cfg(test)]
 mod tests;
 
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
+pub mod weights;
+pub use weights::WeightInfo;
+
 /// Treasury interface for routing stability fees
 pub trait TreasuryInterface<AccountId, Balance> {
 	/// Send stability fees to treasury
 	fn receive_stability_fees(amount: Balance) -> Result<(), sp_runtime::DispatchError>;
+
+	/// Record EDSC liquidation penalties credited to the treasury account
+	fn receive_edsc_penalties(amount: u128) -> Result<(), sp_runtime::DispatchError>;
+
+	/// Release up to `amount` of treasury EDSC to cover bad debt
+	///
+	/// Returns the amount released; the pallet burns it from the treasury account.
+	fn cover_bad_debt(amount: u128) -> u128;
 }
 
 #[frame_support::pallet]
@@ -71,9 +96,10 @@ pub mod pallet {
 	use sp_runtime::traits::{AccountIdConversion, CheckedAdd, CheckedSub, Saturating, Zero};
 	use sp_runtime::SaturatedConversion;
 	use sp_std::vec::Vec;
-	use crate::TreasuryInterface;
+	use etrid_oracle_primitives::{PriceFeed, EDSC_USD};
+	use crate::{TreasuryInterface, WeightInfo};
 
-	type BalanceOf<T> = <<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
+	pub type BalanceOf<T> = <<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
 
 	/// Asset type for reserve composition
 	#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
@@ -150,6 +176,23 @@ pub mod pallet {
 		pub block_number: u32,
 	}
 
+	/// Dutch auction of collateral seized from a liquidated position
+	#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	#[scale_info(skip_type_params(AccountId, Balance))]
+	#[codec(mel_bound())]
+	pub struct CollateralAuction<AccountId: MaxEncodedLen, Balance: MaxEncodedLen> {
+		/// Keeper that started the auction
+		pub keeper: AccountId,
+		/// Collateral left for sale (held by the pallet account)
+		pub lot: Balance,
+		/// EDSC debt still to be raised (burned when paid)
+		pub debt: u128,
+		/// EDSC penalty still to be raised once the debt is paid (goes to treasury)
+		pub penalty: u128,
+		/// Block the price curve starts at
+		pub started_at: u32,
+	}
+
 	/// Rebalancing event record
 	#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
 	pub struct RebalanceRecord {
@@ -186,6 +229,26 @@ pub mod pallet {
 		#[pallet::constant]
 		type LiquidationPenalty: Get<u16>;
 
+		/// Collateral ratio restored by a partial liquidation (160% = 16000 basis points)
+		#[pallet::constant]
+		type LiquidationTargetRatio: Get<u16>;
+
+		/// Auction start price relative to collateral value (120% = 12000 basis points)
+		#[pallet::constant]
+		type AuctionStartPrice: Get<u16>;
+
+		/// Auction floor price relative to collateral value (50% = 5000 basis points)
+		#[pallet::constant]
+		type AuctionFloorPrice: Get<u16>;
+
+		/// Blocks for the auction price to fall from start to floor
+		#[pallet::constant]
+		type AuctionDuration: Get<u32>;
+
+		/// Share of the lot paid to the keeper starting an auction (0.5% = 50 basis points)
+		#[pallet::constant]
+		type KeeperReward: Get<u16>;
+
 		/// Rebalancing deviation threshold (5% = 500 basis points)
 		#[pallet::constant]
 		type RebalanceThreshold: Get<u16>;
@@ -213,28 +276,13 @@ pub mod pallet {
 		/// Treasury pallet interface for proper fee routing
 		type Treasury: crate::TreasuryInterface<Self::AccountId, BalanceOf<Self>>;
 
+		/// Oracle price feeds; the EDSC/USD feed drives `EDSCPrice`
+		type PriceFeed: PriceFeed;
+
 		/// Weight information
 		type WeightInfo: WeightInfo;
 	}
 
-	pub trait WeightInfo {
-		fn deposit_collateral_mint_edsc() -> Weight;
-		fn burn_edsc_withdraw_collateral() -> Weight;
-		fn liquidate_position() -> Weight;
-		fn trigger_rebalance() -> Weight;
-		fn adjust_interest_rate() -> Weight;
-		fn emergency_pause() -> Weight;
-	}
-
-	impl WeightInfo for () {
-		fn deposit_collateral_mint_edsc() -> Weight { Weight::from_parts(20_000, 0) }
-		fn burn_edsc_withdraw_collateral() -> Weight { Weight::from_parts(20_000, 0) }
-		fn liquidate_position() -> Weight { Weight::from_parts(30_000, 0) }
-		fn trigger_rebalance() -> Weight { Weight::from_parts(50_000, 0) }
-		fn adjust_interest_rate() -> Weight { Weight::from_parts(10_000, 0) }
-		fn emergency_pause() -> Weight { Weight::from_parts(10_000, 0) }
-	}
-
 	// ===================== STORAGE =====================
 
 	/// Main EDSC reserve account balance
@@ -317,6 +365,22 @@ pub mod pallet {
 	#[pallet::storage]
 	pub type NextLiquidationId<T: Config> = StorageValue<_, u32, ValueQuery>;
 
+	/// Running collateral auctions, one per position owner
+	#[pallet::storage]
+	#[pallet::getter(fn auction)]
+	pub type Auctions<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		CollateralAuction<T::AccountId, BalanceOf<T>>,
+		OptionQuery
+	>;
+
+	/// EDSC in circulation no longer backed by any position
+	#[pallet::storage]
+	#[pallet::getter(fn bad_debt)]
+	pub type BadDebt<T: Config> = StorageValue<_, u128, ValueQuery>;
+
 	/// Rebalancing history
 	#[pallet::storage]
 	pub type RebalanceHistory<T: Config> = StorageMap<
@@ -332,7 +396,7 @@ pub mod pallet {
 	pub type NextRebalanceId<T: Config> = StorageValue<_, u32, ValueQuery>;
 
 	/// Current EDSC price (in cents, e.g., 100 = $1.00)
-	/// In production, this would come from oracle
+	/// Refreshed every block from the EDSC/USD feed while it is fresh
 	#[pallet::storage]
 	#[pallet::getter(fn edsc_price)]
 	pub type EDSCPrice<T: Config> = StorageValue<_, u32, ValueQuery>;
@@ -388,7 +452,7 @@ pub mod pallet {
 			collateral_returned: BalanceOf<T>,
 			interest_paid: u128,
 		},
-		/// Position liquidated
+		/// Position liquidated, seized collateral sent to auction
 		PositionLiquidated {
 			owner: T::AccountId,
 			liquidator: T::AccountId,
@@ -396,6 +460,39 @@ pub mod pallet {
 			collateral_seized: BalanceOf<T>,
 			penalty: BalanceOf<T>,
 		},
+		/// Collateral auction started
+		AuctionStarted {
+			owner: T::AccountId,
+			keeper: T::AccountId,
+			lot: BalanceOf<T>,
+			debt: u128,
+			penalty: u128,
+			keeper_reward: BalanceOf<T>,
+		},
+		/// Collateral bought from an auction
+		CollateralTaken {
+			owner: T::AccountId,
+			buyer: T::AccountId,
+			collateral: BalanceOf<T>,
+			price: u16,
+			edsc_paid: u128,
+		},
+		/// Auction ended
+		AuctionSettled {
+			owner: T::AccountId,
+			unsold_collateral: BalanceOf<T>,
+			shortfall: u128,
+		},
+		/// Auction shortfall recorded as bad debt
+		BadDebtRecorded {
+			owner: T::AccountId,
+			amount: u128,
+		},
+		/// Bad debt covered from treasury EDSC
+		BadDebtCovered {
+			amount: u128,
+			remaining: u128,
+		},
 		/// Reserve rebalanced
 		ReserveRebalanced {
 			old_composition: ReserveComposition,
@@ -479,6 +576,18 @@ pub mod pallet {
 		NotAuthorized,
 		/// Peg deviation not critical
 		PegDeviationNotCritical,
+		/// Position already has a running auction
+		AuctionInProgress,
+		/// No auction for this position
+		AuctionNotFound,
+		/// Auction has not reached its floor price yet
+		AuctionNotExpired,
+		/// Current auction price exceeds the buyer's limit
+		PriceAboveLimit,
+		/// Nothing to buy at the requested amount
+		NothingToTake,
+		/// No bad debt outstanding
+		NoBadDebt,
 	}
 
 	// ===================== HOOKS =====================
@@ -486,9 +595,15 @@ pub mod pallet {
 	#[pallet::hooks]
 	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
 		fn on_initialize(_n: BlockNumberFor<T>) -> Weight {
+			// Track the oracle network's EDSC/USD price; a stale feed keeps the last value
+			if let Some(price) = T::PriceFeed::fresh(&EDSC_USD) {
+				EDSCPrice::<T>::put(price.scaled(2).saturated_into::<u32>());
+			}
+
 			// Check if automatic rebalancing is needed
 			// In production, this would be done periodically
-			Weight::from_parts(5_000, 0)
+			// (the benchmarked weight includes `on_finalize`)
+			T::WeightInfo::on_initialize()
 		}
 
 		fn on_finalize(_n: BlockNumberFor<T>) {
@@ -725,8 +840,10 @@ pub mod pallet {
 
 		/// Liquidate an undercollateralized position
 		///
-		/// Anyone can liquidate positions below 120% collateralization.
-		/// Liquidator must provide EDSC to burn, receives collateral with 5% penalty to treasury.
+		/// Anyone can liquidate positions below 120% collateralization. Only enough
+		/// debt is covered to restore the target ratio; the matching collateral plus
+		/// the 5% penalty goes to a Dutch auction (see `take_collateral`). The caller
+		/// (keeper) is paid a share of the lot.
 		///
 		/// Parameters:
 		/// - owner: Account with undercollateralized position
@@ -736,10 +853,13 @@ pub mod pallet {
 			origin: OriginFor<T>,
 			owner: T::AccountId,
 		) -> DispatchResult {
-			let liquidator = ensure_signed(origin)?;
+			let keeper = ensure_signed(origin)?;
+
+			ensure!(!EmergencyPaused::<T>::get(), Error::<T>::SystemPaused);
+			ensure!(!Auctions::<T>::contains_key(&owner), Error::<T>::AuctionInProgress);
 
 			// Get position
-			let position = Positions::<T>::get(&owner)
+			let mut position = Positions::<T>::get(&owner)
 				.ok_or(Error::<T>::PositionNotFound)?;
 
 			// Calculate current collateralization ratio
@@ -754,90 +874,93 @@ pub mod pallet {
 
 			// Check if undercollateralized
 			ensure!(
-				(collateral_ratio as u16) < T::LiquidationThreshold::get(),
+				collateral_ratio < T::LiquidationThreshold::get() as u128,
 				Error::<T>::PositionHealthy
 			);
 
-			// Liquidator must have enough EDSC
-			let liquidator_balance = EDSCBalances::<T>::get(&liquidator);
-			ensure!(
-				liquidator_balance >= debt_value,
-				Error::<T>::InsufficientEDSCBalance
-			);
+			let (debt_covered, lot) = Self::liquidation_amounts(collateral_value, debt_value)?;
+			let full = debt_covered == debt_value;
+			let lot = if full { position.collateral_amount } else { Self::u128_to_balance(lot)? };
 
-			// Calculate penalty
-			let penalty_amount = position.collateral_amount
-				.saturated_into::<u128>()
+			let penalty = debt_covered
 				.checked_mul(T::LiquidationPenalty::get() as u128)
 				.ok_or(Error::<T>::ArithmeticOverflow)?
-				.checked_div(10000)
-				.ok_or(Error::<T>::DivisionByZero)?;
-
-			let penalty = Self::u128_to_balance(penalty_amount)?;
-			let collateral_to_liquidator = position.collateral_amount
-				.checked_sub(&penalty)
-				.unwrap_or(position.collateral_amount);
-
-			// Burn liquidator's EDSC
-			EDSCBalances::<T>::mutate(&liquidator, |balance| {
-				*balance = balance.saturating_sub(debt_value);
-			});
-
-			// Update total supply
-			TotalEDSCSupply::<T>::mutate(|supply| {
-				*supply = supply.saturating_sub(debt_value);
-			});
-
-			// Transfer collateral to liquidator
-			T::Currency::unreserve(&owner, position.collateral_amount);
-			T::Currency::transfer(&owner, &liquidator, collateral_to_liquidator, ExistenceRequirement::KeepAlive)?;
-
-			// Send liquidation penalty to treasury using proper integration
-			if !penalty.is_zero() {
-				let treasury_account = T::TreasuryAccount::get();
-
-				// Transfer penalty from owner to treasury account
-				T::Currency::transfer(&owner, &treasury_account, penalty, ExistenceRequirement::KeepAlive)?;
-
-				// Route through treasury pallet for proper tracking
-				T::Treasury::receive_stability_fees(penalty)?;
+				/ 10000;
 
-				// Track in local stability fees storage
-				StabilityFees::<T>::mutate(|fees| {
-					*fees = fees.saturating_add(penalty);
-				});
+			let keeper_reward = Self::u128_to_balance(
+				Self::balance_to_u128(lot)?
+					.checked_mul(T::KeeperReward::get() as u128)
+					.ok_or(Error::<T>::ArithmeticOverflow)?
+					/ 10000,
+			)?;
+			let for_sale = lot.saturating_sub(keeper_reward);
+
+			// Move the lot out of the position: keeper reward to the keeper, the rest
+			// to the pallet account for auction. Liquidations must not fail on the
+			// owner's existential deposit.
+			T::Currency::unreserve(&owner, lot);
+			T::Currency::transfer(&owner, &Self::account_id(), for_sale, ExistenceRequirement::AllowDeath)?;
+			if !keeper_reward.is_zero() {
+				T::Currency::transfer(&owner, &keeper, keeper_reward, ExistenceRequirement::AllowDeath)?;
 			}
 
-			// Remove position
-			Positions::<T>::remove(&owner);
+			if full {
+				Positions::<T>::remove(&owner);
+			} else {
+				position.collateral_amount = position.collateral_amount
+					.checked_sub(&lot)
+					.ok_or(Error::<T>::ArithmeticUnderflow)?;
+				position.edsc_minted = position.edsc_minted
+					.checked_sub(debt_covered)
+					.ok_or(Error::<T>::ArithmeticUnderflow)?;
+				Positions::<T>::insert(&owner, position);
+			}
 
 			// Update reserve
 			EDSCReserveBalance::<T>::mutate(|balance| {
-				*balance = balance.saturating_sub(position.collateral_amount);
+				*balance = balance.saturating_sub(lot);
 			});
 
-			// Record liquidation
-			let liquidation_id = NextLiquidationId::<T>::get();
 			let current_block = <frame_system::Pallet<T>>::block_number()
 				.saturated_into::<u32>();
 
+			Auctions::<T>::insert(&owner, CollateralAuction {
+				keeper: keeper.clone(),
+				lot: for_sale,
+				debt: debt_covered,
+				penalty,
+				started_at: current_block,
+			});
+
+			// Record liquidation
+			let liquidation_id = NextLiquidationId::<T>::get();
+			let penalty_value = Self::u128_to_balance(penalty)?;
+
 			let record = EDSCLiquidation {
 				owner: owner.clone(),
-				liquidator: liquidator.clone(),
-				edsc_amount: debt_value,
-				collateral_seized: collateral_to_liquidator,
-				penalty_amount: penalty,
+				liquidator: keeper.clone(),
+				edsc_amount: debt_covered,
+				collateral_seized: lot,
+				penalty_amount: penalty_value,
 				block_number: current_block,
 			};
 			LiquidationHistory::<T>::insert(liquidation_id, record);
 			NextLiquidationId::<T>::put(liquidation_id.saturating_add(1));
 
 			Self::deposit_event(Event::PositionLiquidated {
+				owner: owner.clone(),
+				liquidator: keeper.clone(),
+				edsc_amount: debt_covered,
+				collateral_seized: lot,
+				penalty: penalty_value,
+			});
+			Self::deposit_event(Event::AuctionStarted {
 				owner,
-				liquidator,
-				edsc_amount: debt_value,
-				collateral_seized: collateral_to_liquidator,
+				keeper,
+				lot: for_sale,
+				debt: debt_covered,
 				penalty,
+				keeper_reward,
 			});
 
 			Ok(())
@@ -969,7 +1092,7 @@ pub mod pallet {
 
 		/// Deactivate emergency pause (governance only)
 		#[pallet::call_index(6)]
-		#[pallet::weight(10_000)]
+		#[pallet::weight(T::WeightInfo::deactivate_emergency_pause())]
 		pub fn deactivate_emergency_pause(origin: OriginFor<T>) -> DispatchResult {
 			let _ = ensure_root(origin)?;
 
@@ -992,7 +1115,7 @@ pub mod pallet {
 		/// - seth_allocation: sETH allocation in basis points
 		/// - other_allocation: Other assets allocation in basis points
 		#[pallet::call_index(7)]
-		#[pallet::weight(10_000)]
+		#[pallet::weight(T::WeightInfo::update_target_composition())]
 		pub fn update_target_composition(
 			origin: OriginFor<T>,
 			etr_allocation: u16,
@@ -1033,7 +1156,7 @@ pub mod pallet {
 		/// Parameters:
 		/// - amount: Additional collateral amount
 		#[pallet::call_index(8)]
-		#[pallet::weight(10_000)]
+		#[pallet::weight(T::WeightInfo::add_collateral())]
 		pub fn add_collateral(
 			origin: OriginFor<T>,
 			amount: BalanceOf<T>,
@@ -1073,7 +1196,7 @@ pub mod pallet {
 		/// Parameters:
 		/// - owner: Account with position to collect interest from
 		#[pallet::call_index(9)]
-		#[pallet::weight(20_000)]
+		#[pallet::weight(T::WeightInfo::collect_interest())]
 		pub fn collect_interest(
 			origin: OriginFor<T>,
 			owner: T::AccountId,
@@ -1122,6 +1245,143 @@ pub mod pallet {
 
 			Ok(())
 		}
+
+		/// Buy collateral from a liquidation auction
+		///
+		/// Pays the current auction price in EDSC. Payment first covers the debt
+		/// (burned), then the penalty (credited to treasury). Buys are capped at
+		/// what the remaining debt and penalty need; the auction settles once they
+		/// are paid or the lot is sold out.
+		///
+		/// Parameters:
+		/// - owner: Account whose collateral is being auctioned
+		/// - max_collateral: Most collateral to buy
+		/// - max_price: Highest acceptable price (basis points of collateral value)
+		#[pallet::call_index(10)]
+		#[pallet::weight(T::WeightInfo::take_collateral())]
+		pub fn take_collateral(
+			origin: OriginFor<T>,
+			owner: T::AccountId,
+			max_collateral: BalanceOf<T>,
+			max_price: u16,
+		) -> DispatchResult {
+			let buyer = ensure_signed(origin)?;
+
+			ensure!(!EmergencyPaused::<T>::get(), Error::<T>::SystemPaused);
+
+			let mut auction = Auctions::<T>::get(&owner)
+				.ok_or(Error::<T>::AuctionNotFound)?;
+
+			let current_block = <frame_system::Pallet<T>>::block_number()
+				.saturated_into::<u32>();
+			let price = Self::auction_price(auction.started_at, current_block);
+			ensure!(price <= max_price, Error::<T>::PriceAboveLimit);
+
+			let tab = auction.debt.saturating_add(auction.penalty);
+			let mut amount = Self::balance_to_u128(max_collateral.min(auction.lot))?;
+			let mut cost = amount
+				.checked_mul(price as u128)
+				.ok_or(Error::<T>::ArithmeticOverflow)?
+				.div_ceil(10000);
+			if cost > tab {
+				// Only buy what the tab needs (price is non-zero here)
+				amount = tab
+					.checked_mul(10000)
+					.ok_or(Error::<T>::ArithmeticOverflow)?
+					/ price as u128;
+				cost = tab;
+			}
+			ensure!(amount > 0, Error::<T>::NothingToTake);
+
+			ensure!(
+				EDSCBalances::<T>::get(&buyer) >= cost,
+				Error::<T>::InsufficientEDSCBalance
+			);
+
+			let debt_paid = cost.min(auction.debt);
+			let penalty_paid = cost.saturating_sub(debt_paid);
+			let collateral = Self::u128_to_balance(amount)?;
+
+			// Burn the debt share of the payment
+			EDSCBalances::<T>::mutate(&buyer, |balance| {
+				*balance = balance.saturating_sub(cost);
+			});
+			TotalEDSCSupply::<T>::mutate(|supply| {
+				*supply = supply.saturating_sub(debt_paid);
+			});
+
+			// Penalty share stays in circulation, held by the treasury
+			if penalty_paid > 0 {
+				EDSCBalances::<T>::mutate(T::TreasuryAccount::get(), |balance| {
+					*balance = balance.saturating_add(penalty_paid);
+				});
+				T::Treasury::receive_edsc_penalties(penalty_paid)?;
+			}
+
+			T::Currency::transfer(&Self::account_id(), &buyer, collateral, ExistenceRequirement::AllowDeath)?;
+
+			auction.lot = auction.lot.saturating_sub(collateral);
+			auction.debt = auction.debt.saturating_sub(debt_paid);
+			auction.penalty = auction.penalty.saturating_sub(penalty_paid);
+
+			Self::deposit_event(Event::CollateralTaken {
+				owner: owner.clone(),
+				buyer,
+				collateral,
+				price,
+				edsc_paid: cost,
+			});
+
+			if (auction.debt == 0 && auction.penalty == 0) || auction.lot.is_zero() {
+				Self::settle_auction(&owner, auction)?;
+			} else {
+				Auctions::<T>::insert(&owner, auction);
+			}
+
+			Ok(())
+		}
+
+		/// Close an auction that reached its floor price without clearing
+		///
+		/// Anyone can close an auction once its duration has passed.
+		///
+		/// Parameters:
+		/// - owner: Account whose collateral is being auctioned
+		#[pallet::call_index(11)]
+		#[pallet::weight(T::WeightInfo::close_auction())]
+		pub fn close_auction(
+			origin: OriginFor<T>,
+			owner: T::AccountId,
+		) -> DispatchResult {
+			let _ = ensure_signed(origin)?;
+
+			let auction = Auctions::<T>::get(&owner)
+				.ok_or(Error::<T>::AuctionNotFound)?;
+
+			let current_block = <frame_system::Pallet<T>>::block_number()
+				.saturated_into::<u32>();
+			ensure!(
+				current_block >= auction.started_at.saturating_add(T::AuctionDuration::get()),
+				Error::<T>::AuctionNotExpired
+			);
+
+			Self::settle_auction(&owner, auction)
+		}
+
+		/// Retry covering outstanding bad debt from treasury EDSC
+		///
+		/// Anyone can call this once the treasury holds EDSC again.
+		#[pallet::call_index(12)]
+		#[pallet::weight(T::WeightInfo::settle_bad_debt())]
+		pub fn settle_bad_debt(origin: OriginFor<T>) -> DispatchResult {
+			let _ = ensure_signed(origin)?;
+
+			ensure!(BadDebt::<T>::get() > 0, Error::<T>::NoBadDebt);
+
+			Self::cover_bad_debt();
+
+			Ok(())
+		}
 	}
 
 	// ===================== HELPER FUNCTIONS =====================
@@ -1168,6 +1428,149 @@ pub mod pallet {
 			Ok(interest)
 		}
 
+		/// Debt to cover and collateral to seize when liquidating a position
+		///
+		/// Covers just enough debt for the remaining position to sit at the target
+		/// ratio, seizing that debt plus penalty in collateral. Falls back to the
+		/// whole position when that is not possible or would leave dust below the
+		/// minimum mint.
+		fn liquidation_amounts(collateral: u128, debt: u128) -> Result<(u128, u128), DispatchError> {
+			let target = T::LiquidationTargetRatio::get() as u128;
+			let seize_bps = 10000u128.saturating_add(T::LiquidationPenalty::get() as u128);
+
+			// (collateral - d * seize) / (debt - d) = target  =>  d = (target * debt - collateral) / (target - seize)
+			let denominator = target.saturating_sub(seize_bps);
+			if denominator == 0 {
+				return Ok((debt, collateral));
+			}
+			let numerator = target
+				.checked_mul(debt)
+				.ok_or(Error::<T>::ArithmeticOverflow)?
+				.saturating_sub(collateral.checked_mul(10000).ok_or(Error::<T>::ArithmeticOverflow)?);
+			let covered = numerator.div_ceil(denominator);
+
+			if covered >= debt || debt - covered < T::MinEDSCMint::get() {
+				return Ok((debt, collateral));
+			}
+
+			let lot = covered
+				.checked_mul(seize_bps)
+				.ok_or(Error::<T>::ArithmeticOverflow)?
+				.div_ceil(10000)
+				.min(collateral);
+
+			Ok((covered, lot))
+		}
+
+		/// Auction price (basis points of collateral value) at `now`
+		///
+		/// Falls linearly from the start price to the floor price over the auction
+		/// duration, then stays at the floor.
+		pub fn auction_price(started_at: u32, now: u32) -> u16 {
+			let floor = T::AuctionFloorPrice::get();
+			let start = T::AuctionStartPrice::get().max(floor);
+			let duration = T::AuctionDuration::get();
+			let elapsed = now.saturating_sub(started_at);
+
+			if elapsed >= duration {
+				return floor;
+			}
+
+			let decay = (start - floor) as u64 * elapsed as u64 / duration as u64;
+			start - decay as u16
+		}
+
+		/// Current price of a position's running auction
+		pub fn current_auction_price(owner: &T::AccountId) -> Option<u16> {
+			let auction = Auctions::<T>::get(owner)?;
+			let now = <frame_system::Pallet<T>>::block_number().saturated_into::<u32>();
+			Some(Self::auction_price(auction.started_at, now))
+		}
+
+		/// End an auction
+		///
+		/// If the position still exists (partial liquidation), unsold collateral and
+		/// unpaid debt go back to it. A fully liquidated position's unsold collateral
+		/// goes to the treasury and its unpaid debt becomes bad debt; if its debt was
+		/// paid, leftovers return to the owner. Unpaid penalty is waived.
+		fn settle_auction(
+			owner: &T::AccountId,
+			auction: CollateralAuction<T::AccountId, BalanceOf<T>>,
+		) -> DispatchResult {
+			Auctions::<T>::remove(owner);
+
+			let pallet_account = Self::account_id();
+			let shortfall = auction.debt;
+			let lot = auction.lot;
+
+			if let Some(mut position) = Positions::<T>::get(owner) {
+				if !lot.is_zero() {
+					T::Currency::transfer(&pallet_account, owner, lot, ExistenceRequirement::AllowDeath)?;
+					T::Currency::reserve(owner, lot)?;
+					position.collateral_amount = position.collateral_amount
+						.checked_add(&lot)
+						.ok_or(Error::<T>::ArithmeticOverflow)?;
+					EDSCReserveBalance::<T>::mutate(|balance| {
+						*balance = balance.saturating_add(lot);
+					});
+				}
+				position.edsc_minted = position.edsc_minted.saturating_add(shortfall);
+				Positions::<T>::insert(owner, position);
+			} else if shortfall > 0 {
+				if !lot.is_zero() {
+					T::Currency::transfer(&pallet_account, &T::TreasuryAccount::get(), lot, ExistenceRequirement::AllowDeath)?;
+				}
+
+				BadDebt::<T>::mutate(|debt| {
+					*debt = debt.saturating_add(shortfall);
+				});
+				Self::deposit_event(Event::BadDebtRecorded {
+					owner: owner.clone(),
+					amount: shortfall,
+				});
+
+				Self::cover_bad_debt();
+			} else if !lot.is_zero() {
+				T::Currency::transfer(&pallet_account, owner, lot, ExistenceRequirement::AllowDeath)?;
+			}
+
+			Self::deposit_event(Event::AuctionSettled {
+				owner: owner.clone(),
+				unsold_collateral: lot,
+				shortfall,
+			});
+
+			Ok(())
+		}
+
+		/// Cover bad debt by burning treasury EDSC, as far as the treasury releases it
+		fn cover_bad_debt() {
+			let bad_debt = BadDebt::<T>::get();
+			let treasury_account = T::TreasuryAccount::get();
+			let requested = bad_debt.min(EDSCBalances::<T>::get(&treasury_account));
+			if requested == 0 {
+				return;
+			}
+
+			let covered = T::Treasury::cover_bad_debt(requested).min(requested);
+			if covered == 0 {
+				return;
+			}
+
+			EDSCBalances::<T>::mutate(&treasury_account, |balance| {
+				*balance = balance.saturating_sub(covered);
+			});
+			TotalEDSCSupply::<T>::mutate(|supply| {
+				*supply = supply.saturating_sub(covered);
+			});
+			BadDebt::<T>::put(bad_debt - covered);
+
+			Self::deposit_event(Event::BadDebtCovered {
+				amount: covered,
+				remaining: bad_debt - covered,
+			});
+		}
+
 		/// Calculate deviation between current and target composition
 		fn calculate_composition_deviation(
 			current: &ReserveComposition,
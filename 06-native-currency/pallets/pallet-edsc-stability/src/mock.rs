@@ -0,0 +1,119 @@
+//! Mock runtime for EDSC stability pallet tests
+
+use crate as pallet_edsc_stability;
+use crate::TreasuryInterface;
+use frame_support::{
+	derive_impl, parameter_types,
+	traits::{ConstU128, ConstU16, ConstU32},
+	PalletId,
+};
+use sp_runtime::{traits::IdentityLookup, BuildStorage, DispatchError};
+
+type Block = frame_system::mocking::MockBlock<Test>;
+
+frame_support::construct_runtime!(
+	pub enum Test
+	{
+		System: frame_system,
+		Balances: pallet_balances,
+		EdscStability: pallet_edsc_stability,
+	}
+);
+
+#[derive_impl(frame_system::config_preludes::TestDefaultConfig)]
+impl frame_system::Config for Test {
+	type Block = Block;
+	type AccountId = u64;
+	type Lookup = IdentityLookup<Self::AccountId>;
+	type AccountData = pallet_balances::AccountData<u128>;
+}
+
+parameter_types! {
+	pub const ExistentialDeposit: u128 = 1;
+	pub const EdscPalletId: PalletId = PalletId(*b"py/edscs");
+	pub const TreasuryAccount: u64 = TREASURY;
+	/// EDSC penalties reported to `MockTreasury`
+	pub static PenaltiesReceived: u128 = 0;
+	/// Treasury EDSC released by `MockTreasury` to cover bad debt
+	pub static BackstopDrawn: u128 = 0;
+}
+
+#[derive_impl(pallet_balances::config_preludes::TestDefaultConfig)]
+impl pallet_balances::Config for Test {
+	type Balance = u128;
+	type ExistentialDeposit = ExistentialDeposit;
+	type AccountStore = System;
+}
+
+/// Treasury that records penalties and releases all EDSC requested for bad debt
+pub struct MockTreasury;
+impl TreasuryInterface<u64, u128> for MockTreasury {
+	fn receive_stability_fees(_amount: u128) -> Result<(), DispatchError> {
+		Ok(())
+	}
+
+	fn receive_edsc_penalties(amount: u128) -> Result<(), DispatchError> {
+		PenaltiesReceived::mutate(|received| *received += amount);
+		Ok(())
+	}
+
+	fn cover_bad_debt(amount: u128) -> u128 {
+		BackstopDrawn::mutate(|drawn| *drawn += amount);
+		amount
+	}
+}
+
+/// Blocks for the auction price to fall from start to floor
+pub const AUCTION_DURATION: u32 = 100;
+
+impl pallet_edsc_stability::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type Currency = Balances;
+	type MinCollateralRatio = ConstU16<15000>;
+	type LiquidationThreshold = ConstU16<12000>;
+	type LiquidationPenalty = ConstU16<500>;
+	type LiquidationTargetRatio = ConstU16<16000>;
+	type AuctionStartPrice = ConstU16<12000>;
+	type AuctionFloorPrice = ConstU16<5000>;
+	type AuctionDuration = ConstU32<AUCTION_DURATION>;
+	type KeeperReward = ConstU16<50>;
+	type RebalanceThreshold = ConstU16<500>;
+	type EmergencyPauseThreshold = ConstU16<1000>;
+	type MinEDSCMint = ConstU128<100>;
+	type BaseInterestRate = ConstU16<500>;
+	type PalletId = EdscPalletId;
+	type TreasuryAccount = TreasuryAccount;
+	type Treasury = MockTreasury;
+	type PriceFeed = ();
+	type WeightInfo = ();
+}
+
+pub const OWNER: u64 = 1;
+pub const KEEPER: u64 = 2;
+pub const BUYER: u64 = 3;
+pub const TREASURY: u64 = 99;
+pub const INITIAL_BALANCE: u128 = 100_000;
+
+// Build genesis storage; owner, keeper and buyer are funded
+pub fn new_test_ext() -> sp_io::TestExternalities {
+	let mut t = frame_system::GenesisConfig::<Test>::default().build_storage().unwrap();
+
+	pallet_balances::GenesisConfig::<Test> {
+		balances: vec![(OWNER, INITIAL_BALANCE), (KEEPER, INITIAL_BALANCE), (BUYER, INITIAL_BALANCE)],
+		..Default::default()
+	}
+	.assimilate_storage(&mut t)
+	.unwrap();
+
+	pallet_edsc_stability::GenesisConfig::<Test>::default()
+		.assimilate_storage(&mut t)
+		.unwrap();
+
+	let mut ext = sp_io::TestExternalities::new(t);
+	ext.execute_with(|| {
+		System::set_block_number(1);
+		PenaltiesReceived::set(0);
+		BackstopDrawn::set(0);
+	});
+	ext
+}
@@ -0,0 +1,208 @@
+//! Tests for EDSC stability pallet
+
+use crate::{
+	mock::*, Auctions, BadDebt, EDSCBalances, EDSCPosition, EDSCReserveBalance, Error, Event,
+	Positions, TotalEDSCSupply,
+};
+use frame_support::{assert_noop, assert_ok, traits::ReservableCurrency};
+
+/// Open a position for `who` at any ratio, minting `debt` EDSC to it
+fn open_position(who: u64, collateral: u128, debt: u128) {
+	assert_ok!(Balances::reserve(&who, collateral));
+	Positions::<Test>::insert(who, EDSCPosition {
+		collateral_amount: collateral,
+		edsc_minted: debt,
+		interest_rate: 0,
+		interest_owed: 0,
+		last_interest_update: 1,
+		created_at: 1,
+	});
+	EDSCReserveBalance::<Test>::mutate(|balance| *balance += collateral);
+	give_edsc(who, debt);
+}
+
+fn give_edsc(who: u64, amount: u128) {
+	EDSCBalances::<Test>::mutate(who, |balance| *balance += amount);
+	TotalEDSCSupply::<Test>::mutate(|supply| *supply += amount);
+}
+
+fn liquidate(owner: u64) -> frame_support::dispatch::DispatchResult {
+	EdscStability::liquidate_position(RuntimeOrigin::signed(KEEPER), owner)
+}
+
+fn take(max_collateral: u128, max_price: u16) -> frame_support::dispatch::DispatchResult {
+	EdscStability::take_collateral(RuntimeOrigin::signed(BUYER), OWNER, max_collateral, max_price)
+}
+
+#[test]
+fn auction_price_decays_linearly_to_floor() {
+	new_test_ext().execute_with(|| {
+		let price = |now| EdscStability::auction_price(10, now);
+
+		assert_eq!(price(5), 12000);
+		assert_eq!(price(10), 12000);
+		assert_eq!(price(60), 8500);
+		assert_eq!(price(109), 5070);
+		assert_eq!(price(10 + AUCTION_DURATION), 5000);
+		assert_eq!(price(1_000), 5000);
+		for now in 10..=10 + AUCTION_DURATION {
+			assert!(price(now) >= price(now + 1));
+		}
+
+		// Running auctions follow the same curve
+		open_position(OWNER, 10_000, 10_000);
+		assert_ok!(liquidate(OWNER));
+		assert_eq!(EdscStability::current_auction_price(&OWNER), Some(12000));
+		System::set_block_number(51);
+		assert_eq!(EdscStability::current_auction_price(&OWNER), Some(8500));
+		assert_eq!(EdscStability::current_auction_price(&KEEPER), None);
+	});
+}
+
+#[test]
+fn partial_liquidation_restores_target_ratio() {
+	new_test_ext().execute_with(|| {
+		open_position(OWNER, 16_000, 10_000);
+		assert_noop!(liquidate(OWNER), Error::<Test>::PositionHealthy);
+		Positions::<Test>::remove(OWNER);
+		assert_ok!(Balances::unreserve(&OWNER, 16_000));
+
+		// 110%: covering 9,091 EDSC (seizing it plus 5%) leaves 909 EDSC at 160%
+		open_position(OWNER, 11_000, 10_000);
+		assert_ok!(liquidate(OWNER));
+
+		let position = Positions::<Test>::get(OWNER).unwrap();
+		assert_eq!((position.collateral_amount, position.edsc_minted), (1_454, 909));
+		assert_eq!(EdscStability::get_position_collateral_ratio(&OWNER), Some(15995));
+		assert_eq!(Balances::reserved_balance(&OWNER), 1_454);
+
+		let auction = Auctions::<Test>::get(OWNER).unwrap();
+		assert_eq!((auction.lot, auction.debt, auction.penalty), (9_499, 9_091, 454));
+		assert_eq!(Balances::free_balance(&EdscStability::account_id()), 9_499);
+		assert_eq!(Balances::free_balance(&KEEPER), INITIAL_BALANCE + 47);
+		System::assert_last_event(
+			Event::AuctionStarted {
+				owner: OWNER,
+				keeper: KEEPER,
+				lot: 9_499,
+				debt: 9_091,
+				penalty: 454,
+				keeper_reward: 47,
+			}
+			.into(),
+		);
+		assert_noop!(liquidate(OWNER), Error::<Test>::AuctionInProgress);
+
+		// Unsold collateral and unpaid debt go back to the position
+		System::set_block_number(1 + AUCTION_DURATION);
+		assert_ok!(EdscStability::close_auction(RuntimeOrigin::signed(BUYER), OWNER));
+		let position = Positions::<Test>::get(OWNER).unwrap();
+		assert_eq!((position.collateral_amount, position.edsc_minted), (10_953, 10_000));
+		assert_eq!(Balances::reserved_balance(&OWNER), 10_953);
+		assert_eq!(BadDebt::<Test>::get(), 0);
+	});
+}
+
+#[test]
+fn partial_fill_pays_down_debt() {
+	new_test_ext().execute_with(|| {
+		// 100%: the whole position is liquidated
+		open_position(OWNER, 10_000, 10_000);
+		give_edsc(BUYER, 20_000);
+		assert_ok!(liquidate(OWNER));
+		assert!(Positions::<Test>::get(OWNER).is_none());
+
+		System::set_block_number(51);
+		assert_noop!(take(1_000, 8_499), Error::<Test>::PriceAboveLimit);
+		assert_ok!(take(1_000, 8_500));
+
+		// 1,000 collateral at 85% costs 850 EDSC, all of it burned against the debt
+		System::assert_last_event(
+			Event::CollateralTaken { owner: OWNER, buyer: BUYER, collateral: 1_000, price: 8_500, edsc_paid: 850 }
+				.into(),
+		);
+		let auction = Auctions::<Test>::get(OWNER).unwrap();
+		assert_eq!((auction.lot, auction.debt, auction.penalty), (8_950, 9_150, 500));
+		assert_eq!(EDSCBalances::<Test>::get(BUYER), 19_150);
+		assert_eq!(TotalEDSCSupply::<Test>::get(), 29_150);
+		assert_eq!(Balances::free_balance(&BUYER), INITIAL_BALANCE + 1_000);
+		assert_eq!(PenaltiesReceived::get(), 0);
+	});
+}
+
+#[test]
+fn full_fill_settles_auction() {
+	new_test_ext().execute_with(|| {
+		open_position(OWNER, 10_000, 10_000);
+		give_edsc(BUYER, 20_000);
+		assert_ok!(liquidate(OWNER));
+		assert_eq!(Balances::free_balance(&OWNER), INITIAL_BALANCE - 10_000);
+
+		// At 120% the 10,500 EDSC tab buys 8,750 of the 9,950 lot
+		assert_ok!(take(9_950, 12_000));
+
+		System::assert_has_event(
+			Event::CollateralTaken { owner: OWNER, buyer: BUYER, collateral: 8_750, price: 12_000, edsc_paid: 10_500 }
+				.into(),
+		);
+		assert!(!Auctions::<Test>::contains_key(OWNER));
+		assert_eq!(EDSCBalances::<Test>::get(BUYER), 9_500);
+		assert_eq!(Balances::free_balance(&BUYER), INITIAL_BALANCE + 8_750);
+
+		// Debt is burned and the penalty credited to the treasury
+		assert_eq!(TotalEDSCSupply::<Test>::get(), 20_000);
+		assert_eq!(EDSCBalances::<Test>::get(TREASURY), 500);
+		assert_eq!(PenaltiesReceived::get(), 500);
+
+		// Leftover collateral returns to the owner
+		System::assert_last_event(
+			Event::AuctionSettled { owner: OWNER, unsold_collateral: 1_200, shortfall: 0 }.into(),
+		);
+		assert_eq!(Balances::free_balance(&OWNER), INITIAL_BALANCE - 10_000 + 1_200);
+		assert_eq!(Balances::free_balance(&EdscStability::account_id()), 0);
+		assert_eq!(BadDebt::<Test>::get(), 0);
+	});
+}
+
+#[test]
+fn closing_with_shortfall_draws_treasury_backstop() {
+	new_test_ext().execute_with(|| {
+		open_position(OWNER, 10_000, 10_000);
+		give_edsc(TREASURY, 4_000);
+		assert_ok!(liquidate(OWNER));
+
+		System::set_block_number(AUCTION_DURATION);
+		assert_noop!(
+			EdscStability::close_auction(RuntimeOrigin::signed(BUYER), OWNER),
+			Error::<Test>::AuctionNotExpired
+		);
+
+		System::set_block_number(1 + AUCTION_DURATION);
+		assert_ok!(EdscStability::close_auction(RuntimeOrigin::signed(BUYER), OWNER));
+
+		// Unsold collateral goes to the treasury and the unpaid debt is bad debt
+		System::assert_has_event(Event::BadDebtRecorded { owner: OWNER, amount: 10_000 }.into());
+		System::assert_last_event(
+			Event::AuctionSettled { owner: OWNER, unsold_collateral: 9_950, shortfall: 10_000 }.into(),
+		);
+		assert_eq!(Balances::free_balance(&TREASURY), 9_950);
+
+		// The treasury's 4,000 EDSC are burned against it
+		System::assert_has_event(Event::BadDebtCovered { amount: 4_000, remaining: 6_000 }.into());
+		assert_eq!(BackstopDrawn::get(), 4_000);
+		assert_eq!(EDSCBalances::<Test>::get(TREASURY), 0);
+		assert_eq!(BadDebt::<Test>::get(), 6_000);
+		assert_eq!(TotalEDSCSupply::<Test>::get(), 10_000);
+
+		// The rest is covered once the treasury holds EDSC again
+		give_edsc(TREASURY, 6_000);
+		assert_ok!(EdscStability::settle_bad_debt(RuntimeOrigin::signed(BUYER)));
+		System::assert_last_event(Event::BadDebtCovered { amount: 6_000, remaining: 0 }.into());
+		assert_eq!(BackstopDrawn::get(), 10_000);
+		assert_eq!(BadDebt::<Test>::get(), 0);
+		assert_noop!(
+			EdscStability::settle_bad_debt(RuntimeOrigin::signed(BUYER)),
+			Error::<Test>::NoBadDebt
+		);
+	});
+}
@@ -0,0 +1,312 @@
+//! PLACEHOLDER weights for `pallet_edsc_stability`: hand-estimated, not benchmark output.
+//!
+//! Overwrite with `./scripts/benchmark-pallets.sh pallet_edsc_stability` on reference hardware.
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(missing_docs)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use core::marker::PhantomData;
+
+/// Weight functions needed for `pallet_edsc_stability`.
+pub trait WeightInfo {
+	fn deposit_collateral_mint_edsc() -> Weight;
+	fn burn_edsc_withdraw_collateral() -> Weight;
+	fn liquidate_position() -> Weight;
+	fn trigger_rebalance() -> Weight;
+	fn adjust_interest_rate() -> Weight;
+	fn emergency_pause() -> Weight;
+	fn deactivate_emergency_pause() -> Weight;
+	fn update_target_composition() -> Weight;
+	fn add_collateral() -> Weight;
+	fn collect_interest() -> Weight;
+	fn take_collateral() -> Weight;
+	fn close_auction() -> Weight;
+	fn settle_bad_debt() -> Weight;
+	fn on_initialize() -> Weight;
+}
+
+/// Placeholder weights for `pallet_edsc_stability`, pending benchmarking.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	/// Storage: `EdscStability::EmergencyPaused` (r:1 w:0)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Storage: `EdscStability::InterestRate` (r:1 w:0)
+	/// Storage: `EdscStability::Positions` (r:1 w:1)
+	/// Storage: `EdscStability::EDSCBalances` (r:1 w:1)
+	/// Storage: `EdscStability::TotalEDSCSupply` (r:1 w:1)
+	/// Storage: `EdscStability::EDSCReserveBalance` (r:1 w:1)
+	fn deposit_collateral_mint_edsc() -> Weight {
+		Weight::from_parts(46_210_000, 10728)
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+	/// Storage: `EdscStability::EDSCBalances` (r:1 w:1)
+	/// Storage: `EdscStability::Positions` (r:1 w:1)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Storage: `Balances::TotalIssuance` (r:1 w:1)
+	/// Storage: `EtridTreasury::TreasuryBalance` (r:1 w:1)
+	/// Storage: `EtridTreasury::FundingSourceTotals` (r:1 w:1)
+	/// Storage: `EdscStability::StabilityFees` (r:1 w:1)
+	/// Storage: `EdscStability::TotalEDSCSupply` (r:1 w:1)
+	/// Storage: `EdscStability::EDSCReserveBalance` (r:1 w:1)
+	fn burn_edsc_withdraw_collateral() -> Weight {
+		Weight::from_parts(78_530_000, 16379)
+			.saturating_add(T::DbWeight::get().reads(10_u64))
+			.saturating_add(T::DbWeight::get().writes(10_u64))
+	}
+	/// Storage: `EdscStability::EmergencyPaused` (r:1 w:0)
+	/// Storage: `EdscStability::Auctions` (r:1 w:1)
+	/// Storage: `EdscStability::Positions` (r:1 w:1)
+	/// Storage: `System::Account` (r:3 w:3)
+	/// Storage: `EdscStability::EDSCReserveBalance` (r:1 w:1)
+	/// Storage: `EdscStability::NextLiquidationId` (r:1 w:1)
+	/// Storage: `EdscStability::LiquidationHistory` (r:0 w:1)
+	fn liquidate_position() -> Weight {
+		Weight::from_parts(88_940_000, 15493)
+			.saturating_add(T::DbWeight::get().reads(8_u64))
+			.saturating_add(T::DbWeight::get().writes(8_u64))
+	}
+	/// Storage: `EdscStability::EmergencyPaused` (r:1 w:0)
+	/// Storage: `EdscStability::CurrentReserveComposition` (r:1 w:1)
+	/// Storage: `EdscStability::TargetReserveComposition` (r:1 w:0)
+	/// Storage: `EdscStability::NextRebalanceId` (r:1 w:1)
+	/// Storage: `EdscStability::EDSCReserveBalance` (r:1 w:0)
+	/// Storage: `EdscStability::LastRebalanceBlock` (r:0 w:1)
+	/// Storage: `EdscStability::RebalanceHistory` (r:0 w:1)
+	fn trigger_rebalance() -> Weight {
+		Weight::from_parts(21_370_000, 3502)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: `EdscStability::InterestRate` (r:1 w:1)
+	fn adjust_interest_rate() -> Weight {
+		Weight::from_parts(9_120_000, 1487)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `EdscStability::EDSCPrice` (r:1 w:0)
+	/// Storage: `EdscStability::EmergencyPaused` (r:0 w:1)
+	fn emergency_pause() -> Weight {
+		Weight::from_parts(10_040_000, 1489)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `EdscStability::EmergencyPaused` (r:0 w:1)
+	fn deactivate_emergency_pause() -> Weight {
+		Weight::from_parts(7_610_000, 990)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `EdscStability::TargetReserveComposition` (r:0 w:1)
+	fn update_target_composition() -> Weight {
+		Weight::from_parts(8_230_000, 990)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `EdscStability::Positions` (r:1 w:1)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Storage: `EdscStability::EDSCReserveBalance` (r:1 w:1)
+	fn add_collateral() -> Weight {
+		Weight::from_parts(36_480_000, 6685)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: `EdscStability::Positions` (r:1 w:1)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Storage: `Balances::TotalIssuance` (r:1 w:1)
+	/// Storage: `EtridTreasury::TreasuryBalance` (r:1 w:1)
+	/// Storage: `EtridTreasury::FundingSourceTotals` (r:1 w:1)
+	/// Storage: `EdscStability::StabilityFees` (r:1 w:1)
+	fn collect_interest() -> Weight {
+		Weight::from_parts(57_660_000, 10215)
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
+	}
+	/// Storage: `EdscStability::EmergencyPaused` (r:1 w:0)
+	/// Storage: `EdscStability::Auctions` (r:1 w:1)
+	/// Storage: `EdscStability::EDSCBalances` (r:2 w:2)
+	/// Storage: `EdscStability::TotalEDSCSupply` (r:1 w:1)
+	/// Storage: `EtridTreasury::EdscBalance` (r:1 w:1)
+	/// Storage: `System::Account` (r:3 w:3)
+	/// Storage: `EdscStability::Positions` (r:1 w:1)
+	/// Storage: `EdscStability::EDSCReserveBalance` (r:1 w:1)
+	fn take_collateral() -> Weight {
+		Weight::from_parts(97_850_000, 21094)
+			.saturating_add(T::DbWeight::get().reads(11_u64))
+			.saturating_add(T::DbWeight::get().writes(10_u64))
+	}
+	/// Storage: `EdscStability::Auctions` (r:1 w:1)
+	/// Storage: `EdscStability::Positions` (r:1 w:0)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Storage: `EdscStability::BadDebt` (r:2 w:1)
+	/// Storage: `EdscStability::EDSCBalances` (r:1 w:1)
+	/// Storage: `EtridTreasury::EdscBalance` (r:1 w:1)
+	/// Storage: `EdscStability::TotalEDSCSupply` (r:1 w:1)
+	fn close_auction() -> Weight {
+		Weight::from_parts(81_290_000, 15967)
+			.saturating_add(T::DbWeight::get().reads(9_u64))
+			.saturating_add(T::DbWeight::get().writes(7_u64))
+	}
+	/// Storage: `EdscStability::BadDebt` (r:2 w:1)
+	/// Storage: `EdscStability::EDSCBalances` (r:1 w:1)
+	/// Storage: `EtridTreasury::EdscBalance` (r:1 w:1)
+	/// Storage: `EdscStability::TotalEDSCSupply` (r:1 w:1)
+	fn settle_bad_debt() -> Weight {
+		Weight::from_parts(31_750_000, 5573)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: `OracleNetwork::LatestPrices` (r:1 w:0)
+	/// Storage: `OracleNetwork::Feeds` (r:1 w:0)
+	/// Storage: `EdscStability::EDSCPrice` (r:0 w:1)
+	/// Storage: `EdscStability::TotalEDSCSupply` (r:1 w:0)
+	/// Storage: `EdscStability::EDSCReserveBalance` (r:1 w:0)
+	/// Storage: `EdscStability::CollateralizationRatio` (r:0 w:1)
+	fn on_initialize() -> Weight {
+		Weight::from_parts(13_820_000, 7080)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+}
+
+// For backwards compatibility and tests.
+impl WeightInfo for () {
+	/// Storage: `EdscStability::EmergencyPaused` (r:1 w:0)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Storage: `EdscStability::InterestRate` (r:1 w:0)
+	/// Storage: `EdscStability::Positions` (r:1 w:1)
+	/// Storage: `EdscStability::EDSCBalances` (r:1 w:1)
+	/// Storage: `EdscStability::TotalEDSCSupply` (r:1 w:1)
+	/// Storage: `EdscStability::EDSCReserveBalance` (r:1 w:1)
+	fn deposit_collateral_mint_edsc() -> Weight {
+		Weight::from_parts(46_210_000, 10728)
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
+	/// Storage: `EdscStability::EDSCBalances` (r:1 w:1)
+	/// Storage: `EdscStability::Positions` (r:1 w:1)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Storage: `Balances::TotalIssuance` (r:1 w:1)
+	/// Storage: `EtridTreasury::TreasuryBalance` (r:1 w:1)
+	/// Storage: `EtridTreasury::FundingSourceTotals` (r:1 w:1)
+	/// Storage: `EdscStability::StabilityFees` (r:1 w:1)
+	/// Storage: `EdscStability::TotalEDSCSupply` (r:1 w:1)
+	/// Storage: `EdscStability::EDSCReserveBalance` (r:1 w:1)
+	fn burn_edsc_withdraw_collateral() -> Weight {
+		Weight::from_parts(78_530_000, 16379)
+			.saturating_add(RocksDbWeight::get().reads(10_u64))
+			.saturating_add(RocksDbWeight::get().writes(10_u64))
+	}
+	/// Storage: `EdscStability::EmergencyPaused` (r:1 w:0)
+	/// Storage: `EdscStability::Auctions` (r:1 w:1)
+	/// Storage: `EdscStability::Positions` (r:1 w:1)
+	/// Storage: `System::Account` (r:3 w:3)
+	/// Storage: `EdscStability::EDSCReserveBalance` (r:1 w:1)
+	/// Storage: `EdscStability::NextLiquidationId` (r:1 w:1)
+	/// Storage: `EdscStability::LiquidationHistory` (r:0 w:1)
+	fn liquidate_position() -> Weight {
+		Weight::from_parts(88_940_000, 15493)
+			.saturating_add(RocksDbWeight::get().reads(8_u64))
+			.saturating_add(RocksDbWeight::get().writes(8_u64))
+	}
+	/// Storage: `EdscStability::EmergencyPaused` (r:1 w:0)
+	/// Storage: `EdscStability::CurrentReserveComposition` (r:1 w:1)
+	/// Storage: `EdscStability::TargetReserveComposition` (r:1 w:0)
+	/// Storage: `EdscStability::NextRebalanceId` (r:1 w:1)
+	/// Storage: `EdscStability::EDSCReserveBalance` (r:1 w:0)
+	/// Storage: `EdscStability::LastRebalanceBlock` (r:0 w:1)
+	/// Storage: `EdscStability::RebalanceHistory` (r:0 w:1)
+	fn trigger_rebalance() -> Weight {
+		Weight::from_parts(21_370_000, 3502)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: `EdscStability::InterestRate` (r:1 w:1)
+	fn adjust_interest_rate() -> Weight {
+		Weight::from_parts(9_120_000, 1487)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `EdscStability::EDSCPrice` (r:1 w:0)
+	/// Storage: `EdscStability::EmergencyPaused` (r:0 w:1)
+	fn emergency_pause() -> Weight {
+		Weight::from_parts(10_040_000, 1489)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `EdscStability::EmergencyPaused` (r:0 w:1)
+	fn deactivate_emergency_pause() -> Weight {
+		Weight::from_parts(7_610_000, 990)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `EdscStability::TargetReserveComposition` (r:0 w:1)
+	fn update_target_composition() -> Weight {
+		Weight::from_parts(8_230_000, 990)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `EdscStability::Positions` (r:1 w:1)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Storage: `EdscStability::EDSCReserveBalance` (r:1 w:1)
+	fn add_collateral() -> Weight {
+		Weight::from_parts(36_480_000, 6685)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: `EdscStability::Positions` (r:1 w:1)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Storage: `Balances::TotalIssuance` (r:1 w:1)
+	/// Storage: `EtridTreasury::TreasuryBalance` (r:1 w:1)
+	/// Storage: `EtridTreasury::FundingSourceTotals` (r:1 w:1)
+	/// Storage: `EdscStability::StabilityFees` (r:1 w:1)
+	fn collect_interest() -> Weight {
+		Weight::from_parts(57_660_000, 10215)
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
+	}
+	/// Storage: `EdscStability::EmergencyPaused` (r:1 w:0)
+	/// Storage: `EdscStability::Auctions` (r:1 w:1)
+	/// Storage: `EdscStability::EDSCBalances` (r:2 w:2)
+	/// Storage: `EdscStability::TotalEDSCSupply` (r:1 w:1)
+	/// Storage: `EtridTreasury::EdscBalance` (r:1 w:1)
+	/// Storage: `System::Account` (r:3 w:3)
+	/// Storage: `EdscStability::Positions` (r:1 w:1)
+	/// Storage: `EdscStability::EDSCReserveBalance` (r:1 w:1)
+	fn take_collateral() -> Weight {
+		Weight::from_parts(97_850_000, 21094)
+			.saturating_add(RocksDbWeight::get().reads(11_u64))
+			.saturating_add(RocksDbWeight::get().writes(10_u64))
+	}
+	/// Storage: `EdscStability::Auctions` (r:1 w:1)
+	/// Storage: `EdscStability::Positions` (r:1 w:0)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Storage: `EdscStability::BadDebt` (r:2 w:1)
+	/// Storage: `EdscStability::EDSCBalances` (r:1 w:1)
+	/// Storage: `EtridTreasury::EdscBalance` (r:1 w:1)
+	/// Storage: `EdscStability::TotalEDSCSupply` (r:1 w:1)
+	fn close_auction() -> Weight {
+		Weight::from_parts(81_290_000, 15967)
+			.saturating_add(RocksDbWeight::get().reads(9_u64))
+			.saturating_add(RocksDbWeight::get().writes(7_u64))
+	}
+	/// Storage: `EdscStability::BadDebt` (r:2 w:1)
+	/// Storage: `EdscStability::EDSCBalances` (r:1 w:1)
+	/// Storage: `EtridTreasury::EdscBalance` (r:1 w:1)
+	/// Storage: `EdscStability::TotalEDSCSupply` (r:1 w:1)
+	fn settle_bad_debt() -> Weight {
+		Weight::from_parts(31_750_000, 5573)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: `OracleNetwork::LatestPrices` (r:1 w:0)
+	/// Storage: `OracleNetwork::Feeds` (r:1 w:0)
+	/// Storage: `EdscStability::EDSCPrice` (r:0 w:1)
+	/// Storage: `EdscStability::TotalEDSCSupply` (r:1 w:0)
+	/// Storage: `EdscStability::EDSCReserveBalance` (r:1 w:0)
+	/// Storage: `EdscStability::CollateralizationRatio` (r:0 w:1)
+	fn on_initialize() -> Weight {
+		Weight::from_parts(13_820_000, 7080)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+}
@@ -0,0 +1,59 @@
+//! Benchmarking setup for pallet-etr-lock
+//!
+//! Every benchmark runs against a full 1000-entry lock history, which is
+//! rewritten on each lock and dominates the proof size.
+
+#![cfg(feature = "runtime-benchmarks")]
+
+use super::*;
+use frame_benchmarking::v2::*;
+use frame_support::traits::Currency;
+use frame_system::RawOrigin;
+use sp_runtime::traits::Zero;
+use sp_std::vec;
+
+/// Amount locked by the benchmarks
+fn amount<T: Config>() -> BalanceOf<T> {
+    T::Currency::minimum_balance().max(1u32.into()) * 1_000u32.into()
+}
+
+#[benchmarks]
+mod benchmarks {
+    use super::*;
+
+    #[benchmark]
+    fn set_lock_account() -> Result<(), BenchmarkError> {
+        let origin =
+            T::BridgeOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+        let account: T::AccountId = account("lock", 1, 0);
+
+        #[extrinsic_call]
+        _(origin as T::RuntimeOrigin, account.clone());
+
+        assert_eq!(LockAccount::<T>::get(), Some(account));
+        Ok(())
+    }
+
+    #[benchmark]
+    fn lock_for_bridge() {
+        let caller: T::AccountId = whitelisted_caller();
+        Pallet::<T>::prepare_benchmark(ChainId::Base, amount::<T>());
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller), ChainId::Base, amount::<T>(), vec![0u8; 128]);
+
+        assert_eq!(LockEvents::<T>::get().len(), 1000);
+    }
+
+    #[benchmark]
+    fn unlock_from_bridge() {
+        let caller: T::AccountId = whitelisted_caller();
+        Pallet::<T>::prepare_benchmark(ChainId::Base, amount::<T>());
+
+        // Leave a remainder so the lock is updated rather than removed
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller), ChainId::Base, amount::<T>() / 2u32.into());
+
+        assert!(!TotalLocked::<T>::get().is_zero());
+    }
+}
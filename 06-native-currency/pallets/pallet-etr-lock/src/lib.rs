@@ -8,6 +8,11 @@ pub use codec::{Encode, Decode, DecodeWithMemTracking};
 pub use scale_info::TypeInfo;
 pub use pallet::*;
 
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+pub mod weights;
+pub use weights::WeightInfo;
+
 #[frame_support::pallet]
 pub mod pallet {
     use codec::{Encode, Decode, DecodeWithMemTracking};
@@ -19,8 +24,9 @@ pub mod pallet {
     use frame_system::pallet_prelude::*;
     use sp_runtime::traits::{CheckedAdd, CheckedSub};
     use sp_std::vec::Vec;
+    use crate::WeightInfo;
 
-    type BalanceOf<T> = <<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
+    pub(crate) type BalanceOf<T> = <<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
 
     /// Chain identifier for PBC/external chains
     #[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
@@ -111,6 +117,9 @@ pub mod pallet {
         /// Lock identifier for currency locking
         #[pallet::constant]
         type LockIdentifier: Get<[u8; 8]>;
+
+        /// Weight information for extrinsics
+        type WeightInfo: WeightInfo;
     }
 
     /// Total amount locked across all chains
@@ -200,7 +209,7 @@ pub mod pallet {
         ///
         /// Requires: Foundation multisig
         #[pallet::call_index(0)]
-        #[pallet::weight(10_000)]
+        #[pallet::weight(T::WeightInfo::set_lock_account())]
         pub fn set_lock_account(
             origin: OriginFor<T>,
             account: T::AccountId,
@@ -224,7 +233,7 @@ pub mod pallet {
         ///
         /// Callable by: Bridge pallets or authorized origin
         #[pallet::call_index(1)]
-        #[pallet::weight(100_000)]
+        #[pallet::weight(T::WeightInfo::lock_for_bridge())]
         pub fn lock_for_bridge(
             origin: OriginFor<T>,
             chain_id: ChainId,
@@ -304,7 +313,7 @@ pub mod pallet {
         ///
         /// Callable by: Bridge pallets or authorized origin
         #[pallet::call_index(2)]
-        #[pallet::weight(100_000)]
+        #[pallet::weight(T::WeightInfo::unlock_from_bridge())]
         pub fn unlock_from_bridge(
             origin: OriginFor<T>,
             chain_id: ChainId,
@@ -374,4 +383,38 @@ pub mod pallet {
             TotalLocked::<T>::get()
         }
     }
+
+    #[cfg(feature = "runtime-benchmarks")]
+    impl<T: Config> Pallet<T> {
+        /// Set up a funded lock account with `amount` locked for `chain_id` and a full
+        /// lock history, so bridge benchmarks lock and unlock against worst-case storage
+        pub fn prepare_benchmark(chain_id: ChainId, amount: BalanceOf<T>) -> T::AccountId {
+            use sp_runtime::traits::Saturating;
+
+            let lock_account: T::AccountId = frame_benchmarking::account("lock", 0, 0);
+            let free = amount
+                .saturating_add(T::Currency::minimum_balance())
+                .saturating_mul(4u32.into());
+            T::Currency::make_free_balance_be(&lock_account, free);
+            LockAccount::<T>::put(&lock_account);
+            TotalLocked::<T>::put(amount);
+            LockedForChain::<T>::insert(chain_id, amount);
+            T::Currency::set_lock(
+                T::LockIdentifier::get(),
+                &lock_account,
+                amount,
+                frame_support::traits::WithdrawReasons::all(),
+            );
+
+            let event = LockEvent::<T> {
+                chain_id,
+                amount,
+                locker: lock_account.clone(),
+                block_number: Default::default(),
+                destination_address: BoundedVec::truncate_from(sp_std::vec![0u8; 128]),
+            };
+            LockEvents::<T>::put(BoundedVec::truncate_from(sp_std::vec![event; 1000]));
+            lock_account
+        }
+    }
 }
@@ -0,0 +1,300 @@
+//! Benchmarking setup for pallet-oracle-network
+//!
+//! The active oracle list is filled to one below `MaxOracles`, since registration,
+//! deregistration, reactivation and slashing all scan it. Feed benchmarks run with
+//! `MaxFeeds` registered feeds and every other feed pending a round.
+
+#![cfg(feature = "runtime-benchmarks")]
+
+use super::*;
+use codec::Decode;
+use etrid_oracle_primitives::FeedId;
+use frame_benchmarking::v2::*;
+use frame_support::{
+	assert_ok,
+	traits::{Currency, Get},
+	BoundedVec,
+};
+use frame_system::{pallet_prelude::BlockNumberFor, RawOrigin};
+use sp_arithmetic::{traits::Saturating, Permill};
+use sp_runtime::traits::{IdentifyAccount, StaticLookup, TrailingZeroInput};
+use sp_std::{vec, vec::Vec};
+
+/// Feed id derived from an index
+fn feed(i: u32) -> FeedId {
+	let mut id = [0u8; 16];
+	id[..4].copy_from_slice(&i.to_le_bytes());
+	id
+}
+
+fn feed_config<T: Config>() -> FeedConfig<BlockNumberFor<T>> {
+	FeedConfig {
+		decimals: 8,
+		heartbeat: 100u32.into(),
+		deviation_threshold: Permill::from_percent(1),
+		min_reporters: 3,
+		mad_multiplier: 3,
+		tolerance: Permill::from_percent(1),
+	}
+}
+
+/// Longest source accepted by `FeedSource`
+fn feed_source() -> FeedSource {
+	FeedSource {
+		url: BoundedVec::truncate_from(vec![b'u'; 256]),
+		json_path: BoundedVec::truncate_from(vec![b'p'; 64]),
+	}
+}
+
+/// Give `who` enough free balance to stake the maximum twice over
+fn fund<T: Config>(who: &T::AccountId) {
+	let amount = T::MaximumStake::get()
+		.saturating_mul(2u32.into())
+		.saturating_add(T::Currency::minimum_balance());
+	T::Currency::make_free_balance_be(who, amount);
+}
+
+/// Register `who` as an oracle with the minimum stake
+fn register<T: Config>(who: &T::AccountId) {
+	fund::<T>(who);
+	assert_ok!(Pallet::<T>::register_oracle(
+		RawOrigin::Signed(who.clone()).into(),
+		T::MinimumStake::get()
+	));
+}
+
+/// Fill the active oracle list with `n` placeholder accounts
+fn fill_oracles<T: Config>(n: u32) {
+	let oracles: Vec<T::AccountId> = (0..n).map(|i| account("oracle", i, 0)).collect();
+	ActiveOracles::<T>::put(BoundedVec::truncate_from(oracles));
+	OracleCount::<T>::put(n);
+}
+
+/// Register `MaxFeeds` feeds, all but the first pending a round
+fn fill_feeds<T: Config>() {
+	let max = T::MaxFeeds::get();
+	for i in 0..max {
+		Feeds::<T>::insert(feed(i), feed_config::<T>());
+	}
+	PendingFeeds::<T>::put(BoundedVec::truncate_from((1..max).map(feed).collect::<Vec<_>>()));
+}
+
+#[benchmarks]
+mod benchmarks {
+	use super::*;
+
+	#[benchmark]
+	fn register_oracle() {
+		fill_oracles::<T>(T::MaxOracles::get() - 1);
+		let caller: T::AccountId = whitelisted_caller();
+		fund::<T>(&caller);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller.clone()), T::MinimumStake::get());
+
+		assert!(OracleOperators::<T>::contains_key(&caller));
+		assert_eq!(ActiveOracles::<T>::get().len() as u32, T::MaxOracles::get());
+	}
+
+	/// The caller is last in a full active list
+	#[benchmark]
+	fn deregister_oracle() {
+		fill_oracles::<T>(T::MaxOracles::get() - 1);
+		let caller: T::AccountId = whitelisted_caller();
+		register::<T>(&caller);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller.clone()));
+
+		assert!(!OracleOperators::<T>::contains_key(&caller));
+	}
+
+	#[benchmark]
+	fn increase_stake() {
+		let caller: T::AccountId = whitelisted_caller();
+		register::<T>(&caller);
+		let additional = T::MaximumStake::get().saturating_sub(T::MinimumStake::get());
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller.clone()), additional);
+
+		assert_eq!(OracleOperators::<T>::get(&caller).unwrap().stake, T::MaximumStake::get());
+	}
+
+	#[benchmark]
+	fn decrease_stake() {
+		let caller: T::AccountId = whitelisted_caller();
+		register::<T>(&caller);
+		let additional = T::MaximumStake::get().saturating_sub(T::MinimumStake::get());
+		assert_ok!(Pallet::<T>::increase_stake(RawOrigin::Signed(caller.clone()).into(), additional));
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller.clone()), additional);
+
+		assert_eq!(OracleOperators::<T>::get(&caller).unwrap().stake, T::MinimumStake::get());
+	}
+
+	#[benchmark]
+	fn create_data_request() {
+		let caller: T::AccountId = whitelisted_caller();
+		fund::<T>(&caller);
+
+		#[extrinsic_call]
+		_(
+			RawOrigin::Signed(caller),
+			vec![b's'; 64],
+			T::Currency::minimum_balance(),
+			3,
+			100u32.into(),
+		);
+
+		assert!(DataRequests::<T>::contains_key(0));
+	}
+
+	/// The response fulfils the request and raises the oracle's reputation
+	#[benchmark]
+	fn submit_oracle_response() {
+		let requester: T::AccountId = account("requester", 0, 0);
+		fund::<T>(&requester);
+		assert_ok!(Pallet::<T>::create_data_request(
+			RawOrigin::Signed(requester).into(),
+			vec![b's'; 64],
+			T::Currency::minimum_balance(),
+			1,
+			100u32.into(),
+		));
+		let caller: T::AccountId = whitelisted_caller();
+		register::<T>(&caller);
+		OracleOperators::<T>::mutate(&caller, |operator| {
+			if let Some(operator) = operator {
+				operator.reputation = 99;
+			}
+		});
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller.clone()), 0, vec![b'd'; 128]);
+
+		assert!(DataRequests::<T>::get(0).unwrap().fulfilled);
+		assert_eq!(OracleOperators::<T>::get(&caller).unwrap().reputation, 100);
+	}
+
+	/// The slash drops the oracle below the minimum reputation and deactivates it
+	#[benchmark]
+	fn slash_oracle() {
+		fill_oracles::<T>(T::MaxOracles::get() - 1);
+		let oracle: T::AccountId = account("slashed", 0, 0);
+		register::<T>(&oracle);
+		OracleOperators::<T>::mutate(&oracle, |operator| {
+			if let Some(operator) = operator {
+				operator.reputation = T::MinimumReputation::get();
+			}
+		});
+		let history = vec![(BlockNumberFor::<T>::from(0u32), 100u8); 99];
+		ReputationHistory::<T>::insert(&oracle, BoundedVec::truncate_from(history));
+		let lookup = T::Lookup::unlookup(oracle.clone());
+
+		#[extrinsic_call]
+		_(RawOrigin::Root, lookup, vec![b'r'; 64]);
+
+		assert!(!OracleOperators::<T>::get(&oracle).unwrap().active);
+	}
+
+	#[benchmark]
+	fn reactivate_oracle() {
+		fill_oracles::<T>(T::MaxOracles::get() - 1);
+		let caller: T::AccountId = whitelisted_caller();
+		register::<T>(&caller);
+		ActiveOracles::<T>::mutate(|oracles| oracles.retain(|o| o != &caller));
+		OracleOperators::<T>::mutate(&caller, |operator| {
+			if let Some(operator) = operator {
+				operator.active = false;
+			}
+		});
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller.clone()));
+
+		assert!(Pallet::<T>::is_oracle_active(&caller));
+	}
+
+	#[benchmark]
+	fn register_feed() {
+		for i in 1..T::MaxFeeds::get() {
+			Feeds::<T>::insert(feed(i), feed_config::<T>());
+		}
+
+		#[extrinsic_call]
+		_(RawOrigin::Root, feed(0), feed_config::<T>());
+
+		assert_eq!(Feeds::<T>::count(), T::MaxFeeds::get());
+	}
+
+	/// Removes a feed with `r` submissions in its open round and every source set
+	#[benchmark]
+	fn remove_feed(r: Linear<0, { T::MaxOracles::get() }>) {
+		fill_feeds::<T>();
+		PendingFeeds::<T>::mutate(|pending| {
+			let mut all = vec![feed(0)];
+			all.extend(pending.iter().copied());
+			*pending = BoundedVec::truncate_from(all);
+		});
+		for i in 0..r {
+			let reporter: T::AccountId = account("reporter", i, 0);
+			FeedSubmissions::<T>::insert(feed(0), reporter, 1u128);
+		}
+		let sources = vec![feed_source(); T::MaxFeedSources::get() as usize];
+		FeedSources::<T>::insert(feed(0), BoundedVec::truncate_from(sources));
+
+		#[extrinsic_call]
+		_(RawOrigin::Root, feed(0));
+
+		assert!(!Feeds::<T>::contains_key(feed(0)));
+	}
+
+	/// The feed's first submission, joining every other feed in the pending list
+	#[benchmark]
+	fn submit_feed_value() {
+		fill_feeds::<T>();
+		let caller: T::AccountId = whitelisted_caller();
+		register::<T>(&caller);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller.clone()), feed(0), 1_000u128);
+
+		assert!(FeedSubmissions::<T>::contains_key(feed(0), &caller));
+	}
+
+	#[benchmark]
+	fn set_feed_sources(s: Linear<0, { T::MaxFeedSources::get() }>) {
+		Feeds::<T>::insert(feed(0), feed_config::<T>());
+		let sources = vec![feed_source(); s as usize];
+
+		#[extrinsic_call]
+		_(RawOrigin::Root, feed(0), sources);
+
+		assert_eq!(FeedSources::<T>::get(feed(0)).len() as u32, s);
+	}
+
+	/// Same work as `submit_feed_value`; the signature is checked in `validate_unsigned`
+	#[benchmark]
+	fn submit_feed_value_unsigned() {
+		fill_feeds::<T>();
+		let public = T::Public::decode(&mut TrailingZeroInput::zeroes()).expect("infinite input; qed");
+		let signature =
+			T::Signature::decode(&mut TrailingZeroInput::zeroes()).expect("infinite input; qed");
+		let reporter = public.clone().into_account();
+		register::<T>(&reporter);
+		let payload = FeedValuePayload {
+			feed: feed(0),
+			value: 1_000,
+			round: OpenRounds::<T>::get(feed(0)),
+			block_number: frame_system::Pallet::<T>::block_number(),
+			public,
+		};
+
+		#[extrinsic_call]
+		_(RawOrigin::None, payload, signature);
+
+		assert!(FeedSubmissions::<T>::contains_key(feed(0), &reporter));
+	}
+}
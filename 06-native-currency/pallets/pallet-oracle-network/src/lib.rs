@@ -29,17 +29,73 @@
 //! - Aggregation of multiple oracle responses
 //! - Payment to oracles upon successful response
 //!
+//! ### Price Feeds
+//! - Feeds are registered once by governance with a heartbeat, a deviation threshold,
+//!   a minimum reporter count and an outlier tolerance
+//! - Active operators submit one value per feed per round
+//! - At the end of a block, rounds with enough reporters are filtered with the
+//!   median + MAD rule from `etrid-oracle-primitives`
+//! - Reporters outside the tolerance band are slashed automatically
+//! - A new price is published when the heartbeat has elapsed or the accepted median
+//!   moved by more than the deviation threshold
+//! - Published prices are read through `etrid_oracle_primitives::PriceFeed`
+//!
+//! ### Offchain Worker
+//! - Governance configures up to `MaxFeedSources` HTTP sources per feed, each with a
+//!   JSON path to the price (e.g. `data.amount`, `result.0.price`)
+//! - Operators insert an `orcl` sr25519 key whose account is their operator account
+//! - Every `FetchInterval` blocks the worker fetches each source, drops outlier sources
+//!   with the feed's MAD rule and submits the median once per round
+//! - Submissions are signed transactions or, with `SubmissionMode::UnsignedSignedPayload`,
+//!   unsigned transactions carrying a payload signed by the oracle key
+//! - Signed payloads name the feed's open round and expire after `FetchInterval`
+//!   blocks, so they can't be replayed into a later round
+//! - Failed fetches back off exponentially per feed, up to `MaxFetchBackoff` blocks
+//!
 //! ## Integration
 //!
 //! This pallet works with:
 //! - `pallet-reserve-oracle`: Price data aggregation
 //! - `pallet-edsc-oracle`: EDSC TWAP oracle
+//! - `pallet-edsc-stability`, `pallet-reserve-vault`: `PriceFeed` consumers
 //! - Native currency system: ETR staking
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
 pub use pallet::*;
 
+use sp_core::crypto::KeyTypeId;
+
+pub mod offchain;
+pub mod weights;
+pub use weights::WeightInfo;
+
+/// Key type of the oracle reporter keys used by the offchain worker
+pub const KEY_TYPE: KeyTypeId = KeyTypeId(*b"orcl");
+
+/// Oracle reporter crypto
+///
+/// The offchain worker signs submissions with an `orcl` key whose account is a
+/// registered oracle operator.
+pub mod crypto {
+	use super::KEY_TYPE;
+	use sp_runtime::{
+		app_crypto::{app_crypto, sr25519},
+		MultiSignature, MultiSigner,
+	};
+
+	app_crypto!(sr25519, KEY_TYPE);
+
+	/// Signer of oracle submissions in a runtime with `MultiSignature` accounts
+	pub struct OracleAuthId;
+
+	impl frame_system::offchain::AppCrypto<MultiSigner, MultiSignature> for OracleAuthId {
+		type RuntimeAppPublic = Public;
+		type GenericSignature = sp_core::sr25519::Signature;
+		type GenericPublic = sp_core::sr25519::Public;
+	}
+}
+
 /// Loose coupling interface for treasury notifications
 /// Allows oracle network slashing proceeds to be recorded by treasury pallet
 pub trait TreasuryNotifier<Balance> {
@@ -47,6 +103,15 @@ pub trait TreasuryNotifier<Balance> {
 	fn notify_slashing_proceeds(amount: Balance) -> Result<(), sp_runtime::DispatchError>;
 }
 
+impl<Balance> TreasuryNotifier<Balance> for () {
+	fn notify_slashing_proceeds(_amount: Balance) -> Result<(), sp_runtime::DispatchError> {
+		Ok(())
+	}
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
 #[cfg(test)]
 mod mock;
 
@@ -59,11 +124,30 @@ pub mod pallet {
 		pallet_prelude::*,
 		traits::{Currency, ReservableCurrency},
 	};
-	use frame_system::pallet_prelude::*;
-	use sp_arithmetic::{Permill, traits::{Saturating, CheckedAdd, CheckedSub}};
-	use sp_runtime::traits::{AccountIdConversion, StaticLookup};
+	use frame_system::{
+		offchain::{
+			AppCrypto, CreateBare, CreateSignedTransaction, SendSignedTransaction,
+			SendUnsignedTransaction, SignedPayload, Signer, SigningTypes,
+		},
+		pallet_prelude::*,
+	};
+	use codec::DecodeWithMemTracking;
+	use sp_arithmetic::{Permill, traits::{Saturating, CheckedAdd, CheckedSub, Zero}};
+	use sp_runtime::{
+		offchain::{
+			storage::StorageValueRef,
+			storage_lock::{BlockAndTime, StorageLock},
+			Duration,
+		},
+		traits::{AccountIdConversion, IdentifyAccount, SaturatedConversion, StaticLookup},
+		RuntimeAppPublic,
+	};
 	use sp_std::vec::Vec;
-	use crate::TreasuryNotifier;
+	use etrid_oracle_primitives::{self as oracle_primitives, FeedId, FeedPrice};
+	use crate::{
+		offchain::{self, FetchState, OffchainError},
+		TreasuryNotifier, WeightInfo,
+	};
 
 	type BalanceOf<T> = <<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
 
@@ -124,11 +208,69 @@ pub mod pallet {
 		pub submitted_at: BlockNumber,
 	}
 
+	/// Price feed parameters
+	#[derive(Clone, Encode, Decode, DecodeWithMemTracking, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	pub struct FeedConfig<BlockNumber> {
+		/// Decimals of submitted and published values
+		pub decimals: u8,
+		/// Maximum blocks between published prices
+		pub heartbeat: BlockNumber,
+		/// Median move that publishes a price before the heartbeat
+		pub deviation_threshold: Permill,
+		/// Submissions needed to close a round
+		pub min_reporters: u32,
+		/// Width of the outlier band in MADs
+		pub mad_multiplier: u32,
+		/// Minimum width of the outlier band relative to the median
+		pub tolerance: Permill,
+	}
+
+	/// HTTP source of a feed's price
+	#[derive(Clone, Encode, Decode, DecodeWithMemTracking, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	pub struct FeedSource {
+		/// URL fetched with a plain GET
+		pub url: BoundedVec<u8, ConstU32<256>>,
+		/// Dot-separated path to the price in the JSON response
+		pub json_path: BoundedVec<u8, ConstU32<64>>,
+	}
+
+	/// Feed value signed by an oracle key, submitted in an unsigned transaction
+	#[derive(Clone, Encode, Decode, DecodeWithMemTracking, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+	pub struct FeedValuePayload<Public, BlockNumber> {
+		/// Feed the value is for
+		pub feed: FeedId,
+		/// Value with the feed's decimals
+		pub value: u128,
+		/// Open round of the feed when the value was fetched
+		pub round: u64,
+		/// Block at which the value was fetched
+		pub block_number: BlockNumber,
+		/// Oracle key whose account reports the value
+		pub public: Public,
+	}
+
+	impl<T: SigningTypes> SignedPayload<T> for FeedValuePayload<T::Public, BlockNumberFor<T>> {
+		fn public(&self) -> T::Public {
+			self.public.clone()
+		}
+	}
+
+	/// How the offchain worker submits fetched values
+	#[derive(Clone, Copy, Eq, PartialEq, RuntimeDebug)]
+	pub enum SubmissionMode {
+		/// Signed `submit_feed_value`, paying fees from the operator account
+		Signed,
+		/// Fee-free `submit_feed_value_unsigned` carrying a signed payload
+		UnsignedSignedPayload,
+	}
+
 	#[pallet::pallet]
 	pub struct Pallet<T>(_);
 
 	#[pallet::config]
-	pub trait Config: frame_system::Config {
+	pub trait Config:
+		CreateSignedTransaction<Call<Self>> + CreateBare<Call<Self>> + frame_system::Config
+	{
 		/// Because this pallet emits events, it depends on the runtime's definition of an event.
 		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
 
@@ -166,6 +308,35 @@ pub mod pallet {
 		/// Maximum number of pending data requests
 		#[pallet::constant]
 		type MaxDataRequests: Get<u32>;
+
+		/// Maximum number of registered price feeds
+		#[pallet::constant]
+		type MaxFeeds: Get<u32>;
+
+		/// Crypto of the `orcl` keys the offchain worker signs with
+		type AuthorityId: AppCrypto<Self::Public, Self::Signature>;
+
+		/// Whether the offchain worker submits signed or unsigned transactions
+		type OffchainSubmission: Get<SubmissionMode>;
+
+		/// Maximum number of HTTP sources per feed
+		#[pallet::constant]
+		type MaxFeedSources: Get<u32>;
+
+		/// Blocks between offchain fetches of a feed
+		#[pallet::constant]
+		type FetchInterval: Get<BlockNumberFor<Self>>;
+
+		/// Upper bound of the offchain retry delay after failed fetches
+		#[pallet::constant]
+		type MaxFetchBackoff: Get<BlockNumberFor<Self>>;
+
+		/// Priority of unsigned feed submissions
+		#[pallet::constant]
+		type UnsignedPriority: Get<TransactionPriority>;
+
+		/// Weight information for extrinsics
+		type WeightInfo: WeightInfo;
 	}
 
 	/// Registered oracle operators
@@ -232,6 +403,56 @@ pub mod pallet {
 		ValueQuery,
 	>;
 
+	/// Registered price feeds
+	#[pallet::storage]
+	#[pallet::getter(fn feeds)]
+	pub type Feeds<T: Config> = CountedStorageMap<
+		_,
+		Blake2_128Concat,
+		FeedId,
+		FeedConfig<BlockNumberFor<T>>,
+		OptionQuery,
+	>;
+
+	/// Submissions for the open round of each feed
+	/// Maps: FeedId -> Reporter -> Value
+	#[pallet::storage]
+	pub type FeedSubmissions<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		FeedId,
+		Blake2_128Concat,
+		T::AccountId,
+		u128,
+		OptionQuery,
+	>;
+
+	/// Id of each feed's open round, advanced whenever a round closes
+	///
+	/// Kept when a feed is removed so round ids never repeat for a feed.
+	#[pallet::storage]
+	pub type OpenRounds<T: Config> = StorageMap<_, Blake2_128Concat, FeedId, u64, ValueQuery>;
+
+	/// Feeds with at least one submission in their open round
+	#[pallet::storage]
+	pub type PendingFeeds<T: Config> = StorageValue<_, BoundedVec<FeedId, T::MaxFeeds>, ValueQuery>;
+
+	/// Latest published price per feed
+	#[pallet::storage]
+	#[pallet::getter(fn latest_price)]
+	pub type LatestPrices<T: Config> = StorageMap<_, Blake2_128Concat, FeedId, FeedPrice, OptionQuery>;
+
+	/// HTTP sources fetched by the offchain worker per feed
+	#[pallet::storage]
+	#[pallet::getter(fn feed_sources)]
+	pub type FeedSources<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		FeedId,
+		BoundedVec<FeedSource, T::MaxFeedSources>,
+		ValueQuery,
+	>;
+
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
 	pub enum Event<T: Config> {
@@ -302,6 +523,39 @@ pub mod pallet {
 		DataRequestExpired {
 			request_id: u64,
 		},
+		/// Price feed registered or reconfigured [feed]
+		FeedRegistered {
+			feed: FeedId,
+		},
+		/// Price feed removed [feed]
+		FeedRemoved {
+			feed: FeedId,
+		},
+		/// Reporter submitted a value for a feed's open round [feed, reporter, value]
+		FeedValueSubmitted {
+			feed: FeedId,
+			reporter: T::AccountId,
+			value: u128,
+		},
+		/// Reporter's value fell outside the round's tolerance band [feed, reporter, value, median]
+		FeedValueRejected {
+			feed: FeedId,
+			reporter: T::AccountId,
+			value: u128,
+			median: u128,
+		},
+		/// New aggregated price published [feed, value, round, reporters]
+		FeedPriceUpdated {
+			feed: FeedId,
+			value: u128,
+			round: u64,
+			reporters: u32,
+		},
+		/// Offchain HTTP sources of a feed replaced [feed, sources]
+		FeedSourcesUpdated {
+			feed: FeedId,
+			sources: u32,
+		},
 	}
 
 	#[pallet::error]
@@ -336,6 +590,16 @@ pub mod pallet {
 		InsufficientPayment,
 		/// Not the oracle operator
 		NotOracleOperator,
+		/// Price feed not registered
+		FeedNotFound,
+		/// Feed configuration is invalid
+		InvalidFeedConfig,
+		/// Maximum feeds reached
+		MaxFeedsReached,
+		/// Reporter already submitted to this round
+		AlreadySubmitted,
+		/// More sources than `MaxFeedSources`
+		TooManyFeedSources,
 	}
 
 	#[pallet::hooks]
@@ -344,17 +608,40 @@ pub mod pallet {
 			// Check for expired data requests
 			Self::process_expired_requests(n);
 
+			// Close price feed rounds that reached their reporter quorum
+			Self::close_feed_rounds(n);
+
 			// Update reputation scores periodically (every 1000 blocks)
 			if n % 1000u32.into() == 0u32.into() {
 				Self::update_reputation_scores();
 			}
 		}
+
+		fn offchain_worker(now: BlockNumberFor<T>) {
+			// Only one worker at a time, so forks don't submit the same round twice
+			let mut lock = StorageLock::<BlockAndTime<frame_system::Pallet<T>>>::with_block_and_time_deadline(
+				offchain::LOCK_KEY,
+				offchain::LOCK_BLOCK_EXPIRATION,
+				Duration::from_millis(offchain::LOCK_TIMEOUT_EXPIRATION_MS),
+			);
+			let Ok(_guard) = lock.try_lock() else {
+				return;
+			};
+
+			let Some((public, reporter)) = Self::local_reporter() else {
+				return;
+			};
+
+			for (feed, sources) in FeedSources::<T>::iter() {
+				Self::offchain_feed(feed, &sources, &public, &reporter, now);
+			}
+		}
 	}
 
 	#[pallet::call]
 	impl<T: Config> Pallet<T> {
 		/// Register as an oracle operator
-		#[pallet::weight(10_000)]
+		#[pallet::weight(T::WeightInfo::register_oracle())]
 		#[pallet::call_index(0)]
 		pub fn register_oracle(
 			origin: OriginFor<T>,
@@ -410,7 +697,7 @@ pub mod pallet {
 		}
 
 		/// Deregister as an oracle operator
-		#[pallet::weight(10_000)]
+		#[pallet::weight(T::WeightInfo::deregister_oracle())]
 		#[pallet::call_index(1)]
 		pub fn deregister_oracle(origin: OriginFor<T>) -> DispatchResult {
 			let who = ensure_signed(origin)?;
@@ -439,7 +726,7 @@ pub mod pallet {
 		}
 
 		/// Increase stake
-		#[pallet::weight(10_000)]
+		#[pallet::weight(T::WeightInfo::increase_stake())]
 		#[pallet::call_index(2)]
 		pub fn increase_stake(
 			origin: OriginFor<T>,
@@ -470,7 +757,7 @@ pub mod pallet {
 		}
 
 		/// Decrease stake (cannot go below minimum)
-		#[pallet::weight(10_000)]
+		#[pallet::weight(T::WeightInfo::decrease_stake())]
 		#[pallet::call_index(3)]
 		pub fn decrease_stake(
 			origin: OriginFor<T>,
@@ -501,7 +788,7 @@ pub mod pallet {
 		}
 
 		/// Create a data request (governance or authorized requesters)
-		#[pallet::weight(10_000)]
+		#[pallet::weight(T::WeightInfo::create_data_request())]
 		#[pallet::call_index(4)]
 		pub fn create_data_request(
 			origin: OriginFor<T>,
@@ -549,7 +836,7 @@ pub mod pallet {
 		}
 
 		/// Submit oracle response to a data request
-		#[pallet::weight(10_000)]
+		#[pallet::weight(T::WeightInfo::submit_oracle_response())]
 		#[pallet::call_index(5)]
 		pub fn submit_oracle_response(
 			origin: OriginFor<T>,
@@ -619,7 +906,7 @@ pub mod pallet {
 		}
 
 		/// Slash an oracle for bad data (governance only)
-		#[pallet::weight(10_000)]
+		#[pallet::weight(T::WeightInfo::slash_oracle())]
 		#[pallet::call_index(6)]
 		pub fn slash_oracle(
 			origin: OriginFor<T>,
@@ -630,7 +917,206 @@ pub mod pallet {
 
 			let oracle_account = T::Lookup::lookup(oracle)?;
 
-			let mut operator = OracleOperators::<T>::get(&oracle_account)
+			Self::do_slash(&oracle_account, reason)
+		}
+
+		/// Reactivate oracle (after improving reputation)
+		#[pallet::weight(T::WeightInfo::reactivate_oracle())]
+		#[pallet::call_index(7)]
+		pub fn reactivate_oracle(origin: OriginFor<T>) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let mut operator = OracleOperators::<T>::get(&who)
+				.ok_or(Error::<T>::OracleNotFound)?;
+
+			ensure!(operator.reputation >= T::MinimumReputation::get(), Error::<T>::ReputationTooLow);
+
+			operator.active = true;
+			OracleOperators::<T>::insert(&who, operator);
+
+			// Add back to active list
+			ActiveOracles::<T>::mutate(|oracles| {
+				if !oracles.contains(&who) {
+					let _ = oracles.try_push(who.clone());
+				}
+			});
+
+			Self::deposit_event(Event::OracleReactivated {
+				operator: who,
+			});
+
+			Ok(())
+		}
+
+		/// Register or reconfigure a price feed (governance only)
+		#[pallet::weight(T::WeightInfo::register_feed())]
+		#[pallet::call_index(8)]
+		pub fn register_feed(
+			origin: OriginFor<T>,
+			feed: FeedId,
+			config: FeedConfig<BlockNumberFor<T>>,
+		) -> DispatchResult {
+			ensure_root(origin)?;
+
+			ensure!(
+				config.min_reporters > 0 && config.mad_multiplier > 0 && !config.heartbeat.is_zero(),
+				Error::<T>::InvalidFeedConfig
+			);
+			ensure!(
+				Feeds::<T>::contains_key(feed) || Feeds::<T>::count() < T::MaxFeeds::get(),
+				Error::<T>::MaxFeedsReached
+			);
+
+			Feeds::<T>::insert(feed, config);
+
+			Self::deposit_event(Event::FeedRegistered { feed });
+
+			Ok(())
+		}
+
+		/// Remove a price feed with its open round and published price (governance only)
+		///
+		/// Only active oracles submit, so the open round has at most `MaxOracles` entries.
+		#[pallet::weight(T::WeightInfo::remove_feed(T::MaxOracles::get()))]
+		#[pallet::call_index(9)]
+		pub fn remove_feed(origin: OriginFor<T>, feed: FeedId) -> DispatchResultWithPostInfo {
+			ensure_root(origin)?;
+
+			ensure!(Feeds::<T>::contains_key(feed), Error::<T>::FeedNotFound);
+
+			Feeds::<T>::remove(feed);
+			LatestPrices::<T>::remove(feed);
+			FeedSources::<T>::remove(feed);
+			let removed = FeedSubmissions::<T>::clear_prefix(feed, u32::MAX, None).unique;
+			PendingFeeds::<T>::mutate(|pending| pending.retain(|f| f != &feed));
+
+			Self::deposit_event(Event::FeedRemoved { feed });
+
+			Ok(Some(T::WeightInfo::remove_feed(removed)).into())
+		}
+
+		/// Submit a value to a price feed's open round
+		#[pallet::weight(T::WeightInfo::submit_feed_value())]
+		#[pallet::call_index(10)]
+		pub fn submit_feed_value(
+			origin: OriginFor<T>,
+			feed: FeedId,
+			value: u128,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			Self::do_submit_feed_value(who, feed, value)
+		}
+
+		/// Replace the HTTP sources the offchain worker fetches for a feed (governance only)
+		#[pallet::weight(T::WeightInfo::set_feed_sources(sources.len() as u32))]
+		#[pallet::call_index(11)]
+		pub fn set_feed_sources(
+			origin: OriginFor<T>,
+			feed: FeedId,
+			sources: Vec<FeedSource>,
+		) -> DispatchResult {
+			ensure_root(origin)?;
+
+			ensure!(Feeds::<T>::contains_key(feed), Error::<T>::FeedNotFound);
+			let sources: BoundedVec<FeedSource, T::MaxFeedSources> = sources.try_into()
+				.map_err(|_| Error::<T>::TooManyFeedSources)?;
+			let count = sources.len() as u32;
+
+			if sources.is_empty() {
+				FeedSources::<T>::remove(feed);
+			} else {
+				FeedSources::<T>::insert(feed, sources);
+			}
+
+			Self::deposit_event(Event::FeedSourcesUpdated { feed, sources: count });
+
+			Ok(())
+		}
+
+		/// Submit a value signed by an oracle key without paying fees
+		///
+		/// Signature, reporter and round checks happen in `validate_unsigned`.
+		#[pallet::weight(T::WeightInfo::submit_feed_value_unsigned())]
+		#[pallet::call_index(12)]
+		pub fn submit_feed_value_unsigned(
+			origin: OriginFor<T>,
+			payload: FeedValuePayload<T::Public, BlockNumberFor<T>>,
+			_signature: T::Signature,
+		) -> DispatchResult {
+			ensure_none(origin)?;
+
+			Self::do_submit_feed_value(payload.public.into_account(), payload.feed, payload.value)
+		}
+	}
+
+	#[pallet::validate_unsigned]
+	impl<T: Config> ValidateUnsigned for Pallet<T> {
+		type Call = Call<T>;
+
+		fn validate_unsigned(_source: TransactionSource, call: &Self::Call) -> TransactionValidity {
+			let Call::submit_feed_value_unsigned { payload, signature } = call else {
+				return InvalidTransaction::Call.into();
+			};
+
+			if !payload.verify::<T::AuthorityId>(signature.clone()) {
+				return InvalidTransaction::BadProof.into();
+			}
+
+			let reporter = payload.public.clone().into_account();
+			if !Self::is_oracle_active(&reporter) {
+				return InvalidTransaction::BadSigner.into();
+			}
+			if !Feeds::<T>::contains_key(payload.feed) {
+				return InvalidTransaction::Call.into();
+			}
+			if FeedSubmissions::<T>::contains_key(payload.feed, &reporter) {
+				return InvalidTransaction::Stale.into();
+			}
+			// Payloads from an earlier round or fetch can't take the reporter's slot
+			if payload.round != OpenRounds::<T>::get(payload.feed) {
+				return InvalidTransaction::Stale.into();
+			}
+			let now = <frame_system::Pallet<T>>::block_number();
+			if payload.block_number > now {
+				return InvalidTransaction::Future.into();
+			}
+			if payload.block_number.saturating_add(T::FetchInterval::get()) < now {
+				return InvalidTransaction::Stale.into();
+			}
+
+			ValidTransaction::with_tag_prefix("OracleNetworkFeedValue")
+				.priority(T::UnsignedPriority::get())
+				.and_provides((payload.feed, payload.round, reporter))
+				.longevity(T::FetchInterval::get().saturated_into::<u64>().max(1))
+				.propagate(true)
+				.build()
+		}
+	}
+
+	impl<T: Config> Pallet<T> {
+		/// Record successful data submission
+		fn record_successful_submission(oracle: &T::AccountId) {
+			if let Some(mut operator) = OracleOperators::<T>::get(oracle) {
+				operator.successful_submissions = operator.successful_submissions.saturating_add(1);
+				operator.last_submission = <frame_system::Pallet<T>>::block_number();
+
+				// Improve reputation slightly (up to 100)
+				let old_reputation = operator.reputation;
+				operator.reputation = (operator.reputation.saturating_add(1)).min(100);
+
+				OracleOperators::<T>::insert(oracle, operator.clone());
+
+				if old_reputation != operator.reputation {
+					Self::record_reputation_change(oracle, operator.reputation);
+				}
+			}
+		}
+
+		/// Slash an operator's stake, split the proceeds between treasury and burn, and
+		/// lower its reputation
+		pub(crate) fn do_slash(oracle: &T::AccountId, reason: Vec<u8>) -> DispatchResult {
+			let mut operator = OracleOperators::<T>::get(oracle)
 				.ok_or(Error::<T>::OracleNotFound)?;
 
 			// Calculate slash amount
@@ -638,7 +1124,7 @@ pub mod pallet {
 
 			// Slash (reduce reserved amount)
 			// slash_reserved returns (NegativeImbalance, Balance)
-			let (_imbalance, actual_slash) = T::Currency::slash_reserved(&oracle_account, slash_amount);
+			let (_imbalance, actual_slash) = T::Currency::slash_reserved(oracle, slash_amount);
 
 			// Transfer 50% of slashed funds to treasury, 50% burned
 			let treasury_amount = actual_slash / 2u32.into();
@@ -662,24 +1148,24 @@ pub mod pallet {
 			let old_reputation = operator.reputation;
 			operator.reputation = operator.reputation.saturating_sub(10);
 
-			OracleOperators::<T>::insert(&oracle_account, operator.clone());
+			OracleOperators::<T>::insert(oracle, operator.clone());
 
 			// Record reputation change
-			Self::record_reputation_change(&oracle_account, operator.reputation);
+			Self::record_reputation_change(oracle, operator.reputation);
 
 			// Check if should deactivate
 			if operator.reputation < T::MinimumReputation::get() {
-				Self::deactivate_oracle(&oracle_account)?;
+				Self::deactivate_oracle(oracle)?;
 			}
 
 			Self::deposit_event(Event::OracleSlashed {
-				operator: oracle_account.clone(),
+				operator: oracle.clone(),
 				slashed_amount: actual_slash,
 				reason,
 			});
 
 			Self::deposit_event(Event::ReputationUpdated {
-				operator: oracle_account,
+				operator: oracle.clone(),
 				old_score: old_reputation,
 				new_score: operator.reputation,
 			});
@@ -687,54 +1173,6 @@ pub mod pallet {
 			Ok(())
 		}
 
-		/// Reactivate oracle (after improving reputation)
-		#[pallet::weight(10_000)]
-		#[pallet::call_index(7)]
-		pub fn reactivate_oracle(origin: OriginFor<T>) -> DispatchResult {
-			let who = ensure_signed(origin)?;
-
-			let mut operator = OracleOperators::<T>::get(&who)
-				.ok_or(Error::<T>::OracleNotFound)?;
-
-			ensure!(operator.reputation >= T::MinimumReputation::get(), Error::<T>::ReputationTooLow);
-
-			operator.active = true;
-			OracleOperators::<T>::insert(&who, operator);
-
-			// Add back to active list
-			ActiveOracles::<T>::mutate(|oracles| {
-				if !oracles.contains(&who) {
-					let _ = oracles.try_push(who.clone());
-				}
-			});
-
-			Self::deposit_event(Event::OracleReactivated {
-				operator: who,
-			});
-
-			Ok(())
-		}
-	}
-
-	impl<T: Config> Pallet<T> {
-		/// Record successful data submission
-		fn record_successful_submission(oracle: &T::AccountId) {
-			if let Some(mut operator) = OracleOperators::<T>::get(oracle) {
-				operator.successful_submissions = operator.successful_submissions.saturating_add(1);
-				operator.last_submission = <frame_system::Pallet<T>>::block_number();
-
-				// Improve reputation slightly (up to 100)
-				let old_reputation = operator.reputation;
-				operator.reputation = (operator.reputation.saturating_add(1)).min(100);
-
-				OracleOperators::<T>::insert(oracle, operator.clone());
-
-				if old_reputation != operator.reputation {
-					Self::record_reputation_change(oracle, operator.reputation);
-				}
-			}
-		}
-
 		/// Deactivate oracle
 		fn deactivate_oracle(oracle: &T::AccountId) -> DispatchResult {
 			OracleOperators::<T>::mutate(oracle, |op| {
@@ -808,5 +1246,244 @@ pub mod pallet {
 		pub fn get_active_oracles() -> Vec<T::AccountId> {
 			ActiveOracles::<T>::get().into_inner()
 		}
+
+		/// Record a reporter's value in a feed's open round
+		fn do_submit_feed_value(who: T::AccountId, feed: FeedId, value: u128) -> DispatchResult {
+			ensure!(Self::is_oracle_active(&who), Error::<T>::OracleNotActive);
+			ensure!(Feeds::<T>::contains_key(feed), Error::<T>::FeedNotFound);
+			ensure!(
+				!FeedSubmissions::<T>::contains_key(feed, &who),
+				Error::<T>::AlreadySubmitted
+			);
+
+			PendingFeeds::<T>::try_mutate(|pending| -> DispatchResult {
+				if !pending.contains(&feed) {
+					pending.try_push(feed).map_err(|_| Error::<T>::MaxFeedsReached)?;
+				}
+				Ok(())
+			})?;
+			FeedSubmissions::<T>::insert(feed, &who, value);
+
+			Self::deposit_event(Event::FeedValueSubmitted {
+				feed,
+				reporter: who,
+				value,
+			});
+
+			Ok(())
+		}
+
+		/// Close every pending feed round that reached its reporter quorum
+		fn close_feed_rounds(now: BlockNumberFor<T>) {
+			let mut still_open = BoundedVec::<FeedId, T::MaxFeeds>::default();
+
+			for feed in PendingFeeds::<T>::take() {
+				let Some(config) = Feeds::<T>::get(feed) else {
+					let _ = FeedSubmissions::<T>::clear_prefix(feed, u32::MAX, None);
+					continue;
+				};
+
+				let submissions: Vec<(T::AccountId, u128)> =
+					FeedSubmissions::<T>::iter_prefix(feed).collect();
+				if (submissions.len() as u32) < config.min_reporters {
+					let _ = still_open.try_push(feed);
+					continue;
+				}
+
+				let _ = FeedSubmissions::<T>::clear_prefix(feed, u32::MAX, None);
+				OpenRounds::<T>::mutate(feed, |round| *round = round.saturating_add(1));
+				Self::close_round(feed, &config, submissions, now);
+			}
+
+			PendingFeeds::<T>::put(still_open);
+		}
+
+		/// Filter a round, slash reporters outside tolerance and publish the accepted
+		/// median if the heartbeat elapsed or the deviation threshold was crossed
+		fn close_round(
+			feed: FeedId,
+			config: &FeedConfig<BlockNumberFor<T>>,
+			submissions: Vec<(T::AccountId, u128)>,
+			now: BlockNumberFor<T>,
+		) {
+			let values: Vec<u128> = submissions.iter().map(|(_, value)| *value).collect();
+			let Some(outcome) =
+				oracle_primitives::filter_round(&values, config.mad_multiplier, config.tolerance)
+			else {
+				return;
+			};
+
+			for &i in &outcome.rejected {
+				let (reporter, value) = &submissions[i];
+				if let Err(e) = Self::do_slash(reporter, b"feed value outside tolerance".to_vec()) {
+					log::warn!("Failed to slash reporter for feed {:?}: {:?}", feed, e);
+				}
+				Self::deposit_event(Event::FeedValueRejected {
+					feed,
+					reporter: reporter.clone(),
+					value: *value,
+					median: outcome.median,
+				});
+			}
+			for &i in &outcome.accepted {
+				Self::record_successful_submission(&submissions[i].0);
+			}
+
+			let now: u32 = now.saturated_into();
+			let previous = LatestPrices::<T>::get(feed);
+			let due = match &previous {
+				None => true,
+				Some(price) => {
+					now.saturating_sub(price.updated_at) >= config.heartbeat.saturated_into::<u32>()
+						|| oracle_primitives::deviation_exceeds(
+							price.value,
+							outcome.value,
+							config.deviation_threshold,
+						)
+				},
+			};
+			if !due {
+				return;
+			}
+
+			let round = previous.map(|price| price.round).unwrap_or(0).saturating_add(1);
+			let reporters = outcome.accepted.len() as u32;
+			LatestPrices::<T>::insert(feed, FeedPrice {
+				value: outcome.value,
+				decimals: config.decimals,
+				updated_at: now,
+				round,
+				reporters,
+			});
+
+			Self::deposit_event(Event::FeedPriceUpdated {
+				feed,
+				value: outcome.value,
+				round,
+				reporters,
+			});
+		}
+	}
+
+	impl<T: Config> Pallet<T> {
+		/// First local `orcl` key whose account is an active oracle operator
+		fn local_reporter() -> Option<(T::Public, T::AccountId)> {
+			<T::AuthorityId as AppCrypto<T::Public, T::Signature>>::RuntimeAppPublic::all()
+				.into_iter()
+				.map(|key| {
+					let generic: <T::AuthorityId as AppCrypto<T::Public, T::Signature>>::GenericPublic =
+						key.into();
+					let public: T::Public = generic.into();
+					(public.clone(), public.into_account())
+				})
+				.find(|(_, reporter)| Self::is_oracle_active(reporter))
+		}
+
+		/// Fetch and submit one feed if it is due, recording the outcome for backoff
+		fn offchain_feed(
+			feed: FeedId,
+			sources: &[FeedSource],
+			public: &T::Public,
+			reporter: &T::AccountId,
+			now: BlockNumberFor<T>,
+		) {
+			let Some(config) = Feeds::<T>::get(feed) else {
+				return;
+			};
+			// Our value is already in the open round
+			if FeedSubmissions::<T>::contains_key(feed, reporter) {
+				return;
+			}
+
+			let key = offchain::fetch_state_key(&feed);
+			let storage = StorageValueRef::persistent(&key);
+			let state = storage.get::<FetchState>().ok().flatten().unwrap_or_default();
+			let block: u32 = now.saturated_into();
+			if block < state.next_fetch {
+				return;
+			}
+
+			let interval: u32 = T::FetchInterval::get().saturated_into();
+			let state = match Self::fetch_feed_value(&config, sources)
+				.and_then(|value| Self::send_feed_value(feed, value, public, now))
+			{
+				Ok(()) => FetchState::succeeded(block, interval),
+				Err(e) => {
+					let state = state.failed(block, interval, T::MaxFetchBackoff::get().saturated_into());
+					log::warn!(
+						"Oracle feed {:?} failed ({:?}), retrying at block {}",
+						feed, e, state.next_fetch,
+					);
+					state
+				},
+			};
+			storage.set(&state);
+		}
+
+		/// Median of the sources that returned a value, after dropping outliers with the
+		/// feed's own MAD rule
+		pub(crate) fn fetch_feed_value(
+			config: &FeedConfig<BlockNumberFor<T>>,
+			sources: &[FeedSource],
+		) -> Result<u128, OffchainError> {
+			let values: Vec<u128> = sources
+				.iter()
+				.filter_map(|source| {
+					let body = offchain::fetch(&source.url)
+						.map_err(|e| log::warn!("Oracle source fetch failed: {:?}", e))
+						.ok()?;
+					let text = offchain::extract_json_path(&body, &source.json_path)?;
+					offchain::parse_decimal(&text, config.decimals).filter(|value| *value > 0)
+				})
+				.collect();
+
+			oracle_primitives::filter_round(&values, config.mad_multiplier, config.tolerance)
+				.map(|outcome| outcome.value)
+				.ok_or(OffchainError::NoValue)
+		}
+
+		/// Submit a fetched value from the local oracle key
+		fn send_feed_value(
+			feed: FeedId,
+			value: u128,
+			public: &T::Public,
+			now: BlockNumberFor<T>,
+		) -> Result<(), OffchainError> {
+			let signer = Signer::<T, T::AuthorityId>::any_account().with_filter(sp_std::vec![public.clone()]);
+
+			let (_, result) = match T::OffchainSubmission::get() {
+				SubmissionMode::Signed => signer
+					.send_signed_transaction(|_| Call::submit_feed_value { feed, value }),
+				SubmissionMode::UnsignedSignedPayload => signer.send_unsigned_transaction(
+					|account| FeedValuePayload {
+						feed,
+						value,
+						round: OpenRounds::<T>::get(feed),
+						block_number: now,
+						public: account.public.clone(),
+					},
+					|payload, signature| Call::submit_feed_value_unsigned { payload, signature },
+				),
+			}
+			.ok_or(OffchainError::NoSigner)?;
+
+			result.map_err(|()| OffchainError::SubmitFailed)
+		}
+	}
+
+	impl<T: Config> oracle_primitives::PriceFeed for Pallet<T> {
+		fn latest(feed: &FeedId) -> Option<FeedPrice> {
+			LatestPrices::<T>::get(feed)
+		}
+
+		/// A price is fresh for two heartbeats: one until the next round is due and
+		/// one for reporters to reach quorum on it
+		fn fresh(feed: &FeedId) -> Option<FeedPrice> {
+			let price = LatestPrices::<T>::get(feed)?;
+			let heartbeat: u32 = Feeds::<T>::get(feed)?.heartbeat.saturated_into();
+			let now: u32 = <frame_system::Pallet<T>>::block_number().saturated_into();
+
+			(now.saturating_sub(price.updated_at) <= heartbeat.saturating_mul(2)).then_some(price)
+		}
 	}
 }
@@ -1,17 +1,23 @@
 //! Mock runtime for oracle network pallet tests
 
 use crate as pallet_oracle_network;
+use crate::SubmissionMode;
 use frame_support::{
 	parameter_types,
 	traits::{ConstU32, ConstU64},
 };
+use frame_system::offchain::{
+	AppCrypto, CreateBare, CreateSignedTransaction, CreateTransactionBase, SigningTypes,
+};
 use sp_core::H256;
 use sp_runtime::{
+	testing::{TestSignature, TestXt, UintAuthorityId},
 	traits::{BlakeTwo256, IdentityLookup},
 	BuildStorage,
 };
 
 type Block = frame_system::mocking::MockBlock<Test>;
+pub type Extrinsic = TestXt<RuntimeCall, ()>;
 
 // Configure a mock runtime to test the pallet.
 frame_support::construct_runtime!(
@@ -90,11 +96,18 @@ parameter_types! {
 	pub const SubmissionReward: u128 = 10;
 	pub const MaxOracles: u32 = 100;
 	pub const MaxDataRequests: u32 = 1000;
+	pub const MaxFeeds: u32 = 10;
+	pub const MaxFeedSources: u32 = 4;
+	pub const FetchInterval: u64 = 5;
+	pub const MaxFetchBackoff: u64 = 40;
+	pub const UnsignedPriority: u64 = 1 << 20;
+	pub static OffchainSubmission: SubmissionMode = SubmissionMode::Signed;
 }
 
 impl pallet_oracle_network::Config for Test {
 	type RuntimeEvent = RuntimeEvent;
 	type Currency = Balances;
+	type Treasury = ();
 	type MinimumStake = MinimumStake;
 	type MaximumStake = MaximumStake;
 	type SlashPercentage = SlashPercentage;
@@ -102,6 +115,60 @@ impl pallet_oracle_network::Config for Test {
 	type SubmissionReward = SubmissionReward;
 	type MaxOracles = MaxOracles;
 	type MaxDataRequests = MaxDataRequests;
+	type MaxFeeds = MaxFeeds;
+	type AuthorityId = TestAuthId;
+	type OffchainSubmission = OffchainSubmission;
+	type MaxFeedSources = MaxFeedSources;
+	type FetchInterval = FetchInterval;
+	type MaxFetchBackoff = MaxFetchBackoff;
+	type UnsignedPriority = UnsignedPriority;
+	type WeightInfo = ();
+}
+
+/// Oracle keys are `UintAuthorityId`s whose account is the wrapped `u64`; set the
+/// local keys with `UintAuthorityId::set_all_keys`
+pub struct TestAuthId;
+
+impl AppCrypto<UintAuthorityId, TestSignature> for TestAuthId {
+	type RuntimeAppPublic = UintAuthorityId;
+	type GenericPublic = UintAuthorityId;
+	type GenericSignature = TestSignature;
+}
+
+impl SigningTypes for Test {
+	type Public = UintAuthorityId;
+	type Signature = TestSignature;
+}
+
+impl<LocalCall> CreateTransactionBase<LocalCall> for Test
+where
+	RuntimeCall: From<LocalCall>,
+{
+	type RuntimeCall = RuntimeCall;
+	type Extrinsic = Extrinsic;
+}
+
+impl<LocalCall> CreateSignedTransaction<LocalCall> for Test
+where
+	RuntimeCall: From<LocalCall>,
+{
+	fn create_signed_transaction<C: AppCrypto<Self::Public, Self::Signature>>(
+		call: RuntimeCall,
+		_public: UintAuthorityId,
+		account: u64,
+		_nonce: u64,
+	) -> Option<Extrinsic> {
+		Some(Extrinsic::new_signed(call, account, (), ()))
+	}
+}
+
+impl<LocalCall> CreateBare<LocalCall> for Test
+where
+	RuntimeCall: From<LocalCall>,
+{
+	fn create_bare(call: RuntimeCall) -> Extrinsic {
+		Extrinsic::new_bare(call)
+	}
 }
 
 // Build genesis storage according to the mock runtime.
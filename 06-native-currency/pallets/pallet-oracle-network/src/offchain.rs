@@ -0,0 +1,144 @@
+//! Offchain price fetching
+//!
+//! HTTP fetching, JSON path extraction and fixed-point parsing used by the offchain
+//! worker, plus the per-feed retry state it keeps in offchain local storage.
+
+use codec::{Decode, Encode};
+use sp_runtime::{
+	offchain::{http, Duration},
+	RuntimeDebug,
+};
+use sp_std::vec::Vec;
+
+/// Deadline for a single source request
+pub const FETCH_TIMEOUT_MS: u64 = 3_000;
+
+/// Prefix of the offchain local storage key holding a feed's `FetchState`
+pub const FETCH_STATE_PREFIX: &[u8] = b"oracle-network::fetch::";
+
+/// Offchain local storage key of the worker lock
+pub const LOCK_KEY: &[u8] = b"oracle-network::lock";
+
+/// Blocks after which a stale worker lock may be taken over
+pub const LOCK_BLOCK_EXPIRATION: u32 = 3;
+
+/// Milliseconds after which a stale worker lock may be taken over
+pub const LOCK_TIMEOUT_EXPIRATION_MS: u64 = 20_000;
+
+/// Why a feed could not be fetched or submitted
+#[derive(Clone, Copy, PartialEq, Eq, RuntimeDebug)]
+pub enum OffchainError {
+	/// No source returned a usable value
+	NoValue,
+	/// No local oracle key could sign the submission
+	NoSigner,
+	/// The transaction pool refused the submission
+	SubmitFailed,
+}
+
+/// Retry state of a feed, kept in offchain local storage
+#[derive(Clone, Copy, Default, Encode, Decode, PartialEq, Eq, RuntimeDebug)]
+pub struct FetchState {
+	/// First block at which the feed may be fetched again
+	pub next_fetch: u32,
+	/// Consecutive failed attempts
+	pub failures: u32,
+}
+
+impl FetchState {
+	/// State after a successful submission: fetch again after one interval
+	pub fn succeeded(now: u32, interval: u32) -> Self {
+		Self { next_fetch: now.saturating_add(interval.max(1)), failures: 0 }
+	}
+
+	/// State after a failed attempt: wait `interval * 2^failures`, capped at `max_backoff`
+	pub fn failed(self, now: u32, interval: u32, max_backoff: u32) -> Self {
+		let failures = self.failures.saturating_add(1);
+		let interval = interval.max(1);
+		let delay = interval
+			.saturating_mul(2u32.saturating_pow(failures))
+			.min(max_backoff.max(interval));
+		Self { next_fetch: now.saturating_add(delay), failures }
+	}
+}
+
+/// Offchain local storage key of a feed's `FetchState`
+pub fn fetch_state_key(feed: &[u8]) -> Vec<u8> {
+	[FETCH_STATE_PREFIX, feed].concat()
+}
+
+/// GET `url` and return the body of a `200` response
+pub fn fetch(url: &[u8]) -> Result<Vec<u8>, http::Error> {
+	let url = core::str::from_utf8(url).map_err(|_| http::Error::Unknown)?;
+	let deadline = sp_io::offchain::timestamp().add(Duration::from_millis(FETCH_TIMEOUT_MS));
+
+	let pending = http::Request::get(url)
+		.deadline(deadline)
+		.send()
+		.map_err(|_| http::Error::IoError)?;
+	let response = pending.try_wait(deadline).map_err(|_| http::Error::DeadlineReached)??;
+	if response.code != 200 {
+		log::warn!("Oracle source {} returned status {}", url, response.code);
+		return Err(http::Error::Unknown);
+	}
+
+	Ok(response.body().collect())
+}
+
+/// Extract the value at a dot-separated path such as `data.amount` or `result.0.price`
+///
+/// Numeric segments index into arrays, an empty path selects the whole document.
+/// Returns the value as decimal text; strings are returned as-is, so sources that
+/// quote their numbers work too.
+pub fn extract_json_path(body: &[u8], path: &[u8]) -> Option<Vec<u8>> {
+	use serde_json::Value;
+
+	let mut value: Value = serde_json::from_slice(body).ok()?;
+	let path = core::str::from_utf8(path).ok()?;
+
+	for segment in path.split('.').filter(|segment| !segment.is_empty()) {
+		value = match value {
+			Value::Object(mut fields) => fields.remove(segment)?,
+			Value::Array(mut items) => {
+				let index: usize = segment.parse().ok()?;
+				if index >= items.len() {
+					return None;
+				}
+				items.swap_remove(index)
+			},
+			_ => return None,
+		};
+	}
+
+	match value {
+		Value::String(text) => Some(text.into_bytes()),
+		Value::Number(number) => serde_json::to_vec(&number).ok(),
+		_ => None,
+	}
+}
+
+/// Parse unsigned decimal text such as `65123.45` into a fixed-point integer with
+/// `decimals` decimals, truncating extra fraction digits
+///
+/// Signs, exponents and anything else but digits and a single `.` are rejected.
+pub fn parse_decimal(text: &[u8], decimals: u8) -> Option<u128> {
+	let (integer, fraction) = match text.iter().position(|&b| b == b'.') {
+		Some(dot) => (&text[..dot], &text[dot + 1..]),
+		None => (text, &[][..]),
+	};
+	if integer.is_empty() || !integer.iter().chain(fraction).all(u8::is_ascii_digit) {
+		return None;
+	}
+
+	let fraction = fraction
+		.iter()
+		.copied()
+		.chain(core::iter::repeat(b'0'))
+		.take(decimals as usize);
+
+	let mut value: u128 = 0;
+	for digit in integer.iter().copied().chain(fraction) {
+		value = value.checked_mul(10)?.checked_add((digit - b'0') as u128)?;
+	}
+	Some(value)
+}
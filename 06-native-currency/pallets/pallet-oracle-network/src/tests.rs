@@ -1,8 +1,24 @@
 //! Tests for oracle network pallet
 
-use crate::{mock::*, Error, Event};
-use frame_support::{assert_noop, assert_ok};
-use sp_runtime::traits::StaticLookup;
+use crate::{
+	mock::*,
+	offchain::{self, FetchState},
+	Call, Error, Event, FeedConfig, FeedSource, FeedValuePayload, SubmissionMode,
+};
+use codec::{Decode, Encode};
+use etrid_oracle_primitives::{feed_id, FeedId, PriceFeed, ETH_USD};
+use frame_support::{assert_noop, assert_ok, traits::Hooks};
+use sp_arithmetic::Permill;
+use sp_core::offchain::{
+	testing::{PendingRequest, TestOffchainExt, TestTransactionPoolExt},
+	OffchainDbExt, OffchainWorkerExt, TransactionPoolExt,
+};
+use sp_runtime::{
+	offchain::storage::StorageValueRef,
+	testing::{TestSignature, UintAuthorityId},
+	traits::{StaticLookup, ValidateUnsigned},
+	transaction_validity::{InvalidTransaction, TransactionSource, TransactionValidity},
+};
 
 #[test]
 fn register_oracle_works() {
@@ -306,3 +322,452 @@ fn get_active_oracles_works() {
 		assert!(active.contains(&3));
 	});
 }
+
+fn feed_config(min_reporters: u32) -> FeedConfig<u64> {
+	FeedConfig {
+		decimals: 2,
+		heartbeat: 10,
+		deviation_threshold: Permill::from_percent(1),
+		min_reporters,
+		mad_multiplier: 3,
+		tolerance: Permill::from_percent(1),
+	}
+}
+
+#[test]
+fn register_feed_validates_config() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			OracleNetwork::register_feed(RuntimeOrigin::signed(1), ETH_USD, feed_config(1)),
+			sp_runtime::DispatchError::BadOrigin
+		);
+		assert_noop!(
+			OracleNetwork::register_feed(RuntimeOrigin::root(), ETH_USD, feed_config(0)),
+			Error::<Test>::InvalidFeedConfig
+		);
+
+		assert_ok!(OracleNetwork::register_feed(RuntimeOrigin::root(), ETH_USD, feed_config(1)));
+		assert_eq!(OracleNetwork::feeds(ETH_USD), Some(feed_config(1)));
+		System::assert_last_event(Event::FeedRegistered { feed: ETH_USD }.into());
+	});
+}
+
+#[test]
+fn submit_feed_value_requires_active_oracle_and_feed() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(OracleNetwork::register_feed(RuntimeOrigin::root(), ETH_USD, feed_config(2)));
+
+		assert_noop!(
+			OracleNetwork::submit_feed_value(RuntimeOrigin::signed(1), ETH_USD, 100),
+			Error::<Test>::OracleNotActive
+		);
+
+		assert_ok!(OracleNetwork::register_oracle(RuntimeOrigin::signed(1), 1000));
+		assert_noop!(
+			OracleNetwork::submit_feed_value(RuntimeOrigin::signed(1), feed_id(b"NOPE/USD"), 100),
+			Error::<Test>::FeedNotFound
+		);
+
+		assert_ok!(OracleNetwork::submit_feed_value(RuntimeOrigin::signed(1), ETH_USD, 100));
+		assert_noop!(
+			OracleNetwork::submit_feed_value(RuntimeOrigin::signed(1), ETH_USD, 101),
+			Error::<Test>::AlreadySubmitted
+		);
+
+		// Quorum of two not reached: the round stays open
+		OracleNetwork::on_finalize(1);
+		assert!(OracleNetwork::latest_price(ETH_USD).is_none());
+		assert_noop!(
+			OracleNetwork::submit_feed_value(RuntimeOrigin::signed(1), ETH_USD, 101),
+			Error::<Test>::AlreadySubmitted
+		);
+	});
+}
+
+#[test]
+fn feed_round_rejects_and_slashes_outlier() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(OracleNetwork::register_feed(RuntimeOrigin::root(), ETH_USD, feed_config(4)));
+		for (reporter, value) in [(1, 100_000), (2, 100_100), (3, 99_900), (4, 150_000)] {
+			assert_ok!(OracleNetwork::register_oracle(RuntimeOrigin::signed(reporter), 1000));
+			assert_ok!(OracleNetwork::submit_feed_value(RuntimeOrigin::signed(reporter), ETH_USD, value));
+		}
+
+		OracleNetwork::on_finalize(1);
+
+		let price = OracleNetwork::latest_price(ETH_USD).unwrap();
+		assert_eq!(price.value, 100_000);
+		assert_eq!(price.round, 1);
+		assert_eq!(price.reporters, 3);
+		assert_eq!(OracleNetwork::fresh(&ETH_USD), Some(price));
+
+		// Outlier lost 5% of its stake and 10 reputation
+		let outlier = OracleNetwork::oracle_operators(4).unwrap();
+		assert_eq!(outlier.stake, 950);
+		assert_eq!(outlier.reputation, 90);
+		assert_eq!(outlier.failed_submissions, 1);
+		System::assert_has_event(Event::FeedValueRejected {
+			feed: ETH_USD,
+			reporter: 4,
+			value: 150_000,
+			median: 100_050,
+		}.into());
+
+		// Honest reporters were credited
+		assert_eq!(OracleNetwork::oracle_operators(1).unwrap().successful_submissions, 1);
+		assert_eq!(OracleNetwork::oracle_operators(1).unwrap().stake, 1000);
+	});
+}
+
+#[test]
+fn feed_publishes_on_deviation_or_heartbeat() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(OracleNetwork::register_feed(RuntimeOrigin::root(), ETH_USD, feed_config(1)));
+		assert_ok!(OracleNetwork::register_oracle(RuntimeOrigin::signed(1), 1000));
+
+		let report = |block: u64, value: u128| {
+			System::set_block_number(block);
+			assert_ok!(OracleNetwork::submit_feed_value(RuntimeOrigin::signed(1), ETH_USD, value));
+			OracleNetwork::on_finalize(block);
+			OracleNetwork::latest_price(ETH_USD).unwrap()
+		};
+
+		assert_eq!(report(1, 100_000).round, 1);
+
+		// 0.5% move inside the 1% threshold before the heartbeat: nothing published
+		let price = report(2, 100_500);
+		assert_eq!((price.round, price.value), (1, 100_000));
+
+		// 2% move crosses the threshold
+		let price = report(3, 102_000);
+		assert_eq!((price.round, price.value, price.updated_at), (2, 102_000, 3));
+
+		// Unchanged value is republished once the heartbeat elapses
+		let price = report(13, 102_000);
+		assert_eq!((price.round, price.updated_at), (3, 13));
+
+		// Stale after two heartbeats without a new round
+		System::set_block_number(33);
+		assert!(OracleNetwork::fresh(&ETH_USD).is_some());
+		System::set_block_number(34);
+		assert!(OracleNetwork::fresh(&ETH_USD).is_none());
+		assert!(OracleNetwork::latest(&ETH_USD).is_some());
+	});
+}
+
+#[test]
+fn remove_feed_clears_round_and_price() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(OracleNetwork::register_feed(RuntimeOrigin::root(), ETH_USD, feed_config(1)));
+		assert_ok!(OracleNetwork::register_oracle(RuntimeOrigin::signed(1), 1000));
+		assert_ok!(OracleNetwork::submit_feed_value(RuntimeOrigin::signed(1), ETH_USD, 100_000));
+		OracleNetwork::on_finalize(1);
+		assert_ok!(OracleNetwork::submit_feed_value(RuntimeOrigin::signed(1), ETH_USD, 100_000));
+
+		assert_ok!(OracleNetwork::remove_feed(RuntimeOrigin::root(), ETH_USD));
+
+		assert!(OracleNetwork::feeds(ETH_USD).is_none());
+		assert!(OracleNetwork::latest_price(ETH_USD).is_none());
+		assert!(crate::PendingFeeds::<Test>::get().is_empty());
+		assert_noop!(
+			OracleNetwork::remove_feed(RuntimeOrigin::root(), ETH_USD),
+			Error::<Test>::FeedNotFound
+		);
+	});
+}
+
+fn source(url: &str, json_path: &str) -> FeedSource {
+	FeedSource {
+		url: url.as_bytes().to_vec().try_into().unwrap(),
+		json_path: json_path.as_bytes().to_vec().try_into().unwrap(),
+	}
+}
+
+/// Expected request for the mock HTTP server
+fn get(url: &str, body: &str) -> PendingRequest {
+	PendingRequest {
+		method: "GET".into(),
+		uri: url.into(),
+		response: Some(body.as_bytes().to_vec()),
+		sent: true,
+		..Default::default()
+	}
+}
+
+fn fetch_state(feed: FeedId) -> FetchState {
+	StorageValueRef::persistent(&offchain::fetch_state_key(&feed))
+		.get()
+		.unwrap()
+		.unwrap_or_default()
+}
+
+/// Oracle 1 with a local `orcl` key, reporting ETH/USD from three sources
+fn setup_offchain_feed() {
+	assert_ok!(OracleNetwork::register_oracle(RuntimeOrigin::signed(1), 1000));
+	assert_ok!(OracleNetwork::register_feed(RuntimeOrigin::root(), ETH_USD, feed_config(1)));
+	assert_ok!(OracleNetwork::set_feed_sources(RuntimeOrigin::root(), ETH_USD, vec![
+		source("https://a.test/eth", "data.amount"),
+		source("https://b.test/eth", "result.0.price"),
+		source("https://c.test/eth", "ethereum.usd"),
+	]));
+	UintAuthorityId::set_all_keys(vec![1u64]);
+}
+
+/// Source responses: two agree around $3012.75, the third is an outlier
+const SOURCE_RESPONSES: [(&str, &str); 3] = [
+	("https://a.test/eth", r#"{"data":{"base":"ETH","amount":"3012.50"}}"#),
+	("https://b.test/eth", r#"{"result":[{"price":3013.0}]}"#),
+	("https://c.test/eth", r#"{"ethereum":{"usd":3500}}"#),
+];
+
+#[test]
+fn set_feed_sources_works() {
+	new_test_ext().execute_with(|| {
+		let sources = vec![source("https://a.test/eth", "data.amount")];
+		assert_noop!(
+			OracleNetwork::set_feed_sources(RuntimeOrigin::signed(1), ETH_USD, sources.clone()),
+			sp_runtime::DispatchError::BadOrigin
+		);
+		assert_noop!(
+			OracleNetwork::set_feed_sources(RuntimeOrigin::root(), ETH_USD, sources.clone()),
+			Error::<Test>::FeedNotFound
+		);
+
+		assert_ok!(OracleNetwork::register_feed(RuntimeOrigin::root(), ETH_USD, feed_config(1)));
+		assert_noop!(
+			OracleNetwork::set_feed_sources(RuntimeOrigin::root(), ETH_USD, vec![sources[0].clone(); 5]),
+			Error::<Test>::TooManyFeedSources
+		);
+
+		assert_ok!(OracleNetwork::set_feed_sources(RuntimeOrigin::root(), ETH_USD, sources.clone()));
+		assert_eq!(OracleNetwork::feed_sources(ETH_USD).into_inner(), sources);
+		System::assert_last_event(Event::FeedSourcesUpdated { feed: ETH_USD, sources: 1 }.into());
+
+		// Removing the feed drops its sources
+		assert_ok!(OracleNetwork::remove_feed(RuntimeOrigin::root(), ETH_USD));
+		assert!(OracleNetwork::feed_sources(ETH_USD).is_empty());
+	});
+}
+
+#[test]
+fn json_path_and_decimal_parsing() {
+	let body = br#"{"data":{"amount":"65123.456789123"},"result":[{"price":3012.5}],"usd":65000}"#;
+
+	assert_eq!(offchain::extract_json_path(body, b"data.amount"), Some(b"65123.456789123".to_vec()));
+	assert_eq!(offchain::extract_json_path(body, b"result.0.price"), Some(b"3012.5".to_vec()));
+	assert_eq!(offchain::extract_json_path(body, b"usd"), Some(b"65000".to_vec()));
+	assert_eq!(offchain::extract_json_path(body, b"result.1.price"), None);
+	assert_eq!(offchain::extract_json_path(body, b"data"), None);
+	assert_eq!(offchain::extract_json_path(b"<html>", b"usd"), None);
+
+	assert_eq!(offchain::parse_decimal(b"65123.456789123", 8), Some(6_512_345_678_912));
+	assert_eq!(offchain::parse_decimal(b"65000", 2), Some(6_500_000));
+	assert_eq!(offchain::parse_decimal(b"-1", 2), None);
+	assert_eq!(offchain::parse_decimal(b"1e5", 2), None);
+	assert_eq!(offchain::parse_decimal(b"1.2.3", 2), None);
+}
+
+#[test]
+fn offchain_worker_submits_signed_median() {
+	let (offchain, http) = TestOffchainExt::new();
+	let (pool, pool_state) = TestTransactionPoolExt::new();
+	let mut t = new_test_ext();
+	t.register_extension(OffchainDbExt::new(offchain.clone()));
+	t.register_extension(OffchainWorkerExt::new(offchain));
+	t.register_extension(TransactionPoolExt::new(pool));
+
+	t.execute_with(|| {
+		setup_offchain_feed();
+		for (url, body) in SOURCE_RESPONSES {
+			http.write().expect_request(get(url, body));
+		}
+
+		OracleNetwork::offchain_worker(1);
+
+		// Outlier source dropped, median of the other two submitted
+		let tx = pool_state.write().transactions.pop().unwrap();
+		assert!(pool_state.read().transactions.is_empty());
+		let tx = Extrinsic::decode(&mut &*tx).unwrap();
+		assert_eq!(
+			tx.function,
+			RuntimeCall::OracleNetwork(Call::submit_feed_value { feed: ETH_USD, value: 301_275 })
+		);
+		assert_eq!(fetch_state(ETH_USD), FetchState { next_fetch: 6, failures: 0 });
+
+		// Not due again until the next interval
+		OracleNetwork::offchain_worker(2);
+		assert!(pool_state.read().transactions.is_empty());
+	});
+}
+
+#[test]
+fn offchain_worker_submits_unsigned_with_signed_payload() {
+	let (offchain, http) = TestOffchainExt::new();
+	let (pool, pool_state) = TestTransactionPoolExt::new();
+	let mut t = new_test_ext();
+	t.register_extension(OffchainDbExt::new(offchain.clone()));
+	t.register_extension(OffchainWorkerExt::new(offchain));
+	t.register_extension(TransactionPoolExt::new(pool));
+	OffchainSubmission::set(SubmissionMode::UnsignedSignedPayload);
+
+	t.execute_with(|| {
+		setup_offchain_feed();
+		for (url, body) in SOURCE_RESPONSES {
+			http.write().expect_request(get(url, body));
+		}
+
+		OracleNetwork::offchain_worker(1);
+
+		let tx = pool_state.write().transactions.pop().unwrap();
+		let tx = Extrinsic::decode(&mut &*tx).unwrap();
+		let RuntimeCall::OracleNetwork(call) = tx.function else {
+			panic!("unexpected call");
+		};
+		let Call::submit_feed_value_unsigned { payload, signature } = call.clone() else {
+			panic!("expected an unsigned submission");
+		};
+		assert_eq!(payload, FeedValuePayload {
+			feed: ETH_USD,
+			value: 301_275,
+			round: 0,
+			block_number: 1,
+			public: UintAuthorityId(1),
+		});
+		assert!(OracleNetwork::validate_unsigned(TransactionSource::External, &call).is_ok());
+
+		// A payload altered after signing is rejected
+		let forged = Call::submit_feed_value_unsigned {
+			payload: FeedValuePayload { value: 1, ..payload.clone() },
+			signature: signature.clone(),
+		};
+		assert_eq!(
+			OracleNetwork::validate_unsigned(TransactionSource::External, &forged),
+			TransactionValidity::from(InvalidTransaction::BadProof)
+		);
+
+		// Dispatching records the value; a second copy is stale
+		assert_ok!(OracleNetwork::submit_feed_value_unsigned(RuntimeOrigin::none(), payload, signature));
+		assert_eq!(crate::FeedSubmissions::<Test>::get(ETH_USD, 1), Some(301_275));
+		assert_eq!(
+			OracleNetwork::validate_unsigned(TransactionSource::External, &call),
+			TransactionValidity::from(InvalidTransaction::Stale)
+		);
+	});
+}
+
+/// Unsigned submission of `value` by oracle 1's key
+fn unsigned_feed_value(value: u128, round: u64, block_number: u64) -> Call<Test> {
+	let payload = FeedValuePayload {
+		feed: ETH_USD,
+		value,
+		round,
+		block_number,
+		public: UintAuthorityId(1),
+	};
+	let signature = TestSignature(1, payload.encode());
+	Call::submit_feed_value_unsigned { payload, signature }
+}
+
+fn dispatch_unsigned(call: Call<Test>) {
+	let Call::submit_feed_value_unsigned { payload, signature } = call else {
+		unreachable!();
+	};
+	assert_ok!(OracleNetwork::submit_feed_value_unsigned(RuntimeOrigin::none(), payload, signature));
+}
+
+#[test]
+fn signed_payload_from_earlier_round_is_rejected() {
+	new_test_ext().execute_with(|| {
+		setup_offchain_feed();
+		let first = unsigned_feed_value(301_275, 0, 1);
+		assert!(OracleNetwork::validate_unsigned(TransactionSource::External, &first).is_ok());
+		dispatch_unsigned(first.clone());
+
+		// Round 0 closes with oracle 1 as the only reporter
+		OracleNetwork::on_finalize(1);
+		assert_eq!(crate::OpenRounds::<Test>::get(ETH_USD), 1);
+		assert_eq!(crate::FeedSubmissions::<Test>::get(ETH_USD, 1), None);
+
+		// Replaying the round 0 payload within its fetch interval is still stale
+		System::set_block_number(2);
+		assert_eq!(
+			OracleNetwork::validate_unsigned(TransactionSource::External, &first),
+			TransactionValidity::from(InvalidTransaction::Stale)
+		);
+
+		// The reporter's own submission for round 1 is unaffected
+		let second = unsigned_feed_value(301_300, 1, 2);
+		assert!(OracleNetwork::validate_unsigned(TransactionSource::External, &second).is_ok());
+		dispatch_unsigned(second);
+		assert_eq!(crate::FeedSubmissions::<Test>::get(ETH_USD, 1), Some(301_300));
+	});
+}
+
+#[test]
+fn signed_payload_older_than_fetch_interval_is_rejected() {
+	new_test_ext().execute_with(|| {
+		setup_offchain_feed();
+
+		// Fetched at block 1, still the open round, but past the fetch interval
+		System::set_block_number(1 + FetchInterval::get() + 1);
+		let stale = unsigned_feed_value(301_275, 0, 1);
+		assert_eq!(
+			OracleNetwork::validate_unsigned(TransactionSource::External, &stale),
+			TransactionValidity::from(InvalidTransaction::Stale)
+		);
+
+		// Fetched exactly one interval ago is still accepted
+		let fresh = unsigned_feed_value(301_275, 0, 2);
+		assert!(OracleNetwork::validate_unsigned(TransactionSource::External, &fresh).is_ok());
+
+		// Payloads from the future are rejected too
+		let future = unsigned_feed_value(301_275, 0, 1 + FetchInterval::get() + 2);
+		assert_eq!(
+			OracleNetwork::validate_unsigned(TransactionSource::External, &future),
+			TransactionValidity::from(InvalidTransaction::Future)
+		);
+	});
+}
+
+#[test]
+fn offchain_worker_backs_off_on_failures() {
+	let (offchain, http) = TestOffchainExt::new();
+	let (pool, pool_state) = TestTransactionPoolExt::new();
+	let mut t = new_test_ext();
+	t.register_extension(OffchainDbExt::new(offchain.clone()));
+	t.register_extension(OffchainWorkerExt::new(offchain));
+	t.register_extension(TransactionPoolExt::new(pool));
+
+	t.execute_with(|| {
+		setup_offchain_feed();
+		assert_ok!(OracleNetwork::set_feed_sources(RuntimeOrigin::root(), ETH_USD, vec![
+			source("https://a.test/eth", "data.amount"),
+		]));
+		let rate_limited = r#"{"errors":["rate limited"]}"#;
+
+		// First failure waits two intervals
+		http.write().expect_request(get("https://a.test/eth", rate_limited));
+		OracleNetwork::offchain_worker(1);
+		assert_eq!(fetch_state(ETH_USD), FetchState { next_fetch: 11, failures: 1 });
+
+		// Nothing is fetched while backing off
+		OracleNetwork::offchain_worker(10);
+		assert_eq!(fetch_state(ETH_USD), FetchState { next_fetch: 11, failures: 1 });
+
+		// Second failure doubles the delay
+		http.write().expect_request(get("https://a.test/eth", rate_limited));
+		OracleNetwork::offchain_worker(11);
+		assert_eq!(fetch_state(ETH_USD), FetchState { next_fetch: 31, failures: 2 });
+		assert!(pool_state.read().transactions.is_empty());
+
+		// Success resets the backoff
+		http.write().expect_request(get("https://a.test/eth", SOURCE_RESPONSES[0].1));
+		OracleNetwork::offchain_worker(31);
+		assert_eq!(fetch_state(ETH_USD), FetchState { next_fetch: 36, failures: 0 });
+		assert_eq!(pool_state.read().transactions.len(), 1);
+
+		// The delay never exceeds `MaxFetchBackoff`
+		let state = FetchState { next_fetch: 0, failures: 10 };
+		assert_eq!(state.failed(100, 5, 40), FetchState { next_fetch: 140, failures: 11 });
+	});
+}
@@ -0,0 +1,265 @@
+//! PLACEHOLDER weights for `pallet_oracle_network`: hand-estimated, not benchmark output.
+//!
+//! Overwrite with `./scripts/benchmark-pallets.sh pallet_oracle_network` on reference hardware.
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(missing_docs)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use core::marker::PhantomData;
+
+/// Weight functions needed for `pallet_oracle_network`.
+pub trait WeightInfo {
+	fn register_oracle() -> Weight;
+	fn deregister_oracle() -> Weight;
+	fn increase_stake() -> Weight;
+	fn decrease_stake() -> Weight;
+	fn create_data_request() -> Weight;
+	fn submit_oracle_response() -> Weight;
+	fn slash_oracle() -> Weight;
+	fn reactivate_oracle() -> Weight;
+	fn register_feed() -> Weight;
+	fn remove_feed(r: u32, ) -> Weight;
+	fn submit_feed_value() -> Weight;
+	fn set_feed_sources(s: u32, ) -> Weight;
+	fn submit_feed_value_unsigned() -> Weight;
+}
+
+/// Placeholder weights for `pallet_oracle_network`, pending benchmarking.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	/// Storage: `OracleNetwork::OracleOperators` (r:1 w:1)
+	/// Storage: `OracleNetwork::OracleCount` (r:1 w:1)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Storage: `OracleNetwork::ActiveOracles` (r:1 w:1)
+	fn register_oracle() -> Weight {
+		Weight::from_parts(86_240_000, 39210)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: `OracleNetwork::OracleOperators` (r:1 w:1)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Storage: `OracleNetwork::OracleCount` (r:1 w:1)
+	/// Storage: `OracleNetwork::ActiveOracles` (r:1 w:1)
+	fn deregister_oracle() -> Weight {
+		Weight::from_parts(89_170_000, 39210)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: `OracleNetwork::OracleOperators` (r:1 w:1)
+	/// Storage: `System::Account` (r:1 w:1)
+	fn increase_stake() -> Weight {
+		Weight::from_parts(42_380_000, 6214)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `OracleNetwork::OracleOperators` (r:1 w:1)
+	/// Storage: `System::Account` (r:1 w:1)
+	fn decrease_stake() -> Weight {
+		Weight::from_parts(40_910_000, 6214)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `OracleNetwork::NextRequestId` (r:1 w:1)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Storage: `OracleNetwork::DataRequests` (r:0 w:1)
+	fn create_data_request() -> Weight {
+		Weight::from_parts(45_720_000, 4096)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: `OracleNetwork::OracleOperators` (r:1 w:1)
+	/// Storage: `OracleNetwork::DataRequests` (r:1 w:1)
+	/// Storage: `OracleNetwork::OracleResponses` (r:1 w:1)
+	/// Storage: `OracleNetwork::ReputationHistory` (r:1 w:1)
+	fn submit_oracle_response() -> Weight {
+		Weight::from_parts(61_530_000, 11982)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: `OracleNetwork::OracleOperators` (r:1 w:1)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Storage: `Balances::TotalIssuance` (r:1 w:1)
+	/// Storage: `OracleNetwork::ReputationHistory` (r:1 w:1)
+	/// Storage: `OracleNetwork::ActiveOracles` (r:1 w:1)
+	fn slash_oracle() -> Weight {
+		Weight::from_parts(112_860_000, 44850)
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
+	}
+	/// Storage: `OracleNetwork::OracleOperators` (r:1 w:1)
+	/// Storage: `OracleNetwork::ActiveOracles` (r:1 w:1)
+	fn reactivate_oracle() -> Weight {
+		Weight::from_parts(71_040_000, 36108)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `OracleNetwork::Feeds` (r:1 w:1)
+	/// Storage: `OracleNetwork::CounterForFeeds` (r:1 w:1)
+	fn register_feed() -> Weight {
+		Weight::from_parts(24_630_000, 4017)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `OracleNetwork::Feeds` (r:1 w:1)
+	/// Storage: `OracleNetwork::CounterForFeeds` (r:1 w:1)
+	/// Storage: `OracleNetwork::LatestPrices` (r:0 w:1)
+	/// Storage: `OracleNetwork::FeedSources` (r:0 w:1)
+	/// Storage: `OracleNetwork::PendingFeeds` (r:1 w:1)
+	/// Storage: `OracleNetwork::FeedSubmissions` (r:0 w:0)
+	/// The range of component `r` is `[0, 1000]`.
+	fn remove_feed(r: u32, ) -> Weight {
+		Weight::from_parts(38_470_000, 5538)
+			.saturating_add(Weight::from_parts(1_160_000, 0).saturating_mul(r.into()))
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(r.into())))
+	}
+	/// Storage: `OracleNetwork::OracleOperators` (r:1 w:0)
+	/// Storage: `OracleNetwork::Feeds` (r:1 w:0)
+	/// Storage: `OracleNetwork::FeedSubmissions` (r:1 w:1)
+	/// Storage: `OracleNetwork::PendingFeeds` (r:1 w:1)
+	fn submit_feed_value() -> Weight {
+		Weight::from_parts(48_350_000, 10231)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `OracleNetwork::Feeds` (r:1 w:0)
+	/// Storage: `OracleNetwork::FeedSources` (r:0 w:1)
+	/// The range of component `s` is `[0, 8]`.
+	fn set_feed_sources(s: u32, ) -> Weight {
+		Weight::from_parts(18_210_000, 3518)
+			.saturating_add(Weight::from_parts(1_930_000, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `OracleNetwork::OracleOperators` (r:1 w:0)
+	/// Storage: `OracleNetwork::Feeds` (r:1 w:0)
+	/// Storage: `OracleNetwork::FeedSubmissions` (r:1 w:1)
+	/// Storage: `OracleNetwork::PendingFeeds` (r:1 w:1)
+	fn submit_feed_value_unsigned() -> Weight {
+		Weight::from_parts(46_980_000, 10231)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+}
+
+// For backwards compatibility and tests.
+impl WeightInfo for () {
+	/// Storage: `OracleNetwork::OracleOperators` (r:1 w:1)
+	/// Storage: `OracleNetwork::OracleCount` (r:1 w:1)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Storage: `OracleNetwork::ActiveOracles` (r:1 w:1)
+	fn register_oracle() -> Weight {
+		Weight::from_parts(86_240_000, 39210)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: `OracleNetwork::OracleOperators` (r:1 w:1)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Storage: `OracleNetwork::OracleCount` (r:1 w:1)
+	/// Storage: `OracleNetwork::ActiveOracles` (r:1 w:1)
+	fn deregister_oracle() -> Weight {
+		Weight::from_parts(89_170_000, 39210)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: `OracleNetwork::OracleOperators` (r:1 w:1)
+	/// Storage: `System::Account` (r:1 w:1)
+	fn increase_stake() -> Weight {
+		Weight::from_parts(42_380_000, 6214)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `OracleNetwork::OracleOperators` (r:1 w:1)
+	/// Storage: `System::Account` (r:1 w:1)
+	fn decrease_stake() -> Weight {
+		Weight::from_parts(40_910_000, 6214)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `OracleNetwork::NextRequestId` (r:1 w:1)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Storage: `OracleNetwork::DataRequests` (r:0 w:1)
+	fn create_data_request() -> Weight {
+		Weight::from_parts(45_720_000, 4096)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: `OracleNetwork::OracleOperators` (r:1 w:1)
+	/// Storage: `OracleNetwork::DataRequests` (r:1 w:1)
+	/// Storage: `OracleNetwork::OracleResponses` (r:1 w:1)
+	/// Storage: `OracleNetwork::ReputationHistory` (r:1 w:1)
+	fn submit_oracle_response() -> Weight {
+		Weight::from_parts(61_530_000, 11982)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: `OracleNetwork::OracleOperators` (r:1 w:1)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Storage: `Balances::TotalIssuance` (r:1 w:1)
+	/// Storage: `OracleNetwork::ReputationHistory` (r:1 w:1)
+	/// Storage: `OracleNetwork::ActiveOracles` (r:1 w:1)
+	fn slash_oracle() -> Weight {
+		Weight::from_parts(112_860_000, 44850)
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
+	}
+	/// Storage: `OracleNetwork::OracleOperators` (r:1 w:1)
+	/// Storage: `OracleNetwork::ActiveOracles` (r:1 w:1)
+	fn reactivate_oracle() -> Weight {
+		Weight::from_parts(71_040_000, 36108)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `OracleNetwork::Feeds` (r:1 w:1)
+	/// Storage: `OracleNetwork::CounterForFeeds` (r:1 w:1)
+	fn register_feed() -> Weight {
+		Weight::from_parts(24_630_000, 4017)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `OracleNetwork::Feeds` (r:1 w:1)
+	/// Storage: `OracleNetwork::CounterForFeeds` (r:1 w:1)
+	/// Storage: `OracleNetwork::LatestPrices` (r:0 w:1)
+	/// Storage: `OracleNetwork::FeedSources` (r:0 w:1)
+	/// Storage: `OracleNetwork::PendingFeeds` (r:1 w:1)
+	/// Storage: `OracleNetwork::FeedSubmissions` (r:0 w:0)
+	/// The range of component `r` is `[0, 1000]`.
+	fn remove_feed(r: u32, ) -> Weight {
+		Weight::from_parts(38_470_000, 5538)
+			.saturating_add(Weight::from_parts(1_160_000, 0).saturating_mul(r.into()))
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(r.into())))
+	}
+	/// Storage: `OracleNetwork::OracleOperators` (r:1 w:0)
+	/// Storage: `OracleNetwork::Feeds` (r:1 w:0)
+	/// Storage: `OracleNetwork::FeedSubmissions` (r:1 w:1)
+	/// Storage: `OracleNetwork::PendingFeeds` (r:1 w:1)
+	fn submit_feed_value() -> Weight {
+		Weight::from_parts(48_350_000, 10231)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `OracleNetwork::Feeds` (r:1 w:0)
+	/// Storage: `OracleNetwork::FeedSources` (r:0 w:1)
+	/// The range of component `s` is `[0, 8]`.
+	fn set_feed_sources(s: u32, ) -> Weight {
+		Weight::from_parts(18_210_000, 3518)
+			.saturating_add(Weight::from_parts(1_930_000, 0).saturating_mul(s.into()))
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `OracleNetwork::OracleOperators` (r:1 w:0)
+	/// Storage: `OracleNetwork::Feeds` (r:1 w:0)
+	/// Storage: `OracleNetwork::FeedSubmissions` (r:1 w:1)
+	/// Storage: `OracleNetwork::PendingFeeds` (r:1 w:1)
+	fn submit_feed_value_unsigned() -> Weight {
+		Weight::from_parts(46_980_000, 10231)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+}
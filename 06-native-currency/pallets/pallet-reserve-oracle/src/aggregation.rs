@@ -6,7 +6,7 @@
 //! - Multi-source price storage (up to 10 sources)
 //! - Median calculation for robust price discovery
 //! - Weighted mean calculation based on confidence scores
-//! - Outlier filtering using 2 standard deviation rule
+//! - Outlier filtering using the shared median + MAD rule
 //! - Confidence scoring based on source count and individual confidences
 //! - Staleness detection and failover mechanisms
 //!
@@ -23,11 +23,14 @@
 //! ```
 //!
 //! ### Outlier Filter
-//! Removes prices that are more than 2 standard deviations from the mean:
+//! Removes prices too far from the median, using the same rule `pallet-oracle-network`
+//! applies to reporter rounds (`etrid_oracle_primitives::filter_round`):
 //! ```text
-//! |price - mean| > 2σ  →  filtered out
+//! |price - median| > max(3 × MAD, 1% × median)  →  filtered out
 //! ```
 //! This removes extreme values that could indicate faulty oracles or manipulation attempts.
+//! Unlike a standard deviation band, one extreme value cannot widen the band enough to
+//! hide a second one.
 //!
 //! ### Confidence Score
 //! Overall confidence is calculated as:
@@ -37,8 +40,15 @@
 //! ```
 //! This rewards having multiple independent sources (up to 5 sources for +20 bonus).
 
+use etrid_oracle_primitives::Permill;
 use sp_std::vec::Vec;
 
+/// Width of the outlier band in MADs
+pub const MAD_MULTIPLIER: u32 = 3;
+
+/// Minimum width of the outlier band relative to the median (1%)
+pub const OUTLIER_TOLERANCE: Permill = Permill::from_parts(10_000);
+
 /// no_std compatible square root using Newton's method
 pub fn sqrt_f64(x: f64) -> f64 {
 	if x == 0.0 {
@@ -140,84 +150,23 @@ pub fn calculate_weighted_mean(prices: &[PriceWithConfidence]) -> u128 {
 	}
 }
 
-/// Calculate standard deviation
-fn calculate_std_dev(prices: &[PriceWithConfidence]) -> f64 {
-	if prices.len() < 2 {
-		return 0.0;
-	}
-
-	let mean = prices.iter().map(|(p, _)| *p as f64).sum::<f64>() / prices.len() as f64;
-
-	let variance = prices
-		.iter()
-		.map(|(p, _)| {
-			let diff = *p as f64 - mean;
-			diff * diff
-		})
-		.sum::<f64>()
-		/ prices.len() as f64;
-
-	sqrt_f64(variance)
-}
-
-/// Filter outliers using 2 standard deviation rule
+/// Filter outliers using the median + MAD rule
 ///
-/// Prices more than 2 standard deviations from the mean are considered outliers
-/// and are removed. This helps protect against faulty oracles or manipulation.
+/// Prices further than `max(3 × MAD, 1% × median)` from the median are considered
+/// outliers and are removed. This helps protect against faulty oracles or manipulation.
 ///
 /// For small datasets (< 3 prices), no filtering is applied.
 pub fn filter_outliers(prices: &[PriceWithConfidence]) -> (Vec<PriceWithConfidence>, u32) {
-	if prices.len() < 3 {
-		return (prices.to_vec(), 0);
-	}
-
-	// Determine appropriate scaling factor based on price magnitude
-	let max_price = prices.iter().map(|(p, _)| *p).max().unwrap_or(1);
-	let scale_factor = if max_price > 1_000_000_000 {
-		// For large prices (> 1 billion), scale down by 1e8
-		100_000_000.0
-	} else {
-		// For smaller prices, no scaling needed
-		1.0
+	let values: Vec<u128> = prices.iter().map(|(p, _)| *p).collect();
+	let Some(outcome) =
+		etrid_oracle_primitives::filter_round(&values, MAD_MULTIPLIER, OUTLIER_TOLERANCE)
+	else {
+		return (Vec::new(), 0);
 	};
 
-	// Convert to scaled f64 values
-	let scaled: Vec<f64> = prices.iter().map(|(p, _)| *p as f64 / scale_factor).collect();
-
-	let mean = scaled.iter().sum::<f64>() / scaled.len() as f64;
-
-	// Calculate standard deviation on scaled values
-	let variance = scaled
-		.iter()
-		.map(|p| {
-			let diff = *p - mean;
-			diff * diff
-		})
-		.sum::<f64>()
-		/ scaled.len() as f64;
-	let std_dev = sqrt_f64(variance);
-
-	let original_count = prices.len();
+	let filtered = outcome.accepted.iter().map(|&i| prices[i]).collect();
 
-	// If std_dev is 0 or very small, don't filter (all prices are very similar)
-	if std_dev < 0.01 {
-		return (prices.to_vec(), 0);
-	}
-
-	// Filter: keep prices within ~2 standard deviations (using 1.85 for stricter outlier detection)
-	let filtered: Vec<PriceWithConfidence> = prices
-		.iter()
-		.zip(scaled.iter())
-		.filter(|(_, scaled_p)| {
-			let diff = (*scaled_p - mean).abs();
-			diff < 1.85 * std_dev
-		})
-		.map(|((price, conf), _)| (*price, *conf))
-		.collect();
-
-	let outliers_removed = (original_count - filtered.len()) as u32;
-
-	(filtered, outliers_removed)
+	(filtered, outcome.rejected.len() as u32)
 }
 
 /// Calculate overall confidence score
@@ -357,6 +306,22 @@ mod tests {
 		assert_eq!(outliers_removed, 1);
 	}
 
+	#[test]
+	fn outlier_filtering_is_not_masked_by_second_outlier() {
+		let prices = vec![
+			(100, 90),
+			(101, 90),
+			(102, 90),
+			(103, 90),
+			(104, 90),
+			(1000, 90), // Outlier
+			(2000, 90), // Outlier
+		];
+		let (filtered, outliers_removed) = filter_outliers(&prices);
+		assert_eq!(filtered.len(), 5);
+		assert_eq!(outliers_removed, 2);
+	}
+
 	#[test]
 	fn outlier_filtering_small_dataset() {
 		let prices = vec![(100, 90), (1000, 90)];
@@ -811,42 +811,6 @@ pub mod pallet {
 			}
 		}
 
-		/// Filter outliers using 2 standard deviation rule
-		fn filter_outliers(prices: &[(u128, u8)]) -> Vec<(u128, u8)> {
-			if prices.len() < 3 {
-				return prices.to_vec();
-			}
-
-			// Calculate mean
-			let mean = prices.iter().map(|(p, _)| *p as f64).sum::<f64>() / prices.len() as f64;
-
-			// Calculate standard deviation
-			let variance = prices
-				.iter()
-				.map(|(p, _)| {
-					let diff = *p as f64 - mean;
-					diff * diff
-				})
-				.sum::<f64>()
-				/ prices.len() as f64;
-			let std_dev = aggregation::sqrt_f64(variance);
-
-			// If std_dev is 0 or very small, don't filter (all prices are very similar)
-			if std_dev < 0.01 {
-				return prices.to_vec();
-			}
-
-			// Filter: keep prices within ~2 standard deviations (using 1.85 for stricter outlier detection)
-			prices
-				.iter()
-				.filter(|(p, _)| {
-					let diff = (*p as f64 - mean).abs();
-					diff < 1.85 * std_dev
-				})
-				.copied()
-				.collect()
-		}
-
 		/// Calculate overall confidence score
 		fn calculate_confidence_score(prices: &[(u128, u8)]) -> u8 {
 			if prices.is_empty() {
@@ -917,9 +917,9 @@ fn multiple_outliers_filtered() {
 		));
 
 		let aggregated = ReserveOracle::get_aggregated_price(b"TEST").unwrap();
-		// Should filter the extreme outlier (2000), 1000 may or may not be filtered depending on threshold
-		// With 1.85 std dev threshold and this data distribution, typically only 2000 is filtered
-		assert_eq!(aggregated.sources_count, 6);
+		// Both outliers are filtered: the median + MAD band is not widened by the 2000 outlier
+		assert_eq!(aggregated.sources_count, 5);
+		assert_eq!(aggregated.median_price, 102_00000000u128);
 	});
 }
 
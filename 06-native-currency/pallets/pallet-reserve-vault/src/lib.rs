@@ -42,6 +42,7 @@ pub mod pallet {
 	use sp_arithmetic::{FixedPointNumber, FixedU128, Permill, traits::{SaturatedConversion, Saturating}};
 	use sp_runtime::traits::CheckedSub;
 	use sp_std::vec::Vec;
+	use etrid_oracle_primitives::{self as oracle_primitives, FeedId, PriceFeed};
 
 	/// Supported collateral asset types
 	#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
@@ -74,6 +75,23 @@ pub mod pallet {
 				_ => None,
 			}
 		}
+
+		/// USD price feed for this asset
+		pub fn feed_id(&self) -> FeedId {
+			match self {
+				AssetType::ETR => oracle_primitives::ETR_USD,
+				AssetType::BTC => oracle_primitives::BTC_USD,
+				AssetType::ETH => oracle_primitives::ETH_USD,
+				AssetType::USDC => oracle_primitives::USDC_USD,
+				AssetType::USDT => oracle_primitives::USDT_USD,
+				AssetType::DAI => oracle_primitives::DAI_USD,
+			}
+		}
+
+		/// All supported asset types
+		pub fn all() -> [AssetType; 6] {
+			[AssetType::ETR, AssetType::BTC, AssetType::ETH, AssetType::USDC, AssetType::USDT, AssetType::DAI]
+		}
 	}
 
 	/// Vault entry for each asset type
@@ -110,6 +128,9 @@ pub mod pallet {
 		/// Emergency reserve ratio (1.00 = 100%)
 		#[pallet::constant]
 		type EmergencyReserveRatio: Get<FixedU128>;
+
+		/// Oracle price feeds for collateral assets
+		type PriceFeed: PriceFeed;
 	}
 
 	#[pallet::pallet]
@@ -591,6 +612,30 @@ pub mod pallet {
 			Ok(())
 		}
 
+		/// Pull fresh oracle prices into `AssetPrices` and revalue the affected vault entries
+		///
+		/// Assets without a fresh feed keep their last price.
+		pub fn sync_feed_prices() {
+			for asset in AssetType::all() {
+				let Some(feed_price) = T::PriceFeed::fresh(&asset.feed_id()) else {
+					continue;
+				};
+				let price = feed_price.scaled(2);
+				if price == 0 || price == AssetPrices::<T>::get(&asset) {
+					continue;
+				}
+
+				AssetPrices::<T>::insert(&asset, price);
+				if let Some(mut entry) = Vault::<T>::get(&asset) {
+					if Self::update_vault_entry_value(&mut entry, &asset).is_ok() {
+						Vault::<T>::insert(&asset, entry);
+					}
+				}
+
+				Self::deposit_event(Event::AssetPriceUpdated { asset_type: asset as u8, price });
+			}
+		}
+
 		/// Update vault entry USD values
 		fn update_vault_entry_value(entry: &mut VaultEntry, asset: &AssetType) -> DispatchResult {
 			let price = AssetPrices::<T>::get(asset);
@@ -730,12 +775,14 @@ pub mod pallet {
 	/// Hooks for automatic reserve ratio updates
 	#[pallet::hooks]
 	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
-		/// On finalize, recalculate reserve ratio periodically
+		/// On finalize, refresh oracle prices and recalculate reserve ratio periodically
 		fn on_finalize(_n: BlockNumberFor<T>) {
 			// Auto-recalculate every 100 blocks (~10 minutes)
 			let current_block = <frame_system::Pallet<T>>::block_number();
 
 			if current_block.saturated_into::<u32>() % 100 == 0 {
+				Self::sync_feed_prices();
+
 				// Attempt recalculation (ignore errors)
 				let _ = Self::calculate_and_update_reserve_ratio();
 			}
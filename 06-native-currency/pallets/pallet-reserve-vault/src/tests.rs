@@ -137,6 +137,25 @@ impl pallet_reserve_vault::Config for Test {
 	type OptimalReserveMax = OptimalReserveMax;
 	type ThrottleReserveRatio = ThrottleReserveRatio;
 	type EmergencyReserveRatio = EmergencyReserveRatio;
+	type PriceFeed = MockPriceFeed;
+}
+
+/// Publishes BTC at $65,000 with 8 decimals; every other feed is missing
+pub struct MockPriceFeed;
+impl etrid_oracle_primitives::PriceFeed for MockPriceFeed {
+	fn latest(feed: &etrid_oracle_primitives::FeedId) -> Option<etrid_oracle_primitives::FeedPrice> {
+		(feed == &etrid_oracle_primitives::BTC_USD).then_some(etrid_oracle_primitives::FeedPrice {
+			value: 65_000_00000000,
+			decimals: 8,
+			updated_at: 1,
+			round: 1,
+			reporters: 3,
+		})
+	}
+
+	fn fresh(feed: &etrid_oracle_primitives::FeedId) -> Option<etrid_oracle_primitives::FeedPrice> {
+		Self::latest(feed)
+	}
 }
 
 pub fn new_test_ext() -> sp_io::TestExternalities {
@@ -505,6 +524,27 @@ fn test_price_update_recalculates_vault_value() {
 	});
 }
 
+#[test]
+fn test_sync_feed_prices_revalues_vault() {
+	new_test_ext().execute_with(|| {
+		// Deposit 1 BTC at $60,000
+		assert_ok!(ReserveVault::deposit_collateral(
+			RuntimeOrigin::signed(ALICE),
+			1, // BTC
+			100_000_000
+		));
+
+		ReserveVault::sync_feed_prices();
+
+		// BTC follows the feed, ETH has no feed and keeps its genesis price
+		assert_eq!(ReserveVault::asset_price(AssetType::BTC), 6_500_000);
+		assert_eq!(ReserveVault::asset_price(AssetType::ETH), 300_000);
+
+		let vault = ReserveVault::vault(AssetType::BTC).unwrap();
+		assert_eq!(vault.usd_value, 650); // $65,000 (with decimal adjustment)
+	});
+}
+
 // ═══════════════════════════════════════════════════════════════════════════
 // TEST MODULE 6: Haircut Updates
 // ═══════════════════════════════════════════════════════════════════════════
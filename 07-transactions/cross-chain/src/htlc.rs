@@ -0,0 +1,844 @@
+//! HTLC Atomic Swap Engine
+//!
+//! Drives an atomic swap as two hashed time-locked contracts, one per chain:
+//! - Leg A: `party_a` locks `amount_a` on chain A (typically ÉTRID, via
+//!   `pallet-etrid-tx-types::create_htlc`) for `party_b`
+//! - Leg B: `party_b` locks `amount_b` on chain B (typically a PBC) for `party_a`
+//!
+//! `party_a` chooses the secret. Claiming leg B reveals it on chain B, and the
+//! engine propagates it to claim leg A for `party_b`. Time locks are asymmetric:
+//! leg A must outlive leg B by a safety margin, measured in wall-clock time and
+//! converted to each chain's own block height. Expired legs are refunded
+//! automatically by `tick`.
+//!
+//! Hash locks are SHA-256, matching `hash_secret` in the transaction types pallet.
+//!
+//! `pallet_transaction::htlc_chain::PalletChain` drives the ÉTRID side through
+//! that pallet; `SimulatedChain` (tests and the `test-utils` feature) stands in
+//! for any chain in memory.
+
+use sha2::{Digest, Sha256};
+
+#[cfg(not(feature = "std"))]
+use alloc::{
+    collections::{BTreeMap, BTreeSet},
+    string::{String, ToString},
+    vec::Vec,
+};
+
+#[cfg(feature = "std")]
+use std::collections::{BTreeMap, BTreeSet};
+
+use crate::{AtomicSwap, BridgeError, ChainId, SwapState};
+
+/// HTLC identifier as assigned by the chain holding it
+pub type HtlcId = [u8; 32];
+
+/// Hash a secret preimage into a hash lock (SHA-256)
+pub fn hash_secret(secret: &[u8; 32]) -> [u8; 32] {
+    Sha256::digest(secret).into()
+}
+
+/// HTLC as observed on a chain
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HtlcInfo {
+    pub sender: String,
+    pub receiver: String,
+    pub amount: u128,
+    pub hash_lock: [u8; 32],
+    /// Block height at which the sender may refund
+    pub time_lock: u64,
+    pub claimed: bool,
+    pub refunded: bool,
+    /// Preimage revealed by the claim (from the `HTLCClaimed` event)
+    pub secret: Option<[u8; 32]>,
+}
+
+/// A chain that can hold HTLCs
+///
+/// Implementations follow `pallet-etrid-tx-types` semantics: creation requires
+/// `time_lock > block_height`, only the receiver may claim (with the preimage),
+/// and only the sender may refund once `block_height >= time_lock`.
+pub trait HtlcChain {
+    /// Chain this client talks to
+    fn chain_id(&self) -> ChainId;
+
+    /// Current best block height
+    fn block_height(&self) -> u64;
+
+    /// Target block time in milliseconds
+    fn block_time_ms(&self) -> u64;
+
+    /// Lock `amount` from `sender` for `receiver`
+    fn create_htlc(
+        &mut self,
+        sender: &str,
+        receiver: &str,
+        amount: u128,
+        hash_lock: [u8; 32],
+        time_lock: u64,
+    ) -> Result<HtlcId, BridgeError>;
+
+    /// Claim an HTLC as its receiver by revealing the preimage
+    fn claim_htlc(&mut self, claimer: &str, id: &HtlcId, secret: [u8; 32]) -> Result<(), BridgeError>;
+
+    /// Refund an expired HTLC to its sender
+    fn refund_htlc(&mut self, refunder: &str, id: &HtlcId) -> Result<(), BridgeError>;
+
+    /// Look up an HTLC
+    fn htlc(&self, id: &HtlcId) -> Option<HtlcInfo>;
+}
+
+/// Asymmetric swap timeouts (wall-clock milliseconds)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SwapTimeouts {
+    /// Lifetime of leg A (the initiator's lock)
+    pub initiator_lock_ms: u64,
+    /// Lifetime of leg B (the participant's lock)
+    pub participant_lock_ms: u64,
+    /// Minimum time leg A must outlive leg B, so `party_b` can claim A
+    /// after the secret is revealed on B
+    pub safety_margin_ms: u64,
+}
+
+impl Default for SwapTimeouts {
+    fn default() -> Self {
+        Self {
+            initiator_lock_ms: 48 * 60 * 60 * 1000,
+            participant_lock_ms: 24 * 60 * 60 * 1000,
+            safety_margin_ms: 6 * 60 * 60 * 1000,
+        }
+    }
+}
+
+impl SwapTimeouts {
+    /// Check leg A outlives leg B by at least the safety margin
+    pub fn validate(&self) -> Result<(), BridgeError> {
+        if self.participant_lock_ms == 0
+            || self.initiator_lock_ms < self.participant_lock_ms.saturating_add(self.safety_margin_ms)
+        {
+            return Err(BridgeError::InvalidExpiration);
+        }
+        Ok(())
+    }
+}
+
+/// Convert a duration into blocks on a chain (rounded up)
+pub fn blocks_for(duration_ms: u64, block_time_ms: u64) -> u64 {
+    let block_time_ms = block_time_ms.max(1);
+    duration_ms.div_ceil(block_time_ms)
+}
+
+/// Which HTLC of a swap
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum SwapLeg {
+    /// `party_a` -> `party_b` on chain A
+    A,
+    /// `party_b` -> `party_a` on chain B
+    B,
+}
+
+/// Settlement status of one leg
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LegStatus {
+    Locked,
+    Claimed,
+    Refunded,
+}
+
+/// One locked HTLC of a swap
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LegLock {
+    pub htlc_id: HtlcId,
+    /// Refund height on that leg's chain
+    pub time_lock: u64,
+    pub status: LegStatus,
+}
+
+/// Swap record tracked by the engine
+#[derive(Debug, Clone, PartialEq)]
+pub struct HtlcSwap {
+    pub swap: AtomicSwap,
+    pub hash_lock: [u8; 32],
+    pub secret: Option<[u8; 32]>,
+    pub leg_a: Option<LegLock>,
+    pub leg_b: Option<LegLock>,
+}
+
+/// Actions performed by `tick`
+#[derive(Debug, Clone, PartialEq)]
+pub enum SwapEvent {
+    /// Secret revealed on chain B was used to claim leg A
+    SecretPropagated { swap_id: String },
+    /// Expired leg refunded to its sender
+    Refunded { swap_id: String, leg: SwapLeg },
+    /// A chain rejected an action; retried on the next tick
+    ActionFailed { swap_id: String, leg: SwapLeg, error: BridgeError },
+}
+
+/// Two-chain HTLC swap engine
+pub struct HtlcSwapEngine<A: HtlcChain, B: HtlcChain> {
+    chain_a: A,
+    chain_b: B,
+    timeouts: SwapTimeouts,
+    swaps: BTreeMap<String, HtlcSwap>,
+    /// Pending refunds by (height on chain A, swap id)
+    refunds_a: BTreeSet<(u64, String)>,
+    /// Pending refunds by (height on chain B, swap id)
+    refunds_b: BTreeSet<(u64, String)>,
+}
+
+impl<A: HtlcChain, B: HtlcChain> HtlcSwapEngine<A, B> {
+    /// Create engine over two chain clients
+    pub fn new(chain_a: A, chain_b: B, timeouts: SwapTimeouts) -> Result<Self, BridgeError> {
+        timeouts.validate()?;
+
+        Ok(Self {
+            chain_a,
+            chain_b,
+            timeouts,
+            swaps: BTreeMap::new(),
+            refunds_a: BTreeSet::new(),
+            refunds_b: BTreeSet::new(),
+        })
+    }
+
+    pub fn chain_a(&self) -> &A {
+        &self.chain_a
+    }
+
+    pub fn chain_a_mut(&mut self) -> &mut A {
+        &mut self.chain_a
+    }
+
+    pub fn chain_b(&self) -> &B {
+        &self.chain_b
+    }
+
+    pub fn chain_b_mut(&mut self) -> &mut B {
+        &mut self.chain_b
+    }
+
+    /// Get swap record
+    pub fn get_swap(&self, id: &str) -> Result<HtlcSwap, BridgeError> {
+        self.swaps
+            .get(id)
+            .cloned()
+            .ok_or_else(|| BridgeError::SwapNotFound(id.to_string()))
+    }
+
+    /// Lock leg A for a new swap
+    pub fn initiate(&mut self, swap: AtomicSwap, hash_lock: [u8; 32]) -> Result<HtlcId, BridgeError> {
+        if swap.chain_a != self.chain_a.chain_id() || swap.chain_b != self.chain_b.chain_id() {
+            return Err(BridgeError::ChainMismatch);
+        }
+        if swap.state != SwapState::Initiated || self.swaps.contains_key(&swap.id) {
+            return Err(BridgeError::InvalidSwapTransition {
+                current: swap.state,
+                requested: SwapState::LockedA,
+            });
+        }
+
+        let time_lock = self.chain_a.block_height().saturating_add(blocks_for(
+            self.timeouts.initiator_lock_ms,
+            self.chain_a.block_time_ms(),
+        ));
+        let htlc_id = self.chain_a.create_htlc(
+            &swap.party_a,
+            &swap.party_b,
+            swap.amount_a,
+            hash_lock,
+            time_lock,
+        )?;
+
+        let mut swap = swap;
+        swap.lock_time = time_lock;
+        swap.advance_state(SwapState::LockedA)?;
+
+        self.refunds_a.insert((time_lock, swap.id.clone()));
+        self.swaps.insert(
+            swap.id.clone(),
+            HtlcSwap {
+                swap,
+                hash_lock,
+                secret: None,
+                leg_a: Some(LegLock { htlc_id, time_lock, status: LegStatus::Locked }),
+                leg_b: None,
+            },
+        );
+
+        Ok(htlc_id)
+    }
+
+    /// Lock leg B after checking leg A on chain A
+    pub fn participate(&mut self, id: &str) -> Result<HtlcId, BridgeError> {
+        let record = self.get_swap(id)?;
+        if record.swap.state != SwapState::LockedA {
+            return Err(BridgeError::InvalidSwapTransition {
+                current: record.swap.state,
+                requested: SwapState::LockedB,
+            });
+        }
+        let leg_a = record.leg_a.as_ref().ok_or(BridgeError::HtlcNotFound)?;
+
+        // Leg A must exist as promised, not just in our records
+        let on_chain = self.chain_a.htlc(&leg_a.htlc_id).ok_or(BridgeError::HtlcNotFound)?;
+        if on_chain.hash_lock != record.hash_lock
+            || on_chain.amount != record.swap.amount_a
+            || on_chain.receiver != record.swap.party_b
+            || on_chain.claimed
+            || on_chain.refunded
+        {
+            return Err(BridgeError::InvalidProof);
+        }
+
+        // Leg A must outlive leg B by the safety margin from now
+        let remaining_a_ms = on_chain
+            .time_lock
+            .saturating_sub(self.chain_a.block_height())
+            .saturating_mul(self.chain_a.block_time_ms());
+        let needed_ms = self
+            .timeouts
+            .participant_lock_ms
+            .saturating_add(self.timeouts.safety_margin_ms);
+        if remaining_a_ms < needed_ms {
+            return Err(BridgeError::TimeLockTooShort);
+        }
+
+        let time_lock = self.chain_b.block_height().saturating_add(blocks_for(
+            self.timeouts.participant_lock_ms,
+            self.chain_b.block_time_ms(),
+        ));
+        let htlc_id = self.chain_b.create_htlc(
+            &record.swap.party_b,
+            &record.swap.party_a,
+            record.swap.amount_b,
+            record.hash_lock,
+            time_lock,
+        )?;
+
+        let record = self.swaps.get_mut(id).ok_or_else(|| BridgeError::SwapNotFound(id.to_string()))?;
+        record.swap.advance_state(SwapState::LockedB)?;
+        record.leg_b = Some(LegLock { htlc_id, time_lock, status: LegStatus::Locked });
+        self.refunds_b.insert((time_lock, id.to_string()));
+
+        Ok(htlc_id)
+    }
+
+    /// Claim leg B for `party_a`, revealing the secret, then claim leg A for `party_b`
+    pub fn redeem(&mut self, id: &str, secret: [u8; 32]) -> Result<(), BridgeError> {
+        let record = self.get_swap(id)?;
+        if hash_secret(&secret) != record.hash_lock {
+            return Err(BridgeError::InvalidSecret);
+        }
+        if record.swap.state != SwapState::LockedB {
+            return Err(BridgeError::InvalidSwapTransition {
+                current: record.swap.state,
+                requested: SwapState::Complete,
+            });
+        }
+        let leg_b = record.leg_b.ok_or(BridgeError::HtlcNotFound)?;
+
+        // Revealing after leg B expires would let party_b refund B and still claim A
+        if self.chain_b.block_height() >= leg_b.time_lock {
+            return Err(BridgeError::TransferExpired);
+        }
+
+        self.chain_b.claim_htlc(&record.swap.party_a, &leg_b.htlc_id, secret)?;
+
+        if let Some(record) = self.swaps.get_mut(id) {
+            record.secret = Some(secret);
+            if let Some(leg) = record.leg_b.as_mut() {
+                leg.status = LegStatus::Claimed;
+            }
+        }
+        self.refunds_b.remove(&(leg_b.time_lock, id.to_string()));
+
+        self.propagate_secret(id)
+    }
+
+    /// Advance all swaps: propagate revealed secrets, then refund expired legs
+    pub fn tick(&mut self) -> Vec<SwapEvent> {
+        let mut events = Vec::new();
+
+        // 1. Secrets revealed on chain B (by us or directly by party_a)
+        let waiting: Vec<String> = self
+            .swaps
+            .iter()
+            .filter(|(_, r)| r.swap.state == SwapState::LockedB)
+            .map(|(id, _)| id.clone())
+            .collect();
+        for id in waiting {
+            if !self.observe_leg_b_claim(&id) {
+                continue;
+            }
+            match self.propagate_secret(&id) {
+                Ok(()) => events.push(SwapEvent::SecretPropagated { swap_id: id }),
+                Err(error) => events.push(SwapEvent::ActionFailed { swap_id: id, leg: SwapLeg::A, error }),
+            }
+        }
+
+        // 2. Expired legs, B first so party_b is never exposed past leg A's lock
+        let height_b = self.chain_b.block_height();
+        let due_b: Vec<(u64, String)> = self
+            .refunds_b
+            .iter()
+            .take_while(|(height, _)| *height <= height_b)
+            .cloned()
+            .collect();
+        for entry in due_b {
+            self.refund_leg(&entry.1, SwapLeg::B, &mut events);
+            if self.leg_settled(&entry.1, SwapLeg::B) {
+                self.refunds_b.remove(&entry);
+            }
+        }
+
+        let height_a = self.chain_a.block_height();
+        let due_a: Vec<(u64, String)> = self
+            .refunds_a
+            .iter()
+            .take_while(|(height, _)| *height <= height_a)
+            .cloned()
+            .collect();
+        for entry in due_a {
+            self.refund_leg(&entry.1, SwapLeg::A, &mut events);
+            if self.leg_settled(&entry.1, SwapLeg::A) {
+                self.refunds_a.remove(&entry);
+            }
+        }
+
+        events
+    }
+
+    /// Get active swaps count
+    pub fn active_swaps_count(&self) -> usize {
+        self.swaps
+            .values()
+            .filter(|r| r.swap.state != SwapState::Complete && r.swap.state != SwapState::Refunded)
+            .count()
+    }
+
+    /// Record a leg B claim seen on chain B; returns true if leg A still needs claiming
+    fn observe_leg_b_claim(&mut self, id: &str) -> bool {
+        let Some(record) = self.swaps.get(id) else {
+            return false;
+        };
+        let Some(leg_b) = record.leg_b.clone() else {
+            return false;
+        };
+
+        if record.secret.is_none() {
+            let revealed = self
+                .chain_b
+                .htlc(&leg_b.htlc_id)
+                .filter(|info| info.claimed)
+                .and_then(|info| info.secret)
+                .filter(|secret| hash_secret(secret) == record.hash_lock);
+            let Some(secret) = revealed else {
+                return false;
+            };
+
+            if let Some(record) = self.swaps.get_mut(id) {
+                record.secret = Some(secret);
+                if let Some(leg) = record.leg_b.as_mut() {
+                    leg.status = LegStatus::Claimed;
+                }
+            }
+            self.refunds_b.remove(&(leg_b.time_lock, id.to_string()));
+        }
+
+        true
+    }
+
+    /// Claim leg A for `party_b` with the known secret
+    fn propagate_secret(&mut self, id: &str) -> Result<(), BridgeError> {
+        let record = self.get_swap(id)?;
+        let secret = record.secret.ok_or(BridgeError::InvalidSecret)?;
+        let leg_a = record.leg_a.ok_or(BridgeError::HtlcNotFound)?;
+        if leg_a.status != LegStatus::Locked {
+            return Ok(());
+        }
+
+        self.chain_a.claim_htlc(&record.swap.party_b, &leg_a.htlc_id, secret)?;
+
+        if let Some(record) = self.swaps.get_mut(id) {
+            if let Some(leg) = record.leg_a.as_mut() {
+                leg.status = LegStatus::Claimed;
+            }
+            record.swap.advance_state(SwapState::Complete)?;
+        }
+        self.refunds_a.remove(&(leg_a.time_lock, id.to_string()));
+
+        Ok(())
+    }
+
+    /// Refund one expired leg to its sender
+    fn refund_leg(&mut self, id: &str, leg: SwapLeg, events: &mut Vec<SwapEvent>) {
+        let Some(record) = self.swaps.get(id).cloned() else {
+            return;
+        };
+        let (lock, sender) = match leg {
+            SwapLeg::A => (record.leg_a, &record.swap.party_a),
+            SwapLeg::B => (record.leg_b, &record.swap.party_b),
+        };
+        let Some(lock) = lock.filter(|l| l.status == LegStatus::Locked) else {
+            return;
+        };
+
+        let result = match leg {
+            SwapLeg::A => self.chain_a.refund_htlc(sender, &lock.htlc_id),
+            SwapLeg::B => self.chain_b.refund_htlc(sender, &lock.htlc_id),
+        };
+        if let Err(error) = result {
+            events.push(SwapEvent::ActionFailed { swap_id: id.to_string(), leg, error });
+            return;
+        }
+
+        if let Some(record) = self.swaps.get_mut(id) {
+            let slot = match leg {
+                SwapLeg::A => record.leg_a.as_mut(),
+                SwapLeg::B => record.leg_b.as_mut(),
+            };
+            if let Some(slot) = slot {
+                slot.status = LegStatus::Refunded;
+            }
+            // The swap is over once the initiator's leg is refunded
+            if leg == SwapLeg::A {
+                let _ = record.swap.advance_state(SwapState::Refunded);
+            }
+        }
+        events.push(SwapEvent::Refunded { swap_id: id.to_string(), leg });
+    }
+
+    fn leg_settled(&self, id: &str, leg: SwapLeg) -> bool {
+        let Some(record) = self.swaps.get(id) else {
+            return true;
+        };
+        let lock = match leg {
+            SwapLeg::A => record.leg_a.as_ref(),
+            SwapLeg::B => record.leg_b.as_ref(),
+        };
+        !lock.is_some_and(|l| l.status == LegStatus::Locked)
+    }
+}
+
+/// In-memory chain for deterministic swap simulation
+///
+/// Applies the same rules as the transaction types pallet, with balances.
+#[cfg(any(test, feature = "test-utils"))]
+#[derive(Debug, Clone)]
+pub struct SimulatedChain {
+    chain_id: ChainId,
+    block_time_ms: u64,
+    height: u64,
+    next_id: u64,
+    balances: BTreeMap<String, u128>,
+    htlcs: BTreeMap<HtlcId, HtlcInfo>,
+}
+
+#[cfg(any(test, feature = "test-utils"))]
+impl SimulatedChain {
+    pub fn new(chain_id: ChainId, block_time_ms: u64) -> Self {
+        Self {
+            chain_id,
+            block_time_ms,
+            height: 0,
+            next_id: 0,
+            balances: BTreeMap::new(),
+            htlcs: BTreeMap::new(),
+        }
+    }
+
+    /// Credit an account
+    pub fn mint(&mut self, account: &str, amount: u128) {
+        let balance = self.balances.entry(account.to_string()).or_insert(0);
+        *balance = balance.saturating_add(amount);
+    }
+
+    pub fn balance_of(&self, account: &str) -> u128 {
+        self.balances.get(account).copied().unwrap_or(0)
+    }
+
+    /// Produce `blocks` blocks
+    pub fn advance(&mut self, blocks: u64) {
+        self.height = self.height.saturating_add(blocks);
+    }
+}
+
+#[cfg(any(test, feature = "test-utils"))]
+impl HtlcChain for SimulatedChain {
+    fn chain_id(&self) -> ChainId {
+        self.chain_id
+    }
+
+    fn block_height(&self) -> u64 {
+        self.height
+    }
+
+    fn block_time_ms(&self) -> u64 {
+        self.block_time_ms
+    }
+
+    fn create_htlc(
+        &mut self,
+        sender: &str,
+        receiver: &str,
+        amount: u128,
+        hash_lock: [u8; 32],
+        time_lock: u64,
+    ) -> Result<HtlcId, BridgeError> {
+        if amount == 0 || self.balance_of(sender) < amount {
+            return Err(BridgeError::InvalidAmount);
+        }
+        if time_lock <= self.height {
+            return Err(BridgeError::InvalidExpiration);
+        }
+
+        let mut hasher = Sha256::new();
+        hasher.update(sender.as_bytes());
+        hasher.update(receiver.as_bytes());
+        hasher.update(hash_lock);
+        hasher.update(self.next_id.to_le_bytes());
+        let id: HtlcId = hasher.finalize().into();
+        self.next_id += 1;
+
+        self.balances.insert(sender.to_string(), self.balance_of(sender) - amount);
+        self.htlcs.insert(
+            id,
+            HtlcInfo {
+                sender: sender.to_string(),
+                receiver: receiver.to_string(),
+                amount,
+                hash_lock,
+                time_lock,
+                claimed: false,
+                refunded: false,
+                secret: None,
+            },
+        );
+
+        Ok(id)
+    }
+
+    fn claim_htlc(&mut self, claimer: &str, id: &HtlcId, secret: [u8; 32]) -> Result<(), BridgeError> {
+        let htlc = self.htlcs.get_mut(id).ok_or(BridgeError::HtlcNotFound)?;
+        if claimer != htlc.receiver {
+            return Err(BridgeError::InvalidAddress);
+        }
+        if htlc.claimed || htlc.refunded {
+            return Err(BridgeError::HtlcSettled);
+        }
+        if hash_secret(&secret) != htlc.hash_lock {
+            return Err(BridgeError::InvalidSecret);
+        }
+
+        htlc.claimed = true;
+        htlc.secret = Some(secret);
+        let (receiver, amount) = (htlc.receiver.clone(), htlc.amount);
+        self.mint(&receiver, amount);
+        Ok(())
+    }
+
+    fn refund_htlc(&mut self, refunder: &str, id: &HtlcId) -> Result<(), BridgeError> {
+        let height = self.height;
+        let htlc = self.htlcs.get_mut(id).ok_or(BridgeError::HtlcNotFound)?;
+        if refunder != htlc.sender {
+            return Err(BridgeError::InvalidAddress);
+        }
+        if htlc.claimed || htlc.refunded {
+            return Err(BridgeError::HtlcSettled);
+        }
+        if height < htlc.time_lock {
+            return Err(BridgeError::TimeLockNotExpired);
+        }
+
+        htlc.refunded = true;
+        let (sender, amount) = (htlc.sender.clone(), htlc.amount);
+        self.mint(&sender, amount);
+        Ok(())
+    }
+
+    fn htlc(&self, id: &HtlcId) -> Option<HtlcInfo> {
+        self.htlcs.get(id).cloned()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::AssetId;
+
+    const SECRET: [u8; 32] = [7u8; 32];
+
+    /// ÉTRID with 6s blocks, PBC with 2s blocks
+    fn engine() -> HtlcSwapEngine<SimulatedChain, SimulatedChain> {
+        let mut etrid = SimulatedChain::new(ChainId::Etrid, 6_000);
+        let mut pbc = SimulatedChain::new(ChainId::Pbc(3), 2_000);
+        etrid.mint("alice", 1_000);
+        pbc.mint("bob", 5_000);
+
+        let timeouts = SwapTimeouts {
+            initiator_lock_ms: 600_000,
+            participant_lock_ms: 300_000,
+            safety_margin_ms: 120_000,
+        };
+        HtlcSwapEngine::new(etrid, pbc, timeouts).unwrap()
+    }
+
+    fn swap(id: &str) -> AtomicSwap {
+        AtomicSwap::new(
+            id.to_string(),
+            ChainId::Etrid,
+            ChainId::Pbc(3),
+            AssetId::etrid_native(),
+            AssetId::new(ChainId::Pbc(3), "NATIVE".to_string()),
+            1_000,
+            5_000,
+            "alice".to_string(),
+            "bob".to_string(),
+            0,
+        )
+        .unwrap()
+    }
+
+    fn locked(id: &str) -> HtlcSwapEngine<SimulatedChain, SimulatedChain> {
+        let mut engine = engine();
+        engine.initiate(swap(id), hash_secret(&SECRET)).unwrap();
+        engine.participate(id).unwrap();
+        engine
+    }
+
+    #[test]
+    fn test_timeouts_must_be_asymmetric() {
+        let timeouts = SwapTimeouts {
+            initiator_lock_ms: 300_000,
+            participant_lock_ms: 300_000,
+            safety_margin_ms: 1,
+        };
+        assert_eq!(timeouts.validate(), Err(BridgeError::InvalidExpiration));
+        assert!(SwapTimeouts::default().validate().is_ok());
+    }
+
+    #[test]
+    fn test_time_locks_use_each_chain_height() {
+        let engine = locked("s1");
+        let record = engine.get_swap("s1").unwrap();
+        // 600s / 6s on ÉTRID, 300s / 2s on the PBC
+        assert_eq!(record.leg_a.unwrap().time_lock, 100);
+        assert_eq!(record.leg_b.unwrap().time_lock, 150);
+        assert_eq!(record.swap.lock_time, 100);
+    }
+
+    #[test]
+    fn test_happy_path_propagates_secret() {
+        let mut engine = locked("s1");
+        engine.redeem("s1", SECRET).unwrap();
+
+        let record = engine.get_swap("s1").unwrap();
+        assert_eq!(record.swap.state, SwapState::Complete);
+        assert_eq!(record.secret, Some(SECRET));
+        assert_eq!(engine.chain_a().balance_of("bob"), 1_000);
+        assert_eq!(engine.chain_b().balance_of("alice"), 5_000);
+        assert_eq!(engine.active_swaps_count(), 0);
+
+        // Nothing left to refund
+        engine.chain_a_mut().advance(1_000);
+        engine.chain_b_mut().advance(1_000);
+        assert!(engine.tick().is_empty());
+    }
+
+    #[test]
+    fn test_secret_revealed_outside_engine_is_propagated() {
+        let mut engine = locked("s1");
+        let leg_b = engine.get_swap("s1").unwrap().leg_b.unwrap();
+        engine.chain_b_mut().claim_htlc("alice", &leg_b.htlc_id, SECRET).unwrap();
+
+        let events = engine.tick();
+        assert_eq!(events, vec![SwapEvent::SecretPropagated { swap_id: "s1".to_string() }]);
+        assert_eq!(engine.get_swap("s1").unwrap().swap.state, SwapState::Complete);
+        assert_eq!(engine.chain_a().balance_of("bob"), 1_000);
+    }
+
+    #[test]
+    fn test_wrong_secret_rejected() {
+        let mut engine = locked("s1");
+        assert_eq!(engine.redeem("s1", [8u8; 32]), Err(BridgeError::InvalidSecret));
+        assert_eq!(engine.get_swap("s1").unwrap().swap.state, SwapState::LockedB);
+    }
+
+    #[test]
+    fn test_unanswered_swap_refunds_initiator() {
+        let mut engine = engine();
+        engine.initiate(swap("s1"), hash_secret(&SECRET)).unwrap();
+
+        engine.chain_a_mut().advance(99);
+        assert!(engine.tick().is_empty());
+
+        engine.chain_a_mut().advance(1);
+        let events = engine.tick();
+        assert_eq!(events, vec![SwapEvent::Refunded { swap_id: "s1".to_string(), leg: SwapLeg::A }]);
+        assert_eq!(engine.get_swap("s1").unwrap().swap.state, SwapState::Refunded);
+        assert_eq!(engine.chain_a().balance_of("alice"), 1_000);
+    }
+
+    #[test]
+    fn test_unredeemed_swap_refunds_both_legs_in_order() {
+        let mut engine = locked("s1");
+
+        // Leg B expires first (150 PBC blocks = 300s)
+        engine.chain_a_mut().advance(50);
+        engine.chain_b_mut().advance(150);
+        let events = engine.tick();
+        assert_eq!(events, vec![SwapEvent::Refunded { swap_id: "s1".to_string(), leg: SwapLeg::B }]);
+        assert_eq!(engine.chain_b().balance_of("bob"), 5_000);
+        assert_eq!(engine.get_swap("s1").unwrap().swap.state, SwapState::LockedB);
+
+        // Too late to reveal now
+        assert_eq!(engine.redeem("s1", SECRET), Err(BridgeError::TransferExpired));
+
+        engine.chain_a_mut().advance(50);
+        let events = engine.tick();
+        assert_eq!(events, vec![SwapEvent::Refunded { swap_id: "s1".to_string(), leg: SwapLeg::A }]);
+        assert_eq!(engine.get_swap("s1").unwrap().swap.state, SwapState::Refunded);
+        assert_eq!(engine.chain_a().balance_of("alice"), 1_000);
+    }
+
+    #[test]
+    fn test_participate_rejects_short_remaining_lock() {
+        let mut engine = engine();
+        engine.initiate(swap("s1"), hash_secret(&SECRET)).unwrap();
+
+        // 100 - 31 = 69 blocks * 6s = 414s < 300s + 120s
+        engine.chain_a_mut().advance(31);
+        assert_eq!(engine.participate("s1"), Err(BridgeError::TimeLockTooShort));
+        assert_eq!(engine.chain_b().balance_of("bob"), 5_000);
+    }
+
+    #[test]
+    fn test_participate_checks_leg_a_on_chain() {
+        let mut engine = engine();
+        engine.initiate(swap("s1"), hash_secret(&SECRET)).unwrap();
+
+        // Refunded behind the engine's back
+        let leg_a = engine.get_swap("s1").unwrap().leg_a.unwrap();
+        engine.chain_a_mut().advance(100);
+        engine.chain_a_mut().refund_htlc("alice", &leg_a.htlc_id).unwrap();
+
+        assert_eq!(engine.participate("s1"), Err(BridgeError::InvalidProof));
+    }
+
+    #[test]
+    fn test_initiate_rejects_wrong_chain() {
+        let mut engine = engine();
+        let mut wrong = swap("s1");
+        wrong.chain_b = ChainId::Ethereum;
+        assert_eq!(engine.initiate(wrong, hash_secret(&SECRET)), Err(BridgeError::ChainMismatch));
+    }
+
+    #[test]
+    fn test_blocks_for_rounds_up() {
+        assert_eq!(blocks_for(6_000, 6_000), 1);
+        assert_eq!(blocks_for(6_001, 6_000), 2);
+        assert_eq!(blocks_for(0, 6_000), 0);
+    }
+}
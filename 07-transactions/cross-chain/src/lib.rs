@@ -5,7 +5,7 @@
 //! - Bitcoin bridge (UTXO coordination)
 //! - State proof generation and verification
 //! - Validator-backed attestations
-//! - Atomic swap settlement
+//! - Atomic swap settlement (HTLC engine in [`htlc`])
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
@@ -29,6 +29,8 @@ use core::{
     option::Option::{self, Some, None},
 };
 
+pub mod htlc;
+
 #[cfg(feature = "std")]
 use std::{
     collections::{HashMap, HashSet},
@@ -46,6 +48,8 @@ pub enum ChainId {
     Ethereum,
     Bitcoin,
     Polygon,
+    /// Partition Burst Chain by PBC ID
+    Pbc(u8),
 }
 
 impl fmt::Display for ChainId {
@@ -55,6 +59,7 @@ impl fmt::Display for ChainId {
             ChainId::Ethereum => write!(f, "Ethereum"),
             ChainId::Bitcoin => write!(f, "Bitcoin"),
             ChainId::Polygon => write!(f, "Polygon"),
+            ChainId::Pbc(id) => write!(f, "PBC-{}", id),
         }
     }
 }
@@ -372,6 +377,13 @@ pub enum BridgeError {
     ProofMissing,
     ValidatorNotFound,
     DuplicateSignature,
+    ChainMismatch,
+    HtlcNotFound,
+    HtlcExists,
+    HtlcSettled,
+    InvalidSecret,
+    TimeLockTooShort,
+    TimeLockNotExpired,
 }
 
 impl fmt::Display for BridgeError {
@@ -396,6 +408,13 @@ impl fmt::Display for BridgeError {
             BridgeError::ProofMissing => write!(f, "Proof missing"),
             BridgeError::ValidatorNotFound => write!(f, "Validator not found"),
             BridgeError::DuplicateSignature => write!(f, "Duplicate signature"),
+            BridgeError::ChainMismatch => write!(f, "Chain mismatch"),
+            BridgeError::HtlcNotFound => write!(f, "HTLC not found"),
+            BridgeError::HtlcExists => write!(f, "HTLC already exists"),
+            BridgeError::HtlcSettled => write!(f, "HTLC already claimed or refunded"),
+            BridgeError::InvalidSecret => write!(f, "Secret does not match hash lock"),
+            BridgeError::TimeLockTooShort => write!(f, "Time lock too short"),
+            BridgeError::TimeLockNotExpired => write!(f, "Time lock not expired"),
         }
     }
 }
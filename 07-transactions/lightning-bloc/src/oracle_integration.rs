@@ -4,7 +4,8 @@
 //! - Bridge-specific oracles (EDSC, Chainlink, etc.)
 //! - Static rates for stablecoins
 //! - DEX TWAP feeds
-//! - Aggregated multi-source pricing
+//! - Oracle network price feeds (`pallet-oracle-network`)
+//! - Aggregated multi-source pricing with median + MAD outlier rejection
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
@@ -19,8 +20,17 @@ use alloc::{
 #[cfg(feature = "std")]
 use std::{vec::Vec, string::String, boxed::Box};
 
+use core::marker::PhantomData;
+use etrid_oracle_primitives::{self as oracle_primitives, PriceFeed, Permill};
+
 use crate::cross_pbc_router::{ChainId, ExchangeRate};
 
+/// Width of the outlier band in MADs when combining oracle sources
+const MAD_MULTIPLIER: u32 = 3;
+
+/// Minimum width of the outlier band relative to the median rate (1%)
+const RATE_TOLERANCE: Permill = Permill::from_parts(10_000);
+
 /// Price oracle trait (simplified from bridge-common)
 pub trait LightningPriceOracle {
     /// Get exchange rate between two chains
@@ -58,22 +68,27 @@ impl OracleManager {
         self.oracles.push(oracle);
     }
 
-    /// Get exchange rate (first available from any oracle)
+    /// Get exchange rate (median of all fresh sources after MAD outlier rejection)
+    ///
+    /// The returned timestamp is the oldest among the accepted sources.
     pub fn get_rate(
         &self,
         from: &ChainId,
         to: &ChainId,
         current_time: u64,
     ) -> Option<ExchangeRate> {
-        for oracle in &self.oracles {
-            if let Some(rate) = oracle.get_exchange_rate(from, to) {
-                // Check staleness
-                if !rate.is_stale(self.max_rate_age, current_time) {
-                    return Some(rate);
-                }
-            }
-        }
-        None
+        let rates: Vec<ExchangeRate> = self
+            .oracles
+            .iter()
+            .filter_map(|oracle| oracle.get_exchange_rate(from, to))
+            .filter(|rate| !rate.is_stale(self.max_rate_age, current_time))
+            .collect();
+
+        let values: Vec<u128> = rates.iter().map(|rate| rate.rate as u128).collect();
+        let outcome = oracle_primitives::filter_round(&values, MAD_MULTIPLIER, RATE_TOLERANCE)?;
+
+        let timestamp = outcome.accepted.iter().map(|&i| rates[i].timestamp).min()?;
+        Some(ExchangeRate::new(u64::try_from(outcome.value).ok()?, timestamp))
     }
 
     /// Get all available rates for a chain
@@ -185,6 +200,62 @@ impl LightningPriceOracle for MockOracle {
     }
 }
 
+/// Exchange rates derived from the oracle network's USD price feeds
+///
+/// The rate from one chain to another is the ratio of the USD prices of their native
+/// assets, using only feeds that are still fresh.
+pub struct FeedRateOracle<F> {
+    _feeds: PhantomData<F>,
+}
+
+impl<F: PriceFeed> FeedRateOracle<F> {
+    /// Create a feed-backed oracle
+    pub fn new() -> Self {
+        Self { _feeds: PhantomData }
+    }
+
+    #[cfg(feature = "std")]
+    fn current_time() -> u64 {
+        use std::time::{SystemTime, UNIX_EPOCH};
+        SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .as_secs()
+    }
+
+    #[cfg(not(feature = "std"))]
+    fn current_time() -> u64 {
+        0
+    }
+}
+
+impl<F: PriceFeed> Default for FeedRateOracle<F> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<F: PriceFeed> LightningPriceOracle for FeedRateOracle<F> {
+    fn get_exchange_rate(&self, from: &ChainId, to: &ChainId) -> Option<ExchangeRate> {
+        if from == to {
+            return None;
+        }
+        let from_price = F::fresh(&oracle_primitives::chain_feed(from)?)?;
+        let to_price = F::fresh(&oracle_primitives::chain_feed(to)?)?;
+        Some(ExchangeRate::new(from_price.rate_bps(&to_price)?, Self::current_time()))
+    }
+
+    fn supports_pair(&self, from: &ChainId, to: &ChainId) -> bool {
+        from != to
+            && oracle_primitives::chain_feed(from).is_some()
+            && oracle_primitives::chain_feed(to).is_some()
+    }
+
+    fn name(&self) -> &str {
+        "OracleNetworkFeeds"
+    }
+}
+
 /// Integration helper to connect oracles to Cross-PBC Router
 pub fn setup_oracles_for_router() -> OracleManager {
     let mut manager = OracleManager::new(
@@ -233,6 +304,67 @@ mod tests {
         assert!(rate.is_some());
     }
 
+    /// Fixed oracle returning one rate for every pair
+    struct FixedRateOracle(u64, u64);
+
+    impl LightningPriceOracle for FixedRateOracle {
+        fn get_exchange_rate(&self, _from: &ChainId, _to: &ChainId) -> Option<ExchangeRate> {
+            Some(ExchangeRate::new(self.0, self.1))
+        }
+
+        fn supports_pair(&self, _from: &ChainId, _to: &ChainId) -> bool {
+            true
+        }
+
+        fn name(&self) -> &str {
+            "FixedRateOracle"
+        }
+    }
+
+    #[test]
+    fn test_oracle_manager_rejects_outliers_and_stale_rates() {
+        let mut manager = OracleManager::new(600, 80);
+        manager.add_oracle(Box::new(FixedRateOracle(500, 1_000)));
+        manager.add_oracle(Box::new(FixedRateOracle(502, 1_100)));
+        manager.add_oracle(Box::new(FixedRateOracle(498, 1_200)));
+        manager.add_oracle(Box::new(FixedRateOracle(900, 1_200)));
+        // Stale: older than 600 seconds at t = 1_500
+        manager.add_oracle(Box::new(FixedRateOracle(100, 800)));
+
+        let rate = manager.get_rate(&"eth-pbc".into(), &"btc-pbc".into(), 1_500).unwrap();
+        assert_eq!(rate.rate, 500);
+        assert_eq!(rate.timestamp, 1_000);
+    }
+
+    struct MockFeeds;
+
+    impl PriceFeed for MockFeeds {
+        fn latest(feed: &oracle_primitives::FeedId) -> Option<oracle_primitives::FeedPrice> {
+            let value = match *feed {
+                oracle_primitives::ETH_USD => 3_000_00000000,
+                oracle_primitives::SOL_USD => 150_00000000,
+                _ => return None,
+            };
+            Some(oracle_primitives::FeedPrice { value, decimals: 8, updated_at: 1, round: 1, reporters: 3 })
+        }
+
+        fn fresh(feed: &oracle_primitives::FeedId) -> Option<oracle_primitives::FeedPrice> {
+            Self::latest(feed)
+        }
+    }
+
+    #[test]
+    fn test_feed_rate_oracle() {
+        let oracle = FeedRateOracle::<MockFeeds>::new();
+
+        // 1 ETH = 20 SOL
+        let rate = oracle.get_exchange_rate(&"eth-pbc".into(), &"sol-pbc".into()).unwrap();
+        assert_eq!(rate.rate, 200_000);
+        assert!(oracle.get_exchange_rate(&"eth-pbc".into(), &"btc-pbc".into()).is_none());
+        assert!(oracle.supports_pair(&"eth-pbc".into(), &"btc-pbc".into()));
+        assert!(!oracle.supports_pair(&"eth-pbc".into(), &"eth-pbc".into()));
+    }
+
     #[test]
     fn test_setup_oracles() {
         let manager = setup_oracles_for_router();
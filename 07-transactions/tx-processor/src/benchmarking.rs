@@ -0,0 +1,190 @@
+//! Benchmarking setup for pallet-tx-processor
+//!
+//! Submissions are signed with a fixed ed25519 key whose public key is the sender's
+//! account id, so they pass `validate_transaction`. Runtimes must use 32-byte
+//! account ids for these benchmarks.
+//!
+//! Pool entries inserted directly carry a reserved fee, so removing or executing
+//! them also touches the sender's balance.
+
+#![cfg(feature = "runtime-benchmarks")]
+
+use super::*;
+use crate::pallet::{BalanceOf, MAX_CLEAR_PER_CALL, MAX_FUTURE_NONCE_GAP, MAX_POOL_SIZE};
+use alloc::{vec, vec::Vec};
+use codec::{Decode, Encode, MaxEncodedLen};
+use ed25519_dalek::{Signer, SigningKey};
+use frame_benchmarking::v2::*;
+use frame_support::{
+    assert_ok,
+    sp_runtime::{traits::{Saturating, TrailingZeroInput}, SaturatedConversion},
+    traits::{Currency, ReservableCurrency},
+};
+use frame_system::RawOrigin;
+use pallet_transaction::{Signature, SignedTransaction, TransactionType};
+
+const SEED: u32 = 0;
+
+/// Fee declared by fee-paying benchmark transactions
+const FEE: u128 = 1_000_000_000;
+
+/// Largest `ContractInit` code accepted by `pallet-transaction`
+const MAX_CODE_LEN: u32 = 524_288;
+
+fn contract_init(len: u32) -> TransactionType {
+    TransactionType::ContractInit {
+        init_code: vec![1u8; len as usize].try_into().expect("len is bounded; qed"),
+        vmw_limit: 1_000_000,
+        value: 0,
+    }
+}
+
+fn lightning_bloc(fee: u128) -> TransactionType {
+    TransactionType::LightningBloc {
+        target_chain: 2,
+        recipient: vec![2u8; 32].try_into().expect("32 bytes; qed"),
+        amount: 1_000,
+        fee,
+    }
+}
+
+/// Give `who` enough free balance to reserve `FEE` a hundred times over
+fn fund<T: Config>(who: &T::AccountId) {
+    let fee: BalanceOf<T> = FEE.saturated_into();
+    let amount = T::Currency::minimum_balance().saturating_add(fee.saturating_mul(100u32.into()));
+    T::Currency::make_free_balance_be(who, amount);
+}
+
+/// Reserve `FEE` for a pool entry as if it had declared it
+fn reserve_fee<T: Config>(sender: &T::AccountId, nonce: u64) {
+    let fee: BalanceOf<T> = FEE.saturated_into();
+    assert_ok!(T::Currency::reserve(sender, fee));
+    ReservedFees::<T>::insert(sender, nonce, fee);
+}
+
+/// Account of the benchmark signing key
+fn signer<T: Config>() -> T::AccountId {
+    let key = SigningKey::from_bytes(&[7u8; 32]);
+    T::AccountId::decode(&mut TrailingZeroInput::new(key.verifying_key().as_bytes()))
+        .expect("infinite input; qed")
+}
+
+/// Transaction signed by the benchmark key, whose account id is the public key
+fn signed_tx<T: Config>(nonce: u64, tx_type: TransactionType) -> SignedTransaction<T::AccountId> {
+    let key = SigningKey::from_bytes(&[7u8; 32]);
+    let sender = signer::<T>();
+    let chain_id: u32 = 1;
+
+    let mut message = Vec::new();
+    message.extend_from_slice(&sender.encode());
+    message.extend_from_slice(&nonce.to_le_bytes());
+    message.extend_from_slice(&tx_type.encode());
+    message.extend_from_slice(&chain_id.to_le_bytes());
+    let signature = key.sign(&message).to_bytes().to_vec();
+
+    SignedTransaction {
+        sender,
+        nonce,
+        tx_type,
+        signature: Signature(signature.try_into().expect("64 bytes; qed")),
+        chain_id,
+    }
+}
+
+/// Unsigned pool entry; `insert_transaction` does not validate
+fn pool_tx<T: Config>(sender: T::AccountId, nonce: u64, len: u32) -> SignedTransaction<T::AccountId> {
+    SignedTransaction {
+        sender,
+        nonce,
+        tx_type: contract_init(len),
+        signature: Signature(vec![0u8; 64].try_into().expect("64 bytes; qed")),
+        chain_id: 1,
+    }
+}
+
+#[benchmarks]
+mod benchmarks {
+    use super::*;
+
+    #[benchmark]
+    fn submit_transaction(s: Linear<0, MAX_CODE_LEN>) {
+        let caller: T::AccountId = whitelisted_caller();
+        let tx = signed_tx::<T>(0, contract_init(s));
+        let sender = tx.sender.clone();
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller), tx);
+
+        assert!(PendingTransactions::<T>::contains_key(&sender, 0));
+    }
+
+    #[benchmark]
+    fn submit_transaction_evicting() {
+        let caller: T::AccountId = whitelisted_caller();
+
+        // Full pool whose lowest-priority entry is outbid by a fee-paying transaction;
+        // the victim has every later nonce parked, which is evicted with it
+        let victim: T::AccountId = account("victim", 0, SEED);
+        fund::<T>(&victim);
+        for nonce in 0..=MAX_FUTURE_NONCE_GAP {
+            assert_ok!(Pallet::<T>::insert_transaction(pool_tx::<T>(victim.clone(), nonce, 0)));
+            reserve_fee::<T>(&victim, nonce);
+        }
+        PoolStats::<T>::mutate(|stats| stats.current_pool_size = MAX_POOL_SIZE as u32);
+        fund::<T>(&signer::<T>());
+        let tx = signed_tx::<T>(0, lightning_bloc(FEE));
+
+        #[extrinsic_call]
+        submit_transaction(RawOrigin::Signed(caller), tx);
+
+        assert!(!PendingTransactions::<T>::contains_key(&victim, 0));
+        assert!(!SenderPoolCount::<T>::contains_key(&victim));
+    }
+
+    #[benchmark]
+    fn clear_mempool(n: Linear<0, MAX_CLEAR_PER_CALL>) {
+        for i in 0..n {
+            let sender: T::AccountId = account("sender", i, SEED);
+            fund::<T>(&sender);
+            assert_ok!(Pallet::<T>::insert_transaction(pool_tx::<T>(sender.clone(), 0, 0)));
+            reserve_fee::<T>(&sender, 0);
+        }
+
+        #[extrinsic_call]
+        _(RawOrigin::Root);
+
+        assert_eq!(PoolStats::<T>::get().current_pool_size, 0);
+    }
+
+    #[benchmark]
+    fn query_pool_size() {
+        let caller: T::AccountId = whitelisted_caller();
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller));
+    }
+
+    #[benchmark]
+    fn process_transaction(s: Linear<0, MAX_CODE_LEN>) {
+        // A ready transaction whose execution promotes the sender's parked next nonce
+        let sender: T::AccountId = account("sender", 0, SEED);
+        fund::<T>(&sender);
+        assert_ok!(Pallet::<T>::insert_transaction(pool_tx::<T>(sender.clone(), 0, s)));
+        reserve_fee::<T>(&sender, 0);
+        assert_ok!(Pallet::<T>::insert_transaction(pool_tx::<T>(sender.clone(), 1, s)));
+
+        // Just enough for one transaction
+        let max_len = SignedTransaction::<T::AccountId>::max_encoded_len() as u32;
+        let limit = T::WeightInfo::process_transaction(max_len);
+        let block = frame_system::Pallet::<T>::block_number();
+
+        #[block]
+        {
+            Pallet::<T>::process_pending_transactions(block, limit);
+        }
+
+        assert_eq!(NextNonce::<T>::get(&sender), 1);
+        assert!(!ReservedFees::<T>::contains_key(&sender, 0));
+        assert!(PendingTransactions::<T>::contains_key(&sender, 1));
+    }
+}
@@ -11,8 +11,16 @@
 //!
 //! - Transaction mempool with configurable size limits (10,000 transactions)
 //! - Ed25519 signature verification for transaction authenticity
-//! - Nonce-based replay protection
-//! - Automatic transaction processing at block finalization
+//! - Nonce-based replay protection with per-sender nonce-ordered queues
+//! - Pluggable priority (`Config::Priority`), fee-per-weight by default
+//! - Replace-by-fee for same-nonce transactions (10% priority bump)
+//! - Future-nonce parking (up to 16 nonces ahead of the account)
+//! - Per-account limits (64 pending) and lowest-priority eviction when full; evicting
+//!   a transaction also drops the sender's later nonces, which could never execute
+//! - Declared fees reserved on submission, charged on execution and released when a
+//!   transaction leaves the pool unexecuted
+//! - Automatic transaction processing in `on_idle`, highest priority first, as far as
+//!   the block's remaining weight allows
 //! - Transaction batching (up to 1,000 transactions per block)
 //! - Pool statistics tracking (submitted, processed, failed counts)
 //! - Transaction history with block height recording
@@ -21,7 +29,7 @@
 //! ## Extrinsics
 //!
 //! - `submit_transaction` - Submit signed transaction to mempool
-//! - `clear_mempool` - Clear all pending transactions (governance only)
+//! - `clear_mempool` - Clear up to 500 pending transactions (governance only)
 //! - `query_pool_size` - Query current mempool size
 //!
 //! ## Usage Example
@@ -48,7 +56,11 @@
 //!
 //! ## Storage Items
 //!
-//! - `TransactionPool` - Pending transaction mempool (max 10,000 transactions)
+//! - `PendingTransactions` - Pending transactions keyed by (sender, nonce)
+//! - `ReadyIndex` - Priority-ordered index of each sender's next executable transaction
+//! - `EvictionIndex` - Priority-ordered index of all pending transactions (lowest first)
+//! - `SenderPoolCount` - Pending transactions per sender
+//! - `ReservedFees` - Fee reserved from the sender for each pending transaction
 //! - `ProcessedTransactions` - Recently processed transactions (max 1,000)
 //! - `NextNonce` - Maps account to expected next nonce
 //! - `TxBlockHeight` - Maps transaction hash to block number
@@ -57,6 +69,8 @@
 //! ## Events
 //!
 //! - `TransactionReceived` - When transaction is accepted into mempool
+//! - `TransactionReplaced` - When a same-nonce transaction replaces a pending one
+//! - `TransactionEvicted` - When a low-priority transaction is dropped for a better one
 //! - `TransactionProcessed` - When transaction is included in block
 //! - `TransactionFailed` - When transaction validation or execution fails
 //! - `MempoolCleared` - When mempool is administratively cleared
@@ -65,18 +79,24 @@
 //!
 //! - `InvalidSignature` - Ed25519 signature verification failed
 //! - `InvalidNonce` - Nonce does not match expected value
-//! - `InsufficientFunds` - Account lacks balance for transaction
+//! - `InsufficientFunds` - Account cannot reserve the transaction's declared fee
 //! - `PoolFull` - Mempool has reached maximum capacity
 //! - `InvalidChainId` - Chain ID does not match expected value
 //! - `DuplicateTransaction` - Transaction already exists in pool
 //! - `TransactionTooLarge` - Transaction size exceeds limit (1MB)
+//! - `NonceTooFarAhead` - Nonce is more than 16 ahead of the account nonce
+//! - `AccountLimitReached` - Sender already has 64 pending transactions
+//! - `ReplacementUnderpriced` - Replacement does not bump priority by 10%
+//! - `PriorityTooLow` - Pool is full and the transaction would be evicted first
 //!
 //! ## Transaction Processing Pipeline
 //!
 //! 1. **Submission**: User submits signed transaction via extrinsic
 //! 2. **Validation**: Signature, nonce, and basic checks performed
-//! 3. **Mempool**: Valid transactions added to pending pool
-//! 4. **Selection**: At block finalization, top transactions selected (by priority)
+//! 3. **Mempool**: Valid transactions added to the sender's queue; the next-nonce
+//!    transaction of each sender is indexed as ready, later nonces are parked
+//! 4. **Selection**: In `on_idle`, top ready transactions selected (by priority);
+//!    executing a sender's head promotes its next nonce into the ready index
 //! 5. **Execution**: Selected transactions processed and state updated
 //! 6. **Recording**: Block height and receipt stored for executed transactions
 //! 7. **Cleanup**: Processed transactions removed from mempool
@@ -86,27 +106,108 @@
 //! - **Max Mempool Size**: 10,000 transactions
 //! - **Max Transactions per Block**: 1,000 transactions
 //! - **Max Transaction Size**: 1 MB
+//! - **Max Transactions Cleared per Call**: 500
+//!
+//! ## Storage Layout
+//!
+//! Priority indexes use the `Identity` hasher over big-endian `(priority, sequence)`
+//! keys, so trie key order equals priority order. Insert, remove and "best"/"worst"
+//! lookups are single trie operations (O(log n)); no write touches the whole pool.
 #![cfg_attr(not(feature = "std"), no_std)]
 
 extern crate alloc;
 
 pub use pallet::*;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
+#[cfg(test)]
+mod mock;
+
+#[cfg(test)]
+mod tests;
+
+pub mod weights;
+pub use weights::WeightInfo;
+
 #[frame_support::pallet]
 pub mod pallet {
 use frame_support::pallet_prelude::*;
+use frame_support::sp_runtime::{traits::Zero, SaturatedConversion};
+use frame_support::traits::{Currency, OnUnbalanced, ReservableCurrency};
 use frame_system::pallet_prelude::*;
 use pallet_transaction::SignedTransaction;
 use codec::{Encode, Decode};
 use scale_info::TypeInfo;
 use alloc::vec::Vec;
 use ed25519_dalek::{Signature, Verifier, VerifyingKey};
+use crate::WeightInfo;
 type AccountOf<T> = <T as frame_system::Config>::AccountId;
 type BlockNumberOf<T> = BlockNumberFor<T>;
 type SignedTx<T> = SignedTransaction<AccountOf<T>>;
+pub type BalanceOf<T> = <<T as Config>::Currency as Currency<AccountOf<T>>>::Balance;
+pub type NegativeImbalanceOf<T> =
+    <<T as Config>::Currency as Currency<AccountOf<T>>>::NegativeImbalance;
+
+const STORAGE_VERSION: StorageVersion = StorageVersion::new(2);
+pub(crate) const MAX_POOL_SIZE: usize = 10_000;
+pub(crate) const MAX_TX_PER_BLOCK: usize = 1_000;
+/// Maximum transactions removed by one `clear_mempool` call
+pub(crate) const MAX_CLEAR_PER_CALL: u32 = 500;
+/// Maximum pending transactions per sender
+///
+/// Submissions are held to `MAX_FUTURE_NONCE_GAP + 1` pending nonces first; this
+/// bounds pools carried over by the v2 migration.
+const MAX_TX_PER_ACCOUNT: u32 = 64;
+/// Maximum distance between a parked nonce and the account's next nonce
+pub(crate) const MAX_FUTURE_NONCE_GAP: u64 = 16;
+/// Minimum priority increase (percent) for replace-by-fee
+const REPLACE_BUMP_PERCENT: u64 = 10;
+
+/// Priority index key: big-endian (priority, sequence)
+pub type IndexKey = [u8; 16];
+
+/// Provides the fee and priority of a transaction (higher is better)
+///
+/// Runtimes can plug in any policy; `FeePerWeight` is the default. The fee is
+/// reserved from the sender while the transaction is pending and charged when it
+/// executes, so a policy should only rank by fees it reports here.
+pub trait TransactionPriority<AccountId: MaxEncodedLen> {
+    fn fee(tx: &SignedTransaction<AccountId>) -> u128;
+    fn priority(tx: &SignedTransaction<AccountId>) -> u64;
+}
 
-const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
-const MAX_POOL_SIZE: usize = 10_000;
-const MAX_TX_PER_BLOCK: usize = 1_000;
+/// Fee offered per unit of weight (VMw limit + encoded length)
+///
+/// Only Lightning Bloc transactions carry an explicit fee today; everything
+/// else pays nothing, gets priority 0 and is ordered by arrival.
+pub struct FeePerWeight;
+
+impl<AccountId: MaxEncodedLen + Encode> TransactionPriority<AccountId> for FeePerWeight {
+    fn fee(tx: &SignedTransaction<AccountId>) -> u128 {
+        match &tx.tx_type {
+            pallet_transaction::TransactionType::LightningBloc { fee, .. } => *fee,
+            _ => 0,
+        }
+    }
+
+    fn priority(tx: &SignedTransaction<AccountId>) -> u64 {
+        use pallet_transaction::TransactionType;
+
+        let fee = Self::fee(tx);
+        let vmw = match &tx.tx_type {
+            TransactionType::SmartContractCall { vmw_limit, .. }
+            | TransactionType::ContractInit { vmw_limit, .. } => *vmw_limit,
+            _ => 0,
+        };
+        let weight = vmw.saturating_add(tx.encoded_size() as u128).max(1);
+
+        // Scale before dividing so small fees still rank
+        let scaled = fee.saturating_mul(1_000_000) / weight;
+        scaled.min(u64::MAX as u128) as u64
+    }
+}
 
 #[pallet::pallet]
 #[pallet::storage_version(STORAGE_VERSION)]
@@ -116,20 +217,84 @@ pub struct Pallet<T>(_);
 #[pallet::config]
 pub trait Config: frame_system::Config {
     type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+    /// Currency the declared fees are reserved in
+    type Currency: ReservableCurrency<AccountOf<Self>>;
+
+    /// Receives the fees of executed transactions
+    type OnFeeCharged: OnUnbalanced<NegativeImbalanceOf<Self>>;
+
+    /// Transaction priority policy
+    type Priority: TransactionPriority<AccountOf<Self>>;
+
+    /// Weight information for extrinsics and transaction processing
+    type WeightInfo: WeightInfo;
 }
 
 // ============================================================
 // STORAGE
 // ============================================================
 
-/// Transaction mempool (pending transactions)
+/// Pending transactions by (sender, nonce), both ready and parked
 #[pallet::storage]
-pub type TransactionPool<T: Config> = StorageValue<
+pub type PendingTransactions<T: Config> = StorageDoubleMap<
     _,
-    BoundedVec<SignedTx<T>, ConstU32<10000>>,
+    Blake2_128Concat,
+    AccountOf<T>,
+    Twox64Concat,
+    u64,
+    PoolEntry<T>,
+    OptionQuery,
+>;
+
+/// Ready transactions (each sender's next nonce), highest priority first
+///
+/// Keys store the inverted priority so ascending key order is descending priority.
+#[pallet::storage]
+pub type ReadyIndex<T: Config> = StorageMap<
+    _,
+    Identity,
+    IndexKey,
+    (AccountOf<T>, u64),
+    OptionQuery,
+>;
+
+/// All pending transactions, lowest priority first (eviction order)
+#[pallet::storage]
+pub type EvictionIndex<T: Config> = StorageMap<
+    _,
+    Identity,
+    IndexKey,
+    (AccountOf<T>, u64),
+    OptionQuery,
+>;
+
+/// Number of pending transactions per sender
+#[pallet::storage]
+pub type SenderPoolCount<T: Config> = StorageMap<
+    _,
+    Blake2_128Concat,
+    AccountOf<T>,
+    u32,
     ValueQuery,
 >;
 
+/// Fee reserved from the sender for each pending transaction (absent when zero)
+#[pallet::storage]
+pub type ReservedFees<T: Config> = StorageDoubleMap<
+    _,
+    Blake2_128Concat,
+    AccountOf<T>,
+    Twox64Concat,
+    u64,
+    BalanceOf<T>,
+    OptionQuery,
+>;
+
+/// Monotonic insertion counter (FIFO tie-break within equal priority)
+#[pallet::storage]
+pub type NextSequence<T: Config> = StorageValue<_, u64, ValueQuery>;
+
 /// Processed transactions (included in blocks)
 #[pallet::storage]
 pub type ProcessedTransactions<T: Config> = StorageValue<
@@ -170,6 +335,50 @@ pub type PoolStats<T: Config> = StorageValue<
 // DATA STRUCTURES
 // ============================================================
 
+/// A pending transaction and its index position
+#[derive(CloneNoBound, Encode, Decode, TypeInfo, RuntimeDebugNoBound, PartialEqNoBound, EqNoBound)]
+#[scale_info(skip_type_params(T))]
+pub struct PoolEntry<T: Config> {
+    pub tx: SignedTx<T>,
+    pub priority: u64,
+    pub sequence: u64,
+    pub submitted_at: BlockNumberOf<T>,
+}
+
+impl<T: Config> PoolEntry<T> {
+    /// Key in `EvictionIndex` (ascending priority)
+    pub fn eviction_key(&self) -> IndexKey {
+        index_key(self.priority, self.sequence)
+    }
+
+    /// Key in `ReadyIndex` (descending priority, FIFO within a priority)
+    pub fn ready_key(&self) -> IndexKey {
+        index_key(u64::MAX - self.priority, self.sequence)
+    }
+}
+
+pub(crate) fn index_key(priority: u64, sequence: u64) -> IndexKey {
+    let mut key = [0u8; 16];
+    key[..8].copy_from_slice(&priority.to_be_bytes());
+    key[8..].copy_from_slice(&sequence.to_be_bytes());
+    key
+}
+
+/// Split an index key back into (priority, sequence)
+pub(crate) fn split_index_key(key: &IndexKey) -> (u64, u64) {
+    let mut priority = [0u8; 8];
+    let mut sequence = [0u8; 8];
+    priority.copy_from_slice(&key[..8]);
+    sequence.copy_from_slice(&key[8..]);
+    (u64::from_be_bytes(priority), u64::from_be_bytes(sequence))
+}
+
+/// `ReadyIndex` key of the entry with the given `EvictionIndex` key
+pub(crate) fn ready_key_of(eviction_key: &IndexKey) -> IndexKey {
+    let (priority, sequence) = split_index_key(eviction_key);
+    index_key(u64::MAX - priority, sequence)
+}
+
 #[derive(Clone, Encode, Decode, MaxEncodedLen, TypeInfo, Debug, PartialEq, Eq)]
 #[scale_info(skip_type_params(T))]
 #[codec(mel_bound())]
@@ -203,6 +412,19 @@ pub enum Event<T: Config> {
         from: AccountOf<T>,
         nonce: u64,
     },
+    /// Pending transaction replaced by a higher-priority one with the same nonce
+    TransactionReplaced {
+        from: AccountOf<T>,
+        nonce: u64,
+        old_priority: u64,
+        new_priority: u64,
+    },
+    /// Lowest-priority transaction evicted to make room
+    TransactionEvicted {
+        from: AccountOf<T>,
+        nonce: u64,
+        priority: u64,
+    },
     /// Transaction processed successfully
     TransactionProcessed {
         from: AccountOf<T>,
@@ -233,6 +455,10 @@ pub enum Error<T> {
     InvalidTimestamp,
     DuplicateTransaction,
     TransactionTooLarge,
+    NonceTooFarAhead,
+    AccountLimitReached,
+    ReplacementUnderpriced,
+    PriorityTooLow,
 }
 
 // ============================================================
@@ -241,10 +467,14 @@ pub enum Error<T> {
 
 #[pallet::hooks]
 impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
-    fn on_finalize(block_number: BlockNumberFor<T>) {
+    fn on_runtime_upgrade() -> Weight {
+        migrations::v2::migrate::<T>()
+    }
+
+    fn on_idle(block_number: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
         // Process pending transactions (simplified for v1)
         // In production: select by gas price, execute, update state
-        Self::process_pending_transactions(block_number);
+        Self::process_pending_transactions(block_number, remaining_weight)
     }
 }
 
@@ -255,8 +485,19 @@ impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
 #[pallet::call]
 impl<T: Config> Pallet<T> {
     /// Submit transaction to mempool
+    ///
+    /// A transaction with the sender's next nonce becomes ready immediately;
+    /// later nonces are parked until the gap closes. Re-submitting a pending
+    /// nonce replaces it only with a 10% higher priority. The declared fee is
+    /// reserved from the sender until the transaction executes or leaves the pool.
+    ///
+    /// Charged by encoded size, or for replacing/evicting if that costs more: under
+    /// fee-per-weight priority only small Lightning Bloc transactions outrank others.
     #[pallet::call_index(0)]
-    #[pallet::weight(20_000)]
+    #[pallet::weight({
+        let len = tx.encoded_size() as u32;
+        T::WeightInfo::submit_transaction(len).max(T::WeightInfo::submit_transaction_evicting())
+    })]
     pub fn submit_transaction(
         origin: OriginFor<T>,
         tx: SignedTx<T>,
@@ -266,54 +507,36 @@ impl<T: Config> Pallet<T> {
         // Validate transaction
         Self::validate_transaction(&tx)?;
 
-        let mut pool = Self::get_mempool();
-
-        // Check pool size
-        ensure!(pool.len() < MAX_POOL_SIZE, Error::<T>::PoolFull);
-
-        // Add to pool
-        pool.try_push(tx.clone())
-            .map_err(|_| Error::<T>::PoolFull)?;
-
-        TransactionPool::<T>::put(pool.clone());
-
-        // Update stats
-        PoolStats::<T>::mutate(|stats| {
-            stats.total_submitted = stats.total_submitted.saturating_add(1);
-            stats.current_pool_size = pool.len() as u32;
-        });
-
-        Self::deposit_event(Event::TransactionReceived {
-            from: tx.sender.clone(),
-            nonce: tx.nonce,
-        });
-
-        Ok(())
+        Self::insert_transaction(tx)
     }
 
     /// Manually clear mempool (admin only)
+    ///
+    /// Removes up to `MAX_CLEAR_PER_CALL` transactions, lowest priority first, so the
+    /// call fits in a block. Repeat until `MempoolCleared` reports fewer than that.
     #[pallet::call_index(1)]
-    #[pallet::weight(100_000)]
-    pub fn clear_mempool(origin: OriginFor<T>) -> DispatchResult {
+    #[pallet::weight(T::WeightInfo::clear_mempool(MAX_CLEAR_PER_CALL))]
+    pub fn clear_mempool(origin: OriginFor<T>) -> DispatchResultWithPostInfo {
         ensure_root(origin)?;
 
-        let pool = Self::get_mempool();
-        let size = pool.len() as u32;
-
-        TransactionPool::<T>::put(BoundedVec::new());
-
-        PoolStats::<T>::mutate(|stats| {
-            stats.current_pool_size = 0;
-        });
+        // Index keys carry everything needed to remove an entry without reading it
+        let victims: Vec<_> = EvictionIndex::<T>::iter()
+            .take(MAX_CLEAR_PER_CALL as usize)
+            .collect();
+        for (key, (sender, nonce)) in &victims {
+            Self::remove_indexed(sender, *nonce, key);
+            Self::release_fee(sender, *nonce);
+        }
 
-        Self::deposit_event(Event::MempoolCleared { tx_count: size });
+        let tx_count = victims.len() as u32;
+        Self::deposit_event(Event::MempoolCleared { tx_count });
 
-        Ok(())
+        Ok(Some(T::WeightInfo::clear_mempool(tx_count)).into())
     }
 
     /// Query current mempool size
     #[pallet::call_index(2)]
-    #[pallet::weight(5_000)]
+    #[pallet::weight(T::WeightInfo::query_pool_size())]
     pub fn query_pool_size(origin: OriginFor<T>) -> DispatchResult {
         let _who = ensure_signed(origin)?;
         // Return via event or storage getter
@@ -360,9 +583,13 @@ impl<T: Config> Pallet<T> {
             return Err(Error::<T>::InvalidSignature.into());
         }
 
-        // 2. Check nonce matches expected next nonce
+        // 2. Check nonce is not stale and not too far ahead (future nonces are parked)
         let expected_nonce = NextNonce::<T>::get(&tx.sender);
-        ensure!(tx.nonce == expected_nonce, Error::<T>::InvalidNonce);
+        ensure!(tx.nonce >= expected_nonce, Error::<T>::InvalidNonce);
+        ensure!(
+            tx.nonce - expected_nonce <= MAX_FUTURE_NONCE_GAP,
+            Error::<T>::NonceTooFarAhead
+        );
 
         // 3. Verify chain ID (simplified - just check it's set)
         ensure!(tx.chain_id > 0, Error::<T>::InvalidChainId);
@@ -408,65 +635,261 @@ impl<T: Config> Pallet<T> {
 }
 
 // ============================================================
-// TRANSACTION PROCESSING
+// POOL MANAGEMENT
 // ============================================================
 
 impl<T: Config> Pallet<T> {
-    fn process_pending_transactions(block_number: BlockNumberOf<T>) {
-        let pool = Self::get_mempool();
+    /// Insert a validated transaction, handling replacement, limits and eviction
+    ///
+    /// Every fallible check runs before the pool or any reservation changes, so a
+    /// rejected transaction leaves no trace even outside a storage layer.
+    pub(crate) fn insert_transaction(tx: SignedTx<T>) -> DispatchResult {
+        let sender = tx.sender.clone();
+        let nonce = tx.nonce;
+        let priority = T::Priority::priority(&tx);
+        let fee: BalanceOf<T> = T::Priority::fee(&tx).saturated_into();
+
+        // Replace-by-fee for an already pending nonce
+        if let Some(existing) = PendingTransactions::<T>::get(&sender, nonce) {
+            let min_priority = existing
+                .priority
+                .saturating_add(existing.priority.saturating_mul(REPLACE_BUMP_PERCENT) / 100)
+                .saturating_add(1);
+            ensure!(priority >= min_priority, Error::<T>::ReplacementUnderpriced);
+
+            // Only the difference to the replaced transaction's fee moves
+            let reserved = ReservedFees::<T>::get(&sender, nonce).unwrap_or_default();
+            if fee > reserved {
+                T::Currency::reserve(&sender, fee - reserved)
+                    .map_err(|_| Error::<T>::InsufficientFunds)?;
+            } else {
+                T::Currency::unreserve(&sender, reserved - fee);
+            }
+            Self::set_reserved_fee(&sender, nonce, fee);
+
+            Self::remove_entry(&existing);
+            Self::add_entry(tx, priority);
+
+            Self::deposit_event(Event::TransactionReplaced {
+                from: sender,
+                nonce,
+                old_priority: existing.priority,
+                new_priority: priority,
+            });
+            return Ok(());
+        }
+
+        ensure!(
+            SenderPoolCount::<T>::get(&sender) < MAX_TX_PER_ACCOUNT,
+            Error::<T>::AccountLimitReached
+        );
+
+        // Make room by evicting the lowest-priority entry if it is worse; its
+        // priority is in the index key, so the (possibly large) entry is never read
+        let victim = if PoolStats::<T>::get().current_pool_size as usize >= MAX_POOL_SIZE {
+            let (key, (victim_sender, victim_nonce)) =
+                EvictionIndex::<T>::iter().next().ok_or(Error::<T>::PoolFull)?;
+            let (victim_priority, _) = split_index_key(&key);
+            ensure!(priority > victim_priority, Error::<T>::PriorityTooLow);
+            Some((key, victim_sender, victim_nonce))
+        } else {
+            None
+        };
 
-        if pool.is_empty() {
-            return;
+        if !fee.is_zero() {
+            T::Currency::reserve(&sender, fee).map_err(|_| Error::<T>::InsufficientFunds)?;
         }
+        Self::set_reserved_fee(&sender, nonce, fee);
 
-        // Select top MAX_TX_PER_BLOCK transactions
-        let tx_count = pool.len().min(MAX_TX_PER_BLOCK);
-
-        for i in 0..tx_count {
-            if let Some(tx) = pool.get(i) {
-                // Update nonce
-                NextNonce::<T>::mutate(&tx.sender, |nonce| {
-                    *nonce = nonce.saturating_add(1);
-                });
-
-                // Record block height (using nonce as simple hash)
-                let tx_hash = Self::compute_tx_hash(tx);
-                TxBlockHeight::<T>::insert(tx_hash, block_number);
-
-                // Update stats
-                PoolStats::<T>::mutate(|stats| {
-                    stats.total_processed = stats.total_processed.saturating_add(1);
-                });
-
-                Self::deposit_event(Event::TransactionProcessed {
-                    from: tx.sender.clone(),
-                    at_block: block_number,
-                });
-            }
+        if let Some((key, victim_sender, victim_nonce)) = victim {
+            Self::evict(&key, victim_sender, victim_nonce);
         }
 
-        // Remove processed transactions from pool
-        let remaining: BoundedVec<SignedTx<T>, ConstU32<10000>> =
-            BoundedVec::truncate_from(
-                pool.iter()
-                    .skip(tx_count)
-                    .cloned()
-                    .collect::<Vec<_>>()
-            );
+        Self::add_entry(tx, priority);
 
-        TransactionPool::<T>::put(remaining.clone());
+        PoolStats::<T>::mutate(|stats| {
+            stats.total_submitted = stats.total_submitted.saturating_add(1);
+        });
+
+        Self::deposit_event(Event::TransactionReceived { from: sender, nonce });
+
+        Ok(())
+    }
+
+    /// Store an entry and index it (ready only if it is the sender's next nonce)
+    fn add_entry(tx: SignedTx<T>, priority: u64) {
+        let sequence = NextSequence::<T>::mutate(|seq| {
+            let current = *seq;
+            *seq = seq.saturating_add(1);
+            current
+        });
+
+        let entry = PoolEntry::<T> {
+            tx,
+            priority,
+            sequence,
+            submitted_at: frame_system::Pallet::<T>::block_number(),
+        };
+        let sender = entry.tx.sender.clone();
+        let nonce = entry.tx.nonce;
+
+        EvictionIndex::<T>::insert(entry.eviction_key(), (sender.clone(), nonce));
+        if nonce == NextNonce::<T>::get(&sender) {
+            ReadyIndex::<T>::insert(entry.ready_key(), (sender.clone(), nonce));
+        }
+        PendingTransactions::<T>::insert(&sender, nonce, entry);
+
+        SenderPoolCount::<T>::mutate(&sender, |count| *count = count.saturating_add(1));
+        PoolStats::<T>::mutate(|stats| {
+            stats.current_pool_size = stats.current_pool_size.saturating_add(1);
+        });
+    }
+
+    /// Remove an entry from storage and both indexes
+    fn remove_entry(entry: &PoolEntry<T>) {
+        Self::remove_indexed(&entry.tx.sender, entry.tx.nonce, &entry.eviction_key());
+    }
+
+    /// Remove an entry by its sender, nonce and eviction key, without reading it
+    fn remove_indexed(sender: &AccountOf<T>, nonce: u64, eviction_key: &IndexKey) {
+        PendingTransactions::<T>::remove(sender, nonce);
+        EvictionIndex::<T>::remove(eviction_key);
+        ReadyIndex::<T>::remove(ready_key_of(eviction_key));
+
+        SenderPoolCount::<T>::mutate_exists(sender, |count| {
+            let remaining = count.unwrap_or(0).saturating_sub(1);
+            *count = if remaining == 0 { None } else { Some(remaining) };
+        });
+        PoolStats::<T>::mutate(|stats| {
+            stats.current_pool_size = stats.current_pool_size.saturating_sub(1);
+        });
+    }
+
+    /// Evict the entry with the given eviction key (pool pressure)
+    ///
+    /// The sender's later nonces can never execute without it, so they are evicted
+    /// too rather than left parked against the sender's limit.
+    fn evict(eviction_key: &IndexKey, sender: AccountOf<T>, nonce: u64) {
+        Self::evict_one(eviction_key, &sender, nonce);
+
+        let last = NextNonce::<T>::get(&sender).saturating_add(MAX_FUTURE_NONCE_GAP);
+        for later in nonce.saturating_add(1)..=last {
+            if let Some(entry) = PendingTransactions::<T>::get(&sender, later) {
+                Self::evict_one(&entry.eviction_key(), &sender, later);
+            }
+        }
+    }
+
+    fn evict_one(eviction_key: &IndexKey, sender: &AccountOf<T>, nonce: u64) {
+        Self::remove_indexed(sender, nonce, eviction_key);
+        Self::release_fee(sender, nonce);
 
         PoolStats::<T>::mutate(|stats| {
-            stats.current_pool_size = remaining.len() as u32;
+            stats.total_failed = stats.total_failed.saturating_add(1);
+        });
+
+        Self::deposit_event(Event::TransactionEvicted {
+            from: sender.clone(),
+            nonce,
+            priority: split_index_key(eviction_key).0,
         });
     }
 
+    /// Record the fee reserved for a pending transaction
+    fn set_reserved_fee(sender: &AccountOf<T>, nonce: u64, fee: BalanceOf<T>) {
+        if fee.is_zero() {
+            ReservedFees::<T>::remove(sender, nonce);
+        } else {
+            ReservedFees::<T>::insert(sender, nonce, fee);
+        }
+    }
+
+    /// Return the reserved fee of a transaction leaving the pool unexecuted
+    fn release_fee(sender: &AccountOf<T>, nonce: u64) {
+        if let Some(fee) = ReservedFees::<T>::take(sender, nonce) {
+            T::Currency::unreserve(sender, fee);
+        }
+    }
+
+    /// Charge the reserved fee of an executed transaction
+    fn charge_fee(sender: &AccountOf<T>, nonce: u64) {
+        if let Some(fee) = ReservedFees::<T>::take(sender, nonce) {
+            let (imbalance, _) = T::Currency::slash_reserved(sender, fee);
+            T::OnFeeCharged::on_unbalanced(imbalance);
+        }
+    }
+
+    /// Move the sender's next nonce (if parked) into the ready index
+    fn promote_next(sender: &AccountOf<T>) {
+        let next = NextNonce::<T>::get(sender);
+        if let Some(entry) = PendingTransactions::<T>::get(sender, next) {
+            ReadyIndex::<T>::insert(entry.ready_key(), (sender.clone(), next));
+        }
+    }
+}
+
+// ============================================================
+// TRANSACTION PROCESSING
+// ============================================================
+
+impl<T: Config> Pallet<T> {
+    /// Execute ready transactions, best first, within `limit`; returns the weight used
+    ///
+    /// A transaction's size is only known once it is read, so the next one is only
+    /// started while one of the largest encodable size would still fit.
+    pub(crate) fn process_pending_transactions(block_number: BlockNumberOf<T>, limit: Weight) -> Weight {
+        let max_tx = T::WeightInfo::process_transaction(SignedTx::<T>::max_encoded_len() as u32);
+        let mut used = Weight::zero();
+
+        for _ in 0..MAX_TX_PER_BLOCK {
+            if used.saturating_add(max_tx).any_gt(limit) {
+                break;
+            }
+
+            // Best ready transaction is always the first key
+            let Some((key, (sender, nonce))) = ReadyIndex::<T>::iter().next() else {
+                used = used.saturating_add(T::DbWeight::get().reads(1));
+                break;
+            };
+            let Some(entry) = PendingTransactions::<T>::get(&sender, nonce) else {
+                // Dangling index entry; drop it and continue
+                ReadyIndex::<T>::remove(key);
+                used = used.saturating_add(T::DbWeight::get().reads_writes(2, 1));
+                continue;
+            };
+            used = used.saturating_add(T::WeightInfo::process_transaction(entry.tx.encoded_size() as u32));
+
+            Self::remove_entry(&entry);
+            Self::charge_fee(&sender, nonce);
+
+            // Update nonce
+            NextNonce::<T>::mutate(&sender, |nonce| {
+                *nonce = nonce.saturating_add(1);
+            });
+
+            // Record block height
+            let tx_hash = Self::compute_tx_hash(&entry.tx);
+            TxBlockHeight::<T>::insert(tx_hash, block_number);
+
+            // Update stats
+            PoolStats::<T>::mutate(|stats| {
+                stats.total_processed = stats.total_processed.saturating_add(1);
+            });
+
+            Self::deposit_event(Event::TransactionProcessed {
+                from: sender.clone(),
+                at_block: block_number,
+            });
+
+            // The sender's next nonce may now be executable
+            Self::promote_next(&sender);
+        }
+
+        used
+    }
+
     fn compute_tx_hash(tx: &SignedTx<T>) -> [u8; 32] {
-        // Simple hash based on nonce (in production use proper hashing)
-        let mut hash = [0u8; 32];
-        let nonce_bytes = tx.nonce.to_le_bytes();
-        hash[0..8].copy_from_slice(&nonce_bytes);
-        hash
+        sp_core::hashing::blake2_256(&tx.encode())
     }
 }
 
@@ -475,12 +898,21 @@ impl<T: Config> Pallet<T> {
 // ============================================================
 
 impl<T: Config> Pallet<T> {
-    pub fn get_mempool() -> BoundedVec<SignedTx<T>, ConstU32<10000>> {
-        TransactionPool::<T>::get()
+    /// Ready transactions in execution order (highest priority first), up to `limit`
+    pub fn get_mempool(limit: u32) -> Vec<SignedTx<T>> {
+        ReadyIndex::<T>::iter_values()
+            .take(limit as usize)
+            .filter_map(|(sender, nonce)| PendingTransactions::<T>::get(sender, nonce))
+            .map(|entry| entry.tx)
+            .collect()
     }
 
     pub fn get_pool_size() -> usize {
-        TransactionPool::<T>::get().len()
+        PoolStats::<T>::get().current_pool_size as usize
+    }
+
+    pub fn get_pending(account: &AccountOf<T>, nonce: u64) -> Option<PoolEntry<T>> {
+        PendingTransactions::<T>::get(account, nonce)
     }
 
     pub fn get_stats() -> PoolStatistics {
@@ -496,6 +928,48 @@ impl<T: Config> Pallet<T> {
     }
 }
 
+// ============================================================
+// MIGRATIONS
+// ============================================================
+
+pub mod migrations {
+    use super::*;
+
+    pub mod v2 {
+        use super::*;
+        use frame_support::traits::GetStorageVersion;
+
+        /// v1 single-vector pool
+        #[frame_support::storage_alias]
+        pub type TransactionPool<T: Config> = StorageValue<
+            Pallet<T>,
+            BoundedVec<SignedTx<T>, ConstU32<10000>>,
+            ValueQuery,
+        >;
+
+        /// Move the v1 pool vector into the indexed v2 layout
+        pub fn migrate<T: Config>() -> Weight {
+            let on_chain = Pallet::<T>::on_chain_storage_version();
+            if on_chain >= 2 {
+                return T::DbWeight::get().reads(1);
+            }
+
+            let old_pool = TransactionPool::<T>::take();
+            let count = old_pool.len() as u64;
+
+            PoolStats::<T>::mutate(|stats| stats.current_pool_size = 0);
+            for tx in old_pool {
+                // Entries that no longer fit the v2 rules are dropped
+                let _ = Pallet::<T>::insert_transaction(tx);
+            }
+
+            STORAGE_VERSION.put::<Pallet<T>>();
+
+            T::DbWeight::get().reads_writes(2 + count, 2 + count.saturating_mul(5))
+        }
+    }
+}
+
 // ============================================================
 // UNIT TESTS
 // ============================================================
@@ -606,5 +1080,13 @@ mod tests {
         assert_eq!(MAX_POOL_SIZE, 10_000);
         assert_eq!(MAX_TX_PER_BLOCK, 1_000);
     }
+
+    #[test]
+    fn test_index_key_orders_by_priority_then_sequence() {
+        assert!(index_key(1, 99) < index_key(2, 0));
+        assert!(index_key(5, 1) < index_key(5, 2));
+        // Inverted priority puts the best transaction first
+        assert!(index_key(u64::MAX - 10, 0) < index_key(u64::MAX - 5, 0));
+    }
 }
 }
\ No newline at end of file
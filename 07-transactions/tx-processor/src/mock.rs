@@ -0,0 +1,111 @@
+//! Mock runtime for transaction processor pallet tests
+
+use crate as pallet_tx_processor;
+use codec::Encode;
+use ed25519_dalek::{Signer, SigningKey};
+use frame_support::{
+    derive_impl, parameter_types,
+    sp_runtime::{traits::IdentityLookup, AccountId32, BuildStorage},
+};
+use pallet_transaction::{Signature, SignedTransaction, TransactionType};
+
+type Block = frame_system::mocking::MockBlock<Test>;
+
+frame_support::construct_runtime!(
+    pub enum Test
+    {
+        System: frame_system,
+        Balances: pallet_balances,
+        TxProcessor: pallet_tx_processor,
+    }
+);
+
+#[derive_impl(frame_system::config_preludes::TestDefaultConfig)]
+impl frame_system::Config for Test {
+    type Block = Block;
+    type AccountId = AccountId32;
+    type Lookup = IdentityLookup<Self::AccountId>;
+    type AccountData = pallet_balances::AccountData<u128>;
+}
+
+parameter_types! {
+    pub const ExistentialDeposit: u128 = 1;
+}
+
+#[derive_impl(pallet_balances::config_preludes::TestDefaultConfig)]
+impl pallet_balances::Config for Test {
+    type Balance = u128;
+    type ExistentialDeposit = ExistentialDeposit;
+    type AccountStore = System;
+}
+
+impl pallet_tx_processor::Config for Test {
+    type RuntimeEvent = RuntimeEvent;
+    type Currency = Balances;
+    type OnFeeCharged = ();
+    type Priority = pallet_tx_processor::FeePerWeight;
+    type WeightInfo = ();
+}
+
+pub const INITIAL_BALANCE: u128 = 1_000_000;
+
+/// ed25519 key of test account `seed`
+fn key(seed: u8) -> SigningKey {
+    SigningKey::from_bytes(&[seed; 32])
+}
+
+/// Account id of test account `seed` (its ed25519 public key)
+pub fn account(seed: u8) -> AccountId32 {
+    AccountId32::new(key(seed).verifying_key().to_bytes())
+}
+
+pub fn lightning_bloc(fee: u128) -> TransactionType {
+    TransactionType::LightningBloc {
+        target_chain: 2,
+        recipient: vec![2u8; 32].try_into().unwrap(),
+        amount: 1_000,
+        fee,
+    }
+}
+
+/// Transaction from test account `seed`, signed the way `validate_transaction` checks
+pub fn signed_tx(seed: u8, nonce: u64, tx_type: TransactionType) -> SignedTransaction<AccountId32> {
+    let sender = account(seed);
+    let chain_id: u32 = 1;
+
+    let mut message = Vec::new();
+    message.extend_from_slice(&sender.encode());
+    message.extend_from_slice(&nonce.to_le_bytes());
+    message.extend_from_slice(&tx_type.encode());
+    message.extend_from_slice(&chain_id.to_le_bytes());
+    let signature = key(seed).sign(&message).to_bytes().to_vec();
+
+    SignedTransaction {
+        sender,
+        nonce,
+        tx_type,
+        signature: Signature(signature.try_into().unwrap()),
+        chain_id,
+    }
+}
+
+/// Fee-paying transaction from test account `seed`
+pub fn fee_tx(seed: u8, nonce: u64, fee: u128) -> SignedTransaction<AccountId32> {
+    signed_tx(seed, nonce, lightning_bloc(fee))
+}
+
+// Build genesis storage; accounts 1..=4 are funded, account 5 is not
+pub fn new_test_ext() -> sp_io::TestExternalities {
+    let mut t = frame_system::GenesisConfig::<Test>::default().build_storage().unwrap();
+
+    pallet_balances::GenesisConfig::<Test> {
+        balances: (1..=4).map(|seed| (account(seed), INITIAL_BALANCE)).collect(),
+        ..Default::default()
+    }
+    .assimilate_storage(&mut t)
+    .unwrap();
+
+    let mut ext = sp_io::TestExternalities::new(t);
+    ext.execute_with(|| System::set_block_number(1));
+    ext
+}
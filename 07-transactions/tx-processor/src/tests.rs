@@ -0,0 +1,279 @@
+//! Tests for transaction processor pallet
+
+use crate::{
+    migrations::v2::TransactionPool, mock::*, pallet::MAX_POOL_SIZE, Error, Event, FeePerWeight,
+    NextNonce, PoolStats, ReservedFees, SenderPoolCount, TransactionPriority, WeightInfo,
+};
+use codec::MaxEncodedLen;
+use frame_support::{
+    assert_noop, assert_ok,
+    dispatch::DispatchResult,
+    sp_runtime::{AccountId32, DispatchError},
+    traits::{GetStorageVersion, Hooks, StorageVersion},
+    weights::Weight,
+    BoundedVec,
+};
+use pallet_transaction::SignedTransaction;
+
+fn submit(tx: SignedTransaction<AccountId32>) -> DispatchResult {
+    TxProcessor::submit_transaction(RuntimeOrigin::signed(account(1)), tx)
+}
+
+/// Execute ready transactions with room for exactly one
+fn process_one() {
+    let max_len = SignedTransaction::<AccountId32>::max_encoded_len() as u32;
+    TxProcessor::on_idle(1, <() as WeightInfo>::process_transaction(max_len));
+}
+
+fn ready_nonces() -> Vec<(u8, u64)> {
+    TxProcessor::get_mempool(100)
+        .into_iter()
+        .map(|tx| ((1..=5).find(|seed| account(*seed) == tx.sender).unwrap(), tx.nonce))
+        .collect()
+}
+
+fn evicted_count() -> usize {
+    System::events()
+        .into_iter()
+        .filter(|record| {
+            matches!(record.event, RuntimeEvent::TxProcessor(Event::TransactionEvicted { .. }))
+        })
+        .count()
+}
+
+#[test]
+fn submit_reserves_declared_fee() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(submit(fee_tx(1, 0, 500)));
+
+        assert_eq!(Balances::reserved_balance(&account(1)), 500);
+        assert_eq!(ReservedFees::<Test>::get(account(1), 0), Some(500));
+        assert_eq!(TxProcessor::get_pool_size(), 1);
+        System::assert_last_event(Event::TransactionReceived { from: account(1), nonce: 0 }.into());
+    });
+}
+
+#[test]
+fn submit_fails_when_fee_cannot_be_reserved() {
+    new_test_ext().execute_with(|| {
+        // Account 5 has no balance and account 1 cannot cover the fee
+        assert_noop!(submit(fee_tx(5, 0, 500)), Error::<Test>::InsufficientFunds);
+        assert_noop!(
+            submit(fee_tx(1, 0, INITIAL_BALANCE + 1)),
+            Error::<Test>::InsufficientFunds
+        );
+
+        // Without a fee nothing is reserved
+        assert_ok!(submit(fee_tx(5, 0, 0)));
+        assert!(!ReservedFees::<Test>::contains_key(account(5), 0));
+    });
+}
+
+#[test]
+fn replace_by_fee_requires_ten_percent_bump() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(submit(fee_tx(1, 0, 1_000)));
+        let old_priority = TxProcessor::get_pending(&account(1), 0).unwrap().priority;
+
+        // 9.9% more fee is 9.9% more priority: underpriced
+        assert_noop!(submit(fee_tx(1, 0, 1_099)), Error::<Test>::ReplacementUnderpriced);
+        assert_eq!(Balances::reserved_balance(&account(1)), 1_000);
+
+        assert_ok!(submit(fee_tx(1, 0, 1_200)));
+        let new_priority = FeePerWeight::priority(&fee_tx(1, 0, 1_200));
+        System::assert_last_event(
+            Event::TransactionReplaced { from: account(1), nonce: 0, old_priority, new_priority }.into(),
+        );
+        assert_eq!(TxProcessor::get_pending(&account(1), 0).unwrap().tx, fee_tx(1, 0, 1_200));
+        assert_eq!(TxProcessor::get_pool_size(), 1);
+        assert_eq!(SenderPoolCount::<Test>::get(account(1)), 1);
+
+        // Only the difference was reserved on top
+        assert_eq!(Balances::reserved_balance(&account(1)), 1_200);
+        assert_eq!(ready_nonces(), vec![(1, 0)]);
+    });
+}
+
+#[test]
+fn future_nonce_is_parked_until_gap_fills() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(submit(fee_tx(1, 1, 100)));
+        assert!(TxProcessor::get_pending(&account(1), 1).is_some());
+        assert!(ready_nonces().is_empty());
+
+        // Nothing is executable while the gap is open
+        process_one();
+        assert_eq!(NextNonce::<Test>::get(account(1)), 0);
+
+        assert_ok!(submit(fee_tx(1, 0, 100)));
+        assert_eq!(ready_nonces(), vec![(1, 0)]);
+
+        // Executing nonce 0 promotes the parked nonce 1
+        process_one();
+        assert_eq!(NextNonce::<Test>::get(account(1)), 1);
+        assert_eq!(ready_nonces(), vec![(1, 1)]);
+
+        process_one();
+        assert_eq!(NextNonce::<Test>::get(account(1)), 2);
+        assert_eq!(TxProcessor::get_pool_size(), 0);
+        assert!(!SenderPoolCount::<Test>::contains_key(account(1)));
+    });
+}
+
+#[test]
+fn processing_charges_reserved_fee() {
+    new_test_ext().execute_with(|| {
+        let issuance = Balances::total_issuance();
+        assert_ok!(submit(fee_tx(1, 0, 500)));
+
+        TxProcessor::on_idle(1, Weight::MAX);
+
+        assert_eq!(Balances::reserved_balance(&account(1)), 0);
+        assert_eq!(Balances::free_balance(&account(1)), INITIAL_BALANCE - 500);
+        assert!(!ReservedFees::<Test>::contains_key(account(1), 0));
+        // `OnFeeCharged = ()` burns the fee
+        assert_eq!(Balances::total_issuance(), issuance - 500);
+        assert_eq!(TxProcessor::get_stats().total_processed, 1);
+    });
+}
+
+#[test]
+fn stale_nonce_is_rejected() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(submit(fee_tx(1, 0, 0)));
+        TxProcessor::on_idle(1, Weight::MAX);
+
+        assert_noop!(submit(fee_tx(1, 0, 0)), Error::<Test>::InvalidNonce);
+    });
+}
+
+#[test]
+fn nonce_gap_is_limited_to_sixteen() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(submit(fee_tx(1, 16, 0)));
+        assert_noop!(submit(fee_tx(1, 17, 0)), Error::<Test>::NonceTooFarAhead);
+
+        // The limit moves with the account nonce
+        assert_ok!(submit(fee_tx(1, 0, 0)));
+        TxProcessor::on_idle(1, Weight::MAX);
+        assert_eq!(NextNonce::<Test>::get(account(1)), 1);
+        assert_ok!(submit(fee_tx(1, 17, 0)));
+    });
+}
+
+#[test]
+fn invalid_signature_is_rejected() {
+    new_test_ext().execute_with(|| {
+        let mut tx = fee_tx(1, 0, 0);
+        tx.nonce = 1;
+
+        assert_noop!(submit(tx), Error::<Test>::InvalidSignature);
+    });
+}
+
+#[test]
+fn pool_full_evicts_lowest_priority_with_its_later_nonces() {
+    new_test_ext().execute_with(|| {
+        // Account 2 holds the lowest-priority head and two parked successors
+        for nonce in 0..3 {
+            assert_ok!(submit(fee_tx(2, nonce, 10)));
+        }
+        assert_ok!(submit(fee_tx(3, 0, 50)));
+        assert_eq!(Balances::reserved_balance(&account(2)), 30);
+        PoolStats::<Test>::mutate(|stats| stats.current_pool_size = MAX_POOL_SIZE as u32);
+
+        assert_ok!(submit(fee_tx(1, 0, 1_000)));
+
+        for nonce in 0..3 {
+            assert!(TxProcessor::get_pending(&account(2), nonce).is_none());
+        }
+        assert_eq!(evicted_count(), 3);
+        assert!(!SenderPoolCount::<Test>::contains_key(account(2)));
+        assert_eq!(Balances::reserved_balance(&account(2)), 0);
+        assert_eq!(TxProcessor::get_pool_size(), MAX_POOL_SIZE - 3 + 1);
+        assert_eq!(TxProcessor::get_stats().total_failed, 3);
+
+        // Other senders are untouched
+        assert!(TxProcessor::get_pending(&account(3), 0).is_some());
+        assert_eq!(ready_nonces(), vec![(1, 0), (3, 0)]);
+    });
+}
+
+#[test]
+fn pool_full_rejects_lower_priority() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(submit(fee_tx(2, 0, 100)));
+        PoolStats::<Test>::mutate(|stats| stats.current_pool_size = MAX_POOL_SIZE as u32);
+
+        assert_noop!(submit(fee_tx(1, 0, 100)), Error::<Test>::PriorityTooLow);
+        assert_noop!(submit(fee_tx(1, 0, 10)), Error::<Test>::PriorityTooLow);
+    });
+}
+
+#[test]
+fn clear_mempool_releases_reserved_fees() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(submit(fee_tx(1, 0, 100)));
+        assert_ok!(submit(fee_tx(1, 1, 100)));
+        assert_ok!(submit(fee_tx(2, 0, 100)));
+
+        assert_noop!(
+            TxProcessor::clear_mempool(RuntimeOrigin::signed(account(1))),
+            DispatchError::BadOrigin
+        );
+        assert_ok!(TxProcessor::clear_mempool(RuntimeOrigin::root()));
+
+        System::assert_last_event(Event::MempoolCleared { tx_count: 3 }.into());
+        assert_eq!(TxProcessor::get_pool_size(), 0);
+        assert_eq!(Balances::reserved_balance(&account(1)), 0);
+        assert_eq!(Balances::reserved_balance(&account(2)), 0);
+        assert!(!SenderPoolCount::<Test>::contains_key(account(1)));
+    });
+}
+
+#[test]
+fn migration_v2_indexes_v1_pool() {
+    new_test_ext().execute_with(|| {
+        StorageVersion::new(1).put::<TxProcessor>();
+        let old = vec![fee_tx(1, 0, 100), fee_tx(1, 1, 100), fee_tx(2, 0, 0), fee_tx(5, 0, 100)];
+        TransactionPool::<Test>::put(BoundedVec::truncate_from(old));
+        PoolStats::<Test>::mutate(|stats| stats.current_pool_size = 4);
+
+        crate::migrations::v2::migrate::<Test>();
+
+        assert_eq!(TxProcessor::on_chain_storage_version(), 2);
+        assert!(!TransactionPool::<Test>::exists());
+        assert!(TxProcessor::get_pending(&account(1), 1).is_some());
+        assert_eq!(ready_nonces(), vec![(1, 0), (2, 0)]);
+        assert_eq!(Balances::reserved_balance(&account(1)), 200);
+
+        // Account 5 cannot reserve its fee, so its transaction is dropped
+        assert!(TxProcessor::get_pending(&account(5), 0).is_none());
+        assert_eq!(TxProcessor::get_pool_size(), 3);
+
+        // A second run is a no-op
+        crate::migrations::v2::migrate::<Test>();
+        assert_eq!(TxProcessor::get_pool_size(), 3);
+    });
+}
+
+#[test]
+fn migration_v2_caps_pending_per_account() {
+    new_test_ext().execute_with(|| {
+        StorageVersion::new(1).put::<TxProcessor>();
+        let old: Vec<_> = (0..70).map(|nonce| fee_tx(1, nonce, 0)).collect();
+        TransactionPool::<Test>::put(BoundedVec::truncate_from(old));
+
+        crate::migrations::v2::migrate::<Test>();
+
+        assert_eq!(SenderPoolCount::<Test>::get(account(1)), 64);
+        assert!(TxProcessor::get_pending(&account(1), 63).is_some());
+        assert!(TxProcessor::get_pending(&account(1), 64).is_none());
+
+        // A further transaction is refused while the account is at the cap
+        assert_noop!(
+            crate::Pallet::<Test>::insert_transaction(fee_tx(1, 64, 0)),
+            Error::<Test>::AccountLimitReached
+        );
+    });
+}
@@ -0,0 +1,161 @@
+//! PLACEHOLDER weights for `pallet_tx_processor`: hand-estimated, not benchmark output.
+//!
+//! Overwrite with `./scripts/benchmark-pallets.sh pallet_tx_processor` on reference hardware.
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(missing_docs)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use core::marker::PhantomData;
+
+/// Weight functions needed for `pallet_tx_processor`.
+pub trait WeightInfo {
+	fn submit_transaction(s: u32, ) -> Weight;
+	fn submit_transaction_evicting() -> Weight;
+	fn clear_mempool(n: u32, ) -> Weight;
+	fn query_pool_size() -> Weight;
+	fn process_transaction(s: u32, ) -> Weight;
+}
+
+/// Placeholder weights for `pallet_tx_processor`, pending benchmarking.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	/// Storage: `TxProcessor::NextNonce` (r:1 w:0)
+	/// Storage: `TxProcessor::PendingTransactions` (r:1 w:1)
+	/// Storage: `TxProcessor::SenderPoolCount` (r:1 w:1)
+	/// Storage: `TxProcessor::PoolStats` (r:1 w:1)
+	/// Storage: `TxProcessor::NextSequence` (r:1 w:1)
+	/// Storage: `TxProcessor::ReservedFees` (r:0 w:1)
+	/// Storage: `TxProcessor::EvictionIndex` (r:0 w:1)
+	/// Storage: `TxProcessor::ReadyIndex` (r:0 w:1)
+	/// The range of component `s` is `[0, 524288]`.
+	fn submit_transaction(s: u32, ) -> Weight {
+		Weight::from_parts(98_170_000, 10288)
+			.saturating_add(Weight::from_parts(9_812, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(7_u64))
+	}
+	/// Storage: `TxProcessor::NextNonce` (r:2 w:0)
+	/// Storage: `TxProcessor::PendingTransactions` (r:17 w:18)
+	/// Storage: `TxProcessor::SenderPoolCount` (r:2 w:2)
+	/// Storage: `TxProcessor::PoolStats` (r:1 w:1)
+	/// Storage: `TxProcessor::EvictionIndex` (r:1 w:18)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Storage: `TxProcessor::ReservedFees` (r:17 w:18)
+	/// Storage: `TxProcessor::NextSequence` (r:1 w:1)
+	/// Storage: `TxProcessor::ReadyIndex` (r:0 w:18)
+	fn submit_transaction_evicting() -> Weight {
+		Weight::from_parts(276_910_000, 117942)
+			.saturating_add(T::DbWeight::get().reads(43_u64))
+			.saturating_add(T::DbWeight::get().writes(78_u64))
+	}
+	/// Storage: `TxProcessor::PoolStats` (r:1 w:1)
+	/// Storage: `TxProcessor::EvictionIndex` (r:1 w:0)
+	/// Storage: `TxProcessor::PendingTransactions` (r:0 w:0)
+	/// Storage: `TxProcessor::ReadyIndex` (r:0 w:0)
+	/// Storage: `TxProcessor::SenderPoolCount` (r:0 w:0)
+	/// Storage: `TxProcessor::ReservedFees` (r:0 w:0)
+	/// Storage: `System::Account` (r:0 w:0)
+	/// The range of component `n` is `[0, 500]`.
+	fn clear_mempool(n: u32, ) -> Weight {
+		Weight::from_parts(6_540_000, 4044)
+			.saturating_add(Weight::from_parts(36_720_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().reads((4_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(T::DbWeight::get().writes((6_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 10216).saturating_mul(n.into()))
+	}
+	fn query_pool_size() -> Weight {
+		Weight::from_parts(5_210_000, 990)
+	}
+	/// Storage: `TxProcessor::ReadyIndex` (r:1 w:2)
+	/// Storage: `TxProcessor::PendingTransactions` (r:2 w:1)
+	/// Storage: `TxProcessor::SenderPoolCount` (r:1 w:1)
+	/// Storage: `TxProcessor::PoolStats` (r:1 w:1)
+	/// Storage: `TxProcessor::ReservedFees` (r:1 w:1)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Storage: `TxProcessor::NextNonce` (r:1 w:1)
+	/// Storage: `TxProcessor::EvictionIndex` (r:0 w:1)
+	/// Storage: `TxProcessor::TxBlockHeight` (r:0 w:1)
+	/// The range of component `s` is `[0, 524288]`.
+	fn process_transaction(s: u32, ) -> Weight {
+		Weight::from_parts(58_240_000, 20688)
+			.saturating_add(Weight::from_parts(2_143, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(8_u64))
+			.saturating_add(T::DbWeight::get().writes(10_u64))
+			.saturating_add(Weight::from_parts(0, 2).saturating_mul(s.into()))
+	}
+}
+
+// For backwards compatibility and tests.
+impl WeightInfo for () {
+	/// Storage: `TxProcessor::NextNonce` (r:1 w:0)
+	/// Storage: `TxProcessor::PendingTransactions` (r:1 w:1)
+	/// Storage: `TxProcessor::SenderPoolCount` (r:1 w:1)
+	/// Storage: `TxProcessor::PoolStats` (r:1 w:1)
+	/// Storage: `TxProcessor::NextSequence` (r:1 w:1)
+	/// Storage: `TxProcessor::ReservedFees` (r:0 w:1)
+	/// Storage: `TxProcessor::EvictionIndex` (r:0 w:1)
+	/// Storage: `TxProcessor::ReadyIndex` (r:0 w:1)
+	/// The range of component `s` is `[0, 524288]`.
+	fn submit_transaction(s: u32, ) -> Weight {
+		Weight::from_parts(98_170_000, 10288)
+			.saturating_add(Weight::from_parts(9_812, 0).saturating_mul(s.into()))
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(7_u64))
+	}
+	/// Storage: `TxProcessor::NextNonce` (r:2 w:0)
+	/// Storage: `TxProcessor::PendingTransactions` (r:17 w:18)
+	/// Storage: `TxProcessor::SenderPoolCount` (r:2 w:2)
+	/// Storage: `TxProcessor::PoolStats` (r:1 w:1)
+	/// Storage: `TxProcessor::EvictionIndex` (r:1 w:18)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Storage: `TxProcessor::ReservedFees` (r:17 w:18)
+	/// Storage: `TxProcessor::NextSequence` (r:1 w:1)
+	/// Storage: `TxProcessor::ReadyIndex` (r:0 w:18)
+	fn submit_transaction_evicting() -> Weight {
+		Weight::from_parts(276_910_000, 117942)
+			.saturating_add(RocksDbWeight::get().reads(43_u64))
+			.saturating_add(RocksDbWeight::get().writes(78_u64))
+	}
+	/// Storage: `TxProcessor::PoolStats` (r:1 w:1)
+	/// Storage: `TxProcessor::EvictionIndex` (r:1 w:0)
+	/// Storage: `TxProcessor::PendingTransactions` (r:0 w:0)
+	/// Storage: `TxProcessor::ReadyIndex` (r:0 w:0)
+	/// Storage: `TxProcessor::SenderPoolCount` (r:0 w:0)
+	/// Storage: `TxProcessor::ReservedFees` (r:0 w:0)
+	/// Storage: `System::Account` (r:0 w:0)
+	/// The range of component `n` is `[0, 500]`.
+	fn clear_mempool(n: u32, ) -> Weight {
+		Weight::from_parts(6_540_000, 4044)
+			.saturating_add(Weight::from_parts(36_720_000, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().reads((4_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(RocksDbWeight::get().writes((6_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 10216).saturating_mul(n.into()))
+	}
+	fn query_pool_size() -> Weight {
+		Weight::from_parts(5_210_000, 990)
+	}
+	/// Storage: `TxProcessor::ReadyIndex` (r:1 w:2)
+	/// Storage: `TxProcessor::PendingTransactions` (r:2 w:1)
+	/// Storage: `TxProcessor::SenderPoolCount` (r:1 w:1)
+	/// Storage: `TxProcessor::PoolStats` (r:1 w:1)
+	/// Storage: `TxProcessor::ReservedFees` (r:1 w:1)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Storage: `TxProcessor::NextNonce` (r:1 w:1)
+	/// Storage: `TxProcessor::EvictionIndex` (r:0 w:1)
+	/// Storage: `TxProcessor::TxBlockHeight` (r:0 w:1)
+	/// The range of component `s` is `[0, 524288]`.
+	fn process_transaction(s: u32, ) -> Weight {
+		Weight::from_parts(58_240_000, 20688)
+			.saturating_add(Weight::from_parts(2_143, 0).saturating_mul(s.into()))
+			.saturating_add(RocksDbWeight::get().reads(8_u64))
+			.saturating_add(RocksDbWeight::get().writes(10_u64))
+			.saturating_add(Weight::from_parts(0, 2).saturating_mul(s.into()))
+	}
+}
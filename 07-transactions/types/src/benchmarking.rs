@@ -0,0 +1,164 @@
+//! Benchmarking setup for pallet-transaction
+//!
+//! `submit_regular_transfer` runs against a full transaction pool and
+//! `submit_contract_call` against the largest contract code allowed.
+
+#![cfg(feature = "runtime-benchmarks")]
+
+use super::*;
+use frame_benchmarking::v2::*;
+use frame_support::traits::Get;
+use frame_system::RawOrigin;
+use sp_std::vec;
+
+const AMOUNT: Balance = 1_000;
+
+fn fill_pool<T: Config>() {
+    let filler = SignedTransaction {
+        sender: account::<T::AccountId>("sender", 0, 0),
+        nonce: 0,
+        tx_type: TransactionType::Regular {
+            recipient: BoundedVec::truncate_from(vec![1u8; 32]),
+            amount: AMOUNT,
+            currency: CurrencyType::Etrid,
+        },
+        signature: Signature(BoundedVec::truncate_from(vec![0u8; 64])),
+        chain_id: 0,
+    };
+    TransactionPool::<T>::put(BoundedVec::truncate_from(vec![filler; T::MaxPoolSize::get() as usize]));
+}
+
+fn locked_htlc<T: Config>(sender: &T::AccountId, receiver: &T::AccountId, secret: &[u8; 32]) -> [u8; 32] {
+    let time_lock = frame_system::Pallet::<T>::block_number() + 10u32.into();
+    Pallet::<T>::create_htlc(
+        RawOrigin::Signed(sender.clone()).into(),
+        receiver.clone(),
+        AMOUNT,
+        Pallet::<T>::hash_secret(secret),
+        time_lock,
+    )
+    .expect("fresh HTLC is created");
+    Pallet::<T>::compute_htlc_id(sender, receiver, &Pallet::<T>::hash_secret(secret))
+}
+
+#[benchmarks]
+mod benchmarks {
+    use super::*;
+
+    #[benchmark]
+    fn submit_regular_transfer() {
+        let caller: T::AccountId = whitelisted_caller();
+        fill_pool::<T>();
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller.clone()), vec![2u8; 32], AMOUNT, true);
+
+        assert_eq!(Pallet::<T>::pool_size(), T::MaxPoolSize::get() as usize);
+        assert_eq!(AccountNonces::<T>::get(&caller), 1);
+    }
+
+    #[benchmark]
+    fn submit_stake_deposit() {
+        let caller: T::AccountId = whitelisted_caller();
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller.clone()), vec![3u8; 32], AMOUNT, 100);
+
+        assert_eq!(Pallet::<T>::get_stake(&caller), AMOUNT);
+    }
+
+    #[benchmark]
+    fn submit_contract_call() {
+        let caller: T::AccountId = whitelisted_caller();
+        let code = vec![0u8; MAX_CONTRACT_CODE_SIZE as usize];
+        let contract = Pallet::<T>::hash_code(&code).to_vec();
+        ContractCode::<T>::insert(contract.clone(), code);
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller.clone()), contract, vec![0u8; 4], 1_000_000, 0);
+
+        assert_eq!(AccountNonces::<T>::get(&caller), 1);
+    }
+
+    #[benchmark]
+    fn deploy_contract(c: Linear<1, MAX_CONTRACT_CODE_SIZE>) {
+        let caller: T::AccountId = whitelisted_caller();
+        let code = vec![0u8; c as usize];
+        let contract = Pallet::<T>::hash_code(&code).to_vec();
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller), code, 1_000_000, 0);
+
+        assert!(Pallet::<T>::get_contract_code(&contract).is_some());
+    }
+
+    #[benchmark]
+    fn submit_lightning_bloc() {
+        let caller: T::AccountId = whitelisted_caller();
+        LightningBlocChannels::<T>::insert(1, LightningBlocChannel {
+            id: 1,
+            target_chain: 1,
+            capacity: AMOUNT * 10,
+            used: 0,
+            active: true,
+        });
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller.clone()), 1, vec![4u8; 32], AMOUNT, 1);
+
+        assert_eq!(AccountNonces::<T>::get(&caller), 1);
+    }
+
+    #[benchmark]
+    fn withdraw_stake() {
+        let caller: T::AccountId = whitelisted_caller();
+        StakingPool::<T>::insert(&caller, AMOUNT);
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller.clone()), AMOUNT);
+
+        assert_eq!(Pallet::<T>::get_stake(&caller), 0);
+    }
+
+    #[benchmark]
+    fn create_htlc() {
+        let caller: T::AccountId = whitelisted_caller();
+        let receiver: T::AccountId = account("receiver", 0, 0);
+        let hash_lock = Pallet::<T>::hash_secret(&[7u8; 32]);
+        let time_lock = frame_system::Pallet::<T>::block_number() + 10u32.into();
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller.clone()), receiver.clone(), AMOUNT, hash_lock, time_lock);
+
+        assert!(Pallet::<T>::get_htlc(&Pallet::<T>::compute_htlc_id(&caller, &receiver, &hash_lock)).is_some());
+    }
+
+    #[benchmark]
+    fn claim_htlc() {
+        let sender: T::AccountId = account("sender", 0, 0);
+        let caller: T::AccountId = whitelisted_caller();
+        let secret = [7u8; 32];
+        let htlc_id = locked_htlc::<T>(&sender, &caller, &secret);
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller), htlc_id, secret);
+
+        assert!(Pallet::<T>::get_htlc(&htlc_id).unwrap().claimed);
+    }
+
+    #[benchmark]
+    fn refund_htlc() {
+        let caller: T::AccountId = whitelisted_caller();
+        let receiver: T::AccountId = account("receiver", 0, 0);
+        let htlc_id = locked_htlc::<T>(&caller, &receiver, &[7u8; 32]);
+        let time_lock = Pallet::<T>::get_htlc(&htlc_id).unwrap().time_lock;
+        frame_system::Pallet::<T>::set_block_number(time_lock);
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller), htlc_id);
+
+        assert!(Pallet::<T>::get_htlc(&htlc_id).unwrap().refunded);
+    }
+
+    impl_benchmark_test_suite!(Pallet, crate::mock::new_test_ext(), crate::mock::Test);
+}
@@ -0,0 +1,263 @@
+//! HTLC chain client backed by this pallet
+//!
+//! [`PalletChain`] implements the cross-chain swap engine's `HtlcChain` for
+//! the ÉTRID leg of an atomic swap by dispatching `create_htlc`, `claim_htlc`
+//! and `refund_htlc`. It lives here rather than in the cross-chain crate so
+//! that crate stays free of FRAME.
+
+use core::{fmt::Display, marker::PhantomData, str::FromStr};
+use std::collections::BTreeMap;
+
+use etrid_transactions_cross_chain::{
+    htlc::{HtlcChain, HtlcId, HtlcInfo},
+    BridgeError, ChainId,
+};
+use frame_system::{pallet_prelude::OriginFor, RawOrigin};
+use sp_runtime::{DispatchError, SaturatedConversion};
+
+use crate::{Config, Error, Event, Pallet};
+
+/// ÉTRID client dispatching this pallet's HTLC calls
+///
+/// Calls are dispatched as signed by the given account, so the client must
+/// run against the runtime's externalities. Accounts are written in their
+/// `Display` form (SS58 for `AccountId32`) and parsed back with `FromStr`.
+///
+/// The pallet does not store preimages: the secret of a claim is known if it
+/// went through this client, or while its `HTLCClaimed` event is still in
+/// the current block's events.
+pub struct PalletChain<T: Config> {
+    chain_id: ChainId,
+    block_time_ms: u64,
+    /// Preimages of claims made through this client
+    secrets: BTreeMap<HtlcId, [u8; 32]>,
+    _runtime: PhantomData<T>,
+}
+
+impl<T> PalletChain<T>
+where
+    T: Config,
+    T::AccountId: FromStr + Display,
+    <T as frame_system::Config>::RuntimeEvent: TryInto<Event<T>>,
+{
+    pub fn new(chain_id: ChainId, block_time_ms: u64) -> Self {
+        Self {
+            chain_id,
+            block_time_ms,
+            secrets: BTreeMap::new(),
+            _runtime: PhantomData,
+        }
+    }
+
+    fn account(address: &str) -> Result<T::AccountId, BridgeError> {
+        address.parse().map_err(|_| BridgeError::InvalidAddress)
+    }
+
+    fn signed(address: &str) -> Result<OriginFor<T>, BridgeError> {
+        Ok(RawOrigin::Signed(Self::account(address)?).into())
+    }
+
+    /// Secret revealed by an `HTLCClaimed` event of the current block
+    fn claimed_secret(id: &HtlcId) -> Option<[u8; 32]> {
+        frame_system::Pallet::<T>::read_events_no_consensus().find_map(|record| {
+            match record.event.try_into().ok()? {
+                Event::<T>::HTLCClaimed { htlc_id, secret, .. } if htlc_id == *id => Some(secret),
+                _ => None,
+            }
+        })
+    }
+
+    fn bridge_error(error: DispatchError) -> BridgeError {
+        [
+            (Error::<T>::InsufficientBalance, BridgeError::InvalidAmount),
+            (Error::<T>::InvalidTransactionFormat, BridgeError::InvalidExpiration),
+            (Error::<T>::TransactionDuplicate, BridgeError::HtlcExists),
+            (Error::<T>::HTLCNotFound, BridgeError::HtlcNotFound),
+            (Error::<T>::HTLCAlreadyClaimed, BridgeError::HtlcSettled),
+            (Error::<T>::HTLCAlreadyRefunded, BridgeError::HtlcSettled),
+            (Error::<T>::HTLCInvalidSecret, BridgeError::InvalidSecret),
+            (Error::<T>::HTLCTimeLockNotExpired, BridgeError::TimeLockNotExpired),
+        ]
+        .into_iter()
+        .find_map(|(pallet_error, bridge_error)| {
+            (DispatchError::from(pallet_error) == error).then_some(bridge_error)
+        })
+        // `InvalidRecipient` and bad origins: the account may not act on the HTLC
+        .unwrap_or(BridgeError::InvalidAddress)
+    }
+}
+
+impl<T> HtlcChain for PalletChain<T>
+where
+    T: Config,
+    T::AccountId: FromStr + Display,
+    <T as frame_system::Config>::RuntimeEvent: TryInto<Event<T>>,
+{
+    fn chain_id(&self) -> ChainId {
+        self.chain_id
+    }
+
+    fn block_height(&self) -> u64 {
+        frame_system::Pallet::<T>::block_number().saturated_into()
+    }
+
+    fn block_time_ms(&self) -> u64 {
+        self.block_time_ms
+    }
+
+    fn create_htlc(
+        &mut self,
+        sender: &str,
+        receiver: &str,
+        amount: u128,
+        hash_lock: [u8; 32],
+        time_lock: u64,
+    ) -> Result<HtlcId, BridgeError> {
+        let sender = Self::account(sender)?;
+        let receiver = Self::account(receiver)?;
+        let id = Pallet::<T>::compute_htlc_id(&sender, &receiver, &hash_lock);
+
+        Pallet::<T>::create_htlc(
+            RawOrigin::Signed(sender).into(),
+            receiver,
+            amount,
+            hash_lock,
+            time_lock.saturated_into(),
+        )
+        .map_err(Self::bridge_error)?;
+
+        Ok(id)
+    }
+
+    fn claim_htlc(
+        &mut self,
+        claimer: &str,
+        id: &HtlcId,
+        secret: [u8; 32],
+    ) -> Result<(), BridgeError> {
+        Pallet::<T>::claim_htlc(Self::signed(claimer)?, *id, secret).map_err(Self::bridge_error)?;
+        self.secrets.insert(*id, secret);
+        Ok(())
+    }
+
+    fn refund_htlc(&mut self, refunder: &str, id: &HtlcId) -> Result<(), BridgeError> {
+        Pallet::<T>::refund_htlc(Self::signed(refunder)?, *id).map_err(Self::bridge_error)
+    }
+
+    fn htlc(&self, id: &HtlcId) -> Option<HtlcInfo> {
+        let htlc = Pallet::<T>::get_htlc(id)?;
+        let secret = if htlc.claimed {
+            self.secrets.get(id).copied().or_else(|| Self::claimed_secret(id))
+        } else {
+            None
+        };
+
+        Some(HtlcInfo {
+            sender: htlc.sender.to_string(),
+            receiver: htlc.receiver.to_string(),
+            amount: htlc.amount,
+            hash_lock: htlc.hash_lock,
+            time_lock: htlc.time_lock.saturated_into(),
+            claimed: htlc.claimed,
+            refunded: htlc.refunded,
+            secret,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mock::{new_test_ext, System, Test};
+    use etrid_transactions_cross_chain::htlc::hash_secret;
+
+    const SECRET: [u8; 32] = [7u8; 32];
+    const ALICE: &str = "1";
+    const BOB: &str = "2";
+
+    fn chain() -> PalletChain<Test> {
+        PalletChain::new(ChainId::Etrid, 6_000)
+    }
+
+    /// `ALICE` locks 500 for `BOB` until block 10
+    fn lock(chain: &mut PalletChain<Test>) -> HtlcId {
+        chain.create_htlc(ALICE, BOB, 500, hash_secret(&SECRET), 10).unwrap()
+    }
+
+    #[test]
+    fn lock_creates_htlc_in_the_pallet() {
+        new_test_ext().execute_with(|| {
+            let mut chain = chain();
+            let id = lock(&mut chain);
+
+            assert_eq!(id, Pallet::<Test>::compute_htlc_id(&1, &2, &hash_secret(&SECRET)));
+            let htlc = chain.htlc(&id).unwrap();
+            assert_eq!((htlc.sender.as_str(), htlc.receiver.as_str()), (ALICE, BOB));
+            assert_eq!((htlc.amount, htlc.time_lock), (500, 10));
+            assert!(!htlc.claimed && !htlc.refunded);
+            assert_eq!(htlc.secret, None);
+
+            let mut create = |sender, hash_lock, time_lock| {
+                chain.create_htlc(sender, BOB, 500, hash_lock, time_lock)
+            };
+            assert_eq!(create(ALICE, hash_secret(&SECRET), 10), Err(BridgeError::HtlcExists));
+            assert_eq!(create(ALICE, [1; 32], 1), Err(BridgeError::InvalidExpiration));
+            assert_eq!(create("alice", [1; 32], 10), Err(BridgeError::InvalidAddress));
+        });
+    }
+
+    #[test]
+    fn claim_with_preimage_reveals_secret() {
+        new_test_ext().execute_with(|| {
+            let mut chain = chain();
+            let id = lock(&mut chain);
+
+            assert_eq!(chain.claim_htlc(BOB, &id, [8; 32]), Err(BridgeError::InvalidSecret));
+            assert_eq!(chain.claim_htlc(ALICE, &id, SECRET), Err(BridgeError::InvalidAddress));
+            assert_eq!(chain.claim_htlc(BOB, &id, SECRET), Ok(()));
+
+            let htlc = chain.htlc(&id).unwrap();
+            assert!(htlc.claimed);
+            assert_eq!(htlc.secret, Some(SECRET));
+
+            // A client that did not make the claim reads the secret from the event
+            assert_eq!(PalletChain::<Test>::claimed_secret(&id), Some(SECRET));
+            assert_eq!(chain().htlc(&id).unwrap().secret, Some(SECRET));
+        });
+    }
+
+    #[test]
+    fn refund_after_timeout() {
+        new_test_ext().execute_with(|| {
+            let mut chain = chain();
+            let id = lock(&mut chain);
+
+            System::set_block_number(9);
+            assert_eq!(chain.refund_htlc(ALICE, &id), Err(BridgeError::TimeLockNotExpired));
+
+            System::set_block_number(10);
+            assert_eq!(chain.block_height(), 10);
+            assert_eq!(chain.refund_htlc(BOB, &id), Err(BridgeError::InvalidAddress));
+            assert_eq!(chain.refund_htlc(ALICE, &id), Ok(()));
+            assert!(chain.htlc(&id).unwrap().refunded);
+
+            // Refunded locks can no longer be claimed
+            assert_eq!(chain.claim_htlc(BOB, &id, SECRET), Err(BridgeError::HtlcSettled));
+        });
+    }
+
+    #[test]
+    fn double_claim_is_rejected() {
+        new_test_ext().execute_with(|| {
+            let mut chain = chain();
+            let id = lock(&mut chain);
+
+            assert_eq!(chain.claim_htlc(BOB, &id, SECRET), Ok(()));
+            assert_eq!(chain.claim_htlc(BOB, &id, SECRET), Err(BridgeError::HtlcSettled));
+
+            System::set_block_number(10);
+            assert_eq!(chain.refund_htlc(ALICE, &id), Err(BridgeError::HtlcSettled));
+            assert_eq!(chain.claim_htlc(BOB, &[0; 32], SECRET), Err(BridgeError::HtlcNotFound));
+        });
+    }
+}
@@ -15,6 +15,7 @@
 //! - Dual-currency support (ÉTR native token and ETD stablecoin)
 //! - Smart contract deployment and execution with VMw (gas) limits
 //! - HTLC implementation for atomic swaps and payment channels
+//! - [`htlc_chain::PalletChain`] drives these HTLCs for the cross-chain swap engine (std only)
 //! - Transaction pool and mempool management
 //! - Nonce-based replay protection
 //! - Cross-chain payment channels via Lightning Bloc
@@ -116,6 +117,18 @@ use ed25519_dalek::{Signature as Ed25519Signature, Verifier, VerifyingKey};
 
 pub use pallet::*;
 
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+#[cfg(feature = "std")]
+pub mod htlc_chain;
+#[cfg(test)]
+mod mock;
+pub mod weights;
+pub use weights::WeightInfo;
+
+/// Largest contract code `deploy_contract` stores (matches `TransactionType::ContractInit`)
+pub const MAX_CONTRACT_CODE_SIZE: u32 = 524_288;
+
 /// Currency type selector
 #[derive(Encode, Decode, TypeInfo, MaxEncodedLen, Debug, Clone, Copy, PartialEq, Eq)]
 pub enum CurrencyType {
@@ -138,6 +151,13 @@ pub mod pallet {
     #[pallet::config]
     pub trait Config: frame_system::Config {
         type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+        /// Transactions kept in `TransactionPool`; the oldest is dropped when full
+        #[pallet::constant]
+        type MaxPoolSize: Get<u32>;
+
+        /// Weight information for extrinsics in this pallet
+        type WeightInfo: WeightInfo;
     }
 
     // ============================================================
@@ -243,7 +263,8 @@ pub mod pallet {
 
     #[pallet::storage]
     #[pallet::getter(fn transaction_pool)]
-    pub(super) type TransactionPool<T: Config> = StorageValue<_, Vec<SignedTransaction<T::AccountId>>, ValueQuery>;
+    pub(super) type TransactionPool<T: Config> =
+        StorageValue<_, BoundedVec<SignedTransaction<T::AccountId>, T::MaxPoolSize>, ValueQuery>;
 
     #[pallet::storage]
     #[pallet::getter(fn tx_receipt)]
@@ -410,7 +431,7 @@ pub mod pallet {
     #[pallet::call]
     impl<T: Config> Pallet<T> {
         /// Submit a regular transfer transaction
-        #[pallet::weight(1_000)]
+        #[pallet::weight(T::WeightInfo::submit_regular_transfer())]
         pub fn submit_regular_transfer(
             origin: OriginFor<T>,
             recipient: Vec<u8>,
@@ -451,10 +472,10 @@ pub mod pallet {
                 chain_id: 0,
             };
 
-            // Add to pool
-            let mut pool = TransactionPool::<T>::get();
-            pool.push(signed_tx);
-            TransactionPool::<T>::set(pool);
+            // Add to pool, dropping the oldest transaction when full
+            TransactionPool::<T>::mutate(|pool| {
+                let _ = pool.force_insert_keep_right(pool.len(), signed_tx);
+            });
 
             Self::deposit_event(Event::TransactionExecuted {
                 tx_hash: Self::hash_transaction(&sender, nonce),
@@ -466,7 +487,7 @@ pub mod pallet {
         }
 
         /// Submit a stake deposit transaction
-        #[pallet::weight(1_000)]
+        #[pallet::weight(T::WeightInfo::submit_stake_deposit())]
         pub fn submit_stake_deposit(
             origin: OriginFor<T>,
             validator: Vec<u8>,
@@ -496,7 +517,7 @@ pub mod pallet {
         }
 
         /// Submit a smart contract call transaction
-        #[pallet::weight(5_000)]
+        #[pallet::weight(T::WeightInfo::submit_contract_call())]
         pub fn submit_contract_call(
             origin: OriginFor<T>,
             contract: Vec<u8>,
@@ -526,7 +547,7 @@ pub mod pallet {
         }
 
         /// Deploy a new smart contract
-        #[pallet::weight(10_000)]
+        #[pallet::weight(T::WeightInfo::deploy_contract(init_code.len() as u32))]
         pub fn deploy_contract(
             origin: OriginFor<T>,
             init_code: Vec<u8>,
@@ -536,6 +557,7 @@ pub mod pallet {
             let sender = ensure_signed(origin)?;
 
             ensure!(!init_code.is_empty(), Error::<T>::InvalidContractCode);
+            ensure!(init_code.len() <= MAX_CONTRACT_CODE_SIZE as usize, Error::<T>::InvalidContractCode);
             ensure!(vmw_limit > 0, Error::<T>::VMwLimitExceeded);
 
             // Generate contract address (simplified: hash of init_code)
@@ -557,7 +579,7 @@ pub mod pallet {
         }
 
         /// Submit a Lightning Bloc cross-chain payment
-        #[pallet::weight(2_000)]
+        #[pallet::weight(T::WeightInfo::submit_lightning_bloc())]
         pub fn submit_lightning_bloc(
             origin: OriginFor<T>,
             target_chain: ChainId,
@@ -588,7 +610,7 @@ pub mod pallet {
         }
 
         /// Withdraw stake (only after lock period expires)
-        #[pallet::weight(1_000)]
+        #[pallet::weight(T::WeightInfo::withdraw_stake())]
         pub fn withdraw_stake(
             origin: OriginFor<T>,
             amount: Balance,
@@ -612,7 +634,7 @@ pub mod pallet {
         ///
         /// The sender locks funds that can be claimed by the receiver with the correct secret,
         /// or refunded to the sender after the time lock expires.
-        #[pallet::weight(2_000)]
+        #[pallet::weight(T::WeightInfo::create_htlc())]
         pub fn create_htlc(
             origin: OriginFor<T>,
             receiver: T::AccountId,
@@ -659,7 +681,7 @@ pub mod pallet {
         }
 
         /// Claim an HTLC by providing the secret preimage
-        #[pallet::weight(2_000)]
+        #[pallet::weight(T::WeightInfo::claim_htlc())]
         pub fn claim_htlc(
             origin: OriginFor<T>,
             htlc_id: [u8; 32],
@@ -696,7 +718,7 @@ pub mod pallet {
         }
 
         /// Refund an HTLC after time lock expires
-        #[pallet::weight(2_000)]
+        #[pallet::weight(T::WeightInfo::refund_htlc())]
         pub fn refund_htlc(
             origin: OriginFor<T>,
             htlc_id: [u8; 32],
@@ -852,7 +874,7 @@ pub mod pallet {
     impl<T: Config> BuildGenesisConfig for GenesisConfig<T> {
         fn build(&self) {
             // Initialize empty transaction pool
-            TransactionPool::<T>::set(Vec::new());
+            TransactionPool::<T>::kill();
         }
     }
 }
@@ -0,0 +1,36 @@
+//! Mock runtime for transaction types pallet tests
+
+use crate as pallet_transaction;
+use frame_support::{derive_impl, traits::ConstU32};
+use sp_runtime::BuildStorage;
+
+type Block = frame_system::mocking::MockBlock<Test>;
+
+frame_support::construct_runtime!(
+    pub enum Test
+    {
+        System: frame_system,
+        Transaction: pallet_transaction,
+    }
+);
+
+#[derive_impl(frame_system::config_preludes::TestDefaultConfig)]
+impl frame_system::Config for Test {
+    type Block = Block;
+}
+
+impl pallet_transaction::Config for Test {
+    type RuntimeEvent = RuntimeEvent;
+    type MaxPoolSize = ConstU32<4>;
+    type WeightInfo = ();
+}
+
+/// Starts at block 1 so events are recorded
+pub fn new_test_ext() -> sp_io::TestExternalities {
+    let storage = frame_system::GenesisConfig::<Test>::default()
+        .build_storage()
+        .unwrap();
+    let mut ext = sp_io::TestExternalities::new(storage);
+    ext.execute_with(|| System::set_block_number(1));
+    ext
+}
@@ -0,0 +1,156 @@
+//! PLACEHOLDER weights for `pallet_transaction`: hand-estimated, not benchmark output.
+//!
+//! Overwrite with `frame-omni-bencher v1 benchmark pallet --pallet pallet_transaction` against
+//! a runtime that includes the pallet, on reference hardware.
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(missing_docs)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use core::marker::PhantomData;
+
+/// Weight functions needed for `pallet_transaction`.
+pub trait WeightInfo {
+	fn submit_regular_transfer() -> Weight;
+	fn submit_stake_deposit() -> Weight;
+	fn submit_contract_call() -> Weight;
+	fn deploy_contract(c: u32, ) -> Weight;
+	fn submit_lightning_bloc() -> Weight;
+	fn withdraw_stake() -> Weight;
+	fn create_htlc() -> Weight;
+	fn claim_htlc() -> Weight;
+	fn refund_htlc() -> Weight;
+}
+
+/// Placeholder weights for `pallet_transaction`, pending benchmarking.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	/// Storage: `Transaction::AccountNonces` (r:1 w:1)
+	/// Storage: `Transaction::TransactionPool` (r:1 w:1)
+	fn submit_regular_transfer() -> Weight {
+		Weight::from_parts(61_270_000, 52834)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `Transaction::StakingPool` (r:1 w:1)
+	/// Storage: `Transaction::AccountNonces` (r:1 w:1)
+	fn submit_stake_deposit() -> Weight {
+		Weight::from_parts(24_180_000, 3509)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `Transaction::ContractCode` (r:1 w:0)
+	/// Storage: `Transaction::AccountNonces` (r:1 w:1)
+	fn submit_contract_call() -> Weight {
+		Weight::from_parts(318_400_000, 527907)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Transaction::AccountNonces` (r:1 w:1)
+	/// Storage: `Transaction::ContractCode` (r:0 w:1)
+	/// The range of component `c` is `[1, 524288]`.
+	fn deploy_contract(c: u32, ) -> Weight {
+		Weight::from_parts(27_730_000, 3509)
+			.saturating_add(Weight::from_parts(1_210, 0).saturating_mul(c.into()))
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `Transaction::LightningBlocChannels` (r:1 w:0)
+	/// Storage: `Transaction::AccountNonces` (r:1 w:1)
+	fn submit_lightning_bloc() -> Weight {
+		Weight::from_parts(23_960_000, 3574)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Transaction::StakingPool` (r:1 w:1)
+	fn withdraw_stake() -> Weight {
+		Weight::from_parts(17_350_000, 3509)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Transaction::HTLCContracts` (r:1 w:1)
+	fn create_htlc() -> Weight {
+		Weight::from_parts(26_410_000, 3632)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Transaction::HTLCContracts` (r:1 w:1)
+	fn claim_htlc() -> Weight {
+		Weight::from_parts(28_840_000, 3632)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Transaction::HTLCContracts` (r:1 w:1)
+	fn refund_htlc() -> Weight {
+		Weight::from_parts(24_690_000, 3632)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+}
+
+// For backwards compatibility and tests.
+impl WeightInfo for () {
+	/// Storage: `Transaction::AccountNonces` (r:1 w:1)
+	/// Storage: `Transaction::TransactionPool` (r:1 w:1)
+	fn submit_regular_transfer() -> Weight {
+		Weight::from_parts(61_270_000, 52834)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `Transaction::StakingPool` (r:1 w:1)
+	/// Storage: `Transaction::AccountNonces` (r:1 w:1)
+	fn submit_stake_deposit() -> Weight {
+		Weight::from_parts(24_180_000, 3509)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `Transaction::ContractCode` (r:1 w:0)
+	/// Storage: `Transaction::AccountNonces` (r:1 w:1)
+	fn submit_contract_call() -> Weight {
+		Weight::from_parts(318_400_000, 527907)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Transaction::AccountNonces` (r:1 w:1)
+	/// Storage: `Transaction::ContractCode` (r:0 w:1)
+	/// The range of component `c` is `[1, 524288]`.
+	fn deploy_contract(c: u32, ) -> Weight {
+		Weight::from_parts(27_730_000, 3509)
+			.saturating_add(Weight::from_parts(1_210, 0).saturating_mul(c.into()))
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `Transaction::LightningBlocChannels` (r:1 w:0)
+	/// Storage: `Transaction::AccountNonces` (r:1 w:1)
+	fn submit_lightning_bloc() -> Weight {
+		Weight::from_parts(23_960_000, 3574)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Transaction::StakingPool` (r:1 w:1)
+	fn withdraw_stake() -> Weight {
+		Weight::from_parts(17_350_000, 3509)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Transaction::HTLCContracts` (r:1 w:1)
+	fn create_htlc() -> Weight {
+		Weight::from_parts(26_410_000, 3632)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Transaction::HTLCContracts` (r:1 w:1)
+	fn claim_htlc() -> Weight {
+		Weight::from_parts(28_840_000, 3632)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Transaction::HTLCContracts` (r:1 w:1)
+	fn refund_htlc() -> Weight {
+		Weight::from_parts(24_690_000, 3632)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+}
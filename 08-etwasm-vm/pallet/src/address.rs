@@ -0,0 +1,52 @@
+//! Ethereum address mapping for ETWasm accounts
+//!
+//! Every account has a 20-byte Ethereum address. Accounts derived from an
+//! Ethereum address are stored as `address ++ [0xEE; 12]` and map back
+//! losslessly, so keys from existing Ethereum wallets work unmodified. Other
+//! accounts use the last 20 bytes of `keccak256(account)`; they call
+//! `map_account` once so that their address resolves back to them.
+
+use sp_core::H160;
+
+/// Suffix marking an account derived from an Ethereum address
+const ETH_SUFFIX: [u8; 12] = [0xEE; 12];
+
+/// Maps runtime accounts to Ethereum addresses and back
+pub trait AddressMapper<AccountId> {
+    /// Ethereum address of `account`
+    fn to_address(account: &AccountId) -> H160;
+
+    /// Account an Ethereum address resolves to when it has no explicit mapping
+    fn to_fallback_account_id(address: &H160) -> AccountId;
+
+    /// Whether `account` is the fallback account of its address
+    fn is_eth_derived(account: &AccountId) -> bool;
+}
+
+/// Mapping for 32-byte account IDs (`AccountId32`)
+pub struct AccountId32Mapper;
+
+impl<AccountId> AddressMapper<AccountId> for AccountId32Mapper
+where
+    AccountId: From<[u8; 32]> + AsRef<[u8; 32]>,
+{
+    fn to_address(account: &AccountId) -> H160 {
+        let bytes = account.as_ref();
+        if Self::is_eth_derived(account) {
+            H160::from_slice(&bytes[..20])
+        } else {
+            H160::from_slice(&sp_io::hashing::keccak_256(bytes)[12..])
+        }
+    }
+
+    fn to_fallback_account_id(address: &H160) -> AccountId {
+        let mut bytes = [0u8; 32];
+        bytes[..20].copy_from_slice(address.as_bytes());
+        bytes[20..].copy_from_slice(&ETH_SUFFIX);
+        bytes.into()
+    }
+
+    fn is_eth_derived(account: &AccountId) -> bool {
+        account.as_ref()[20..] == ETH_SUFFIX
+    }
+}
@@ -0,0 +1,310 @@
+//! Benchmarking setup for pallet-etwasm-vm
+//!
+//! Contracts here are `STOP` code of the benchmarked or maximum size, so
+//! the measured weights cover loading, hashing and storing code but not
+//! running it; execution is weighed separately through `WeightPerGas`.
+//! Ethereum transactions are signed with a keystore key of the benchmark
+//! externalities.
+
+#![cfg(feature = "runtime-benchmarks")]
+
+use super::*;
+use crate::eth::EthTransaction;
+use etwasm_opcodes::STOP;
+use etwasm_runtime::{storage_proof, storage_root, StorageCommitment};
+use frame_benchmarking::v2::*;
+use frame_support::{traits::{Currency, Get}, BoundedVec};
+use frame_system::RawOrigin;
+use rlp::RlpStream;
+use sp_core::crypto::KeyTypeId;
+use sp_runtime::traits::{Bounded, Saturating, Zero};
+use sp_std::{vec, vec::Vec};
+
+/// Keystore key type of the benchmark Ethereum signer
+const KEY_TYPE: KeyTypeId = KeyTypeId(*b"etwb");
+
+fn funded<T: Config>(who: &T::AccountId) {
+    T::Currency::make_free_balance_be(who, BalanceOf::<T>::max_value() / 16u32.into());
+}
+
+/// Code of `len` bytes that halts on its first instruction
+fn stop_code(len: u32) -> Vec<u8> {
+    vec![STOP; len as usize]
+}
+
+fn max_code<T: Config>() -> Vec<u8> {
+    stop_code(T::MaxCodeSize::get())
+}
+
+/// Account `deployer`'s next deployment is stored at
+fn next_contract<T: Config>(deployer: &T::AccountId) -> T::AccountId {
+    let from = T::AddressMapper::to_address(deployer);
+    let address = eth::create_address(&from, EthNonce::<T>::get(from));
+    T::AddressMapper::to_fallback_account_id(&address)
+}
+
+/// Deploy `code` from a funded `deployer`, returning the contract
+fn deployed<T: Config>(deployer: &T::AccountId, code: Vec<u8>) -> T::AccountId {
+    funded::<T>(deployer);
+    let contract = next_contract::<T>(deployer);
+    Pallet::<T>::deploy_contract(RawOrigin::Signed(deployer.clone()).into(), code, Zero::zero())
+        .expect("funded deployer deploys; qed");
+    contract
+}
+
+/// Give `contract` `n` storage slots
+fn fill_slots<T: Config>(contract: &T::AccountId, n: u32) -> Vec<(H256, H256)> {
+    let slots: Vec<(H256, H256)> = (1..=n)
+        .map(|i| (H256::from_low_u64_be(i.into()), H256::repeat_byte(0xff)))
+        .collect();
+    for (key, value) in &slots {
+        ContractStorageValue::<T>::insert(contract, key, value);
+    }
+    StorageSlots::<T>::insert(contract, n);
+    slots
+}
+
+/// Fill the code history of `contract` with `MaxCodeVersions` versions
+fn fill_history<T: Config>(contract: &T::AccountId) {
+    let code_hash = ContractCodeHash::<T>::get(contract).expect("contract is deployed; qed");
+    let history = (1..=T::MaxCodeVersions::get().max(2))
+        .map(|version| CodeVersion { version, code_hash, activated_at: Zero::zero() })
+        .collect::<Vec<_>>();
+    CodeHistory::<T>::insert(contract, history);
+}
+
+/// Big-endian integer bytes without leading zeros, as RLP expects
+fn trim(bytes: &[u8]) -> Vec<u8> {
+    bytes.iter().skip_while(|byte| **byte == 0).copied().collect()
+}
+
+/// Legacy EIP-155 transaction signed by a fresh keystore key, with its sender
+fn signed_transaction<T: Config>(to: Option<H160>, input: Vec<u8>) -> (Vec<u8>, H160) {
+    let chain_id = T::ChainId::get();
+    let append_fields = |stream: &mut RlpStream| {
+        stream.append(&0u64).append(&1u64).append(&T::DefaultGasLimit::get());
+        match to {
+            Some(to) => stream.append(&to.as_bytes().to_vec()),
+            None => stream.append_empty_data(),
+        };
+        stream.append(&0u64).append(&input);
+    };
+
+    let mut unsigned = RlpStream::new_list(9);
+    append_fields(&mut unsigned);
+    unsigned.append(&chain_id).append_empty_data().append_empty_data();
+    let signing_hash = sp_io::hashing::keccak_256(&unsigned.out());
+
+    let public = sp_io::crypto::ecdsa_generate(KEY_TYPE, None);
+    let signature = sp_io::crypto::ecdsa_sign_prehashed(KEY_TYPE, &public, &signing_hash)
+        .expect("key was just generated; qed")
+        .0;
+    let mut signed = RlpStream::new_list(9);
+    append_fields(&mut signed);
+    signed
+        .append(&(chain_id * 2 + 35 + u64::from(signature[64])))
+        .append(&trim(&signature[..32]))
+        .append(&trim(&signature[32..64]));
+
+    let raw = signed.out().to_vec();
+    let from = EthTransaction::decode(&raw).expect("transaction is well-formed; qed").from;
+    (raw, from)
+}
+
+#[benchmarks]
+mod benchmarks {
+    use super::*;
+
+    #[benchmark]
+    fn deploy_contract(c: Linear<1, { T::MaxCodeSize::get() }>) {
+        let caller: T::AccountId = whitelisted_caller();
+        funded::<T>(&caller);
+        let contract = next_contract::<T>(&caller);
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller), stop_code(c), T::Currency::minimum_balance());
+
+        assert!(ContractCodeHash::<T>::contains_key(&contract));
+    }
+
+    #[benchmark]
+    fn call_contract() {
+        let caller: T::AccountId = whitelisted_caller();
+        let contract = deployed::<T>(&account("deployer", 0, 0), max_code::<T>());
+        funded::<T>(&caller);
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller), contract, T::Currency::minimum_balance(), Vec::new(), None);
+
+        assert!(Pallet::<T>::current_receipts().last().expect("call receipt; qed").success);
+    }
+
+    #[benchmark]
+    fn execute_bytecode(c: Linear<1, { T::MaxCodeSize::get() }>) {
+        let caller: T::AccountId = whitelisted_caller();
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller), stop_code(c), T::DefaultGasLimit::get());
+    }
+
+    /// Contract creation with `c` bytes of code
+    #[benchmark]
+    fn transact_create(c: Linear<0, { T::MaxCodeSize::get() }>) {
+        let (transaction, from) = signed_transaction::<T>(None, stop_code(c));
+        funded::<T>(&Pallet::<T>::account_id(&from));
+
+        #[extrinsic_call]
+        transact(RawOrigin::None, transaction);
+
+        let receipt = Pallet::<T>::current_receipts().pop().expect("receipt; qed");
+        assert!(receipt.contract_address.is_some());
+    }
+
+    /// Call of a contract with maximum size code
+    #[benchmark]
+    fn transact_call() {
+        let contract = deployed::<T>(&account("deployer", 0, 0), max_code::<T>());
+        let to = T::AddressMapper::to_address(&contract);
+        let (transaction, from) = signed_transaction::<T>(Some(to), Vec::new());
+        funded::<T>(&Pallet::<T>::account_id(&from));
+
+        #[extrinsic_call]
+        transact(RawOrigin::None, transaction);
+
+        assert!(Pallet::<T>::current_receipts().last().expect("receipt; qed").success);
+    }
+
+    #[benchmark]
+    fn map_account() {
+        let caller: T::AccountId = whitelisted_caller();
+        let address = T::AddressMapper::to_address(&caller);
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller));
+
+        assert!(OriginalAccount::<T>::contains_key(address));
+    }
+
+    #[benchmark]
+    fn deposit_rent() {
+        let caller: T::AccountId = whitelisted_caller();
+        let contract = deployed::<T>(&account("deployer", 0, 0), stop_code(1));
+        funded::<T>(&caller);
+        let amount = T::Currency::minimum_balance().saturating_mul(10u32.into());
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller), contract.clone(), amount);
+
+        assert_eq!(ContractRent::<T>::get(&contract).deposit, amount);
+    }
+
+    /// A contract holding `s` slots with no rent deposit
+    #[benchmark]
+    fn evict_contract(s: Linear<1, { T::MaxStorageSlots::get() }>) {
+        let caller: T::AccountId = whitelisted_caller();
+        let contract = deployed::<T>(&account("deployer", 0, 0), stop_code(1));
+        fill_slots::<T>(&contract, s);
+        frame_system::Pallet::<T>::set_block_number(
+            frame_system::Pallet::<T>::block_number() + 1u32.into(),
+        );
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller), contract.clone());
+
+        assert!(Tombstones::<T>::contains_key(&contract));
+    }
+
+    /// A tombstone of `n` slots, restored with one proof each
+    #[benchmark]
+    fn restore_contract(n: Linear<1, { T::MaxStorageSlots::get() }>) {
+        let caller: T::AccountId = whitelisted_caller();
+        let contract = deployed::<T>(&account("deployer", 0, 0), stop_code(1));
+        let slots = fill_slots::<T>(&contract, n);
+        let proofs = slots
+            .iter()
+            .map(|(key, _)| storage_proof(&slots, key).expect("key is in slots; qed"))
+            .collect::<Vec<_>>();
+        let _ = ContractStorageValue::<T>::clear_prefix(&contract, n, None);
+        StorageSlots::<T>::remove(&contract);
+        ContractRent::<T>::remove(&contract);
+        let code_hash = ContractCodeHash::<T>::take(&contract).expect("contract is deployed; qed");
+        Tombstones::<T>::insert(&contract, Tombstone {
+            code_hash,
+            storage: StorageCommitment::new(storage_root(&slots), n.into(), 0),
+        });
+        let proofs = BoundedVec::try_from(proofs).expect("MaxRestoreEntries >= MaxStorageSlots; qed");
+        funded::<T>(&caller);
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller), contract.clone(), proofs, T::Currency::minimum_balance());
+
+        assert_eq!(StorageSlots::<T>::get(&contract), n);
+    }
+
+    #[benchmark]
+    fn upgrade_contract(c: Linear<1, { T::MaxCodeSize::get() }>) {
+        let owner: T::AccountId = whitelisted_caller();
+        let contract = deployed::<T>(&owner, stop_code(1));
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(owner), contract.clone(), stop_code(c), Some(Vec::new()));
+
+        assert!(PendingUpgrades::<T>::contains_key(&contract));
+    }
+
+    /// Full code history, and a migration call of maximum size code
+    #[benchmark]
+    fn enact_upgrade() {
+        let owner: T::AccountId = whitelisted_caller();
+        let contract = deployed::<T>(&owner, stop_code(1));
+        fill_history::<T>(&contract);
+        Pallet::<T>::upgrade_contract(
+            RawOrigin::Signed(owner.clone()).into(),
+            contract.clone(),
+            max_code::<T>(),
+            Some(Vec::new()),
+        )
+        .expect("owner announces upgrade; qed");
+        frame_system::Pallet::<T>::set_block_number(
+            frame_system::Pallet::<T>::block_number() + T::UpgradeDelay::get(),
+        );
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(owner), contract.clone());
+
+        assert!(!PendingUpgrades::<T>::contains_key(&contract));
+    }
+
+    #[benchmark]
+    fn cancel_upgrade() {
+        let owner: T::AccountId = whitelisted_caller();
+        let contract = deployed::<T>(&owner, stop_code(1));
+        Pallet::<T>::upgrade_contract(
+            RawOrigin::Signed(owner.clone()).into(),
+            contract.clone(),
+            stop_code(2),
+            Some(Vec::new()),
+        )
+        .expect("owner announces upgrade; qed");
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(owner), contract.clone());
+
+        assert!(!PendingUpgrades::<T>::contains_key(&contract));
+    }
+
+    #[benchmark]
+    fn rollback_contract() {
+        let owner: T::AccountId = whitelisted_caller();
+        let contract = deployed::<T>(&owner, stop_code(1));
+        fill_history::<T>(&contract);
+        let versions = CodeHistory::<T>::decode_len(&contract).unwrap_or_default();
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(owner), contract.clone());
+
+        assert_eq!(CodeHistory::<T>::decode_len(&contract), Some(versions - 1));
+    }
+
+    impl_benchmark_test_suite!(Pallet, crate::mock::new_test_ext(), crate::mock::Test);
+}
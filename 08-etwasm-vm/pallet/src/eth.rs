@@ -0,0 +1,261 @@
+//! Ethereum transactions and receipts for ETWasm
+//!
+//! Signed transactions arrive through `eth_sendRawTransaction` and are
+//! dispatched as `transact`. Legacy (EIP-155), EIP-2930 and EIP-1559
+//! envelopes are accepted; access lists are decoded but not used since ETWasm
+//! has no warm/cold storage pricing.
+
+use codec::{Decode, Encode};
+use etwasm_runtime::{EventBloomFilter, ExecutionResult};
+use rlp::{Rlp, RlpStream};
+use scale_info::TypeInfo;
+use sp_core::{H160, H256, U256};
+use sp_runtime::{generic::DigestItem, ConsensusEngineId, Digest};
+use sp_std::prelude::*;
+
+/// Digest engine ID of the block logs bloom, omitted for blocks without logs
+pub const LOGS_BLOOM_ENGINE_ID: ConsensusEngineId = *b"etwl";
+
+/// EIP-2930 transaction type byte
+const ACCESS_LIST_TX_TYPE: u8 = 0x01;
+/// EIP-1559 transaction type byte
+const DYNAMIC_FEE_TX_TYPE: u8 = 0x02;
+
+/// Half the secp256k1 group order; higher `s` values are malleable (EIP-2)
+const SECP256K1_HALF_N: [u8; 32] = [
+    0x7f, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff,
+    0x5d, 0x57, 0x6e, 0x73, 0x57, 0xa4, 0x50, 0x1d, 0xdf, 0xe9, 0x2f, 0x46, 0x68, 0x1b, 0x20, 0xa0,
+];
+
+/// Reasons a raw transaction is rejected
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TransactionError {
+    /// Not a well-formed RLP transaction
+    Malformed,
+    /// Transaction type other than legacy, 0x01 or 0x02
+    UnsupportedType,
+    /// Legacy transaction without EIP-155 replay protection
+    MissingChainId,
+    /// Signature is malleable or does not recover
+    InvalidSignature,
+}
+
+/// Fields of a signed Ethereum transaction used by ETWasm
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EthTransaction {
+    /// Transaction hash, `keccak256` of the raw envelope
+    pub hash: H256,
+    /// Recovered sender
+    pub from: H160,
+    pub chain_id: u64,
+    pub nonce: U256,
+    /// `gasPrice`, or `maxFeePerGas` for EIP-1559 transactions
+    pub gas_price: U256,
+    pub gas_limit: U256,
+    /// Callee, `None` for contract creation
+    pub to: Option<H160>,
+    pub value: U256,
+    pub input: Vec<u8>,
+}
+
+impl EthTransaction {
+    /// Decode a raw signed transaction and recover its sender
+    pub fn decode(raw: &[u8]) -> Result<Self, TransactionError> {
+        match raw.first() {
+            Some(&ACCESS_LIST_TX_TYPE) => Self::decode_typed(raw, 8),
+            Some(&DYNAMIC_FEE_TX_TYPE) => Self::decode_typed(raw, 9),
+            // RLP lists start at 0xc0
+            Some(byte) if *byte >= 0xc0 => Self::decode_legacy(raw),
+            _ => Err(TransactionError::UnsupportedType),
+        }
+    }
+
+    /// `[nonce, gasPrice, gasLimit, to, value, data, v, r, s]`
+    fn decode_legacy(raw: &[u8]) -> Result<Self, TransactionError> {
+        let rlp = list(raw, 9)?;
+        let v = uint(&rlp, 6)?;
+        if v < U256::from(35) || v > U256::from(u64::MAX) {
+            return Err(TransactionError::MissingChainId);
+        }
+        let v = v.low_u64() - 35;
+        let chain_id = v / 2;
+
+        let mut unsigned = RlpStream::new_list(9);
+        for i in 0..6 {
+            unsigned.append_raw(item(&rlp, i)?.as_raw(), 1);
+        }
+        unsigned.append(&chain_id).append_empty_data().append_empty_data();
+        let signing_hash = sp_io::hashing::keccak_256(&unsigned.out());
+
+        Ok(Self {
+            hash: H256(sp_io::hashing::keccak_256(raw)),
+            from: recover(&signing_hash, &rlp, 7, (v % 2) as u8)?,
+            chain_id,
+            nonce: uint(&rlp, 0)?,
+            gas_price: uint(&rlp, 1)?,
+            gas_limit: uint(&rlp, 2)?,
+            to: to(&rlp, 3)?,
+            value: uint(&rlp, 4)?,
+            input: bytes(&rlp, 5)?,
+        })
+    }
+
+    /// `type ++ rlp([chainId, nonce, (gasPrice | maxPriorityFee, maxFee), gasLimit,
+    /// to, value, data, accessList, yParity, r, s])`
+    fn decode_typed(raw: &[u8], unsigned_fields: usize) -> Result<Self, TransactionError> {
+        let rlp = list(&raw[1..], unsigned_fields + 3)?;
+        let chain_id = uint(&rlp, 0)?;
+        if chain_id > U256::from(u64::MAX) {
+            return Err(TransactionError::Malformed);
+        }
+        let y_parity = match uint(&rlp, unsigned_fields)? {
+            v if v.is_zero() => 0,
+            v if v == U256::one() => 1,
+            _ => return Err(TransactionError::InvalidSignature),
+        };
+
+        let mut unsigned = RlpStream::new_list(unsigned_fields);
+        for i in 0..unsigned_fields {
+            unsigned.append_raw(item(&rlp, i)?.as_raw(), 1);
+        }
+        let unsigned = unsigned.out();
+        let mut payload = Vec::with_capacity(1 + unsigned.len());
+        payload.push(raw[0]);
+        payload.extend_from_slice(&unsigned);
+        let signing_hash = sp_io::hashing::keccak_256(&payload);
+
+        // EIP-1559 has one more fee field before gasLimit
+        let fee_fields = unsigned_fields - 7;
+        Ok(Self {
+            hash: H256(sp_io::hashing::keccak_256(raw)),
+            from: recover(&signing_hash, &rlp, unsigned_fields + 1, y_parity)?,
+            chain_id: chain_id.low_u64(),
+            nonce: uint(&rlp, 1)?,
+            gas_price: uint(&rlp, 1 + fee_fields)?,
+            gas_limit: uint(&rlp, 2 + fee_fields)?,
+            to: to(&rlp, 3 + fee_fields)?,
+            value: uint(&rlp, 4 + fee_fields)?,
+            input: bytes(&rlp, 5 + fee_fields)?,
+        })
+    }
+}
+
+/// RLP list spanning all of `raw` with exactly `fields` items
+fn list(raw: &[u8], fields: usize) -> Result<Rlp<'_>, TransactionError> {
+    let rlp = Rlp::new(raw);
+    let total = rlp.payload_info().map_err(|_| TransactionError::Malformed)?.total();
+    if !rlp.is_list() || total != raw.len() || rlp.item_count().ok() != Some(fields) {
+        return Err(TransactionError::Malformed);
+    }
+    Ok(rlp)
+}
+
+fn item<'a>(rlp: &Rlp<'a>, index: usize) -> Result<Rlp<'a>, TransactionError> {
+    rlp.at(index).map_err(|_| TransactionError::Malformed)
+}
+
+fn bytes(rlp: &Rlp, index: usize) -> Result<Vec<u8>, TransactionError> {
+    let item = item(rlp, index)?;
+    if !item.is_data() {
+        return Err(TransactionError::Malformed);
+    }
+    item.data().map(|data| data.to_vec()).map_err(|_| TransactionError::Malformed)
+}
+
+/// Big-endian integer without leading zeros
+fn uint(rlp: &Rlp, index: usize) -> Result<U256, TransactionError> {
+    let data = bytes(rlp, index)?;
+    if data.len() > 32 || data.first() == Some(&0) {
+        return Err(TransactionError::Malformed);
+    }
+    Ok(U256::from_big_endian(&data))
+}
+
+/// 20-byte address, or empty for contract creation
+fn to(rlp: &Rlp, index: usize) -> Result<Option<H160>, TransactionError> {
+    match bytes(rlp, index)? {
+        data if data.is_empty() => Ok(None),
+        data if data.len() == 20 => Ok(Some(H160::from_slice(&data))),
+        _ => Err(TransactionError::Malformed),
+    }
+}
+
+/// Sender of `signing_hash` signed with `(r, s)` at `index` and `index + 1`
+fn recover(
+    signing_hash: &[u8; 32],
+    rlp: &Rlp,
+    index: usize,
+    recovery_id: u8,
+) -> Result<H160, TransactionError> {
+    let r = uint(rlp, index)?.to_big_endian();
+    let s = uint(rlp, index + 1)?.to_big_endian();
+    if s > SECP256K1_HALF_N {
+        return Err(TransactionError::InvalidSignature);
+    }
+
+    let mut signature = [0u8; 65];
+    signature[..32].copy_from_slice(&r);
+    signature[32..64].copy_from_slice(&s);
+    signature[64] = recovery_id;
+    let public = sp_io::crypto::secp256k1_ecdsa_recover(&signature, signing_hash)
+        .map_err(|_| TransactionError::InvalidSignature)?;
+    Ok(H160::from_slice(&sp_io::hashing::keccak_256(&public)[12..]))
+}
+
+/// Address of a contract created by `deployer` with `nonce` (`CREATE`)
+pub fn create_address(deployer: &H160, nonce: u64) -> H160 {
+    let mut stream = RlpStream::new_list(2);
+    stream.append(&deployer.as_bytes().to_vec()).append(&nonce);
+    H160::from_slice(&sp_io::hashing::keccak_256(&stream.out())[12..])
+}
+
+/// Log emitted by a contract, in Ethereum form
+#[derive(Debug, Clone, PartialEq, Eq, Encode, Decode, TypeInfo)]
+pub struct EthLog {
+    pub address: H160,
+    pub topics: Vec<H256>,
+    pub data: Vec<u8>,
+}
+
+/// Outcome of an ETWasm execution included in the current block
+#[derive(Debug, Clone, PartialEq, Eq, Encode, Decode, TypeInfo)]
+pub struct EthReceipt {
+    /// Ethereum transaction hash, or the extrinsic hash for Substrate calls
+    pub transaction_hash: H256,
+    pub from: H160,
+    pub to: Option<H160>,
+    /// Created contract, for deployments
+    pub contract_address: Option<H160>,
+    pub gas_used: u64,
+    pub success: bool,
+    pub logs: Vec<EthLog>,
+    /// Bloom over the addresses and topics of `logs`
+    pub logs_bloom: EventBloomFilter,
+}
+
+/// Bloom over the addresses and topics of `logs`
+pub fn logs_bloom(logs: &[EthLog]) -> EventBloomFilter {
+    let mut bloom = EventBloomFilter::new();
+    for log in logs {
+        bloom.add_log(log.address.as_bytes(), &log.topics);
+    }
+    bloom
+}
+
+/// Block logs bloom carried in a header digest
+pub fn find_logs_bloom(digest: &Digest) -> Option<EventBloomFilter> {
+    digest.logs().iter().find_map(|item| match item {
+        DigestItem::Consensus(id, data) if *id == LOGS_BLOOM_ENGINE_ID => {
+            EventBloomFilter::decode(&mut &data[..]).ok()
+        }
+        _ => None,
+    })
+}
+
+/// Result of a dry-run call (`eth_call` / `eth_estimateGas`)
+#[derive(Debug, Clone, PartialEq, Eq, Encode, Decode, TypeInfo)]
+pub struct EthCallInfo {
+    pub result: ExecutionResult,
+    /// Address the contract would be deployed at, for deployments
+    pub contract_address: Option<H160>,
+}
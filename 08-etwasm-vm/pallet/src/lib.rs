@@ -2,10 +2,48 @@
 //!
 //! This pallet provides EVM-compatible smart contract execution on Ëtrid
 //! using the ETWasm VM runtime.
+//!
+//! Contracts are also reachable from Ethereum tooling: signed Ethereum
+//! transactions are dispatched through `transact`, every execution leaves an
+//! [`EthReceipt`] for the block, and [`EtwasmEthApi`] backs the node's
+//! `eth_*` JSON-RPC methods.
+//!
+//! Calls are paid in native ÉTR: the gas limit is reserved from the caller
+//! at `VmwOperationPrice` before execution, and only the gas used (less
+//! storage-clear refunds) is charged and routed to the treasury afterwards.
+//!
+//! Contract storage pays rent out of a per-contract deposit, charged when the
+//! contract is called. A contract whose deposit runs out is tombstoned: its
+//! storage is dropped and only its Merkle root is kept, so anyone holding the
+//! slots can restore it with a proof per slot. Contracts hold at most
+//! `MaxStorageSlots` slots, which bounds the work of evicting one.
+//!
+//! Contract code can be upgraded by its owner or governance. Upgrades are
+//! announced and enacted after a timelock, optionally running a migration
+//! call of the new code in the same transaction, and can be rolled back to
+//! the previous version.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
+pub mod address;
+pub mod eth;
+pub mod weights;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
+pub use address::{AccountId32Mapper, AddressMapper};
+pub use eth::{EthCallInfo, EthLog, EthReceipt};
 pub use pallet::*;
+pub use weights::WeightInfo;
+
+use sp_core::{H160, H256};
+use sp_runtime::traits::Block as BlockT;
+use sp_std::prelude::*;
 
 // Re-export ETWasm modules
 pub use etwasm_gas_metering as gas;
@@ -13,25 +51,93 @@ pub use etwasm_opcodes as opcodes;
 pub use etwasm_runtime as runtime;
 pub use vmw_runtime as vmw;
 
+/// Loose coupling interface for the treasury receiving ETWasm gas fees
+pub trait FeeTreasury<Balance> {
+    /// Record gas fees withdrawn from contract callers
+    fn receive_transaction_fees(amount: Balance) -> sp_runtime::DispatchResult;
+}
+
+/// Fees are burned
+impl<Balance> FeeTreasury<Balance> for () {
+    fn receive_transaction_fees(_amount: Balance) -> sp_runtime::DispatchResult {
+        Ok(())
+    }
+}
+
 #[frame_support::pallet(dev_mode)]
 pub mod pallet {
     use frame_support::{
         pallet_prelude::*,
+        traits::{
+            Currency, ExistenceRequirement, Imbalance, ReservableCurrency, Time,
+            WithdrawReasons,
+        },
         BoundedVec,
     };
+    use frame_support::storage::{with_transaction, TransactionOutcome};
     use frame_system::pallet_prelude::*;
     use codec::Decode;
     use sp_std::prelude::*;
     use sp_std::collections::btree_set::BTreeSet;
-    use sp_core::H256;
-    use sp_runtime::traits::SaturatedConversion;
+    use sp_core::{H160, H256, U256};
+    use sp_runtime::{
+        generic::DigestItem,
+        traits::{SaturatedConversion, Zero},
+    };
 
     use etwasm_gas_metering::VMw;
     use etwasm_runtime::{
-        ExecutionContext, ExecutionResult, Interpreter,
-        Storage as StorageBackend, InMemoryStorage
+        EventBloomFilter, EventLog, ExecutionContext, ExecutionResult, Interpreter,
+        Storage as StorageBackend, InMemoryStorage, StorageCommitment, StorageMerkleProof,
+        storage_rent, storage_root,
     };
-    use vmw_runtime::{VmwMeteringRuntime, MeteringError};
+    use vmw_runtime::{GasRefundManager, VmwMeteringRuntime, MeteringError};
+
+    use crate::address::AddressMapper;
+    use crate::eth::{self, EthCallInfo, EthLog, EthReceipt, EthTransaction, TransactionError};
+    use crate::{FeeTreasury, WeightInfo};
+
+    pub type BalanceOf<T> =
+        <<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
+
+    /// Rent deposit of a live contract
+    #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, Default)]
+    pub struct RentState<Balance, BlockNumber> {
+        /// Deposit left to pay rent from
+        pub deposit: Balance,
+        /// Block up to which rent has been paid
+        pub paid_until: BlockNumber,
+    }
+
+    /// Announced code upgrade of a contract
+    #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+    pub struct PendingUpgrade<Hash, BlockNumber> {
+        /// New code, already in `CodeStorage`
+        pub code_hash: Hash,
+        /// Input of a call to the new code run with the swap
+        pub migration: Option<Vec<u8>>,
+        /// First block the upgrade can be enacted in
+        pub enactable_at: BlockNumber,
+    }
+
+    /// Code version of a contract
+    #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+    pub struct CodeVersion<Hash, BlockNumber> {
+        /// Starts at 1 for the deployed code
+        pub version: u32,
+        pub code_hash: Hash,
+        /// Block the version went live
+        pub activated_at: BlockNumber,
+    }
+
+    /// What remains of a contract evicted for unpaid rent
+    #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+    pub struct Tombstone<Hash> {
+        /// Code the contract is restored with
+        pub code_hash: Hash,
+        /// Root and slot count of the dropped storage
+        pub storage: StorageCommitment,
+    }
 
     #[pallet::config]
     pub trait Config: frame_system::Config {
@@ -47,9 +153,53 @@ pub mod pallet {
         #[pallet::constant]
         type MaxGasLimit: Get<VMw>;
 
+        /// Weight reserved per unit of gas limit
+        #[pallet::constant]
+        type WeightPerGas: Get<Weight>;
+
         /// VMw operation price (Watts per operation) for metering
         #[pallet::constant]
         type VmwOperationPrice: Get<u32>;
+
+        /// Maps accounts to Ethereum addresses and back
+        type AddressMapper: AddressMapper<Self::AccountId>;
+
+        /// EIP-155 chain ID required of transactions submitted to `transact`
+        #[pallet::constant]
+        type ChainId: Get<u64>;
+
+        /// Native currency for call values and gas fees
+        type Currency: Currency<Self::AccountId> + ReservableCurrency<Self::AccountId>;
+
+        /// Block time source for the `TIMESTAMP` opcode
+        type Time: Time;
+
+        /// Receiver of gas fees
+        type Treasury: FeeTreasury<BalanceOf<Self>>;
+
+        /// Origin allowed to upgrade any contract besides its owner
+        type UpgradeOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+        /// Blocks between announcing and enacting a contract upgrade
+        #[pallet::constant]
+        type UpgradeDelay: Get<BlockNumberFor<Self>>;
+
+        /// Code versions kept per contract for rollback
+        #[pallet::constant]
+        type MaxCodeVersions: Get<u32>;
+
+        /// Storage slots a contract may hold
+        /// An execution leaving more behind is reverted.
+        #[pallet::constant]
+        type MaxStorageSlots: Get<u32>;
+
+        /// Slot proofs accepted by `restore_contract`
+        /// At least `MaxStorageSlots`, so every tombstone can be restored.
+        #[pallet::constant]
+        type MaxRestoreEntries: Get<u32>;
+
+        /// Weight information for extrinsics in this pallet
+        type WeightInfo: WeightInfo;
     }
 
     /// Storage: Contract code hash by account
@@ -93,11 +243,77 @@ pub mod pallet {
         BoundedVec<u8, ConstU32<1048576>> // 1MB max
     >;
 
+    /// Storage: Number of storage slots held by a contract
+    #[pallet::storage]
+    pub type StorageSlots<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, u32, ValueQuery>;
+
+    /// Storage: Rent deposit of a contract
+    #[pallet::storage]
+    pub type ContractRent<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        RentState<BalanceOf<T>, BlockNumberFor<T>>,
+        ValueQuery
+    >;
+
+    /// Storage: Announced code upgrades by contract
+    #[pallet::storage]
+    pub type PendingUpgrades<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        PendingUpgrade<T::Hash, BlockNumberFor<T>>
+    >;
+
+    /// Storage: Code versions of a contract, oldest first
+    /// The last entry is the live code. At most `MaxCodeVersions` are kept.
+    #[pallet::storage]
+    pub type CodeHistory<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        Vec<CodeVersion<T::Hash, BlockNumberFor<T>>>,
+        ValueQuery
+    >;
+
+    /// Storage: Contracts evicted for unpaid rent
+    #[pallet::storage]
+    pub type Tombstones<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        Tombstone<T::Hash>
+    >;
+
     /// Storage: Gas used by contract in current block
     #[pallet::storage]
     #[pallet::getter(fn gas_used)]
     pub type GasUsed<T: Config> = StorageValue<_, VMw, ValueQuery>;
 
+    /// Storage: Account that claimed an Ethereum address with `map_account`
+    #[pallet::storage]
+    pub type OriginalAccount<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        H160,
+        T::AccountId
+    >;
+
+    /// Storage: Ethereum transaction count (nonce) by address
+    /// Also drives `CREATE` addresses of Substrate deployments
+    #[pallet::storage]
+    pub type EthNonce<T: Config> = StorageMap<_, Blake2_128Concat, H160, u64, ValueQuery>;
+
+    /// Storage: Receipts of the ETWasm executions in the current block
+    #[pallet::storage]
+    pub type CurrentReceipts<T: Config> = StorageValue<_, Vec<EthReceipt>, ValueQuery>;
+
+    /// Storage: Bloom over the logs of the current block
+    /// Moved into a header digest on finalize
+    #[pallet::storage]
+    pub type BlockLogsBloom<T: Config> = StorageValue<_, EventBloomFilter, OptionQuery>;
+
     #[pallet::event]
     #[pallet::generate_deposit(pub(super) fn deposit_event)]
     pub enum Event<T: Config> {
@@ -125,6 +341,68 @@ pub mod pallet {
             reason: Vec<u8>,
             gas_used: VMw,
         },
+        /// Bytecode run with `execute_bytecode`
+        BytecodeExecuted {
+            caller: T::AccountId,
+            gas_used: VMw,
+            success: bool,
+        },
+        /// Signed Ethereum transaction applied
+        EthTransactionApplied {
+            from: H160,
+            transaction_hash: H256,
+            success: bool,
+        },
+        /// Account claimed its Ethereum address
+        AccountMapped {
+            account: T::AccountId,
+            address: H160,
+        },
+        /// Gas fee charged for a contract execution
+        GasFeePaid {
+            payer: T::AccountId,
+            gas_used: VMw,
+            gas_refunded: VMw,
+            fee: BalanceOf<T>,
+        },
+        /// Rent deposit topped up
+        RentDeposited {
+            contract: T::AccountId,
+            depositor: T::AccountId,
+            amount: BalanceOf<T>,
+        },
+        /// Contract ran out of rent and was tombstoned
+        ContractTombstoned {
+            contract: T::AccountId,
+            storage_root: H256,
+        },
+        /// Tombstoned contract restored with its storage
+        ContractRestored {
+            contract: T::AccountId,
+            restorer: T::AccountId,
+        },
+        /// Contract code upgrade announced
+        UpgradeAnnounced {
+            contract: T::AccountId,
+            code_hash: T::Hash,
+            enactable_at: BlockNumberFor<T>,
+        },
+        /// Announced upgrade withdrawn
+        UpgradeCancelled {
+            contract: T::AccountId,
+        },
+        /// Contract code swapped to a new version
+        ContractUpgraded {
+            contract: T::AccountId,
+            version: u32,
+            code_hash: T::Hash,
+        },
+        /// Contract code reverted to its previous version
+        ContractRolledBack {
+            contract: T::AccountId,
+            version: u32,
+            code_hash: T::Hash,
+        },
     }
 
     #[pallet::error]
@@ -157,6 +435,38 @@ pub mod pallet {
         MaxCallDepthExceeded,
         /// Account is locked and cannot execute contracts
         AccountLocked,
+        /// Contract already deployed at the derived address
+        ContractAlreadyExists,
+        /// Ethereum transaction is not well-formed or of an unsupported type
+        MalformedEthTransaction,
+        /// Ethereum transaction signature is invalid
+        InvalidEthSignature,
+        /// Ethereum transaction is for another chain
+        InvalidChainId,
+        /// Ethereum transaction nonce does not match the sender's
+        InvalidNonce,
+        /// Transferred value exceeds the native balance range
+        ValueTooLarge,
+        /// Ethereum address is already mapped to an account
+        AddressAlreadyMapped,
+        /// Caller cannot cover the gas limit upfront
+        InsufficientBalance,
+        /// Contract is not tombstoned
+        NotTombstoned,
+        /// Storage proofs do not match the tombstone
+        InvalidStorageProof,
+        /// Contract rent deposit still covers its storage
+        RentNotExhausted,
+        /// An upgrade is already announced for the contract
+        UpgradeAlreadyPending,
+        /// No upgrade is announced for the contract
+        NoPendingUpgrade,
+        /// Upgrade timelock has not passed yet
+        UpgradeTimelocked,
+        /// Migration call of the new code failed
+        MigrationFailed,
+        /// Contract has no earlier code version
+        NoPreviousVersion,
     }
 
     #[pallet::pallet]
@@ -167,7 +477,26 @@ pub mod pallet {
         /// Reset gas counter at the start of each block
         fn on_initialize(_n: BlockNumberFor<T>) -> Weight {
             GasUsed::<T>::put(0);
-            Weight::from_parts(1_000, 0)
+            CurrentReceipts::<T>::kill();
+            // Covers on_finalize
+            Weight::from_parts(3_000, 0)
+        }
+
+        fn integrity_test() {
+            assert!(
+                T::MaxRestoreEntries::get() >= T::MaxStorageSlots::get(),
+                "tombstones with more slots than MaxRestoreEntries cannot be restored"
+            );
+        }
+
+        /// Publish the block logs bloom for log queries
+        fn on_finalize(_n: BlockNumberFor<T>) {
+            if let Some(bloom) = BlockLogsBloom::<T>::take() {
+                frame_system::Pallet::<T>::deposit_log(DigestItem::Consensus(
+                    eth::LOGS_BLOOM_ENGINE_ID,
+                    bloom.encode(),
+                ));
+            }
         }
     }
 
@@ -175,56 +504,56 @@ pub mod pallet {
     impl<T: Config> Pallet<T> {
         /// Deploy a new smart contract
         ///
-        /// Stores the bytecode and initializes contract storage.
-        /// The sender becomes the contract owner.
-        #[pallet::weight(100_000)]
+        /// Stores the bytecode at the Ethereum `CREATE` address of the sender
+        /// and its current nonce. The sender becomes the contract owner and
+        /// funds the initial `rent_deposit`.
+        #[pallet::weight(T::WeightInfo::deploy_contract(code.len() as u32))]
         #[pallet::call_index(0)]
         pub fn deploy_contract(
             origin: OriginFor<T>,
-            code: Vec<u8>
+            code: Vec<u8>,
+            rent_deposit: BalanceOf<T>,
         ) -> DispatchResult {
             let sender = ensure_signed(origin)?;
 
-            // Validate code size
-            ensure!(
-                (code.len() as u32) <= T::MaxCodeSize::get(),
-                Error::<T>::CodeTooLarge
-            );
+            let from = T::AddressMapper::to_address(&sender);
+            let nonce = EthNonce::<T>::get(from);
+            EthNonce::<T>::insert(from, nonce.saturating_add(1));
+            let contract_address = Self::deploy(&sender, code, nonce)?;
+            Self::lock_rent_deposit(&sender, &contract_address, rent_deposit)?;
 
-            // Generate code hash
-            let hash_bytes = sp_io::hashing::blake2_256(&code);
-            let code_hash = T::Hash::decode(&mut &hash_bytes[..])
-                .map_err(|_| Error::<T>::InvalidBytecode)?;
-
-            // Store the code
-            let bounded_code = BoundedVec::<u8, ConstU32<1048576>>::try_from(code.clone())
-                .map_err(|_| Error::<T>::CodeTooLarge)?;
-            CodeStorage::<T>::insert(code_hash, bounded_code);
-
-            // Use sender as contract address (simplified)
-            let contract_address = sender.clone();
-
-            // Store contract metadata
-            ContractCodeHash::<T>::insert(&contract_address, code_hash);
-            ContractOwner::<T>::insert(&contract_address, &sender);
-
-            Self::deposit_event(Event::ContractDeployed {
-                deployer: sender,
-                contract_address,
-                code_hash,
+            Self::record_receipt(EthReceipt {
+                transaction_hash: Self::extrinsic_hash(),
+                from,
+                to: None,
+                contract_address: Some(T::AddressMapper::to_address(&contract_address)),
+                gas_used: 0,
+                success: true,
+                logs: Vec::new(),
+                logs_bloom: EventBloomFilter::new(),
             });
-
             Ok(())
         }
 
         /// Call a deployed contract
         ///
-        /// Executes the contract bytecode with the ETWasm interpreter.
-        #[pallet::weight(100_000)]
+        /// Executes the contract bytecode with the ETWasm interpreter after
+        /// moving `value` to the contract. The caller pays for the gas used
+        /// whether or not the call succeeds; a failed call discards its value
+        /// transfer and storage changes and leaves a failed receipt. Storage
+        /// rent is charged first, and a contract that cannot pay it is
+        /// tombstoned instead of called.
+        #[pallet::weight({
+            let gas_limit = gas_limit.unwrap_or_else(T::DefaultGasLimit::get);
+            T::WeightInfo::call_contract()
+                .saturating_add(Pallet::<T>::gas_weight(gas_limit))
+                .max(T::WeightInfo::evict_contract(T::MaxStorageSlots::get()))
+        })]
         #[pallet::call_index(1)]
         pub fn call_contract(
             origin: OriginFor<T>,
             contract_addr: T::AccountId,
+            value: BalanceOf<T>,
             input_data: Vec<u8>,
             gas_limit: Option<VMw>,
         ) -> DispatchResult {
@@ -238,6 +567,10 @@ pub mod pallet {
             let code = CodeStorage::<T>::get(code_hash)
                 .ok_or(Error::<T>::ContractNotFound)?;
 
+            if !Self::charge_rent(&contract_addr)? {
+                return Ok(());
+            }
+
             // Validate gas limit
             let gas_limit = gas_limit.unwrap_or_else(T::DefaultGasLimit::get);
             ensure!(
@@ -245,83 +578,51 @@ pub mod pallet {
                 Error::<T>::GasLimitExceeded
             );
 
-            // Create execution context
-            let context = ExecutionContext {
-                caller: Self::account_to_bytes32(&caller),
-                address: Self::account_to_bytes32(&contract_addr),
-                value: 0, // No value transfer for now
-                gas_limit,
-                gas_price: 1,
-                block_number: frame_system::Pallet::<T>::block_number().saturated_into(),
-                timestamp: 0, // TODO: Get actual timestamp
-                chain_id: 2, // Ëtrid chain ID
-                call_stack: BTreeSet::new(),
-                reentrancy_depth: 0,
-                max_depth: 10, // Max allowed reentrancy depth
-            };
-
-            // Create storage backend
-            let mut storage = PalletStorage::<T> {
-                contract_addr: contract_addr.clone(),
-                _phantom: Default::default(),
-            };
-
             // Execute bytecode
-            let interpreter = Interpreter::new(context, code.to_vec(), storage);
-            let result = interpreter.execute();
+            let (result, logs) = Self::execute_paid(
+                &caller,
+                &contract_addr,
+                code.to_vec(),
+                input_data,
+                gas_limit,
+                value,
+            )?;
 
-            // Handle execution result
-            match result {
-                ExecutionResult::Success { gas_used, return_data } => {
-                    Self::charge_gas(gas_used)?;
-                    Self::deposit_event(Event::ContractExecuted {
-                        contract: contract_addr,
-                        gas_used,
-                        success: true,
-                    });
-                    Ok(())
-                }
-                ExecutionResult::Revert { gas_used, reason } => {
-                    Self::charge_gas(gas_used)?;
-                    Self::deposit_event(Event::ContractReverted {
-                        contract: contract_addr,
-                        reason,
-                        gas_used,
-                    });
-                    Err(Error::<T>::ExecutionFailed.into())
-                }
-                ExecutionResult::OutOfGas { gas_used } => {
-                    Self::charge_gas(gas_used)?;
-                    Err(Error::<T>::OutOfGas.into())
-                }
-                ExecutionResult::StackError => {
-                    Err(Error::<T>::StackError.into())
-                }
-                ExecutionResult::InvalidOpcode(_) => {
-                    Err(Error::<T>::InvalidOpcode.into())
-                }
-                ExecutionResult::InvalidJump => {
-                    Err(Error::<T>::InvalidJump.into())
-                }
-                ExecutionResult::ReentrancyDetected => {
-                    Err(Error::<T>::ReentrancyDetected.into())
-                }
-                ExecutionResult::MaxCallDepthExceeded => {
-                    Err(Error::<T>::MaxCallDepthExceeded.into())
-                }
-                ExecutionResult::AccountLocked => {
-                    Err(Error::<T>::AccountLocked.into())
-                }
-                ExecutionResult::Error(_) => {
-                    Err(Error::<T>::ExecutionFailed.into())
-                }
+            // A failed call keeps its fee: record it instead of failing
+            let (gas_used, success) = (result.gas_used(), result.is_success());
+            Self::charge_gas(gas_used)?;
+            if let ExecutionResult::Revert { reason, .. } = result {
+                Self::deposit_event(Event::ContractReverted {
+                    contract: contract_addr.clone(),
+                    reason,
+                    gas_used,
+                });
             }
+            Self::record_receipt(EthReceipt {
+                transaction_hash: Self::extrinsic_hash(),
+                from: T::AddressMapper::to_address(&caller),
+                to: Some(T::AddressMapper::to_address(&contract_addr)),
+                contract_address: None,
+                gas_used,
+                success,
+                logs: Self::eth_logs(logs),
+                logs_bloom: EventBloomFilter::new(),
+            });
+            Self::deposit_event(Event::ContractExecuted {
+                contract: contract_addr,
+                gas_used,
+                success,
+            });
+            Ok(())
         }
 
         /// Execute contract bytecode directly with gas limit
         ///
-        /// Useful for testing and direct contract execution.
-        #[pallet::weight(10_000)]
+        /// Useful for testing and direct contract execution. The bytecode
+        /// runs against throwaway storage; its outcome is reported in a
+        /// `BytecodeExecuted` event rather than failing the extrinsic.
+        #[pallet::weight(T::WeightInfo::execute_bytecode(bytecode.len() as u32)
+            .saturating_add(Pallet::<T>::gas_weight(*gas_limit)))]
         #[pallet::call_index(2)]
         pub fn execute_bytecode(
             origin: OriginFor<T>,
@@ -338,14 +639,15 @@ pub mod pallet {
 
             // Create execution context
             let context = ExecutionContext {
-                caller: Self::account_to_bytes32(&caller),
+                caller: Self::address_word(&caller),
                 address: [0u8; 32], // No specific contract address
                 value: 0,
+                input: Vec::new(),
                 gas_limit,
-                gas_price: 1,
+                gas_price: T::VmwOperationPrice::get() as u128,
                 block_number: frame_system::Pallet::<T>::block_number().saturated_into(),
-                timestamp: 0,
-                chain_id: 2,
+                timestamp: Self::timestamp(),
+                chain_id: T::ChainId::get(),
                 call_stack: BTreeSet::new(),
                 reentrancy_depth: 0,
                 max_depth: 10, // Max allowed reentrancy depth
@@ -358,30 +660,889 @@ pub mod pallet {
             let interpreter = Interpreter::new(context, bytecode, storage);
             let result = interpreter.execute();
 
-            // Handle result
-            match result {
-                ExecutionResult::Success { gas_used, .. } => {
-                    Self::charge_gas(gas_used)?;
-                    Ok(())
+            Self::charge_gas(result.gas_used())?;
+            Self::deposit_event(Event::BytecodeExecuted {
+                caller,
+                gas_used: result.gas_used(),
+                success: result.is_success(),
+            });
+            Ok(())
+        }
+
+        /// Apply a signed Ethereum transaction
+        ///
+        /// Submitted unsigned by `eth_sendRawTransaction`; the sender is
+        /// recovered from the transaction signature and pays no Substrate fee,
+        /// only the gas of contract calls. A reverted call still consumes the
+        /// nonce, pays for its gas and leaves a failed receipt, with its value
+        /// transfer and storage changes discarded.
+        #[pallet::weight({
+            let gas_limit = Pallet::<T>::transaction_gas_limit(transaction);
+            T::WeightInfo::transact_create(transaction.len() as u32)
+                .max(T::WeightInfo::transact_call())
+                .saturating_add(Pallet::<T>::gas_weight(gas_limit))
+                .max(T::WeightInfo::evict_contract(T::MaxStorageSlots::get()))
+        })]
+        #[pallet::call_index(3)]
+        pub fn transact(
+            origin: OriginFor<T>,
+            transaction: Vec<u8>,
+        ) -> DispatchResult {
+            ensure_none(origin)?;
+
+            let tx = Self::check_transaction(&transaction)?;
+            let nonce = EthNonce::<T>::get(tx.from);
+            ensure!(tx.nonce == U256::from(nonce), Error::<T>::InvalidNonce);
+            EthNonce::<T>::insert(tx.from, nonce.saturating_add(1));
+
+            let sender = Self::account_id(&tx.from);
+            let value = tx.value.low_u128().saturated_into::<BalanceOf<T>>();
+            let mut receipt = EthReceipt {
+                transaction_hash: tx.hash,
+                from: tx.from,
+                to: tx.to,
+                contract_address: None,
+                gas_used: 0,
+                success: true,
+                logs: Vec::new(),
+                logs_bloom: EventBloomFilter::new(),
+            };
+
+            match tx.to {
+                None => {
+                    let contract = Self::deploy(&sender, tx.input, nonce)?;
+                    T::Currency::transfer(
+                        &sender,
+                        &contract,
+                        value,
+                        ExistenceRequirement::AllowDeath,
+                    )?;
+                    receipt.contract_address = Some(T::AddressMapper::to_address(&contract));
                 }
-                ExecutionResult::OutOfGas { gas_used } => {
-                    Self::charge_gas(gas_used)?;
-                    Err(Error::<T>::OutOfGas.into())
+                Some(to) => {
+                    let contract = Self::account_id(&to);
+                    // Calls to addresses without code are plain transfers
+                    match Self::contract_code(&contract) {
+                        None => T::Currency::transfer(
+                            &sender,
+                            &contract,
+                            value,
+                            ExistenceRequirement::AllowDeath,
+                        )?,
+                        // Tombstoned for unpaid rent
+                        Some(_) if !Self::charge_rent(&contract)? => receipt.success = false,
+                        Some(code) => {
+                            let (result, logs) = Self::execute_paid(
+                                &sender,
+                                &contract,
+                                code,
+                                tx.input,
+                                tx.gas_limit.low_u64(),
+                                value,
+                            )?;
+                            Self::charge_gas(result.gas_used())?;
+                            receipt.gas_used = result.gas_used();
+                            receipt.success = result.is_success();
+                            receipt.logs = Self::eth_logs(logs);
+                            Self::deposit_event(Event::ContractExecuted {
+                                contract,
+                                gas_used: result.gas_used(),
+                                success: result.is_success(),
+                            });
+                        }
+                    }
                 }
-                _ => Err(Error::<T>::ExecutionFailed.into()),
+            }
+
+            Self::deposit_event(Event::EthTransactionApplied {
+                from: tx.from,
+                transaction_hash: tx.hash,
+                success: receipt.success,
+            });
+            Self::record_receipt(receipt);
+            Ok(())
+        }
+
+        /// Claim the Ethereum address of the sender
+        ///
+        /// Afterwards calls and transfers to that address reach the sender
+        /// instead of its fallback account. Accounts derived from an Ethereum
+        /// address resolve without mapping.
+        #[pallet::weight(T::WeightInfo::map_account())]
+        #[pallet::call_index(4)]
+        pub fn map_account(origin: OriginFor<T>) -> DispatchResult {
+            let account = ensure_signed(origin)?;
+            ensure!(
+                !T::AddressMapper::is_eth_derived(&account),
+                Error::<T>::AddressAlreadyMapped
+            );
+
+            let address = T::AddressMapper::to_address(&account);
+            ensure!(
+                !OriginalAccount::<T>::contains_key(address),
+                Error::<T>::AddressAlreadyMapped
+            );
+            OriginalAccount::<T>::insert(address, &account);
+
+            Self::deposit_event(Event::AccountMapped { account, address });
+            Ok(())
+        }
+
+        /// Top up the rent deposit of a live contract
+        ///
+        /// Anyone may fund a contract's rent. Deposits are not refundable;
+        /// rent is paid out of them to the treasury.
+        #[pallet::weight(T::WeightInfo::deposit_rent())]
+        #[pallet::call_index(5)]
+        pub fn deposit_rent(
+            origin: OriginFor<T>,
+            contract_addr: T::AccountId,
+            amount: BalanceOf<T>,
+        ) -> DispatchResult {
+            let depositor = ensure_signed(origin)?;
+            ensure!(
+                ContractCodeHash::<T>::contains_key(&contract_addr),
+                Error::<T>::ContractNotFound
+            );
+            Self::lock_rent_deposit(&depositor, &contract_addr, amount)
+        }
+
+        /// Tombstone a contract whose rent deposit has run out
+        ///
+        /// Rent is otherwise only charged when the contract is called. Weighed
+        /// for `MaxStorageSlots` slots and refunded down to the slots held.
+        #[pallet::weight(T::WeightInfo::evict_contract(T::MaxStorageSlots::get()))]
+        #[pallet::call_index(6)]
+        pub fn evict_contract(
+            origin: OriginFor<T>,
+            contract_addr: T::AccountId,
+        ) -> DispatchResultWithPostInfo {
+            ensure_signed(origin)?;
+            ensure!(
+                ContractCodeHash::<T>::contains_key(&contract_addr),
+                Error::<T>::ContractNotFound
+            );
+            let slots = StorageSlots::<T>::get(&contract_addr);
+            ensure!(!Self::charge_rent(&contract_addr)?, Error::<T>::RentNotExhausted);
+            Ok(Some(T::WeightInfo::evict_contract(slots)).into())
+        }
+
+        /// Restore a tombstoned contract with its storage
+        ///
+        /// `slots` must hold one proof per slot of the evicted storage, in
+        /// ascending key order, each against the tombstone's storage root.
+        /// The sender funds the new `rent_deposit`.
+        #[pallet::weight(T::WeightInfo::restore_contract(slots.len() as u32))]
+        #[pallet::call_index(7)]
+        pub fn restore_contract(
+            origin: OriginFor<T>,
+            contract_addr: T::AccountId,
+            slots: BoundedVec<StorageMerkleProof, T::MaxRestoreEntries>,
+            rent_deposit: BalanceOf<T>,
+        ) -> DispatchResult {
+            let restorer = ensure_signed(origin)?;
+            let tombstone = Tombstones::<T>::get(&contract_addr)
+                .ok_or(Error::<T>::NotTombstoned)?;
+
+            ensure!(
+                slots.len() as u64 == tombstone.storage.slot_count,
+                Error::<T>::InvalidStorageProof
+            );
+            // Ascending keys rule out proving one slot twice
+            let mut previous_key = None;
+            for proof in &slots {
+                ensure!(
+                    proof.root == tombstone.storage.root
+                        && proof.verify()
+                        && previous_key.map_or(true, |key| key < proof.key),
+                    Error::<T>::InvalidStorageProof
+                );
+                previous_key = Some(proof.key);
+            }
+
+            Tombstones::<T>::remove(&contract_addr);
+            for proof in slots {
+                ContractStorageValue::<T>::insert(&contract_addr, proof.key, proof.value);
+            }
+            StorageSlots::<T>::insert(&contract_addr, tombstone.storage.slot_count as u32);
+            ContractCodeHash::<T>::insert(&contract_addr, tombstone.code_hash);
+            Self::start_rent(&contract_addr);
+            Self::lock_rent_deposit(&restorer, &contract_addr, rent_deposit)?;
+
+            Self::deposit_event(Event::ContractRestored {
+                contract: contract_addr,
+                restorer,
+            });
+            Ok(())
+        }
+
+        /// Announce a code upgrade of a contract
+        ///
+        /// Callable by the contract owner or `UpgradeOrigin`. The new code can
+        /// be enacted once `UpgradeDelay` blocks have passed; `migration` is
+        /// then called on it, with the contract itself as caller.
+        #[pallet::weight(T::WeightInfo::upgrade_contract(code.len() as u32))]
+        #[pallet::call_index(8)]
+        pub fn upgrade_contract(
+            origin: OriginFor<T>,
+            contract_addr: T::AccountId,
+            code: Vec<u8>,
+            migration: Option<Vec<u8>>,
+        ) -> DispatchResult {
+            Self::ensure_owner_or_governance(origin, &contract_addr)?;
+            ensure!(
+                ContractCodeHash::<T>::contains_key(&contract_addr),
+                Error::<T>::ContractNotFound
+            );
+            ensure!(
+                !PendingUpgrades::<T>::contains_key(&contract_addr),
+                Error::<T>::UpgradeAlreadyPending
+            );
+
+            let code_hash = Self::store_code(code)?;
+            let enactable_at = frame_system::Pallet::<T>::block_number()
+                .saturating_add(T::UpgradeDelay::get());
+            PendingUpgrades::<T>::insert(&contract_addr, PendingUpgrade {
+                code_hash,
+                migration,
+                enactable_at,
+            });
+
+            Self::deposit_event(Event::UpgradeAnnounced {
+                contract: contract_addr,
+                code_hash,
+                enactable_at,
+            });
+            Ok(())
+        }
+
+        /// Swap in the announced code of a contract
+        ///
+        /// The migration call runs in the same transaction, so a failing
+        /// migration leaves the old code in place. It gets `DefaultGasLimit`
+        /// gas, weighed upfront and refunded down to the gas it used, so the
+        /// enacting extrinsic pays for it.
+        #[pallet::weight(T::WeightInfo::enact_upgrade()
+            .saturating_add(Pallet::<T>::gas_weight(T::DefaultGasLimit::get())))]
+        #[pallet::call_index(9)]
+        pub fn enact_upgrade(
+            origin: OriginFor<T>,
+            contract_addr: T::AccountId,
+        ) -> DispatchResultWithPostInfo {
+            Self::ensure_owner_or_governance(origin, &contract_addr)?;
+            let upgrade = PendingUpgrades::<T>::take(&contract_addr)
+                .ok_or(Error::<T>::NoPendingUpgrade)?;
+            ensure!(
+                frame_system::Pallet::<T>::block_number() >= upgrade.enactable_at,
+                Error::<T>::UpgradeTimelocked
+            );
+            ensure!(
+                ContractCodeHash::<T>::contains_key(&contract_addr),
+                Error::<T>::ContractNotFound
+            );
+
+            let version = Self::activate_code(&contract_addr, upgrade.code_hash);
+            let mut gas_used = 0;
+            if let Some(input) = upgrade.migration {
+                let code = Self::contract_code(&contract_addr)
+                    .ok_or(Error::<T>::ContractNotFound)?;
+                let mut refunds = GasRefundManager::new();
+                let (result, _) = Self::execute(
+                    &contract_addr,
+                    &contract_addr,
+                    code,
+                    input,
+                    T::DefaultGasLimit::get(),
+                    0,
+                    &mut refunds,
+                );
+                gas_used = result.gas_used();
+                Self::charge_gas(gas_used)?;
+                ensure!(result.is_success(), Error::<T>::MigrationFailed);
+            }
+
+            Self::deposit_event(Event::ContractUpgraded {
+                contract: contract_addr,
+                version,
+                code_hash: upgrade.code_hash,
+            });
+            let actual_weight = T::WeightInfo::enact_upgrade().saturating_add(Self::gas_weight(gas_used));
+            Ok(Some(actual_weight).into())
+        }
+
+        /// Withdraw an announced upgrade
+        #[pallet::weight(T::WeightInfo::cancel_upgrade())]
+        #[pallet::call_index(10)]
+        pub fn cancel_upgrade(
+            origin: OriginFor<T>,
+            contract_addr: T::AccountId,
+        ) -> DispatchResult {
+            Self::ensure_owner_or_governance(origin, &contract_addr)?;
+            PendingUpgrades::<T>::take(&contract_addr).ok_or(Error::<T>::NoPendingUpgrade)?;
+
+            Self::deposit_event(Event::UpgradeCancelled { contract: contract_addr });
+            Ok(())
+        }
+
+        /// Revert a contract to its previous code version
+        ///
+        /// Takes effect immediately, without timelock or migration. The
+        /// current version is dropped from the history.
+        #[pallet::weight(T::WeightInfo::rollback_contract())]
+        #[pallet::call_index(11)]
+        pub fn rollback_contract(
+            origin: OriginFor<T>,
+            contract_addr: T::AccountId,
+        ) -> DispatchResult {
+            Self::ensure_owner_or_governance(origin, &contract_addr)?;
+            ensure!(
+                ContractCodeHash::<T>::contains_key(&contract_addr),
+                Error::<T>::ContractNotFound
+            );
+
+            let previous = CodeHistory::<T>::try_mutate(&contract_addr, |history| {
+                if history.len() < 2 {
+                    return Err(Error::<T>::NoPreviousVersion);
+                }
+                history.pop();
+                history.last().cloned().ok_or(Error::<T>::NoPreviousVersion)
+            })?;
+            ContractCodeHash::<T>::insert(&contract_addr, previous.code_hash);
+
+            Self::deposit_event(Event::ContractRolledBack {
+                contract: contract_addr,
+                version: previous.version,
+                code_hash: previous.code_hash,
+            });
+            Ok(())
+        }
+    }
+
+    #[pallet::validate_unsigned]
+    impl<T: Config> ValidateUnsigned for Pallet<T> {
+        type Call = Call<T>;
+
+        /// Order Ethereum transactions per sender by nonce, as the Ethereum
+        /// pool does; future nonces wait for their predecessor.
+        fn validate_unsigned(_source: TransactionSource, call: &Self::Call) -> TransactionValidity {
+            let Call::transact { transaction } = call else {
+                return InvalidTransaction::Call.into();
+            };
+            let tx = Self::check_transaction(transaction).map_err(Self::invalid_transaction)?;
+
+            let nonce = EthNonce::<T>::get(tx.from);
+            let tx_nonce = tx.nonce.low_u64();
+            if tx_nonce < nonce {
+                return InvalidTransaction::Stale.into();
+            }
+
+            // Gas limit and value must be covered, as the Ethereum pool checks
+            let cost = Self::gas_cost(tx.gas_limit.low_u64())
+                .saturating_add(tx.value.low_u128().saturated_into());
+            if T::Currency::free_balance(&Self::account_id(&tx.from)) < cost {
+                return InvalidTransaction::Payment.into();
+            }
+
+            let mut valid = ValidTransaction::with_tag_prefix("EtwasmEth")
+                .priority(tx.gas_price.min(U256::from(u64::MAX)).low_u64())
+                .and_provides((tx.from, tx_nonce))
+                .longevity(64)
+                .propagate(true);
+            if tx_nonce > nonce {
+                valid = valid.and_requires((tx.from, tx_nonce - 1));
+            }
+            valid.build()
+        }
+
+        fn pre_dispatch(call: &Self::Call) -> Result<(), TransactionValidityError> {
+            let Call::transact { transaction } = call else {
+                return Err(InvalidTransaction::Call.into());
+            };
+            let tx = Self::check_transaction(transaction).map_err(Self::invalid_transaction)?;
+
+            let nonce = EthNonce::<T>::get(tx.from);
+            match tx.nonce.low_u64() {
+                n if n < nonce => Err(InvalidTransaction::Stale.into()),
+                n if n > nonce => Err(InvalidTransaction::Future.into()),
+                _ => Ok(()),
             }
         }
     }
 
     // Helper functions
     impl<T: Config> Pallet<T> {
-        /// Convert AccountId to 32-byte array for EVM compatibility
-        fn account_to_bytes32(account: &T::AccountId) -> [u8; 32] {
-            let encoded = account.encode();
-            let mut result = [0u8; 32];
-            let len = core::cmp::min(encoded.len(), 32);
-            result[32 - len..].copy_from_slice(&encoded[..len]);
-            result
+        /// Ethereum address of an account as a 32-byte word (CALLER/ADDRESS)
+        fn address_word(account: &T::AccountId) -> [u8; 32] {
+            H256::from(T::AddressMapper::to_address(account)).0
+        }
+
+        /// Account an Ethereum address resolves to
+        pub fn account_id(address: &H160) -> T::AccountId {
+            OriginalAccount::<T>::get(address)
+                .unwrap_or_else(|| T::AddressMapper::to_fallback_account_id(address))
+        }
+
+        /// Bytecode of a deployed contract
+        fn contract_code(contract: &T::AccountId) -> Option<Vec<u8>> {
+            ContractCodeHash::<T>::get(contract)
+                .and_then(|code_hash| CodeStorage::<T>::get(code_hash))
+                .map(|code| code.into_inner())
+        }
+
+        /// Store `code` at the `CREATE` address of `deployer` and `nonce`
+        fn deploy(
+            deployer: &T::AccountId,
+            code: Vec<u8>,
+            nonce: u64,
+        ) -> Result<T::AccountId, DispatchError> {
+            // Validate code size
+            ensure!(
+                (code.len() as u32) <= T::MaxCodeSize::get(),
+                Error::<T>::CodeTooLarge
+            );
+
+            let address = eth::create_address(&T::AddressMapper::to_address(deployer), nonce);
+            let contract_address = T::AddressMapper::to_fallback_account_id(&address);
+            ensure!(
+                !ContractCodeHash::<T>::contains_key(&contract_address),
+                Error::<T>::ContractAlreadyExists
+            );
+
+            let code_hash = Self::store_code(code)?;
+
+            // Store contract metadata
+            Self::activate_code(&contract_address, code_hash);
+            ContractOwner::<T>::insert(&contract_address, deployer);
+            Self::start_rent(&contract_address);
+
+            Self::deposit_event(Event::ContractDeployed {
+                deployer: deployer.clone(),
+                contract_address: contract_address.clone(),
+                code_hash,
+            });
+            Ok(contract_address)
+        }
+
+        /// Add `code` to `CodeStorage`, returning its hash
+        fn store_code(code: Vec<u8>) -> Result<T::Hash, DispatchError> {
+            // Validate code size
+            ensure!(
+                (code.len() as u32) <= T::MaxCodeSize::get(),
+                Error::<T>::CodeTooLarge
+            );
+
+            // Generate code hash
+            let hash_bytes = sp_io::hashing::blake2_256(&code);
+            let code_hash = T::Hash::decode(&mut &hash_bytes[..])
+                .map_err(|_| Error::<T>::InvalidBytecode)?;
+
+            // Store the code
+            let bounded_code = BoundedVec::<u8, ConstU32<1048576>>::try_from(code)
+                .map_err(|_| Error::<T>::CodeTooLarge)?;
+            CodeStorage::<T>::insert(code_hash, bounded_code);
+            Ok(code_hash)
+        }
+
+        /// Make `code_hash` the live code of `contract` as a new version
+        fn activate_code(contract: &T::AccountId, code_hash: T::Hash) -> u32 {
+            let now = frame_system::Pallet::<T>::block_number();
+            let version = CodeHistory::<T>::mutate(contract, |history| {
+                // Contracts deployed before version tracking
+                if history.is_empty() {
+                    if let Some(current) = ContractCodeHash::<T>::get(contract) {
+                        history.push(CodeVersion {
+                            version: 1,
+                            code_hash: current,
+                            activated_at: now,
+                        });
+                    }
+                }
+                let version = history.last().map_or(1, |last| last.version.saturating_add(1));
+                history.push(CodeVersion { version, code_hash, activated_at: now });
+                // Oldest versions can no longer be rolled back to
+                let excess = history.len().saturating_sub(T::MaxCodeVersions::get().max(1) as usize);
+                history.drain(..excess);
+                version
+            });
+            ContractCodeHash::<T>::insert(contract, code_hash);
+            version
+        }
+
+        /// Check that `origin` is `UpgradeOrigin` or signed by the owner of `contract`
+        fn ensure_owner_or_governance(
+            origin: OriginFor<T>,
+            contract: &T::AccountId,
+        ) -> DispatchResult {
+            let Err(origin) = T::UpgradeOrigin::try_origin(origin) else {
+                return Ok(());
+            };
+            let who = ensure_signed(origin)?;
+            ensure!(
+                ContractOwner::<T>::get(contract).as_ref() == Some(&who),
+                Error::<T>::NotContractOwner
+            );
+            Ok(())
+        }
+
+        /// Code versions of a contract, oldest first
+        pub fn code_history(contract: &T::AccountId) -> Vec<CodeVersion<T::Hash, BlockNumberFor<T>>> {
+            CodeHistory::<T>::get(contract)
+        }
+
+        /// Announced upgrade of a contract
+        pub fn pending_upgrade(
+            contract: &T::AccountId,
+        ) -> Option<PendingUpgrade<T::Hash, BlockNumberFor<T>>> {
+            PendingUpgrades::<T>::get(contract)
+        }
+
+        /// Run contract `code` against its pallet storage
+        ///
+        /// Logs are only returned for successful executions. Storage slots
+        /// cleared by the contract are credited to `refunds`. An execution
+        /// leaving the contract with more than `MaxStorageSlots` slots is
+        /// turned into a revert; callers discard its storage changes.
+        fn execute(
+            caller: &T::AccountId,
+            contract: &T::AccountId,
+            code: Vec<u8>,
+            input: Vec<u8>,
+            gas_limit: VMw,
+            value: u128,
+            refunds: &mut GasRefundManager,
+        ) -> (ExecutionResult, Vec<EventLog>) {
+            let context = ExecutionContext {
+                caller: Self::address_word(caller),
+                address: Self::address_word(contract),
+                value,
+                input,
+                gas_limit,
+                gas_price: T::VmwOperationPrice::get() as u128,
+                block_number: frame_system::Pallet::<T>::block_number().saturated_into(),
+                timestamp: Self::timestamp(),
+                chain_id: T::ChainId::get(),
+                call_stack: BTreeSet::new(),
+                reentrancy_depth: 0,
+                max_depth: 10, // Max allowed reentrancy depth
+            };
+
+            let storage = PalletStorage::<T> {
+                contract_addr: contract.clone(),
+                refunds,
+                _phantom: Default::default(),
+            };
+
+            let (result, logs) = Interpreter::new(context, code, storage).execute_with_logs();
+            // Keeps eviction bounded; the gas used is still charged
+            let slots = StorageSlots::<T>::get(contract);
+            if result.is_success() && slots > T::MaxStorageSlots::get() {
+                let reason = b"storage slot limit exceeded".to_vec();
+                return (ExecutionResult::Revert { gas_used: result.gas_used(), reason }, Vec::new());
+            }
+            (result, logs)
+        }
+
+        /// Run a contract call paid for by `payer`
+        ///
+        /// Reserves the cost of `gas_limit` upfront and moves `value` to the
+        /// contract. Afterwards the reservation is released and only the gas
+        /// used, less storage-clear refunds, is charged and routed to the
+        /// treasury. A failed execution keeps no state changes but its fee.
+        fn execute_paid(
+            payer: &T::AccountId,
+            contract: &T::AccountId,
+            code: Vec<u8>,
+            input: Vec<u8>,
+            gas_limit: VMw,
+            value: BalanceOf<T>,
+        ) -> Result<(ExecutionResult, Vec<EventLog>), DispatchError> {
+            let reserved = Self::gas_cost(gas_limit);
+            T::Currency::reserve(payer, reserved).map_err(|_| Error::<T>::InsufficientBalance)?;
+
+            let (result, logs, gas_refunded) = with_transaction(|| {
+                if let Err(e) =
+                    T::Currency::transfer(payer, contract, value, ExistenceRequirement::AllowDeath)
+                {
+                    return TransactionOutcome::Rollback(Err(e));
+                }
+
+                let mut refunds = GasRefundManager::new();
+                let (result, logs) = Self::execute(
+                    payer,
+                    contract,
+                    code,
+                    input,
+                    gas_limit,
+                    value.saturated_into(),
+                    &mut refunds,
+                );
+                if result.is_success() {
+                    let gas_refunded = refunds.calculate_actual_refund(result.gas_used());
+                    TransactionOutcome::Commit(Ok((result, logs, gas_refunded)))
+                } else {
+                    TransactionOutcome::Rollback(Ok((result, logs, 0)))
+                }
+            })?;
+
+            let gas_used = result.gas_used();
+            T::Currency::unreserve(payer, reserved);
+            let fee = T::Currency::withdraw(
+                payer,
+                Self::gas_cost(gas_used.saturating_sub(gas_refunded)),
+                WithdrawReasons::FEE,
+                ExistenceRequirement::AllowDeath,
+            )?
+            .peek();
+            T::Treasury::receive_transaction_fees(fee)?;
+
+            Self::deposit_event(Event::GasFeePaid {
+                payer: payer.clone(),
+                gas_used,
+                gas_refunded,
+                fee,
+            });
+            Ok((result, logs))
+        }
+
+        /// Weight reserved for running up to `gas`
+        pub fn gas_weight(gas: VMw) -> Weight {
+            T::WeightPerGas::get().saturating_mul(gas)
+        }
+
+        /// Gas limit of a raw Ethereum transaction, zero if it does not decode
+        ///
+        /// Undecodable transactions are rejected before any execution.
+        pub fn transaction_gas_limit(raw: &[u8]) -> VMw {
+            EthTransaction::decode(raw)
+                .map(|tx| tx.gas_limit.min(U256::from(T::MaxGasLimit::get())).low_u64())
+                .unwrap_or_default()
+        }
+
+        /// Cost in native units of `gas` at the configured operation price
+        fn gas_cost(gas: VMw) -> BalanceOf<T> {
+            etrid_vmw_gas::vmw_to_etrid(gas, T::VmwOperationPrice::get()).saturated_into()
+        }
+
+        /// Start charging rent for `contract` from the current block
+        fn start_rent(contract: &T::AccountId) {
+            ContractRent::<T>::insert(contract, RentState {
+                deposit: Zero::zero(),
+                paid_until: frame_system::Pallet::<T>::block_number(),
+            });
+        }
+
+        /// Add `amount` from `depositor` to the rent deposit of `contract`
+        fn lock_rent_deposit(
+            depositor: &T::AccountId,
+            contract: &T::AccountId,
+            amount: BalanceOf<T>,
+        ) -> DispatchResult {
+            if amount.is_zero() {
+                return Ok(());
+            }
+
+            ContractRent::<T>::mutate(contract, |rent| {
+                rent.deposit = rent.deposit.saturating_add(amount)
+            });
+            T::Currency::withdraw(
+                depositor,
+                amount,
+                WithdrawReasons::RESERVE,
+                ExistenceRequirement::KeepAlive,
+            )
+            .map_err(|_| Error::<T>::InsufficientBalance)?;
+            Self::deposit_event(Event::RentDeposited {
+                contract: contract.clone(),
+                depositor: depositor.clone(),
+                amount,
+            });
+            Ok(())
+        }
+
+        /// Charge the storage rent `contract` owes up to now
+        ///
+        /// Rent is priced on the slots held at charging time. Returns `false`
+        /// if the deposit ran out and the contract was tombstoned.
+        fn charge_rent(contract: &T::AccountId) -> Result<bool, DispatchError> {
+            let now = frame_system::Pallet::<T>::block_number();
+            let mut rent = ContractRent::<T>::get(contract);
+            let owed: BalanceOf<T> = storage_rent(
+                StorageSlots::<T>::get(contract) as u64,
+                rent.paid_until.saturated_into(),
+                now.saturated_into(),
+            )
+            .saturated_into();
+
+            let paid = owed.min(rent.deposit);
+            if !paid.is_zero() {
+                T::Treasury::receive_transaction_fees(paid)?;
+            }
+            if paid < owed {
+                Self::tombstone(contract);
+                return Ok(false);
+            }
+
+            rent.deposit = rent.deposit.saturating_sub(paid);
+            rent.paid_until = now;
+            ContractRent::<T>::insert(contract, rent);
+            Ok(true)
+        }
+
+        /// Replace a contract by a tombstone keeping its storage root
+        ///
+        /// Touches at most `MaxStorageSlots` slots, the most a contract holds.
+        fn tombstone(contract: &T::AccountId) {
+            let Some(code_hash) = ContractCodeHash::<T>::take(contract) else {
+                return;
+            };
+
+            let mut slots: Vec<(H256, H256)> =
+                ContractStorageValue::<T>::iter_prefix(contract)
+                    .take(T::MaxStorageSlots::get() as usize)
+                    .collect();
+            slots.sort_unstable_by_key(|(key, _)| *key);
+            let storage = StorageCommitment::new(
+                storage_root(&slots),
+                slots.len() as u64,
+                frame_system::Pallet::<T>::block_number().saturated_into(),
+            );
+
+            let _ = ContractStorageValue::<T>::clear_prefix(contract, T::MaxStorageSlots::get(), None);
+            StorageSlots::<T>::remove(contract);
+            ContractRent::<T>::remove(contract);
+
+            Self::deposit_event(Event::ContractTombstoned {
+                contract: contract.clone(),
+                storage_root: storage.root,
+            });
+            Tombstones::<T>::insert(contract, Tombstone { code_hash, storage });
+        }
+
+        /// Current block time in seconds
+        fn timestamp() -> u64 {
+            T::Time::now().saturated_into::<u64>() / 1_000
+        }
+
+        /// Decode a raw Ethereum transaction and check it against the chain
+        fn check_transaction(raw: &[u8]) -> Result<EthTransaction, Error<T>> {
+            let tx = EthTransaction::decode(raw).map_err(|e| match e {
+                TransactionError::InvalidSignature => Error::<T>::InvalidEthSignature,
+                TransactionError::MissingChainId => Error::<T>::InvalidChainId,
+                _ => Error::<T>::MalformedEthTransaction,
+            })?;
+
+            ensure!(tx.chain_id == T::ChainId::get(), Error::<T>::InvalidChainId);
+            ensure!(tx.nonce <= U256::from(u64::MAX), Error::<T>::InvalidNonce);
+            ensure!(tx.value <= U256::from(u128::MAX), Error::<T>::ValueTooLarge);
+            ensure!(
+                tx.gas_limit <= U256::from(T::MaxGasLimit::get()),
+                Error::<T>::GasLimitExceeded
+            );
+            if tx.to.is_none() {
+                ensure!(
+                    (tx.input.len() as u32) <= T::MaxCodeSize::get(),
+                    Error::<T>::CodeTooLarge
+                );
+            }
+            Ok(tx)
+        }
+
+        fn invalid_transaction(error: Error<T>) -> TransactionValidityError {
+            match error {
+                Error::<T>::InvalidEthSignature => InvalidTransaction::BadProof,
+                Error::<T>::GasLimitExceeded => InvalidTransaction::ExhaustsResources,
+                _ => InvalidTransaction::Call,
+            }
+            .into()
+        }
+
+        /// Hash of the extrinsic being applied
+        fn extrinsic_hash() -> H256 {
+            let index = frame_system::Pallet::<T>::extrinsic_index().unwrap_or_default();
+            H256(sp_io::hashing::blake2_256(&frame_system::ExtrinsicData::<T>::get(index)))
+        }
+
+        /// Add a receipt to the current block
+        ///
+        /// Transactions are indexed by hash off-chain, by the node's log index.
+        fn record_receipt(mut receipt: EthReceipt) {
+            receipt.logs_bloom = eth::logs_bloom(&receipt.logs);
+            if !receipt.logs.is_empty() {
+                BlockLogsBloom::<T>::mutate(|bloom| {
+                    bloom.get_or_insert_with(EventBloomFilter::new).combine(&receipt.logs_bloom)
+                });
+            }
+
+            CurrentReceipts::<T>::append(receipt);
+        }
+
+        fn eth_logs(logs: Vec<EventLog>) -> Vec<EthLog> {
+            logs.into_iter()
+                .map(|log| EthLog {
+                    address: H160::from_slice(&log.address[12..]),
+                    topics: log.topics,
+                    data: log.data,
+                })
+                .collect()
+        }
+
+        /// Nonce of an Ethereum address
+        pub fn eth_nonce(address: H160) -> u64 {
+            EthNonce::<T>::get(address)
+        }
+
+        /// Bytecode at an Ethereum address, empty if none
+        pub fn code_at(address: H160) -> Vec<u8> {
+            Self::contract_code(&Self::account_id(&address)).unwrap_or_default()
+        }
+
+        /// Contract storage slot at an Ethereum address
+        pub fn storage_at(address: H160, key: H256) -> H256 {
+            ContractStorageValue::<T>::get(Self::account_id(&address), key).unwrap_or_default()
+        }
+
+        /// Dry-run a call, or a deployment when `to` is `None`
+        ///
+        /// Storage writes are not discarded here; runtime API calls run on a
+        /// throwaway overlay.
+        pub fn eth_call(
+            from: H160,
+            to: Option<H160>,
+            value: u128,
+            data: Vec<u8>,
+            gas_limit: Option<VMw>,
+        ) -> EthCallInfo {
+            let gas_limit = gas_limit
+                .unwrap_or_else(T::DefaultGasLimit::get)
+                .min(T::MaxGasLimit::get());
+            let caller = Self::account_id(&from);
+
+            let Some(to) = to else {
+                let result = if (data.len() as u32) <= T::MaxCodeSize::get() {
+                    ExecutionResult::Success { gas_used: 0, return_data: Vec::new() }
+                } else {
+                    ExecutionResult::Error(b"code too large".to_vec())
+                };
+                return EthCallInfo {
+                    result,
+                    contract_address: Some(eth::create_address(&from, EthNonce::<T>::get(from))),
+                };
+            };
+
+            let contract = Self::account_id(&to);
+            let result = match Self::contract_code(&contract) {
+                Some(code) => {
+                    let mut refunds = GasRefundManager::new();
+                    Self::execute(&caller, &contract, code, data, gas_limit, value, &mut refunds).0
+                }
+                None => ExecutionResult::Success { gas_used: 0, return_data: Vec::new() },
+            };
+            EthCallInfo { result, contract_address: None }
+        }
+
+        /// Receipts of the ETWasm executions in the current block
+        pub fn current_receipts() -> Vec<EthReceipt> {
+            CurrentReceipts::<T>::get()
         }
 
         /// Charge gas for execution
@@ -419,18 +1580,89 @@ pub mod pallet {
     }
 
     /// Storage backend implementation for pallet storage
-    pub struct PalletStorage<T: Config> {
+    ///
+    /// Zero values are removed, as in the EVM, and credit a refund if the
+    /// slot was set.
+    pub struct PalletStorage<'a, T: Config> {
         contract_addr: T::AccountId,
+        refunds: &'a mut GasRefundManager,
         _phantom: core::marker::PhantomData<T>,
     }
 
-    impl<T: Config> StorageBackend for PalletStorage<T> {
+    impl<T: Config> StorageBackend for PalletStorage<'_, T> {
         fn read(&self, key: &H256) -> Option<H256> {
             ContractStorageValue::<T>::get(&self.contract_addr, key)
         }
 
         fn write(&mut self, key: H256, value: H256) {
-            ContractStorageValue::<T>::insert(&self.contract_addr, key, value);
+            let previous = if value.is_zero() {
+                ContractStorageValue::<T>::take(&self.contract_addr, key)
+            } else {
+                ContractStorageValue::<T>::mutate(&self.contract_addr, key, |slot| {
+                    slot.replace(value)
+                })
+            };
+
+            match (previous, value.is_zero()) {
+                (None, false) => StorageSlots::<T>::mutate(&self.contract_addr, |slots| {
+                    *slots = slots.saturating_add(1)
+                }),
+                (Some(previous), true) => {
+                    StorageSlots::<T>::mutate(&self.contract_addr, |slots| {
+                        *slots = slots.saturating_sub(1)
+                    });
+                    if !previous.is_zero() {
+                        self.refunds.add_storage_clear_refund();
+                    }
+                }
+                _ => {}
+            }
         }
     }
 }
+
+sp_api::decl_runtime_apis! {
+    /// Code versions and upgrades of ETWasm contracts
+    pub trait EtwasmContractsApi<AccountId, BlockNumber> where
+        AccountId: codec::Codec,
+        BlockNumber: codec::Codec,
+    {
+        /// Code versions of a contract, oldest first; the last one is live
+        fn code_history(contract: AccountId) -> Vec<CodeVersion<H256, BlockNumber>>;
+
+        /// Announced upgrade of a contract
+        fn pending_upgrade(contract: AccountId) -> Option<PendingUpgrade<H256, BlockNumber>>;
+    }
+
+    /// Ethereum view of ETWasm contracts, served by the node's `eth_*` RPC
+    pub trait EtwasmEthApi {
+        /// EIP-155 chain ID
+        fn chain_id() -> u64;
+
+        /// Transaction count (nonce) of an Ethereum address
+        fn transaction_count(address: H160) -> u64;
+
+        /// Contract bytecode at an Ethereum address, empty if none
+        fn code(address: H160) -> Vec<u8>;
+
+        /// Contract storage slot
+        fn storage_at(address: H160, key: H256) -> H256;
+
+        /// Dry-run a call, or a deployment when `to` is `None`
+        ///
+        /// `value` is visible to the contract but not transferred.
+        fn call(
+            from: H160,
+            to: Option<H160>,
+            value: u128,
+            data: Vec<u8>,
+            gas_limit: Option<u64>,
+        ) -> EthCallInfo;
+
+        /// Receipts of the ETWasm executions in this block, in order
+        fn receipts() -> Vec<EthReceipt>;
+
+        /// Wrap a raw signed Ethereum transaction into an extrinsic
+        fn convert_transaction(transaction: Vec<u8>) -> <Block as BlockT>::Extrinsic;
+    }
+}
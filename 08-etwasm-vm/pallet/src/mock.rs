@@ -0,0 +1,166 @@
+//! Mock runtime for ETWasm VM pallet tests
+
+use crate as pallet_etwasm_vm;
+use crate::{AccountId32Mapper, Event, FeeTreasury};
+use etwasm_opcodes::{PUSH1, SSTORE, STOP};
+use frame_support::{
+    assert_ok, derive_impl, parameter_types,
+    traits::{ConstU32, ConstU64, Time},
+    weights::Weight,
+};
+use frame_system::EnsureRoot;
+use sp_keystore::{testing::MemoryKeystore, KeystoreExt};
+use sp_runtime::{traits::IdentityLookup, AccountId32, BuildStorage, DispatchResult};
+
+type Block = frame_system::mocking::MockBlock<Test>;
+
+frame_support::construct_runtime!(
+    pub enum Test
+    {
+        System: frame_system,
+        Balances: pallet_balances,
+        EtwasmVm: pallet_etwasm_vm,
+    }
+);
+
+#[derive_impl(frame_system::config_preludes::TestDefaultConfig)]
+impl frame_system::Config for Test {
+    type Block = Block;
+    type AccountId = AccountId32;
+    type Lookup = IdentityLookup<Self::AccountId>;
+    type AccountData = pallet_balances::AccountData<u128>;
+}
+
+parameter_types! {
+    pub const ExistentialDeposit: u128 = 1;
+    /// Total received by `MockTreasury`
+    pub static TreasuryFees: u128 = 0;
+    pub const WeightPerGas: Weight = Weight::from_parts(1_000, 0);
+}
+
+#[derive_impl(pallet_balances::config_preludes::TestDefaultConfig)]
+impl pallet_balances::Config for Test {
+    type Balance = u128;
+    type ExistentialDeposit = ExistentialDeposit;
+    type AccountStore = System;
+}
+
+/// Treasury that records the fees it receives
+pub struct MockTreasury;
+impl FeeTreasury<u128> for MockTreasury {
+    fn receive_transaction_fees(amount: u128) -> DispatchResult {
+        TreasuryFees::mutate(|fees| *fees += amount);
+        Ok(())
+    }
+}
+
+/// Block time fixed at zero
+pub struct MockTime;
+impl Time for MockTime {
+    type Moment = u64;
+
+    fn now() -> u64 {
+        0
+    }
+}
+
+/// Blocks between announcing and enacting an upgrade
+pub const UPGRADE_DELAY: u64 = 10;
+
+/// Code versions kept per contract
+pub const MAX_CODE_VERSIONS: u32 = 3;
+
+/// Storage slots a contract may hold
+pub const MAX_STORAGE_SLOTS: u32 = 4;
+
+impl pallet_etwasm_vm::Config for Test {
+    type MaxCodeSize = ConstU32<1_024>;
+    type DefaultGasLimit = ConstU64<100_000>;
+    type MaxGasLimit = ConstU64<1_000_000>;
+    type WeightPerGas = WeightPerGas;
+    // One VMw costs one unit of balance
+    type VmwOperationPrice = ConstU32<1_000_000>;
+    type AddressMapper = AccountId32Mapper;
+    type ChainId = ConstU64<42>;
+    type Currency = Balances;
+    type Time = MockTime;
+    type Treasury = MockTreasury;
+    type UpgradeOrigin = EnsureRoot<AccountId32>;
+    type UpgradeDelay = ConstU64<UPGRADE_DELAY>;
+    type MaxCodeVersions = ConstU32<MAX_CODE_VERSIONS>;
+    type MaxStorageSlots = ConstU32<MAX_STORAGE_SLOTS>;
+    type MaxRestoreEntries = ConstU32<MAX_STORAGE_SLOTS>;
+    type WeightInfo = ();
+}
+
+pub const ALICE: AccountId32 = AccountId32::new([1u8; 32]);
+pub const BOB: AccountId32 = AccountId32::new([2u8; 32]);
+/// Holds less than the default gas limit
+pub const CHARLIE: AccountId32 = AccountId32::new([3u8; 32]);
+pub const CHARLIE_BALANCE: u128 = 60_000;
+pub const INITIAL_BALANCE: u128 = 10_000_000;
+
+/// Code that halts immediately
+pub fn stop_code() -> Vec<u8> {
+    vec![STOP]
+}
+
+/// Code that stores `value` at slot `key`
+pub fn store_code(key: u8, value: u8) -> Vec<u8> {
+    vec![PUSH1, value, PUSH1, key, SSTORE, STOP]
+}
+
+/// Code that stores `value` at slots `1..=slots`
+pub fn store_slots_code(slots: u8, value: u8) -> Vec<u8> {
+    let mut code: Vec<u8> = (1..=slots)
+        .flat_map(|key| [PUSH1, value, PUSH1, key, SSTORE])
+        .collect();
+    code.push(STOP);
+    code
+}
+
+/// Deploy `code` from `deployer` and return the contract account
+pub fn deploy(deployer: AccountId32, code: Vec<u8>, rent_deposit: u128) -> AccountId32 {
+    assert_ok!(EtwasmVm::deploy_contract(
+        RuntimeOrigin::signed(deployer),
+        code,
+        rent_deposit
+    ));
+    System::events()
+        .into_iter()
+        .rev()
+        .find_map(|record| match record.event {
+            RuntimeEvent::EtwasmVm(Event::ContractDeployed {
+                contract_address, ..
+            }) => Some(contract_address),
+            _ => None,
+        })
+        .expect("contract deployed; qed")
+}
+
+// Build genesis storage; Alice and Bob are funded, Charlie holds `CHARLIE_BALANCE`
+// A keystore lets benchmarks sign Ethereum transactions
+pub fn new_test_ext() -> sp_io::TestExternalities {
+    let mut t = frame_system::GenesisConfig::<Test>::default()
+        .build_storage()
+        .unwrap();
+
+    pallet_balances::GenesisConfig::<Test> {
+        balances: vec![
+            (ALICE, INITIAL_BALANCE),
+            (BOB, INITIAL_BALANCE),
+            (CHARLIE, CHARLIE_BALANCE),
+        ],
+        ..Default::default()
+    }
+    .assimilate_storage(&mut t)
+    .unwrap();
+
+    let mut ext = sp_io::TestExternalities::new(t);
+    ext.register_extension(KeystoreExt::new(MemoryKeystore::new()));
+    ext.execute_with(|| {
+        System::set_block_number(1);
+        TreasuryFees::set(0);
+    });
+    ext
+}
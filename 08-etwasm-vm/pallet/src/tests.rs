@@ -0,0 +1,744 @@
+//! Tests for ETWasm VM pallet
+
+use crate::{
+    mock::*, CodeHistory, ContractCodeHash, ContractRent, ContractStorageValue, Error, Event,
+    PendingUpgrades, RentState, StorageSlots, Tombstones, WeightInfo,
+};
+use etwasm_gas_metering::VMw;
+use etwasm_opcodes::{PUSH1, REVERT, SSTORE};
+use etwasm_runtime::{storage_proof, storage_root, StorageMerkleProof};
+use frame_support::{
+    assert_noop, assert_ok, assert_storage_noop,
+    dispatch::{DispatchResult, GetDispatchInfo},
+    traits::Get,
+};
+use sp_core::H256;
+use sp_runtime::AccountId32;
+
+fn call(
+    caller: AccountId32,
+    contract: &AccountId32,
+    value: u128,
+    gas_limit: Option<VMw>,
+) -> DispatchResult {
+    EtwasmVm::call_contract(
+        RuntimeOrigin::signed(caller),
+        contract.clone(),
+        value,
+        Vec::new(),
+        gas_limit,
+    )
+}
+
+fn code_hash(code: &[u8]) -> H256 {
+    H256(sp_io::hashing::blake2_256(code))
+}
+
+fn announce(contract: &AccountId32, code: Vec<u8>, migration: Option<Vec<u8>>) -> DispatchResult {
+    EtwasmVm::upgrade_contract(
+        RuntimeOrigin::signed(ALICE),
+        contract.clone(),
+        code,
+        migration,
+    )
+}
+
+fn enact(contract: &AccountId32) -> DispatchResult {
+    EtwasmVm::enact_upgrade(RuntimeOrigin::signed(ALICE), contract.clone())
+        .map(|_| ())
+        .map_err(|e| e.error)
+}
+
+/// Announce `code` and enact it once the timelock has passed
+fn upgrade(contract: &AccountId32, code: Vec<u8>) {
+    assert_ok!(announce(contract, code, None));
+    System::set_block_number(System::block_number() + UPGRADE_DELAY);
+    assert_ok!(enact(contract));
+}
+
+fn versions(contract: &AccountId32) -> Vec<u32> {
+    CodeHistory::<Test>::get(contract)
+        .iter()
+        .map(|v| v.version)
+        .collect()
+}
+
+fn slot(key: u64) -> H256 {
+    H256::from_low_u64_be(key)
+}
+
+/// Deploy a contract holding two slots, with `rent_deposit` paid up to block 1
+fn two_slot_contract(rent_deposit: u128) -> AccountId32 {
+    let contract = deploy(ALICE, store_slots_code(2, 7), rent_deposit);
+    assert_ok!(call(BOB, &contract, 0, None));
+    assert_eq!(StorageSlots::<Test>::get(&contract), 2);
+    contract
+}
+
+fn restore(contract: &AccountId32, slots: Vec<StorageMerkleProof>) -> DispatchResult {
+    let slots = slots.try_into().expect("at most MaxRestoreEntries proofs; qed");
+    EtwasmVm::restore_contract(RuntimeOrigin::signed(BOB), contract.clone(), slots, 500)
+}
+
+/// `(gas_used, gas_refunded, fee)` of the last `GasFeePaid` event
+fn last_gas_fee() -> (VMw, VMw, u128) {
+    System::events()
+        .into_iter()
+        .rev()
+        .find_map(|record| match record.event {
+            RuntimeEvent::EtwasmVm(Event::GasFeePaid {
+                gas_used,
+                gas_refunded,
+                fee,
+                ..
+            }) => Some((gas_used, gas_refunded, fee)),
+            _ => None,
+        })
+        .expect("gas fee paid; qed")
+}
+
+#[test]
+fn call_transfers_value_to_contract() {
+    new_test_ext().execute_with(|| {
+        let contract = deploy(ALICE, stop_code(), 0);
+
+        assert_ok!(call(BOB, &contract, 1_000, None));
+
+        let (_, _, fee) = last_gas_fee();
+        assert_eq!(Balances::free_balance(&contract), 1_000);
+        assert_eq!(Balances::free_balance(&BOB), INITIAL_BALANCE - 1_000 - fee);
+        System::assert_last_event(
+            Event::ContractExecuted {
+                contract,
+                gas_used: last_gas_fee().0,
+                success: true,
+            }
+            .into(),
+        );
+    });
+}
+
+#[test]
+fn call_releases_gas_reservation_and_charges_gas_used() {
+    new_test_ext().execute_with(|| {
+        let contract = deploy(ALICE, store_code(1, 7), 0);
+        let issuance = Balances::total_issuance();
+
+        assert_ok!(call(BOB, &contract, 0, Some(50_000)));
+
+        // One VMw costs one unit, and the 50,000 reserved upfront is released
+        let (gas_used, gas_refunded, fee) = last_gas_fee();
+        assert!(gas_used > 0 && gas_used < 50_000);
+        assert_eq!(gas_refunded, 0);
+        assert_eq!(fee, gas_used as u128);
+        assert_eq!(Balances::reserved_balance(&BOB), 0);
+        assert_eq!(Balances::free_balance(&BOB), INITIAL_BALANCE - fee);
+        assert_eq!(Balances::total_issuance(), issuance - fee);
+        assert_eq!(
+            ContractStorageValue::<Test>::get(&contract, H256::from_low_u64_be(1)),
+            Some(H256::from_low_u64_be(7))
+        );
+    });
+}
+
+#[test]
+fn treasury_receives_fee_less_storage_refunds() {
+    new_test_ext().execute_with(|| {
+        // Clears a slot that is set, earning a refund
+        let contract = deploy(ALICE, store_code(1, 0), 0);
+        ContractStorageValue::<Test>::insert(
+            &contract,
+            H256::from_low_u64_be(1),
+            H256::from_low_u64_be(7),
+        );
+        StorageSlots::<Test>::insert(&contract, 1);
+
+        assert_ok!(call(BOB, &contract, 0, None));
+
+        let (gas_used, gas_refunded, fee) = last_gas_fee();
+        assert!(gas_refunded > 0);
+        assert_eq!(fee, (gas_used - gas_refunded) as u128);
+        assert_eq!(TreasuryFees::get(), fee);
+        assert_eq!(Balances::free_balance(&BOB), INITIAL_BALANCE - fee);
+        assert_eq!(StorageSlots::<Test>::get(&contract), 0);
+        System::assert_has_event(
+            Event::GasFeePaid {
+                payer: BOB,
+                gas_used,
+                gas_refunded,
+                fee,
+            }
+            .into(),
+        );
+    });
+}
+
+#[test]
+fn call_fails_when_caller_cannot_cover_value_and_gas() {
+    new_test_ext().execute_with(|| {
+        let contract = deploy(ALICE, stop_code(), 0);
+
+        // The gas limit alone is more than Charlie holds
+        assert_noop!(
+            call(CHARLIE, &contract, 0, Some(100_000)),
+            Error::<Test>::InsufficientBalance
+        );
+
+        // The gas limit is covered but not the value on top of it
+        assert_storage_noop!(assert!(
+            call(CHARLIE, &contract, 20_000, Some(50_000)).is_err()
+        ));
+        assert_eq!(Balances::free_balance(&CHARLIE), CHARLIE_BALANCE);
+        assert_eq!(Balances::reserved_balance(&CHARLIE), 0);
+        assert_eq!(Balances::free_balance(&contract), 0);
+        assert_eq!(TreasuryFees::get(), 0);
+
+        // Both together fit
+        assert_ok!(call(CHARLIE, &contract, 5_000, Some(50_000)));
+        assert_eq!(Balances::free_balance(&contract), 5_000);
+    });
+}
+
+#[test]
+fn reverted_call_keeps_its_fee_and_leaves_failed_receipt() {
+    new_test_ext().execute_with(|| {
+        // Stores a slot, then reverts
+        let code = vec![PUSH1, 7, PUSH1, 1, SSTORE, PUSH1, 0, PUSH1, 0, REVERT];
+        let contract = deploy(ALICE, code, 0);
+
+        assert_ok!(call(BOB, &contract, 1_000, None));
+
+        let (gas_used, _, fee) = last_gas_fee();
+        assert!(gas_used > 0);
+        assert_eq!(fee, gas_used as u128);
+        assert_eq!(TreasuryFees::get(), fee);
+        assert_eq!(Balances::free_balance(&BOB), INITIAL_BALANCE - fee);
+        assert_eq!(Balances::free_balance(&contract), 0);
+        assert_eq!(ContractStorageValue::<Test>::get(&contract, slot(1)), None);
+        assert!(!EtwasmVm::current_receipts().last().expect("call receipt; qed").success);
+        System::assert_has_event(
+            Event::ContractReverted { contract: contract.clone(), reason: Vec::new(), gas_used }
+                .into(),
+        );
+        System::assert_last_event(
+            Event::ContractExecuted { contract, gas_used, success: false }.into(),
+        );
+    });
+}
+
+#[test]
+fn out_of_gas_call_is_charged_its_gas_limit() {
+    new_test_ext().execute_with(|| {
+        let contract = deploy(ALICE, store_code(1, 7), 0);
+
+        assert_ok!(call(BOB, &contract, 0, Some(5)));
+
+        assert_eq!(last_gas_fee(), (5, 0, 5));
+        assert_eq!(Balances::free_balance(&BOB), INITIAL_BALANCE - 5);
+        assert_eq!(Balances::reserved_balance(&BOB), 0);
+        assert_eq!(StorageSlots::<Test>::get(&contract), 0);
+        System::assert_last_event(
+            Event::ContractExecuted { contract, gas_used: 5, success: false }.into(),
+        );
+    });
+}
+
+#[test]
+fn failed_bytecode_execution_is_reported_not_rejected() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(EtwasmVm::execute_bytecode(
+            RuntimeOrigin::signed(BOB),
+            store_code(1, 7),
+            5
+        ));
+
+        System::assert_last_event(
+            Event::BytecodeExecuted { caller: BOB, gas_used: 5, success: false }.into(),
+        );
+    });
+}
+
+#[test]
+fn call_weight_grows_with_gas_limit() {
+    let weight = |gas_limit| {
+        crate::Call::<Test>::call_contract {
+            contract_addr: ALICE,
+            value: 0,
+            input_data: Vec::new(),
+            gas_limit,
+        }
+        .get_dispatch_info()
+        .call_weight
+    };
+
+    assert!(weight(Some(500_000)).ref_time() > weight(Some(1_000)).ref_time());
+    assert_eq!(weight(None), weight(Some(<Test as crate::Config>::DefaultGasLimit::get())));
+}
+
+#[test]
+fn upgrade_is_enacted_only_after_timelock() {
+    new_test_ext().execute_with(|| {
+        let contract = deploy(ALICE, stop_code(), 0);
+        let new_code = store_code(1, 7);
+
+        assert_ok!(announce(&contract, new_code.clone(), None));
+        let enactable_at = 1 + UPGRADE_DELAY;
+        System::assert_last_event(
+            Event::UpgradeAnnounced {
+                contract: contract.clone(),
+                code_hash: code_hash(&new_code),
+                enactable_at,
+            }
+            .into(),
+        );
+        assert_noop!(
+            announce(&contract, store_code(1, 8), None),
+            Error::<Test>::UpgradeAlreadyPending
+        );
+
+        assert_noop!(enact(&contract), Error::<Test>::UpgradeTimelocked);
+        System::set_block_number(enactable_at - 1);
+        assert_noop!(enact(&contract), Error::<Test>::UpgradeTimelocked);
+        assert_eq!(
+            ContractCodeHash::<Test>::get(&contract),
+            Some(code_hash(&stop_code()))
+        );
+
+        System::set_block_number(enactable_at);
+        assert_ok!(enact(&contract));
+
+        System::assert_last_event(
+            Event::ContractUpgraded {
+                contract: contract.clone(),
+                version: 2,
+                code_hash: code_hash(&new_code),
+            }
+            .into(),
+        );
+        assert_eq!(
+            ContractCodeHash::<Test>::get(&contract),
+            Some(code_hash(&new_code))
+        );
+        assert!(!PendingUpgrades::<Test>::contains_key(&contract));
+        assert_eq!(versions(&contract), vec![1, 2]);
+        assert_eq!(
+            CodeHistory::<Test>::get(&contract)[1].activated_at,
+            enactable_at
+        );
+    });
+}
+
+#[test]
+fn upgrade_requires_owner_or_governance() {
+    new_test_ext().execute_with(|| {
+        let contract = deploy(ALICE, stop_code(), 0);
+
+        assert_noop!(
+            EtwasmVm::upgrade_contract(
+                RuntimeOrigin::signed(BOB),
+                contract.clone(),
+                store_code(1, 7),
+                None
+            ),
+            Error::<Test>::NotContractOwner
+        );
+        assert_ok!(EtwasmVm::upgrade_contract(
+            RuntimeOrigin::root(),
+            contract.clone(),
+            store_code(1, 7),
+            None
+        ));
+        assert_noop!(
+            EtwasmVm::cancel_upgrade(RuntimeOrigin::signed(BOB), contract.clone()),
+            Error::<Test>::NotContractOwner
+        );
+    });
+}
+
+#[test]
+fn migration_runs_on_new_code_with_the_swap() {
+    new_test_ext().execute_with(|| {
+        let contract = deploy(ALICE, stop_code(), 0);
+
+        assert_ok!(announce(&contract, store_code(1, 7), Some(Vec::new())));
+        System::set_block_number(1 + UPGRADE_DELAY);
+        assert_ok!(enact(&contract));
+
+        assert_eq!(
+            ContractStorageValue::<Test>::get(&contract, H256::from_low_u64_be(1)),
+            Some(H256::from_low_u64_be(7))
+        );
+    });
+}
+
+#[test]
+fn migration_is_weighed_by_gas_limit_and_refunded_to_gas_used() {
+    new_test_ext().execute_with(|| {
+        let contract = deploy(ALICE, stop_code(), 0);
+        assert_ok!(announce(&contract, store_code(1, 7), Some(Vec::new())));
+        System::set_block_number(1 + UPGRADE_DELAY);
+
+        let base = <() as WeightInfo>::enact_upgrade();
+        let declared = crate::Call::<Test>::enact_upgrade { contract_addr: contract.clone() }
+            .get_dispatch_info()
+            .call_weight;
+        assert_eq!(
+            declared,
+            base.saturating_add(EtwasmVm::gas_weight(<Test as crate::Config>::DefaultGasLimit::get()))
+        );
+
+        let post_info = EtwasmVm::enact_upgrade(RuntimeOrigin::signed(ALICE), contract.clone())
+            .expect("timelock passed; qed");
+        let gas_used = EtwasmVm::gas_used();
+        assert!(gas_used > 0);
+        assert_eq!(post_info.actual_weight, Some(base.saturating_add(EtwasmVm::gas_weight(gas_used))));
+    });
+}
+
+#[test]
+fn cancelled_upgrade_cannot_be_enacted() {
+    new_test_ext().execute_with(|| {
+        let contract = deploy(ALICE, stop_code(), 0);
+        assert_ok!(announce(&contract, store_code(1, 7), None));
+
+        assert_ok!(EtwasmVm::cancel_upgrade(
+            RuntimeOrigin::signed(ALICE),
+            contract.clone()
+        ));
+        System::assert_last_event(
+            Event::UpgradeCancelled {
+                contract: contract.clone(),
+            }
+            .into(),
+        );
+        assert!(!PendingUpgrades::<Test>::contains_key(&contract));
+
+        System::set_block_number(1 + UPGRADE_DELAY);
+        assert_noop!(enact(&contract), Error::<Test>::NoPendingUpgrade);
+        assert_noop!(
+            EtwasmVm::cancel_upgrade(RuntimeOrigin::signed(ALICE), contract.clone()),
+            Error::<Test>::NoPendingUpgrade
+        );
+        assert_eq!(
+            ContractCodeHash::<Test>::get(&contract),
+            Some(code_hash(&stop_code()))
+        );
+
+        // A new upgrade can be announced
+        assert_ok!(announce(&contract, store_code(1, 8), None));
+    });
+}
+
+#[test]
+fn rollback_restores_previous_code_hash() {
+    new_test_ext().execute_with(|| {
+        let contract = deploy(ALICE, stop_code(), 0);
+        assert_noop!(
+            EtwasmVm::rollback_contract(RuntimeOrigin::signed(ALICE), contract.clone()),
+            Error::<Test>::NoPreviousVersion
+        );
+        upgrade(&contract, store_code(1, 7));
+
+        assert_noop!(
+            EtwasmVm::rollback_contract(RuntimeOrigin::signed(BOB), contract.clone()),
+            Error::<Test>::NotContractOwner
+        );
+        assert_ok!(EtwasmVm::rollback_contract(
+            RuntimeOrigin::signed(ALICE),
+            contract.clone()
+        ));
+
+        System::assert_last_event(
+            Event::ContractRolledBack {
+                contract: contract.clone(),
+                version: 1,
+                code_hash: code_hash(&stop_code()),
+            }
+            .into(),
+        );
+        assert_eq!(
+            ContractCodeHash::<Test>::get(&contract),
+            Some(code_hash(&stop_code()))
+        );
+        assert_eq!(versions(&contract), vec![1]);
+
+        // The next upgrade continues from the live version
+        upgrade(&contract, store_code(1, 8));
+        assert_eq!(versions(&contract), vec![1, 2]);
+    });
+}
+
+#[test]
+fn code_history_is_bounded() {
+    new_test_ext().execute_with(|| {
+        let contract = deploy(ALICE, stop_code(), 0);
+        assert_eq!(versions(&contract), vec![1]);
+
+        for value in 2..=3 {
+            upgrade(&contract, store_code(1, value));
+        }
+        assert_eq!(versions(&contract), vec![1, 2, 3]);
+
+        // The oldest versions are dropped past `MaxCodeVersions`
+        for value in 4..=5 {
+            upgrade(&contract, store_code(1, value));
+        }
+        assert_eq!(versions(&contract).len() as u32, MAX_CODE_VERSIONS);
+        assert_eq!(versions(&contract), vec![3, 4, 5]);
+        assert_eq!(
+            EtwasmVm::code_history(&contract),
+            CodeHistory::<Test>::get(&contract)
+        );
+
+        // Only kept versions can be rolled back to
+        for _ in 0..2 {
+            assert_ok!(EtwasmVm::rollback_contract(
+                RuntimeOrigin::signed(ALICE),
+                contract.clone()
+            ));
+        }
+        assert_eq!(
+            ContractCodeHash::<Test>::get(&contract),
+            Some(code_hash(&store_code(1, 3)))
+        );
+        assert_noop!(
+            EtwasmVm::rollback_contract(RuntimeOrigin::signed(ALICE), contract.clone()),
+            Error::<Test>::NoPreviousVersion
+        );
+    });
+}
+
+#[test]
+fn rent_is_charged_over_time() {
+    new_test_ext().execute_with(|| {
+        let contract = deploy(ALICE, store_code(1, 7), 10_000);
+        assert_eq!(Balances::free_balance(&ALICE), INITIAL_BALANCE - 10_000);
+        assert_eq!(
+            ContractRent::<Test>::get(&contract),
+            RentState {
+                deposit: 10_000,
+                paid_until: 1
+            }
+        );
+
+        // Nothing is owed while the contract holds no storage
+        assert_ok!(call(BOB, &contract, 0, None));
+        assert_eq!(StorageSlots::<Test>::get(&contract), 1);
+        assert_eq!(ContractRent::<Test>::get(&contract).deposit, 10_000);
+
+        // One 32-byte slot for ten blocks
+        System::set_block_number(11);
+        let treasury = TreasuryFees::get();
+        assert_ok!(call(BOB, &contract, 0, None));
+        let (_, _, fee) = last_gas_fee();
+        assert_eq!(
+            ContractRent::<Test>::get(&contract),
+            RentState {
+                deposit: 9_680,
+                paid_until: 11
+            }
+        );
+        assert_eq!(TreasuryFees::get(), treasury + 320 + fee);
+
+        // Anyone can top the deposit up
+        assert_ok!(EtwasmVm::deposit_rent(
+            RuntimeOrigin::signed(BOB),
+            contract.clone(),
+            1_000
+        ));
+        System::assert_last_event(
+            Event::RentDeposited {
+                contract: contract.clone(),
+                depositor: BOB,
+                amount: 1_000,
+            }
+            .into(),
+        );
+        assert_eq!(ContractRent::<Test>::get(&contract).deposit, 10_680);
+    });
+}
+
+#[test]
+fn contract_is_tombstoned_when_deposit_runs_out() {
+    new_test_ext().execute_with(|| {
+        let contract = two_slot_contract(100);
+        let slots = vec![(slot(1), slot(7)), (slot(2), slot(7))];
+
+        // Two slots for one block cost 64 of the 100 deposited
+        System::set_block_number(2);
+        assert_noop!(
+            EtwasmVm::evict_contract(RuntimeOrigin::signed(BOB), contract.clone()),
+            Error::<Test>::RentNotExhausted
+        );
+
+        // Four blocks cost 256
+        System::set_block_number(5);
+        let treasury = TreasuryFees::get();
+        let post_info = EtwasmVm::evict_contract(RuntimeOrigin::signed(BOB), contract.clone())
+            .expect("deposit ran out; qed");
+
+        // Refunded down to the two slots evicted
+        assert_eq!(post_info.actual_weight, Some(<() as WeightInfo>::evict_contract(2)));
+
+        System::assert_last_event(
+            Event::ContractTombstoned {
+                contract: contract.clone(),
+                storage_root: storage_root(&slots),
+            }
+            .into(),
+        );
+        // What was left of the deposit is paid
+        assert_eq!(TreasuryFees::get(), treasury + 100);
+        let tombstone = Tombstones::<Test>::get(&contract).unwrap();
+        assert_eq!(tombstone.code_hash, code_hash(&store_slots_code(2, 7)));
+        assert_eq!(
+            (tombstone.storage.root, tombstone.storage.slot_count),
+            (storage_root(&slots), 2)
+        );
+        assert!(ContractCodeHash::<Test>::get(&contract).is_none());
+        assert!(ContractStorageValue::<Test>::iter_prefix(&contract)
+            .next()
+            .is_none());
+        assert_eq!(StorageSlots::<Test>::get(&contract), 0);
+        assert!(!ContractRent::<Test>::contains_key(&contract));
+
+        assert_noop!(
+            call(BOB, &contract, 0, None),
+            Error::<Test>::ContractNotFound
+        );
+    });
+}
+
+#[test]
+fn call_leaving_too_many_slots_is_reverted() {
+    new_test_ext().execute_with(|| {
+        let contract = deploy(ALICE, store_slots_code(MAX_STORAGE_SLOTS as u8 + 1, 7), 0);
+
+        assert_ok!(call(BOB, &contract, 0, Some(200_000)));
+
+        let (gas_used, _, fee) = last_gas_fee();
+        assert!(fee > 0);
+        assert_eq!(StorageSlots::<Test>::get(&contract), 0);
+        assert!(ContractStorageValue::<Test>::iter_prefix(&contract).next().is_none());
+        System::assert_last_event(
+            Event::ContractExecuted { contract: contract.clone(), gas_used, success: false }.into(),
+        );
+
+        // Up to the limit is fine
+        let contract = deploy(ALICE, store_slots_code(MAX_STORAGE_SLOTS as u8, 7), 0);
+        assert_ok!(call(BOB, &contract, 0, Some(200_000)));
+        assert_eq!(StorageSlots::<Test>::get(&contract), MAX_STORAGE_SLOTS);
+    });
+}
+
+#[test]
+fn call_tombstones_contract_instead_of_running_it() {
+    new_test_ext().execute_with(|| {
+        let contract = two_slot_contract(100);
+
+        System::set_block_number(5);
+        assert_ok!(call(BOB, &contract, 1_000, None));
+
+        assert!(Tombstones::<Test>::contains_key(&contract));
+        assert_eq!(Balances::free_balance(&contract), 0);
+        assert_eq!(Balances::reserved_balance(&BOB), 0);
+    });
+}
+
+#[test]
+fn restore_with_valid_proofs() {
+    new_test_ext().execute_with(|| {
+        let contract = two_slot_contract(100);
+        let slots = vec![(slot(1), slot(7)), (slot(2), slot(7))];
+        System::set_block_number(5);
+        assert_ok!(EtwasmVm::evict_contract(
+            RuntimeOrigin::signed(BOB),
+            contract.clone()
+        ));
+
+        let proofs = slots
+            .iter()
+            .map(|(key, _)| storage_proof(&slots, key).unwrap())
+            .collect();
+        assert_ok!(restore(&contract, proofs));
+
+        System::assert_last_event(
+            Event::ContractRestored {
+                contract: contract.clone(),
+                restorer: BOB,
+            }
+            .into(),
+        );
+        assert!(!Tombstones::<Test>::contains_key(&contract));
+        assert_eq!(
+            ContractCodeHash::<Test>::get(&contract),
+            Some(code_hash(&store_slots_code(2, 7)))
+        );
+        assert_eq!(
+            ContractStorageValue::<Test>::get(&contract, slot(1)),
+            Some(slot(7))
+        );
+        assert_eq!(
+            ContractStorageValue::<Test>::get(&contract, slot(2)),
+            Some(slot(7))
+        );
+        assert_eq!(StorageSlots::<Test>::get(&contract), 2);
+        assert_eq!(
+            ContractRent::<Test>::get(&contract),
+            RentState {
+                deposit: 500,
+                paid_until: 5
+            }
+        );
+
+        // The restored contract runs again
+        assert_ok!(call(BOB, &contract, 0, None));
+        assert_noop!(restore(&contract, Vec::new()), Error::<Test>::NotTombstoned);
+    });
+}
+
+#[test]
+fn restore_with_invalid_proofs_is_rejected() {
+    new_test_ext().execute_with(|| {
+        let contract = two_slot_contract(100);
+        let slots = vec![(slot(1), slot(7)), (slot(2), slot(7))];
+        System::set_block_number(5);
+        assert_ok!(EtwasmVm::evict_contract(
+            RuntimeOrigin::signed(BOB),
+            contract.clone()
+        ));
+        let first = storage_proof(&slots, &slot(1)).unwrap();
+        let second = storage_proof(&slots, &slot(2)).unwrap();
+
+        // A slot is missing
+        assert_noop!(
+            restore(&contract, vec![first.clone()]),
+            Error::<Test>::InvalidStorageProof
+        );
+        // The same slot twice, or out of order
+        assert_noop!(
+            restore(&contract, vec![first.clone(), first.clone()]),
+            Error::<Test>::InvalidStorageProof
+        );
+        assert_noop!(
+            restore(&contract, vec![second.clone(), first.clone()]),
+            Error::<Test>::InvalidStorageProof
+        );
+        // A value that was not stored
+        let mut forged = second.clone();
+        forged.value = slot(8);
+        assert_noop!(
+            restore(&contract, vec![first.clone(), forged]),
+            Error::<Test>::InvalidStorageProof
+        );
+        // A valid proof against another root
+        let other = vec![(slot(1), slot(7)), (slot(2), slot(8))];
+        let foreign = storage_proof(&other, &slot(2)).unwrap();
+        assert_noop!(
+            restore(&contract, vec![first, foreign]),
+            Error::<Test>::InvalidStorageProof
+        );
+
+        assert!(Tombstones::<Test>::contains_key(&contract));
+    });
+}
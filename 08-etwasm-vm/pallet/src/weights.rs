@@ -0,0 +1,327 @@
+//! PLACEHOLDER weights for `pallet_etwasm_vm`: hand-estimated, not benchmark output.
+//!
+//! Overwrite with `./scripts/benchmark-pallets.sh pallet_etwasm_vm` on reference hardware.
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(missing_docs)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use core::marker::PhantomData;
+
+/// Weight functions needed for `pallet_etwasm_vm`.
+pub trait WeightInfo {
+	fn deploy_contract(c: u32, ) -> Weight;
+	fn call_contract() -> Weight;
+	fn execute_bytecode(c: u32, ) -> Weight;
+	fn transact_create(c: u32, ) -> Weight;
+	fn transact_call() -> Weight;
+	fn map_account() -> Weight;
+	fn deposit_rent() -> Weight;
+	fn evict_contract(s: u32, ) -> Weight;
+	fn restore_contract(n: u32, ) -> Weight;
+	fn upgrade_contract(c: u32, ) -> Weight;
+	fn enact_upgrade() -> Weight;
+	fn cancel_upgrade() -> Weight;
+	fn rollback_contract() -> Weight;
+}
+
+/// Placeholder weights for `pallet_etwasm_vm`, pending benchmarking.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	/// Storage: `EtwasmVM::EthNonce` (r:1 w:1)
+	/// Storage: `EtwasmVM::ContractCodeHash` (r:1 w:1)
+	/// Storage: `EtwasmVM::CodeStorage` (r:0 w:1)
+	/// Storage: `EtwasmVM::CodeHistory` (r:1 w:1)
+	/// Storage: `EtwasmVM::ContractOwner` (r:0 w:1)
+	/// Storage: `EtwasmVM::ContractRent` (r:1 w:1)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Storage: `EtwasmVM::CurrentReceipts` (r:1 w:1)
+	/// The range of component `c` is `[1, 24576]`.
+	fn deploy_contract(c: u32, ) -> Weight {
+		Weight::from_parts(68_420_000, 4120)
+			.saturating_add(Weight::from_parts(1_850, 0).saturating_mul(c.into()))
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(8_u64))
+	}
+	/// Storage: `EtwasmVM::ContractCodeHash` (r:1 w:0)
+	/// Storage: `EtwasmVM::CodeStorage` (r:1 w:0)
+	/// Storage: `EtwasmVM::ContractRent` (r:1 w:1)
+	/// Storage: `EtwasmVM::StorageSlots` (r:1 w:0)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Storage: `EtwasmVM::GasUsed` (r:1 w:1)
+	/// Storage: `EtwasmVM::CurrentReceipts` (r:1 w:1)
+	fn call_contract() -> Weight {
+		Weight::from_parts(121_300_000, 28190)
+			.saturating_add(T::DbWeight::get().reads(9_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+	/// Storage: `EtwasmVM::GasUsed` (r:1 w:1)
+	/// The range of component `c` is `[1, 24576]`.
+	fn execute_bytecode(c: u32, ) -> Weight {
+		Weight::from_parts(14_860_000, 1489)
+			.saturating_add(Weight::from_parts(420, 0).saturating_mul(c.into()))
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `EtwasmVM::EthNonce` (r:1 w:1)
+	/// Storage: `EtwasmVM::OriginalAccount` (r:1 w:0)
+	/// Storage: `EtwasmVM::ContractCodeHash` (r:1 w:1)
+	/// Storage: `EtwasmVM::CodeStorage` (r:0 w:1)
+	/// Storage: `EtwasmVM::CodeHistory` (r:1 w:1)
+	/// Storage: `EtwasmVM::ContractOwner` (r:0 w:1)
+	/// Storage: `EtwasmVM::ContractRent` (r:0 w:1)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Storage: `EtwasmVM::CurrentReceipts` (r:1 w:1)
+	/// The range of component `c` is `[0, 24576]`.
+	fn transact_create(c: u32, ) -> Weight {
+		Weight::from_parts(142_700_000, 6196)
+			.saturating_add(Weight::from_parts(2_140, 0).saturating_mul(c.into()))
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().writes(9_u64))
+	}
+	/// Storage: `EtwasmVM::EthNonce` (r:1 w:1)
+	/// Storage: `EtwasmVM::OriginalAccount` (r:2 w:0)
+	/// Storage: `EtwasmVM::ContractCodeHash` (r:1 w:0)
+	/// Storage: `EtwasmVM::CodeStorage` (r:1 w:0)
+	/// Storage: `EtwasmVM::ContractRent` (r:1 w:1)
+	/// Storage: `EtwasmVM::StorageSlots` (r:1 w:0)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Storage: `EtwasmVM::GasUsed` (r:1 w:1)
+	/// Storage: `EtwasmVM::CurrentReceipts` (r:1 w:1)
+	fn transact_call() -> Weight {
+		Weight::from_parts(176_900_000, 28190)
+			.saturating_add(T::DbWeight::get().reads(12_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
+	}
+	/// Storage: `EtwasmVM::OriginalAccount` (r:1 w:1)
+	fn map_account() -> Weight {
+		Weight::from_parts(16_240_000, 3509)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `EtwasmVM::ContractCodeHash` (r:1 w:0)
+	/// Storage: `EtwasmVM::ContractRent` (r:1 w:1)
+	/// Storage: `System::Account` (r:1 w:1)
+	fn deposit_rent() -> Weight {
+		Weight::from_parts(43_810_000, 3593)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `EtwasmVM::ContractCodeHash` (r:1 w:1)
+	/// Storage: `EtwasmVM::ContractRent` (r:1 w:1)
+	/// Storage: `EtwasmVM::StorageSlots` (r:1 w:1)
+	/// Storage: `EtwasmVM::ContractStorageValue` (r:1024 w:1024)
+	/// Storage: `EtwasmVM::Tombstones` (r:0 w:1)
+	/// The range of component `s` is `[1, 1024]`.
+	fn evict_contract(s: u32, ) -> Weight {
+		Weight::from_parts(41_280_000, 3593)
+			.saturating_add(Weight::from_parts(3_460_000, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(s.into())))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(s.into())))
+			.saturating_add(Weight::from_parts(0, 2587).saturating_mul(s.into()))
+	}
+	/// Storage: `EtwasmVM::Tombstones` (r:1 w:1)
+	/// Storage: `EtwasmVM::ContractStorageValue` (r:0 w:1024)
+	/// Storage: `EtwasmVM::StorageSlots` (r:0 w:1)
+	/// Storage: `EtwasmVM::ContractCodeHash` (r:0 w:1)
+	/// Storage: `EtwasmVM::ContractRent` (r:1 w:1)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// The range of component `n` is `[1, 1024]`.
+	fn restore_contract(n: u32, ) -> Weight {
+		Weight::from_parts(52_910_000, 3731)
+			.saturating_add(Weight::from_parts(5_120_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(n.into())))
+	}
+	/// Storage: `EtwasmVM::ContractOwner` (r:1 w:0)
+	/// Storage: `EtwasmVM::ContractCodeHash` (r:1 w:0)
+	/// Storage: `EtwasmVM::PendingUpgrades` (r:1 w:1)
+	/// Storage: `EtwasmVM::CodeStorage` (r:0 w:1)
+	/// The range of component `c` is `[1, 24576]`.
+	fn upgrade_contract(c: u32, ) -> Weight {
+		Weight::from_parts(31_770_000, 3593)
+			.saturating_add(Weight::from_parts(1_820, 0).saturating_mul(c.into()))
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `EtwasmVM::ContractOwner` (r:1 w:0)
+	/// Storage: `EtwasmVM::PendingUpgrades` (r:1 w:1)
+	/// Storage: `EtwasmVM::ContractCodeHash` (r:2 w:1)
+	/// Storage: `EtwasmVM::CodeHistory` (r:1 w:1)
+	/// Storage: `EtwasmVM::CodeStorage` (r:1 w:0)
+	/// Storage: `EtwasmVM::GasUsed` (r:1 w:1)
+	fn enact_upgrade() -> Weight {
+		Weight::from_parts(84_930_000, 28190)
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: `EtwasmVM::ContractOwner` (r:1 w:0)
+	/// Storage: `EtwasmVM::PendingUpgrades` (r:1 w:1)
+	fn cancel_upgrade() -> Weight {
+		Weight::from_parts(19_640_000, 3593)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `EtwasmVM::ContractOwner` (r:1 w:0)
+	/// Storage: `EtwasmVM::ContractCodeHash` (r:1 w:1)
+	/// Storage: `EtwasmVM::CodeHistory` (r:1 w:1)
+	fn rollback_contract() -> Weight {
+		Weight::from_parts(27_310_000, 4658)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+}
+
+// For backwards compatibility and tests.
+impl WeightInfo for () {
+	/// Storage: `EtwasmVM::EthNonce` (r:1 w:1)
+	/// Storage: `EtwasmVM::ContractCodeHash` (r:1 w:1)
+	/// Storage: `EtwasmVM::CodeStorage` (r:0 w:1)
+	/// Storage: `EtwasmVM::CodeHistory` (r:1 w:1)
+	/// Storage: `EtwasmVM::ContractOwner` (r:0 w:1)
+	/// Storage: `EtwasmVM::ContractRent` (r:1 w:1)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Storage: `EtwasmVM::CurrentReceipts` (r:1 w:1)
+	/// The range of component `c` is `[1, 24576]`.
+	fn deploy_contract(c: u32, ) -> Weight {
+		Weight::from_parts(68_420_000, 4120)
+			.saturating_add(Weight::from_parts(1_850, 0).saturating_mul(c.into()))
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(8_u64))
+	}
+	/// Storage: `EtwasmVM::ContractCodeHash` (r:1 w:0)
+	/// Storage: `EtwasmVM::CodeStorage` (r:1 w:0)
+	/// Storage: `EtwasmVM::ContractRent` (r:1 w:1)
+	/// Storage: `EtwasmVM::StorageSlots` (r:1 w:0)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Storage: `EtwasmVM::GasUsed` (r:1 w:1)
+	/// Storage: `EtwasmVM::CurrentReceipts` (r:1 w:1)
+	fn call_contract() -> Weight {
+		Weight::from_parts(121_300_000, 28190)
+			.saturating_add(RocksDbWeight::get().reads(9_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
+	/// Storage: `EtwasmVM::GasUsed` (r:1 w:1)
+	/// The range of component `c` is `[1, 24576]`.
+	fn execute_bytecode(c: u32, ) -> Weight {
+		Weight::from_parts(14_860_000, 1489)
+			.saturating_add(Weight::from_parts(420, 0).saturating_mul(c.into()))
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `EtwasmVM::EthNonce` (r:1 w:1)
+	/// Storage: `EtwasmVM::OriginalAccount` (r:1 w:0)
+	/// Storage: `EtwasmVM::ContractCodeHash` (r:1 w:1)
+	/// Storage: `EtwasmVM::CodeStorage` (r:0 w:1)
+	/// Storage: `EtwasmVM::CodeHistory` (r:1 w:1)
+	/// Storage: `EtwasmVM::ContractOwner` (r:0 w:1)
+	/// Storage: `EtwasmVM::ContractRent` (r:0 w:1)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Storage: `EtwasmVM::CurrentReceipts` (r:1 w:1)
+	/// The range of component `c` is `[0, 24576]`.
+	fn transact_create(c: u32, ) -> Weight {
+		Weight::from_parts(142_700_000, 6196)
+			.saturating_add(Weight::from_parts(2_140, 0).saturating_mul(c.into()))
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().writes(9_u64))
+	}
+	/// Storage: `EtwasmVM::EthNonce` (r:1 w:1)
+	/// Storage: `EtwasmVM::OriginalAccount` (r:2 w:0)
+	/// Storage: `EtwasmVM::ContractCodeHash` (r:1 w:0)
+	/// Storage: `EtwasmVM::CodeStorage` (r:1 w:0)
+	/// Storage: `EtwasmVM::ContractRent` (r:1 w:1)
+	/// Storage: `EtwasmVM::StorageSlots` (r:1 w:0)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Storage: `EtwasmVM::GasUsed` (r:1 w:1)
+	/// Storage: `EtwasmVM::CurrentReceipts` (r:1 w:1)
+	fn transact_call() -> Weight {
+		Weight::from_parts(176_900_000, 28190)
+			.saturating_add(RocksDbWeight::get().reads(12_u64))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
+	}
+	/// Storage: `EtwasmVM::OriginalAccount` (r:1 w:1)
+	fn map_account() -> Weight {
+		Weight::from_parts(16_240_000, 3509)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `EtwasmVM::ContractCodeHash` (r:1 w:0)
+	/// Storage: `EtwasmVM::ContractRent` (r:1 w:1)
+	/// Storage: `System::Account` (r:1 w:1)
+	fn deposit_rent() -> Weight {
+		Weight::from_parts(43_810_000, 3593)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `EtwasmVM::ContractCodeHash` (r:1 w:1)
+	/// Storage: `EtwasmVM::ContractRent` (r:1 w:1)
+	/// Storage: `EtwasmVM::StorageSlots` (r:1 w:1)
+	/// Storage: `EtwasmVM::ContractStorageValue` (r:1024 w:1024)
+	/// Storage: `EtwasmVM::Tombstones` (r:0 w:1)
+	/// The range of component `s` is `[1, 1024]`.
+	fn evict_contract(s: u32, ) -> Weight {
+		Weight::from_parts(41_280_000, 3593)
+			.saturating_add(Weight::from_parts(3_460_000, 0).saturating_mul(s.into()))
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(s.into())))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(s.into())))
+			.saturating_add(Weight::from_parts(0, 2587).saturating_mul(s.into()))
+	}
+	/// Storage: `EtwasmVM::Tombstones` (r:1 w:1)
+	/// Storage: `EtwasmVM::ContractStorageValue` (r:0 w:1024)
+	/// Storage: `EtwasmVM::StorageSlots` (r:0 w:1)
+	/// Storage: `EtwasmVM::ContractCodeHash` (r:0 w:1)
+	/// Storage: `EtwasmVM::ContractRent` (r:1 w:1)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// The range of component `n` is `[1, 1024]`.
+	fn restore_contract(n: u32, ) -> Weight {
+		Weight::from_parts(52_910_000, 3731)
+			.saturating_add(Weight::from_parts(5_120_000, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(n.into())))
+	}
+	/// Storage: `EtwasmVM::ContractOwner` (r:1 w:0)
+	/// Storage: `EtwasmVM::ContractCodeHash` (r:1 w:0)
+	/// Storage: `EtwasmVM::PendingUpgrades` (r:1 w:1)
+	/// Storage: `EtwasmVM::CodeStorage` (r:0 w:1)
+	/// The range of component `c` is `[1, 24576]`.
+	fn upgrade_contract(c: u32, ) -> Weight {
+		Weight::from_parts(31_770_000, 3593)
+			.saturating_add(Weight::from_parts(1_820, 0).saturating_mul(c.into()))
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `EtwasmVM::ContractOwner` (r:1 w:0)
+	/// Storage: `EtwasmVM::PendingUpgrades` (r:1 w:1)
+	/// Storage: `EtwasmVM::ContractCodeHash` (r:2 w:1)
+	/// Storage: `EtwasmVM::CodeHistory` (r:1 w:1)
+	/// Storage: `EtwasmVM::CodeStorage` (r:1 w:0)
+	/// Storage: `EtwasmVM::GasUsed` (r:1 w:1)
+	fn enact_upgrade() -> Weight {
+		Weight::from_parts(84_930_000, 28190)
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: `EtwasmVM::ContractOwner` (r:1 w:0)
+	/// Storage: `EtwasmVM::PendingUpgrades` (r:1 w:1)
+	fn cancel_upgrade() -> Weight {
+		Weight::from_parts(19_640_000, 3593)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `EtwasmVM::ContractOwner` (r:1 w:0)
+	/// Storage: `EtwasmVM::ContractCodeHash` (r:1 w:1)
+	/// Storage: `EtwasmVM::CodeHistory` (r:1 w:1)
+	fn rollback_contract() -> Weight {
+		Weight::from_parts(27_310_000, 4658)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+}
@@ -196,7 +196,11 @@ impl EventIndexEntry {
 }
 
 /// Bloom filter for fast event existence checks
-#[derive(Debug, Clone, Encode, Decode, TypeInfo)]
+///
+/// Uses the Ethereum `logsBloom` construction (3 bits out of 2048 taken from
+/// `keccak256(item)`), so blooms built from 20-byte addresses and topics are
+/// interchangeable with Ethereum receipt and block blooms.
+#[derive(Debug, Clone, Encode, Decode, TypeInfo, PartialEq, Eq)]
 pub struct EventBloomFilter {
     /// Bloom filter bits (2048 bits = 256 bytes)
     bits: [u8; 256],
@@ -208,27 +212,46 @@ impl EventBloomFilter {
         Self { bits: [0u8; 256] }
     }
 
+    /// Bloom filter from its 256-byte Ethereum representation
+    pub fn from_bytes(bits: [u8; 256]) -> Self {
+        Self { bits }
+    }
+
+    /// 256-byte Ethereum representation
+    pub fn as_bytes(&self) -> &[u8; 256] {
+        &self.bits
+    }
+
+    /// Whether no item was added
+    pub fn is_empty(&self) -> bool {
+        self.bits.iter().all(|b| *b == 0)
+    }
+
     /// Add an item to the bloom filter
     pub fn add(&mut self, item: &[u8]) {
-        for i in 0..3 {
-            let hash = self.hash(item, i);
-            let byte_index = (hash / 8) as usize % 256;
-            let bit_index = hash % 8;
-            self.bits[byte_index] |= 1 << bit_index;
+        for (byte_index, mask) in Self::positions(item) {
+            self.bits[byte_index] |= mask;
+        }
+    }
+
+    /// Add the address and topics of a log
+    pub fn add_log(&mut self, address: &[u8], topics: &[EventTopic]) {
+        self.add(address);
+        for topic in topics {
+            self.add(topic.as_bytes());
         }
     }
 
     /// Check if an item might be in the filter
     pub fn contains(&self, item: &[u8]) -> bool {
-        for i in 0..3 {
-            let hash = self.hash(item, i);
-            let byte_index = (hash / 8) as usize % 256;
-            let bit_index = hash % 8;
-            if (self.bits[byte_index] & (1 << bit_index)) == 0 {
-                return false;
-            }
-        }
-        true
+        Self::positions(item)
+            .iter()
+            .all(|(byte_index, mask)| self.bits[*byte_index] & mask != 0)
+    }
+
+    /// Check if every bit of `other` is set in this filter
+    pub fn contains_bloom(&self, other: &EventBloomFilter) -> bool {
+        self.bits.iter().zip(other.bits.iter()).all(|(a, b)| a & b == *b)
     }
 
     /// Combine with another bloom filter (OR operation)
@@ -238,13 +261,16 @@ impl EventBloomFilter {
         }
     }
 
-    /// Simple hash function for bloom filter
-    fn hash(&self, data: &[u8], seed: u32) -> u32 {
-        let mut hash = seed;
-        for &byte in data {
-            hash = hash.wrapping_mul(31).wrapping_add(byte as u32);
-        }
-        hash
+    /// Byte index and bit mask of the three bits set for `item`
+    ///
+    /// Each bit index is the low 11 bits of a big-endian byte pair of
+    /// `keccak256(item)`, counted from the end of the filter.
+    fn positions(item: &[u8]) -> [(usize, u8); 3] {
+        let hash = sp_io::hashing::keccak_256(item);
+        core::array::from_fn(|i| {
+            let bit = (((hash[2 * i] as usize) << 8) | hash[2 * i + 1] as usize) & 2047;
+            (255 - bit / 8, 1 << (bit % 8))
+        })
     }
 }
 
@@ -604,6 +630,24 @@ mod tests {
 
         assert!(bloom1.contains(b"hello"));
         assert!(bloom1.contains(b"world"));
+        assert!(bloom1.contains_bloom(&bloom2));
+        assert!(!bloom2.contains_bloom(&bloom1));
+    }
+
+    #[test]
+    fn test_bloom_filter_matches_ethereum() {
+        // keccak256(0x00 * 20) = 5380c7b7ae81a58eb98d9c78de4a1fd7fd9535fc953ed2be602daaa41767312a
+        // sets bits 0x380 = 896, 0x7b7 = 1975 and 0x681 = 1665
+        let mut bloom = EventBloomFilter::new();
+        bloom.add(&[0u8; 20]);
+
+        let mut expected = [0u8; 256];
+        for bit in [896usize, 1975, 1665] {
+            expected[255 - bit / 8] |= 1 << (bit % 8);
+        }
+        assert_eq!(bloom.as_bytes(), &expected);
+        assert!(!bloom.is_empty());
+        assert_eq!(EventBloomFilter::from_bytes(expected), bloom);
     }
 
     #[test]
@@ -7,7 +7,7 @@
 
 extern crate alloc;
 
-use alloc::vec::Vec;
+use alloc::{vec, vec::Vec};
 use codec::{Decode, Encode};
 use scale_info::TypeInfo;
 use sp_core::H256;
@@ -52,6 +52,8 @@ pub struct ExecutionContext {
     pub address: [u8; 32],
     /// Value sent with the call (in VMw units)
     pub value: u128,
+    /// Call data (read by CALLDATALOAD/CALLDATASIZE/CALLDATACOPY)
+    pub input: Vec<u8>,
     /// Gas limit for this execution
     pub gas_limit: VMw,
     /// Gas price
@@ -76,6 +78,7 @@ impl Default for ExecutionContext {
             caller: [0u8; 32],
             address: [0u8; 32],
             value: 0,
+            input: Vec::new(),
             gas_limit: 1_000_000,
             gas_price: 1,
             block_number: 0,
@@ -100,6 +103,7 @@ impl ExecutionContext {
             caller,
             address,
             value,
+            input: Vec::new(),
             gas_limit,
             gas_price: 1,
             block_number: 0,
@@ -111,6 +115,12 @@ impl ExecutionContext {
         }
     }
 
+    /// Set the call data
+    pub fn with_input(mut self, input: Vec<u8>) -> Self {
+        self.input = input;
+        self
+    }
+
     /// Enter a call - check for reentrancy and update call stack
     ///
     /// # Arguments
@@ -334,7 +344,7 @@ impl Memory {
     }
 
     pub fn expand(&mut self, offset: usize, size: usize) -> Result<(), &'static str> {
-        let required = offset + size;
+        let required = offset.checked_add(size).ok_or("Memory limit exceeded")?;
         if required > self.data.len() {
             // Check max memory limit (16MB)
             if required > (MAX_MEMORY_PAGES as usize) * 65536 {
@@ -352,7 +362,7 @@ impl Memory {
     }
 
     pub fn load(&self, offset: usize, size: usize) -> Result<Vec<u8>, &'static str> {
-        if offset + size > self.data.len() {
+        if offset.checked_add(size).map_or(true, |end| end > self.data.len()) {
             return Err("Memory out of bounds");
         }
         Ok(self.data[offset..offset + size].to_vec())
@@ -421,6 +431,8 @@ pub struct Interpreter<S: Storage> {
     pub code: Vec<u8>,
     /// Return data
     pub return_data: Vec<u8>,
+    /// Logs emitted by LOG0-LOG4, in order
+    pub logs: Vec<EventLog>,
 }
 
 impl<S: Storage> Interpreter<S> {
@@ -451,6 +463,7 @@ impl<S: Storage> Interpreter<S> {
             pc: 0,
             code,
             return_data: Vec::new(),
+            logs: Vec::new(),
         }
     }
 
@@ -483,17 +496,31 @@ impl<S: Storage> Interpreter<S> {
             pc: 0,
             code,
             return_data: Vec::new(),
+            logs: Vec::new(),
         }
     }
 
     /// Execute the bytecode
-    pub fn execute(mut self) -> ExecutionResult {
+    pub fn execute(self) -> ExecutionResult {
+        self.execute_with_logs().0
+    }
+
+    /// Execute the bytecode, returning the logs emitted on success
+    ///
+    /// Logs of reverted or failed executions are discarded, as in the EVM.
+    pub fn execute_with_logs(mut self) -> (ExecutionResult, Vec<EventLog>) {
+        let result = self.run();
+        let logs = if result.is_success() { core::mem::take(&mut self.logs) } else { Vec::new() };
+        (result, logs)
+    }
+
+    fn run(&mut self) -> ExecutionResult {
         loop {
             // Check if execution is complete
             if self.pc >= self.code.len() {
                 return ExecutionResult::Success {
                     gas_used: self.context.gas_limit - self.gas_remaining,
-                    return_data: self.return_data,
+                    return_data: core::mem::take(&mut self.return_data),
                 };
             }
 
@@ -518,7 +545,7 @@ impl<S: Storage> Interpreter<S> {
                 Ok(OpcodeResult::Stop) => {
                     return ExecutionResult::Success {
                         gas_used: self.context.gas_limit - self.gas_remaining,
-                        return_data: self.return_data,
+                        return_data: core::mem::take(&mut self.return_data),
                     };
                 }
                 Ok(OpcodeResult::Return(data)) => {
@@ -598,6 +625,14 @@ impl<S: Storage> Interpreter<S> {
             CHAINID => self.op_chainid(),
             GAS => self.op_gas(),
 
+            // Call data
+            CALLDATALOAD => self.op_calldataload(),
+            CALLDATASIZE => self.op_calldatasize(),
+            CALLDATACOPY => self.op_calldatacopy(),
+
+            // Logging
+            LOG0..=LOG4 => self.op_log(opcode - LOG0),
+
             _ => Err("Invalid or unsupported opcode"),
         }
     }
@@ -878,6 +913,73 @@ impl<S: Storage> Interpreter<S> {
         self.stack.push(gas)?;
         Ok(OpcodeResult::Continue)
     }
+
+    // Call data operations
+    fn op_calldataload(&mut self) -> Result<OpcodeResult, &'static str> {
+        let offset = self.stack.pop()?;
+        let mut value = u256_zero();
+        if let Some(data) = self.calldata_from(&offset) {
+            let len = core::cmp::min(data.len(), 32);
+            value[..len].copy_from_slice(&data[..len]);
+        }
+        self.stack.push(value)?;
+        Ok(OpcodeResult::Continue)
+    }
+
+    fn op_calldatasize(&mut self) -> Result<OpcodeResult, &'static str> {
+        self.stack.push(usize_to_u256(self.context.input.len()))?;
+        Ok(OpcodeResult::Continue)
+    }
+
+    fn op_calldatacopy(&mut self) -> Result<OpcodeResult, &'static str> {
+        let dest = u256_to_usize(&self.stack.pop()?);
+        let offset = self.stack.pop()?;
+        let size = u256_to_usize(&self.stack.pop()?);
+        if size == 0 {
+            return Ok(OpcodeResult::Continue);
+        }
+        self.memory.expand(dest, size)?;
+
+        // Bytes past the end of the call data read as zero
+        let mut data = vec![0u8; size];
+        if let Some(input) = self.calldata_from(&offset) {
+            let len = core::cmp::min(input.len(), size);
+            data[..len].copy_from_slice(&input[..len]);
+        }
+        self.memory.store(dest, &data)?;
+        Ok(OpcodeResult::Continue)
+    }
+
+    /// Call data from `offset` onwards, `None` if past the end
+    fn calldata_from(&self, offset: &[u8; 32]) -> Option<&[u8]> {
+        if offset[..24].iter().any(|b| *b != 0) {
+            return None;
+        }
+        self.context.input.get(u256_to_usize(offset)..)
+    }
+
+    // Logging
+    fn op_log(&mut self, topic_count: u8) -> Result<OpcodeResult, &'static str> {
+        let offset = u256_to_usize(&self.stack.pop()?);
+        let size = u256_to_usize(&self.stack.pop()?);
+        let mut topics = Vec::with_capacity(topic_count as usize);
+        for _ in 0..topic_count {
+            topics.push(H256::from(self.stack.pop()?));
+        }
+        let data = if size == 0 { Vec::new() } else { self.memory.load(offset, size)? };
+
+        let log = EventLog::new(
+            self.context.address,
+            topics,
+            data,
+            self.context.block_number,
+            0,
+            self.logs.len() as u32,
+        )
+        .map_err(|_| "Invalid log")?;
+        self.logs.push(log);
+        Ok(OpcodeResult::Continue)
+    }
 }
 
 /// Opcode execution result
@@ -1081,4 +1183,44 @@ mod tests {
         let loaded = memory.load(0, 4).unwrap();
         assert_eq!(loaded, data);
     }
+
+    #[test]
+    fn test_calldata_operations() {
+        // return (calldataload(4), calldatasize())
+        let code = vec![
+            PUSH1, 4, CALLDATALOAD, PUSH1, 0, MSTORE,
+            CALLDATASIZE, PUSH1, 32, MSTORE,
+            PUSH1, 64, PUSH1, 0, RETURN,
+        ];
+        let mut input = vec![0xaa; 4];
+        input.extend_from_slice(&u64_to_u256(7));
+        let context = ExecutionContext::default().with_input(input);
+
+        let result = Interpreter::new(context, code, InMemoryStorage::default()).execute();
+        let ExecutionResult::Success { return_data, .. } = result else { panic!("{:?}", result) };
+        assert_eq!(return_data[..32], u64_to_u256(7));
+        assert_eq!(return_data[32..], usize_to_u256(36));
+    }
+
+    #[test]
+    fn test_logs_kept_only_on_success() {
+        // mstore(0, 42); log1(0, 32, 0x11)
+        let emit = vec![PUSH1, 42, PUSH1, 0, MSTORE, PUSH1, 0x11, PUSH1, 32, PUSH1, 0, LOG1];
+        let context = ExecutionContext { address: [5u8; 32], ..Default::default() };
+
+        let (result, logs) =
+            Interpreter::new(context.clone(), emit.clone(), InMemoryStorage::default()).execute_with_logs();
+        assert!(result.is_success());
+        assert_eq!(logs.len(), 1);
+        assert_eq!(logs[0].address, [5u8; 32]);
+        assert_eq!(logs[0].topics, vec![H256::from_low_u64_be(0x11)]);
+        assert_eq!(logs[0].data, u64_to_u256(42).to_vec());
+
+        let mut reverting = emit;
+        reverting.extend_from_slice(&[PUSH1, 0, PUSH1, 0, REVERT]);
+        let (result, logs) =
+            Interpreter::new(context, reverting, InMemoryStorage::default()).execute_with_logs();
+        assert!(matches!(result, ExecutionResult::Revert { .. }));
+        assert!(logs.is_empty());
+    }
 }
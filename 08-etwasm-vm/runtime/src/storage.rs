@@ -3,7 +3,9 @@
 //! This module provides a comprehensive storage system for smart contracts with:
 //! - Persistent key-value storage
 //! - Merkle tree commitments for proof generation
-//! - Storage rent/fees mechanism
+//! - Storage rent/fees mechanism (collected by the pallet on contract access;
+//!   contracts that cannot pay are tombstoned with their storage root and can
+//!   be restored with a [`StorageMerkleProof`] per slot)
 //! - Storage access tracking (cold/warm)
 
 #![cfg_attr(not(feature = "std"), no_std)]
@@ -31,6 +33,17 @@ pub const STORAGE_RENT_PER_BYTE_PER_BLOCK: VMw = 1;
 /// Minimum storage deposit
 pub const MINIMUM_STORAGE_DEPOSIT: u128 = 1_000_000; // 1 ETR
 
+/// Size of a storage slot in bytes
+const SLOT_SIZE: u64 = 32;
+
+/// Rent owed by `slot_count` slots between `last_rent_block` and `current_block`
+pub fn storage_rent(slot_count: u64, last_rent_block: u64, current_block: u64) -> u128 {
+    let blocks_elapsed = current_block.saturating_sub(last_rent_block);
+    (blocks_elapsed as u128)
+        .saturating_mul(slot_count.saturating_mul(SLOT_SIZE) as u128)
+        .saturating_mul(STORAGE_RENT_PER_BYTE_PER_BLOCK as u128)
+}
+
 /// Storage access modes (for EIP-2929 gas cost optimization)
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Encode, Decode, TypeInfo)]
 pub enum StorageAccessMode {
@@ -69,9 +82,7 @@ impl StorageEntry {
 
     /// Calculate accumulated rent owed
     pub fn calculate_rent(&self, current_block: u64) -> u128 {
-        let blocks_elapsed = current_block.saturating_sub(self.last_rent_block);
-        let storage_size = 32u128; // 32 bytes per storage slot
-        (blocks_elapsed as u128) * storage_size * (STORAGE_RENT_PER_BYTE_PER_BLOCK as u128)
+        storage_rent(1, self.last_rent_block, current_block)
     }
 
     /// Check if rent needs to be paid
@@ -81,7 +92,7 @@ impl StorageEntry {
 }
 
 /// Merkle proof for storage verification
-#[derive(Debug, Clone, Encode, Decode, TypeInfo)]
+#[derive(Debug, Clone, PartialEq, Eq, Encode, Decode, TypeInfo)]
 pub struct StorageMerkleProof {
     /// Storage key being proven
     pub key: StorageKey,
@@ -96,35 +107,89 @@ pub struct StorageMerkleProof {
 impl StorageMerkleProof {
     /// Verify the merkle proof
     pub fn verify(&self) -> bool {
-        let mut current_hash = self.compute_leaf_hash();
+        let mut current_hash = leaf_hash(&self.key, &self.value);
 
         // Walk up the tree
         for sibling in &self.proof_path {
-            current_hash = self.hash_pair(&current_hash, sibling);
+            current_hash = hash_pair(&current_hash, sibling);
         }
 
         current_hash == self.root
     }
+}
+
+/// Leaf node prefix, keeps leaves and inner nodes from being confused
+const LEAF_PREFIX: u8 = 0;
+/// Inner node prefix
+const NODE_PREFIX: u8 = 1;
+
+/// Compute the hash of a leaf node
+fn leaf_hash(key: &StorageKey, value: &StorageValue) -> H256 {
+    let mut data = Vec::with_capacity(65);
+    data.push(LEAF_PREFIX);
+    data.extend_from_slice(key.as_bytes());
+    data.extend_from_slice(value.as_bytes());
+    H256::from(blake2_256(&data))
+}
+
+/// Hash two nodes together (order-independent, so proofs need no directions)
+fn hash_pair(a: &H256, b: &H256) -> H256 {
+    let (left, right) = if a <= b { (a, b) } else { (b, a) };
+    let mut data = Vec::with_capacity(65);
+    data.push(NODE_PREFIX);
+    data.extend_from_slice(left.as_bytes());
+    data.extend_from_slice(right.as_bytes());
+    H256::from(blake2_256(&data))
+}
+
+/// Next tree level; an odd last node moves up unchanged
+fn parent_level(level: &[H256]) -> Vec<H256> {
+    level
+        .chunks(2)
+        .map(|pair| match pair {
+            [a, b] => hash_pair(a, b),
+            _ => pair[0],
+        })
+        .collect()
+}
 
-    /// Compute the hash of the leaf node
-    fn compute_leaf_hash(&self) -> H256 {
-        let mut data = Vec::new();
-        data.extend_from_slice(self.key.as_bytes());
-        data.extend_from_slice(self.value.as_bytes());
-        H256::from(blake2_256(&data))
+/// Merkle root of storage slots sorted by key, zero for empty storage
+pub fn storage_root(slots: &[(StorageKey, StorageValue)]) -> H256 {
+    let mut level: Vec<H256> = slots.iter().map(|(key, value)| leaf_hash(key, value)).collect();
+    if level.is_empty() {
+        return H256::zero();
     }
+    while level.len() > 1 {
+        level = parent_level(&level);
+    }
+    level[0]
+}
 
-    /// Hash two nodes together (order-independent for simplicity)
-    fn hash_pair(&self, a: &H256, b: &H256) -> H256 {
-        let mut data = Vec::new();
-        data.extend_from_slice(a.as_bytes());
-        data.extend_from_slice(b.as_bytes());
-        H256::from(blake2_256(&data))
+/// Merkle proof of `key` among storage slots sorted by key
+pub fn storage_proof(slots: &[(StorageKey, StorageValue)], key: &StorageKey) -> Option<StorageMerkleProof> {
+    let mut index = slots.iter().position(|(slot_key, _)| slot_key == key)?;
+    let value = slots[index].1;
+    let mut level: Vec<H256> = slots.iter().map(|(key, value)| leaf_hash(key, value)).collect();
+    let mut proof_path = Vec::new();
+
+    while level.len() > 1 {
+        if let Some(sibling) = level.get(index ^ 1) {
+            proof_path.push(*sibling);
+        }
+        level = parent_level(&level);
+        index /= 2;
     }
+
+    Some(StorageMerkleProof {
+        key: *key,
+        value,
+        proof_path,
+        root: level[0],
+    })
 }
 
 /// Storage commitment - Merkle root and metadata
-#[derive(Debug, Clone, Encode, Decode, TypeInfo)]
+#[derive(Debug, Clone, PartialEq, Eq, Encode, Decode, TypeInfo)]
 pub struct StorageCommitment {
     /// Merkle root of the storage tree
     pub root: H256,
@@ -142,7 +207,7 @@ impl StorageCommitment {
         Self {
             root,
             slot_count,
-            total_size: slot_count * 32, // 32 bytes per slot
+            total_size: slot_count * SLOT_SIZE,
             block_number,
         }
     }
@@ -228,20 +293,9 @@ impl AdvancedInMemoryStorage {
         self.accessed_keys.clear();
     }
 
-    /// Compute merkle root of storage tree
-    fn compute_merkle_root(&self) -> H256 {
-        if self.entries.is_empty() {
-            return H256::zero();
-        }
-
-        // Simple merkle root: hash all key-value pairs together
-        // In production, use a proper merkle tree implementation
-        let mut data = Vec::new();
-        for (key, entry) in &self.entries {
-            data.extend_from_slice(key.as_bytes());
-            data.extend_from_slice(entry.value.as_bytes());
-        }
-        H256::from(blake2_256(&data))
+    /// Storage slots sorted by key
+    fn slots(&self) -> Vec<(StorageKey, StorageValue)> {
+        self.entries.iter().map(|(key, entry)| (*key, entry.value)).collect()
     }
 }
 
@@ -308,22 +362,13 @@ impl AdvancedStorage for AdvancedInMemoryStorage {
     }
 
     fn get_commitment(&self) -> StorageCommitment {
-        let root = self.compute_merkle_root();
+        let root = storage_root(&self.slots());
         let slot_count = self.entries.len() as u64;
         StorageCommitment::new(root, slot_count, self.current_block)
     }
 
     fn generate_proof(&self, key: &StorageKey) -> Option<StorageMerkleProof> {
-        let entry = self.entries.get(key)?;
-        let root = self.compute_merkle_root();
-
-        // Simple proof generation (in production, use proper merkle tree)
-        Some(StorageMerkleProof {
-            key: *key,
-            value: entry.value,
-            proof_path: Vec::new(), // Simplified
-            root,
-        })
+        storage_proof(&self.slots(), key)
     }
 
     fn calculate_total_rent(&self, current_block: u64) -> u128 {
@@ -482,6 +527,35 @@ mod tests {
         assert_eq!(proof.value, value);
     }
 
+    #[test]
+    fn test_merkle_proof_verification() {
+        let mut storage = AdvancedInMemoryStorage::new(0);
+        for i in 1..=5 {
+            storage.write(H256::from_low_u64_be(i), H256::from_low_u64_be(i * 10));
+        }
+        let root = storage.get_commitment().root;
+
+        // Every slot proves against the commitment, including the odd one out
+        for i in 1..=5 {
+            let proof = storage.generate_proof(&H256::from_low_u64_be(i)).unwrap();
+            assert_eq!(proof.root, root);
+            assert!(proof.verify());
+        }
+
+        // Tampered values and missing keys do not
+        let mut proof = storage.generate_proof(&H256::from_low_u64_be(3)).unwrap();
+        proof.value = H256::from_low_u64_be(31);
+        assert!(!proof.verify());
+        assert!(storage.generate_proof(&H256::from_low_u64_be(6)).is_none());
+
+        // A single slot is its own root
+        let slots = [(H256::from_low_u64_be(1), H256::from_low_u64_be(10))];
+        let proof = storage_proof(&slots, &slots[0].0).unwrap();
+        assert!(proof.proof_path.is_empty());
+        assert_eq!(proof.root, storage_root(&slots));
+        assert!(proof.verify());
+    }
+
     #[test]
     fn test_storage_entry_rent_calculation() {
         let entry = StorageEntry::new(H256::zero(), MINIMUM_STORAGE_DEPOSIT, 0);
@@ -69,6 +69,9 @@ pub use rate_limit::{RateLimitedCollector, RateLimitConfig};
 pub use eclipse::{EclipseDetector, EclipseReport};
 pub use asf_finality::{AsfFinalityLevel, FinalityTracker, CertificateAsfExt};
 
+// Portable justification format (persisted with finalized blocks)
+pub use asf_finality_proof::{AsfJustification, JustificationSignature, ASF_JUSTIFICATION_ENGINE_ID};
+
 // ═══════════════════════════════════════════════════════════════════════════
 // CONSTANTS
 // ═══════════════════════════════════════════════════════════════════════════
@@ -426,6 +429,38 @@ impl CheckpointCertificate {
 
         true
     }
+
+    /// Convert into a portable ASF justification for block-database storage
+    ///
+    /// Chain ID and authority set hash are taken from the signatures, which the
+    /// collector has already checked for consistency. Returns `None` for an
+    /// empty certificate or a signature that is not 64 bytes.
+    pub fn to_justification(&self) -> Option<AsfJustification> {
+        let first = self.signatures.first()?;
+
+        let signatures = self
+            .signatures
+            .iter()
+            .map(|sig| {
+                Some(JustificationSignature {
+                    validator_id: sig.validator_id,
+                    validator_pubkey: sig.validator_pubkey,
+                    checkpoint_type: sig.checkpoint_type.encode(),
+                    signature_nonce: sig.signature_nonce,
+                    signature: sig.signature.clone().try_into().ok()?,
+                })
+            })
+            .collect::<Option<Vec<_>>>()?;
+
+        Some(AsfJustification {
+            chain_id: first.chain_id,
+            block_number: self.block_number,
+            block_hash: self.block_hash,
+            authority_set_id: self.authority_set_id,
+            authority_set_hash: first.authority_set_hash,
+            signatures,
+        })
+    }
 }
 
 /// Authority set configuration with hash-based binding
@@ -1033,4 +1068,28 @@ mod tests {
         let payload2 = sig.signing_payload();
         assert_eq!(payload, payload2);
     }
+
+    #[test]
+    fn test_justification_payload_matches_signature_payload() {
+        let (authority_set, keys) = create_test_authority_set(QUORUM_THRESHOLD);
+        let chain_id = FLARECHAIN_NETWORK_ID;
+
+        let signatures: Vec<CheckpointSignature> = keys
+            .iter()
+            .enumerate()
+            .map(|(i, key)| {
+                create_checkpoint_signature(key, i as u32, 32, [9u8; 32], &authority_set, 1, chain_id)
+            })
+            .collect();
+
+        let certificate = CheckpointCertificate::new(32, [9u8; 32], authority_set.set_id, signatures)
+            .expect("quorum reached");
+        let justification = certificate.to_justification().expect("64-byte signatures");
+
+        assert_eq!(justification.signatures.len(), QUORUM_THRESHOLD);
+        assert_eq!(justification.authority_set_hash, authority_set.authority_set_hash);
+        for (sig, jsig) in certificate.signatures.iter().zip(&justification.signatures) {
+            assert_eq!(sig.signing_payload(), jsig.signing_payload(&justification));
+        }
+    }
 }
@@ -0,0 +1,394 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+//! # ASF Finality Proofs
+//!
+//! Portable, SCALE-encoded proofs that a Primearc block was finalized by an
+//! ASF checkpoint certificate, plus a verifier that needs nothing but a known
+//! authority set.
+//!
+//! This crate deliberately has no node, client or runtime dependency so that
+//! bridge relayers, light clients and exchanges can verify finality without
+//! pulling in the Substrate client stack.
+//!
+//! ## Layout
+//!
+//! - [`AsfJustification`]: the certificate set persisted in the block database
+//!   under [`ASF_JUSTIFICATION_ENGINE_ID`] when a checkpoint is finalized
+//! - [`FinalityProof`]: a justification bundled with the SCALE-encoded header
+//!   it finalizes, as returned by `asf_proveFinality`
+//! - [`verify_finality_proof`] / [`verify_justification`]: stateless verification
+//!   against an [`AuthoritySetInfo`]
+//!
+//! Signing payloads are byte-for-byte identical to
+//! `checkpoint_bft::CheckpointSignature::signing_payload`.
+
+use codec::{Decode, Encode};
+use scale_info::TypeInfo;
+use sp_core::hashing::blake2_256;
+use sp_std::{collections::btree_set::BTreeSet, vec::Vec};
+
+/// Consensus engine ID under which ASF justifications are stored in the block database
+pub const ASF_JUSTIFICATION_ENGINE_ID: [u8; 4] = *b"ASFJ";
+
+/// Domain separator for checkpoint signatures (must match checkpoint-bft)
+pub const SIGNATURE_DOMAIN: &[u8] = b"ETRID-CHECKPOINT-V2";
+
+/// Current finality proof format version
+pub const FINALITY_PROOF_VERSION: u8 = 1;
+
+// ═══════════════════════════════════════════════════════════════════════════
+// PROOF TYPES
+// ═══════════════════════════════════════════════════════════════════════════
+
+/// A single validator signature inside a justification
+///
+/// Block and authority-set fields are shared by every signature and live on
+/// [`AsfJustification`]; only per-validator data is kept here.
+#[derive(Clone, Debug, PartialEq, Eq, Encode, Decode, TypeInfo)]
+pub struct JustificationSignature {
+    /// Index of the validator in the authority set
+    pub validator_id: u32,
+    /// Sr25519 public key of the validator
+    pub validator_pubkey: [u8; 32],
+    /// SCALE-encoded `CheckpointType` (guaranteed or VRF opportunity)
+    pub checkpoint_type: Vec<u8>,
+    /// Per-validator signature nonce
+    pub signature_nonce: u64,
+    /// Sr25519 signature over the checkpoint signing payload
+    pub signature: [u8; 64],
+}
+
+/// ASF justification: the checkpoint certificate set for a finalized block
+#[derive(Clone, Debug, PartialEq, Eq, Encode, Decode, TypeInfo)]
+pub struct AsfJustification {
+    /// Network chain ID the signatures are bound to
+    pub chain_id: [u8; 32],
+    /// Finalized block number
+    pub block_number: u32,
+    /// Finalized block hash
+    pub block_hash: [u8; 32],
+    /// Authority set ID that produced the certificate
+    pub authority_set_id: u64,
+    /// Blake2-256 hash of the authority public keys
+    pub authority_set_hash: [u8; 32],
+    /// Validator signatures (at least a BFT quorum)
+    pub signatures: Vec<JustificationSignature>,
+}
+
+impl JustificationSignature {
+    /// Reconstruct the exact payload the validator signed
+    pub fn signing_payload(&self, justification: &AsfJustification) -> Vec<u8> {
+        let mut payload = Vec::with_capacity(
+            SIGNATURE_DOMAIN.len() + 32 * 4 + 4 + 4 + 8 + 8 + self.checkpoint_type.len(),
+        );
+
+        payload.extend_from_slice(SIGNATURE_DOMAIN);
+        payload.extend_from_slice(&justification.chain_id);
+        payload.extend_from_slice(&justification.block_hash);
+        payload.extend_from_slice(&justification.block_number.to_le_bytes());
+        payload.extend_from_slice(&self.validator_id.to_le_bytes());
+        payload.extend_from_slice(&self.validator_pubkey);
+        payload.extend_from_slice(&justification.authority_set_id.to_le_bytes());
+        payload.extend_from_slice(&justification.authority_set_hash);
+        payload.extend_from_slice(&self.checkpoint_type);
+        payload.extend_from_slice(&self.signature_nonce.to_le_bytes());
+
+        payload
+    }
+}
+
+/// Portable proof that a block is finalized
+#[derive(Clone, Debug, PartialEq, Eq, Encode, Decode, TypeInfo)]
+pub struct FinalityProof {
+    /// Proof format version
+    pub version: u8,
+    /// SCALE-encoded block header (its Blake2-256 hash must equal the justified hash)
+    pub header: Vec<u8>,
+    /// Justification finalizing the header
+    pub justification: AsfJustification,
+}
+
+impl FinalityProof {
+    /// Bundle a header and its justification into a proof
+    pub fn new(header: Vec<u8>, justification: AsfJustification) -> Self {
+        Self {
+            version: FINALITY_PROOF_VERSION,
+            header,
+            justification,
+        }
+    }
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// VERIFICATION
+// ═══════════════════════════════════════════════════════════════════════════
+
+/// Authority set trusted by the verifier
+#[derive(Clone, Debug, PartialEq, Eq, Encode, Decode, TypeInfo)]
+pub struct AuthoritySetInfo {
+    /// Authority set ID
+    pub set_id: u64,
+    /// Chain ID the set signs for
+    pub chain_id: [u8; 32],
+    /// Ordered sr25519 public keys (index == validator_id)
+    pub authorities: Vec<[u8; 32]>,
+}
+
+impl AuthoritySetInfo {
+    /// Blake2-256(pubkey_0 || pubkey_1 || ... || pubkey_n)
+    pub fn authority_set_hash(&self) -> [u8; 32] {
+        let mut data = Vec::with_capacity(self.authorities.len() * 32);
+        for pubkey in &self.authorities {
+            data.extend_from_slice(pubkey);
+        }
+        blake2_256(&data)
+    }
+
+    /// Number of distinct signatures required (2/3 + 1, i.e. 15 of 21)
+    pub fn quorum_threshold(&self) -> usize {
+        self.authorities.len() * 2 / 3 + 1
+    }
+}
+
+/// Summary of a successfully verified proof
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct VerifiedFinality {
+    /// Finalized block number
+    pub block_number: u32,
+    /// Finalized block hash
+    pub block_hash: [u8; 32],
+    /// Number of valid signatures
+    pub signature_count: u32,
+}
+
+/// Reasons a proof can be rejected
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ProofError {
+    /// Proof format version is not supported
+    UnsupportedVersion(u8),
+    /// Header hash does not match the justified block hash
+    HeaderHashMismatch,
+    /// Justification is bound to a different chain
+    ChainIdMismatch,
+    /// Justification was produced by a different authority set
+    AuthoritySetIdMismatch { expected: u64, got: u64 },
+    /// Authority set hash does not match the trusted set
+    AuthoritySetHashMismatch,
+    /// Validator index outside the authority set
+    UnknownValidator(u32),
+    /// Public key does not match the authority at that index
+    ValidatorKeyMismatch(u32),
+    /// Same validator signed more than once
+    DuplicateSigner(u32),
+    /// Signature failed cryptographic verification
+    InvalidSignature(u32),
+    /// Not enough valid signatures
+    InsufficientSignatures { required: u32, got: u32 },
+    /// Proof bytes could not be decoded
+    Decode,
+}
+
+/// Verify a justification against a trusted authority set
+pub fn verify_justification(
+    justification: &AsfJustification,
+    authority_set: &AuthoritySetInfo,
+) -> Result<VerifiedFinality, ProofError> {
+    if justification.chain_id != authority_set.chain_id {
+        return Err(ProofError::ChainIdMismatch);
+    }
+
+    if justification.authority_set_id != authority_set.set_id {
+        return Err(ProofError::AuthoritySetIdMismatch {
+            expected: authority_set.set_id,
+            got: justification.authority_set_id,
+        });
+    }
+
+    if justification.authority_set_hash != authority_set.authority_set_hash() {
+        return Err(ProofError::AuthoritySetHashMismatch);
+    }
+
+    let mut signers = BTreeSet::new();
+    for sig in &justification.signatures {
+        let expected_key = authority_set
+            .authorities
+            .get(sig.validator_id as usize)
+            .ok_or(ProofError::UnknownValidator(sig.validator_id))?;
+
+        if *expected_key != sig.validator_pubkey {
+            return Err(ProofError::ValidatorKeyMismatch(sig.validator_id));
+        }
+
+        if !signers.insert(sig.validator_id) {
+            return Err(ProofError::DuplicateSigner(sig.validator_id));
+        }
+
+        let payload = sig.signing_payload(justification);
+        let public = sp_core::sr25519::Public::from_raw(sig.validator_pubkey);
+        let signature = sp_core::sr25519::Signature::from_raw(sig.signature);
+        if !<sp_core::sr25519::Pair as sp_core::Pair>::verify(&signature, &payload, &public) {
+            return Err(ProofError::InvalidSignature(sig.validator_id));
+        }
+    }
+
+    let required = authority_set.quorum_threshold() as u32;
+    let got = signers.len() as u32;
+    if got < required {
+        return Err(ProofError::InsufficientSignatures { required, got });
+    }
+
+    Ok(VerifiedFinality {
+        block_number: justification.block_number,
+        block_hash: justification.block_hash,
+        signature_count: got,
+    })
+}
+
+/// Verify a finality proof (header binding + justification)
+pub fn verify_finality_proof(
+    proof: &FinalityProof,
+    authority_set: &AuthoritySetInfo,
+) -> Result<VerifiedFinality, ProofError> {
+    if proof.version != FINALITY_PROOF_VERSION {
+        return Err(ProofError::UnsupportedVersion(proof.version));
+    }
+
+    if blake2_256(&proof.header) != proof.justification.block_hash {
+        return Err(ProofError::HeaderHashMismatch);
+    }
+
+    verify_justification(&proof.justification, authority_set)
+}
+
+/// Decode and verify a SCALE-encoded finality proof
+pub fn verify_encoded_finality_proof(
+    mut encoded: &[u8],
+    authority_set: &AuthoritySetInfo,
+) -> Result<VerifiedFinality, ProofError> {
+    let proof = FinalityProof::decode(&mut encoded).map_err(|_| ProofError::Decode)?;
+    verify_finality_proof(&proof, authority_set)
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// TESTS
+// ═══════════════════════════════════════════════════════════════════════════
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sp_core::{sr25519, Pair};
+
+    const CHAIN_ID: [u8; 32] = [7u8; 32];
+
+    fn keys(count: usize) -> Vec<sr25519::Pair> {
+        (0..count)
+            .map(|i| sr25519::Pair::from_seed(&[i as u8 + 1; 32]))
+            .collect()
+    }
+
+    fn authority_set(pairs: &[sr25519::Pair]) -> AuthoritySetInfo {
+        AuthoritySetInfo {
+            set_id: 3,
+            chain_id: CHAIN_ID,
+            authorities: pairs.iter().map(|p| p.public().0).collect(),
+        }
+    }
+
+    fn signed_proof(pairs: &[sr25519::Pair], signers: usize) -> FinalityProof {
+        let set = authority_set(pairs);
+        let header = b"encoded header".to_vec();
+        let mut justification = AsfJustification {
+            chain_id: CHAIN_ID,
+            block_number: 64,
+            block_hash: blake2_256(&header),
+            authority_set_id: set.set_id,
+            authority_set_hash: set.authority_set_hash(),
+            signatures: Vec::new(),
+        };
+
+        for (i, pair) in pairs.iter().take(signers).enumerate() {
+            let mut sig = JustificationSignature {
+                validator_id: i as u32,
+                validator_pubkey: pair.public().0,
+                checkpoint_type: vec![0u8],
+                signature_nonce: 1,
+                signature: [0u8; 64],
+            };
+            sig.signature = pair.sign(&sig.signing_payload(&justification)).0;
+            justification.signatures.push(sig);
+        }
+
+        FinalityProof::new(header, justification)
+    }
+
+    #[test]
+    fn test_quorum_threshold() {
+        let set = authority_set(&keys(21));
+        assert_eq!(set.quorum_threshold(), 15);
+    }
+
+    #[test]
+    fn test_valid_proof_verifies() {
+        let pairs = keys(21);
+        let proof = signed_proof(&pairs, 15);
+        let verified = verify_encoded_finality_proof(&proof.encode(), &authority_set(&pairs)).unwrap();
+        assert_eq!(verified.block_number, 64);
+        assert_eq!(verified.signature_count, 15);
+    }
+
+    #[test]
+    fn test_insufficient_signatures_rejected() {
+        let pairs = keys(21);
+        let proof = signed_proof(&pairs, 14);
+        assert_eq!(
+            verify_finality_proof(&proof, &authority_set(&pairs)),
+            Err(ProofError::InsufficientSignatures { required: 15, got: 14 })
+        );
+    }
+
+    #[test]
+    fn test_tampered_header_rejected() {
+        let pairs = keys(21);
+        let mut proof = signed_proof(&pairs, 21);
+        proof.header.push(0);
+        assert_eq!(
+            verify_finality_proof(&proof, &authority_set(&pairs)),
+            Err(ProofError::HeaderHashMismatch)
+        );
+    }
+
+    #[test]
+    fn test_duplicate_signer_rejected() {
+        let pairs = keys(21);
+        let mut proof = signed_proof(&pairs, 15);
+        let dup = proof.justification.signatures[0].clone();
+        proof.justification.signatures.push(dup);
+        assert_eq!(
+            verify_finality_proof(&proof, &authority_set(&pairs)),
+            Err(ProofError::DuplicateSigner(0))
+        );
+    }
+
+    #[test]
+    fn test_wrong_authority_set_rejected() {
+        let pairs = keys(21);
+        let proof = signed_proof(&pairs, 21);
+        let mut set = authority_set(&pairs);
+        set.set_id = 4;
+        assert_eq!(
+            verify_finality_proof(&proof, &set),
+            Err(ProofError::AuthoritySetIdMismatch { expected: 4, got: 3 })
+        );
+    }
+
+    #[test]
+    fn test_forged_signature_rejected() {
+        let pairs = keys(21);
+        let mut proof = signed_proof(&pairs, 15);
+        proof.justification.signatures[2].signature[0] ^= 0xff;
+        assert_eq!(
+            verify_finality_proof(&proof, &authority_set(&pairs)),
+            Err(ProofError::InvalidSignature(2))
+        );
+    }
+}
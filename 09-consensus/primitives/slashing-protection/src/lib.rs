@@ -0,0 +1,505 @@
+//! ASF Slashing Protection
+//!
+//! Local database of the highest position each validator key has signed, consulted
+//! before every signature so a restarted or migrated validator cannot equivocate.
+//!
+//! ## Rules
+//!
+//! A signature at `(epoch, round, phase, height)` is allowed only if that position
+//! is strictly greater (lexicographically) than the last one signed by the key in
+//! the same domain (votes or checkpoints). Re-signing the exact same position and
+//! signing root is allowed, since it cannot produce conflicting messages.
+//!
+//! Signature nonces are persisted too, so nonces keep increasing across restarts.
+//!
+//! ## Interchange Format
+//!
+//! The database file is the interchange document itself (JSON), so exporting is a
+//! copy and importing is a merge that never lowers a recorded position:
+//!
+//! ```json
+//! {
+//!   "metadata": { "interchange_format_version": 1, "genesis_hash": "0x…" },
+//!   "data": [
+//!     {
+//!       "pubkey": "0x…",
+//!       "last_vote": { "epoch": 3, "round": 12, "phase": 2, "height": 9120, "signing_root": "0x…" },
+//!       "last_checkpoint": { "epoch": 3, "round": 0, "phase": 0, "height": 9120, "signing_root": "0x…" },
+//!       "last_nonce": 517
+//!     }
+//!   ]
+//! }
+//! ```
+//!
+//! Writes go to a temporary file that is synced and renamed over the database, so a
+//! crash leaves either the old or the new state, never a partial one.
+
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fmt;
+use std::fs;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+/// Current interchange format version
+pub const INTERCHANGE_FORMAT_VERSION: u32 = 1;
+
+/// Default database file name inside the node's base path
+pub const DEFAULT_DB_FILE: &str = "slashing_protection.json";
+
+// ═══════════════════════════════════════════════════════════════════════════════
+// TYPES
+// ═══════════════════════════════════════════════════════════════════════════════
+
+/// Signing domain (positions are tracked independently per domain)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SigningKind {
+    /// Consensus votes
+    Vote,
+    /// Checkpoint BFT signatures
+    Checkpoint,
+}
+
+impl fmt::Display for SigningKind {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            SigningKind::Vote => write!(f, "vote"),
+            SigningKind::Checkpoint => write!(f, "checkpoint"),
+        }
+    }
+}
+
+/// Position of a signed message, ordered by (epoch, round, phase, height)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+pub struct SigningPosition {
+    pub epoch: u64,
+    pub round: u64,
+    pub phase: u8,
+    pub height: u64,
+}
+
+impl fmt::Display for SigningPosition {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "epoch {} round {} phase {} height {}",
+            self.epoch, self.round, self.phase, self.height
+        )
+    }
+}
+
+/// Last signed message in a domain
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SignedRecord {
+    #[serde(flatten)]
+    pub position: SigningPosition,
+    /// Hash of the content attested (0x-prefixed hex)
+    pub signing_root: String,
+}
+
+/// Everything recorded for one validator key
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct KeyRecord {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_vote: Option<SignedRecord>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_checkpoint: Option<SignedRecord>,
+    #[serde(default)]
+    pub last_nonce: u64,
+}
+
+impl KeyRecord {
+    fn slot(&mut self, kind: SigningKind) -> &mut Option<SignedRecord> {
+        match kind {
+            SigningKind::Vote => &mut self.last_vote,
+            SigningKind::Checkpoint => &mut self.last_checkpoint,
+        }
+    }
+
+    /// Merge another record, keeping the higher position in every domain
+    fn merge(&mut self, other: KeyRecord) {
+        for (mine, theirs) in [
+            (&mut self.last_vote, other.last_vote),
+            (&mut self.last_checkpoint, other.last_checkpoint),
+        ] {
+            if let Some(theirs) = theirs {
+                let higher = match mine.as_ref() {
+                    Some(m) => theirs.position > m.position,
+                    None => true,
+                };
+                if higher {
+                    *mine = Some(theirs);
+                }
+            }
+        }
+        self.last_nonce = self.last_nonce.max(other.last_nonce);
+    }
+}
+
+/// Interchange metadata
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct InterchangeMetadata {
+    pub interchange_format_version: u32,
+    /// Genesis hash of the chain the records belong to (0x-prefixed hex)
+    pub genesis_hash: String,
+}
+
+/// Records for one key in the interchange document
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct InterchangeEntry {
+    /// Validator public key (0x-prefixed hex)
+    pub pubkey: String,
+    #[serde(flatten)]
+    pub record: KeyRecord,
+}
+
+/// Portable slashing-protection document
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Interchange {
+    pub metadata: InterchangeMetadata,
+    pub data: Vec<InterchangeEntry>,
+}
+
+impl Interchange {
+    pub fn from_json(json: &str) -> Result<Self, ProtectionError> {
+        let interchange: Interchange =
+            serde_json::from_str(json).map_err(|e| ProtectionError::Corrupt(e.to_string()))?;
+        if interchange.metadata.interchange_format_version != INTERCHANGE_FORMAT_VERSION {
+            return Err(ProtectionError::UnsupportedVersion(
+                interchange.metadata.interchange_format_version,
+            ));
+        }
+        Ok(interchange)
+    }
+
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).expect("interchange types always serialize; qed")
+    }
+}
+
+/// Slashing-protection errors
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ProtectionError {
+    /// Signing would conflict with an earlier signature
+    Slashable {
+        kind: SigningKind,
+        last: SigningPosition,
+        attempted: SigningPosition,
+    },
+    /// Records belong to a different chain
+    GenesisMismatch { expected: String, found: String },
+    UnsupportedVersion(u32),
+    Corrupt(String),
+    Io(String),
+}
+
+impl fmt::Display for ProtectionError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ProtectionError::Slashable { kind, last, attempted } => write!(
+                f,
+                "Refusing to sign {} at {}: already signed {}",
+                kind, attempted, last
+            ),
+            ProtectionError::GenesisMismatch { expected, found } => {
+                write!(f, "Genesis mismatch: expected {}, found {}", expected, found)
+            }
+            ProtectionError::UnsupportedVersion(v) => {
+                write!(f, "Unsupported interchange format version: {}", v)
+            }
+            ProtectionError::Corrupt(e) => write!(f, "Corrupt slashing-protection data: {}", e),
+            ProtectionError::Io(e) => write!(f, "Slashing-protection I/O error: {}", e),
+        }
+    }
+}
+
+impl std::error::Error for ProtectionError {}
+
+fn to_hex(bytes: &[u8]) -> String {
+    format!("0x{}", hex::encode(bytes))
+}
+
+// ═══════════════════════════════════════════════════════════════════════════════
+// DATABASE
+// ═══════════════════════════════════════════════════════════════════════════════
+
+/// Slashing-protection database (persisted on every change)
+#[derive(Debug)]
+pub struct SlashingProtectionDb {
+    /// File backing the database (`None` for in-memory use)
+    path: Option<PathBuf>,
+    genesis_hash: String,
+    keys: BTreeMap<String, KeyRecord>,
+}
+
+impl SlashingProtectionDb {
+    /// Open (or create) the database at `path` for the chain with `genesis_hash`
+    pub fn open(path: impl AsRef<Path>, genesis_hash: [u8; 32]) -> Result<Self, ProtectionError> {
+        let path = path.as_ref().to_path_buf();
+        let genesis_hash = to_hex(&genesis_hash);
+
+        let keys = match fs::read_to_string(&path) {
+            Ok(json) => {
+                let interchange = Interchange::from_json(&json)?;
+                if interchange.metadata.genesis_hash != genesis_hash {
+                    return Err(ProtectionError::GenesisMismatch {
+                        expected: genesis_hash,
+                        found: interchange.metadata.genesis_hash,
+                    });
+                }
+                Self::index(interchange.data)
+            }
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => BTreeMap::new(),
+            Err(e) => return Err(ProtectionError::Io(e.to_string())),
+        };
+
+        let db = Self { path: Some(path), genesis_hash, keys };
+        db.persist()?;
+        Ok(db)
+    }
+
+    /// Database that is not persisted
+    pub fn in_memory(genesis_hash: [u8; 32]) -> Self {
+        Self {
+            path: None,
+            genesis_hash: to_hex(&genesis_hash),
+            keys: BTreeMap::new(),
+        }
+    }
+
+    /// Record a signature about to be made; fails if it could be slashable
+    ///
+    /// The record is persisted before returning `Ok`, so the caller may sign.
+    pub fn check_and_record(
+        &mut self,
+        pubkey: &[u8; 32],
+        kind: SigningKind,
+        position: SigningPosition,
+        signing_root: &[u8; 32],
+    ) -> Result<(), ProtectionError> {
+        let pubkey = to_hex(pubkey);
+        let signing_root = to_hex(signing_root);
+
+        let mut record = self.keys.get(&pubkey).cloned().unwrap_or_default();
+        let slot = record.slot(kind);
+
+        if let Some(last) = slot.as_ref() {
+            if last.position == position && last.signing_root == signing_root {
+                return Ok(());
+            }
+            if position <= last.position {
+                return Err(ProtectionError::Slashable {
+                    kind,
+                    last: last.position,
+                    attempted: position,
+                });
+            }
+        }
+
+        *slot = Some(SignedRecord { position, signing_root });
+        self.commit(pubkey, record)
+    }
+
+    /// Reserve the next signature nonce for a key (persisted)
+    pub fn next_nonce(&mut self, pubkey: &[u8; 32]) -> Result<u64, ProtectionError> {
+        let pubkey = to_hex(pubkey);
+        let mut record = self.keys.get(&pubkey).cloned().unwrap_or_default();
+        record.last_nonce = record.last_nonce.saturating_add(1);
+        let nonce = record.last_nonce;
+
+        self.commit(pubkey, record)?;
+        Ok(nonce)
+    }
+
+    /// Records for one key
+    pub fn record(&self, pubkey: &[u8; 32]) -> Option<KeyRecord> {
+        self.keys.get(&to_hex(pubkey)).cloned()
+    }
+
+    /// Export all records
+    pub fn export(&self) -> Interchange {
+        Interchange {
+            metadata: InterchangeMetadata {
+                interchange_format_version: INTERCHANGE_FORMAT_VERSION,
+                genesis_hash: self.genesis_hash.clone(),
+            },
+            data: self
+                .keys
+                .iter()
+                .map(|(pubkey, record)| InterchangeEntry {
+                    pubkey: pubkey.clone(),
+                    record: record.clone(),
+                })
+                .collect(),
+        }
+    }
+
+    /// Merge records from another validator instance; returns the number of keys touched
+    ///
+    /// Positions and nonces only ever move up.
+    pub fn import(&mut self, interchange: Interchange) -> Result<usize, ProtectionError> {
+        if interchange.metadata.interchange_format_version != INTERCHANGE_FORMAT_VERSION {
+            return Err(ProtectionError::UnsupportedVersion(
+                interchange.metadata.interchange_format_version,
+            ));
+        }
+        if interchange.metadata.genesis_hash != self.genesis_hash {
+            return Err(ProtectionError::GenesisMismatch {
+                expected: self.genesis_hash.clone(),
+                found: interchange.metadata.genesis_hash,
+            });
+        }
+
+        let previous = self.keys.clone();
+        let count = interchange.data.len();
+        for (pubkey, record) in Self::index(interchange.data) {
+            self.keys.entry(pubkey).or_default().merge(record);
+        }
+
+        if let Err(e) = self.persist() {
+            self.keys = previous;
+            return Err(e);
+        }
+        Ok(count)
+    }
+
+    fn index(entries: Vec<InterchangeEntry>) -> BTreeMap<String, KeyRecord> {
+        let mut keys: BTreeMap<String, KeyRecord> = BTreeMap::new();
+        for entry in entries {
+            keys.entry(entry.pubkey.to_lowercase()).or_default().merge(entry.record);
+        }
+        keys
+    }
+
+    /// Apply a record update only if it can be persisted
+    fn commit(&mut self, pubkey: String, record: KeyRecord) -> Result<(), ProtectionError> {
+        let previous = self.keys.insert(pubkey.clone(), record);
+        if let Err(e) = self.persist() {
+            match previous {
+                Some(previous) => self.keys.insert(pubkey, previous),
+                None => self.keys.remove(&pubkey),
+            };
+            return Err(e);
+        }
+        Ok(())
+    }
+
+    fn persist(&self) -> Result<(), ProtectionError> {
+        let Some(path) = &self.path else {
+            return Ok(());
+        };
+        let io = |e: std::io::Error| ProtectionError::Io(e.to_string());
+
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent).map_err(io)?;
+        }
+
+        let tmp = path.with_extension("json.tmp");
+        let mut file = fs::File::create(&tmp).map_err(io)?;
+        file.write_all(self.export().to_json().as_bytes()).map_err(io)?;
+        file.sync_all().map_err(io)?;
+        fs::rename(&tmp, path).map_err(io)?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const GENESIS: [u8; 32] = [9u8; 32];
+    const KEY: [u8; 32] = [1u8; 32];
+
+    fn pos(epoch: u64, round: u64, phase: u8, height: u64) -> SigningPosition {
+        SigningPosition { epoch, round, phase, height }
+    }
+
+    fn temp_path(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("asf-sp-{}-{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        dir.join(DEFAULT_DB_FILE)
+    }
+
+    #[test]
+    fn test_only_higher_positions_are_signed() {
+        let mut db = SlashingProtectionDb::in_memory(GENESIS);
+        db.check_and_record(&KEY, SigningKind::Vote, pos(1, 2, 1, 10), &[1u8; 32]).unwrap();
+
+        // Same position, same root: harmless re-sign
+        assert!(db.check_and_record(&KEY, SigningKind::Vote, pos(1, 2, 1, 10), &[1u8; 32]).is_ok());
+
+        // Same position, different root: equivocation
+        assert!(matches!(
+            db.check_and_record(&KEY, SigningKind::Vote, pos(1, 2, 1, 10), &[2u8; 32]),
+            Err(ProtectionError::Slashable { .. })
+        ));
+
+        // Lower position
+        assert!(db.check_and_record(&KEY, SigningKind::Vote, pos(1, 2, 0, 11), &[3u8; 32]).is_err());
+
+        // Higher phase, then higher round
+        assert!(db.check_and_record(&KEY, SigningKind::Vote, pos(1, 2, 2, 10), &[4u8; 32]).is_ok());
+        assert!(db.check_and_record(&KEY, SigningKind::Vote, pos(1, 3, 0, 10), &[5u8; 32]).is_ok());
+    }
+
+    #[test]
+    fn test_domains_are_independent() {
+        let mut db = SlashingProtectionDb::in_memory(GENESIS);
+        db.check_and_record(&KEY, SigningKind::Vote, pos(5, 0, 0, 100), &[1u8; 32]).unwrap();
+        assert!(db.check_and_record(&KEY, SigningKind::Checkpoint, pos(1, 0, 0, 32), &[2u8; 32]).is_ok());
+    }
+
+    #[test]
+    fn test_records_survive_restart() {
+        let path = temp_path("restart");
+        {
+            let mut db = SlashingProtectionDb::open(&path, GENESIS).unwrap();
+            db.check_and_record(&KEY, SigningKind::Checkpoint, pos(1, 0, 0, 64), &[1u8; 32]).unwrap();
+            assert_eq!(db.next_nonce(&KEY).unwrap(), 1);
+            assert_eq!(db.next_nonce(&KEY).unwrap(), 2);
+        }
+
+        let mut db = SlashingProtectionDb::open(&path, GENESIS).unwrap();
+        assert!(db.check_and_record(&KEY, SigningKind::Checkpoint, pos(1, 0, 0, 64), &[2u8; 32]).is_err());
+        assert_eq!(db.next_nonce(&KEY).unwrap(), 3);
+
+        // A different chain's records are never used
+        assert!(matches!(
+            SlashingProtectionDb::open(&path, [0u8; 32]),
+            Err(ProtectionError::GenesisMismatch { .. })
+        ));
+        let _ = fs::remove_dir_all(path.parent().unwrap());
+    }
+
+    #[test]
+    fn test_import_never_lowers() {
+        let mut old = SlashingProtectionDb::in_memory(GENESIS);
+        old.check_and_record(&KEY, SigningKind::Vote, pos(2, 7, 1, 500), &[1u8; 32]).unwrap();
+        old.next_nonce(&KEY).unwrap();
+
+        let mut new = SlashingProtectionDb::in_memory(GENESIS);
+        new.check_and_record(&KEY, SigningKind::Vote, pos(2, 8, 0, 510), &[2u8; 32]).unwrap();
+
+        let exported = Interchange::from_json(&old.export().to_json()).unwrap();
+        assert_eq!(new.import(exported).unwrap(), 1);
+
+        let record = new.record(&KEY).unwrap();
+        assert_eq!(record.last_vote.unwrap().position, pos(2, 8, 0, 510));
+        assert_eq!(record.last_nonce, 1);
+
+        // Migrated validator refuses what the old machine already signed
+        let mut migrated = SlashingProtectionDb::in_memory(GENESIS);
+        migrated.import(old.export()).unwrap();
+        assert!(migrated.check_and_record(&KEY, SigningKind::Vote, pos(2, 7, 1, 500), &[9u8; 32]).is_err());
+    }
+
+    #[test]
+    fn test_import_rejects_other_chain() {
+        let other = SlashingProtectionDb::in_memory([0u8; 32]);
+        let mut db = SlashingProtectionDb::in_memory(GENESIS);
+        assert!(matches!(
+            db.import(other.export()),
+            Err(ProtectionError::GenesisMismatch { .. })
+        ));
+    }
+}
@@ -0,0 +1,122 @@
+//! Benchmarking setup for pallet-vote-delegation
+//!
+//! Every call relocks the caller's balance by walking all of its delegations and
+//! carried-over locks, so component `c` is the number walked. The extra entries
+//! are spread over the other tracks, one delegation and one lock per track.
+
+#![cfg(feature = "runtime-benchmarks")]
+
+use super::*;
+use frame_benchmarking::v2::*;
+use frame_support::{
+    assert_ok,
+    traits::{Currency, Get},
+};
+use frame_system::{pallet_prelude::BlockNumberFor, RawOrigin};
+use sp_runtime::traits::{Bounded, Saturating};
+
+const SEED: u32 = 0;
+
+/// Account funded well beyond every delegated balance
+fn funded<T: Config>(name: &'static str, index: u32) -> T::AccountId {
+    let who: T::AccountId = account(name, index, SEED);
+    let amount = T::Currency::minimum_balance().saturating_mul(1_000_000u32.into());
+    T::Currency::make_free_balance_be(&who, amount);
+    who
+}
+
+fn delegated_balance<T: Config>() -> BalanceOf<T> {
+    T::Currency::minimum_balance().saturating_mul(100u32.into()).max(1u32.into())
+}
+
+/// Give `who` the commitments `start..start + count` of the sequence
+/// lock(0), delegation(1), lock(1), delegation(2), lock(2), ...
+fn add_commitments<T: Config>(who: &T::AccountId, start: u32, count: u32) {
+    let target: T::AccountId = account("target", 1, SEED);
+    for slot in start..start.saturating_add(count) {
+        let track = ((slot + 1) / 2) as TrackId;
+        if slot % 2 == 1 {
+            let delegation = Delegation {
+                target: target.clone(),
+                conviction: Conviction::Locked1x,
+                balance: delegated_balance::<T>(),
+            };
+            Delegations::<T>::insert(who, track, delegation);
+        } else {
+            let lock = PriorLock {
+                until: BlockNumberFor::<T>::max_value(),
+                amount: delegated_balance::<T>(),
+            };
+            PriorLocks::<T>::insert(who, track, lock);
+        }
+    }
+}
+
+#[benchmarks]
+mod benchmarks {
+    use super::*;
+
+    /// Delegating on track 0 alongside `c - 1` other commitments
+    #[benchmark]
+    fn delegate(c: Linear<1, { Pallet::<T>::max_commitments() }>) {
+        let caller = funded::<T>("caller", 0);
+        let target: T::AccountId = account("target", 0, SEED);
+        add_commitments::<T>(&caller, 0, c - 1);
+
+        #[extrinsic_call]
+        _(
+            RawOrigin::Signed(caller.clone()),
+            GOVERNANCE_TRACK,
+            target.clone(),
+            Conviction::Locked6x,
+            delegated_balance::<T>(),
+        );
+
+        assert!(Delegations::<T>::contains_key(&caller, GOVERNANCE_TRACK));
+        assert_eq!(DelegatedVotes::<T>::get(&target, GOVERNANCE_TRACK).delegators, 1);
+    }
+
+    /// Undelegating on track 0, merging into its carried-over lock, alongside
+    /// `c - 1` other commitments
+    #[benchmark]
+    fn undelegate(c: Linear<1, { Pallet::<T>::max_commitments() - 1 }>) {
+        let caller = funded::<T>("caller", 0);
+        let target: T::AccountId = account("target", 0, SEED);
+        add_commitments::<T>(&caller, 0, 1);
+        add_commitments::<T>(&caller, 1, c - 1);
+        assert_ok!(Pallet::<T>::delegate(
+            RawOrigin::Signed(caller.clone()).into(),
+            GOVERNANCE_TRACK,
+            target,
+            Conviction::Locked6x,
+            delegated_balance::<T>(),
+        ));
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller.clone()), GOVERNANCE_TRACK);
+
+        assert!(!Delegations::<T>::contains_key(&caller, GOVERNANCE_TRACK));
+        assert!(PriorLocks::<T>::contains_key(&caller, GOVERNANCE_TRACK));
+    }
+
+    /// Releasing an expired lock on track 0 alongside `c` other commitments
+    #[benchmark]
+    fn unlock(c: Linear<0, { Pallet::<T>::max_commitments() - 2 }>) {
+        let caller: T::AccountId = whitelisted_caller();
+        let target = funded::<T>("target", 0);
+        add_commitments::<T>(&target, 1, c);
+        let now = frame_system::Pallet::<T>::block_number();
+        PriorLocks::<T>::insert(
+            &target,
+            GOVERNANCE_TRACK,
+            PriorLock { until: now, amount: delegated_balance::<T>() },
+        );
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller), GOVERNANCE_TRACK, target.clone());
+
+        assert!(!PriorLocks::<T>::contains_key(&target, GOVERNANCE_TRACK));
+    }
+
+    impl_benchmark_test_suite!(Pallet, crate::tests::new_test_ext(), crate::tests::Test);
+}
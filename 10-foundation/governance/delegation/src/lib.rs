@@ -0,0 +1,713 @@
+//! # Vote Delegation Pallet
+//!
+//! Per-track delegation of voting power with conviction locks, shared by
+//! `pallet-governance` and `pallet-consensus-day`.
+//!
+//! ## Overview
+//!
+//! - An account delegates a locked balance on one track to one delegate
+//! - Conviction multiplies the delegated votes in exchange for a longer lock
+//! - Undelegating keeps the lock for `conviction.lock_periods() * VoteLockingPeriod`
+//! - Delegations are not transitive: power received by a delegate is not passed on
+//!   if the delegate itself delegates
+//!
+//! Each delegate keeps a running total of the votes delegated to it per track,
+//! so a delegator change touches one total and the delegate's own votes. The
+//! consuming pallets are told about the change through [`DelegationHooks`] and
+//! re-tally the delegate's open votes; they read totals through [`DelegationInfo`].
+//!
+//! Calls are weighed by the delegations and carried-over locks of the caller
+//! walked when its balance lock is recomputed, at most two per track. The
+//! re-tally weight reported by the hooks depends on the delegate's open votes,
+//! which only the voting pallets can bound, and is registered as it is spent.
+//!
+//! ## Conviction
+//!
+//! | Conviction | Votes           | Lock periods |
+//! |------------|-----------------|--------------|
+//! | None       | 0.1 × balance   | 0            |
+//! | Locked1x   | 1 × balance     | 1            |
+//! | Locked2x   | 2 × balance     | 2            |
+//! | Locked3x   | 3 × balance     | 4            |
+//! | Locked4x   | 4 × balance     | 8            |
+//! | Locked5x   | 5 × balance     | 16           |
+//! | Locked6x   | 6 × balance     | 32           |
+//!
+//! ## Extrinsics
+//!
+//! - `delegate` - Delegate a balance on a track with a conviction
+//! - `undelegate` - Remove a delegation, carrying its conviction lock over
+//! - `unlock` - Release an expired carried-over lock
+//!
+//! ## Storage
+//!
+//! - `Delegations` - Maps (delegator, track) to the active delegation
+//! - `DelegatedVotes` - Maps (delegate, track) to the votes delegated to it
+//! - `PriorLocks` - Maps (account, track) to a lock carried over after undelegation
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+pub use pallet::*;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+pub mod weights;
+pub use weights::WeightInfo;
+
+use codec::{Decode, DecodeWithMemTracking, Encode, MaxEncodedLen};
+use frame_support::weights::Weight;
+use scale_info::TypeInfo;
+use sp_runtime::RuntimeDebug;
+
+/// Delegation track identifier
+pub type TrackId = u8;
+
+/// Track for standard `pallet-governance` proposals
+pub const GOVERNANCE_TRACK: TrackId = 0;
+
+/// Track for `pallet-consensus-day` proposals
+pub const CONSENSUS_DAY_TRACK: TrackId = 1;
+
+/// Vote multiplier traded against lock duration
+#[derive(
+    Clone, Copy, Encode, Decode, DecodeWithMemTracking, PartialEq, Eq, PartialOrd, Ord,
+    TypeInfo, MaxEncodedLen, RuntimeDebug, Default,
+)]
+pub enum Conviction {
+    /// 0.1x votes, no lock after undelegation
+    #[default]
+    None,
+    Locked1x,
+    Locked2x,
+    Locked3x,
+    Locked4x,
+    Locked5x,
+    Locked6x,
+}
+
+impl Conviction {
+    /// Votes carried by `capital` at this conviction
+    pub fn votes(self, capital: u128) -> u128 {
+        match self {
+            Conviction::None => capital / 10,
+            Conviction::Locked1x => capital,
+            Conviction::Locked2x => capital.saturating_mul(2),
+            Conviction::Locked3x => capital.saturating_mul(3),
+            Conviction::Locked4x => capital.saturating_mul(4),
+            Conviction::Locked5x => capital.saturating_mul(5),
+            Conviction::Locked6x => capital.saturating_mul(6),
+        }
+    }
+
+    /// Number of `VoteLockingPeriod`s the balance stays locked after undelegation
+    pub fn lock_periods(self) -> u32 {
+        match self {
+            Conviction::None => 0,
+            Conviction::Locked1x => 1,
+            Conviction::Locked2x => 2,
+            Conviction::Locked3x => 4,
+            Conviction::Locked4x => 8,
+            Conviction::Locked5x => 16,
+            Conviction::Locked6x => 32,
+        }
+    }
+}
+
+/// Read access to delegation state for voting pallets
+pub trait DelegationInfo<AccountId> {
+    /// Conviction-weighted votes currently delegated to `who` on `track`
+    fn delegated_votes(who: &AccountId, track: TrackId) -> u128;
+
+    /// Whether `who` has delegated its own vote on `track`
+    fn is_delegating(who: &AccountId, track: TrackId) -> bool;
+}
+
+impl<AccountId> DelegationInfo<AccountId> for () {
+    fn delegated_votes(_: &AccountId, _: TrackId) -> u128 {
+        0
+    }
+
+    fn is_delegating(_: &AccountId, _: TrackId) -> bool {
+        false
+    }
+}
+
+/// Callbacks into voting pallets that tally delegated votes
+pub trait DelegationHooks<AccountId> {
+    /// Whether `who` has votes on open proposals of `track`
+    ///
+    /// Delegating is refused while this is true so the same balance is not
+    /// counted both directly and through a delegate.
+    fn has_active_votes(who: &AccountId, track: TrackId) -> bool;
+
+    /// Votes delegated to `delegate` on `track` changed from `old` to `new`
+    fn on_delegated_votes_changed(delegate: &AccountId, track: TrackId, old: u128, new: u128) -> Weight;
+}
+
+impl<AccountId> DelegationHooks<AccountId> for () {
+    fn has_active_votes(_: &AccountId, _: TrackId) -> bool {
+        false
+    }
+
+    fn on_delegated_votes_changed(_: &AccountId, _: TrackId, _: u128, _: u128) -> Weight {
+        Weight::zero()
+    }
+}
+
+impl<AccountId, A, B> DelegationHooks<AccountId> for (A, B)
+where
+    A: DelegationHooks<AccountId>,
+    B: DelegationHooks<AccountId>,
+{
+    fn has_active_votes(who: &AccountId, track: TrackId) -> bool {
+        A::has_active_votes(who, track) || B::has_active_votes(who, track)
+    }
+
+    fn on_delegated_votes_changed(delegate: &AccountId, track: TrackId, old: u128, new: u128) -> Weight {
+        A::on_delegated_votes_changed(delegate, track, old, new)
+            .saturating_add(B::on_delegated_votes_changed(delegate, track, old, new))
+    }
+}
+
+#[frame_support::pallet]
+pub mod pallet {
+    use super::*;
+    use frame_support::{
+        pallet_prelude::*,
+        traits::{Currency, LockIdentifier, LockableCurrency, WithdrawReasons},
+    };
+    use frame_system::pallet_prelude::*;
+    use sp_runtime::traits::{Saturating, UniqueSaturatedInto, Zero};
+
+    /// Lock identifier for delegated balances
+    pub const DELEGATION_LOCK_ID: LockIdentifier = *b"delegate";
+
+    pub type BalanceOf<T> =
+        <<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
+
+    /// An active delegation
+    #[derive(Encode, Decode, TypeInfo, MaxEncodedLen, Clone, Eq, PartialEq, RuntimeDebug)]
+    pub struct Delegation<AccountId, Balance> {
+        pub target: AccountId,
+        pub conviction: Conviction,
+        pub balance: Balance,
+    }
+
+    /// Running totals delegated to one account on one track
+    #[derive(Encode, Decode, TypeInfo, MaxEncodedLen, Clone, Eq, PartialEq, RuntimeDebug, Default)]
+    pub struct DelegatedTotals<Balance> {
+        /// Conviction-weighted votes
+        pub votes: u128,
+        /// Locked balance behind those votes
+        pub capital: Balance,
+        pub delegators: u32,
+    }
+
+    /// Lock kept after undelegation
+    #[derive(Encode, Decode, TypeInfo, MaxEncodedLen, Clone, Eq, PartialEq, RuntimeDebug)]
+    pub struct PriorLock<BlockNumber, Balance> {
+        pub until: BlockNumber,
+        pub amount: Balance,
+    }
+
+    #[pallet::pallet]
+    pub struct Pallet<T>(_);
+
+    #[pallet::config]
+    pub trait Config: frame_system::Config {
+        type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+        /// Currency whose balances are locked while delegated
+        type Currency: LockableCurrency<Self::AccountId, Moment = BlockNumberFor<Self>>;
+
+        /// Length of one conviction lock period
+        #[pallet::constant]
+        type VoteLockingPeriod: Get<BlockNumberFor<Self>>;
+
+        /// Number of tracks; valid track IDs are `0..Tracks`
+        #[pallet::constant]
+        type Tracks: Get<TrackId>;
+
+        /// Voting pallets that tally delegated votes
+        type Hooks: DelegationHooks<Self::AccountId>;
+
+        /// Weight information for extrinsics
+        type WeightInfo: WeightInfo;
+    }
+
+    #[pallet::storage]
+    #[pallet::getter(fn delegations)]
+    pub type Delegations<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat, T::AccountId,
+        Twox64Concat, TrackId,
+        Delegation<T::AccountId, BalanceOf<T>>,
+        OptionQuery,
+    >;
+
+    #[pallet::storage]
+    #[pallet::getter(fn delegated_totals)]
+    pub type DelegatedVotes<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat, T::AccountId,
+        Twox64Concat, TrackId,
+        DelegatedTotals<BalanceOf<T>>,
+        ValueQuery,
+    >;
+
+    #[pallet::storage]
+    #[pallet::getter(fn prior_locks)]
+    pub type PriorLocks<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat, T::AccountId,
+        Twox64Concat, TrackId,
+        PriorLock<BlockNumberFor<T>, BalanceOf<T>>,
+        OptionQuery,
+    >;
+
+    #[pallet::event]
+    #[pallet::generate_deposit(pub(super) fn deposit_event)]
+    pub enum Event<T: Config> {
+        Delegated {
+            who: T::AccountId,
+            target: T::AccountId,
+            track: TrackId,
+            conviction: Conviction,
+            balance: BalanceOf<T>,
+        },
+        Undelegated {
+            who: T::AccountId,
+            target: T::AccountId,
+            track: TrackId,
+            lock_until: Option<BlockNumberFor<T>>,
+        },
+        Unlocked { who: T::AccountId, track: TrackId },
+    }
+
+    #[pallet::error]
+    pub enum Error<T> {
+        InvalidTrack,
+        SelfDelegation,
+        AlreadyDelegating,
+        NotDelegating,
+        ZeroBalance,
+        InsufficientBalance,
+        /// Account has votes on open proposals of this track
+        ActiveVotes,
+        NoPriorLock,
+        LockNotExpired,
+    }
+
+    #[pallet::call]
+    impl<T: Config> Pallet<T> {
+        /// Delegate `balance` on `track` to `to` with `conviction`
+        ///
+        /// The balance is locked until undelegation plus the conviction period.
+        #[pallet::weight(T::WeightInfo::delegate(Pallet::<T>::max_commitments()))]
+        #[pallet::call_index(0)]
+        pub fn delegate(
+            origin: OriginFor<T>,
+            track: TrackId,
+            to: T::AccountId,
+            conviction: Conviction,
+            balance: BalanceOf<T>,
+        ) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+            ensure!(track < T::Tracks::get(), Error::<T>::InvalidTrack);
+            ensure!(who != to, Error::<T>::SelfDelegation);
+            ensure!(!balance.is_zero(), Error::<T>::ZeroBalance);
+            ensure!(
+                !Delegations::<T>::contains_key(&who, track),
+                Error::<T>::AlreadyDelegating
+            );
+            ensure!(
+                T::Currency::free_balance(&who) >= balance,
+                Error::<T>::InsufficientBalance
+            );
+            ensure!(!T::Hooks::has_active_votes(&who, track), Error::<T>::ActiveVotes);
+
+            Self::adjust_totals(&to, track, |totals| {
+                totals.votes = totals.votes.saturating_add(Self::votes_of(conviction, balance));
+                totals.capital = totals.capital.saturating_add(balance);
+                totals.delegators = totals.delegators.saturating_add(1);
+            });
+
+            Delegations::<T>::insert(
+                &who,
+                track,
+                Delegation { target: to.clone(), conviction, balance },
+            );
+            let walked = Self::update_lock(&who);
+
+            Self::deposit_event(Event::Delegated { who, target: to, track, conviction, balance });
+            Ok(Some(T::WeightInfo::delegate(walked)).into())
+        }
+
+        /// Remove the delegation on `track`
+        ///
+        /// The balance stays locked for the conviction's lock periods.
+        #[pallet::weight(T::WeightInfo::undelegate(Pallet::<T>::max_commitments()))]
+        #[pallet::call_index(1)]
+        pub fn undelegate(origin: OriginFor<T>, track: TrackId) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+            let delegation =
+                Delegations::<T>::take(&who, track).ok_or(Error::<T>::NotDelegating)?;
+
+            Self::adjust_totals(&delegation.target, track, |totals| {
+                totals.votes = totals
+                    .votes
+                    .saturating_sub(Self::votes_of(delegation.conviction, delegation.balance));
+                totals.capital = totals.capital.saturating_sub(delegation.balance);
+                totals.delegators = totals.delegators.saturating_sub(1);
+            });
+
+            let periods = delegation.conviction.lock_periods();
+            let lock_until = if periods > 0 {
+                let until = frame_system::Pallet::<T>::block_number()
+                    .saturating_add(T::VoteLockingPeriod::get().saturating_mul(periods.into()));
+                PriorLocks::<T>::mutate(&who, track, |prior| {
+                    let merged = match prior.take() {
+                        Some(p) => PriorLock {
+                            until: p.until.max(until),
+                            amount: p.amount.max(delegation.balance),
+                        },
+                        None => PriorLock { until, amount: delegation.balance },
+                    };
+                    *prior = Some(merged);
+                });
+                Some(until)
+            } else {
+                None
+            };
+            let walked = Self::update_lock(&who);
+
+            Self::deposit_event(Event::Undelegated {
+                who,
+                target: delegation.target,
+                track,
+                lock_until,
+            });
+            Ok(Some(T::WeightInfo::undelegate(walked)).into())
+        }
+
+        /// Release `target`'s expired carried-over lock on `track` (callable by anyone)
+        #[pallet::weight(T::WeightInfo::unlock(Pallet::<T>::max_commitments()))]
+        #[pallet::call_index(2)]
+        pub fn unlock(
+            origin: OriginFor<T>,
+            track: TrackId,
+            target: T::AccountId,
+        ) -> DispatchResultWithPostInfo {
+            ensure_signed(origin)?;
+            let prior = PriorLocks::<T>::get(&target, track).ok_or(Error::<T>::NoPriorLock)?;
+            ensure!(
+                prior.until <= frame_system::Pallet::<T>::block_number(),
+                Error::<T>::LockNotExpired
+            );
+
+            PriorLocks::<T>::remove(&target, track);
+            let walked = Self::update_lock(&target);
+
+            Self::deposit_event(Event::Unlocked { who: target, track });
+            Ok(Some(T::WeightInfo::unlock(walked)).into())
+        }
+    }
+
+    impl<T: Config> Pallet<T> {
+        /// Most delegations and carried-over locks one account can hold: one of each per track
+        pub fn max_commitments() -> u32 {
+            u32::from(T::Tracks::get()).saturating_mul(2)
+        }
+
+        fn votes_of(conviction: Conviction, balance: BalanceOf<T>) -> u128 {
+            conviction.votes(balance.unique_saturated_into())
+        }
+
+        /// Apply `f` to a delegate's totals and notify the voting pallets
+        ///
+        /// The weight the voting pallets spend re-tallying is registered with the block.
+        fn adjust_totals(
+            delegate: &T::AccountId,
+            track: TrackId,
+            f: impl FnOnce(&mut DelegatedTotals<BalanceOf<T>>),
+        ) {
+            let mut totals = DelegatedVotes::<T>::get(delegate, track);
+            let old = totals.votes;
+            f(&mut totals);
+            let new = totals.votes;
+
+            if totals.delegators == 0 {
+                DelegatedVotes::<T>::remove(delegate, track);
+            } else {
+                DelegatedVotes::<T>::insert(delegate, track, totals);
+            }
+            if old != new {
+                let weight = T::Hooks::on_delegated_votes_changed(delegate, track, old, new);
+                frame_system::Pallet::<T>::register_extra_weight_unchecked(
+                    weight,
+                    DispatchClass::Normal,
+                );
+            }
+        }
+
+        /// Lock the largest balance held by any delegation or carried-over lock
+        ///
+        /// Returns the number of delegations and carried-over locks walked.
+        fn update_lock(who: &T::AccountId) -> u32 {
+            let mut walked = 0u32;
+            let delegated = Delegations::<T>::iter_prefix_values(who).map(|d| d.balance);
+            let prior = PriorLocks::<T>::iter_prefix_values(who).map(|p| p.amount);
+            let amount = delegated
+                .chain(prior)
+                .inspect(|_| walked += 1)
+                .max()
+                .unwrap_or_else(Zero::zero);
+
+            if amount.is_zero() {
+                T::Currency::remove_lock(DELEGATION_LOCK_ID, who);
+            } else {
+                T::Currency::set_lock(DELEGATION_LOCK_ID, who, amount, WithdrawReasons::all());
+            }
+            walked
+        }
+    }
+
+    impl<T: Config> DelegationInfo<T::AccountId> for Pallet<T> {
+        fn delegated_votes(who: &T::AccountId, track: TrackId) -> u128 {
+            DelegatedVotes::<T>::get(who, track).votes
+        }
+
+        fn is_delegating(who: &T::AccountId, track: TrackId) -> bool {
+            Delegations::<T>::contains_key(who, track)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use frame_support::{
+        assert_noop, assert_ok, derive_impl,
+        dispatch::GetDispatchInfo,
+        parameter_types,
+        traits::{ConstU32, ConstU64, ConstU8},
+    };
+    use sp_runtime::{traits::IdentityLookup, BuildStorage};
+    use std::cell::RefCell;
+
+    type Block = frame_system::mocking::MockBlock<Test>;
+
+    frame_support::construct_runtime!(
+        pub enum Test {
+            System: frame_system,
+            Balances: pallet_balances,
+            Delegation: crate,
+        }
+    );
+
+    #[derive_impl(frame_system::config_preludes::TestDefaultConfig)]
+    impl frame_system::Config for Test {
+        type Block = Block;
+        type AccountId = u64;
+        type Lookup = IdentityLookup<Self::AccountId>;
+        type AccountData = pallet_balances::AccountData<u64>;
+    }
+
+    parameter_types! {
+        pub const ExistentialDeposit: u64 = 1;
+    }
+
+    impl pallet_balances::Config for Test {
+        type MaxLocks = ConstU32<50>;
+        type MaxReserves = ();
+        type ReserveIdentifier = [u8; 8];
+        type Balance = u64;
+        type RuntimeEvent = RuntimeEvent;
+        type DustRemoval = ();
+        type ExistentialDeposit = ExistentialDeposit;
+        type AccountStore = System;
+        type WeightInfo = ();
+        type FreezeIdentifier = ();
+        type MaxFreezes = ();
+        type RuntimeHoldReason = ();
+        type RuntimeFreezeReason = ();
+        type DoneSlashHandler = ();
+    }
+
+    thread_local! {
+        static CHANGES: RefCell<Vec<(u64, TrackId, u128, u128)>> = RefCell::new(Vec::new());
+        static VOTING: RefCell<Vec<u64>> = RefCell::new(Vec::new());
+    }
+
+    pub struct RecordingHooks;
+    impl DelegationHooks<u64> for RecordingHooks {
+        fn has_active_votes(who: &u64, _: TrackId) -> bool {
+            VOTING.with(|v| v.borrow().contains(who))
+        }
+
+        fn on_delegated_votes_changed(delegate: &u64, track: TrackId, old: u128, new: u128) -> Weight {
+            CHANGES.with(|c| c.borrow_mut().push((*delegate, track, old, new)));
+            Weight::zero()
+        }
+    }
+
+    impl Config for Test {
+        type RuntimeEvent = RuntimeEvent;
+        type Currency = Balances;
+        type VoteLockingPeriod = ConstU64<10>;
+        type Tracks = ConstU8<2>;
+        type Hooks = RecordingHooks;
+        type WeightInfo = ();
+    }
+
+    pub fn new_test_ext() -> sp_io::TestExternalities {
+        let mut t = frame_system::GenesisConfig::<Test>::default().build_storage().unwrap();
+        pallet_balances::GenesisConfig::<Test> {
+            balances: vec![(1, 1_000), (2, 1_000), (3, 1_000)],
+            dev_accounts: None,
+        }
+        .assimilate_storage(&mut t)
+        .unwrap();
+
+        let mut ext: sp_io::TestExternalities = t.into();
+        ext.execute_with(|| {
+            System::set_block_number(1);
+            CHANGES.with(|c| c.borrow_mut().clear());
+            VOTING.with(|v| v.borrow_mut().clear());
+        });
+        ext
+    }
+
+    fn locked(who: u64) -> u64 {
+        pallet_balances::Locks::<Test>::get(who)
+            .iter()
+            .find(|l| l.id == DELEGATION_LOCK_ID)
+            .map_or(0, |l| l.amount)
+    }
+
+    #[test]
+    fn conviction_scales_votes_and_lock() {
+        assert_eq!(Conviction::None.votes(100), 10);
+        assert_eq!(Conviction::Locked3x.votes(100), 300);
+        assert_eq!(Conviction::Locked6x.lock_periods(), 32);
+        assert!(Conviction::Locked1x < Conviction::Locked2x);
+    }
+
+    #[test]
+    fn delegate_updates_totals_and_locks() {
+        new_test_ext().execute_with(|| {
+            assert_ok!(Delegation::delegate(RuntimeOrigin::signed(1), 0, 3, Conviction::Locked2x, 100));
+            assert_ok!(Delegation::delegate(RuntimeOrigin::signed(2), 0, 3, Conviction::None, 500));
+
+            let totals = Delegation::delegated_totals(3, 0);
+            assert_eq!(totals.votes, 250);
+            assert_eq!(totals.capital, 600);
+            assert_eq!(totals.delegators, 2);
+            assert_eq!(<Delegation as DelegationInfo<u64>>::delegated_votes(&3, 1), 0);
+            assert!(<Delegation as DelegationInfo<u64>>::is_delegating(&1, 0));
+            assert_eq!(locked(1), 100);
+
+            CHANGES.with(|c| assert_eq!(*c.borrow(), vec![(3, 0, 0, 200), (3, 0, 200, 250)]));
+        });
+    }
+
+    #[test]
+    fn delegate_rejects_invalid_requests() {
+        new_test_ext().execute_with(|| {
+            assert_noop!(
+                Delegation::delegate(RuntimeOrigin::signed(1), 2, 3, Conviction::None, 10),
+                Error::<Test>::InvalidTrack
+            );
+            assert_noop!(
+                Delegation::delegate(RuntimeOrigin::signed(1), 0, 1, Conviction::None, 10),
+                Error::<Test>::SelfDelegation
+            );
+            assert_noop!(
+                Delegation::delegate(RuntimeOrigin::signed(1), 0, 3, Conviction::None, 2_000),
+                Error::<Test>::InsufficientBalance
+            );
+
+            VOTING.with(|v| v.borrow_mut().push(1));
+            assert_noop!(
+                Delegation::delegate(RuntimeOrigin::signed(1), 0, 3, Conviction::None, 10),
+                Error::<Test>::ActiveVotes
+            );
+            VOTING.with(|v| v.borrow_mut().clear());
+
+            assert_ok!(Delegation::delegate(RuntimeOrigin::signed(1), 0, 3, Conviction::None, 10));
+            assert_noop!(
+                Delegation::delegate(RuntimeOrigin::signed(1), 0, 2, Conviction::None, 10),
+                Error::<Test>::AlreadyDelegating
+            );
+        });
+    }
+
+    #[test]
+    fn undelegate_carries_lock_over_until_expiry() {
+        new_test_ext().execute_with(|| {
+            assert_ok!(Delegation::delegate(RuntimeOrigin::signed(1), 0, 3, Conviction::Locked2x, 100));
+            assert_ok!(Delegation::undelegate(RuntimeOrigin::signed(1), 0));
+
+            assert_eq!(Delegation::delegated_totals(3, 0), Default::default());
+            assert_eq!(Delegation::prior_locks(1, 0).unwrap().until, 21);
+            assert_eq!(locked(1), 100);
+
+            System::set_block_number(20);
+            assert_noop!(
+                Delegation::unlock(RuntimeOrigin::signed(2), 0, 1),
+                Error::<Test>::LockNotExpired
+            );
+
+            System::set_block_number(21);
+            assert_ok!(Delegation::unlock(RuntimeOrigin::signed(2), 0, 1));
+            assert_eq!(locked(1), 0);
+        });
+    }
+
+    #[test]
+    fn lock_covers_largest_commitment_across_tracks() {
+        new_test_ext().execute_with(|| {
+            assert_ok!(Delegation::delegate(RuntimeOrigin::signed(1), 0, 3, Conviction::Locked1x, 300));
+            assert_ok!(Delegation::delegate(RuntimeOrigin::signed(1), 1, 2, Conviction::None, 100));
+            assert_eq!(locked(1), 300);
+
+            // No-conviction undelegation releases immediately; the other track still locks
+            assert_ok!(Delegation::undelegate(RuntimeOrigin::signed(1), 1));
+            assert!(Delegation::prior_locks(1, 1).is_none());
+            assert_eq!(locked(1), 300);
+
+            assert_ok!(Delegation::undelegate(RuntimeOrigin::signed(1), 0));
+            assert_eq!(locked(1), 300);
+            assert_noop!(
+                Delegation::undelegate(RuntimeOrigin::signed(1), 0),
+                Error::<Test>::NotDelegating
+            );
+        });
+    }
+
+    #[test]
+    fn calls_are_weighed_by_commitments_walked() {
+        new_test_ext().execute_with(|| {
+            let call = Call::<Test>::delegate {
+                track: 0,
+                to: 3,
+                conviction: Conviction::Locked1x,
+                balance: 100,
+            };
+            assert_eq!(
+                call.get_dispatch_info().call_weight,
+                <() as WeightInfo>::delegate(Delegation::max_commitments())
+            );
+
+            assert_ok!(Delegation::delegate(RuntimeOrigin::signed(1), 1, 2, Conviction::Locked1x, 10));
+            let info =
+                Delegation::delegate(RuntimeOrigin::signed(1), 0, 3, Conviction::Locked1x, 100)
+                    .unwrap();
+            assert_eq!(info.actual_weight, Some(<() as WeightInfo>::delegate(2)));
+
+            // The carried-over lock on track 0 and the delegation on track 1
+            let info = Delegation::undelegate(RuntimeOrigin::signed(1), 0).unwrap();
+            assert_eq!(info.actual_weight, Some(<() as WeightInfo>::undelegate(2)));
+        });
+    }
+}
@@ -0,0 +1,111 @@
+//! PLACEHOLDER weights for `pallet_vote_delegation`: hand-estimated, not benchmark output.
+//!
+//! Overwrite with `./scripts/benchmark-pallets.sh pallet_vote_delegation` on reference hardware.
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(missing_docs)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use core::marker::PhantomData;
+
+/// Weight functions needed for `pallet_vote_delegation`.
+pub trait WeightInfo {
+	fn delegate(c: u32, ) -> Weight;
+	fn undelegate(c: u32, ) -> Weight;
+	fn unlock(c: u32, ) -> Weight;
+}
+
+/// Placeholder weights for `pallet_vote_delegation`, pending benchmarking.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	/// Storage: `VoteDelegation::Delegations` (r:1 w:1)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Storage: `VoteDelegation::DelegatedVotes` (r:1 w:1)
+	/// Storage: `Balances::Locks` (r:1 w:1)
+	/// Storage: `Balances::Freezes` (r:1 w:0)
+	/// The range of component `c` is `[1, 4]`.
+	fn delegate(c: u32, ) -> Weight {
+		Weight::from_parts(48_260_000, 4764)
+			.saturating_add(Weight::from_parts(3_140_000, 0).saturating_mul(c.into()))
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(c.into())))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+			.saturating_add(Weight::from_parts(0, 2531).saturating_mul(c.into()))
+	}
+	/// Storage: `VoteDelegation::Delegations` (r:1 w:1)
+	/// Storage: `VoteDelegation::DelegatedVotes` (r:1 w:1)
+	/// Storage: `VoteDelegation::PriorLocks` (r:1 w:1)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Storage: `Balances::Locks` (r:1 w:1)
+	/// Storage: `Balances::Freezes` (r:1 w:0)
+	/// The range of component `c` is `[1, 4]`.
+	fn undelegate(c: u32, ) -> Weight {
+		Weight::from_parts(51_870_000, 4764)
+			.saturating_add(Weight::from_parts(3_140_000, 0).saturating_mul(c.into()))
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(c.into())))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+			.saturating_add(Weight::from_parts(0, 2531).saturating_mul(c.into()))
+	}
+	/// Storage: `VoteDelegation::PriorLocks` (r:1 w:1)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Storage: `Balances::Locks` (r:1 w:1)
+	/// Storage: `Balances::Freezes` (r:1 w:0)
+	/// The range of component `c` is `[0, 4]`.
+	fn unlock(c: u32, ) -> Weight {
+		Weight::from_parts(36_420_000, 4764)
+			.saturating_add(Weight::from_parts(3_140_000, 0).saturating_mul(c.into()))
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(c.into())))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+			.saturating_add(Weight::from_parts(0, 2531).saturating_mul(c.into()))
+	}
+}
+
+// For backwards compatibility and tests.
+impl WeightInfo for () {
+	/// Storage: `VoteDelegation::Delegations` (r:1 w:1)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Storage: `VoteDelegation::DelegatedVotes` (r:1 w:1)
+	/// Storage: `Balances::Locks` (r:1 w:1)
+	/// Storage: `Balances::Freezes` (r:1 w:0)
+	/// The range of component `c` is `[1, 4]`.
+	fn delegate(c: u32, ) -> Weight {
+		Weight::from_parts(48_260_000, 4764)
+			.saturating_add(Weight::from_parts(3_140_000, 0).saturating_mul(c.into()))
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(c.into())))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+			.saturating_add(Weight::from_parts(0, 2531).saturating_mul(c.into()))
+	}
+	/// Storage: `VoteDelegation::Delegations` (r:1 w:1)
+	/// Storage: `VoteDelegation::DelegatedVotes` (r:1 w:1)
+	/// Storage: `VoteDelegation::PriorLocks` (r:1 w:1)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Storage: `Balances::Locks` (r:1 w:1)
+	/// Storage: `Balances::Freezes` (r:1 w:0)
+	/// The range of component `c` is `[1, 4]`.
+	fn undelegate(c: u32, ) -> Weight {
+		Weight::from_parts(51_870_000, 4764)
+			.saturating_add(Weight::from_parts(3_140_000, 0).saturating_mul(c.into()))
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(c.into())))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+			.saturating_add(Weight::from_parts(0, 2531).saturating_mul(c.into()))
+	}
+	/// Storage: `VoteDelegation::PriorLocks` (r:1 w:1)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Storage: `Balances::Locks` (r:1 w:1)
+	/// Storage: `Balances::Freezes` (r:1 w:0)
+	/// The range of component `c` is `[0, 4]`.
+	fn unlock(c: u32, ) -> Weight {
+		Weight::from_parts(36_420_000, 4764)
+			.saturating_add(Weight::from_parts(3_140_000, 0).saturating_mul(c.into()))
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(c.into())))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+			.saturating_add(Weight::from_parts(0, 2531).saturating_mul(c.into()))
+	}
+}
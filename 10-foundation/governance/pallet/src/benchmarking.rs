@@ -0,0 +1,423 @@
+//! Benchmarking setup for pallet-governance
+//!
+//! Finalizing benchmarks run with `MaxVotesPerProposal` voters to unreserve and
+//! preimage benchmarks with calls up to `MaxPreimageSize` bytes, since both are
+//! read in full by the benchmarked call.
+
+#![cfg(feature = "runtime-benchmarks")]
+
+use super::*;
+use crate::pallet::AGENDA_SEARCH_LIMIT;
+use codec::Encode;
+use frame_benchmarking::v2::*;
+use frame_support::{
+    assert_ok,
+    traits::{Currency, EnsureOrigin, Get, Hooks, ReservableCurrency, Time},
+    BoundedVec,
+};
+use frame_system::{pallet_prelude::BlockNumberFor, RawOrigin};
+use pallet_vote_delegation::{DelegationHooks, GOVERNANCE_TRACK};
+use sp_runtime::traits::{Hash, Saturating};
+use sp_std::{vec, vec::Vec};
+
+const SEED: u32 = 0;
+
+/// Delegated-vote re-tallies benchmarked for one delegate
+const MAX_DELEGATE_VOTES: u32 = 100;
+
+/// Account holding enough for a proposal stake, votes and the largest preimage deposit
+fn funded<T: Config>(name: &'static str, index: u32) -> T::AccountId {
+    let who: T::AccountId = account(name, index, SEED);
+    let amount = T::PreimageByteDeposit::get()
+        .saturating_mul(T::MaxPreimageSize::get().into())
+        .saturating_add(T::PreimageBaseDeposit::get())
+        .saturating_add(T::MinProposalStake::get())
+        .saturating_mul(10u32.into());
+    T::Currency::make_free_balance_be(&who, amount);
+    who
+}
+
+fn vote_stake<T: Config>() -> BalanceOf<T> {
+    T::MinProposalStake::get().max(1u32.into())
+}
+
+/// Encoded `remark` call of at most `len` bytes
+fn remark_call<T: Config>(len: u32) -> Vec<u8> {
+    // Call indices and the compact length take at most 7 bytes
+    let remark = vec![1u8; len.saturating_sub(8) as usize];
+    <T as Config>::RuntimeCall::from(frame_system::Call::<T>::remark { remark }).encode()
+}
+
+/// Note a preimage of about `len` bytes for `depositor`, referenced by `references` proposals
+fn add_preimage<T: Config>(depositor: &T::AccountId, len: u32, references: u32) -> T::Hash {
+    let call: BoundedVec<u8, T::MaxPreimageSize> =
+        remark_call::<T>(len).try_into().expect("remark is shorter than len; qed");
+    let hash = T::Hashing::hash(&call[..]);
+    let deposit = T::PreimageBaseDeposit::get();
+    assert_ok!(T::Currency::reserve(depositor, deposit));
+    Preimages::<T>::insert(hash, PreimageInfo { depositor: depositor.clone(), deposit, call, references });
+    hash
+}
+
+/// Insert an active proposal whose voting ends at `voting_ends`
+fn add_proposal<T: Config>(proposer: &T::AccountId, voting_ends: MomentOf<T>) -> ProposalId {
+    let id = NextProposalId::<T>::get();
+    NextProposalId::<T>::put(id + 1);
+    Proposals::<T>::insert(id, Proposal::<T> {
+        id,
+        title: vec![b't'; 256].try_into().expect("256 bytes; qed"),
+        description: vec![b'd'; 1024].try_into().expect("1024 bytes; qed"),
+        proposer: proposer.clone(),
+        created_at: T::Time::now(),
+        voting_ends,
+        votes_for: 0u32.into(),
+        votes_against: 0u32.into(),
+        status: ProposalStatus::Active,
+    });
+    id
+}
+
+/// Attach a call preimage of about `len` bytes to a proposal
+fn add_call<T: Config>(proposal_id: ProposalId, depositor: &T::AccountId, len: u32) -> T::Hash {
+    let hash = add_preimage::<T>(depositor, len, 1);
+    ProposalCalls::<T>::insert(proposal_id, hash);
+    hash
+}
+
+/// Cast `v` reserved votes, each with a delegated-vote record
+fn add_votes<T: Config>(proposal_id: ProposalId, v: u32, support: bool) {
+    let stake = vote_stake::<T>();
+    for i in 0..v {
+        let voter = funded::<T>("voter", i);
+        assert_ok!(T::Currency::reserve(&voter, stake));
+        Votes::<T>::insert(proposal_id, &voter, VoteInfo { vote: support, stake });
+        DelegateVotes::<T>::insert(&voter, proposal_id, (support, stake));
+    }
+    Proposals::<T>::mutate(proposal_id, |maybe_p| {
+        if let Some(p) = maybe_p {
+            let total = stake.saturating_mul(v.into());
+            if support {
+                p.votes_for = total;
+            } else {
+                p.votes_against = total;
+            }
+        }
+    });
+    VoterCount::<T>::insert(proposal_id, v);
+}
+
+/// Fill every agenda slot of `blocks` blocks starting at `from`
+fn fill_agenda<T: Config>(from: BlockNumberFor<T>, blocks: u32) {
+    let full: BoundedVec<ProposalId, T::MaxScheduledPerBlock> =
+        vec![ProposalId::MAX; T::MaxScheduledPerBlock::get() as usize]
+            .try_into()
+            .expect("MaxScheduledPerBlock entries; qed");
+    for offset in 0..blocks {
+        Agenda::<T>::insert(from.saturating_add(offset.into()), full.clone());
+    }
+}
+
+fn first_enactment_block<T: Config>() -> BlockNumberFor<T> {
+    frame_system::Pallet::<T>::block_number()
+        .saturating_add(T::EnactmentDelay::get())
+        .saturating_add(1u32.into())
+}
+
+fn start_consensus_day<T: Config>() {
+    IsConsensusDayActive::<T>::put(true);
+    ConsensusDaySchedule::<T>::put(ConsensusDayConfig::<T> {
+        frequency: 100u32.into(),
+        duration: 10u32.into(),
+        next_start: frame_system::Pallet::<T>::block_number(),
+        active: true,
+    });
+}
+
+fn add_consensus_day_proposal<T: Config>(proposer: &T::AccountId, ends_at: BlockNumberFor<T>) -> ProposalId {
+    let proposal_id = NextProposalId::<T>::get();
+    NextProposalId::<T>::put(proposal_id + 1);
+    ConsensusDayProposals::<T>::insert(proposal_id, ConsensusDayProposal::<T> {
+        proposal_id,
+        proposer: proposer.clone(),
+        title: vec![b't'; 256].try_into().expect("256 bytes; qed"),
+        description: vec![b'd'; 4096].try_into().expect("4096 bytes; qed"),
+        yes_votes: 0,
+        no_votes: 0,
+        total_stake_voted: 0,
+        created_at: frame_system::Pallet::<T>::block_number(),
+        ends_at,
+        supermajority_threshold: 60,
+        min_participation: 20,
+        executed: false,
+    });
+    proposal_id
+}
+
+#[benchmarks]
+mod benchmarks {
+    use super::*;
+
+    #[benchmark]
+    fn create_proposal() {
+        let caller = funded::<T>("caller", 0);
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller.clone()), vec![b't'; 256], vec![b'd'; 1024]);
+
+        assert_eq!(Proposals::<T>::get(0).unwrap().proposer, caller);
+    }
+
+    #[benchmark]
+    fn vote() {
+        let proposer = funded::<T>("proposer", 0);
+        let id = add_proposal::<T>(&proposer, T::Time::now().saturating_add(T::ProposalDuration::get()));
+        // Worst case: the last voter the proposal accepts
+        add_votes::<T>(id, T::MaxVotesPerProposal::get().saturating_sub(1), true);
+        let caller = funded::<T>("caller", 0);
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller.clone()), id, true, vote_stake::<T>());
+
+        assert!(Votes::<T>::contains_key(id, &caller));
+    }
+
+    #[benchmark]
+    fn execute_proposal(
+        v: Linear<0, { T::MaxVotesPerProposal::get() }>,
+        s: Linear<8, { T::MaxPreimageSize::get() }>,
+    ) {
+        let caller: T::AccountId = whitelisted_caller();
+        let proposer = funded::<T>("proposer", 0);
+
+        // Rejected proposal releasing its call preimage
+        let id = add_proposal::<T>(&proposer, T::Time::now());
+        add_call::<T>(id, &proposer, s);
+        add_votes::<T>(id, v, false);
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller), id);
+
+        assert_eq!(Proposals::<T>::get(id).unwrap().status, ProposalStatus::Rejected);
+        assert_eq!(Votes::<T>::iter_prefix(id).count(), 0);
+    }
+
+    #[benchmark]
+    fn execute_proposal_scheduling(v: Linear<1, { T::MaxVotesPerProposal::get() }>) {
+        let caller: T::AccountId = whitelisted_caller();
+        let proposer = funded::<T>("proposer", 0);
+
+        // Passed proposal that only finds agenda space in the last block searched
+        let id = add_proposal::<T>(&proposer, T::Time::now());
+        add_call::<T>(id, &proposer, 8);
+        add_votes::<T>(id, v, true);
+        fill_agenda::<T>(first_enactment_block::<T>(), AGENDA_SEARCH_LIMIT - 1);
+
+        #[extrinsic_call]
+        execute_proposal(RawOrigin::Signed(caller), id);
+
+        assert!(ScheduledEnactments::<T>::contains_key(id));
+    }
+
+    #[benchmark]
+    fn cancel_proposal(
+        v: Linear<0, { T::MaxVotesPerProposal::get() }>,
+        s: Linear<8, { T::MaxPreimageSize::get() }>,
+    ) {
+        let proposer = funded::<T>("proposer", 0);
+        let id = add_proposal::<T>(&proposer, T::Time::now().saturating_add(T::ProposalDuration::get()));
+        add_call::<T>(id, &proposer, s);
+        add_votes::<T>(id, v, true);
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(proposer), id);
+
+        assert_eq!(Proposals::<T>::get(id).unwrap().status, ProposalStatus::Cancelled);
+    }
+
+    #[benchmark]
+    fn initialize_consensus_day() -> Result<(), BenchmarkError> {
+        let origin =
+            T::GovernanceOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+
+        #[extrinsic_call]
+        _(origin as T::RuntimeOrigin, 100u32.into(), 10u32.into());
+
+        assert!(ConsensusDaySchedule::<T>::get().is_some());
+        Ok(())
+    }
+
+    #[benchmark]
+    fn create_consensus_day_proposal() {
+        let caller = funded::<T>("caller", 0);
+        start_consensus_day::<T>();
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller), vec![b't'; 256], vec![b'd'; 4096], 60, 20);
+
+        assert!(ConsensusDayProposals::<T>::contains_key(0));
+    }
+
+    #[benchmark]
+    fn vote_consensus_day_proposal() {
+        let proposer = funded::<T>("proposer", 0);
+        start_consensus_day::<T>();
+        let id = add_consensus_day_proposal::<T>(&proposer, 10u32.into());
+        VoterCount::<T>::insert(id, T::MaxVotesPerProposal::get().saturating_sub(1));
+        let caller = funded::<T>("caller", 0);
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller.clone()), id, true, vote_stake::<T>());
+
+        assert!(ConsensusDayVotes::<T>::contains_key(id, &caller));
+    }
+
+    #[benchmark]
+    fn finalize_consensus_day_proposal(v: Linear<0, { T::MaxVotesPerProposal::get() }>) {
+        let caller: T::AccountId = whitelisted_caller();
+        let proposer = funded::<T>("proposer", 0);
+        let id = add_consensus_day_proposal::<T>(&proposer, frame_system::Pallet::<T>::block_number());
+
+        let stake = vote_stake::<T>();
+        for i in 0..v {
+            let voter = funded::<T>("voter", i);
+            assert_ok!(T::Currency::reserve(&voter, stake));
+            ConsensusDayVotes::<T>::insert(id, &voter, VoteInfo { vote: true, stake });
+        }
+        VoterCount::<T>::insert(id, v);
+        // Full participation, so the proposal passes
+        ConsensusDayProposals::<T>::mutate(id, |maybe_proposal| {
+            if let Some(proposal) = maybe_proposal {
+                proposal.yes_votes = 1_000_000;
+                proposal.total_stake_voted = 1_000_000;
+            }
+        });
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller), id);
+
+        assert!(ConsensusDayProposals::<T>::get(id).unwrap().executed);
+    }
+
+    #[benchmark]
+    fn note_preimage(s: Linear<8, { T::MaxPreimageSize::get() }>) {
+        let caller = funded::<T>("caller", 0);
+        let call = remark_call::<T>(s);
+        let hash = T::Hashing::hash(&call[..]);
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller), call);
+
+        assert!(Preimages::<T>::contains_key(hash));
+    }
+
+    #[benchmark]
+    fn unnote_preimage(s: Linear<8, { T::MaxPreimageSize::get() }>) {
+        let caller = funded::<T>("caller", 0);
+        let hash = add_preimage::<T>(&caller, s, 0);
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller), hash);
+
+        assert!(!Preimages::<T>::contains_key(hash));
+    }
+
+    #[benchmark]
+    fn create_proposal_with_call(s: Linear<8, { T::MaxPreimageSize::get() }>) {
+        let caller = funded::<T>("caller", 0);
+        let hash = add_preimage::<T>(&caller, s, 0);
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller), vec![b't'; 256], vec![b'd'; 1024], hash);
+
+        assert_eq!(Preimages::<T>::get(hash).unwrap().references, 1);
+    }
+
+    #[benchmark]
+    fn veto_proposal(s: Linear<8, { T::MaxPreimageSize::get() }>) -> Result<(), BenchmarkError> {
+        let origin = T::VetoOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+        let proposer = funded::<T>("proposer", 0);
+        let id = add_proposal::<T>(&proposer, T::Time::now());
+        add_call::<T>(id, &proposer, s);
+        Proposals::<T>::mutate(id, |maybe_p| {
+            if let Some(p) = maybe_p {
+                p.status = ProposalStatus::Passed;
+            }
+        });
+
+        // Scheduled last in a full agenda
+        let at = first_enactment_block::<T>();
+        fill_agenda::<T>(at, 1);
+        Agenda::<T>::mutate(at, |agenda| {
+            if let Some(last) = agenda.last_mut() {
+                *last = id;
+            }
+        });
+        ScheduledEnactments::<T>::insert(id, at);
+
+        #[extrinsic_call]
+        _(origin as T::RuntimeOrigin, id);
+
+        assert_eq!(Proposals::<T>::get(id).unwrap().status, ProposalStatus::Vetoed);
+        Ok(())
+    }
+
+    #[benchmark]
+    fn enact(s: Linear<8, { T::MaxPreimageSize::get() }>) {
+        // Excludes the dispatched call, whose own weight is added when it is enacted
+        let proposer = funded::<T>("proposer", 0);
+        let id = add_proposal::<T>(&proposer, T::Time::now());
+        add_call::<T>(id, &proposer, s);
+        ScheduledEnactments::<T>::insert(id, frame_system::Pallet::<T>::block_number());
+
+        #[block]
+        {
+            Pallet::<T>::enact(id);
+        }
+
+        assert_eq!(Proposals::<T>::get(id).unwrap().status, ProposalStatus::Enacted(true));
+    }
+
+    #[benchmark]
+    fn on_initialize() {
+        // Consensus Day starts and, with no duration, ends in the same block
+        let n = frame_system::Pallet::<T>::block_number();
+        ConsensusDaySchedule::<T>::put(ConsensusDayConfig::<T> {
+            frequency: 100u32.into(),
+            duration: 0u32.into(),
+            next_start: n,
+            active: false,
+        });
+
+        #[block]
+        {
+            Pallet::<T>::on_initialize(n);
+        }
+
+        assert!(!IsConsensusDayActive::<T>::get());
+    }
+
+    #[benchmark]
+    fn on_delegated_votes_changed(p: Linear<0, MAX_DELEGATE_VOTES>) {
+        let delegate: T::AccountId = account("delegate", 0, SEED);
+        let proposer = funded::<T>("proposer", 0);
+        let voting_ends = T::Time::now().saturating_add(T::ProposalDuration::get());
+        for _ in 0..p {
+            let id = add_proposal::<T>(&proposer, voting_ends);
+            DelegateVotes::<T>::insert(&delegate, id, (true, vote_stake::<T>()));
+        }
+
+        #[block]
+        {
+            <Pallet<T> as DelegationHooks<T::AccountId>>::on_delegated_votes_changed(
+                &delegate,
+                GOVERNANCE_TRACK,
+                0,
+                1_000,
+            );
+        }
+
+        if p > 0 {
+            assert_eq!(DelegateVotes::<T>::get(&delegate, 0).unwrap().1, 1_000u32.into());
+        }
+    }
+}
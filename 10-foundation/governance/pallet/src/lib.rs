@@ -16,6 +16,12 @@
 //! - Vote tallying with majority rule
 //! - Proposal cancellation by proposer
 //! - Transparent execution tracking
+//! - Executable proposals: a proposal may reference a noted `RuntimeCall` preimage
+//! - Deposit-backed preimages with a size limit
+//! - Passed calls are dispatched as Root after an enactment delay
+//! - Veto window: `VetoOrigin` can stop a passed call before it is enacted
+//! - Delegated votes: votes delegated through `pallet-vote-delegation` on the
+//!   governance track are added to the delegate's vote and re-tallied on change
 //!
 //! ## Extrinsics
 //!
@@ -23,6 +29,10 @@
 //! - `vote` - Vote on an active proposal with staked tokens
 //! - `execute_proposal` - Finalize and execute a proposal after voting period
 //! - `cancel_proposal` - Cancel an active proposal (proposer only)
+//! - `note_preimage` - Store an encoded `RuntimeCall`, reserving a size-based deposit
+//! - `unnote_preimage` - Remove an unreferenced preimage and return its deposit
+//! - `create_proposal_with_call` - Create a proposal that dispatches a noted call if passed
+//! - `veto_proposal` - Stop a passed call during its enactment delay (`VetoOrigin`)
 //!
 //! ## Usage Example
 //!
@@ -56,6 +66,12 @@
 //! - `LastConsensusDay` - Timestamp of last consensus day
 //! - `Proposals` - Maps proposal ID to proposal details
 //! - `Votes` - Maps (proposal_id, voter) to vote info (support, stake)
+//! - `Preimages` - Maps call hash to the encoded call, its depositor and deposit
+//! - `ProposalCalls` - Maps proposal ID to the call hash it dispatches
+//! - `Agenda` - Maps block number to proposals enacted at that block
+//! - `ScheduledEnactments` - Maps proposal ID to its enactment block
+//! - `DelegateVotes` - Maps (voter, proposal_id) to the delegated votes counted for the voter
+//! - `VoterCount` - Maps proposal ID to the number of accounts that voted on it
 //!
 //! ## Events
 //!
@@ -65,6 +81,11 @@
 //! - `ProposalRejected` - When a proposal is rejected
 //! - `ProposalCancelled` - When a proposal is cancelled by proposer
 //! - `VotesUnreserved` - When votes are unreserved after finalization
+//! - `PreimageNoted` / `PreimageCleared` - When a preimage is stored / removed
+//! - `ProposalScheduled` - When a passed proposal's call is scheduled for enactment
+//! - `ProposalEnacted` - When a scheduled call is dispatched (with its result)
+//! - `ProposalVetoed` - When a scheduled call is vetoed
+//! - `DelegatedTallyUpdated` - When a delegate's counted delegated votes change
 //!
 //! ## Errors
 //!
@@ -73,6 +94,13 @@
 //! - `AlreadyFinalized` - Proposal already finalized
 //! - `NotProposer` - Caller is not the proposal creator
 //! - `InsufficientStake` - Insufficient tokens for operation
+//! - `PreimageTooLarge` / `PreimageExists` / `PreimageMissing` - Preimage noting errors
+//! - `UndecodableCall` - Preimage is not a valid `RuntimeCall`
+//! - `PreimageInUse` - Preimage is referenced by an unfinished proposal
+//! - `NotScheduled` - Proposal has no pending enactment to veto
+//! - `AgendaFull` - No room to schedule enactment
+//! - `DelegatingVotes` - Voter has delegated its governance vote
+//! - `TooManyVotes` - Proposal already has `MaxVotesPerProposal` voters
 //!
 //! ## Vote Reservation
 //!
@@ -81,20 +109,31 @@
 //! - Proposal is cancelled by proposer
 //!
 //! This ensures economic commitment during voting while freeing funds after resolution.
+//! Unreservation happens in the finalizing call, so each proposal accepts at most
+//! `MaxVotesPerProposal` voters to keep that call within a block.
 #![cfg_attr(not(feature = "std"), no_std)]
 
 pub use pallet::*;
 
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
+pub mod weights;
+pub use weights::WeightInfo;
+
 #[frame_support::pallet]
 pub mod pallet {
     use frame_support::{
+        dispatch::{GetDispatchInfo, PostDispatchInfo},
         pallet_prelude::*,
         traits::{Currency, ReservableCurrency, Time},
         BoundedVec,
     };
     use frame_system::pallet_prelude::*;
     use sp_std::vec::Vec;
-    use sp_runtime::traits::Zero;
+    use sp_runtime::traits::{Dispatchable, Hash, Saturating, SaturatedConversion, Zero};
+    use pallet_vote_delegation::{DelegationHooks, DelegationInfo, TrackId, GOVERNANCE_TRACK};
+    use crate::WeightInfo;
 
     pub type ProposalId = u32;
     pub type MomentOf<T> = <<T as Config>::Time as Time>::Moment;
@@ -105,6 +144,22 @@ pub mod pallet {
         Passed,
         Rejected,
         Cancelled,
+        /// Passed call dispatched (`true` if the dispatch succeeded)
+        Enacted(bool),
+        /// Passed call stopped by `VetoOrigin` during the enactment delay
+        Vetoed,
+    }
+
+    /// A noted call preimage
+    #[derive(Encode, Decode, TypeInfo, MaxEncodedLen, Clone, Eq, PartialEq, RuntimeDebug)]
+    #[scale_info(skip_type_params(T))]
+    pub struct PreimageInfo<T: Config> {
+        pub depositor: T::AccountId,
+        pub deposit: BalanceOf<T>,
+        /// SCALE-encoded `RuntimeCall`
+        pub call: BoundedVec<u8, T::MaxPreimageSize>,
+        /// Unfinished proposals referencing this preimage
+        pub references: u32,
     }
 
     #[derive(Encode, Decode, TypeInfo, MaxEncodedLen, Clone, Eq, PartialEq, RuntimeDebug)]
@@ -161,8 +216,50 @@ pub mod pallet {
         type ProposalDuration: Get<MomentOf<Self>>;
         type MinProposalStake: Get<BalanceOf<Self>>;
         type GovernanceOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+        /// Call type dispatched by enacted proposals
+        type RuntimeCall: Parameter
+            + Dispatchable<RuntimeOrigin = Self::RuntimeOrigin, PostInfo = PostDispatchInfo>
+            + GetDispatchInfo
+            + From<frame_system::Call<Self>>;
+
+        /// Maximum encoded size of a call preimage
+        #[pallet::constant]
+        type MaxPreimageSize: Get<u32>;
+
+        /// Deposit reserved for any preimage
+        #[pallet::constant]
+        type PreimageBaseDeposit: Get<BalanceOf<Self>>;
+
+        /// Additional deposit per preimage byte
+        #[pallet::constant]
+        type PreimageByteDeposit: Get<BalanceOf<Self>>;
+
+        /// Blocks between a proposal passing and its call being dispatched
+        #[pallet::constant]
+        type EnactmentDelay: Get<BlockNumberFor<Self>>;
+
+        /// Maximum enactments in one block
+        #[pallet::constant]
+        type MaxScheduledPerBlock: Get<u32>;
+
+        /// Origin that can veto a passed call before enactment
+        type VetoOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+        /// Source of votes delegated on `GOVERNANCE_TRACK`
+        type Delegation: DelegationInfo<Self::AccountId>;
+
+        /// Maximum accounts voting on one proposal, bounding the unreservation on finalization
+        #[pallet::constant]
+        type MaxVotesPerProposal: Get<u32>;
+
+        /// Weight information for extrinsics and hooks
+        type WeightInfo: WeightInfo;
     }
 
+    /// Blocks searched past the enactment delay for free agenda space
+    pub(crate) const AGENDA_SEARCH_LIMIT: u32 = 16;
+
     pub type BalanceOf<T> = <<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
 
     #[pallet::type_value]
@@ -195,6 +292,44 @@ pub mod pallet {
         OptionQuery,
     >;
 
+    #[pallet::storage]
+    #[pallet::getter(fn preimages)]
+    pub type Preimages<T: Config> = StorageMap<_, Identity, T::Hash, PreimageInfo<T>>;
+
+    #[pallet::storage]
+    #[pallet::getter(fn proposal_calls)]
+    pub type ProposalCalls<T: Config> = StorageMap<_, Blake2_128Concat, ProposalId, T::Hash>;
+
+    #[pallet::storage]
+    #[pallet::getter(fn agenda)]
+    pub type Agenda<T: Config> = StorageMap<
+        _,
+        Twox64Concat,
+        BlockNumberFor<T>,
+        BoundedVec<ProposalId, T::MaxScheduledPerBlock>,
+        ValueQuery,
+    >;
+
+    #[pallet::storage]
+    #[pallet::getter(fn delegate_votes)]
+    pub type DelegateVotes<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat, T::AccountId,
+        Blake2_128Concat, ProposalId,
+        (bool, BalanceOf<T>),
+        OptionQuery,
+    >;
+
+    #[pallet::storage]
+    #[pallet::getter(fn scheduled_enactments)]
+    pub type ScheduledEnactments<T: Config> =
+        StorageMap<_, Blake2_128Concat, ProposalId, BlockNumberFor<T>>;
+
+    /// Accounts that voted on a proposal, shared by regular and Consensus Day proposals
+    #[pallet::storage]
+    #[pallet::getter(fn voter_count)]
+    pub type VoterCount<T: Config> = StorageMap<_, Blake2_128Concat, ProposalId, u32, ValueQuery>;
+
     #[pallet::storage]
     #[pallet::getter(fn consensus_day_schedule)]
     pub type ConsensusDaySchedule<T: Config> = StorageValue<_, ConsensusDayConfig<T>, OptionQuery>;
@@ -239,6 +374,12 @@ pub mod pallet {
         ConsensusDayVoteCast { proposal_id: ProposalId, voter: T::AccountId, vote: bool, stake: BalanceOf<T> },
         ConsensusDayProposalPassed { proposal_id: ProposalId, yes_pct: u8 },
         ConsensusDayProposalRejected { proposal_id: ProposalId, yes_pct: u8 },
+        PreimageNoted { hash: T::Hash, depositor: T::AccountId, deposit: BalanceOf<T> },
+        PreimageCleared { hash: T::Hash },
+        ProposalScheduled { proposal_id: ProposalId, at: BlockNumberFor<T> },
+        ProposalEnacted { proposal_id: ProposalId, result: DispatchResult },
+        ProposalVetoed { proposal_id: ProposalId },
+        DelegatedTallyUpdated { proposal_id: ProposalId, delegate: T::AccountId, votes: BalanceOf<T> },
     }
 
     #[pallet::error]
@@ -257,21 +398,44 @@ pub mod pallet {
         VotingNotEnded,
         AlreadyExecuted,
         InsufficientParticipation,
+        PreimageTooLarge,
+        PreimageExists,
+        PreimageMissing,
+        UndecodableCall,
+        PreimageInUse,
+        NotScheduled,
+        AgendaFull,
+        DelegatingVotes,
+        TooManyVotes,
     }
 
     #[pallet::pallet]
     pub struct Pallet<T>(_);
 
     impl<T: Config> Pallet<T> {
+        /// Count a voter against the proposal's voter limit unless it already voted
+        fn note_voter(proposal_id: ProposalId, already_voted: bool) -> DispatchResult {
+            if already_voted {
+                return Ok(());
+            }
+            VoterCount::<T>::try_mutate(proposal_id, |count| {
+                ensure!(*count < T::MaxVotesPerProposal::get(), Error::<T>::TooManyVotes);
+                *count += 1;
+                Ok(())
+            })
+        }
+
         /// Unreserve all votes for a finalized proposal
         fn unreserve_votes(proposal_id: ProposalId) -> u32 {
             let mut count = 0u32;
+            VoterCount::<T>::remove(proposal_id);
 
             // Iterate through all votes for this proposal
             let _ = Votes::<T>::drain_prefix(proposal_id)
                 .for_each(|(voter, vote_info)| {
                     // Unreserve the staked amount
                     T::Currency::unreserve(&voter, vote_info.stake);
+                    DelegateVotes::<T>::remove(&voter, proposal_id);
                     count += 1;
                 });
 
@@ -281,6 +445,7 @@ pub mod pallet {
         /// Unreserve all votes for a finalized Consensus Day proposal
         fn unreserve_consensus_day_votes(proposal_id: ProposalId) -> u32 {
             let mut count = 0u32;
+            VoterCount::<T>::remove(proposal_id);
 
             // Iterate through all votes for this proposal
             let _ = ConsensusDayVotes::<T>::drain_prefix(proposal_id)
@@ -293,6 +458,67 @@ pub mod pallet {
             count
         }
 
+        /// Drop a finished proposal's reference to its preimage, returning the preimage length
+        fn release_call(proposal_id: ProposalId) -> u32 {
+            let Some(hash) = ProposalCalls::<T>::take(proposal_id) else { return 0 };
+            Preimages::<T>::mutate(hash, |maybe_info| match maybe_info {
+                Some(info) => {
+                    info.references = info.references.saturating_sub(1);
+                    info.call.len() as u32
+                }
+                None => 0,
+            })
+        }
+
+        /// Schedule a passed proposal's call at the first free block after the delay
+        fn schedule_enactment(proposal_id: ProposalId) -> Result<BlockNumberFor<T>, DispatchError> {
+            let mut at = frame_system::Pallet::<T>::block_number()
+                .saturating_add(T::EnactmentDelay::get())
+                .saturating_add(1u32.into());
+
+            for _ in 0..AGENDA_SEARCH_LIMIT {
+                let scheduled = Agenda::<T>::try_mutate(at, |agenda| agenda.try_push(proposal_id));
+                if scheduled.is_ok() {
+                    ScheduledEnactments::<T>::insert(proposal_id, at);
+                    Self::deposit_event(Event::ProposalScheduled { proposal_id, at });
+                    return Ok(at);
+                }
+                at = at.saturating_add(1u32.into());
+            }
+
+            Err(Error::<T>::AgendaFull.into())
+        }
+
+        /// Dispatch one scheduled proposal as Root, returning the weight used
+        pub(crate) fn enact(proposal_id: ProposalId) -> Weight {
+            ScheduledEnactments::<T>::remove(proposal_id);
+
+            let preimage = ProposalCalls::<T>::get(proposal_id).and_then(Preimages::<T>::get);
+            let len = preimage.as_ref().map_or(0, |info| info.call.len() as u32);
+            let call = preimage
+                .and_then(|info| <T as Config>::RuntimeCall::decode(&mut &info.call[..]).ok());
+
+            let (result, weight) = match call {
+                Some(call) => {
+                    let info = call.get_dispatch_info();
+                    let outcome = call.dispatch(frame_system::RawOrigin::Root.into());
+                    let actual = frame_support::dispatch::extract_actual_weight(&outcome, &info);
+                    (outcome.map(|_| ()).map_err(|e| e.error), actual)
+                }
+                None => (Err(Error::<T>::PreimageMissing.into()), Weight::zero()),
+            };
+
+            Proposals::<T>::mutate(proposal_id, |maybe_p| {
+                if let Some(p) = maybe_p {
+                    p.status = ProposalStatus::Enacted(result.is_ok());
+                }
+            });
+            Self::release_call(proposal_id);
+            Self::deposit_event(Event::ProposalEnacted { proposal_id, result });
+
+            T::WeightInfo::enact(len).saturating_add(weight)
+        }
+
         /// Convert Balance to u128 for calculations
         fn balance_to_u128(balance: BalanceOf<T>) -> u128 {
             use sp_runtime::traits::SaturatedConversion;
@@ -309,7 +535,7 @@ pub mod pallet {
 
     #[pallet::call]
     impl<T: Config> Pallet<T> {
-        #[pallet::weight(10_000)]
+        #[pallet::weight(T::WeightInfo::create_proposal())]
         #[pallet::call_index(0)]
         pub fn create_proposal(
             origin: OriginFor<T>,
@@ -347,7 +573,7 @@ pub mod pallet {
             Ok(())
         }
 
-        #[pallet::weight(10_000)]
+        #[pallet::weight(T::WeightInfo::vote())]
         #[pallet::call_index(1)]
         pub fn vote(
             origin: OriginFor<T>,
@@ -356,14 +582,31 @@ pub mod pallet {
             amount: BalanceOf<T>,
         ) -> DispatchResult {
             let voter = ensure_signed(origin)?;
+            ensure!(
+                !T::Delegation::is_delegating(&voter, GOVERNANCE_TRACK),
+                Error::<T>::DelegatingVotes
+            );
             let now = T::Time::now();
             Proposals::<T>::try_mutate(proposal_id, |maybe_p| {
                 let p = maybe_p.as_mut().ok_or(Error::<T>::ProposalNotFound)?;
                 ensure!(p.status == ProposalStatus::Active, Error::<T>::AlreadyFinalized);
                 ensure!(now < p.voting_ends, Error::<T>::VotingClosed);
 
+                Self::note_voter(proposal_id, Votes::<T>::contains_key(proposal_id, &voter))?;
                 T::Currency::reserve(&voter, amount)?;
 
+                // Votes delegated to the voter follow its latest vote
+                if let Some((prev_support, prev_delegated)) = DelegateVotes::<T>::get(&voter, proposal_id) {
+                    if prev_support {
+                        p.votes_for = p.votes_for.saturating_sub(prev_delegated);
+                    } else {
+                        p.votes_against = p.votes_against.saturating_sub(prev_delegated);
+                    }
+                }
+                let delegated: BalanceOf<T> =
+                    T::Delegation::delegated_votes(&voter, GOVERNANCE_TRACK).saturated_into();
+                DelegateVotes::<T>::insert(&voter, proposal_id, (support, delegated));
+
                 // Store vote info for later unreservation
                 Votes::<T>::insert(
                     proposal_id,
@@ -375,9 +618,9 @@ pub mod pallet {
                 );
 
                 if support {
-                    p.votes_for += amount;
+                    p.votes_for += amount.saturating_add(delegated);
                 } else {
-                    p.votes_against += amount;
+                    p.votes_against += amount.saturating_add(delegated);
                 }
 
                 Self::deposit_event(Event::Voted(proposal_id, voter, support, amount));
@@ -385,9 +628,12 @@ pub mod pallet {
             })
         }
 
-        #[pallet::weight(10_000)]
+        #[pallet::weight(
+            T::WeightInfo::execute_proposal(T::MaxVotesPerProposal::get(), T::MaxPreimageSize::get())
+                .max(T::WeightInfo::execute_proposal_scheduling(T::MaxVotesPerProposal::get()))
+        )]
         #[pallet::call_index(2)]
-        pub fn execute_proposal(origin: OriginFor<T>, proposal_id: ProposalId) -> DispatchResult {
+        pub fn execute_proposal(origin: OriginFor<T>, proposal_id: ProposalId) -> DispatchResultWithPostInfo {
             let _ = ensure_signed(origin)?;
             let now = T::Time::now();
             Proposals::<T>::try_mutate(proposal_id, |maybe_p| {
@@ -395,25 +641,41 @@ pub mod pallet {
                 ensure!(p.status == ProposalStatus::Active, Error::<T>::AlreadyFinalized);
                 ensure!(now >= p.voting_ends, Error::<T>::VotingClosed);
 
-                if p.votes_for > p.votes_against {
+                let passed = p.votes_for > p.votes_against;
+                let mut released = 0;
+                if passed {
                     p.status = ProposalStatus::Passed;
                     Self::deposit_event(Event::ProposalPassed(proposal_id));
+
+                    // Executable proposals take effect after the enactment delay
+                    if ProposalCalls::<T>::contains_key(proposal_id) {
+                        Self::schedule_enactment(proposal_id)?;
+                    }
                 } else {
                     p.status = ProposalStatus::Rejected;
                     Self::deposit_event(Event::ProposalRejected(proposal_id));
+                    released = Self::release_call(proposal_id);
                 }
 
                 // Unreserve all votes after proposal finalization
                 let unreserved_count = Self::unreserve_votes(proposal_id);
                 Self::deposit_event(Event::VotesUnreserved(proposal_id, unreserved_count));
 
-                Ok(())
+                let actual = if passed {
+                    T::WeightInfo::execute_proposal_scheduling(unreserved_count)
+                } else {
+                    T::WeightInfo::execute_proposal(unreserved_count, released)
+                };
+                Ok(Some(actual).into())
             })
         }
 
-        #[pallet::weight(10_000)]
+        #[pallet::weight(T::WeightInfo::cancel_proposal(
+            T::MaxVotesPerProposal::get(),
+            T::MaxPreimageSize::get(),
+        ))]
         #[pallet::call_index(3)]
-        pub fn cancel_proposal(origin: OriginFor<T>, proposal_id: ProposalId) -> DispatchResult {
+        pub fn cancel_proposal(origin: OriginFor<T>, proposal_id: ProposalId) -> DispatchResultWithPostInfo {
             let sender = ensure_signed(origin)?;
             Proposals::<T>::try_mutate(proposal_id, |maybe_p| {
                 let p = maybe_p.as_mut().ok_or(Error::<T>::ProposalNotFound)?;
@@ -421,17 +683,18 @@ pub mod pallet {
                 ensure!(p.status == ProposalStatus::Active, Error::<T>::AlreadyFinalized);
                 p.status = ProposalStatus::Cancelled;
                 Self::deposit_event(Event::ProposalCancelled(proposal_id));
+                let released = Self::release_call(proposal_id);
 
                 // Unreserve all votes when proposal is cancelled
                 let unreserved_count = Self::unreserve_votes(proposal_id);
                 Self::deposit_event(Event::VotesUnreserved(proposal_id, unreserved_count));
 
-                Ok(())
+                Ok(Some(T::WeightInfo::cancel_proposal(unreserved_count, released)).into())
             })
         }
 
         /// Initialize Consensus Day schedule (governance-only)
-        #[pallet::weight(10_000)]
+        #[pallet::weight(T::WeightInfo::initialize_consensus_day())]
         #[pallet::call_index(4)]
         pub fn initialize_consensus_day(
             origin: OriginFor<T>,
@@ -457,7 +720,7 @@ pub mod pallet {
         }
 
         /// Create a Consensus Day proposal (only during Consensus Day)
-        #[pallet::weight(10_000)]
+        #[pallet::weight(T::WeightInfo::create_consensus_day_proposal())]
         #[pallet::call_index(5)]
         pub fn create_consensus_day_proposal(
             origin: OriginFor<T>,
@@ -512,7 +775,7 @@ pub mod pallet {
         }
 
         /// Vote on Consensus Day proposal
-        #[pallet::weight(10_000)]
+        #[pallet::weight(T::WeightInfo::vote_consensus_day_proposal())]
         #[pallet::call_index(6)]
         pub fn vote_consensus_day_proposal(
             origin: OriginFor<T>,
@@ -525,6 +788,8 @@ pub mod pallet {
             // Ensure Consensus Day is active
             ensure!(IsConsensusDayActive::<T>::get(), Error::<T>::ConsensusDayNotActive);
 
+            Self::note_voter(proposal_id, ConsensusDayVotes::<T>::contains_key(proposal_id, &who))?;
+
             // Reserve stake
             T::Currency::reserve(&who, stake)?;
 
@@ -565,12 +830,12 @@ pub mod pallet {
         }
 
         /// Finalize Consensus Day proposal
-        #[pallet::weight(10_000)]
+        #[pallet::weight(T::WeightInfo::finalize_consensus_day_proposal(T::MaxVotesPerProposal::get()))]
         #[pallet::call_index(7)]
         pub fn finalize_consensus_day_proposal(
             origin: OriginFor<T>,
             proposal_id: ProposalId,
-        ) -> DispatchResult {
+        ) -> DispatchResultWithPostInfo {
             let _ = ensure_signed(origin)?;
 
             let mut proposal = ConsensusDayProposals::<T>::get(proposal_id).ok_or(Error::<T>::ProposalNotFound)?;
@@ -597,7 +862,7 @@ pub mod pallet {
             ConsensusDayProposals::<T>::insert(proposal_id, proposal);
 
             // Unreserve all votes
-            let _ = Self::unreserve_consensus_day_votes(proposal_id);
+            let unreserved_count = Self::unreserve_consensus_day_votes(proposal_id);
 
             if passed {
                 Self::deposit_event(Event::ConsensusDayProposalPassed { proposal_id, yes_pct: yes_pct as u8 });
@@ -605,13 +870,111 @@ pub mod pallet {
                 Self::deposit_event(Event::ConsensusDayProposalRejected { proposal_id, yes_pct: yes_pct as u8 });
             }
 
+            Ok(Some(T::WeightInfo::finalize_consensus_day_proposal(unreserved_count)).into())
+        }
+
+        /// Store an encoded `RuntimeCall` for use by proposals
+        ///
+        /// Reserves `PreimageBaseDeposit + PreimageByteDeposit * len` until unnoted.
+        #[pallet::weight(T::WeightInfo::note_preimage(encoded_call.len() as u32))]
+        #[pallet::call_index(8)]
+        pub fn note_preimage(origin: OriginFor<T>, encoded_call: Vec<u8>) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            let call: BoundedVec<u8, T::MaxPreimageSize> =
+                encoded_call.try_into().map_err(|_| Error::<T>::PreimageTooLarge)?;
+            ensure!(
+                <T as Config>::RuntimeCall::decode(&mut &call[..]).is_ok(),
+                Error::<T>::UndecodableCall
+            );
+
+            let hash = T::Hashing::hash(&call[..]);
+            ensure!(!Preimages::<T>::contains_key(hash), Error::<T>::PreimageExists);
+
+            let deposit = T::PreimageByteDeposit::get()
+                .saturating_mul((call.len() as u32).into())
+                .saturating_add(T::PreimageBaseDeposit::get());
+            T::Currency::reserve(&who, deposit)?;
+
+            Preimages::<T>::insert(
+                hash,
+                PreimageInfo { depositor: who.clone(), deposit, call, references: 0 },
+            );
+            Self::deposit_event(Event::PreimageNoted { hash, depositor: who, deposit });
             Ok(())
         }
+
+        /// Remove an unreferenced preimage and return the deposit (depositor only)
+        #[pallet::weight(T::WeightInfo::unnote_preimage(T::MaxPreimageSize::get()))]
+        #[pallet::call_index(9)]
+        pub fn unnote_preimage(origin: OriginFor<T>, hash: T::Hash) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+
+            let info = Preimages::<T>::get(hash).ok_or(Error::<T>::PreimageMissing)?;
+            ensure!(info.depositor == who, Error::<T>::NotProposer);
+            ensure!(info.references == 0, Error::<T>::PreimageInUse);
+
+            T::Currency::unreserve(&info.depositor, info.deposit);
+            Preimages::<T>::remove(hash);
+            Self::deposit_event(Event::PreimageCleared { hash });
+            Ok(Some(T::WeightInfo::unnote_preimage(info.call.len() as u32)).into())
+        }
+
+        /// Create a proposal that dispatches the noted call `call_hash` if it passes
+        #[pallet::weight(T::WeightInfo::create_proposal_with_call(T::MaxPreimageSize::get()))]
+        #[pallet::call_index(10)]
+        pub fn create_proposal_with_call(
+            origin: OriginFor<T>,
+            title: Vec<u8>,
+            description: Vec<u8>,
+            call_hash: T::Hash,
+        ) -> DispatchResultWithPostInfo {
+            ensure!(Preimages::<T>::contains_key(call_hash), Error::<T>::PreimageMissing);
+
+            let id = NextProposalId::<T>::get();
+            Self::create_proposal(origin, title, description)?;
+
+            let len = Preimages::<T>::mutate(call_hash, |maybe_info| match maybe_info {
+                Some(info) => {
+                    info.references = info.references.saturating_add(1);
+                    info.call.len() as u32
+                }
+                None => 0,
+            });
+            ProposalCalls::<T>::insert(id, call_hash);
+            Ok(Some(T::WeightInfo::create_proposal_with_call(len)).into())
+        }
+
+        /// Stop a passed proposal's call before it is enacted
+        #[pallet::weight(T::WeightInfo::veto_proposal(T::MaxPreimageSize::get()))]
+        #[pallet::call_index(11)]
+        pub fn veto_proposal(origin: OriginFor<T>, proposal_id: ProposalId) -> DispatchResultWithPostInfo {
+            T::VetoOrigin::ensure_origin(origin)?;
+
+            let at = ScheduledEnactments::<T>::take(proposal_id).ok_or(Error::<T>::NotScheduled)?;
+            Agenda::<T>::mutate(at, |agenda| agenda.retain(|id| *id != proposal_id));
+
+            Proposals::<T>::mutate(proposal_id, |maybe_p| {
+                if let Some(p) = maybe_p {
+                    p.status = ProposalStatus::Vetoed;
+                }
+            });
+            let released = Self::release_call(proposal_id);
+            Self::deposit_event(Event::ProposalVetoed { proposal_id });
+            Ok(Some(T::WeightInfo::veto_proposal(released)).into())
+        }
     }
 
     #[pallet::hooks]
     impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
         fn on_initialize(n: BlockNumberFor<T>) -> Weight {
+            let mut weight = T::WeightInfo::on_initialize();
+
+            // Dispatch proposals whose enactment delay has elapsed
+            for proposal_id in Agenda::<T>::take(n) {
+                weight = weight.saturating_add(Self::enact(proposal_id));
+            }
+
             // Check if Consensus Day should start
             if let Some(mut config) = ConsensusDaySchedule::<T>::get() {
                 if n >= config.next_start && !config.active {
@@ -631,7 +994,55 @@ pub mod pallet {
                 }
             }
 
-            Weight::from_parts(10_000, 0)
+            weight
+        }
+    }
+
+    impl<T: Config> DelegationHooks<T::AccountId> for Pallet<T> {
+        fn has_active_votes(who: &T::AccountId, track: TrackId) -> bool {
+            track == GOVERNANCE_TRACK
+                && DelegateVotes::<T>::iter_prefix(who).any(|(proposal_id, _)| {
+                    Proposals::<T>::get(proposal_id).is_some_and(|p| p.status == ProposalStatus::Active)
+                })
+        }
+
+        fn on_delegated_votes_changed(
+            delegate: &T::AccountId,
+            track: TrackId,
+            _old: u128,
+            new: u128,
+        ) -> Weight {
+            if track != GOVERNANCE_TRACK {
+                return Weight::zero();
+            }
+
+            let now = T::Time::now();
+            let new: BalanceOf<T> = new.saturated_into();
+            let mut touched = 0u32;
+
+            // Only the delegate's own open votes are re-tallied, never its delegators
+            let votes: Vec<_> = DelegateVotes::<T>::iter_prefix(delegate).collect();
+            for (proposal_id, (support, counted)) in votes {
+                touched += 1;
+                Proposals::<T>::mutate(proposal_id, |maybe_p| {
+                    let Some(p) = maybe_p else { return };
+                    if p.status != ProposalStatus::Active || now >= p.voting_ends {
+                        return;
+                    }
+
+                    let side = if support { &mut p.votes_for } else { &mut p.votes_against };
+                    *side = side.saturating_sub(counted).saturating_add(new);
+
+                    DelegateVotes::<T>::insert(delegate, proposal_id, (support, new));
+                    Self::deposit_event(Event::DelegatedTallyUpdated {
+                        proposal_id,
+                        delegate: delegate.clone(),
+                        votes: new,
+                    });
+                });
+            }
+
+            T::WeightInfo::on_delegated_votes_changed(touched)
         }
     }
 }
@@ -643,9 +1054,10 @@ mod tests {
         assert_ok, assert_noop, parameter_types,
         traits::{ConstU32, Hooks},
     };
+    use codec::Encode;
     use sp_core::H256;
     use sp_runtime::{
-        traits::{BlakeTwo256, IdentityLookup},
+        traits::{BlakeTwo256, Hash, IdentityLookup},
         BuildStorage,
     };
 
@@ -656,6 +1068,7 @@ mod tests {
             System: frame_system,
             Balances: pallet_balances,
             Governance: crate,
+            VoteDelegation: pallet_vote_delegation,
         }
     );
 
@@ -701,7 +1114,7 @@ mod tests {
     }
 
     impl pallet_balances::Config for Test {
-        type MaxLocks = ();
+        type MaxLocks = ConstU32<50>;
         type MaxReserves = ConstU32<50>;
         type ReserveIdentifier = [u8; 8];
         type Balance = u64;
@@ -728,6 +1141,7 @@ mod tests {
     parameter_types! {
         pub const ProposalDuration: u64 = 100;
         pub const MinProposalStake: u64 = 100;
+        pub const EnactmentDelay: u64 = 10;
     }
 
     impl Config for Test {
@@ -737,6 +1151,25 @@ mod tests {
         type ProposalDuration = ProposalDuration;
         type MinProposalStake = MinProposalStake;
         type GovernanceOrigin = frame_system::EnsureRoot<u64>;
+        type RuntimeCall = RuntimeCall;
+        type MaxPreimageSize = ConstU32<1024>;
+        type PreimageBaseDeposit = frame_support::traits::ConstU64<10>;
+        type PreimageByteDeposit = frame_support::traits::ConstU64<1>;
+        type EnactmentDelay = EnactmentDelay;
+        type MaxScheduledPerBlock = ConstU32<2>;
+        type VetoOrigin = frame_system::EnsureRoot<u64>;
+        type Delegation = VoteDelegation;
+        type MaxVotesPerProposal = ConstU32<3>;
+        type WeightInfo = ();
+    }
+
+    impl pallet_vote_delegation::Config for Test {
+        type RuntimeEvent = RuntimeEvent;
+        type Currency = Balances;
+        type VoteLockingPeriod = frame_support::traits::ConstU64<10>;
+        type Tracks = frame_support::traits::ConstU8<2>;
+        type Hooks = Governance;
+        type WeightInfo = ();
     }
 
     fn new_test_ext() -> sp_io::TestExternalities {
@@ -1646,4 +2079,279 @@ mod tests {
             assert_eq!(prop2.min_participation, 50);
         });
     }
+
+    fn set_storage_call() -> Vec<u8> {
+        RuntimeCall::System(frame_system::Call::set_storage {
+            items: vec![(b":gov:test".to_vec(), b"enacted".to_vec())],
+        })
+        .encode()
+    }
+
+    /// Note the call, create a proposal for it and pass it at block 102
+    fn pass_call_proposal(encoded: Vec<u8>) -> H256 {
+        let hash = BlakeTwo256::hash(&encoded);
+        assert_ok!(Governance::note_preimage(RuntimeOrigin::signed(1), encoded));
+        assert_ok!(Governance::create_proposal_with_call(
+            RuntimeOrigin::signed(1),
+            b"Set storage".to_vec(),
+            b"Root call".to_vec(),
+            hash,
+        ));
+        assert_ok!(Governance::vote(RuntimeOrigin::signed(2), 0, true, 500));
+        System::set_block_number(102);
+        assert_ok!(Governance::execute_proposal(RuntimeOrigin::signed(1), 0));
+        hash
+    }
+
+    #[test]
+    fn note_preimage_reserves_deposit_and_unnote_refunds() {
+        new_test_ext().execute_with(|| {
+            System::set_block_number(1);
+            let encoded = set_storage_call();
+            let len = encoded.len() as u64;
+            let hash = BlakeTwo256::hash(&encoded);
+
+            assert_ok!(Governance::note_preimage(RuntimeOrigin::signed(1), encoded.clone()));
+            assert_eq!(Balances::reserved_balance(1), 10 + len);
+            assert_noop!(
+                Governance::note_preimage(RuntimeOrigin::signed(2), encoded),
+                Error::<Test>::PreimageExists
+            );
+
+            assert_noop!(
+                Governance::unnote_preimage(RuntimeOrigin::signed(2), hash),
+                Error::<Test>::NotProposer
+            );
+            assert_ok!(Governance::unnote_preimage(RuntimeOrigin::signed(1), hash));
+            assert_eq!(Balances::reserved_balance(1), 0);
+            assert!(Preimages::<Test>::get(hash).is_none());
+        });
+    }
+
+    #[test]
+    fn note_preimage_rejects_oversized_or_undecodable() {
+        new_test_ext().execute_with(|| {
+            assert_noop!(
+                Governance::note_preimage(RuntimeOrigin::signed(1), vec![0u8; 1025]),
+                Error::<Test>::PreimageTooLarge
+            );
+            assert_noop!(
+                Governance::note_preimage(RuntimeOrigin::signed(1), vec![0xff, 0xff]),
+                Error::<Test>::UndecodableCall
+            );
+        });
+    }
+
+    #[test]
+    fn passed_call_is_enacted_as_root_after_delay() {
+        new_test_ext().execute_with(|| {
+            System::set_block_number(1);
+            let hash = pass_call_proposal(set_storage_call());
+
+            // Scheduled at now + delay + 1, preimage locked meanwhile
+            assert_eq!(ScheduledEnactments::<Test>::get(0), Some(113));
+            assert_eq!(Governance::proposals(0).unwrap().status, ProposalStatus::Passed);
+            assert_noop!(
+                Governance::unnote_preimage(RuntimeOrigin::signed(1), hash),
+                Error::<Test>::PreimageInUse
+            );
+
+            Governance::on_initialize(112);
+            assert_eq!(sp_io::storage::get(b":gov:test"), None);
+
+            Governance::on_initialize(113);
+            assert_eq!(sp_io::storage::get(b":gov:test").as_deref(), Some(&b"enacted"[..]));
+            assert_eq!(Governance::proposals(0).unwrap().status, ProposalStatus::Enacted(true));
+            System::assert_has_event(
+                Event::ProposalEnacted { proposal_id: 0, result: Ok(()) }.into(),
+            );
+
+            // Reference released once enacted
+            assert_ok!(Governance::unnote_preimage(RuntimeOrigin::signed(1), hash));
+        });
+    }
+
+    #[test]
+    fn veto_during_delay_prevents_enactment() {
+        new_test_ext().execute_with(|| {
+            System::set_block_number(1);
+            pass_call_proposal(set_storage_call());
+
+            assert_noop!(
+                Governance::veto_proposal(RuntimeOrigin::signed(1), 0),
+                sp_runtime::DispatchError::BadOrigin
+            );
+            assert_ok!(Governance::veto_proposal(RuntimeOrigin::root(), 0));
+            assert_noop!(
+                Governance::veto_proposal(RuntimeOrigin::root(), 0),
+                Error::<Test>::NotScheduled
+            );
+
+            Governance::on_initialize(113);
+            assert_eq!(sp_io::storage::get(b":gov:test"), None);
+            assert_eq!(Governance::proposals(0).unwrap().status, ProposalStatus::Vetoed);
+        });
+    }
+
+    #[test]
+    fn rejected_call_proposal_is_not_scheduled() {
+        new_test_ext().execute_with(|| {
+            System::set_block_number(1);
+            let encoded = set_storage_call();
+            let hash = BlakeTwo256::hash(&encoded);
+            assert_ok!(Governance::note_preimage(RuntimeOrigin::signed(1), encoded));
+            assert_ok!(Governance::create_proposal_with_call(
+                RuntimeOrigin::signed(1),
+                b"Set storage".to_vec(),
+                b"Root call".to_vec(),
+                hash,
+            ));
+            assert_ok!(Governance::vote(RuntimeOrigin::signed(2), 0, false, 500));
+            System::set_block_number(102);
+            assert_ok!(Governance::execute_proposal(RuntimeOrigin::signed(1), 0));
+
+            assert!(ScheduledEnactments::<Test>::get(0).is_none());
+            assert_eq!(Preimages::<Test>::get(hash).unwrap().references, 0);
+        });
+    }
+
+    #[test]
+    fn full_agenda_spills_into_next_block() {
+        new_test_ext().execute_with(|| {
+            System::set_block_number(1);
+            let encoded = set_storage_call();
+            let hash = BlakeTwo256::hash(&encoded);
+            assert_ok!(Governance::note_preimage(RuntimeOrigin::signed(1), encoded));
+            for id in 0..3 {
+                assert_ok!(Governance::create_proposal_with_call(
+                    RuntimeOrigin::signed(1),
+                    b"Set storage".to_vec(),
+                    b"Root call".to_vec(),
+                    hash,
+                ));
+                assert_ok!(Governance::vote(RuntimeOrigin::signed(2), id, true, 100));
+            }
+
+            System::set_block_number(102);
+            for id in 0..3 {
+                assert_ok!(Governance::execute_proposal(RuntimeOrigin::signed(1), id));
+            }
+
+            assert_eq!(Governance::agenda(113).len(), 2);
+            assert_eq!(ScheduledEnactments::<Test>::get(2), Some(114));
+        });
+    }
+
+    #[test]
+    fn delegated_votes_add_to_delegate_vote() {
+        use pallet_vote_delegation::Conviction;
+
+        new_test_ext().execute_with(|| {
+            System::set_block_number(1);
+            assert_ok!(Governance::create_proposal(
+                RuntimeOrigin::signed(1),
+                b"Test".to_vec(),
+                b"Test".to_vec()
+            ));
+            assert_ok!(VoteDelegation::delegate(
+                RuntimeOrigin::signed(3),
+                pallet_vote_delegation::GOVERNANCE_TRACK,
+                2,
+                Conviction::Locked2x,
+                200,
+            ));
+
+            assert_ok!(Governance::vote(RuntimeOrigin::signed(2), 0, true, 100));
+            assert_eq!(Governance::proposals(0).unwrap().votes_for, 500);
+            assert_eq!(Governance::delegate_votes(2, 0), Some((true, 400)));
+
+            // Delegators cannot vote on the track they delegated
+            assert_noop!(
+                Governance::vote(RuntimeOrigin::signed(3), 0, false, 100),
+                Error::<Test>::DelegatingVotes
+            );
+
+            // Re-voting does not count delegated votes twice
+            assert_ok!(Governance::vote(RuntimeOrigin::signed(2), 0, false, 100));
+            assert_eq!(Governance::proposals(0).unwrap().votes_against, 500);
+            assert_eq!(Governance::proposals(0).unwrap().votes_for, 100);
+        });
+    }
+
+    #[test]
+    fn delegation_changes_retally_open_votes() {
+        use pallet_vote_delegation::{Conviction, GOVERNANCE_TRACK};
+
+        new_test_ext().execute_with(|| {
+            System::set_block_number(1);
+            assert_ok!(Governance::create_proposal(
+                RuntimeOrigin::signed(1),
+                b"Test".to_vec(),
+                b"Test".to_vec()
+            ));
+            assert_ok!(Governance::vote(RuntimeOrigin::signed(2), 0, true, 100));
+
+            assert_ok!(VoteDelegation::delegate(
+                RuntimeOrigin::signed(3),
+                GOVERNANCE_TRACK,
+                2,
+                Conviction::Locked1x,
+                200,
+            ));
+            assert_eq!(Governance::proposals(0).unwrap().votes_for, 300);
+
+            assert_ok!(VoteDelegation::undelegate(RuntimeOrigin::signed(3), GOVERNANCE_TRACK));
+            assert_eq!(Governance::proposals(0).unwrap().votes_for, 100);
+
+            // An account with an open vote cannot delegate it
+            assert_noop!(
+                VoteDelegation::delegate(
+                    RuntimeOrigin::signed(2),
+                    GOVERNANCE_TRACK,
+                    4,
+                    Conviction::None,
+                    10,
+                ),
+                pallet_vote_delegation::Error::<Test>::ActiveVotes
+            );
+
+            // Closed proposals keep their tally
+            System::set_block_number(102);
+            assert_ok!(VoteDelegation::delegate(
+                RuntimeOrigin::signed(4),
+                GOVERNANCE_TRACK,
+                2,
+                Conviction::Locked1x,
+                500,
+            ));
+            assert_eq!(Governance::proposals(0).unwrap().votes_for, 100);
+        });
+    }
+
+    #[test]
+    fn voters_per_proposal_are_limited() {
+        new_test_ext().execute_with(|| {
+            System::set_block_number(1);
+            assert_ok!(Governance::create_proposal(
+                RuntimeOrigin::signed(1),
+                b"Test".to_vec(),
+                b"Test".to_vec()
+            ));
+            for voter in 1..=3 {
+                assert_ok!(Governance::vote(RuntimeOrigin::signed(voter), 0, true, 100));
+            }
+            assert_eq!(Governance::voter_count(0), 3);
+
+            // Re-voting does not count against the limit, a fourth voter does
+            assert_ok!(Governance::vote(RuntimeOrigin::signed(3), 0, false, 100));
+            assert_noop!(
+                Governance::vote(RuntimeOrigin::signed(4), 0, true, 100),
+                Error::<Test>::TooManyVotes
+            );
+
+            System::set_block_number(102);
+            assert_ok!(Governance::execute_proposal(RuntimeOrigin::signed(1), 0));
+            assert_eq!(Governance::voter_count(0), 0);
+        });
+    }
 }
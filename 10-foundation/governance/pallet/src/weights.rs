@@ -0,0 +1,436 @@
+//! PLACEHOLDER weights for `pallet_governance`: hand-estimated, not benchmark output.
+//!
+//! Overwrite with `./scripts/benchmark-pallets.sh pallet_governance` on reference hardware.
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(missing_docs)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use core::marker::PhantomData;
+
+/// Weight functions needed for `pallet_governance`.
+pub trait WeightInfo {
+	fn create_proposal() -> Weight;
+	fn vote() -> Weight;
+	fn execute_proposal(v: u32, s: u32, ) -> Weight;
+	fn execute_proposal_scheduling(v: u32, ) -> Weight;
+	fn cancel_proposal(v: u32, s: u32, ) -> Weight;
+	fn initialize_consensus_day() -> Weight;
+	fn create_consensus_day_proposal() -> Weight;
+	fn vote_consensus_day_proposal() -> Weight;
+	fn finalize_consensus_day_proposal(v: u32, ) -> Weight;
+	fn note_preimage(s: u32, ) -> Weight;
+	fn unnote_preimage(s: u32, ) -> Weight;
+	fn create_proposal_with_call(s: u32, ) -> Weight;
+	fn veto_proposal(s: u32, ) -> Weight;
+	fn enact(s: u32, ) -> Weight;
+	fn on_initialize() -> Weight;
+	fn on_delegated_votes_changed(p: u32, ) -> Weight;
+}
+
+/// Placeholder weights for `pallet_governance`, pending benchmarking.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	/// Storage: `Timestamp::Now` (r:1 w:0)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Storage: `Governance::NextProposalId` (r:1 w:1)
+	/// Storage: `Governance::Proposals` (r:0 w:1)
+	fn create_proposal() -> Weight {
+		Weight::from_parts(38_420_000, 4595)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: `VoteDelegation::Delegations` (r:1 w:0)
+	/// Storage: `Timestamp::Now` (r:1 w:0)
+	/// Storage: `Governance::Proposals` (r:1 w:1)
+	/// Storage: `Governance::Votes` (r:1 w:1)
+	/// Storage: `Governance::VoterCount` (r:1 w:1)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Storage: `Governance::DelegateVotes` (r:1 w:1)
+	/// Storage: `VoteDelegation::DelegatedVotes` (r:1 w:0)
+	fn vote() -> Weight {
+		Weight::from_parts(61_730_000, 20709)
+			.saturating_add(T::DbWeight::get().reads(8_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+	/// Storage: `Timestamp::Now` (r:1 w:0)
+	/// Storage: `Governance::Proposals` (r:1 w:1)
+	/// Storage: `Governance::ProposalCalls` (r:1 w:1)
+	/// Storage: `Governance::Preimages` (r:1 w:1)
+	/// Storage: `Governance::VoterCount` (r:0 w:1)
+	/// Storage: `Governance::Votes` (r:0 w:0)
+	/// Storage: `System::Account` (r:0 w:0)
+	/// Storage: `Governance::DelegateVotes` (r:0 w:0)
+	/// The range of component `v` is `[0, 512]`.
+	/// The range of component `s` is `[8, 4194304]`.
+	fn execute_proposal(v: u32, s: u32, ) -> Weight {
+		Weight::from_parts(41_280_000, 10455)
+			.saturating_add(Weight::from_parts(27_940_000, 0).saturating_mul(v.into()))
+			.saturating_add(Weight::from_parts(1_105, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(v.into())))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+			.saturating_add(T::DbWeight::get().writes((3_u64).saturating_mul(v.into())))
+			.saturating_add(Weight::from_parts(0, 5163).saturating_mul(v.into()))
+			.saturating_add(Weight::from_parts(0, 1).saturating_mul(s.into()))
+	}
+	/// Storage: `Timestamp::Now` (r:1 w:0)
+	/// Storage: `Governance::Proposals` (r:1 w:1)
+	/// Storage: `Governance::ProposalCalls` (r:1 w:0)
+	/// Storage: `Governance::Agenda` (r:16 w:1)
+	/// Storage: `Governance::ScheduledEnactments` (r:0 w:1)
+	/// Storage: `Governance::VoterCount` (r:0 w:1)
+	/// Storage: `Governance::Votes` (r:0 w:0)
+	/// Storage: `System::Account` (r:0 w:0)
+	/// Storage: `Governance::DelegateVotes` (r:0 w:0)
+	/// The range of component `v` is `[1, 512]`.
+	fn execute_proposal_scheduling(v: u32, ) -> Weight {
+		Weight::from_parts(86_510_000, 50873)
+			.saturating_add(Weight::from_parts(27_940_000, 0).saturating_mul(v.into()))
+			.saturating_add(T::DbWeight::get().reads(19_u64))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(v.into())))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+			.saturating_add(T::DbWeight::get().writes((3_u64).saturating_mul(v.into())))
+			.saturating_add(Weight::from_parts(0, 5163).saturating_mul(v.into()))
+	}
+	/// Storage: `Governance::Proposals` (r:1 w:1)
+	/// Storage: `Governance::ProposalCalls` (r:1 w:1)
+	/// Storage: `Governance::Preimages` (r:1 w:1)
+	/// Storage: `Governance::VoterCount` (r:0 w:1)
+	/// Storage: `Governance::Votes` (r:0 w:0)
+	/// Storage: `System::Account` (r:0 w:0)
+	/// Storage: `Governance::DelegateVotes` (r:0 w:0)
+	/// The range of component `v` is `[0, 512]`.
+	/// The range of component `s` is `[8, 4194304]`.
+	fn cancel_proposal(v: u32, s: u32, ) -> Weight {
+		Weight::from_parts(39_660_000, 9952)
+			.saturating_add(Weight::from_parts(27_940_000, 0).saturating_mul(v.into()))
+			.saturating_add(Weight::from_parts(1_105, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(v.into())))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+			.saturating_add(T::DbWeight::get().writes((3_u64).saturating_mul(v.into())))
+			.saturating_add(Weight::from_parts(0, 5163).saturating_mul(v.into()))
+			.saturating_add(Weight::from_parts(0, 1).saturating_mul(s.into()))
+	}
+	/// Storage: `Governance::ConsensusDaySchedule` (r:0 w:1)
+	fn initialize_consensus_day() -> Weight {
+		Weight::from_parts(9_870_000, 990)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Governance::IsConsensusDayActive` (r:1 w:0)
+	/// Storage: `Governance::NextProposalId` (r:1 w:1)
+	/// Storage: `Governance::ConsensusDaySchedule` (r:1 w:0)
+	/// Storage: `Governance::ConsensusDayProposals` (r:0 w:1)
+	fn create_consensus_day_proposal() -> Weight {
+		Weight::from_parts(29_150_000, 2493)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `Governance::IsConsensusDayActive` (r:1 w:0)
+	/// Storage: `Governance::ConsensusDayVotes` (r:1 w:1)
+	/// Storage: `Governance::VoterCount` (r:1 w:1)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Storage: `Governance::ConsensusDayProposals` (r:1 w:1)
+	fn vote_consensus_day_proposal() -> Weight {
+		Weight::from_parts(52_380_000, 16094)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: `Governance::ConsensusDayProposals` (r:1 w:1)
+	/// Storage: `Governance::VoterCount` (r:0 w:1)
+	/// Storage: `Governance::ConsensusDayVotes` (r:0 w:0)
+	/// Storage: `System::Account` (r:0 w:0)
+	/// The range of component `v` is `[0, 512]`.
+	fn finalize_consensus_day_proposal(v: u32, ) -> Weight {
+		Weight::from_parts(27_310_000, 7936)
+			.saturating_add(Weight::from_parts(25_460_000, 0).saturating_mul(v.into()))
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(v.into())))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(v.into())))
+			.saturating_add(Weight::from_parts(0, 5163).saturating_mul(v.into()))
+	}
+	/// Storage: `Governance::Preimages` (r:1 w:1)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// The range of component `s` is `[8, 4194304]`.
+	fn note_preimage(s: u32, ) -> Weight {
+		Weight::from_parts(41_020_000, 6183)
+			.saturating_add(Weight::from_parts(2_712, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `Governance::Preimages` (r:1 w:1)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// The range of component `s` is `[8, 4194304]`.
+	fn unnote_preimage(s: u32, ) -> Weight {
+		Weight::from_parts(36_890_000, 6183)
+			.saturating_add(Weight::from_parts(1_087, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+			.saturating_add(Weight::from_parts(0, 1).saturating_mul(s.into()))
+	}
+	/// Storage: `Governance::Preimages` (r:1 w:1)
+	/// Storage: `Governance::NextProposalId` (r:1 w:1)
+	/// Storage: `Timestamp::Now` (r:1 w:0)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Storage: `Governance::Proposals` (r:0 w:1)
+	/// Storage: `Governance::ProposalCalls` (r:0 w:1)
+	/// The range of component `s` is `[8, 4194304]`.
+	fn create_proposal_with_call(s: u32, ) -> Weight {
+		Weight::from_parts(49_760_000, 7185)
+			.saturating_add(Weight::from_parts(1_103, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+			.saturating_add(Weight::from_parts(0, 1).saturating_mul(s.into()))
+	}
+	/// Storage: `Governance::ScheduledEnactments` (r:1 w:1)
+	/// Storage: `Governance::Agenda` (r:1 w:1)
+	/// Storage: `Governance::Proposals` (r:1 w:1)
+	/// Storage: `Governance::ProposalCalls` (r:1 w:1)
+	/// Storage: `Governance::Preimages` (r:1 w:1)
+	/// The range of component `s` is `[8, 4194304]`.
+	fn veto_proposal(s: u32, ) -> Weight {
+		Weight::from_parts(34_570_000, 15139)
+			.saturating_add(Weight::from_parts(1_105, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+			.saturating_add(Weight::from_parts(0, 1).saturating_mul(s.into()))
+	}
+	/// Storage: `Governance::ScheduledEnactments` (r:0 w:1)
+	/// Storage: `Governance::ProposalCalls` (r:1 w:1)
+	/// Storage: `Governance::Preimages` (r:1 w:1)
+	/// Storage: `Governance::Proposals` (r:1 w:1)
+	/// The range of component `s` is `[8, 4194304]`.
+	fn enact(s: u32, ) -> Weight {
+		Weight::from_parts(36_120_000, 9952)
+			.saturating_add(Weight::from_parts(2_416, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+			.saturating_add(Weight::from_parts(0, 1).saturating_mul(s.into()))
+	}
+	/// Storage: `Governance::Agenda` (r:1 w:1)
+	/// Storage: `Governance::ConsensusDaySchedule` (r:1 w:1)
+	/// Storage: `Governance::IsConsensusDayActive` (r:0 w:1)
+	fn on_initialize() -> Weight {
+		Weight::from_parts(14_930_000, 4186)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: `Timestamp::Now` (r:1 w:0)
+	/// Storage: `Governance::DelegateVotes` (r:0 w:0)
+	/// Storage: `Governance::Proposals` (r:0 w:0)
+	/// The range of component `p` is `[0, 100]`.
+	fn on_delegated_votes_changed(p: u32, ) -> Weight {
+		Weight::from_parts(7_240_000, 1493)
+			.saturating_add(Weight::from_parts(16_380_000, 0).saturating_mul(p.into()))
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(p.into())))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(p.into())))
+			.saturating_add(Weight::from_parts(0, 6405).saturating_mul(p.into()))
+	}
+}
+
+// For backwards compatibility and tests.
+impl WeightInfo for () {
+	/// Storage: `Timestamp::Now` (r:1 w:0)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Storage: `Governance::NextProposalId` (r:1 w:1)
+	/// Storage: `Governance::Proposals` (r:0 w:1)
+	fn create_proposal() -> Weight {
+		Weight::from_parts(38_420_000, 4595)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: `VoteDelegation::Delegations` (r:1 w:0)
+	/// Storage: `Timestamp::Now` (r:1 w:0)
+	/// Storage: `Governance::Proposals` (r:1 w:1)
+	/// Storage: `Governance::Votes` (r:1 w:1)
+	/// Storage: `Governance::VoterCount` (r:1 w:1)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Storage: `Governance::DelegateVotes` (r:1 w:1)
+	/// Storage: `VoteDelegation::DelegatedVotes` (r:1 w:0)
+	fn vote() -> Weight {
+		Weight::from_parts(61_730_000, 20709)
+			.saturating_add(RocksDbWeight::get().reads(8_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
+	/// Storage: `Timestamp::Now` (r:1 w:0)
+	/// Storage: `Governance::Proposals` (r:1 w:1)
+	/// Storage: `Governance::ProposalCalls` (r:1 w:1)
+	/// Storage: `Governance::Preimages` (r:1 w:1)
+	/// Storage: `Governance::VoterCount` (r:0 w:1)
+	/// Storage: `Governance::Votes` (r:0 w:0)
+	/// Storage: `System::Account` (r:0 w:0)
+	/// Storage: `Governance::DelegateVotes` (r:0 w:0)
+	/// The range of component `v` is `[0, 512]`.
+	/// The range of component `s` is `[8, 4194304]`.
+	fn execute_proposal(v: u32, s: u32, ) -> Weight {
+		Weight::from_parts(41_280_000, 10455)
+			.saturating_add(Weight::from_parts(27_940_000, 0).saturating_mul(v.into()))
+			.saturating_add(Weight::from_parts(1_105, 0).saturating_mul(s.into()))
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().reads((2_u64).saturating_mul(v.into())))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+			.saturating_add(RocksDbWeight::get().writes((3_u64).saturating_mul(v.into())))
+			.saturating_add(Weight::from_parts(0, 5163).saturating_mul(v.into()))
+			.saturating_add(Weight::from_parts(0, 1).saturating_mul(s.into()))
+	}
+	/// Storage: `Timestamp::Now` (r:1 w:0)
+	/// Storage: `Governance::Proposals` (r:1 w:1)
+	/// Storage: `Governance::ProposalCalls` (r:1 w:0)
+	/// Storage: `Governance::Agenda` (r:16 w:1)
+	/// Storage: `Governance::ScheduledEnactments` (r:0 w:1)
+	/// Storage: `Governance::VoterCount` (r:0 w:1)
+	/// Storage: `Governance::Votes` (r:0 w:0)
+	/// Storage: `System::Account` (r:0 w:0)
+	/// Storage: `Governance::DelegateVotes` (r:0 w:0)
+	/// The range of component `v` is `[1, 512]`.
+	fn execute_proposal_scheduling(v: u32, ) -> Weight {
+		Weight::from_parts(86_510_000, 50873)
+			.saturating_add(Weight::from_parts(27_940_000, 0).saturating_mul(v.into()))
+			.saturating_add(RocksDbWeight::get().reads(19_u64))
+			.saturating_add(RocksDbWeight::get().reads((2_u64).saturating_mul(v.into())))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+			.saturating_add(RocksDbWeight::get().writes((3_u64).saturating_mul(v.into())))
+			.saturating_add(Weight::from_parts(0, 5163).saturating_mul(v.into()))
+	}
+	/// Storage: `Governance::Proposals` (r:1 w:1)
+	/// Storage: `Governance::ProposalCalls` (r:1 w:1)
+	/// Storage: `Governance::Preimages` (r:1 w:1)
+	/// Storage: `Governance::VoterCount` (r:0 w:1)
+	/// Storage: `Governance::Votes` (r:0 w:0)
+	/// Storage: `System::Account` (r:0 w:0)
+	/// Storage: `Governance::DelegateVotes` (r:0 w:0)
+	/// The range of component `v` is `[0, 512]`.
+	/// The range of component `s` is `[8, 4194304]`.
+	fn cancel_proposal(v: u32, s: u32, ) -> Weight {
+		Weight::from_parts(39_660_000, 9952)
+			.saturating_add(Weight::from_parts(27_940_000, 0).saturating_mul(v.into()))
+			.saturating_add(Weight::from_parts(1_105, 0).saturating_mul(s.into()))
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().reads((2_u64).saturating_mul(v.into())))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+			.saturating_add(RocksDbWeight::get().writes((3_u64).saturating_mul(v.into())))
+			.saturating_add(Weight::from_parts(0, 5163).saturating_mul(v.into()))
+			.saturating_add(Weight::from_parts(0, 1).saturating_mul(s.into()))
+	}
+	/// Storage: `Governance::ConsensusDaySchedule` (r:0 w:1)
+	fn initialize_consensus_day() -> Weight {
+		Weight::from_parts(9_870_000, 990)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Governance::IsConsensusDayActive` (r:1 w:0)
+	/// Storage: `Governance::NextProposalId` (r:1 w:1)
+	/// Storage: `Governance::ConsensusDaySchedule` (r:1 w:0)
+	/// Storage: `Governance::ConsensusDayProposals` (r:0 w:1)
+	fn create_consensus_day_proposal() -> Weight {
+		Weight::from_parts(29_150_000, 2493)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `Governance::IsConsensusDayActive` (r:1 w:0)
+	/// Storage: `Governance::ConsensusDayVotes` (r:1 w:1)
+	/// Storage: `Governance::VoterCount` (r:1 w:1)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Storage: `Governance::ConsensusDayProposals` (r:1 w:1)
+	fn vote_consensus_day_proposal() -> Weight {
+		Weight::from_parts(52_380_000, 16094)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: `Governance::ConsensusDayProposals` (r:1 w:1)
+	/// Storage: `Governance::VoterCount` (r:0 w:1)
+	/// Storage: `Governance::ConsensusDayVotes` (r:0 w:0)
+	/// Storage: `System::Account` (r:0 w:0)
+	/// The range of component `v` is `[0, 512]`.
+	fn finalize_consensus_day_proposal(v: u32, ) -> Weight {
+		Weight::from_parts(27_310_000, 7936)
+			.saturating_add(Weight::from_parts(25_460_000, 0).saturating_mul(v.into()))
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().reads((2_u64).saturating_mul(v.into())))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+			.saturating_add(RocksDbWeight::get().writes((2_u64).saturating_mul(v.into())))
+			.saturating_add(Weight::from_parts(0, 5163).saturating_mul(v.into()))
+	}
+	/// Storage: `Governance::Preimages` (r:1 w:1)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// The range of component `s` is `[8, 4194304]`.
+	fn note_preimage(s: u32, ) -> Weight {
+		Weight::from_parts(41_020_000, 6183)
+			.saturating_add(Weight::from_parts(2_712, 0).saturating_mul(s.into()))
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `Governance::Preimages` (r:1 w:1)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// The range of component `s` is `[8, 4194304]`.
+	fn unnote_preimage(s: u32, ) -> Weight {
+		Weight::from_parts(36_890_000, 6183)
+			.saturating_add(Weight::from_parts(1_087, 0).saturating_mul(s.into()))
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+			.saturating_add(Weight::from_parts(0, 1).saturating_mul(s.into()))
+	}
+	/// Storage: `Governance::Preimages` (r:1 w:1)
+	/// Storage: `Governance::NextProposalId` (r:1 w:1)
+	/// Storage: `Timestamp::Now` (r:1 w:0)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Storage: `Governance::Proposals` (r:0 w:1)
+	/// Storage: `Governance::ProposalCalls` (r:0 w:1)
+	/// The range of component `s` is `[8, 4194304]`.
+	fn create_proposal_with_call(s: u32, ) -> Weight {
+		Weight::from_parts(49_760_000, 7185)
+			.saturating_add(Weight::from_parts(1_103, 0).saturating_mul(s.into()))
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+			.saturating_add(Weight::from_parts(0, 1).saturating_mul(s.into()))
+	}
+	/// Storage: `Governance::ScheduledEnactments` (r:1 w:1)
+	/// Storage: `Governance::Agenda` (r:1 w:1)
+	/// Storage: `Governance::Proposals` (r:1 w:1)
+	/// Storage: `Governance::ProposalCalls` (r:1 w:1)
+	/// Storage: `Governance::Preimages` (r:1 w:1)
+	/// The range of component `s` is `[8, 4194304]`.
+	fn veto_proposal(s: u32, ) -> Weight {
+		Weight::from_parts(34_570_000, 15139)
+			.saturating_add(Weight::from_parts(1_105, 0).saturating_mul(s.into()))
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+			.saturating_add(Weight::from_parts(0, 1).saturating_mul(s.into()))
+	}
+	/// Storage: `Governance::ScheduledEnactments` (r:0 w:1)
+	/// Storage: `Governance::ProposalCalls` (r:1 w:1)
+	/// Storage: `Governance::Preimages` (r:1 w:1)
+	/// Storage: `Governance::Proposals` (r:1 w:1)
+	/// The range of component `s` is `[8, 4194304]`.
+	fn enact(s: u32, ) -> Weight {
+		Weight::from_parts(36_120_000, 9952)
+			.saturating_add(Weight::from_parts(2_416, 0).saturating_mul(s.into()))
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+			.saturating_add(Weight::from_parts(0, 1).saturating_mul(s.into()))
+	}
+	/// Storage: `Governance::Agenda` (r:1 w:1)
+	/// Storage: `Governance::ConsensusDaySchedule` (r:1 w:1)
+	/// Storage: `Governance::IsConsensusDayActive` (r:0 w:1)
+	fn on_initialize() -> Weight {
+		Weight::from_parts(14_930_000, 4186)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: `Timestamp::Now` (r:1 w:0)
+	/// Storage: `Governance::DelegateVotes` (r:0 w:0)
+	/// Storage: `Governance::Proposals` (r:0 w:0)
+	/// The range of component `p` is `[0, 100]`.
+	fn on_delegated_votes_changed(p: u32, ) -> Weight {
+		Weight::from_parts(7_240_000, 1493)
+			.saturating_add(Weight::from_parts(16_380_000, 0).saturating_mul(p.into()))
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().reads((2_u64).saturating_mul(p.into())))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(p.into())))
+			.saturating_add(Weight::from_parts(0, 6405).saturating_mul(p.into()))
+	}
+}
@@ -1,14 +1,43 @@
 //! Benchmarking setup for pallet-treasury
 //!
 //! This module contains benchmarks for all extrinsics in pallet-treasury.
+//! Every benchmark runs with the full board of 9 directors and the caller in
+//! the last seat, so director lookups scan the whole list.
 
 #![cfg(feature = "runtime-benchmarks")]
 
 use super::*;
 use frame_benchmarking::v2::*;
-use frame_support::assert_ok;
+use frame_support::traits::Currency;
 use frame_system::RawOrigin;
-use frame_support::traits::fungible::Mutate;
+use sp_runtime::traits::{Saturating, Zero};
+use sp_std::{vec, vec::Vec};
+
+const MAX_DIRECTORS: u32 = 9;
+
+fn directors<T: Config>(n: u32) -> Vec<T::AccountId> {
+    let directors: Vec<T::AccountId> = (0..n).map(|i| account("director", i, 0)).collect();
+    Directors::<T>::put(BoundedVec::truncate_from(directors.clone()));
+    directors
+}
+
+/// Amount large enough to keep every account above the existential deposit
+fn amount<T: Config>() -> BalanceOf<T> {
+    T::Currency::minimum_balance().saturating_mul(1_000u32.into())
+}
+
+/// Fund the treasury account and every budget bucket with `10 * amount`
+fn fund<T: Config>() {
+    let funds = amount::<T>().saturating_mul(10u32.into());
+    T::Currency::make_free_balance_be(&Pallet::<T>::account_id(), funds);
+    TreasuryBalance::<T>::put(funds);
+    CategoryAllocations::<T>::insert(BudgetCategory::Development, funds);
+    EmergencyReserve::<T>::put(funds);
+}
+
+fn max_description() -> Vec<u8> {
+    vec![b'd'; 256]
+}
 
 #[benchmarks]
 mod benchmarks {
@@ -17,154 +46,112 @@ mod benchmarks {
     #[benchmark]
     fn fund_treasury() {
         let caller: T::AccountId = whitelisted_caller();
-        let amount: BalanceOf<T> = 10000u32.into();
-
-        // Setup: Give caller some balance
-        T::Currency::mint_into(&caller, amount * 2u32.into()).unwrap();
+        let amount = amount::<T>();
 
         #[extrinsic_call]
-        fund_treasury(
-            RawOrigin::Signed(caller.clone()),
-            FundingSource::ExternalDonation,
-            amount
-        );
+        _(RawOrigin::Signed(caller), FundingSource::Other, amount);
 
-        // Verify treasury balance increased
-        let treasury_account = Pallet::<T>::account_id();
-        assert!(T::Currency::balance(&treasury_account) >= amount);
+        assert_eq!(FundingSourceTotals::<T>::get(FundingSource::Other), amount);
     }
 
     #[benchmark]
     fn propose_disbursement() {
-        let caller: T::AccountId = whitelisted_caller();
+        let caller = directors::<T>(MAX_DIRECTORS).pop().unwrap();
         let recipient: T::AccountId = account("recipient", 0, 0);
-        let amount: BalanceOf<T> = 1000u32.into();
-
-        // Setup: Make caller a director
-        let mut directors = Directors::<T>::get();
-        if !directors.contains(&caller) {
-            directors.try_push(caller.clone()).ok();
-            Directors::<T>::put(directors);
-        }
-
-        // Fund treasury first
-        let treasury_account = Pallet::<T>::account_id();
-        T::Currency::mint_into(&treasury_account, amount * 10u32.into()).unwrap();
+        fund::<T>();
 
         #[extrinsic_call]
-        propose_disbursement(
-            RawOrigin::Signed(caller.clone()),
-            recipient.clone(),
-            amount,
-            BudgetCategory::Development
+        _(
+            RawOrigin::Signed(caller),
+            BudgetCategory::Development,
+            recipient,
+            amount::<T>(),
+            max_description(),
         );
 
-        // Verify proposal was created
-        assert!(ProposalCount::<T>::get() > 0);
+        assert_eq!(DisbursementCount::<T>::get(), 1);
     }
 
+    /// The final approval reaches the threshold and pays out
     #[benchmark]
     fn approve_disbursement() {
-        let director1: T::AccountId = whitelisted_caller();
-        let director2: T::AccountId = account("director2", 0, 0);
-        let director3: T::AccountId = account("director3", 0, 0);
+        let mut directors = directors::<T>(MAX_DIRECTORS);
+        let caller = directors.pop().unwrap();
         let recipient: T::AccountId = account("recipient", 0, 0);
-        let amount: BalanceOf<T> = 1000u32.into();
-
-        // Setup: Create directors
-        let mut directors = BoundedVec::new();
-        directors.try_push(director1.clone()).ok();
-        directors.try_push(director2.clone()).ok();
-        directors.try_push(director3).ok();
-        Directors::<T>::put(directors);
-
-        // Fund treasury
-        let treasury_account = Pallet::<T>::account_id();
-        T::Currency::mint_into(&treasury_account, amount * 10u32.into()).unwrap();
-
-        // Create a proposal
-        let proposal_id = ProposalCount::<T>::get();
-        let proposal = DisbursementProposal {
-            proposer: director1.clone(),
-            recipient: recipient.clone(),
-            amount,
-            category: BudgetCategory::Development,
-            approvals: BoundedVec::new(),
-            status: ProposalStatus::Pending,
-            created_at: frame_system::Pallet::<T>::block_number(),
-        };
-        Proposals::<T>::insert(proposal_id, proposal);
-        ProposalCount::<T>::put(proposal_id + 1);
+        fund::<T>();
+        Pallet::<T>::propose_disbursement(
+            RawOrigin::Signed(directors[0].clone()).into(),
+            BudgetCategory::Development,
+            recipient.clone(),
+            amount::<T>(),
+            max_description(),
+        )
+        .expect("director proposes");
+        let threshold = T::ApprovalThreshold::get() as usize;
+        for director in directors.iter().take(threshold - 1).skip(1) {
+            Pallet::<T>::approve_disbursement(RawOrigin::Signed(director.clone()).into(), 0)
+                .expect("director approves");
+        }
 
         #[extrinsic_call]
-        approve_disbursement(RawOrigin::Signed(director1.clone()), proposal_id);
+        _(RawOrigin::Signed(caller), 0);
 
-        // Verify approval was recorded
-        let updated_proposal = Proposals::<T>::get(proposal_id).unwrap();
-        assert!(updated_proposal.approvals.len() > 0);
+        assert_eq!(Disbursements::<T>::get(0).unwrap().status, DisbursementStatus::Executed);
+        assert_eq!(T::Currency::free_balance(&recipient), amount::<T>());
     }
 
     #[benchmark]
     fn emergency_withdrawal() {
-        let director1: T::AccountId = whitelisted_caller();
-        let director2: T::AccountId = account("director2", 0, 0);
-        let director3: T::AccountId = account("director3", 0, 0);
-        let director4: T::AccountId = account("director4", 0, 0);
-        let director5: T::AccountId = account("director5", 0, 0);
-        let director6: T::AccountId = account("director6", 0, 0);
-        let director7: T::AccountId = account("director7", 0, 0);
+        let caller = directors::<T>(MAX_DIRECTORS).pop().unwrap();
         let recipient: T::AccountId = account("recipient", 0, 0);
-        let amount: BalanceOf<T> = 5000u32.into();
-
-        // Setup: Create enough directors for emergency threshold (7-of-9)
-        let mut directors = BoundedVec::new();
-        directors.try_push(director1.clone()).ok();
-        directors.try_push(director2.clone()).ok();
-        directors.try_push(director3.clone()).ok();
-        directors.try_push(director4.clone()).ok();
-        directors.try_push(director5.clone()).ok();
-        directors.try_push(director6.clone()).ok();
-        directors.try_push(director7.clone()).ok();
-        Directors::<T>::put(directors);
-
-        // Fund treasury
-        let treasury_account = Pallet::<T>::account_id();
-        T::Currency::mint_into(&treasury_account, amount * 10u32.into()).unwrap();
-
-        // Create an emergency proposal
-        let proposal_id = ProposalCount::<T>::get();
-        let mut approvals = BoundedVec::new();
-        // Add 6 approvals (need 7 total with the benchmark call)
-        approvals.try_push(director2).ok();
-        approvals.try_push(director3).ok();
-        approvals.try_push(director4).ok();
-        approvals.try_push(director5).ok();
-        approvals.try_push(director6).ok();
-        approvals.try_push(director7).ok();
-
-        let proposal = DisbursementProposal {
-            proposer: director1.clone(),
-            recipient: recipient.clone(),
-            amount,
-            category: BudgetCategory::Emergency,
-            approvals,
-            status: ProposalStatus::Pending,
-            created_at: frame_system::Pallet::<T>::block_number(),
-        };
-        Proposals::<T>::insert(proposal_id, proposal);
-        ProposalCount::<T>::put(proposal_id + 1);
+        fund::<T>();
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller), recipient, amount::<T>(), max_description());
+
+        assert!(Disbursements::<T>::get(0).unwrap().is_emergency);
+    }
+
+    #[benchmark]
+    fn set_budget_allocations() {
+        let allocations = BudgetAllocations::default_allocations();
+
+        #[extrinsic_call]
+        _(RawOrigin::Root, allocations.clone());
+
+        assert_eq!(BudgetAllocationsStorage::<T>::get(), allocations);
+    }
+
+    #[benchmark]
+    fn allocate_to_categories() {
+        BudgetAllocationsStorage::<T>::put(BudgetAllocations::default_allocations());
+
+        #[extrinsic_call]
+        _(RawOrigin::Root, amount::<T>());
+
+        assert!(!EmergencyReserve::<T>::get().is_zero());
+    }
+
+    #[benchmark]
+    fn add_director() {
+        directors::<T>(MAX_DIRECTORS - 1);
+        let director: T::AccountId = account("director", MAX_DIRECTORS, 0);
+
+        #[extrinsic_call]
+        _(RawOrigin::Root, director.clone());
+
+        assert!(Pallet::<T>::is_director(&director));
+    }
+
+    #[benchmark]
+    fn remove_director() {
+        let director = directors::<T>(MAX_DIRECTORS).pop().unwrap();
 
         #[extrinsic_call]
-        emergency_withdrawal(RawOrigin::Signed(director1.clone()), proposal_id);
+        _(RawOrigin::Root, director.clone());
 
-        // Verify emergency withdrawal was executed
-        let updated_proposal = Proposals::<T>::get(proposal_id).unwrap();
-        assert_eq!(updated_proposal.status, ProposalStatus::Executed);
+        assert!(!Pallet::<T>::is_director(&director));
     }
 
-    impl_benchmark_test_suite!(
-        Pallet,
-        crate::mock::new_test_ext(),
-        crate::mock::Test
-    );
+    impl_benchmark_test_suite!(Pallet, crate::mock::new_test_ext(), crate::mock::Test);
 }
@@ -61,7 +61,7 @@
 //! ## Storage
 //!
 //! - `TreasuryBalance` - Total ËTR balance in treasury
-//! - `EdscBalance` - EDSC stablecoin balance
+//! - `EdscBalance` - EDSC stablecoin balance (liquidation penalties, bad-debt backstop)
 //! - `BudgetAllocations` - Category → allocated amount
 //! - `Disbursements` - Pending and historical disbursements
 //! - `DirectorApprovals` - Disbursement ID → list of approving directors
@@ -75,6 +75,13 @@ pub use pallet::*;
 
 #[cfg(feature = "runtime-benchmarks")]
 mod benchmarking;
+// Only the benchmark test suite runs against the mock so far; its helpers
+// are shared with the scenario tests in tests.rs.
+#[cfg(test)]
+#[allow(dead_code)]
+mod mock;
+pub mod weights;
+pub use weights::WeightInfo;
 
 // ═══════════════════════════════════════════════════════════════════════════════
 // Type Definitions (MUST be outside pallet module to avoid DecodeWithMemTracking issues)
@@ -188,11 +195,11 @@ pub mod pallet {
     use frame_support::traits::{Currency, ReservableCurrency, ExistenceRequirement};
     use frame_support::PalletId;
     use frame_system::pallet_prelude::*;
-    use frame_support::weights::constants::RocksDbWeight;
-    use sp_runtime::traits::{Saturating, AccountIdConversion};
+    use crate::WeightInfo;
+    use sp_runtime::traits::{Saturating, AccountIdConversion, Zero};
     use sp_std::vec::Vec;
 
-    type BalanceOf<T> =
+    pub type BalanceOf<T> =
         <<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
 
     /// Disbursement proposal details
@@ -221,38 +228,6 @@ pub mod pallet {
         pub is_emergency: bool,
     }
 
-    /// Weight functions for pallet_treasury.
-    pub trait WeightInfo {
-        fn fund_treasury() -> Weight;
-        fn propose_disbursement() -> Weight;
-        fn approve_disbursement() -> Weight;
-        fn emergency_withdrawal() -> Weight;
-    }
-
-    /// Conservative weight estimates for production safety.
-    impl WeightInfo for () {
-        fn fund_treasury() -> Weight {
-            Weight::from_parts(40_000_000, 0)
-                .saturating_add(RocksDbWeight::get().reads(1))
-                .saturating_add(RocksDbWeight::get().writes(1))
-        }
-        fn propose_disbursement() -> Weight {
-            Weight::from_parts(45_000_000, 0)
-                .saturating_add(RocksDbWeight::get().reads(2))
-                .saturating_add(RocksDbWeight::get().writes(1))
-        }
-        fn approve_disbursement() -> Weight {
-            Weight::from_parts(50_000_000, 0)
-                .saturating_add(RocksDbWeight::get().reads(3))
-                .saturating_add(RocksDbWeight::get().writes(2))
-        }
-        fn emergency_withdrawal() -> Weight {
-            Weight::from_parts(60_000_000, 0)
-                .saturating_add(RocksDbWeight::get().reads(3))
-                .saturating_add(RocksDbWeight::get().writes(2))
-        }
-    }
-
     #[pallet::config]
     pub trait Config: frame_system::Config {
         /// Because this pallet emits events, it depends on the runtime's definition of an event.
@@ -404,6 +379,10 @@ pub mod pallet {
         DirectorRemoved(T::AccountId),
         /// Category allocation increased [category, amount]
         CategoryAllocationIncreased(BudgetCategory, BalanceOf<T>),
+        /// EDSC liquidation penalties received [amount]
+        EdscDeposited(BalanceOf<T>),
+        /// EDSC released to cover liquidation bad debt [amount]
+        EdscBadDebtCovered(BalanceOf<T>),
     }
 
     #[pallet::error]
@@ -452,7 +431,7 @@ pub mod pallet {
         /// - pallet-validator-rewards (50% of slashing penalties)
         /// - Bridge pallets (10% of cross-chain fees)
         #[pallet::call_index(0)]
-        #[pallet::weight(10_000)]
+        #[pallet::weight(T::WeightInfo::fund_treasury())]
         pub fn fund_treasury(
             origin: OriginFor<T>,
             source: FundingSource,
@@ -484,7 +463,7 @@ pub mod pallet {
         ///
         /// Must be called by one of the 9 directors
         #[pallet::call_index(1)]
-        #[pallet::weight(50_000)]
+        #[pallet::weight(T::WeightInfo::propose_disbursement())]
         pub fn propose_disbursement(
             origin: OriginFor<T>,
             category: BudgetCategory,
@@ -551,7 +530,7 @@ pub mod pallet {
         ///
         /// Need 6-of-9 approvals for normal disbursements, 7-of-9 for emergency
         #[pallet::call_index(2)]
-        #[pallet::weight(30_000)]
+        #[pallet::weight(T::WeightInfo::approve_disbursement())]
         pub fn approve_disbursement(
             origin: OriginFor<T>,
             disbursement_id: u64,
@@ -616,7 +595,7 @@ pub mod pallet {
         ///
         /// Requires 7-of-9 director approvals (higher threshold)
         #[pallet::call_index(3)]
-        #[pallet::weight(50_000)]
+        #[pallet::weight(T::WeightInfo::emergency_withdrawal())]
         pub fn emergency_withdrawal(
             origin: OriginFor<T>,
             recipient: T::AccountId,
@@ -678,7 +657,7 @@ pub mod pallet {
         ///
         /// Can only be called during Consensus Day governance
         #[pallet::call_index(4)]
-        #[pallet::weight(20_000)]
+        #[pallet::weight(T::WeightInfo::set_budget_allocations())]
         pub fn set_budget_allocations(
             origin: OriginFor<T>,
             allocations: BudgetAllocations,
@@ -701,7 +680,7 @@ pub mod pallet {
         ///
         /// Called during Consensus Day after minting
         #[pallet::call_index(5)]
-        #[pallet::weight(30_000)]
+        #[pallet::weight(T::WeightInfo::allocate_to_categories())]
         pub fn allocate_to_categories(
             origin: OriginFor<T>,
             total_amount: BalanceOf<T>,
@@ -748,7 +727,7 @@ pub mod pallet {
 
         /// Add a new director (governance only)
         #[pallet::call_index(6)]
-        #[pallet::weight(10_000)]
+        #[pallet::weight(T::WeightInfo::add_director())]
         pub fn add_director(
             origin: OriginFor<T>,
             director: T::AccountId,
@@ -771,7 +750,7 @@ pub mod pallet {
 
         /// Remove a director (governance only)
         #[pallet::call_index(7)]
-        #[pallet::weight(10_000)]
+        #[pallet::weight(T::WeightInfo::remove_director())]
         pub fn remove_director(
             origin: OriginFor<T>,
             director: T::AccountId,
@@ -972,5 +951,38 @@ pub mod pallet {
 
             Ok(())
         }
+
+        /// Record EDSC liquidation penalties paid to the treasury
+        ///
+        /// The EDSC itself stays on pallet-edsc-stability's ledger; this tracks
+        /// the treasury's share of it.
+        pub fn receive_edsc_penalties(amount: BalanceOf<T>) -> DispatchResult {
+            EdscBalance::<T>::mutate(|balance| {
+                *balance = balance.saturating_add(amount);
+            });
+
+            Self::deposit_event(Event::EdscDeposited(amount));
+
+            Ok(())
+        }
+
+        /// Release treasury EDSC to cover liquidation bad debt (backstop)
+        ///
+        /// Called by pallet-edsc-stability when an auction leaves debt uncovered.
+        /// Returns the amount released, at most the treasury's EDSC balance.
+        pub fn cover_edsc_bad_debt(amount: BalanceOf<T>) -> BalanceOf<T> {
+            let covered = amount.min(EdscBalance::<T>::get());
+            if covered.is_zero() {
+                return covered;
+            }
+
+            EdscBalance::<T>::mutate(|balance| {
+                *balance = balance.saturating_sub(covered);
+            });
+
+            Self::deposit_event(Event::EdscBadDebtCovered(covered));
+
+            covered
+        }
     }
 }
@@ -1,7 +1,7 @@
 use crate as pallet_treasury;
 use frame_support::{
-	parameter_types,
-	traits::{ConstU32, ConstU64, ConstU16, Hooks},
+	derive_impl, parameter_types,
+	traits::{ConstU32, Hooks},
 	PalletId,
 };
 use sp_core::H256;
@@ -62,24 +62,13 @@ impl frame_system::Config for Test {
 
 parameter_types! {
 	pub const ExistentialDeposit: Balance = 1;
-	pub const MaxLocks: u32 = 50;
-	pub const MaxReserves: u32 = 50;
 }
 
+#[derive_impl(pallet_balances::config_preludes::TestDefaultConfig)]
 impl pallet_balances::Config for Test {
-	type MaxLocks = MaxLocks;
-	type MaxReserves = MaxReserves;
-	type ReserveIdentifier = [u8; 8];
 	type Balance = Balance;
-	type RuntimeEvent = RuntimeEvent;
-	type DustRemoval = ();
 	type ExistentialDeposit = ExistentialDeposit;
 	type AccountStore = System;
-	type WeightInfo = ();
-	type FreezeIdentifier = ();
-	type MaxFreezes = ();
-	type RuntimeHoldReason = ();
-	type RuntimeFreezeReason = ();
 }
 
 parameter_types! {
@@ -97,6 +86,7 @@ impl pallet_treasury::Config for Test {
 	type ApprovalThreshold = ApprovalThreshold;
 	type EmergencyThreshold = EmergencyThreshold;
 	type ProposalExpiration = ProposalExpiration;
+	type WeightInfo = ();
 }
 
 // Build genesis storage according to the mock runtime.
@@ -394,3 +394,24 @@ fn helper_methods_work() {
 		);
 	});
 }
+
+#[test]
+fn edsc_bad_debt_backstop_works() {
+	new_test_ext().execute_with(|| {
+		// Nothing to release before any penalties arrive
+		assert_eq!(Treasury::cover_edsc_bad_debt(100 * ETR), 0);
+
+		assert_ok!(Treasury::receive_edsc_penalties(300 * ETR));
+		assert_eq!(Treasury::edsc_balance(), 300 * ETR);
+		System::assert_last_event(Event::EdscDeposited(300 * ETR).into());
+
+		// Partial cover leaves the rest
+		assert_eq!(Treasury::cover_edsc_bad_debt(100 * ETR), 100 * ETR);
+		assert_eq!(Treasury::edsc_balance(), 200 * ETR);
+		System::assert_last_event(Event::EdscBadDebtCovered(100 * ETR).into());
+
+		// Cover is capped at the EDSC held
+		assert_eq!(Treasury::cover_edsc_bad_debt(500 * ETR), 200 * ETR);
+		assert_eq!(Treasury::edsc_balance(), 0);
+	});
+}
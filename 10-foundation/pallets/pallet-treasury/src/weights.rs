@@ -0,0 +1,162 @@
+//! PLACEHOLDER weights for `pallet_treasury`: hand-estimated, not benchmark output.
+//!
+//! Overwrite with `./scripts/benchmark-pallets.sh pallet_treasury` on reference hardware.
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(missing_docs)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use core::marker::PhantomData;
+
+/// Weight functions needed for `pallet_treasury`.
+pub trait WeightInfo {
+	fn fund_treasury() -> Weight;
+	fn propose_disbursement() -> Weight;
+	fn approve_disbursement() -> Weight;
+	fn emergency_withdrawal() -> Weight;
+	fn set_budget_allocations() -> Weight;
+	fn allocate_to_categories() -> Weight;
+	fn add_director() -> Weight;
+	fn remove_director() -> Weight;
+}
+
+/// Placeholder weights for `pallet_treasury`, pending benchmarking.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Storage: `EtridTreasury::TreasuryBalance` (r:1 w:1)
+	/// Storage: `EtridTreasury::FundingSourceTotals` (r:1 w:1)
+	fn fund_treasury() -> Weight {
+		Weight::from_parts(36_540_000, 3593)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: `EtridTreasury::Directors` (r:1 w:0)
+	/// Storage: `EtridTreasury::CategoryAllocations` (r:1 w:0)
+	/// Storage: `EtridTreasury::TreasuryBalance` (r:1 w:0)
+	/// Storage: `EtridTreasury::DisbursementCount` (r:1 w:1)
+	/// Storage: `EtridTreasury::DirectorApprovals` (r:1 w:1)
+	/// Storage: `EtridTreasury::Disbursements` (r:0 w:1)
+	fn propose_disbursement() -> Weight {
+		Weight::from_parts(41_280_000, 4178)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: `EtridTreasury::Directors` (r:1 w:0)
+	/// Storage: `EtridTreasury::Disbursements` (r:2 w:2)
+	/// Storage: `EtridTreasury::DirectorApprovals` (r:1 w:1)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Storage: `EtridTreasury::TreasuryBalance` (r:1 w:1)
+	/// Storage: `EtridTreasury::CategoryAllocations` (r:1 w:1)
+	fn approve_disbursement() -> Weight {
+		Weight::from_parts(97_610_000, 6196)
+			.saturating_add(T::DbWeight::get().reads(8_u64))
+			.saturating_add(T::DbWeight::get().writes(7_u64))
+	}
+	/// Storage: `EtridTreasury::Directors` (r:1 w:0)
+	/// Storage: `EtridTreasury::EmergencyReserve` (r:1 w:0)
+	/// Storage: `EtridTreasury::DisbursementCount` (r:1 w:1)
+	/// Storage: `EtridTreasury::DirectorApprovals` (r:1 w:1)
+	/// Storage: `EtridTreasury::Disbursements` (r:0 w:1)
+	fn emergency_withdrawal() -> Weight {
+		Weight::from_parts(39_720_000, 4178)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: `EtridTreasury::BudgetAllocationsStorage` (r:0 w:1)
+	fn set_budget_allocations() -> Weight {
+		Weight::from_parts(10_930_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `EtridTreasury::BudgetAllocationsStorage` (r:1 w:0)
+	/// Storage: `EtridTreasury::CategoryAllocations` (r:4 w:4)
+	/// Storage: `EtridTreasury::EmergencyReserve` (r:1 w:1)
+	fn allocate_to_categories() -> Weight {
+		Weight::from_parts(42_350_000, 11204)
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+	/// Storage: `EtridTreasury::Directors` (r:1 w:1)
+	fn add_director() -> Weight {
+		Weight::from_parts(15_480_000, 1803)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `EtridTreasury::Directors` (r:1 w:1)
+	fn remove_director() -> Weight {
+		Weight::from_parts(15_120_000, 1803)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+}
+
+// For backwards compatibility and tests.
+impl WeightInfo for () {
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Storage: `EtridTreasury::TreasuryBalance` (r:1 w:1)
+	/// Storage: `EtridTreasury::FundingSourceTotals` (r:1 w:1)
+	fn fund_treasury() -> Weight {
+		Weight::from_parts(36_540_000, 3593)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: `EtridTreasury::Directors` (r:1 w:0)
+	/// Storage: `EtridTreasury::CategoryAllocations` (r:1 w:0)
+	/// Storage: `EtridTreasury::TreasuryBalance` (r:1 w:0)
+	/// Storage: `EtridTreasury::DisbursementCount` (r:1 w:1)
+	/// Storage: `EtridTreasury::DirectorApprovals` (r:1 w:1)
+	/// Storage: `EtridTreasury::Disbursements` (r:0 w:1)
+	fn propose_disbursement() -> Weight {
+		Weight::from_parts(41_280_000, 4178)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: `EtridTreasury::Directors` (r:1 w:0)
+	/// Storage: `EtridTreasury::Disbursements` (r:2 w:2)
+	/// Storage: `EtridTreasury::DirectorApprovals` (r:1 w:1)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Storage: `EtridTreasury::TreasuryBalance` (r:1 w:1)
+	/// Storage: `EtridTreasury::CategoryAllocations` (r:1 w:1)
+	fn approve_disbursement() -> Weight {
+		Weight::from_parts(97_610_000, 6196)
+			.saturating_add(RocksDbWeight::get().reads(8_u64))
+			.saturating_add(RocksDbWeight::get().writes(7_u64))
+	}
+	/// Storage: `EtridTreasury::Directors` (r:1 w:0)
+	/// Storage: `EtridTreasury::EmergencyReserve` (r:1 w:0)
+	/// Storage: `EtridTreasury::DisbursementCount` (r:1 w:1)
+	/// Storage: `EtridTreasury::DirectorApprovals` (r:1 w:1)
+	/// Storage: `EtridTreasury::Disbursements` (r:0 w:1)
+	fn emergency_withdrawal() -> Weight {
+		Weight::from_parts(39_720_000, 4178)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: `EtridTreasury::BudgetAllocationsStorage` (r:0 w:1)
+	fn set_budget_allocations() -> Weight {
+		Weight::from_parts(10_930_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `EtridTreasury::BudgetAllocationsStorage` (r:1 w:0)
+	/// Storage: `EtridTreasury::CategoryAllocations` (r:4 w:4)
+	/// Storage: `EtridTreasury::EmergencyReserve` (r:1 w:1)
+	fn allocate_to_categories() -> Weight {
+		Weight::from_parts(42_350_000, 11204)
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
+	/// Storage: `EtridTreasury::Directors` (r:1 w:1)
+	fn add_director() -> Weight {
+		Weight::from_parts(15_480_000, 1803)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `EtridTreasury::Directors` (r:1 w:1)
+	fn remove_director() -> Weight {
+		Weight::from_parts(15_120_000, 1803)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+}
@@ -0,0 +1,44 @@
+//! Benchmarking setup for consensus-day-distribution
+//!
+//! Recipients come from runtime constants, so the benchmark pays generated voter
+//! accounts through the same code path `execute_distribution` uses.
+
+#![cfg(feature = "runtime-benchmarks")]
+
+use super::*;
+use frame_benchmarking::v2::*;
+use frame_support::assert_ok;
+use sp_runtime::traits::Saturating;
+use sp_std::vec::Vec;
+
+const SEED: u32 = 0;
+
+/// Recipients covered by the benchmark
+const MAX_RECIPIENTS: u32 = 1_000;
+
+#[benchmarks]
+mod benchmarks {
+    use super::*;
+
+    #[benchmark]
+    fn execute_distribution(r: Linear<0, MAX_RECIPIENTS>) {
+        let foundation = T::FoundationAccount::get();
+        let funds = T::Currency::minimum_balance().saturating_mul(1_000_000u32.into());
+        T::Currency::make_free_balance_be(&foundation, funds);
+
+        let voters: Vec<T::AccountId> = (0..r)
+            .map(|i| {
+                let voter: T::AccountId = account("voter", i, SEED);
+                T::Currency::make_free_balance_be(&voter, T::Currency::minimum_balance());
+                voter
+            })
+            .collect();
+
+        #[block]
+        {
+            assert_ok!(Pallet::<T>::distribute(1, Vec::new(), Vec::new(), voters));
+        }
+
+        assert!(Distributions::<T>::contains_key(1));
+    }
+}
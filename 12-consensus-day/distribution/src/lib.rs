@@ -7,6 +7,12 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 
 pub use pallet::*;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
+pub mod weights;
+pub use weights::WeightInfo;
 use frame_support::{
     pallet_prelude::*,
     traits::{Currency, ExistenceRequirement::KeepAlive, Get},
@@ -51,6 +57,8 @@ pub mod pallet {
         /// List of active voter accounts (from Consensus Day registration)
         #[pallet::constant]
         type Voters: Get<Vec<Self::AccountId>>;
+        /// Weight information for extrinsics
+        type WeightInfo: WeightInfo;
     }
 
     /// Record of each completed distribution
@@ -95,10 +103,26 @@ pub mod pallet {
         /// NOTE: Mint record validation is stubbed out for compilation. Full implementation
         /// will require runtime integration with consensus-day-minting-logic.
         #[pallet::call_index(0)]
-        #[pallet::weight(30_000)]
+        #[pallet::weight(T::WeightInfo::execute_distribution(Pallet::<T>::recipient_count()))]
         pub fn execute_distribution(origin: OriginFor<T>, mint_id: u64) -> DispatchResult {
             ensure_root(origin)?;
+            Self::distribute(mint_id, T::Directors::get(), T::Validators::get(), T::Voters::get())
+        }
+    }
+
+    impl<T: Config> Pallet<T> {
+        /// Directors, validators and voters paid by one distribution
+        pub fn recipient_count() -> u32 {
+            (T::Directors::get().len() + T::Validators::get().len() + T::Voters::get().len()) as u32
+        }
 
+        /// Pay out a distribution to the given recipient groups
+        pub(crate) fn distribute(
+            mint_id: u64,
+            directors: Vec<T::AccountId>,
+            validators: Vec<T::AccountId>,
+            voters: Vec<T::AccountId>,
+        ) -> DispatchResult {
             // TODO: Integrate with consensus-day-minting-logic once runtime is configured
             // Stub validation - will be implemented when runtime has proper dependencies
 
@@ -128,7 +152,6 @@ pub mod pallet {
             Self::deposit_event(Event::<T>::RecipientPaid(foundation.clone(), foundation_amt));
 
             // Split and pay Directors
-            let directors = T::Directors::get();
             if !directors.is_empty() {
                 let per_director = directors_amt / BalanceOf::<T>::from(directors.len() as u32);
                 for acct in directors.iter() {
@@ -139,7 +162,6 @@ pub mod pallet {
             }
 
             // Split and pay Validators
-            let validators = T::Validators::get();
             if !validators.is_empty() {
                 let per_validator = validators_amt / BalanceOf::<T>::from(validators.len() as u32);
                 for acct in validators.iter() {
@@ -150,7 +172,6 @@ pub mod pallet {
             }
 
             // Split and pay Registered Voters
-            let voters = T::Voters::get();
             if !voters.is_empty() {
                 let per_voter = voters_amt / BalanceOf::<T>::from(voters.len() as u32);
                 for acct in voters.iter() {
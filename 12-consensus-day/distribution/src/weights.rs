@@ -0,0 +1,49 @@
+//! PLACEHOLDER weights for `consensus_day_distribution`: hand-estimated, not benchmark output.
+//!
+//! Overwrite with `./scripts/benchmark-pallets.sh consensus_day_distribution` on reference hardware.
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(missing_docs)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use core::marker::PhantomData;
+
+/// Weight functions needed for `consensus_day_distribution`.
+pub trait WeightInfo {
+	fn execute_distribution(r: u32, ) -> Weight;
+}
+
+/// Placeholder weights for `consensus_day_distribution`, pending benchmarking.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	/// Storage: `ConsensusDayDistribution::Distributions` (r:1 w:1)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// The range of component `r` is `[0, 1000]`.
+	fn execute_distribution(r: u32, ) -> Weight {
+		Weight::from_parts(48_260_000, 6117)
+			.saturating_add(Weight::from_parts(31_740_000, 0).saturating_mul(r.into()))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(r.into())))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(r.into())))
+			.saturating_add(Weight::from_parts(0, 2603).saturating_mul(r.into()))
+	}
+}
+
+// For backwards compatibility and tests.
+impl WeightInfo for () {
+	/// Storage: `ConsensusDayDistribution::Distributions` (r:1 w:1)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// The range of component `r` is `[0, 1000]`.
+	fn execute_distribution(r: u32, ) -> Weight {
+		Weight::from_parts(48_260_000, 6117)
+			.saturating_add(Weight::from_parts(31_740_000, 0).saturating_mul(r.into()))
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(r.into())))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(r.into())))
+			.saturating_add(Weight::from_parts(0, 2603).saturating_mul(r.into()))
+	}
+}
@@ -0,0 +1,48 @@
+//! Benchmarking setup for consensus-day-minting-logic
+
+#![cfg(feature = "runtime-benchmarks")]
+
+use super::*;
+use frame_benchmarking::v2::*;
+use frame_system::RawOrigin;
+
+/// Mint amount used by the benchmarks
+fn amount<T: Config>() -> BalanceOf<T> {
+    T::Currency::minimum_balance().max(1u32.into()) * 1_000u32.into()
+}
+
+#[benchmarks]
+mod benchmarks {
+    use super::*;
+
+    #[benchmark]
+    fn schedule_mint() {
+        let caller: T::AccountId = whitelisted_caller();
+        // Minting is capped relative to what was already minted this year
+        AnnualMinted::<T>::put(amount::<T>());
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller), 0, amount::<T>());
+
+        assert!(MintEvents::<T>::contains_key(0));
+    }
+
+    #[benchmark]
+    fn execute_mint() {
+        let caller: T::AccountId = whitelisted_caller();
+        AnnualMinted::<T>::put(amount::<T>());
+        MintEvents::<T>::insert(0, MintRecord {
+            id: 0,
+            proposer: caller,
+            amount: amount::<T>(),
+            executed: false,
+            timestamp: 0,
+        });
+        NextMintId::<T>::put(1);
+
+        #[extrinsic_call]
+        _(RawOrigin::Root, 0);
+
+        assert!(MintEvents::<T>::get(0).unwrap().executed);
+    }
+}
@@ -7,6 +7,12 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 
 pub use pallet::*;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
+pub mod weights;
+pub use weights::WeightInfo;
 use frame_support::{
     pallet_prelude::*,
     traits::Currency,
@@ -18,7 +24,7 @@ use sp_runtime::traits::UniqueSaturatedInto;
 pub mod pallet {
     use super::*;
 
-    type BalanceOf<T> =
+    pub(crate) type BalanceOf<T> =
         <<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
 
     #[pallet::config]
@@ -36,6 +42,9 @@ pub mod pallet {
         /// Maximum mintable percentage of total supply per year (e.g., 5%)
         #[pallet::constant]
         type AnnualMintCapPercent: Get<u8>;
+
+        /// Weight information for extrinsics
+        type WeightInfo: WeightInfo;
     }
 
     /// Record of each minting event.
@@ -97,7 +106,7 @@ pub mod pallet {
         /// NOTE: Proposal validation is stubbed out for compilation. Full implementation
         /// will require runtime integration with consensus-day-proposal-system.
         #[pallet::call_index(0)]
-        #[pallet::weight(10_000)]
+        #[pallet::weight(T::WeightInfo::schedule_mint())]
         pub fn schedule_mint(
             origin: OriginFor<T>,
             _proposal_id: u64,
@@ -139,7 +148,7 @@ pub mod pallet {
 
         /// Execute a scheduled mint, transferring funds to the Treasury account.
         #[pallet::call_index(1)]
-        #[pallet::weight(20_000)]
+        #[pallet::weight(T::WeightInfo::execute_mint())]
         pub fn execute_mint(origin: OriginFor<T>, mint_id: u64) -> DispatchResult {
             ensure_root(origin)?;
             let mut record =
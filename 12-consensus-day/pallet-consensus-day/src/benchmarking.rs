@@ -0,0 +1,462 @@
+//! Benchmarking setup for pallet-consensus-day
+//!
+//! Each `advance_phase` transition is benchmarked separately, with proposals up
+//! to `MaxProposals`, voters and ballots up to `MaxVoters`, candidates up to
+//! `MaxDirectorCandidates` and commitments up to `MaxCommitments`.
+//! The reveal and distribution transitions iterate whole maps, so their proof
+//! size is measured rather than taken from `MaxEncodedLen`.
+
+#![cfg(feature = "runtime-benchmarks")]
+
+use super::*;
+use frame_benchmarking::v2::*;
+use frame_support::{
+    assert_ok,
+    traits::{Currency, Get, ReservableCurrency},
+    BoundedVec,
+};
+use frame_system::RawOrigin;
+use pallet_vote_delegation::{DelegationHooks, CONSENSUS_DAY_TRACK};
+use sp_core::H256;
+use sp_runtime::traits::{Bounded, Saturating};
+use sp_std::{vec, vec::Vec};
+
+const SEED: u32 = 0;
+
+/// Voting power given to benchmark voters
+const POWER: u128 = 1_000_000;
+
+/// Block far enough past every phase start for any phase duration to have elapsed
+fn late_block<T: Config>() -> BlockNumberFor<T> {
+    10_000_000u32.into()
+}
+
+fn set_phase<T: Config>(phase: Phase) {
+    frame_system::Pallet::<T>::set_block_number(late_block::<T>());
+    ConsensusDayState::<T>::put(ConsensusDayInfo::<T> {
+        phase,
+        phase_start_block: 1u32.into(),
+        event_start_block: 1u32.into(),
+        year: 1,
+    });
+}
+
+/// Account holding enough for a proposal bond and a director stake
+fn funded<T: Config>(name: &'static str, index: u32) -> T::AccountId {
+    let who: T::AccountId = account(name, index, SEED);
+    let amount = T::ProposalBond::get()
+        .saturating_add(T::DirectorMinStake::get())
+        .saturating_add(T::Currency::minimum_balance())
+        .saturating_mul(10u32.into());
+    T::Currency::make_free_balance_be(&who, amount);
+    who
+}
+
+/// Insert `p` budget proposals from distinct proposers with their bonds reserved
+fn add_proposals<T: Config>(p: u32, approved: bool) {
+    let bond = T::ProposalBond::get();
+    let title: BoundedVec<u8, T::MaxTitleLength> =
+        vec![b't'; T::MaxTitleLength::get() as usize].try_into().expect("max length; qed");
+    for id in 0..p {
+        let proposer = funded::<T>("proposer", id);
+        assert_ok!(T::Currency::reserve(&proposer, bond));
+        Proposals::<T>::insert(id as u64, Proposal {
+            id: id as u64,
+            proposer,
+            title: title.clone(),
+            category: ProposalCategory::BudgetAllocation,
+            budget_request: T::Currency::minimum_balance(),
+            budget_category: Some(BudgetCategory::CommunityGrants),
+            bond,
+            yes_votes: 0,
+            no_votes: 0,
+            abstain_votes: 0,
+            validator_count: 0,
+            approved,
+            executed: false,
+        });
+    }
+    NextProposalId::<T>::put(p as u64);
+    // Large enough that every budget fits the inflation cap and quorum is never met
+    CirculatingSupply::<T>::put(BalanceOf::<T>::max_value() / 2u32.into());
+    ActiveValidatorCount::<T>::put(u32::MAX);
+}
+
+/// Give `who` stake-based voting power
+fn add_voter<T: Config>(who: &T::AccountId) {
+    VotingPowerMap::<T>::insert(who, VotingPowerInfo::<T> {
+        staked_amount: T::Currency::minimum_balance(),
+        stake_locked_at: 1u32.into(),
+        participation_history: 1,
+        voting_power: POWER,
+    });
+    VoterCount::<T>::mutate(|count| *count = count.saturating_add(1));
+}
+
+/// Nominate `c` director candidates
+fn add_candidates<T: Config>(c: u32) -> Vec<T::AccountId> {
+    let candidates: Vec<T::AccountId> = (0..c)
+        .map(|i| {
+            let who: T::AccountId = account("candidate", i, SEED);
+            DirectorCandidates::<T>::insert(&who, DirectorCandidate {
+                account: who.clone(),
+                stake: T::DirectorMinStake::get(),
+                votes: 0,
+            });
+            who
+        })
+        .collect();
+    CandidateCount::<T>::put(c);
+    candidates
+}
+
+/// Record `b` director ballots, each approving up to nine candidates
+fn add_ballots<T: Config>(b: u32, candidates: &[T::AccountId]) -> Vec<T::AccountId> {
+    let seats = (DIRECTOR_SEATS as usize).min(candidates.len());
+    DirectorBallotCount::<T>::put(b);
+    (0..b)
+        .map(|i| {
+            let voter: T::AccountId = account("ballot", i, SEED);
+            let approved: Vec<T::AccountId> = (0..seats)
+                .map(|k| candidates[(i as usize + k) % candidates.len()].clone())
+                .collect();
+            DirectorApprovals::<T>::insert(&voter, DirectorBallot {
+                weight: POWER,
+                candidates: approved.try_into().expect("at most nine; qed"),
+            });
+            voter
+        })
+        .collect()
+}
+
+/// Insert `c` unrevealed commitments on proposal 0
+fn add_commitments<T: Config>(c: u32) {
+    for i in 0..c {
+        let voter: T::AccountId = account("committer", i, SEED);
+        Commitments::<T>::insert(&voter, 0u64, H256::repeat_byte(1));
+    }
+    CommitmentCount::<T>::put(c);
+}
+
+#[benchmarks]
+mod benchmarks {
+    use super::*;
+
+    #[benchmark]
+    fn start_consensus_day() {
+        #[extrinsic_call]
+        _(RawOrigin::Root);
+
+        assert_eq!(ConsensusDayState::<T>::get().phase, Phase::Registration);
+    }
+
+    /// Registration to Voting, or Voting to Reveal in secret ballot mode
+    #[benchmark]
+    fn advance_phase() {
+        let caller: T::AccountId = whitelisted_caller();
+        set_phase::<T>(Phase::Registration);
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller));
+
+        assert_eq!(ConsensusDayState::<T>::get().phase, Phase::Voting);
+    }
+
+    /// Voting to Minting: every proposal misses quorum and has its bond slashed
+    #[benchmark]
+    fn advance_phase_finalize(p: Linear<0, { T::MaxProposals::get() }>) {
+        let caller: T::AccountId = whitelisted_caller();
+        add_proposals::<T>(p, false);
+        set_phase::<T>(Phase::Voting);
+
+        #[extrinsic_call]
+        advance_phase(RawOrigin::Signed(caller));
+
+        assert_eq!(ConsensusDayState::<T>::get().phase, Phase::Minting);
+    }
+
+    /// Reveal to Minting: `c` commitments expire before voting is finalized
+    #[benchmark(pov_mode = Measured)]
+    fn advance_phase_reveal(
+        p: Linear<0, { T::MaxProposals::get() }>,
+        c: Linear<0, { T::MaxCommitments::get() }>,
+    ) {
+        let caller: T::AccountId = whitelisted_caller();
+        add_proposals::<T>(p, false);
+        add_commitments::<T>(c);
+        SecretBallots::<T>::put(true);
+        set_phase::<T>(Phase::Reveal);
+
+        #[extrinsic_call]
+        advance_phase(RawOrigin::Signed(caller));
+
+        assert_eq!(CommitmentCount::<T>::get(), 0);
+        assert_eq!(UnrevealedBallots::<T>::iter().count() as u32, c);
+    }
+
+    /// Minting to Distribution: every proposal is an approved budget within the cap
+    #[benchmark]
+    fn advance_phase_minting(p: Linear<0, { T::MaxProposals::get() }>) {
+        let caller: T::AccountId = whitelisted_caller();
+        add_proposals::<T>(p, true);
+        VotedInflationRate::<T>::put(300);
+        set_phase::<T>(Phase::Minting);
+
+        #[extrinsic_call]
+        advance_phase(RawOrigin::Signed(caller));
+
+        assert_eq!(ConsensusDayState::<T>::get().phase, Phase::Distribution);
+    }
+
+    /// Distribution to Inactive: rewards for `v` voters, `u` forfeits and an on-chain
+    /// election over `b` ballots and `c` candidates
+    #[benchmark(pov_mode = Measured)]
+    fn advance_phase_distribution(
+        v: Linear<0, { T::MaxVoters::get() }>,
+        u: Linear<0, { T::MaxCommitments::get() }>,
+        b: Linear<0, { T::MaxVoters::get() }>,
+        c: Linear<1, { T::MaxDirectorCandidates::get() }>,
+    ) {
+        let caller: T::AccountId = whitelisted_caller();
+        for i in 0..v {
+            let voter: T::AccountId = account("voter", i, SEED);
+            add_voter::<T>(&voter);
+            ProposalsVoted::<T>::insert(&voter, 1);
+        }
+        for i in 0..u {
+            let voter: T::AccountId = account("committer", i, SEED);
+            UnrevealedBallots::<T>::insert(&voter, 1);
+        }
+        let candidates = add_candidates::<T>(c);
+        add_ballots::<T>(b, &candidates);
+        TotalMinted::<T>::put(BalanceOf::<T>::max_value() / 2u32.into());
+        set_phase::<T>(Phase::Distribution);
+
+        #[extrinsic_call]
+        advance_phase(RawOrigin::Signed(caller));
+
+        assert_eq!(ConsensusDayState::<T>::get().phase, Phase::Inactive);
+        assert_eq!(DirectorBallotCount::<T>::get(), 0);
+    }
+
+    #[benchmark]
+    fn submit_proposal() {
+        let caller = funded::<T>("proposer", 0);
+        add_proposals::<T>(T::MaxProposals::get().saturating_sub(1), false);
+        set_phase::<T>(Phase::Registration);
+        let title = vec![b't'; T::MaxTitleLength::get() as usize];
+
+        #[extrinsic_call]
+        _(
+            RawOrigin::Signed(caller),
+            title,
+            ProposalCategory::BudgetAllocation,
+            T::Currency::minimum_balance(),
+            Some(BudgetCategory::Research),
+        );
+
+        assert_eq!(NextProposalId::<T>::get(), T::MaxProposals::get() as u64);
+    }
+
+    #[benchmark]
+    fn vote() {
+        let caller: T::AccountId = whitelisted_caller();
+        add_voter::<T>(&caller);
+        Validators::<T>::insert(&caller, true);
+        add_proposals::<T>(1, false);
+        set_phase::<T>(Phase::Voting);
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller.clone()), 0, VoteType::Yes);
+
+        assert!(Votes::<T>::contains_key(&caller, 0));
+    }
+
+    #[benchmark]
+    fn set_secret_ballots() {
+        set_phase::<T>(Phase::Registration);
+
+        #[extrinsic_call]
+        _(RawOrigin::Root, true);
+
+        assert!(SecretBallots::<T>::get());
+    }
+
+    #[benchmark]
+    fn commit_vote() {
+        let caller: T::AccountId = whitelisted_caller();
+        add_proposals::<T>(1, false);
+        CommitmentCount::<T>::put(T::MaxCommitments::get().saturating_sub(1));
+        SecretBallots::<T>::put(true);
+        set_phase::<T>(Phase::Voting);
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller.clone()), 0, H256::repeat_byte(1));
+
+        assert!(Commitments::<T>::contains_key(&caller, 0));
+    }
+
+    #[benchmark]
+    fn reveal_vote() {
+        let caller: T::AccountId = whitelisted_caller();
+        add_voter::<T>(&caller);
+        Validators::<T>::insert(&caller, true);
+        add_proposals::<T>(1, false);
+        let salt = [7u8; 32];
+        let commitment = Pallet::<T>::ballot_commitment(&caller, 0, VoteType::No, &salt);
+        Commitments::<T>::insert(&caller, 0u64, commitment);
+        CommitmentCount::<T>::put(1);
+        SecretBallots::<T>::put(true);
+        set_phase::<T>(Phase::Reveal);
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller.clone()), 0, VoteType::No, salt);
+
+        assert!(Votes::<T>::contains_key(&caller, 0));
+        assert_eq!(CommitmentCount::<T>::get(), 0);
+    }
+
+    /// `a` assignments checked against `b` director ballots
+    #[benchmark]
+    fn submit_director_election(
+        a: Linear<1, { T::MaxVoters::get() }>,
+        b: Linear<1, { T::MaxVoters::get() }>,
+    ) {
+        let caller: T::AccountId = whitelisted_caller();
+        let mut winners = add_candidates::<T>(DIRECTOR_SEATS);
+        winners.sort();
+        let voters = add_ballots::<T>(a.max(b), &winners);
+        let share = POWER / DIRECTOR_SEATS as u128;
+        let assignments: Vec<election::Assignment<T::AccountId>> = voters
+            .into_iter()
+            .take(a as usize)
+            .map(|who| (who, winners.iter().map(|w| (w.clone(), share)).collect()))
+            .collect();
+        set_phase::<T>(Phase::Minting);
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller), winners, assignments);
+
+        assert!(QueuedDirectorElection::<T>::get().is_some());
+    }
+
+    #[benchmark]
+    fn lock_stake_for_voting() {
+        let caller = funded::<T>("staker", 0);
+        VoterCount::<T>::put(T::MaxVoters::get().saturating_sub(1));
+        set_phase::<T>(Phase::Registration);
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller.clone()), T::Currency::minimum_balance());
+
+        assert_eq!(VoterCount::<T>::get(), T::MaxVoters::get());
+    }
+
+    #[benchmark]
+    fn claim_participation_reward() {
+        let caller: T::AccountId = whitelisted_caller();
+        let reward = T::Currency::minimum_balance().saturating_mul(10u32.into());
+        T::Currency::make_free_balance_be(
+            &Pallet::<T>::account_id(),
+            reward.saturating_mul(10u32.into()),
+        );
+        ParticipationRewards::<T>::insert(&caller, reward);
+        set_phase::<T>(Phase::Distribution);
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller.clone()));
+
+        assert!(!ParticipationRewards::<T>::contains_key(&caller));
+    }
+
+    #[benchmark]
+    fn nominate_director() {
+        let caller = funded::<T>("nominee", 0);
+        CandidateCount::<T>::put(T::MaxDirectorCandidates::get().saturating_sub(1));
+        set_phase::<T>(Phase::Registration);
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller.clone()));
+
+        assert!(DirectorCandidates::<T>::contains_key(&caller));
+    }
+
+    /// Replaces a nine-candidate ballot with another nine candidates
+    #[benchmark]
+    fn vote_director() {
+        let caller: T::AccountId = whitelisted_caller();
+        add_voter::<T>(&caller);
+        let candidates = add_candidates::<T>(DIRECTOR_SEATS * 2);
+        let (previous, next) = candidates.split_at(DIRECTOR_SEATS as usize);
+        DirectorApprovals::<T>::insert(&caller, DirectorBallot {
+            weight: POWER,
+            candidates: previous.to_vec().try_into().expect("nine candidates; qed"),
+        });
+        DirectorBallotCount::<T>::put(1);
+        set_phase::<T>(Phase::Voting);
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller.clone()), next.to_vec());
+
+        assert_eq!(DirectorApprovals::<T>::get(&caller).map(|b| b.candidates.into_inner()), Some(next.to_vec()));
+    }
+
+    #[benchmark]
+    fn register_validator() {
+        let validator: T::AccountId = account("validator", 0, SEED);
+
+        #[extrinsic_call]
+        _(RawOrigin::Root, validator.clone());
+
+        assert!(Validators::<T>::get(&validator));
+    }
+
+    #[benchmark]
+    fn update_circulating_supply() {
+        let supply = T::Currency::minimum_balance();
+
+        #[extrinsic_call]
+        _(RawOrigin::Root, supply);
+
+        assert_eq!(CirculatingSupply::<T>::get(), supply);
+    }
+
+    #[benchmark]
+    fn execute_approved_budgets(p: Linear<0, { T::MaxProposals::get() }>) {
+        let caller: T::AccountId = whitelisted_caller();
+        add_proposals::<T>(p, true);
+        set_phase::<T>(Phase::Minting);
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller));
+
+        assert_eq!(Proposals::<T>::iter_values().filter(|p| p.executed).count() as u32, p);
+    }
+
+    /// Re-tallies `p` of the delegate's votes and its director ballot
+    #[benchmark]
+    fn on_delegated_votes_changed(p: Linear<0, { T::MaxProposals::get() }>) {
+        let delegate: T::AccountId = account("delegate", 0, SEED);
+        add_proposals::<T>(p, false);
+        for id in 0..p as u64 {
+            Votes::<T>::insert(&delegate, id, VoteRecord {
+                vote: VoteType::Yes,
+                voting_power: POWER,
+                is_validator: false,
+            });
+        }
+        let candidates = add_candidates::<T>(DIRECTOR_SEATS);
+        DirectorApprovals::<T>::insert(&delegate, DirectorBallot {
+            weight: POWER,
+            candidates: candidates.try_into().expect("nine candidates; qed"),
+        });
+        set_phase::<T>(Phase::Voting);
+
+        #[block]
+        {
+            Pallet::<T>::on_delegated_votes_changed(&delegate, CONSENSUS_DAY_TRACK, 0, POWER);
+        }
+
+        assert_eq!(DirectorApprovals::<T>::get(&delegate).map(|b| b.weight), Some(POWER * 2));
+    }
+}
@@ -0,0 +1,327 @@
+//! Director election: sequential Phragmén with score balancing
+//!
+//! Pure functions shared by the on-chain fallback election and off-chain
+//! solution miners. A solution is a winner set plus, for each voter, the part
+//! of its weight backing each winner it approved. Solutions are compared by
+//! [`ElectionScore`]: maximise the weakest winner's support, then the total
+//! support, then minimise the sum of squared supports (the most even split).
+
+use codec::{Decode, DecodeWithMemTracking, Encode, MaxEncodedLen};
+use scale_info::TypeInfo;
+use sp_runtime::RuntimeDebug;
+use sp_std::{collections::btree_map::BTreeMap, vec::Vec};
+
+/// Fixed-point unit for Phragmén loads
+///
+/// Normalised weights fit in 64 bits, so `load × weight` stays below
+/// `LOAD_UNIT × (seats + 1)` and cannot overflow for any realistic seat count.
+const LOAD_UNIT: u128 = 1 << 100;
+
+/// Weights are shifted down to this many bits while computing loads
+const WEIGHT_BITS: u32 = 64;
+
+/// Precision used when turning loads back into stake
+const PARTS: u128 = 1_000_000_000;
+
+/// A ballot: voter, its weight and the candidates it approves
+#[derive(Clone, PartialEq, Eq, RuntimeDebug)]
+pub struct Voter<AccountId> {
+    pub who: AccountId,
+    pub weight: u128,
+    pub approvals: Vec<AccountId>,
+}
+
+/// Stake a voter puts behind each winner
+pub type Assignment<AccountId> = (AccountId, Vec<(AccountId, u128)>);
+
+/// Winners and how each voter's weight is split between them
+#[derive(Clone, PartialEq, Eq, RuntimeDebug)]
+pub struct ElectionResult<AccountId> {
+    pub winners: Vec<AccountId>,
+    pub assignments: Vec<Assignment<AccountId>>,
+}
+
+/// Quality of a solution (see module docs for ordering)
+#[derive(
+    Clone, Copy, Encode, Decode, DecodeWithMemTracking, PartialEq, Eq, TypeInfo, MaxEncodedLen,
+    RuntimeDebug, Default,
+)]
+pub struct ElectionScore {
+    pub minimal_support: u128,
+    pub total_support: u128,
+    pub sum_support_squared: u128,
+}
+
+impl ElectionScore {
+    /// Whether `self` is strictly better than `other`
+    pub fn is_better_than(&self, other: &Self) -> bool {
+        (self.minimal_support, self.total_support, other.sum_support_squared)
+            > (other.minimal_support, other.total_support, self.sum_support_squared)
+    }
+}
+
+/// Elect up to `seats` candidates with sequential Phragmén
+///
+/// Ties are broken by candidate order, so the result is deterministic for a
+/// given input.
+pub fn seq_phragmen<AccountId: Clone + Ord>(
+    seats: usize,
+    candidates: &[AccountId],
+    voters: &[Voter<AccountId>],
+) -> ElectionResult<AccountId> {
+    let total: u128 = voters.iter().fold(0u128, |acc, v| acc.saturating_add(v.weight));
+    let shift = (128 - total.leading_zeros()).saturating_sub(WEIGHT_BITS);
+    let weights: Vec<u128> = voters.iter().map(|v| v.weight >> shift).collect();
+
+    // Normalised approval stake per candidate
+    let mut approval_stake: BTreeMap<&AccountId, u128> =
+        candidates.iter().map(|c| (c, 0u128)).collect();
+    for (voter, weight) in voters.iter().zip(weights.iter()) {
+        for c in voter.approvals.iter() {
+            if let Some(stake) = approval_stake.get_mut(c) {
+                *stake = stake.saturating_add(*weight);
+            }
+        }
+    }
+
+    let mut loads = sp_std::vec![0u128; voters.len()];
+    let mut edges: Vec<Vec<(AccountId, u128)>> = sp_std::vec![Vec::new(); voters.len()];
+    let mut winners: Vec<AccountId> = Vec::new();
+
+    while winners.len() < seats {
+        let mut best: Option<(&AccountId, u128)> = None;
+        for c in candidates.iter() {
+            let stake = approval_stake.get(c).copied().unwrap_or(0);
+            if stake == 0 || winners.contains(c) {
+                continue;
+            }
+
+            let backing = voters
+                .iter()
+                .zip(weights.iter())
+                .zip(loads.iter())
+                .filter(|((v, _), _)| v.approvals.contains(c))
+                .fold(0u128, |acc, ((_, w), l)| acc.saturating_add(w.saturating_mul(*l)));
+            let score = LOAD_UNIT.saturating_add(backing) / stake;
+
+            if best.is_none_or(|(_, s)| score < s) {
+                best = Some((c, score));
+            }
+        }
+
+        let Some((elected, score)) = best else { break };
+        for (i, voter) in voters.iter().enumerate() {
+            if weights[i] > 0 && voter.approvals.contains(elected) {
+                edges[i].push((elected.clone(), score.saturating_sub(loads[i])));
+                loads[i] = score;
+            }
+        }
+        winners.push(elected.clone());
+    }
+
+    // Split each voter's full weight in proportion to its edge loads
+    let assignments = voters
+        .iter()
+        .zip(edges)
+        .zip(loads)
+        .filter(|((_, e), _)| !e.is_empty())
+        .map(|((voter, e), load)| (voter.who.clone(), split_weight(voter.weight, e, load)))
+        .collect();
+
+    ElectionResult { winners, assignments }
+}
+
+/// Split `weight` across edges proportionally to their load; the remainder goes to the first edge
+fn split_weight<AccountId>(
+    weight: u128,
+    edges: Vec<(AccountId, u128)>,
+    total_load: u128,
+) -> Vec<(AccountId, u128)> {
+    let shift = (128 - total_load.leading_zeros()).saturating_sub(WEIGHT_BITS);
+    let total = (total_load >> shift).max(1);
+
+    let mut split: Vec<(AccountId, u128)> = edges
+        .into_iter()
+        .map(|(c, load)| {
+            let parts = (load >> shift).saturating_mul(PARTS) / total;
+            let stake = (weight / PARTS)
+                .saturating_mul(parts)
+                .saturating_add((weight % PARTS).saturating_mul(parts) / PARTS);
+            (c, stake)
+        })
+        .collect();
+
+    let assigned = split.iter().fold(0u128, |acc, (_, s)| acc.saturating_add(*s));
+    if let Some((_, first)) = split.first_mut() {
+        *first = first.saturating_add(weight.saturating_sub(assigned));
+    }
+    split
+}
+
+/// Total backing of every winner
+pub fn supports<AccountId: Clone + Ord>(
+    assignments: &[Assignment<AccountId>],
+) -> BTreeMap<AccountId, u128> {
+    let mut supports = BTreeMap::new();
+    for (_, edges) in assignments.iter() {
+        for (c, stake) in edges.iter() {
+            let support = supports.entry(c.clone()).or_insert(0u128);
+            *support = support.saturating_add(*stake);
+        }
+    }
+    supports
+}
+
+/// Score a winner set against the supports it received
+pub fn evaluate<AccountId: Ord>(
+    winners: &[AccountId],
+    supports: &BTreeMap<AccountId, u128>,
+) -> ElectionScore {
+    let backing: Vec<u128> =
+        winners.iter().map(|w| supports.get(w).copied().unwrap_or(0)).collect();
+
+    ElectionScore {
+        minimal_support: backing.iter().copied().min().unwrap_or(0),
+        total_support: backing.iter().fold(0u128, |acc, s| acc.saturating_add(*s)),
+        sum_support_squared: backing
+            .iter()
+            .fold(0u128, |acc, s| acc.saturating_add(s.saturating_mul(*s))),
+    }
+}
+
+/// Even out winner supports by re-splitting each voter's weight
+///
+/// Each voter in turn moves its stake towards its least-backed winners
+/// ("water filling"). Stops after `iterations` rounds or once no stake moves by
+/// more than `tolerance`.
+pub fn balance<AccountId: Clone + Ord>(
+    assignments: &mut [Assignment<AccountId>],
+    iterations: usize,
+    tolerance: u128,
+) {
+    let mut supports = supports(assignments);
+
+    for _ in 0..iterations {
+        let mut max_moved = 0u128;
+
+        for (_, edges) in assignments.iter_mut() {
+            if edges.len() < 2 {
+                continue;
+            }
+
+            // Supports of this voter's winners without its own stake
+            let weight = edges.iter().fold(0u128, |acc, (_, s)| acc.saturating_add(*s));
+            let mut others: Vec<(usize, u128)> = edges
+                .iter()
+                .enumerate()
+                .map(|(i, (c, s))| (i, supports.get(c).copied().unwrap_or(0).saturating_sub(*s)))
+                .collect();
+            others.sort_by_key(|(_, s)| *s);
+
+            // Highest k winners' level the weight can lift the lowest k to
+            let mut level = 0u128;
+            let mut filled = 0usize;
+            let mut cumulative = 0u128;
+            for (k, (_, s)) in others.iter().enumerate() {
+                let candidate_level = weight.saturating_add(cumulative).saturating_add(*s)
+                    / (k as u128 + 1);
+                if candidate_level < *s {
+                    break;
+                }
+                cumulative = cumulative.saturating_add(*s);
+                level = candidate_level;
+                filled = k + 1;
+            }
+
+            let mut new_stakes = sp_std::vec![0u128; edges.len()];
+            for (i, s) in others.iter().take(filled) {
+                new_stakes[*i] = level.saturating_sub(*s);
+            }
+            let assigned = new_stakes.iter().fold(0u128, |acc, s| acc.saturating_add(*s));
+            if let Some((i, _)) = others.first() {
+                new_stakes[*i] = new_stakes[*i].saturating_add(weight.saturating_sub(assigned));
+            }
+
+            for ((c, stake), new) in edges.iter_mut().zip(new_stakes) {
+                let support = supports.entry(c.clone()).or_insert(0);
+                *support = support.saturating_sub(*stake).saturating_add(new);
+                max_moved = max_moved.max(stake.abs_diff(new));
+                *stake = new;
+            }
+        }
+
+        if max_moved <= tolerance {
+            break;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn voter(who: u32, weight: u128, approvals: &[u32]) -> Voter<u32> {
+        Voter { who, weight, approvals: approvals.to_vec() }
+    }
+
+    #[test]
+    fn large_holder_cannot_take_every_seat() {
+        // One whale approving A, B, C versus two smaller voters approving D and E
+        let voters = sp_std::vec![
+            voter(1, 600, &[10, 11, 12]),
+            voter(2, 250, &[13]),
+            voter(3, 150, &[14]),
+        ];
+        let result = seq_phragmen(3, &[10, 11, 12, 13, 14], &voters);
+
+        assert_eq!(result.winners.len(), 3);
+        assert!(result.winners.contains(&13));
+        assert_eq!(result.winners.iter().filter(|w| **w < 13).count(), 2);
+    }
+
+    #[test]
+    fn assignments_spend_exact_weight_on_winners() {
+        let voters = sp_std::vec![
+            voter(1, 1_000_000_000_000_000_000_000, &[10, 11]),
+            voter(2, 7, &[11, 12]),
+            voter(3, 0, &[12]),
+        ];
+        let result = seq_phragmen(2, &[10, 11, 12], &voters);
+
+        for (who, edges) in result.assignments.iter() {
+            let weight = voters.iter().find(|v| v.who == *who).unwrap().weight;
+            assert_eq!(edges.iter().map(|(_, s)| s).sum::<u128>(), weight);
+            assert!(edges.iter().all(|(c, _)| result.winners.contains(c)));
+        }
+        assert!(result.assignments.iter().all(|(who, _)| *who != 3));
+    }
+
+    #[test]
+    fn balancing_improves_score() {
+        let voters = sp_std::vec![
+            voter(1, 100, &[10, 11]),
+            voter(2, 60, &[10]),
+            voter(3, 20, &[11]),
+        ];
+        let mut result = seq_phragmen(2, &[10, 11], &voters);
+        let before = evaluate(&result.winners, &supports(&result.assignments));
+
+        balance(&mut result.assignments, 10, 0);
+        let after = evaluate(&result.winners, &supports(&result.assignments));
+
+        assert!(!before.is_better_than(&after));
+        assert_eq!(after.total_support, 180);
+        assert_eq!(after.minimal_support, 90);
+    }
+
+    #[test]
+    fn score_ordering() {
+        let base = ElectionScore { minimal_support: 10, total_support: 100, sum_support_squared: 5_000 };
+        let higher_min = ElectionScore { minimal_support: 11, ..base };
+        let more_even = ElectionScore { sum_support_squared: 4_000, ..base };
+
+        assert!(higher_min.is_better_than(&base));
+        assert!(more_even.is_better_than(&base));
+        assert!(!base.is_better_than(&base));
+    }
+}
@@ -11,7 +11,7 @@
 //! - Time-weighted voting power (staked ËTR × coinage)
 //! - Dual quorum system (33% community + 51% validators)
 //! - Inflation rate voting (0-5% cap)
-//! - Director elections (9 positions)
+//! - Director elections (9 positions, sequential Phragmén)
 //! - Participation rewards for all voters
 //!
 //! ## Consensus Day Phases
@@ -20,14 +20,19 @@
 //! - Submit proposals with 10,000 ËTR bond
 //! - Lock stakes for voting power
 //! - Validators signal participation
-//! - Delegate assignment
+//! - Delegate assignment (`pallet-vote-delegation`, `CONSENSUS_DAY_TRACK`)
 //!
 //! **Phase 2: Voting (12 hours)**
 //! - Vote on proposals (Yes/No/Abstain)
-//! - Voting power = Staked ËTR × Coinage multiplier
+//! - Voting power = Staked ËTR × Coinage multiplier + delegated votes
 //! - Quorum: 33% community + 51% validators
 //! - Approval: >50% for budget/params, >66% for upgrades
 //!
+//! **Phase 2b: Reveal (secret ballot mode only)**
+//! - Voters reveal `(vote, salt)` for each commitment made during Voting
+//! - Only revealed ballots are tallied when the phase ends
+//! - Unrevealed commitments forfeit the voter's participation reward
+//!
 //! **Phase 3: Minting (3 hours)**
 //! - Execute approved budgets
 //! - Mint new ËTR (0-5% inflation cap)
@@ -54,6 +59,17 @@
 //! }
 //! ```
 //!
+//! ## Director Election
+//!
+//! Voters approve up to 9 candidates. Seats are filled by sequential Phragmén
+//! with score balancing (see [`election`]), so a large holder's weight is
+//! spread over the seats it wins instead of carrying every seat it approves.
+//! During Minting and Distribution anyone may submit a solution (winners plus
+//! how each ballot's weight backs them); it is checked and scored on-chain and
+//! kept only if it beats the queued one. When Distribution ends the best
+//! solution is used, or the election is computed on-chain if none was
+//! submitted, and the result replaces the treasury director set.
+//!
 //! ## Voting Power Calculation
 //!
 //! ```rust
@@ -68,9 +84,13 @@
 //! - `advance_phase()` - Move to next phase when time elapsed
 //! - `submit_proposal()` - Submit proposal with 10,000 ËTR bond
 //! - `vote()` - Cast vote on proposal with voting power
+//! - `set_secret_ballots()` - Enable commit-reveal voting for the next Voting phase (governance only)
+//! - `commit_vote()` - Submit a salted ballot hash during Voting (secret ballot mode)
+//! - `reveal_vote()` - Reveal a committed ballot during Reveal
 //! - `claim_participation_reward()` - Claim voter reward after distribution
 //! - `nominate_director()` - Nominate self for director election
-//! - `vote_director()` - Vote for director candidate
+//! - `vote_director()` - Approve up to 9 director candidates
+//! - `submit_director_election()` - Submit a scored director election solution
 //!
 //! ## Storage
 //!
@@ -80,13 +100,33 @@
 //! - `VotingPower` - Calculated voting power per account
 //! - `InflationRate` - Current and voted inflation rate
 //! - `DirectorCandidates` - Candidates for director election
-//! - `DirectorVotes` - Director election votes
+//! - `DirectorApprovals` - Director approval ballots with voter weight
+//! - `QueuedDirectorElection` - Best submitted director election solution
 //! - `ParticipationRewards` - Pending participation rewards
+//! - `EventFirstProposal` - First proposal ID of the current Consensus Day
+//! - `SecretBallots` - Whether Voting uses commit-reveal ballots
+//! - `Commitments` - Unrevealed ballot hashes per account per proposal
+//! - `UnrevealedBallots` - Accounts whose commitments were never revealed
+//! - `VoterCount`, `CandidateCount`, `DirectorBallotCount`, `CommitmentCount` - Entry counts
+//!   bounded by `MaxVoters`, `MaxDirectorCandidates` and `MaxCommitments`, used to weigh
+//!   `advance_phase` and `submit_director_election`
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
 pub use pallet::*;
 
+pub mod election;
+pub mod rules;
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+pub mod weights;
+pub use weights::WeightInfo;
+
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
 use codec::{Decode, DecodeWithMemTracking, Encode};
 use frame_support::pallet_prelude::*;
 
@@ -124,6 +164,8 @@ pub enum Phase {
     Minting,
     /// Distribution phase (1 hour)
     Distribution,
+    /// Ballot reveal between Voting and Minting (secret ballot mode only)
+    Reveal,
 }
 
 impl Default for Phase {
@@ -164,9 +206,16 @@ pub mod pallet {
     use frame_support::pallet_prelude::*;
     use frame_support::traits::{Currency, ReservableCurrency, ExistenceRequirement, Get};
     use frame_system::pallet_prelude::*;
-    use sp_runtime::traits::{Zero, Saturating, CheckedDiv, CheckedMul, AccountIdConversion, Bounded};
+    use sp_core::H256;
+    use sp_runtime::traits::{Zero, Saturating, CheckedDiv, CheckedMul, AccountIdConversion, Bounded, BlakeTwo256, Hash};
     use sp_runtime::{Permill, Percent};
     use sp_std::vec::Vec;
+    use pallet_vote_delegation::{DelegationHooks, DelegationInfo, TrackId, CONSENSUS_DAY_TRACK};
+    use crate::election::{self, ElectionScore};
+    use crate::WeightInfo;
+
+    /// Director seats filled each Consensus Day
+    pub const DIRECTOR_SEATS: u32 = 9;
 
     /// Treasury interface trait for funding operations
     pub trait TreasuryInterface<AccountId, Balance> {
@@ -176,9 +225,12 @@ pub mod pallet {
             amount: Balance,
             categories: Vec<(BudgetCategory, Balance)>,
         ) -> DispatchResult;
+
+        /// Replace the treasury director set with `directors`
+        fn set_directors(directors: Vec<AccountId>) -> DispatchResult;
     }
 
-    type BalanceOf<T> =
+    pub(crate) type BalanceOf<T> =
         <<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
 
     /// Consensus Day state
@@ -274,6 +326,23 @@ pub mod pallet {
         }
     }
 
+    /// Director approval ballot
+    #[derive(Clone, Encode, Decode, PartialEq, Eq, TypeInfo, MaxEncodedLen, RuntimeDebug)]
+    pub struct DirectorBallot<AccountId> {
+        /// Voting power at the time of voting (own + delegated)
+        pub weight: u128,
+        /// Approved candidates
+        pub candidates: BoundedVec<AccountId, ConstU32<DIRECTOR_SEATS>>,
+    }
+
+    /// Submitted director election solution
+    #[derive(Clone, Encode, Decode, PartialEq, Eq, TypeInfo, MaxEncodedLen, RuntimeDebug)]
+    pub struct QueuedElection<AccountId> {
+        pub winners: BoundedVec<AccountId, ConstU32<DIRECTOR_SEATS>>,
+        pub score: ElectionScore,
+        pub submitter: AccountId,
+    }
+
     /// Director candidate
     #[derive(Clone, Encode, Decode, PartialEq, Eq, TypeInfo, MaxEncodedLen, RuntimeDebug)]
     pub struct DirectorCandidate<AccountId, Balance> {
@@ -312,6 +381,10 @@ pub mod pallet {
         #[pallet::constant]
         type DistributionDuration: Get<u32>;
 
+        /// Reveal phase duration, used only in secret ballot mode
+        #[pallet::constant]
+        type RevealDuration: Get<u32>;
+
         /// Proposal bond amount (10,000 ËTR)
         #[pallet::constant]
         type ProposalBond: Get<BalanceOf<Self>>;
@@ -331,6 +404,28 @@ pub mod pallet {
         /// Maximum title length
         #[pallet::constant]
         type MaxTitleLength: Get<u32>;
+
+        /// Score balancing rounds for the on-chain fallback director election
+        #[pallet::constant]
+        type DirectorBalancingIterations: Get<u32>;
+
+        /// Source of votes delegated on `CONSENSUS_DAY_TRACK`
+        type Delegation: DelegationInfo<Self::AccountId>;
+
+        /// Maximum accounts with locked voting stake, and maximum director ballots
+        #[pallet::constant]
+        type MaxVoters: Get<u32>;
+
+        /// Maximum director candidates
+        #[pallet::constant]
+        type MaxDirectorCandidates: Get<u32>;
+
+        /// Maximum secret ballot commitments awaiting reveal
+        #[pallet::constant]
+        type MaxCommitments: Get<u32>;
+
+        /// Weight information for extrinsics
+        type WeightInfo: WeightInfo;
     }
 
     #[pallet::pallet]
@@ -410,14 +505,23 @@ pub mod pallet {
         OptionQuery,
     >;
 
-    /// Director votes (voter → candidate)
+    /// Director approval ballots (voter → ballot)
     #[pallet::storage]
-    #[pallet::getter(fn director_votes)]
-    pub type DirectorVotes<T: Config> = StorageMap<
+    #[pallet::getter(fn director_approvals)]
+    pub type DirectorApprovals<T: Config> = StorageMap<
         _,
         Blake2_128Concat,
         T::AccountId,  // Voter
-        T::AccountId,  // Candidate voted for
+        DirectorBallot<T::AccountId>,
+        OptionQuery,
+    >;
+
+    /// Best director election solution submitted this Consensus Day
+    #[pallet::storage]
+    #[pallet::getter(fn queued_director_election)]
+    pub type QueuedDirectorElection<T: Config> = StorageValue<
+        _,
+        QueuedElection<T::AccountId>,
         OptionQuery,
     >;
 
@@ -489,11 +593,65 @@ pub mod pallet {
         ValueQuery,
     >;
 
+    /// First proposal ID submitted in the current Consensus Day
+    #[pallet::storage]
+    #[pallet::getter(fn event_first_proposal)]
+    pub type EventFirstProposal<T: Config> = StorageValue<_, u64, ValueQuery>;
+
+    /// Commit-reveal ballots for the Voting phase
+    #[pallet::storage]
+    #[pallet::getter(fn secret_ballots)]
+    pub type SecretBallots<T: Config> = StorageValue<_, bool, ValueQuery>;
+
+    /// Ballot commitments awaiting reveal (account + proposal_id → hash)
+    #[pallet::storage]
+    #[pallet::getter(fn commitments)]
+    pub type Commitments<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        Blake2_128Concat,
+        u64,
+        H256,
+        OptionQuery,
+    >;
+
+    /// Accounts with unrevealed commitments (forfeit participation rewards)
+    #[pallet::storage]
+    #[pallet::getter(fn unrevealed_ballots)]
+    pub type UnrevealedBallots<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        u32,  // Number of unrevealed commitments
+        ValueQuery,
+    >;
+
     /// Total amount transferred to treasury during current Consensus Day
     #[pallet::storage]
     #[pallet::getter(fn total_treasury_funded)]
     pub type TotalTreasuryFunded<T: Config> = StorageValue<_, BalanceOf<T>, ValueQuery>;
 
+    /// Number of accounts in `VotingPowerMap`
+    #[pallet::storage]
+    #[pallet::getter(fn voter_count)]
+    pub type VoterCount<T: Config> = StorageValue<_, u32, ValueQuery>;
+
+    /// Number of entries in `DirectorCandidates`
+    #[pallet::storage]
+    #[pallet::getter(fn candidate_count)]
+    pub type CandidateCount<T: Config> = StorageValue<_, u32, ValueQuery>;
+
+    /// Number of entries in `DirectorApprovals`
+    #[pallet::storage]
+    #[pallet::getter(fn director_ballot_count)]
+    pub type DirectorBallotCount<T: Config> = StorageValue<_, u32, ValueQuery>;
+
+    /// Number of entries in `Commitments`
+    #[pallet::storage]
+    #[pallet::getter(fn commitment_count)]
+    pub type CommitmentCount<T: Config> = StorageValue<_, u32, ValueQuery>;
+
     #[pallet::event]
     #[pallet::generate_deposit(pub(super) fn deposit_event)]
     pub enum Event<T: Config> {
@@ -519,8 +677,12 @@ pub mod pallet {
         ParticipationRewardClaimed(T::AccountId, BalanceOf<T>),
         /// Director nominated [candidate, stake]
         DirectorNominated(T::AccountId, BalanceOf<T>),
-        /// Director vote cast [voter, candidate, voting_power]
-        DirectorVoteCast(T::AccountId, T::AccountId, u128),
+        /// Director approvals cast [voter, candidates, voting_power]
+        DirectorVoteCast(T::AccountId, Vec<T::AccountId>, u128),
+        /// Director election solution queued [submitter, score]
+        DirectorElectionSubmitted(T::AccountId, ElectionScore),
+        /// Treasury director set could not be updated [error]
+        DirectorSetUpdateFailed(DispatchError),
         /// Directors elected [directors]
         DirectorsElected(Vec<T::AccountId>),
         /// Consensus Day completed [year, total_minted]
@@ -531,6 +693,16 @@ pub mod pallet {
         TreasuryTransferCompleted(BalanceOf<T>, u32),
         /// Budget allocation approved with category [proposal_id, category, amount]
         BudgetAllocationCategorized(u64, BudgetCategory, BalanceOf<T>),
+        /// Delegated votes re-tallied on a delegate's vote [delegate, proposal_id, voting_power]
+        DelegatedVoteUpdated(T::AccountId, u64, u128),
+        /// Secret ballot mode set [enabled]
+        SecretBallotsSet(bool),
+        /// Ballot committed [voter, proposal_id]
+        VoteCommitted(T::AccountId, u64),
+        /// Commitment not revealed; participation reward forfeited [voter, proposal_id]
+        BallotUnrevealed(T::AccountId, u64),
+        /// Participation reward forfeited for unrevealed ballots [voter, unrevealed]
+        ParticipationRewardForfeited(T::AccountId, u32),
     }
 
     #[pallet::error]
@@ -569,6 +741,32 @@ pub mod pallet {
         PhaseDurationNotElapsed,
         /// Consensus Day already active
         AlreadyActive,
+        /// Vote delegated on the Consensus Day track
+        DelegatingVotes,
+        /// Secret ballot mode requires commit_vote/reveal_vote
+        SecretBallotRequired,
+        /// Secret ballot mode is not enabled
+        SecretBallotsDisabled,
+        /// Already committed a ballot for this proposal
+        AlreadyCommitted,
+        /// No commitment for this proposal
+        NoCommitment,
+        /// Revealed ballot does not match commitment
+        CommitmentMismatch,
+        /// Director candidate not found
+        CandidateNotFound,
+        /// Director ballot must approve 1 to 9 distinct candidates
+        InvalidDirectorBallot,
+        /// Director election solution is malformed
+        InvalidElectionSolution,
+        /// Director election solution does not beat the queued one
+        WeakElectionSolution,
+        /// `MaxVoters` accounts already hold voting stake or director ballots
+        TooManyVoters,
+        /// `MaxDirectorCandidates` candidates already nominated
+        TooManyCandidates,
+        /// `MaxCommitments` ballot commitments already awaiting reveal
+        TooManyCommitments,
     }
 
     #[pallet::call]
@@ -577,7 +775,7 @@ pub mod pallet {
         ///
         /// Initiates the annual Consensus Day event
         #[pallet::call_index(0)]
-        #[pallet::weight(100_000)]
+        #[pallet::weight(T::WeightInfo::start_consensus_day())]
         pub fn start_consensus_day(origin: OriginFor<T>) -> DispatchResult {
             ensure_root(origin)?;
 
@@ -597,6 +795,7 @@ pub mod pallet {
 
             ConsensusDayState::<T>::put(new_state);
             TotalMinted::<T>::put(BalanceOf::<T>::zero());
+            EventFirstProposal::<T>::put(NextProposalId::<T>::get());
 
             Self::deposit_event(Event::ConsensusDayStarted(year, current_block));
 
@@ -606,8 +805,11 @@ pub mod pallet {
         /// Advance to next phase
         ///
         /// Moves Consensus Day to the next phase when duration elapsed
+        ///
+        /// Charged for the transition out of the current phase, which is sized by the
+        /// proposal, voter, ballot, candidate and commitment counts.
         #[pallet::call_index(1)]
-        #[pallet::weight(50_000)]
+        #[pallet::weight(Pallet::<T>::advance_phase_weight())]
         pub fn advance_phase(origin: OriginFor<T>) -> DispatchResult {
             ensure_signed(origin)?;
 
@@ -622,6 +824,7 @@ pub mod pallet {
                 Phase::Voting => T::VotingDuration::get(),
                 Phase::Minting => T::MintingDuration::get(),
                 Phase::Distribution => T::DistributionDuration::get(),
+                Phase::Reveal => T::RevealDuration::get(),
                 Phase::Inactive => return Err(Error::<T>::NotActive.into()),
             };
 
@@ -633,11 +836,18 @@ pub mod pallet {
             let old_phase = state.phase.clone();
             let new_phase = match state.phase {
                 Phase::Registration => Phase::Voting,
+                Phase::Voting if SecretBallots::<T>::get() => Phase::Reveal,
                 Phase::Voting => {
                     // Execute quorum checks and finalize votes
                     Self::finalize_voting()?;
                     Phase::Minting
                 }
+                Phase::Reveal => {
+                    // Only revealed ballots were tallied
+                    Self::expire_commitments();
+                    Self::finalize_voting()?;
+                    Phase::Minting
+                }
                 Phase::Minting => {
                     // Execute approved proposals
                     Self::execute_minting()?;
@@ -669,7 +879,7 @@ pub mod pallet {
         ///
         /// Submits a proposal during Registration phase (requires 10,000 ËTR bond)
         #[pallet::call_index(2)]
-        #[pallet::weight(10_000)]
+        #[pallet::weight(T::WeightInfo::submit_proposal())]
         pub fn submit_proposal(
             origin: OriginFor<T>,
             title: Vec<u8>,
@@ -738,7 +948,7 @@ pub mod pallet {
         ///
         /// Vote Yes/No/Abstain with calculated voting power
         #[pallet::call_index(3)]
-        #[pallet::weight(10_000)]
+        #[pallet::weight(T::WeightInfo::vote())]
         pub fn vote(
             origin: OriginFor<T>,
             proposal_id: u64,
@@ -748,53 +958,138 @@ pub mod pallet {
 
             let state = ConsensusDayState::<T>::get();
             ensure!(state.phase == Phase::Voting, Error::<T>::WrongPhase);
+            ensure!(!SecretBallots::<T>::get(), Error::<T>::SecretBallotRequired);
+
+            Self::record_vote(voter, proposal_id, vote)
+        }
+
+        /// Enable or disable commit-reveal ballots (governance/root only)
+        ///
+        /// Can only change before voting starts
+        #[pallet::call_index(11)]
+        #[pallet::weight(T::WeightInfo::set_secret_ballots())]
+        pub fn set_secret_ballots(origin: OriginFor<T>, enabled: bool) -> DispatchResult {
+            ensure_root(origin)?;
 
+            let state = ConsensusDayState::<T>::get();
             ensure!(
-                !Votes::<T>::contains_key(&voter, proposal_id),
-                Error::<T>::AlreadyVoted
+                state.phase == Phase::Inactive || state.phase == Phase::Registration,
+                Error::<T>::WrongPhase
             );
 
-            let mut proposal = Proposals::<T>::get(proposal_id)
-                .ok_or(Error::<T>::ProposalNotFound)?;
+            SecretBallots::<T>::put(enabled);
+            Self::deposit_event(Event::SecretBallotsSet(enabled));
+
+            Ok(())
+        }
 
-            let voting_power_info = VotingPowerMap::<T>::get(&voter);
+        /// Commit a secret ballot
+        ///
+        /// `commitment` is `ballot_commitment(voter, proposal_id, vote, salt)`
+        #[pallet::call_index(12)]
+        #[pallet::weight(T::WeightInfo::commit_vote())]
+        pub fn commit_vote(
+            origin: OriginFor<T>,
+            proposal_id: u64,
+            commitment: H256,
+        ) -> DispatchResult {
+            let voter = ensure_signed(origin)?;
+
+            let state = ConsensusDayState::<T>::get();
+            ensure!(state.phase == Phase::Voting, Error::<T>::WrongPhase);
+            ensure!(SecretBallots::<T>::get(), Error::<T>::SecretBallotsDisabled);
+
+            ensure!(
+                Proposals::<T>::contains_key(proposal_id),
+                Error::<T>::ProposalNotFound
+            );
+            ensure!(
+                !Commitments::<T>::contains_key(&voter, proposal_id),
+                Error::<T>::AlreadyCommitted
+            );
             ensure!(
-                voting_power_info.voting_power > 0,
-                Error::<T>::NoVotingPower
+                !T::Delegation::is_delegating(&voter, CONSENSUS_DAY_TRACK),
+                Error::<T>::DelegatingVotes
             );
 
-            let is_validator = Validators::<T>::get(&voter);
+            let commitments = CommitmentCount::<T>::get();
+            ensure!(commitments < T::MaxCommitments::get(), Error::<T>::TooManyCommitments);
 
-            // Update proposal vote counts
-            match vote {
-                VoteType::Yes => proposal.yes_votes = proposal.yes_votes.saturating_add(voting_power_info.voting_power),
-                VoteType::No => proposal.no_votes = proposal.no_votes.saturating_add(voting_power_info.voting_power),
-                VoteType::Abstain => proposal.abstain_votes = proposal.abstain_votes.saturating_add(voting_power_info.voting_power),
-            }
+            Commitments::<T>::insert(&voter, proposal_id, commitment);
+            CommitmentCount::<T>::put(commitments.saturating_add(1));
 
-            if is_validator {
-                proposal.validator_count = proposal.validator_count.saturating_add(1);
-            }
+            Self::deposit_event(Event::VoteCommitted(voter, proposal_id));
 
-            // Record vote
-            let vote_record = VoteRecord {
-                vote: vote.clone(),
-                voting_power: voting_power_info.voting_power,
-                is_validator,
-            };
+            Ok(())
+        }
 
-            Votes::<T>::insert(&voter, proposal_id, vote_record);
-            Proposals::<T>::insert(proposal_id, proposal);
+        /// Reveal a committed ballot
+        ///
+        /// Voting power is taken at reveal time
+        #[pallet::call_index(13)]
+        #[pallet::weight(T::WeightInfo::reveal_vote())]
+        pub fn reveal_vote(
+            origin: OriginFor<T>,
+            proposal_id: u64,
+            vote: VoteType,
+            salt: [u8; 32],
+        ) -> DispatchResult {
+            let voter = ensure_signed(origin)?;
 
-            // Track proposals voted on (for completeness bonus)
-            ProposalsVoted::<T>::mutate(&voter, |count| *count = count.saturating_add(1));
+            let state = ConsensusDayState::<T>::get();
+            ensure!(state.phase == Phase::Reveal, Error::<T>::WrongPhase);
 
-            Self::deposit_event(Event::VoteCast(
-                voter,
-                proposal_id,
-                vote,
-                voting_power_info.voting_power,
-            ));
+            let commitment = Commitments::<T>::get(&voter, proposal_id)
+                .ok_or(Error::<T>::NoCommitment)?;
+            ensure!(
+                commitment == Self::ballot_commitment(&voter, proposal_id, vote, &salt),
+                Error::<T>::CommitmentMismatch
+            );
+
+            Self::record_vote(voter.clone(), proposal_id, vote)?;
+            Commitments::<T>::remove(&voter, proposal_id);
+            CommitmentCount::<T>::mutate(|count| *count = count.saturating_sub(1));
+
+            Ok(())
+        }
+
+        /// Submit a director election solution
+        ///
+        /// `assignments` splits each listed ballot's weight over the winners it
+        /// approved. The solution is scored on-chain and queued if it beats the
+        /// current one; it is applied when Distribution ends.
+        #[pallet::call_index(14)]
+        #[pallet::weight(T::WeightInfo::submit_director_election(
+            assignments.len() as u32,
+            DirectorBallotCount::<T>::get(),
+        ))]
+        pub fn submit_director_election(
+            origin: OriginFor<T>,
+            winners: Vec<T::AccountId>,
+            assignments: Vec<election::Assignment<T::AccountId>>,
+        ) -> DispatchResult {
+            let submitter = ensure_signed(origin)?;
+
+            let state = ConsensusDayState::<T>::get();
+            ensure!(
+                state.phase == Phase::Minting || state.phase == Phase::Distribution,
+                Error::<T>::WrongPhase
+            );
+
+            let score = Self::score_solution(&winners, &assignments)?;
+            if let Some(queued) = QueuedDirectorElection::<T>::get() {
+                ensure!(score.is_better_than(&queued.score), Error::<T>::WeakElectionSolution);
+            }
+
+            let winners: BoundedVec<T::AccountId, ConstU32<DIRECTOR_SEATS>> =
+                winners.try_into().map_err(|_| Error::<T>::InvalidElectionSolution)?;
+            QueuedDirectorElection::<T>::put(QueuedElection {
+                winners,
+                score,
+                submitter: submitter.clone(),
+            });
+
+            Self::deposit_event(Event::DirectorElectionSubmitted(submitter, score));
 
             Ok(())
         }
@@ -803,7 +1098,7 @@ pub mod pallet {
         ///
         /// Must be called during Registration phase to participate
         #[pallet::call_index(4)]
-        #[pallet::weight(10_000)]
+        #[pallet::weight(T::WeightInfo::lock_stake_for_voting())]
         pub fn lock_stake_for_voting(
             origin: OriginFor<T>,
             amount: BalanceOf<T>,
@@ -813,6 +1108,11 @@ pub mod pallet {
             let state = ConsensusDayState::<T>::get();
             ensure!(state.phase == Phase::Registration, Error::<T>::WrongPhase);
 
+            let new_voter = !VotingPowerMap::<T>::contains_key(&staker);
+            if new_voter {
+                ensure!(VoterCount::<T>::get() < T::MaxVoters::get(), Error::<T>::TooManyVoters);
+            }
+
             // Reserve stake
             T::Currency::reserve(&staker, amount)?;
 
@@ -832,6 +1132,9 @@ pub mod pallet {
             vp_info.voting_power = voting_power;
 
             VotingPowerMap::<T>::insert(&staker, vp_info);
+            if new_voter {
+                VoterCount::<T>::mutate(|count| *count = count.saturating_add(1));
+            }
 
             Ok(())
         }
@@ -840,7 +1143,7 @@ pub mod pallet {
         ///
         /// Claim reward for voting during Consensus Day
         #[pallet::call_index(5)]
-        #[pallet::weight(10_000)]
+        #[pallet::weight(T::WeightInfo::claim_participation_reward())]
         pub fn claim_participation_reward(origin: OriginFor<T>) -> DispatchResult {
             let claimer = ensure_signed(origin)?;
 
@@ -872,7 +1175,7 @@ pub mod pallet {
         ///
         /// Requires 128 ËTR minimum stake
         #[pallet::call_index(6)]
-        #[pallet::weight(10_000)]
+        #[pallet::weight(T::WeightInfo::nominate_director())]
         pub fn nominate_director(origin: OriginFor<T>) -> DispatchResult {
             let candidate = ensure_signed(origin)?;
 
@@ -883,6 +1186,11 @@ pub mod pallet {
                 !DirectorCandidates::<T>::contains_key(&candidate),
                 Error::<T>::AlreadyNominatedDirector
             );
+            let candidates = CandidateCount::<T>::get();
+            ensure!(
+                candidates < T::MaxDirectorCandidates::get(),
+                Error::<T>::TooManyCandidates
+            );
 
             let min_stake = T::DirectorMinStake::get();
             let balance = T::Currency::free_balance(&candidate);
@@ -898,53 +1206,71 @@ pub mod pallet {
             };
 
             DirectorCandidates::<T>::insert(&candidate, director_candidate);
+            CandidateCount::<T>::put(candidates.saturating_add(1));
 
             Self::deposit_event(Event::DirectorNominated(candidate, min_stake));
 
             Ok(())
         }
 
-        /// Vote for director candidate
+        /// Approve director candidates
         ///
-        /// Vote for one of the director candidates
+        /// Approve up to 9 candidates; a new ballot replaces the previous one.
+        /// Candidate `votes` track total approval weight.
         #[pallet::call_index(7)]
-        #[pallet::weight(10_000)]
+        #[pallet::weight(T::WeightInfo::vote_director())]
         pub fn vote_director(
             origin: OriginFor<T>,
-            candidate: T::AccountId,
+            candidates: Vec<T::AccountId>,
         ) -> DispatchResult {
             let voter = ensure_signed(origin)?;
 
             let state = ConsensusDayState::<T>::get();
             ensure!(state.phase == Phase::Voting, Error::<T>::WrongPhase);
 
-            let mut director_candidate = DirectorCandidates::<T>::get(&candidate)
-                .ok_or(Error::<T>::ProposalNotFound)?;
-
-            let voting_power_info = VotingPowerMap::<T>::get(&voter);
+            let mut sorted = candidates.clone();
+            sorted.sort();
+            sorted.dedup();
             ensure!(
-                voting_power_info.voting_power > 0,
-                Error::<T>::NoVotingPower
+                !candidates.is_empty() && sorted.len() == candidates.len(),
+                Error::<T>::InvalidDirectorBallot
             );
+            ensure!(
+                candidates.iter().all(|c| DirectorCandidates::<T>::contains_key(c)),
+                Error::<T>::CandidateNotFound
+            );
+            let bounded: BoundedVec<T::AccountId, ConstU32<DIRECTOR_SEATS>> = candidates
+                .clone()
+                .try_into()
+                .map_err(|_| Error::<T>::InvalidDirectorBallot)?;
 
-            // Update director votes
-            director_candidate.votes = director_candidate.votes.saturating_add(voting_power_info.voting_power);
-            DirectorCandidates::<T>::insert(&candidate, director_candidate);
-
-            DirectorVotes::<T>::insert(&voter, &candidate);
+            ensure!(
+                !T::Delegation::is_delegating(&voter, CONSENSUS_DAY_TRACK),
+                Error::<T>::DelegatingVotes
+            );
+            let weight = VotingPowerMap::<T>::get(&voter)
+                .voting_power
+                .saturating_add(T::Delegation::delegated_votes(&voter, CONSENSUS_DAY_TRACK));
+            ensure!(weight > 0, Error::<T>::NoVotingPower);
+
+            if let Some(previous) = DirectorApprovals::<T>::get(&voter) {
+                Self::adjust_candidate_votes(&previous.candidates, previous.weight, 0);
+            } else {
+                let ballots = DirectorBallotCount::<T>::get();
+                ensure!(ballots < T::MaxVoters::get(), Error::<T>::TooManyVoters);
+                DirectorBallotCount::<T>::put(ballots.saturating_add(1));
+            }
+            Self::adjust_candidate_votes(&bounded, 0, weight);
+            DirectorApprovals::<T>::insert(&voter, DirectorBallot { weight, candidates: bounded });
 
-            Self::deposit_event(Event::DirectorVoteCast(
-                voter,
-                candidate,
-                voting_power_info.voting_power,
-            ));
+            Self::deposit_event(Event::DirectorVoteCast(voter, candidates, weight));
 
             Ok(())
         }
 
         /// Register validator (called by validator pallet)
         #[pallet::call_index(8)]
-        #[pallet::weight(5_000)]
+        #[pallet::weight(T::WeightInfo::register_validator())]
         pub fn register_validator(
             origin: OriginFor<T>,
             validator: T::AccountId,
@@ -961,7 +1287,7 @@ pub mod pallet {
 
         /// Update circulating supply (called by system)
         #[pallet::call_index(9)]
-        #[pallet::weight(5_000)]
+        #[pallet::weight(T::WeightInfo::update_circulating_supply())]
         pub fn update_circulating_supply(
             origin: OriginFor<T>,
             supply: BalanceOf<T>,
@@ -976,7 +1302,7 @@ pub mod pallet {
         /// This is typically called automatically during phase transition to Minting,
         /// but can be called manually if needed
         #[pallet::call_index(10)]
-        #[pallet::weight(100_000)]
+        #[pallet::weight(T::WeightInfo::execute_approved_budgets(Pallet::<T>::proposal_count()))]
         pub fn execute_approved_budgets(origin: OriginFor<T>) -> DispatchResult {
             ensure_signed(origin)?;
 
@@ -990,43 +1316,223 @@ pub mod pallet {
     }
 
     impl<T: Config> Pallet<T> {
+        /// Proposals iterated when voting is finalized and budgets are minted
+        pub fn proposal_count() -> u32 {
+            NextProposalId::<T>::get().min(T::MaxProposals::get().into()) as u32
+        }
+
+        /// Weight of `advance_phase` out of the current phase
+        pub fn advance_phase_weight() -> Weight {
+            let proposals = Self::proposal_count();
+            let secret = SecretBallots::<T>::get();
+            match ConsensusDayState::<T>::get().phase {
+                Phase::Voting if !secret => T::WeightInfo::advance_phase_finalize(proposals),
+                Phase::Reveal => {
+                    T::WeightInfo::advance_phase_reveal(proposals, CommitmentCount::<T>::get())
+                }
+                Phase::Minting => T::WeightInfo::advance_phase_minting(proposals),
+                Phase::Distribution => T::WeightInfo::advance_phase_distribution(
+                    VoterCount::<T>::get(),
+                    // Unrevealed ballots are only recorded in secret ballot mode
+                    if secret { T::MaxCommitments::get() } else { 0 },
+                    DirectorBallotCount::<T>::get(),
+                    CandidateCount::<T>::get(),
+                ),
+                Phase::Inactive | Phase::Registration | Phase::Voting => T::WeightInfo::advance_phase(),
+            }
+        }
+
+        /// Ballot hash committed in secret ballot mode
+        pub fn ballot_commitment(
+            voter: &T::AccountId,
+            proposal_id: u64,
+            vote: VoteType,
+            salt: &[u8; 32],
+        ) -> H256 {
+            BlakeTwo256::hash_of(&(voter, proposal_id, vote, salt))
+        }
+
+        /// Tally a vote with the voter's current power
+        fn record_vote(voter: T::AccountId, proposal_id: u64, vote: VoteType) -> DispatchResult {
+            ensure!(
+                !Votes::<T>::contains_key(&voter, proposal_id),
+                Error::<T>::AlreadyVoted
+            );
+
+            let mut proposal = Proposals::<T>::get(proposal_id)
+                .ok_or(Error::<T>::ProposalNotFound)?;
+
+            ensure!(
+                !T::Delegation::is_delegating(&voter, CONSENSUS_DAY_TRACK),
+                Error::<T>::DelegatingVotes
+            );
+
+            // Own stake-weighted power plus votes delegated to the voter
+            let voting_power = VotingPowerMap::<T>::get(&voter)
+                .voting_power
+                .saturating_add(T::Delegation::delegated_votes(&voter, CONSENSUS_DAY_TRACK));
+            ensure!(voting_power > 0, Error::<T>::NoVotingPower);
+
+            let is_validator = Validators::<T>::get(&voter);
+
+            // Update proposal vote counts
+            Self::adjust_tally(&mut proposal, vote, 0, voting_power);
+
+            if is_validator {
+                proposal.validator_count = proposal.validator_count.saturating_add(1);
+            }
+
+            // Record vote
+            let vote_record = VoteRecord {
+                vote: vote.clone(),
+                voting_power,
+                is_validator,
+            };
+
+            Votes::<T>::insert(&voter, proposal_id, vote_record);
+            Proposals::<T>::insert(proposal_id, proposal);
+
+            // Track proposals voted on (for completeness bonus)
+            ProposalsVoted::<T>::mutate(&voter, |count| *count = count.saturating_add(1));
+
+            Self::deposit_event(Event::VoteCast(
+                voter,
+                proposal_id,
+                vote,
+                voting_power,
+            ));
+
+            Ok(())
+        }
+
+        /// Move a ballot's weight on its candidates' approval totals
+        fn adjust_candidate_votes(candidates: &[T::AccountId], remove: u128, add: u128) {
+            for c in candidates.iter() {
+                DirectorCandidates::<T>::mutate(c, |maybe_candidate| {
+                    if let Some(candidate) = maybe_candidate {
+                        candidate.votes = candidate.votes.saturating_sub(remove).saturating_add(add);
+                    }
+                });
+            }
+        }
+
+        /// Check a director election solution against the ballots and score it
+        fn score_solution(
+            winners: &[T::AccountId],
+            assignments: &[election::Assignment<T::AccountId>],
+        ) -> Result<ElectionScore, DispatchError> {
+            // Winners: distinct candidates, as many as there are approved candidates (max 9)
+            let mut approved: Vec<T::AccountId> = DirectorApprovals::<T>::iter_values()
+                .flat_map(|ballot| ballot.candidates.into_inner())
+                .collect();
+            approved.sort();
+            approved.dedup();
+            let seats = approved.len().min(DIRECTOR_SEATS as usize);
+
+            let mut distinct = winners.to_vec();
+            distinct.sort();
+            distinct.dedup();
+            ensure!(
+                distinct.len() == winners.len()
+                    && winners.len() == seats
+                    && winners.iter().all(|w| DirectorCandidates::<T>::contains_key(w)),
+                Error::<T>::InvalidElectionSolution
+            );
+
+            // Assignments: one per ballot, only to approved winners, within ballot weight
+            let mut voters: Vec<&T::AccountId> = assignments.iter().map(|(who, _)| who).collect();
+            voters.sort();
+            voters.dedup();
+            ensure!(voters.len() == assignments.len(), Error::<T>::InvalidElectionSolution);
+
+            for (who, edges) in assignments.iter() {
+                let ballot = DirectorApprovals::<T>::get(who)
+                    .ok_or(Error::<T>::InvalidElectionSolution)?;
+                let spent = edges.iter().try_fold(0u128, |acc, (c, stake)| {
+                    let valid = winners.contains(c) && ballot.candidates.contains(c);
+                    if valid { acc.checked_add(*stake) } else { None }
+                });
+                ensure!(
+                    spent.is_some_and(|spent| spent <= ballot.weight),
+                    Error::<T>::InvalidElectionSolution
+                );
+            }
+
+            Ok(election::evaluate(winners, &election::supports(assignments)))
+        }
+
+        /// Pick this year's directors and hand them to the treasury
+        fn elect_directors() -> Vec<T::AccountId> {
+            let elected = match QueuedDirectorElection::<T>::take() {
+                Some(queued) => queued.winners.into_inner(),
+                None => {
+                    // No solution submitted: run the election on-chain
+                    let candidates: Vec<T::AccountId> = DirectorCandidates::<T>::iter_keys().collect();
+                    let voters: Vec<election::Voter<T::AccountId>> = DirectorApprovals::<T>::iter()
+                        .map(|(who, ballot)| election::Voter {
+                            who,
+                            weight: ballot.weight,
+                            approvals: ballot.candidates.into_inner(),
+                        })
+                        .collect();
+                    let mut result =
+                        election::seq_phragmen(DIRECTOR_SEATS as usize, &candidates, &voters);
+                    election::balance(
+                        &mut result.assignments,
+                        T::DirectorBalancingIterations::get() as usize,
+                        0,
+                    );
+                    result.winners
+                }
+            };
+
+            let _ = DirectorApprovals::<T>::clear(T::MaxVoters::get(), None);
+            DirectorBallotCount::<T>::kill();
+            elected
+        }
+
+        /// Drop unrevealed commitments and mark their voters as forfeiting rewards
+        fn expire_commitments() {
+            for (voter, proposal_id, _) in Commitments::<T>::drain() {
+                UnrevealedBallots::<T>::mutate(&voter, |count| *count = count.saturating_add(1));
+                Self::deposit_event(Event::BallotUnrevealed(voter, proposal_id));
+            }
+            CommitmentCount::<T>::kill();
+        }
+
         /// Pallet account ID (holds reward pool)
         pub fn account_id() -> T::AccountId {
             frame_support::PalletId(*b"py/cnsdy").into_account_truncating()
         }
 
-        /// Calculate voting power
-        ///
-        /// Formula: base_stake × duration_multiplier × history_multiplier
-        /// - Duration bonus: max +20% for long-term stakes
-        /// - History bonus: max +10% for consistent participation
+        /// Calculate voting power (see [`crate::rules::voting_power`])
         pub fn calculate_voting_power(
             info: &VotingPowerInfo<T>,
             current_block: BlockNumberFor<T>,
         ) -> u128 {
-            let base_power = Self::balance_to_u128(info.staked_amount);
-
-            // Calculate stake duration in blocks
             let duration = current_block.saturating_sub(info.stake_locked_at);
             let duration_u32: u32 = duration.try_into().unwrap_or(u32::MAX);
 
-            // Duration multiplier (max +20%)
-            // Assumes 365 days = ~31,536,000 blocks at 1s/block
-            let blocks_per_year = 31_536_000u32;
-            let duration_bonus_bps = (duration_u32 as u64 * 2000 / blocks_per_year as u64).min(2000) as u32;
-            let duration_multiplier = 10000u32.saturating_add(duration_bonus_bps);
-
-            // Participation history bonus (max +10%)
-            let history_bonus_bps = (info.participation_history.saturating_mul(200)).min(1000);
-            let history_multiplier = 10000u32.saturating_add(history_bonus_bps);
-
-            // Combined: base × (1 + duration_bonus) × (1 + history_bonus)
-            let adjusted_power = (base_power as u128)
-                .saturating_mul(duration_multiplier as u128)
-                .saturating_mul(history_multiplier as u128)
-                / 100_000_000u128; // Divide by 10000 × 10000
+            crate::rules::voting_power(
+                Self::balance_to_u128(info.staked_amount),
+                duration_u32,
+                info.participation_history,
+            )
+        }
 
-            adjusted_power
+        /// Replace `remove` with `add` voting power on the `vote` side of a proposal
+        fn adjust_tally(
+            proposal: &mut Proposal<T::AccountId, BalanceOf<T>, BoundedVec<u8, T::MaxTitleLength>>,
+            vote: VoteType,
+            remove: u128,
+            add: u128,
+        ) {
+            let side = match vote {
+                VoteType::Yes => &mut proposal.yes_votes,
+                VoteType::No => &mut proposal.no_votes,
+                VoteType::Abstain => &mut proposal.abstain_votes,
+            };
+            *side = side.saturating_sub(remove).saturating_add(add);
         }
 
         /// Finalize voting (called at end of voting phase)
@@ -1040,34 +1546,16 @@ pub mod pallet {
                     .saturating_add(proposal.no_votes)
                     .saturating_add(proposal.abstain_votes);
 
-                // Community quorum: 33% of circulating supply
-                let community_quorum_met = total_votes >= Self::balance_to_u128(circulating).saturating_mul(33) / 100;
-
-                // Validator quorum: 51% of active validators
-                let validator_quorum_met = proposal.validator_count >= active_validators.saturating_mul(51) / 100;
-
-                let quorum_met = community_quorum_met && validator_quorum_met;
+                // Dual quorum: 33% of circulating supply and 51% of validators
+                let quorum_met = crate::rules::quorum_met(
+                    total_votes,
+                    Self::balance_to_u128(circulating),
+                    proposal.validator_count,
+                    active_validators,
+                );
 
                 if quorum_met {
-                    let total_decisive_votes = proposal.yes_votes.saturating_add(proposal.no_votes);
-
-                    // Determine threshold based on category
-                    let threshold_bps = match proposal.category {
-                        ProposalCategory::BudgetAllocation => 5000,  // 50%
-                        ProposalCategory::ParameterChange => 5000,   // 50%
-                        ProposalCategory::InflationRate => 5000,     // 50%
-                        ProposalCategory::ProtocolUpgrade => 6600,   // 66%
-                        ProposalCategory::EmergencyAction => 6600,   // 66%
-                        ProposalCategory::DirectorElection => 5000, // 50%
-                    };
-
-                    let approval_ratio = if total_decisive_votes > 0 {
-                        proposal.yes_votes.saturating_mul(10000) / total_decisive_votes
-                    } else {
-                        0
-                    };
-
-                    if approval_ratio >= threshold_bps as u128 {
+                    if crate::rules::approved(proposal.category, proposal.yes_votes, proposal.no_votes) {
                         proposal.approved = true;
                         Self::deposit_event(Event::ProposalApproved(
                             proposal_id,
@@ -1115,8 +1603,8 @@ pub mod pallet {
         fn execute_minting_and_treasury_funding() -> DispatchResult {
             let circulating = CirculatingSupply::<T>::get();
             let max_inflation_bps = T::MaxInflationBps::get();
-            let max_mintable = Self::balance_to_u128(circulating)
-                .saturating_mul(max_inflation_bps as u128) / 10000u128;
+            let max_mintable =
+                crate::rules::max_mintable(Self::balance_to_u128(circulating), max_inflation_bps);
 
             let mut total_minted = 0u128;
             let mut category_allocations: Vec<(BudgetCategory, BalanceOf<T>)> = Vec::new();
@@ -1207,13 +1695,24 @@ pub mod pallet {
             let total_proposals = NextProposalId::<T>::get();
 
             // Calculate participation pool (1% of total minted)
-            let participation_pool = total_minted / 100u32.into();
+            let participation_pool =
+                crate::rules::participation_pool(Self::balance_to_u128(total_minted));
+
+            // Unrevealed secret ballots forfeit this year's participation reward
+            let mut forfeited: Vec<T::AccountId> = Vec::new();
+            for (voter, unrevealed) in UnrevealedBallots::<T>::drain() {
+                Self::deposit_event(Event::ParticipationRewardForfeited(voter.clone(), unrevealed));
+                forfeited.push(voter);
+            }
+            forfeited.sort();
+            let eligible = |voter: &T::AccountId| {
+                ProposalsVoted::<T>::get(voter) > 0 && forfeited.binary_search(voter).is_err()
+            };
 
             // Calculate total voting power used
             let mut total_voting_power = 0u128;
             for (voter, _) in VotingPowerMap::<T>::iter() {
-                let proposals_voted = ProposalsVoted::<T>::get(&voter);
-                if proposals_voted > 0 {
+                if eligible(&voter) {
                     let vp_info = VotingPowerMap::<T>::get(&voter);
                     total_voting_power = total_voting_power.saturating_add(vp_info.voting_power);
                 }
@@ -1224,18 +1723,15 @@ pub mod pallet {
                 for (voter, _) in VotingPowerMap::<T>::iter() {
                     let proposals_voted = ProposalsVoted::<T>::get(&voter);
 
-                    if proposals_voted > 0 {
+                    if eligible(&voter) {
                         let vp_info = VotingPowerMap::<T>::get(&voter);
-                        let voter_share = vp_info.voting_power as u128 * Self::balance_to_u128(participation_pool) / total_voting_power;
-
                         // Completeness bonus (20% if voted on all proposals)
-                        let completeness_multiplier = if proposals_voted as u64 >= total_proposals {
-                            12000u128 // 1.2x
-                        } else {
-                            10000u128 // 1.0x
-                        };
-
-                        let final_reward = voter_share.saturating_mul(completeness_multiplier) / 10000u128;
+                        let final_reward = crate::rules::participation_reward(
+                            vp_info.voting_power,
+                            participation_pool,
+                            total_voting_power,
+                            proposals_voted as u64 >= total_proposals,
+                        );
 
                         ParticipationRewards::<T>::insert(
                             &voter,
@@ -1255,25 +1751,19 @@ pub mod pallet {
                 }
             }
 
-            // Elect top 9 directors
-            let mut candidates: Vec<_> = DirectorCandidates::<T>::iter()
-                .map(|(_, candidate)| candidate)
-                .collect();
-
-            // Sort by votes (descending)
-            candidates.sort_by(|a, b| b.votes.cmp(&a.votes));
-
-            let elected: Vec<T::AccountId> = candidates
-                .into_iter()
-                .take(9)
-                .map(|c| c.account)
-                .collect();
+            // Elect 9 directors proportionally
+            let elected = Self::elect_directors();
 
             if elected.len() > 0 {
                 let bounded_elected: BoundedVec<T::AccountId, ConstU32<9>> =
                     elected.clone().try_into().unwrap_or_default();
                 ElectedDirectors::<T>::put(bounded_elected);
 
+                // A treasury failure must not stall the phase machine
+                if let Err(e) = T::Treasury::set_directors(elected.clone()) {
+                    Self::deposit_event(Event::DirectorSetUpdateFailed(e));
+                }
+
                 Self::deposit_event(Event::DirectorsElected(elected));
             }
 
@@ -1290,4 +1780,59 @@ pub mod pallet {
             value.try_into().unwrap_or_else(|_| Bounded::max_value())
         }
     }
+
+    impl<T: Config> DelegationHooks<T::AccountId> for Pallet<T> {
+        fn has_active_votes(who: &T::AccountId, track: TrackId) -> bool {
+            track == CONSENSUS_DAY_TRACK
+                && ConsensusDayState::<T>::get().phase != Phase::Inactive
+                && (Commitments::<T>::iter_key_prefix(who).next().is_some()
+                    || Votes::<T>::iter_key_prefix(who)
+                        .any(|proposal_id| proposal_id >= EventFirstProposal::<T>::get()))
+        }
+
+        fn on_delegated_votes_changed(
+            delegate: &T::AccountId,
+            track: TrackId,
+            old: u128,
+            new: u128,
+        ) -> Weight {
+            // Tallies are final once voting (or revealing) ends
+            let phase = ConsensusDayState::<T>::get().phase;
+            if track != CONSENSUS_DAY_TRACK || !matches!(phase, Phase::Voting | Phase::Reveal) {
+                return Weight::zero();
+            }
+
+            let first = EventFirstProposal::<T>::get();
+            let mut touched = 0u32;
+
+            // Only the delegate's own votes are re-tallied, never its delegators
+            let votes: Vec<_> = Votes::<T>::iter_prefix(delegate)
+                .filter(|(proposal_id, _)| *proposal_id >= first)
+                .collect();
+            DirectorApprovals::<T>::mutate(delegate, |maybe_ballot| {
+                if let Some(ballot) = maybe_ballot {
+                    Self::adjust_candidate_votes(&ballot.candidates, old, new);
+                    ballot.weight = ballot.weight.saturating_sub(old).saturating_add(new);
+                }
+            });
+
+            for (proposal_id, mut record) in votes {
+                touched += 1;
+                let Some(mut proposal) = Proposals::<T>::get(proposal_id) else { continue };
+
+                Self::adjust_tally(&mut proposal, record.vote, old, new);
+                record.voting_power = record.voting_power.saturating_sub(old).saturating_add(new);
+
+                Proposals::<T>::insert(proposal_id, proposal);
+                Votes::<T>::insert(delegate, proposal_id, record.clone());
+                Self::deposit_event(Event::DelegatedVoteUpdated(
+                    delegate.clone(),
+                    proposal_id,
+                    record.voting_power,
+                ));
+            }
+
+            T::WeightInfo::on_delegated_votes_changed(touched)
+        }
+    }
 }
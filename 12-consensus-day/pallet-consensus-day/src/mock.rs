@@ -0,0 +1,95 @@
+//! Mock runtime for Consensus Day pallet tests
+
+use crate as pallet_consensus_day;
+use crate::{BudgetCategory, TreasuryInterface};
+use frame_support::{
+    derive_impl, parameter_types,
+    pallet_prelude::DispatchResult,
+    traits::{ConstU128, ConstU32},
+};
+use sp_runtime::{traits::IdentityLookup, BuildStorage};
+
+type Block = frame_system::mocking::MockBlock<Test>;
+
+frame_support::construct_runtime!(
+    pub enum Test
+    {
+        System: frame_system,
+        Balances: pallet_balances,
+        ConsensusDay: pallet_consensus_day,
+    }
+);
+
+#[derive_impl(frame_system::config_preludes::TestDefaultConfig)]
+impl frame_system::Config for Test {
+    type Block = Block;
+    type AccountId = u64;
+    type Lookup = IdentityLookup<Self::AccountId>;
+    type AccountData = pallet_balances::AccountData<u128>;
+}
+
+parameter_types! {
+    pub const ExistentialDeposit: u128 = 1;
+}
+
+#[derive_impl(pallet_balances::config_preludes::TestDefaultConfig)]
+impl pallet_balances::Config for Test {
+    type Balance = u128;
+    type ExistentialDeposit = ExistentialDeposit;
+    type AccountStore = System;
+}
+
+/// Treasury that accepts every funding and director update
+pub struct MockTreasury;
+impl TreasuryInterface<u64, u128> for MockTreasury {
+    fn fund_treasury(_: &u64, _: u128, _: Vec<(BudgetCategory, u128)>) -> DispatchResult {
+        Ok(())
+    }
+
+    fn set_directors(_: Vec<u64>) -> DispatchResult {
+        Ok(())
+    }
+}
+
+/// Length of every phase in blocks
+pub const PHASE_DURATION: u32 = 10;
+
+impl pallet_consensus_day::Config for Test {
+    type RuntimeEvent = RuntimeEvent;
+    type Currency = Balances;
+    type Treasury = MockTreasury;
+    type RegistrationDuration = ConstU32<PHASE_DURATION>;
+    type VotingDuration = ConstU32<PHASE_DURATION>;
+    type MintingDuration = ConstU32<PHASE_DURATION>;
+    type DistributionDuration = ConstU32<PHASE_DURATION>;
+    type RevealDuration = ConstU32<PHASE_DURATION>;
+    type ProposalBond = ConstU128<100>;
+    type DirectorMinStake = ConstU128<10>;
+    type MaxInflationBps = ConstU32<500>;
+    type MaxProposals = ConstU32<10>;
+    type MaxTitleLength = ConstU32<100>;
+    type DirectorBalancingIterations = ConstU32<2>;
+    type Delegation = ();
+    type MaxVoters = ConstU32<10>;
+    type MaxDirectorCandidates = ConstU32<10>;
+    type MaxCommitments = ConstU32<10>;
+    type WeightInfo = ();
+}
+
+pub const INITIAL_BALANCE: u128 = 10_000;
+
+// Build genesis storage; accounts 1..=4 are funded
+pub fn new_test_ext() -> sp_io::TestExternalities {
+    let mut t = frame_system::GenesisConfig::<Test>::default().build_storage().unwrap();
+
+    pallet_balances::GenesisConfig::<Test> {
+        balances: (1..=4).map(|who| (who, INITIAL_BALANCE)).collect(),
+        ..Default::default()
+    }
+    .assimilate_storage(&mut t)
+    .unwrap();
+
+    let mut ext = sp_io::TestExternalities::new(t);
+    ext.execute_with(|| System::set_block_number(1));
+    ext
+}
@@ -0,0 +1,149 @@
+//! Consensus Day arithmetic
+//!
+//! Plain functions behind voting power, quorum, approval, the inflation cap and
+//! participation rewards. The pallet calls these directly; off-chain tools
+//! (e.g. the Consensus Day simulator) use them to project an outcome without
+//! drifting from the on-chain rules.
+
+use crate::ProposalCategory;
+
+/// Blocks in a year at 1s per block (duration bonus reaches its maximum here)
+pub const BLOCKS_PER_YEAR: u32 = 31_536_000;
+
+/// Maximum stake duration bonus (basis points)
+pub const MAX_DURATION_BONUS_BPS: u32 = 2000;
+
+/// Maximum participation history bonus (basis points)
+pub const MAX_HISTORY_BONUS_BPS: u32 = 1000;
+
+/// History bonus per previous Consensus Day (basis points)
+pub const HISTORY_BONUS_STEP_BPS: u32 = 200;
+
+/// Community quorum: percent of circulating supply that must vote
+pub const COMMUNITY_QUORUM_PERCENT: u128 = 33;
+
+/// Validator quorum: percent of active validators that must vote
+pub const VALIDATOR_QUORUM_PERCENT: u32 = 51;
+
+/// Share of minted tokens paid out as participation rewards (percent)
+pub const PARTICIPATION_POOL_PERCENT: u128 = 1;
+
+/// Reward multiplier for voting on every proposal (basis points)
+pub const COMPLETENESS_BONUS_BPS: u128 = 12000;
+
+/// Voting power for `staked` locked for `stake_duration` blocks
+///
+/// Formula: base_stake × duration_multiplier × history_multiplier
+/// - Duration bonus: max +20% for long-term stakes
+/// - History bonus: max +10% for consistent participation
+pub fn voting_power(staked: u128, stake_duration: u32, participation_history: u32) -> u128 {
+    let duration_bonus_bps = (stake_duration as u64 * MAX_DURATION_BONUS_BPS as u64
+        / BLOCKS_PER_YEAR as u64)
+        .min(MAX_DURATION_BONUS_BPS as u64) as u32;
+    let duration_multiplier = 10000u32.saturating_add(duration_bonus_bps);
+
+    let history_bonus_bps = participation_history
+        .saturating_mul(HISTORY_BONUS_STEP_BPS)
+        .min(MAX_HISTORY_BONUS_BPS);
+    let history_multiplier = 10000u32.saturating_add(history_bonus_bps);
+
+    // Combined: base × (1 + duration_bonus) × (1 + history_bonus)
+    staked
+        .saturating_mul(duration_multiplier as u128)
+        .saturating_mul(history_multiplier as u128)
+        / 100_000_000u128 // Divide by 10000 × 10000
+}
+
+/// Whether both the community (33%) and validator (51%) quorums are met
+pub fn quorum_met(
+    total_votes: u128,
+    circulating_supply: u128,
+    validator_votes: u32,
+    active_validators: u32,
+) -> bool {
+    let community = total_votes
+        >= circulating_supply.saturating_mul(COMMUNITY_QUORUM_PERCENT) / 100;
+    let validator = validator_votes
+        >= active_validators.saturating_mul(VALIDATOR_QUORUM_PERCENT) / 100;
+    community && validator
+}
+
+/// Yes share of decisive votes required to approve a proposal (basis points)
+pub fn approval_threshold_bps(category: ProposalCategory) -> u32 {
+    match category {
+        ProposalCategory::BudgetAllocation => 5000,  // 50%
+        ProposalCategory::ParameterChange => 5000,   // 50%
+        ProposalCategory::InflationRate => 5000,     // 50%
+        ProposalCategory::ProtocolUpgrade => 6600,   // 66%
+        ProposalCategory::EmergencyAction => 6600,   // 66%
+        ProposalCategory::DirectorElection => 5000,  // 50%
+    }
+}
+
+/// Whether `yes` reaches the category threshold of `yes + no`
+pub fn approved(category: ProposalCategory, yes: u128, no: u128) -> bool {
+    let approval_ratio =
+        yes.saturating_mul(10000).checked_div(yes.saturating_add(no)).unwrap_or(0);
+    approval_ratio >= approval_threshold_bps(category) as u128
+}
+
+/// Most that may be minted in one Consensus Day
+pub fn max_mintable(circulating_supply: u128, max_inflation_bps: u32) -> u128 {
+    circulating_supply.saturating_mul(max_inflation_bps as u128) / 10000u128
+}
+
+/// Participation reward pool for `total_minted`
+pub fn participation_pool(total_minted: u128) -> u128 {
+    total_minted.saturating_mul(PARTICIPATION_POOL_PERCENT) / 100
+}
+
+/// A voter's share of the participation pool
+///
+/// Voters who voted on every proposal receive the 1.2x completeness bonus.
+pub fn participation_reward(
+    voting_power: u128,
+    pool: u128,
+    total_voting_power: u128,
+    voted_on_all: bool,
+) -> u128 {
+    if total_voting_power == 0 {
+        return 0;
+    }
+    let share = voting_power.saturating_mul(pool) / total_voting_power;
+    let multiplier = if voted_on_all { COMPLETENESS_BONUS_BPS } else { 10000u128 };
+    share.saturating_mul(multiplier) / 10000u128
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn voting_power_bonuses_are_capped() {
+        assert_eq!(voting_power(1_000_000, 0, 0), 1_000_000);
+        // Half a year: +10%; two prior Consensus Days: +4%
+        assert_eq!(voting_power(1_000_000, BLOCKS_PER_YEAR / 2, 2), 1_144_000);
+        // Caps: +20% duration, +10% history
+        assert_eq!(voting_power(1_000_000, u32::MAX, 100), 1_320_000);
+    }
+
+    #[test]
+    fn quorum_and_thresholds() {
+        assert!(quorum_met(330, 1000, 51, 100));
+        assert!(!quorum_met(329, 1000, 51, 100));
+        assert!(!quorum_met(330, 1000, 50, 100));
+
+        assert!(approved(ProposalCategory::BudgetAllocation, 50, 50));
+        assert!(!approved(ProposalCategory::ProtocolUpgrade, 65, 35));
+        assert!(approved(ProposalCategory::ProtocolUpgrade, 66, 34));
+        assert!(!approved(ProposalCategory::InflationRate, 0, 0));
+    }
+
+    #[test]
+    fn participation_reward_completeness_bonus() {
+        assert_eq!(participation_pool(1_000_000), 10_000);
+        assert_eq!(participation_reward(25, 10_000, 100, false), 2_500);
+        assert_eq!(participation_reward(25, 10_000, 100, true), 3_000);
+        assert_eq!(participation_reward(25, 10_000, 0, true), 0);
+    }
+}
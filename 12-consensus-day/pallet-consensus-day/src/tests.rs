@@ -0,0 +1,168 @@
+//! Tests for Consensus Day pallet
+
+use crate::{
+    mock::*, CommitmentCount, Commitments, Error, Event, Phase, ProposalCategory, UnrevealedBallots,
+    VoteType, Votes,
+};
+use frame_support::{assert_noop, assert_ok};
+use sp_core::H256;
+
+const SALT: [u8; 32] = [7u8; 32];
+
+/// Move past the current phase and advance to the next one
+fn advance() {
+    System::set_block_number(System::block_number() + PHASE_DURATION as u64);
+    assert_ok!(ConsensusDay::advance_phase(RuntimeOrigin::signed(1)));
+}
+
+fn phase() -> Phase {
+    ConsensusDay::consensus_day_state().phase
+}
+
+fn commitment(who: u64, vote: VoteType, salt: &[u8; 32]) -> H256 {
+    ConsensusDay::ballot_commitment(&who, 0, vote, salt)
+}
+
+fn commit(who: u64, vote: VoteType) {
+    assert_ok!(ConsensusDay::commit_vote(
+        RuntimeOrigin::signed(who),
+        0,
+        commitment(who, vote, &SALT)
+    ));
+}
+
+fn reveal(who: u64, vote: VoteType, salt: [u8; 32]) -> frame_support::dispatch::DispatchResult {
+    ConsensusDay::reveal_vote(RuntimeOrigin::signed(who), 0, vote, salt)
+}
+
+/// Secret ballot Consensus Day in Voting with proposal 0 from account 1 and
+/// accounts 2 and 3 holding 1,000 and 2,000 voting power
+fn secret_voting() {
+    assert_ok!(ConsensusDay::set_secret_ballots(RuntimeOrigin::root(), true));
+    assert_ok!(ConsensusDay::start_consensus_day(RuntimeOrigin::root()));
+    assert_ok!(ConsensusDay::submit_proposal(
+        RuntimeOrigin::signed(1),
+        b"raise grants".to_vec(),
+        ProposalCategory::ParameterChange,
+        0,
+        None,
+    ));
+    assert_ok!(ConsensusDay::lock_stake_for_voting(RuntimeOrigin::signed(2), 1_000));
+    assert_ok!(ConsensusDay::lock_stake_for_voting(RuntimeOrigin::signed(3), 2_000));
+    advance();
+    assert_eq!(phase(), Phase::Voting);
+}
+
+#[test]
+fn reveal_matching_commitment_is_tallied() {
+    new_test_ext().execute_with(|| {
+        secret_voting();
+        commit(2, VoteType::Yes);
+        System::assert_last_event(Event::VoteCommitted(2, 0).into());
+        assert_eq!(CommitmentCount::<Test>::get(), 1);
+
+        // Nothing is tallied until the reveal
+        assert_eq!(ConsensusDay::proposals(0).unwrap().yes_votes, 0);
+        advance();
+        assert_eq!(phase(), Phase::Reveal);
+
+        assert_ok!(reveal(2, VoteType::Yes, SALT));
+
+        System::assert_last_event(Event::VoteCast(2, 0, VoteType::Yes, 1_000).into());
+        assert_eq!(ConsensusDay::proposals(0).unwrap().yes_votes, 1_000);
+        assert_eq!(Votes::<Test>::get(2, 0).unwrap().vote, VoteType::Yes);
+        assert!(!Commitments::<Test>::contains_key(2, 0));
+        assert_eq!(CommitmentCount::<Test>::get(), 0);
+
+        // The ballot cannot be revealed twice
+        assert_noop!(reveal(2, VoteType::Yes, SALT), Error::<Test>::NoCommitment);
+    });
+}
+
+#[test]
+fn reveal_with_wrong_salt_or_vote_is_rejected() {
+    new_test_ext().execute_with(|| {
+        secret_voting();
+        commit(2, VoteType::Yes);
+        advance();
+
+        assert_noop!(reveal(2, VoteType::Yes, [8u8; 32]), Error::<Test>::CommitmentMismatch);
+        assert_noop!(reveal(2, VoteType::No, SALT), Error::<Test>::CommitmentMismatch);
+        // Another account cannot reveal the commitment
+        assert_noop!(reveal(3, VoteType::Yes, SALT), Error::<Test>::NoCommitment);
+
+        let proposal = ConsensusDay::proposals(0).unwrap();
+        assert_eq!((proposal.yes_votes, proposal.no_votes), (0, 0));
+        assert!(Commitments::<Test>::contains_key(2, 0));
+
+        assert_ok!(reveal(2, VoteType::Yes, SALT));
+    });
+}
+
+#[test]
+fn reveal_outside_reveal_phase_is_rejected() {
+    new_test_ext().execute_with(|| {
+        secret_voting();
+        commit(2, VoteType::Yes);
+
+        // Too early: still Voting
+        assert_noop!(reveal(2, VoteType::Yes, SALT), Error::<Test>::WrongPhase);
+
+        advance();
+        // Commitments close with Voting
+        assert_noop!(
+            ConsensusDay::commit_vote(RuntimeOrigin::signed(3), 0, commitment(3, VoteType::No, &SALT)),
+            Error::<Test>::WrongPhase
+        );
+
+        // Too late: the Reveal phase has ended
+        advance();
+        assert_eq!(phase(), Phase::Minting);
+        assert_noop!(reveal(2, VoteType::Yes, SALT), Error::<Test>::WrongPhase);
+    });
+}
+
+#[test]
+fn second_commitment_is_rejected() {
+    new_test_ext().execute_with(|| {
+        secret_voting();
+        commit(2, VoteType::Yes);
+
+        assert_noop!(
+            ConsensusDay::commit_vote(RuntimeOrigin::signed(2), 0, commitment(2, VoteType::No, &SALT)),
+            Error::<Test>::AlreadyCommitted
+        );
+        // Open votes are refused in secret ballot mode
+        assert_noop!(
+            ConsensusDay::vote(RuntimeOrigin::signed(2), 0, VoteType::No),
+            Error::<Test>::SecretBallotRequired
+        );
+        assert_eq!(Commitments::<Test>::get(2, 0), Some(commitment(2, VoteType::Yes, &SALT)));
+        assert_eq!(CommitmentCount::<Test>::get(), 1);
+    });
+}
+
+#[test]
+fn unrevealed_commitment_is_not_tallied() {
+    new_test_ext().execute_with(|| {
+        secret_voting();
+        commit(2, VoteType::Yes);
+        commit(3, VoteType::No);
+        advance();
+        assert_ok!(reveal(2, VoteType::Yes, SALT));
+
+        // Account 3 never reveals its larger No vote
+        advance();
+        assert_eq!(phase(), Phase::Minting);
+
+        let proposal = ConsensusDay::proposals(0).unwrap();
+        assert_eq!((proposal.yes_votes, proposal.no_votes), (1_000, 0));
+        assert!(proposal.approved);
+        assert!(!Votes::<Test>::contains_key(3, 0));
+        assert!(!Commitments::<Test>::contains_key(3, 0));
+        assert_eq!(CommitmentCount::<Test>::get(), 0);
+        assert_eq!(UnrevealedBallots::<Test>::get(3), 1);
+        assert_eq!(UnrevealedBallots::<Test>::get(2), 0);
+        System::assert_has_event(Event::BallotUnrevealed(3, 0).into());
+    });
+}
@@ -0,0 +1,604 @@
+//! PLACEHOLDER weights for `pallet_consensus_day`: hand-estimated, not benchmark output.
+//!
+//! Overwrite with `./scripts/benchmark-pallets.sh pallet_consensus_day` on reference hardware.
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(missing_docs)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use core::marker::PhantomData;
+
+/// Weight functions needed for `pallet_consensus_day`.
+pub trait WeightInfo {
+	fn start_consensus_day() -> Weight;
+	fn advance_phase() -> Weight;
+	fn advance_phase_finalize(p: u32, ) -> Weight;
+	fn advance_phase_reveal(p: u32, c: u32, ) -> Weight;
+	fn advance_phase_minting(p: u32, ) -> Weight;
+	fn advance_phase_distribution(v: u32, u: u32, b: u32, c: u32, ) -> Weight;
+	fn submit_proposal() -> Weight;
+	fn vote() -> Weight;
+	fn set_secret_ballots() -> Weight;
+	fn commit_vote() -> Weight;
+	fn reveal_vote() -> Weight;
+	fn submit_director_election(a: u32, b: u32, ) -> Weight;
+	fn lock_stake_for_voting() -> Weight;
+	fn claim_participation_reward() -> Weight;
+	fn nominate_director() -> Weight;
+	fn vote_director() -> Weight;
+	fn register_validator() -> Weight;
+	fn update_circulating_supply() -> Weight;
+	fn execute_approved_budgets(p: u32, ) -> Weight;
+	fn on_delegated_votes_changed(p: u32, ) -> Weight;
+}
+
+/// Placeholder weights for `pallet_consensus_day`, pending benchmarking.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	/// Storage: `ConsensusDayPallet::ConsensusDayState` (r:1 w:1)
+	/// Storage: `ConsensusDayPallet::NextProposalId` (r:1 w:0)
+	/// Storage: `ConsensusDayPallet::TotalMinted` (r:0 w:1)
+	/// Storage: `ConsensusDayPallet::EventFirstProposal` (r:0 w:1)
+	fn start_consensus_day() -> Weight {
+		Weight::from_parts(14_620_000, 2001)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: `ConsensusDayPallet::ConsensusDayState` (r:1 w:1)
+	/// Storage: `ConsensusDayPallet::SecretBallots` (r:1 w:0)
+	fn advance_phase() -> Weight {
+		Weight::from_parts(17_380_000, 1994)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `ConsensusDayPallet::ConsensusDayState` (r:1 w:1)
+	/// Storage: `ConsensusDayPallet::SecretBallots` (r:1 w:0)
+	/// Storage: `ConsensusDayPallet::CirculatingSupply` (r:1 w:0)
+	/// Storage: `ConsensusDayPallet::ActiveValidatorCount` (r:1 w:0)
+	/// Storage: `Balances::TotalIssuance` (r:1 w:1)
+	/// Storage: `ConsensusDayPallet::Proposals` (r:0 w:0)
+	/// Storage: `System::Account` (r:0 w:0)
+	/// The range of component `p` is `[0, 100]`.
+	fn advance_phase_finalize(p: u32, ) -> Weight {
+		Weight::from_parts(24_910_000, 3515)
+			.saturating_add(Weight::from_parts(38_460_000, 0).saturating_mul(p.into()))
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(p.into())))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(p.into())))
+			.saturating_add(Weight::from_parts(0, 5333).saturating_mul(p.into()))
+	}
+	/// Storage: `ConsensusDayPallet::ConsensusDayState` (r:1 w:1)
+	/// Storage: `ConsensusDayPallet::SecretBallots` (r:1 w:0)
+	/// Storage: `ConsensusDayPallet::CirculatingSupply` (r:1 w:0)
+	/// Storage: `ConsensusDayPallet::ActiveValidatorCount` (r:1 w:0)
+	/// Storage: `Balances::TotalIssuance` (r:1 w:1)
+	/// Storage: `ConsensusDayPallet::CommitmentCount` (r:0 w:1)
+	/// Storage: `ConsensusDayPallet::Proposals` (r:0 w:0)
+	/// Storage: `System::Account` (r:0 w:0)
+	/// Storage: `ConsensusDayPallet::Commitments` (r:0 w:0)
+	/// Storage: `ConsensusDayPallet::UnrevealedBallots` (r:0 w:0)
+	/// The range of component `p` is `[0, 100]`.
+	/// The range of component `c` is `[0, 2000]`.
+	fn advance_phase_reveal(p: u32, c: u32, ) -> Weight {
+		Weight::from_parts(26_070_000, 3515)
+			.saturating_add(Weight::from_parts(38_460_000, 0).saturating_mul(p.into()))
+			.saturating_add(Weight::from_parts(11_290_000, 0).saturating_mul(c.into()))
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(p.into())))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(c.into())))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(p.into())))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(c.into())))
+			.saturating_add(Weight::from_parts(0, 5333).saturating_mul(p.into()))
+			.saturating_add(Weight::from_parts(0, 1212).saturating_mul(c.into()))
+	}
+	/// Storage: `ConsensusDayPallet::ConsensusDayState` (r:1 w:1)
+	/// Storage: `ConsensusDayPallet::CirculatingSupply` (r:1 w:0)
+	/// Storage: `ConsensusDayPallet::TotalTreasuryFunded` (r:0 w:2)
+	/// Storage: `ConsensusDayPallet::TreasuryAllocations` (r:1 w:1)
+	/// Storage: `Balances::TotalIssuance` (r:1 w:1)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Storage: `EtridTreasury::TreasuryBalance` (r:1 w:1)
+	/// Storage: `EtridTreasury::FundingSourceTotals` (r:1 w:1)
+	/// Storage: `EtridTreasury::BudgetAllocationsStorage` (r:1 w:0)
+	/// Storage: `EtridTreasury::CategoryAllocations` (r:5 w:5)
+	/// Storage: `ConsensusDayPallet::VotedInflationRate` (r:1 w:1)
+	/// Storage: `ConsensusDayPallet::InflationRate` (r:1 w:1)
+	/// Storage: `ConsensusDayPallet::TotalMinted` (r:1 w:2)
+	/// Storage: `ConsensusDayPallet::Proposals` (r:0 w:0)
+	/// The range of component `p` is `[0, 100]`.
+	fn advance_phase_minting(p: u32, ) -> Weight {
+		Weight::from_parts(96_840_000, 27817)
+			.saturating_add(Weight::from_parts(21_730_000, 0).saturating_mul(p.into()))
+			.saturating_add(T::DbWeight::get().reads(17_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(p.into())))
+			.saturating_add(T::DbWeight::get().writes(18_u64))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(p.into())))
+			.saturating_add(Weight::from_parts(0, 2730).saturating_mul(p.into()))
+	}
+	/// Storage: `ConsensusDayPallet::ConsensusDayState` (r:1 w:1)
+	/// Storage: `ConsensusDayPallet::TotalMinted` (r:1 w:0)
+	/// Storage: `ConsensusDayPallet::NextProposalId` (r:1 w:0)
+	/// Storage: `ConsensusDayPallet::QueuedDirectorElection` (r:1 w:1)
+	/// Storage: `ConsensusDayPallet::DirectorBallotCount` (r:0 w:1)
+	/// Storage: `ConsensusDayPallet::ElectedDirectors` (r:0 w:1)
+	/// Storage: `EtridTreasury::Directors` (r:1 w:1)
+	/// Storage: `ConsensusDayPallet::VotingPowerMap` (r:0 w:0)
+	/// Storage: `ConsensusDayPallet::ProposalsVoted` (r:0 w:0)
+	/// Storage: `ConsensusDayPallet::ParticipationRewards` (r:0 w:0)
+	/// Storage: `ConsensusDayPallet::UnrevealedBallots` (r:0 w:0)
+	/// Storage: `ConsensusDayPallet::DirectorApprovals` (r:0 w:0)
+	/// Storage: `ConsensusDayPallet::DirectorCandidates` (r:0 w:0)
+	/// The range of component `v` is `[0, 1000]`.
+	/// The range of component `u` is `[0, 2000]`.
+	/// The range of component `b` is `[0, 1000]`.
+	/// The range of component `c` is `[1, 100]`.
+	fn advance_phase_distribution(v: u32, u: u32, b: u32, c: u32, ) -> Weight {
+		Weight::from_parts(61_520_000, 4160)
+			.saturating_add(Weight::from_parts(42_180_000, 0).saturating_mul(v.into()))
+			.saturating_add(Weight::from_parts(9_340_000, 0).saturating_mul(u.into()))
+			.saturating_add(Weight::from_parts(58_610_000, 0).saturating_mul(b.into()))
+			.saturating_add(Weight::from_parts(14_270_000, 0).saturating_mul(c.into()))
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(v.into())))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(u.into())))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(b.into())))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(c.into())))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(v.into())))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(u.into())))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(b.into())))
+			.saturating_add(Weight::from_parts(0, 1190).saturating_mul(v.into()))
+			.saturating_add(Weight::from_parts(0, 578).saturating_mul(u.into()))
+			.saturating_add(Weight::from_parts(0, 904).saturating_mul(b.into()))
+			.saturating_add(Weight::from_parts(0, 648).saturating_mul(c.into()))
+	}
+	/// Storage: `ConsensusDayPallet::ConsensusDayState` (r:1 w:0)
+	/// Storage: `ConsensusDayPallet::NextProposalId` (r:1 w:1)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Storage: `ConsensusDayPallet::Proposals` (r:0 w:1)
+	fn submit_proposal() -> Weight {
+		Weight::from_parts(47_390_000, 4604)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: `ConsensusDayPallet::ConsensusDayState` (r:1 w:0)
+	/// Storage: `ConsensusDayPallet::SecretBallots` (r:1 w:0)
+	/// Storage: `ConsensusDayPallet::Votes` (r:1 w:1)
+	/// Storage: `ConsensusDayPallet::Proposals` (r:1 w:1)
+	/// Storage: `VoteDelegation::Delegations` (r:1 w:0)
+	/// Storage: `ConsensusDayPallet::VotingPowerMap` (r:1 w:0)
+	/// Storage: `VoteDelegation::DelegatedVotes` (r:1 w:0)
+	/// Storage: `ConsensusDayPallet::Validators` (r:1 w:0)
+	/// Storage: `ConsensusDayPallet::ProposalsVoted` (r:1 w:1)
+	fn vote() -> Weight {
+		Weight::from_parts(52_840_000, 20052)
+			.saturating_add(T::DbWeight::get().reads(9_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: `ConsensusDayPallet::ConsensusDayState` (r:1 w:0)
+	/// Storage: `ConsensusDayPallet::SecretBallots` (r:0 w:1)
+	fn set_secret_ballots() -> Weight {
+		Weight::from_parts(11_460_000, 1498)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `ConsensusDayPallet::ConsensusDayState` (r:1 w:0)
+	/// Storage: `ConsensusDayPallet::SecretBallots` (r:1 w:0)
+	/// Storage: `ConsensusDayPallet::Proposals` (r:1 w:0)
+	/// Storage: `ConsensusDayPallet::Commitments` (r:1 w:1)
+	/// Storage: `VoteDelegation::Delegations` (r:1 w:0)
+	/// Storage: `ConsensusDayPallet::CommitmentCount` (r:1 w:1)
+	fn commit_vote() -> Weight {
+		Weight::from_parts(34_150_000, 10383)
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `ConsensusDayPallet::ConsensusDayState` (r:1 w:0)
+	/// Storage: `ConsensusDayPallet::Commitments` (r:1 w:1)
+	/// Storage: `ConsensusDayPallet::Votes` (r:1 w:1)
+	/// Storage: `ConsensusDayPallet::Proposals` (r:1 w:1)
+	/// Storage: `VoteDelegation::Delegations` (r:1 w:0)
+	/// Storage: `ConsensusDayPallet::VotingPowerMap` (r:1 w:0)
+	/// Storage: `VoteDelegation::DelegatedVotes` (r:1 w:0)
+	/// Storage: `ConsensusDayPallet::Validators` (r:1 w:0)
+	/// Storage: `ConsensusDayPallet::ProposalsVoted` (r:1 w:1)
+	/// Storage: `ConsensusDayPallet::CommitmentCount` (r:1 w:1)
+	fn reveal_vote() -> Weight {
+		Weight::from_parts(63_270_000, 22634)
+			.saturating_add(T::DbWeight::get().reads(10_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+	/// Storage: `ConsensusDayPallet::ConsensusDayState` (r:1 w:0)
+	/// Storage: `ConsensusDayPallet::DirectorCandidates` (r:9 w:0)
+	/// Storage: `ConsensusDayPallet::QueuedDirectorElection` (r:1 w:1)
+	/// Storage: `ConsensusDayPallet::DirectorApprovals` (r:0 w:0)
+	/// The range of component `a` is `[1, 1000]`.
+	/// The range of component `b` is `[1, 1000]`.
+	fn submit_director_election(a: u32, b: u32, ) -> Weight {
+		Weight::from_parts(31_880_000, 25645)
+			.saturating_add(Weight::from_parts(12_640_000, 0).saturating_mul(a.into()))
+			.saturating_add(Weight::from_parts(4_310_000, 0).saturating_mul(b.into()))
+			.saturating_add(T::DbWeight::get().reads(11_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(a.into())))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(b.into())))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(Weight::from_parts(0, 2844).saturating_mul(a.into()))
+			.saturating_add(Weight::from_parts(0, 2844).saturating_mul(b.into()))
+	}
+	/// Storage: `ConsensusDayPallet::ConsensusDayState` (r:1 w:0)
+	/// Storage: `ConsensusDayPallet::VotingPowerMap` (r:1 w:1)
+	/// Storage: `ConsensusDayPallet::VoterCount` (r:1 w:1)
+	/// Storage: `System::Account` (r:1 w:1)
+	fn lock_stake_for_voting() -> Weight {
+		Weight::from_parts(41_730_000, 7163)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: `ConsensusDayPallet::ConsensusDayState` (r:1 w:0)
+	/// Storage: `ConsensusDayPallet::ParticipationRewards` (r:1 w:1)
+	/// Storage: `System::Account` (r:2 w:2)
+	fn claim_participation_reward() -> Weight {
+		Weight::from_parts(56_910_000, 9243)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: `ConsensusDayPallet::ConsensusDayState` (r:1 w:0)
+	/// Storage: `ConsensusDayPallet::DirectorCandidates` (r:1 w:1)
+	/// Storage: `ConsensusDayPallet::CandidateCount` (r:1 w:1)
+	/// Storage: `System::Account` (r:1 w:1)
+	fn nominate_director() -> Weight {
+		Weight::from_parts(45_280_000, 7187)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: `ConsensusDayPallet::ConsensusDayState` (r:1 w:0)
+	/// Storage: `ConsensusDayPallet::DirectorCandidates` (r:18 w:18)
+	/// Storage: `VoteDelegation::Delegations` (r:1 w:0)
+	/// Storage: `ConsensusDayPallet::VotingPowerMap` (r:1 w:0)
+	/// Storage: `VoteDelegation::DelegatedVotes` (r:1 w:0)
+	/// Storage: `ConsensusDayPallet::DirectorApprovals` (r:1 w:1)
+	fn vote_director() -> Weight {
+		Weight::from_parts(48_960_000, 58620)
+			.saturating_add(T::DbWeight::get().reads(23_u64))
+			.saturating_add(T::DbWeight::get().writes(19_u64))
+	}
+	/// Storage: `ConsensusDayPallet::Validators` (r:1 w:1)
+	/// Storage: `ConsensusDayPallet::ActiveValidatorCount` (r:1 w:1)
+	fn register_validator() -> Weight {
+		Weight::from_parts(15_830_000, 4013)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `ConsensusDayPallet::CirculatingSupply` (r:0 w:1)
+	fn update_circulating_supply() -> Weight {
+		Weight::from_parts(8_120_000, 990)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `ConsensusDayPallet::ConsensusDayState` (r:1 w:0)
+	/// Storage: `ConsensusDayPallet::CirculatingSupply` (r:1 w:0)
+	/// Storage: `ConsensusDayPallet::TotalTreasuryFunded` (r:0 w:2)
+	/// Storage: `ConsensusDayPallet::TreasuryAllocations` (r:1 w:1)
+	/// Storage: `Balances::TotalIssuance` (r:1 w:1)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Storage: `EtridTreasury::TreasuryBalance` (r:1 w:1)
+	/// Storage: `EtridTreasury::FundingSourceTotals` (r:1 w:1)
+	/// Storage: `EtridTreasury::BudgetAllocationsStorage` (r:1 w:0)
+	/// Storage: `EtridTreasury::CategoryAllocations` (r:5 w:5)
+	/// Storage: `ConsensusDayPallet::VotedInflationRate` (r:1 w:0)
+	/// Storage: `ConsensusDayPallet::TotalMinted` (r:0 w:1)
+	/// Storage: `ConsensusDayPallet::Proposals` (r:0 w:0)
+	/// The range of component `p` is `[0, 100]`.
+	fn execute_approved_budgets(p: u32, ) -> Weight {
+		Weight::from_parts(91_450_000, 26807)
+			.saturating_add(Weight::from_parts(21_730_000, 0).saturating_mul(p.into()))
+			.saturating_add(T::DbWeight::get().reads(15_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(p.into())))
+			.saturating_add(T::DbWeight::get().writes(14_u64))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(p.into())))
+			.saturating_add(Weight::from_parts(0, 2730).saturating_mul(p.into()))
+	}
+	/// Storage: `ConsensusDayPallet::ConsensusDayState` (r:1 w:0)
+	/// Storage: `ConsensusDayPallet::EventFirstProposal` (r:1 w:0)
+	/// Storage: `ConsensusDayPallet::DirectorApprovals` (r:1 w:1)
+	/// Storage: `ConsensusDayPallet::DirectorCandidates` (r:9 w:9)
+	/// Storage: `ConsensusDayPallet::Votes` (r:0 w:0)
+	/// Storage: `ConsensusDayPallet::Proposals` (r:0 w:0)
+	/// The range of component `p` is `[0, 100]`.
+	fn on_delegated_votes_changed(p: u32, ) -> Weight {
+		Weight::from_parts(38_540_000, 28128)
+			.saturating_add(Weight::from_parts(19_870_000, 0).saturating_mul(p.into()))
+			.saturating_add(T::DbWeight::get().reads(12_u64))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(p.into())))
+			.saturating_add(T::DbWeight::get().writes(10_u64))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(p.into())))
+			.saturating_add(Weight::from_parts(0, 5295).saturating_mul(p.into()))
+	}
+}
+
+// For backwards compatibility and tests.
+impl WeightInfo for () {
+	/// Storage: `ConsensusDayPallet::ConsensusDayState` (r:1 w:1)
+	/// Storage: `ConsensusDayPallet::NextProposalId` (r:1 w:0)
+	/// Storage: `ConsensusDayPallet::TotalMinted` (r:0 w:1)
+	/// Storage: `ConsensusDayPallet::EventFirstProposal` (r:0 w:1)
+	fn start_consensus_day() -> Weight {
+		Weight::from_parts(14_620_000, 2001)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: `ConsensusDayPallet::ConsensusDayState` (r:1 w:1)
+	/// Storage: `ConsensusDayPallet::SecretBallots` (r:1 w:0)
+	fn advance_phase() -> Weight {
+		Weight::from_parts(17_380_000, 1994)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `ConsensusDayPallet::ConsensusDayState` (r:1 w:1)
+	/// Storage: `ConsensusDayPallet::SecretBallots` (r:1 w:0)
+	/// Storage: `ConsensusDayPallet::CirculatingSupply` (r:1 w:0)
+	/// Storage: `ConsensusDayPallet::ActiveValidatorCount` (r:1 w:0)
+	/// Storage: `Balances::TotalIssuance` (r:1 w:1)
+	/// Storage: `ConsensusDayPallet::Proposals` (r:0 w:0)
+	/// Storage: `System::Account` (r:0 w:0)
+	/// The range of component `p` is `[0, 100]`.
+	fn advance_phase_finalize(p: u32, ) -> Weight {
+		Weight::from_parts(24_910_000, 3515)
+			.saturating_add(Weight::from_parts(38_460_000, 0).saturating_mul(p.into()))
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().reads((2_u64).saturating_mul(p.into())))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+			.saturating_add(RocksDbWeight::get().writes((2_u64).saturating_mul(p.into())))
+			.saturating_add(Weight::from_parts(0, 5333).saturating_mul(p.into()))
+	}
+	/// Storage: `ConsensusDayPallet::ConsensusDayState` (r:1 w:1)
+	/// Storage: `ConsensusDayPallet::SecretBallots` (r:1 w:0)
+	/// Storage: `ConsensusDayPallet::CirculatingSupply` (r:1 w:0)
+	/// Storage: `ConsensusDayPallet::ActiveValidatorCount` (r:1 w:0)
+	/// Storage: `Balances::TotalIssuance` (r:1 w:1)
+	/// Storage: `ConsensusDayPallet::CommitmentCount` (r:0 w:1)
+	/// Storage: `ConsensusDayPallet::Proposals` (r:0 w:0)
+	/// Storage: `System::Account` (r:0 w:0)
+	/// Storage: `ConsensusDayPallet::Commitments` (r:0 w:0)
+	/// Storage: `ConsensusDayPallet::UnrevealedBallots` (r:0 w:0)
+	/// The range of component `p` is `[0, 100]`.
+	/// The range of component `c` is `[0, 2000]`.
+	fn advance_phase_reveal(p: u32, c: u32, ) -> Weight {
+		Weight::from_parts(26_070_000, 3515)
+			.saturating_add(Weight::from_parts(38_460_000, 0).saturating_mul(p.into()))
+			.saturating_add(Weight::from_parts(11_290_000, 0).saturating_mul(c.into()))
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().reads((2_u64).saturating_mul(p.into())))
+			.saturating_add(RocksDbWeight::get().reads((2_u64).saturating_mul(c.into())))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+			.saturating_add(RocksDbWeight::get().writes((2_u64).saturating_mul(p.into())))
+			.saturating_add(RocksDbWeight::get().writes((2_u64).saturating_mul(c.into())))
+			.saturating_add(Weight::from_parts(0, 5333).saturating_mul(p.into()))
+			.saturating_add(Weight::from_parts(0, 1212).saturating_mul(c.into()))
+	}
+	/// Storage: `ConsensusDayPallet::ConsensusDayState` (r:1 w:1)
+	/// Storage: `ConsensusDayPallet::CirculatingSupply` (r:1 w:0)
+	/// Storage: `ConsensusDayPallet::TotalTreasuryFunded` (r:0 w:2)
+	/// Storage: `ConsensusDayPallet::TreasuryAllocations` (r:1 w:1)
+	/// Storage: `Balances::TotalIssuance` (r:1 w:1)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Storage: `EtridTreasury::TreasuryBalance` (r:1 w:1)
+	/// Storage: `EtridTreasury::FundingSourceTotals` (r:1 w:1)
+	/// Storage: `EtridTreasury::BudgetAllocationsStorage` (r:1 w:0)
+	/// Storage: `EtridTreasury::CategoryAllocations` (r:5 w:5)
+	/// Storage: `ConsensusDayPallet::VotedInflationRate` (r:1 w:1)
+	/// Storage: `ConsensusDayPallet::InflationRate` (r:1 w:1)
+	/// Storage: `ConsensusDayPallet::TotalMinted` (r:1 w:2)
+	/// Storage: `ConsensusDayPallet::Proposals` (r:0 w:0)
+	/// The range of component `p` is `[0, 100]`.
+	fn advance_phase_minting(p: u32, ) -> Weight {
+		Weight::from_parts(96_840_000, 27817)
+			.saturating_add(Weight::from_parts(21_730_000, 0).saturating_mul(p.into()))
+			.saturating_add(RocksDbWeight::get().reads(17_u64))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(p.into())))
+			.saturating_add(RocksDbWeight::get().writes(18_u64))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(p.into())))
+			.saturating_add(Weight::from_parts(0, 2730).saturating_mul(p.into()))
+	}
+	/// Storage: `ConsensusDayPallet::ConsensusDayState` (r:1 w:1)
+	/// Storage: `ConsensusDayPallet::TotalMinted` (r:1 w:0)
+	/// Storage: `ConsensusDayPallet::NextProposalId` (r:1 w:0)
+	/// Storage: `ConsensusDayPallet::QueuedDirectorElection` (r:1 w:1)
+	/// Storage: `ConsensusDayPallet::DirectorBallotCount` (r:0 w:1)
+	/// Storage: `ConsensusDayPallet::ElectedDirectors` (r:0 w:1)
+	/// Storage: `EtridTreasury::Directors` (r:1 w:1)
+	/// Storage: `ConsensusDayPallet::VotingPowerMap` (r:0 w:0)
+	/// Storage: `ConsensusDayPallet::ProposalsVoted` (r:0 w:0)
+	/// Storage: `ConsensusDayPallet::ParticipationRewards` (r:0 w:0)
+	/// Storage: `ConsensusDayPallet::UnrevealedBallots` (r:0 w:0)
+	/// Storage: `ConsensusDayPallet::DirectorApprovals` (r:0 w:0)
+	/// Storage: `ConsensusDayPallet::DirectorCandidates` (r:0 w:0)
+	/// The range of component `v` is `[0, 1000]`.
+	/// The range of component `u` is `[0, 2000]`.
+	/// The range of component `b` is `[0, 1000]`.
+	/// The range of component `c` is `[1, 100]`.
+	fn advance_phase_distribution(v: u32, u: u32, b: u32, c: u32, ) -> Weight {
+		Weight::from_parts(61_520_000, 4160)
+			.saturating_add(Weight::from_parts(42_180_000, 0).saturating_mul(v.into()))
+			.saturating_add(Weight::from_parts(9_340_000, 0).saturating_mul(u.into()))
+			.saturating_add(Weight::from_parts(58_610_000, 0).saturating_mul(b.into()))
+			.saturating_add(Weight::from_parts(14_270_000, 0).saturating_mul(c.into()))
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().reads((2_u64).saturating_mul(v.into())))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(u.into())))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(b.into())))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(c.into())))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+			.saturating_add(RocksDbWeight::get().writes((2_u64).saturating_mul(v.into())))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(u.into())))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(b.into())))
+			.saturating_add(Weight::from_parts(0, 1190).saturating_mul(v.into()))
+			.saturating_add(Weight::from_parts(0, 578).saturating_mul(u.into()))
+			.saturating_add(Weight::from_parts(0, 904).saturating_mul(b.into()))
+			.saturating_add(Weight::from_parts(0, 648).saturating_mul(c.into()))
+	}
+	/// Storage: `ConsensusDayPallet::ConsensusDayState` (r:1 w:0)
+	/// Storage: `ConsensusDayPallet::NextProposalId` (r:1 w:1)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Storage: `ConsensusDayPallet::Proposals` (r:0 w:1)
+	fn submit_proposal() -> Weight {
+		Weight::from_parts(47_390_000, 4604)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: `ConsensusDayPallet::ConsensusDayState` (r:1 w:0)
+	/// Storage: `ConsensusDayPallet::SecretBallots` (r:1 w:0)
+	/// Storage: `ConsensusDayPallet::Votes` (r:1 w:1)
+	/// Storage: `ConsensusDayPallet::Proposals` (r:1 w:1)
+	/// Storage: `VoteDelegation::Delegations` (r:1 w:0)
+	/// Storage: `ConsensusDayPallet::VotingPowerMap` (r:1 w:0)
+	/// Storage: `VoteDelegation::DelegatedVotes` (r:1 w:0)
+	/// Storage: `ConsensusDayPallet::Validators` (r:1 w:0)
+	/// Storage: `ConsensusDayPallet::ProposalsVoted` (r:1 w:1)
+	fn vote() -> Weight {
+		Weight::from_parts(52_840_000, 20052)
+			.saturating_add(RocksDbWeight::get().reads(9_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: `ConsensusDayPallet::ConsensusDayState` (r:1 w:0)
+	/// Storage: `ConsensusDayPallet::SecretBallots` (r:0 w:1)
+	fn set_secret_ballots() -> Weight {
+		Weight::from_parts(11_460_000, 1498)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `ConsensusDayPallet::ConsensusDayState` (r:1 w:0)
+	/// Storage: `ConsensusDayPallet::SecretBallots` (r:1 w:0)
+	/// Storage: `ConsensusDayPallet::Proposals` (r:1 w:0)
+	/// Storage: `ConsensusDayPallet::Commitments` (r:1 w:1)
+	/// Storage: `VoteDelegation::Delegations` (r:1 w:0)
+	/// Storage: `ConsensusDayPallet::CommitmentCount` (r:1 w:1)
+	fn commit_vote() -> Weight {
+		Weight::from_parts(34_150_000, 10383)
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `ConsensusDayPallet::ConsensusDayState` (r:1 w:0)
+	/// Storage: `ConsensusDayPallet::Commitments` (r:1 w:1)
+	/// Storage: `ConsensusDayPallet::Votes` (r:1 w:1)
+	/// Storage: `ConsensusDayPallet::Proposals` (r:1 w:1)
+	/// Storage: `VoteDelegation::Delegations` (r:1 w:0)
+	/// Storage: `ConsensusDayPallet::VotingPowerMap` (r:1 w:0)
+	/// Storage: `VoteDelegation::DelegatedVotes` (r:1 w:0)
+	/// Storage: `ConsensusDayPallet::Validators` (r:1 w:0)
+	/// Storage: `ConsensusDayPallet::ProposalsVoted` (r:1 w:1)
+	/// Storage: `ConsensusDayPallet::CommitmentCount` (r:1 w:1)
+	fn reveal_vote() -> Weight {
+		Weight::from_parts(63_270_000, 22634)
+			.saturating_add(RocksDbWeight::get().reads(10_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
+	/// Storage: `ConsensusDayPallet::ConsensusDayState` (r:1 w:0)
+	/// Storage: `ConsensusDayPallet::DirectorCandidates` (r:9 w:0)
+	/// Storage: `ConsensusDayPallet::QueuedDirectorElection` (r:1 w:1)
+	/// Storage: `ConsensusDayPallet::DirectorApprovals` (r:0 w:0)
+	/// The range of component `a` is `[1, 1000]`.
+	/// The range of component `b` is `[1, 1000]`.
+	fn submit_director_election(a: u32, b: u32, ) -> Weight {
+		Weight::from_parts(31_880_000, 25645)
+			.saturating_add(Weight::from_parts(12_640_000, 0).saturating_mul(a.into()))
+			.saturating_add(Weight::from_parts(4_310_000, 0).saturating_mul(b.into()))
+			.saturating_add(RocksDbWeight::get().reads(11_u64))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(a.into())))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(b.into())))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(Weight::from_parts(0, 2844).saturating_mul(a.into()))
+			.saturating_add(Weight::from_parts(0, 2844).saturating_mul(b.into()))
+	}
+	/// Storage: `ConsensusDayPallet::ConsensusDayState` (r:1 w:0)
+	/// Storage: `ConsensusDayPallet::VotingPowerMap` (r:1 w:1)
+	/// Storage: `ConsensusDayPallet::VoterCount` (r:1 w:1)
+	/// Storage: `System::Account` (r:1 w:1)
+	fn lock_stake_for_voting() -> Weight {
+		Weight::from_parts(41_730_000, 7163)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: `ConsensusDayPallet::ConsensusDayState` (r:1 w:0)
+	/// Storage: `ConsensusDayPallet::ParticipationRewards` (r:1 w:1)
+	/// Storage: `System::Account` (r:2 w:2)
+	fn claim_participation_reward() -> Weight {
+		Weight::from_parts(56_910_000, 9243)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: `ConsensusDayPallet::ConsensusDayState` (r:1 w:0)
+	/// Storage: `ConsensusDayPallet::DirectorCandidates` (r:1 w:1)
+	/// Storage: `ConsensusDayPallet::CandidateCount` (r:1 w:1)
+	/// Storage: `System::Account` (r:1 w:1)
+	fn nominate_director() -> Weight {
+		Weight::from_parts(45_280_000, 7187)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: `ConsensusDayPallet::ConsensusDayState` (r:1 w:0)
+	/// Storage: `ConsensusDayPallet::DirectorCandidates` (r:18 w:18)
+	/// Storage: `VoteDelegation::Delegations` (r:1 w:0)
+	/// Storage: `ConsensusDayPallet::VotingPowerMap` (r:1 w:0)
+	/// Storage: `VoteDelegation::DelegatedVotes` (r:1 w:0)
+	/// Storage: `ConsensusDayPallet::DirectorApprovals` (r:1 w:1)
+	fn vote_director() -> Weight {
+		Weight::from_parts(48_960_000, 58620)
+			.saturating_add(RocksDbWeight::get().reads(23_u64))
+			.saturating_add(RocksDbWeight::get().writes(19_u64))
+	}
+	/// Storage: `ConsensusDayPallet::Validators` (r:1 w:1)
+	/// Storage: `ConsensusDayPallet::ActiveValidatorCount` (r:1 w:1)
+	fn register_validator() -> Weight {
+		Weight::from_parts(15_830_000, 4013)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `ConsensusDayPallet::CirculatingSupply` (r:0 w:1)
+	fn update_circulating_supply() -> Weight {
+		Weight::from_parts(8_120_000, 990)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `ConsensusDayPallet::ConsensusDayState` (r:1 w:0)
+	/// Storage: `ConsensusDayPallet::CirculatingSupply` (r:1 w:0)
+	/// Storage: `ConsensusDayPallet::TotalTreasuryFunded` (r:0 w:2)
+	/// Storage: `ConsensusDayPallet::TreasuryAllocations` (r:1 w:1)
+	/// Storage: `Balances::TotalIssuance` (r:1 w:1)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Storage: `EtridTreasury::TreasuryBalance` (r:1 w:1)
+	/// Storage: `EtridTreasury::FundingSourceTotals` (r:1 w:1)
+	/// Storage: `EtridTreasury::BudgetAllocationsStorage` (r:1 w:0)
+	/// Storage: `EtridTreasury::CategoryAllocations` (r:5 w:5)
+	/// Storage: `ConsensusDayPallet::VotedInflationRate` (r:1 w:0)
+	/// Storage: `ConsensusDayPallet::TotalMinted` (r:0 w:1)
+	/// Storage: `ConsensusDayPallet::Proposals` (r:0 w:0)
+	/// The range of component `p` is `[0, 100]`.
+	fn execute_approved_budgets(p: u32, ) -> Weight {
+		Weight::from_parts(91_450_000, 26807)
+			.saturating_add(Weight::from_parts(21_730_000, 0).saturating_mul(p.into()))
+			.saturating_add(RocksDbWeight::get().reads(15_u64))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(p.into())))
+			.saturating_add(RocksDbWeight::get().writes(14_u64))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(p.into())))
+			.saturating_add(Weight::from_parts(0, 2730).saturating_mul(p.into()))
+	}
+	/// Storage: `ConsensusDayPallet::ConsensusDayState` (r:1 w:0)
+	/// Storage: `ConsensusDayPallet::EventFirstProposal` (r:1 w:0)
+	/// Storage: `ConsensusDayPallet::DirectorApprovals` (r:1 w:1)
+	/// Storage: `ConsensusDayPallet::DirectorCandidates` (r:9 w:9)
+	/// Storage: `ConsensusDayPallet::Votes` (r:0 w:0)
+	/// Storage: `ConsensusDayPallet::Proposals` (r:0 w:0)
+	/// The range of component `p` is `[0, 100]`.
+	fn on_delegated_votes_changed(p: u32, ) -> Weight {
+		Weight::from_parts(38_540_000, 28128)
+			.saturating_add(Weight::from_parts(19_870_000, 0).saturating_mul(p.into()))
+			.saturating_add(RocksDbWeight::get().reads(12_u64))
+			.saturating_add(RocksDbWeight::get().reads((2_u64).saturating_mul(p.into())))
+			.saturating_add(RocksDbWeight::get().writes(10_u64))
+			.saturating_add(RocksDbWeight::get().writes((2_u64).saturating_mul(p.into())))
+			.saturating_add(Weight::from_parts(0, 5295).saturating_mul(p.into()))
+	}
+}
@@ -0,0 +1,73 @@
+//! Benchmarking setup for consensus-day-proposal-system
+
+#![cfg(feature = "runtime-benchmarks")]
+
+use super::*;
+use frame_benchmarking::v2::*;
+use frame_support::assert_ok;
+use frame_system::RawOrigin;
+use sp_runtime::traits::Saturating;
+use sp_std::vec;
+
+/// Registered participant with enough balance for its deposit
+fn participant<T: Config>() -> T::AccountId {
+    let who: T::AccountId = whitelisted_caller();
+    let balance = T::RegistrationDeposit::get()
+        .saturating_add(T::Currency::minimum_balance())
+        .saturating_mul(10u32.into());
+    T::Currency::make_free_balance_be(&who, balance);
+    who
+}
+
+/// Pending proposal with a title and description of maximum length
+fn add_proposal<T: Config>(proposer: T::AccountId) -> u64 {
+    let id = NextProposalId::<T>::get();
+    Proposals::<T>::insert(id, ProposalRecord {
+        proposer,
+        category: ProposalCategory::General,
+        title: vec![b't'; MAX_TITLE_LEN as usize],
+        description: vec![b'd'; MAX_DESCRIPTION_LEN as usize],
+        created_at: 0,
+        status: ProposalStatus::Pending,
+        votes_for: 0,
+        votes_against: 0,
+    });
+    NextProposalId::<T>::put(id + 1);
+    id
+}
+
+#[benchmarks]
+mod benchmarks {
+    use super::*;
+
+    #[benchmark]
+    fn register_participant() {
+        let caller = participant::<T>();
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller.clone()));
+
+        assert!(Participants::<T>::get(&caller));
+    }
+
+    #[benchmark]
+    fn submit_proposal(t: Linear<0, MAX_TITLE_LEN>, d: Linear<0, MAX_DESCRIPTION_LEN>) {
+        let caller = participant::<T>();
+        assert_ok!(Pallet::<T>::register_participant(RawOrigin::Signed(caller.clone()).into()));
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller), 4, vec![b't'; t as usize], vec![b'd'; d as usize]);
+
+        assert!(Proposals::<T>::contains_key(0));
+    }
+
+    #[benchmark(pov_mode = Measured)]
+    fn update_status() {
+        let id = add_proposal::<T>(participant::<T>());
+
+        #[extrinsic_call]
+        _(RawOrigin::Root, id, 1);
+
+        assert_eq!(Proposals::<T>::get(id).unwrap().status, ProposalStatus::Active);
+    }
+}
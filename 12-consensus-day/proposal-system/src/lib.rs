@@ -11,6 +11,13 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 
 pub use pallet::*;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
+pub mod weights;
+pub use weights::WeightInfo;
+
 use frame_support::{
     pallet_prelude::*,
     traits::{Currency, ReservableCurrency, Get},
@@ -23,6 +30,11 @@ use sp_std::vec::Vec;
 
 // -------------------- CORE TYPES --------------------
 
+/// Maximum proposal title length in bytes.
+pub const MAX_TITLE_LEN: u32 = 256;
+/// Maximum proposal description length in bytes.
+pub const MAX_DESCRIPTION_LEN: u32 = 8192;
+
 /// Classification of proposals that can be registered.
 #[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
 pub enum ProposalCategory {
@@ -99,6 +111,8 @@ pub mod pallet {
         /// Minimum deposit required to register or submit a proposal.
         #[pallet::constant]
         type RegistrationDeposit: Get<BalanceOf<Self>>;
+        /// Weight information for extrinsics.
+        type WeightInfo: WeightInfo;
     }
 
     /// Registry of all proposals by an auto-incremented ID.
@@ -138,6 +152,8 @@ pub mod pallet {
         InsufficientDeposit,
         InvalidCategory,
         InvalidStatus,
+        TitleTooLong,
+        DescriptionTooLong,
     }
 
     #[pallet::pallet]
@@ -148,7 +164,7 @@ pub mod pallet {
     impl<T: Config> Pallet<T> {
         /// Register an account for the upcoming Consensus Day.
         #[pallet::call_index(0)]
-        #[pallet::weight(10_000)]
+        #[pallet::weight(T::WeightInfo::register_participant())]
         pub fn register_participant(origin: OriginFor<T>) -> DispatchResult {
             let who = ensure_signed(origin)?;
             ensure!(!Participants::<T>::contains_key(&who), Error::<T>::AlreadyRegistered);
@@ -168,7 +184,7 @@ pub mod pallet {
 
         /// Submit a new proposal to be voted on during Consensus Day.
         #[pallet::call_index(1)]
-        #[pallet::weight(20_000)]
+        #[pallet::weight(T::WeightInfo::submit_proposal(title.len() as u32, description.len() as u32))]
         pub fn submit_proposal(
             origin: OriginFor<T>,
             category: u8,
@@ -177,6 +193,11 @@ pub mod pallet {
         ) -> DispatchResult {
             let who = ensure_signed(origin)?;
             ensure!(Participants::<T>::get(&who), Error::<T>::NotRegistered);
+            ensure!(title.len() as u32 <= MAX_TITLE_LEN, Error::<T>::TitleTooLong);
+            ensure!(
+                description.len() as u32 <= MAX_DESCRIPTION_LEN,
+                Error::<T>::DescriptionTooLong
+            );
 
             let category_enum = ProposalCategory::from_u8(category)
                 .ok_or(Error::<T>::InvalidCategory)?;
@@ -203,7 +224,7 @@ pub mod pallet {
 
         /// Update a proposal's status (root call – Foundation DAO or Governance pallet).
         #[pallet::call_index(2)]
-        #[pallet::weight(5_000)]
+        #[pallet::weight(T::WeightInfo::update_status())]
         pub fn update_status(
             origin: OriginFor<T>,
             proposal_id: u64,
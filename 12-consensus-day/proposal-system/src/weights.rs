@@ -0,0 +1,77 @@
+//! PLACEHOLDER weights for `consensus_day_proposal_system`: hand-estimated, not benchmark output.
+//!
+//! Overwrite with `./scripts/benchmark-pallets.sh consensus_day_proposal_system` on reference hardware.
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(missing_docs)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use core::marker::PhantomData;
+
+/// Weight functions needed for `consensus_day_proposal_system`.
+pub trait WeightInfo {
+	fn register_participant() -> Weight;
+	fn submit_proposal(t: u32, d: u32, ) -> Weight;
+	fn update_status() -> Weight;
+}
+
+/// Placeholder weights for `consensus_day_proposal_system`, pending benchmarking.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	/// Storage: `ConsensusDayProposalSystem::Participants` (r:1 w:1)
+	/// Storage: `System::Account` (r:1 w:1)
+	fn register_participant() -> Weight {
+		Weight::from_parts(34_810_000, 6117)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `ConsensusDayProposalSystem::Participants` (r:1 w:0)
+	/// Storage: `ConsensusDayProposalSystem::NextProposalId` (r:1 w:1)
+	/// Storage: `ConsensusDayProposalSystem::Proposals` (r:0 w:1)
+	/// The range of component `t` is `[0, 256]`.
+	/// The range of component `d` is `[0, 8192]`.
+	fn submit_proposal(t: u32, d: u32, ) -> Weight {
+		Weight::from_parts(21_470_000, 4017)
+			.saturating_add(Weight::from_parts(1_214, 0).saturating_mul(t.into()))
+			.saturating_add(Weight::from_parts(1_187, 0).saturating_mul(d.into()))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `ConsensusDayProposalSystem::Proposals` (r:1 w:1)
+	fn update_status() -> Weight {
+		Weight::from_parts(24_960_000, 9612)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+}
+
+// For backwards compatibility and tests.
+impl WeightInfo for () {
+	/// Storage: `ConsensusDayProposalSystem::Participants` (r:1 w:1)
+	/// Storage: `System::Account` (r:1 w:1)
+	fn register_participant() -> Weight {
+		Weight::from_parts(34_810_000, 6117)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `ConsensusDayProposalSystem::Participants` (r:1 w:0)
+	/// Storage: `ConsensusDayProposalSystem::NextProposalId` (r:1 w:1)
+	/// Storage: `ConsensusDayProposalSystem::Proposals` (r:0 w:1)
+	/// The range of component `t` is `[0, 256]`.
+	/// The range of component `d` is `[0, 8192]`.
+	fn submit_proposal(t: u32, d: u32, ) -> Weight {
+		Weight::from_parts(21_470_000, 4017)
+			.saturating_add(Weight::from_parts(1_214, 0).saturating_mul(t.into()))
+			.saturating_add(Weight::from_parts(1_187, 0).saturating_mul(d.into()))
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `ConsensusDayProposalSystem::Proposals` (r:1 w:1)
+	fn update_status() -> Weight {
+		Weight::from_parts(24_960_000, 9612)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+}
@@ -0,0 +1,487 @@
+//! Ëtrid Consensus-Day — Dry-Run Simulator
+//!
+//! Projects the outcome of the next Consensus Day from current stakes and
+//! declared intentions: which proposals pass, how much is minted and where it
+//! goes, the resulting inflation rate, the elected director set and what each
+//! account is paid.
+//!
+//! The simulation replays the four phases with the same arithmetic the chain
+//! uses: voting power, quorum, thresholds, the inflation cap and participation
+//! rewards come from [`pallet_consensus_day::rules`], the director election from
+//! [`pallet_consensus_day::election`], and the fiscal split of minted tokens from
+//! the `consensus-day-distribution` shares.
+//!
+//! A snapshot is either written by hand as JSON (see [`Snapshot`]) or read from
+//! exported chain storage (see [`storage`]). Intentions the chain would reject
+//! (e.g. a delegator voting, a duplicate vote) are reported in
+//! [`Outcome::skipped`] instead of failing the run.
+
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    fmt,
+};
+
+use codec::Encode;
+use consensus_day_distribution::{DIRECTORS_SHARE, FOUNDATION_SHARE, VALIDATORS_SHARE, VOTERS_SHARE};
+use pallet_consensus_day::{election, rules, DIRECTOR_SEATS};
+use serde::Serialize;
+use sp_core::hashing::blake2_128;
+
+pub mod snapshot;
+pub mod storage;
+
+pub use snapshot::Snapshot;
+use snapshot::{Account, Budget, Category, Choice};
+
+/// Errors loading a snapshot
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SimulationError {
+    /// JSON snapshot could not be parsed
+    InvalidSnapshot(String),
+    /// Storage dump is not a set of hex key/value pairs
+    InvalidStorage(String),
+    /// A storage item did not decode with the runtime's types
+    Undecodable(&'static str),
+}
+
+impl fmt::Display for SimulationError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            SimulationError::InvalidSnapshot(e) => write!(f, "Invalid snapshot: {}", e),
+            SimulationError::InvalidStorage(e) => write!(f, "Invalid storage dump: {}", e),
+            SimulationError::Undecodable(item) => write!(f, "Could not decode storage item {}", item),
+        }
+    }
+}
+
+impl std::error::Error for SimulationError {}
+
+/// Projected Consensus Day result
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize)]
+pub struct Outcome {
+    pub proposals: Vec<ProposalOutcome>,
+    /// Inflation cap for this Consensus Day
+    pub max_mintable: u128,
+    pub total_minted: u128,
+    pub treasury_allocations: BTreeMap<Budget, u128>,
+    /// Inflation rate after Minting (basis points)
+    pub inflation_rate_bps: u32,
+    pub participation_pool: u128,
+    pub directors: Vec<Account>,
+    /// Weakest elected director's backing
+    pub director_min_support: u128,
+    pub fiscal_split: FiscalSplit,
+    pub accounts: BTreeMap<Account, AccountOutcome>,
+    pub skipped: Vec<Skipped>,
+}
+
+/// Tally and result of one proposal
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct ProposalOutcome {
+    pub id: u64,
+    pub title: String,
+    pub category: Category,
+    pub yes_votes: u128,
+    pub no_votes: u128,
+    pub abstain_votes: u128,
+    pub validator_votes: u32,
+    pub quorum_met: bool,
+    pub approved: bool,
+    /// Budget minted for this proposal
+    pub minted: u128,
+}
+
+/// Minted total split by the distribution shares
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize)]
+pub struct FiscalSplit {
+    pub foundation: u128,
+    pub directors: u128,
+    pub validators: u128,
+    pub voters: u128,
+}
+
+/// Power used and payouts for one account
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize)]
+pub struct AccountOutcome {
+    pub voting_power: u128,
+    pub delegated_votes: u128,
+    pub proposals_voted: u32,
+    pub participation_reward: u128,
+    /// Director, validator and voter shares of the fiscal split
+    pub fiscal_payout: u128,
+}
+
+impl AccountOutcome {
+    pub fn total_payout(&self) -> u128 {
+        self.participation_reward.saturating_add(self.fiscal_payout)
+    }
+}
+
+/// An intention the chain would reject
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct Skipped {
+    pub who: Account,
+    pub reason: String,
+}
+
+#[derive(Default)]
+struct Tally {
+    yes: u128,
+    no: u128,
+    abstain: u128,
+    validators: u32,
+}
+
+/// Replay registration, voting, minting and distribution over `snapshot`
+pub fn simulate(snapshot: &Snapshot) -> Outcome {
+    let mut outcome = Outcome::default();
+    let mut skip = |who: &str, reason: &str| {
+        outcome.skipped.push(Skipped { who: who.to_string(), reason: reason.to_string() })
+    };
+
+    // Registration: voting power and delegations
+    let mut power: BTreeMap<&str, u128> = BTreeMap::new();
+    let mut validators: BTreeSet<&str> = BTreeSet::new();
+    for stake in snapshot.stakes.iter() {
+        let voting_power = stake.voting_power.unwrap_or_else(|| {
+            rules::voting_power(
+                stake.staked,
+                snapshot.block_number.saturating_sub(stake.stake_locked_at),
+                stake.participation_history,
+            )
+        });
+        power.insert(&stake.who, voting_power);
+        if stake.validator {
+            validators.insert(&stake.who);
+        }
+    }
+
+    let mut delegating: BTreeSet<&str> = BTreeSet::new();
+    let mut delegated: BTreeMap<&str, u128> = BTreeMap::new();
+    for d in snapshot.delegations.iter() {
+        if d.delegator == d.delegate || !delegating.insert(&d.delegator) {
+            skip(&d.delegator, "invalid or duplicate delegation");
+            continue;
+        }
+        let votes = pallet_vote_delegation::Conviction::from(d.conviction).votes(d.balance);
+        let total = delegated.entry(&d.delegate).or_default();
+        *total = total.saturating_add(votes);
+    }
+
+    // Own stake-weighted power plus votes delegated to the account
+    let weight = |who: &str| {
+        power.get(who).copied().unwrap_or(0).saturating_add(delegated.get(who).copied().unwrap_or(0))
+    };
+
+    // Voting
+    let proposal_ids: BTreeSet<u64> = snapshot.proposals.iter().map(|p| p.id).collect();
+    let mut tallies: BTreeMap<u64, Tally> = BTreeMap::new();
+    let mut cast: BTreeSet<(&str, u64)> = BTreeSet::new();
+    let mut proposals_voted: BTreeMap<&str, u32> = BTreeMap::new();
+    for v in snapshot.votes.iter() {
+        let voting_power = weight(&v.voter);
+        let reason = if !proposal_ids.contains(&v.proposal_id) {
+            Some("proposal not found")
+        } else if delegating.contains(v.voter.as_str()) {
+            Some("voter is delegating")
+        } else if voting_power == 0 {
+            Some("no voting power")
+        } else if !cast.insert((v.voter.as_str(), v.proposal_id)) {
+            Some("already voted")
+        } else {
+            None
+        };
+        if let Some(reason) = reason {
+            skip(&v.voter, reason);
+            continue;
+        }
+
+        let tally = tallies.entry(v.proposal_id).or_default();
+        let side = match v.vote {
+            Choice::Yes => &mut tally.yes,
+            Choice::No => &mut tally.no,
+            Choice::Abstain => &mut tally.abstain,
+        };
+        *side = side.saturating_add(voting_power);
+        if validators.contains(v.voter.as_str()) {
+            tally.validators = tally.validators.saturating_add(1);
+        }
+        *proposals_voted.entry(&v.voter).or_default() += 1;
+    }
+
+    let mut ballots: BTreeMap<&str, election::Voter<Account>> = BTreeMap::new();
+    for b in snapshot.director_ballots.iter() {
+        let distinct: BTreeSet<&Account> = b.candidates.iter().collect();
+        let voting_power = weight(&b.voter);
+        let reason = if b.candidates.is_empty()
+            || b.candidates.len() > DIRECTOR_SEATS as usize
+            || distinct.len() != b.candidates.len()
+        {
+            Some("invalid director ballot")
+        } else if !b.candidates.iter().all(|c| snapshot.director_candidates.contains(c)) {
+            Some("director candidate not found")
+        } else if delegating.contains(b.voter.as_str()) {
+            Some("voter is delegating")
+        } else if voting_power == 0 {
+            Some("no voting power")
+        } else {
+            None
+        };
+        if let Some(reason) = reason {
+            skip(&b.voter, reason);
+            continue;
+        }
+
+        // A new ballot replaces the previous one
+        ballots.insert(
+            b.voter.as_str(),
+            election::Voter { who: b.voter.clone(), weight: voting_power, approvals: b.candidates.clone() },
+        );
+    }
+
+    // Finalize voting
+    let circulating = snapshot.circulating_supply;
+    let active_validators = snapshot.active_validator_count.unwrap_or(validators.len() as u32);
+    for p in snapshot.proposals.iter() {
+        let tally = tallies.remove(&p.id).unwrap_or_default();
+        let total_votes = tally.yes.saturating_add(tally.no).saturating_add(tally.abstain);
+        let quorum_met = rules::quorum_met(total_votes, circulating, tally.validators, active_validators);
+        let approved = quorum_met && rules::approved(p.category.into(), tally.yes, tally.no);
+
+        outcome.proposals.push(ProposalOutcome {
+            id: p.id,
+            title: p.title.clone(),
+            category: p.category,
+            yes_votes: tally.yes,
+            no_votes: tally.no,
+            abstain_votes: tally.abstain,
+            validator_votes: tally.validators,
+            quorum_met,
+            approved,
+            minted: 0,
+        });
+    }
+
+    // Minting: approved budgets in on-chain iteration order (Blake2_128Concat of the ID)
+    // until the inflation cap is reached
+    outcome.max_mintable = rules::max_mintable(circulating, snapshot.max_inflation_bps);
+    let mut mint_order: Vec<usize> = (0..outcome.proposals.len()).collect();
+    mint_order.sort_by_key(|i| {
+        let id = outcome.proposals[*i].id.encode();
+        [blake2_128(&id).to_vec(), id].concat()
+    });
+    for i in mint_order {
+        let p = &snapshot.proposals[i];
+        if !outcome.proposals[i].approved || p.category != Category::BudgetAllocation {
+            continue;
+        }
+        if outcome.total_minted.saturating_add(p.budget_request) > outcome.max_mintable {
+            continue;
+        }
+        outcome.total_minted = outcome.total_minted.saturating_add(p.budget_request);
+        outcome.proposals[i].minted = p.budget_request;
+        if let Some(category) = p.budget_category {
+            let allocation = outcome.treasury_allocations.entry(category).or_default();
+            *allocation = allocation.saturating_add(p.budget_request);
+        }
+    }
+    outcome.inflation_rate_bps = snapshot.voted_inflation_rate_bps.unwrap_or(snapshot.inflation_rate_bps);
+
+    // Distribution: participation rewards from own (not delegated) voting power
+    outcome.participation_pool = rules::participation_pool(outcome.total_minted);
+    let total_proposals = snapshot.next_proposal_id.unwrap_or(snapshot.proposals.len() as u64);
+    let participants: Vec<(&str, u128, u32)> = power
+        .iter()
+        .filter_map(|(who, p)| proposals_voted.get(who).map(|voted| (*who, *p, *voted)))
+        .collect();
+    let total_voting_power = participants.iter().fold(0u128, |acc, (_, p, _)| acc.saturating_add(*p));
+
+    for (who, voting_power, voted) in participants.iter() {
+        let reward = rules::participation_reward(
+            *voting_power,
+            outcome.participation_pool,
+            total_voting_power,
+            *voted as u64 >= total_proposals,
+        );
+        outcome.accounts.entry(who.to_string()).or_default().participation_reward = reward;
+    }
+
+    // Director election
+    let voters: Vec<election::Voter<Account>> = ballots.into_values().collect();
+    let mut result =
+        election::seq_phragmen(DIRECTOR_SEATS as usize, &snapshot.director_candidates, &voters);
+    election::balance(&mut result.assignments, snapshot.balancing_iterations as usize, 0);
+    outcome.director_min_support =
+        election::evaluate(&result.winners, &election::supports(&result.assignments)).minimal_support;
+    outcome.directors = result.winners;
+
+    // Fiscal split of the minted total
+    let share = |percent: u8| outcome.total_minted.saturating_mul(percent as u128) / 100;
+    outcome.fiscal_split = FiscalSplit {
+        foundation: share(FOUNDATION_SHARE),
+        directors: share(DIRECTORS_SHARE),
+        validators: share(VALIDATORS_SHARE),
+        voters: share(VOTERS_SHARE),
+    };
+    let voter_accounts: Vec<&str> = proposals_voted.keys().copied().collect();
+    let recipients = [
+        (outcome.fiscal_split.directors, outcome.directors.iter().map(|d| d.as_str()).collect::<Vec<_>>()),
+        (outcome.fiscal_split.validators, validators.iter().copied().collect()),
+        (outcome.fiscal_split.voters, voter_accounts),
+    ];
+    for (amount, accounts) in recipients.iter() {
+        if accounts.is_empty() {
+            continue;
+        }
+        let each = amount / accounts.len() as u128;
+        for who in accounts.iter() {
+            let entry = outcome.accounts.entry(who.to_string()).or_default();
+            entry.fiscal_payout = entry.fiscal_payout.saturating_add(each);
+        }
+    }
+
+    for (who, voted) in proposals_voted.iter() {
+        outcome.accounts.entry(who.to_string()).or_default().proposals_voted = *voted;
+    }
+    for who in power.keys().chain(delegated.keys()) {
+        let entry = outcome.accounts.entry(who.to_string()).or_default();
+        entry.voting_power = power.get(who).copied().unwrap_or(0);
+        entry.delegated_votes = delegated.get(who).copied().unwrap_or(0);
+    }
+
+    outcome
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snapshot::{Delegation, DirectorBallot, Proposal, Stake, Vote};
+
+    fn stake(who: &str, staked: u128, validator: bool) -> Stake {
+        Stake { who: who.into(), staked, validator, ..Default::default() }
+    }
+
+    fn vote(voter: &str, proposal_id: u64, vote: Choice) -> Vote {
+        Vote { voter: voter.into(), proposal_id, vote }
+    }
+
+    fn budget(id: u64, request: u128) -> Proposal {
+        Proposal {
+            id,
+            proposer: "alice".into(),
+            title: format!("Budget {}", id),
+            category: Category::BudgetAllocation,
+            budget_request: request,
+            budget_category: Some(Budget::Infrastructure),
+        }
+    }
+
+    fn snapshot() -> Snapshot {
+        Snapshot {
+            circulating_supply: 1_000_000,
+            stakes: vec![stake("alice", 400_000, true), stake("bob", 200_000, true), stake("carol", 100_000, false)],
+            proposals: vec![budget(0, 30_000), budget(1, 30_000)],
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn approved_budgets_are_minted_up_to_the_cap() {
+        let mut s = snapshot();
+        for id in [0, 1] {
+            s.votes.push(vote("alice", id, Choice::Yes));
+            s.votes.push(vote("bob", id, Choice::No));
+        }
+
+        let outcome = simulate(&s);
+
+        assert!(outcome.proposals.iter().all(|p| p.quorum_met && p.approved));
+        // 5% of 1,000,000 only covers one 30,000 budget
+        assert_eq!(outcome.max_mintable, 50_000);
+        assert_eq!(outcome.total_minted, 30_000);
+        assert_eq!(outcome.proposals.iter().filter(|p| p.minted > 0).count(), 1);
+        assert_eq!(outcome.treasury_allocations.get(&Budget::Infrastructure), Some(&30_000));
+        assert_eq!(outcome.fiscal_split.foundation, 12_000);
+    }
+
+    #[test]
+    fn quorum_requires_validators_and_supply() {
+        let mut s = snapshot();
+        // Carol alone is neither 33% of supply nor 51% of validators
+        s.votes.push(vote("carol", 0, Choice::Yes));
+
+        let outcome = simulate(&s);
+
+        assert!(!outcome.proposals[0].quorum_met);
+        assert_eq!(outcome.total_minted, 0);
+    }
+
+    #[test]
+    fn delegated_votes_count_and_delegators_are_skipped() {
+        let mut s = snapshot();
+        s.delegations.push(Delegation {
+            delegator: "carol".into(),
+            delegate: "bob".into(),
+            conviction: snapshot::Conviction::Locked2x,
+            balance: 100_000,
+        });
+        s.votes.push(vote("carol", 0, Choice::Yes));
+        s.votes.push(vote("bob", 0, Choice::Yes));
+        s.votes.push(vote("bob", 0, Choice::No));
+
+        let outcome = simulate(&s);
+
+        assert_eq!(outcome.proposals[0].yes_votes, 400_000);
+        assert_eq!(outcome.accounts["bob"].delegated_votes, 200_000);
+        assert_eq!(
+            outcome.skipped.iter().map(|s| s.reason.as_str()).collect::<Vec<_>>(),
+            vec!["voter is delegating", "already voted"]
+        );
+    }
+
+    #[test]
+    fn payouts_and_directors() {
+        let mut s = snapshot();
+        s.director_candidates = vec!["dave".into(), "erin".into()];
+        s.director_ballots = vec![
+            DirectorBallot { voter: "alice".into(), candidates: vec!["dave".into()] },
+            DirectorBallot { voter: "bob".into(), candidates: vec!["erin".into(), "mallory".into()] },
+        ];
+        for id in [0, 1] {
+            s.votes.push(vote("alice", id, Choice::Yes));
+        }
+        s.votes.push(vote("bob", 0, Choice::Yes));
+
+        let outcome = simulate(&s);
+
+        assert_eq!(outcome.directors, vec!["dave".to_string()]);
+        assert_eq!(outcome.skipped[0].reason, "director candidate not found");
+        // Pool is 1% of 30,000; alice voted on everything and gets the 1.2x bonus
+        assert_eq!(outcome.participation_pool, 300);
+        assert_eq!(outcome.accounts["alice"].participation_reward, 240);
+        assert_eq!(outcome.accounts["bob"].participation_reward, 100);
+        // 20% to the single director, 30% split by two validators, 10% by two voters
+        assert_eq!(outcome.accounts["dave"].fiscal_payout, 6_000);
+        assert_eq!(outcome.accounts["alice"].fiscal_payout, 4_500 + 1_500);
+        assert_eq!(outcome.accounts["carol"].total_payout(), 0);
+    }
+
+    #[test]
+    fn json_snapshot_round_trip() {
+        let json = r#"{
+            "block_number": 100,
+            "circulating_supply": 1000,
+            "stakes": [{ "who": "alice", "staked": 500, "validator": true }],
+            "delegations": [{ "delegator": "bob", "delegate": "alice", "conviction": "locked1x", "balance": 10 }],
+            "proposals": [{ "id": 0, "category": "inflation_rate" }],
+            "votes": [{ "voter": "alice", "proposal_id": 0, "vote": "yes" }]
+        }"#;
+
+        let s = Snapshot::from_json(json).unwrap();
+
+        assert_eq!(s.max_inflation_bps, snapshot::DEFAULT_MAX_INFLATION_BPS);
+        assert_eq!(s.stakes, vec![stake("alice", 500, true)]);
+        assert_eq!(simulate(&s).proposals[0].yes_votes, 510);
+        assert!(Snapshot::from_json("{\"stakes\": 1}").is_err());
+    }
+}
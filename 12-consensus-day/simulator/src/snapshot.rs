@@ -0,0 +1,252 @@
+//! Simulation input: state snapshot plus declared intentions
+
+use pallet_consensus_day::{BudgetCategory, ProposalCategory, VoteType};
+use serde::{Deserialize, Serialize};
+
+use crate::SimulationError;
+
+/// Account identifier as it appears in the snapshot (SS58 or any label)
+pub type Account = String;
+
+/// Runtime `MaxInflationBps`
+pub const DEFAULT_MAX_INFLATION_BPS: u32 = 500;
+
+/// Runtime `DirectorBalancingIterations`
+pub const DEFAULT_BALANCING_ITERATIONS: u32 = 10;
+
+/// Everything the simulation needs to replay one Consensus Day
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Snapshot {
+    /// Block at which stakes are registered (voting power is computed here)
+    pub block_number: u32,
+    /// Circulating supply for the community quorum and the inflation cap
+    pub circulating_supply: u128,
+    /// Active validators for the validator quorum (defaults to the validator accounts)
+    pub active_validator_count: Option<u32>,
+    /// Current inflation rate (basis points)
+    pub inflation_rate_bps: u32,
+    /// Inflation rate applied at Minting, if one was voted
+    pub voted_inflation_rate_bps: Option<u32>,
+    pub max_inflation_bps: u32,
+    pub balancing_iterations: u32,
+    /// `NextProposalId`, used for the completeness bonus (defaults to the proposal count)
+    pub next_proposal_id: Option<u64>,
+    pub stakes: Vec<Stake>,
+    pub delegations: Vec<Delegation>,
+    pub proposals: Vec<Proposal>,
+    pub votes: Vec<Vote>,
+    pub director_candidates: Vec<Account>,
+    pub director_ballots: Vec<DirectorBallot>,
+}
+
+impl Default for Snapshot {
+    fn default() -> Self {
+        Self {
+            block_number: 0,
+            circulating_supply: 0,
+            active_validator_count: None,
+            inflation_rate_bps: 0,
+            voted_inflation_rate_bps: None,
+            max_inflation_bps: DEFAULT_MAX_INFLATION_BPS,
+            balancing_iterations: DEFAULT_BALANCING_ITERATIONS,
+            next_proposal_id: None,
+            stakes: Vec::new(),
+            delegations: Vec::new(),
+            proposals: Vec::new(),
+            votes: Vec::new(),
+            director_candidates: Vec::new(),
+            director_ballots: Vec::new(),
+        }
+    }
+}
+
+impl Snapshot {
+    /// Parse a JSON snapshot
+    pub fn from_json(json: &str) -> Result<Self, SimulationError> {
+        serde_json::from_str(json).map_err(|e| SimulationError::InvalidSnapshot(e.to_string()))
+    }
+}
+
+/// Stake locked for voting (`lock_stake_for_voting`)
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Stake {
+    pub who: Account,
+    pub staked: u128,
+    pub stake_locked_at: u32,
+    /// Previous Consensus Days participated in
+    pub participation_history: u32,
+    pub validator: bool,
+    /// Voting power already recorded on-chain; computed from the stake when absent
+    pub voting_power: Option<u128>,
+}
+
+/// Consensus Day track delegation
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Delegation {
+    pub delegator: Account,
+    pub delegate: Account,
+    #[serde(default)]
+    pub conviction: Conviction,
+    pub balance: u128,
+}
+
+/// Mirror of `pallet_vote_delegation::Conviction`
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Conviction {
+    #[default]
+    None,
+    Locked1x,
+    Locked2x,
+    Locked3x,
+    Locked4x,
+    Locked5x,
+    Locked6x,
+}
+
+impl From<Conviction> for pallet_vote_delegation::Conviction {
+    fn from(c: Conviction) -> Self {
+        match c {
+            Conviction::None => Self::None,
+            Conviction::Locked1x => Self::Locked1x,
+            Conviction::Locked2x => Self::Locked2x,
+            Conviction::Locked3x => Self::Locked3x,
+            Conviction::Locked4x => Self::Locked4x,
+            Conviction::Locked5x => Self::Locked5x,
+            Conviction::Locked6x => Self::Locked6x,
+        }
+    }
+}
+
+impl From<pallet_vote_delegation::Conviction> for Conviction {
+    fn from(c: pallet_vote_delegation::Conviction) -> Self {
+        use pallet_vote_delegation::Conviction as C;
+        match c {
+            C::None => Self::None,
+            C::Locked1x => Self::Locked1x,
+            C::Locked2x => Self::Locked2x,
+            C::Locked3x => Self::Locked3x,
+            C::Locked4x => Self::Locked4x,
+            C::Locked5x => Self::Locked5x,
+            C::Locked6x => Self::Locked6x,
+        }
+    }
+}
+
+/// Submitted proposal
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Proposal {
+    pub id: u64,
+    #[serde(default)]
+    pub proposer: Account,
+    #[serde(default)]
+    pub title: String,
+    pub category: Category,
+    #[serde(default)]
+    pub budget_request: u128,
+    #[serde(default)]
+    pub budget_category: Option<Budget>,
+}
+
+/// Mirror of `ProposalCategory`
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Category {
+    InflationRate,
+    ParameterChange,
+    BudgetAllocation,
+    ProtocolUpgrade,
+    DirectorElection,
+    EmergencyAction,
+}
+
+impl From<Category> for ProposalCategory {
+    fn from(c: Category) -> Self {
+        match c {
+            Category::InflationRate => Self::InflationRate,
+            Category::ParameterChange => Self::ParameterChange,
+            Category::BudgetAllocation => Self::BudgetAllocation,
+            Category::ProtocolUpgrade => Self::ProtocolUpgrade,
+            Category::DirectorElection => Self::DirectorElection,
+            Category::EmergencyAction => Self::EmergencyAction,
+        }
+    }
+}
+
+impl From<ProposalCategory> for Category {
+    fn from(c: ProposalCategory) -> Self {
+        match c {
+            ProposalCategory::InflationRate => Self::InflationRate,
+            ProposalCategory::ParameterChange => Self::ParameterChange,
+            ProposalCategory::BudgetAllocation => Self::BudgetAllocation,
+            ProposalCategory::ProtocolUpgrade => Self::ProtocolUpgrade,
+            ProposalCategory::DirectorElection => Self::DirectorElection,
+            ProposalCategory::EmergencyAction => Self::EmergencyAction,
+        }
+    }
+}
+
+/// Mirror of `BudgetCategory`
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Budget {
+    Infrastructure,
+    Marketing,
+    Security,
+    CommunityGrants,
+    Operations,
+    Research,
+    Legal,
+    EmergencyReserves,
+}
+
+impl From<BudgetCategory> for Budget {
+    fn from(c: BudgetCategory) -> Self {
+        match c {
+            BudgetCategory::Infrastructure => Self::Infrastructure,
+            BudgetCategory::Marketing => Self::Marketing,
+            BudgetCategory::Security => Self::Security,
+            BudgetCategory::CommunityGrants => Self::CommunityGrants,
+            BudgetCategory::Operations => Self::Operations,
+            BudgetCategory::Research => Self::Research,
+            BudgetCategory::Legal => Self::Legal,
+            BudgetCategory::EmergencyReserves => Self::EmergencyReserves,
+        }
+    }
+}
+
+/// Declared (or already cast) proposal vote
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Vote {
+    pub voter: Account,
+    pub proposal_id: u64,
+    pub vote: Choice,
+}
+
+/// Mirror of `VoteType`
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Choice {
+    Yes,
+    No,
+    Abstain,
+}
+
+impl From<VoteType> for Choice {
+    fn from(v: VoteType) -> Self {
+        match v {
+            VoteType::Yes => Self::Yes,
+            VoteType::No => Self::No,
+            VoteType::Abstain => Self::Abstain,
+        }
+    }
+}
+
+/// Declared (or already cast) director approval ballot
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DirectorBallot {
+    pub voter: Account,
+    pub candidates: Vec<Account>,
+}
@@ -0,0 +1,264 @@
+//! Snapshot from exported chain storage
+//!
+//! Accepts a key/value dump of a live chain: a JSON object `{"0x…": "0x…"}`,
+//! the array of pairs returned by `state_getPairs`, or a raw chain spec
+//! (`genesis.raw.top`). Only the Consensus Day and vote delegation pallets are
+//! read, and values are decoded with the runtime's `AccountId32`, `u128`
+//! balances and `u32` block numbers.
+//!
+//! Votes and director ballots already cast are carried over as intentions.
+//! Secret ballot commitments are hidden until revealed, so they are not.
+
+use std::collections::{BTreeMap, BTreeSet};
+
+use codec::Decode;
+use pallet_consensus_day::{DirectorBallot as OnChainBallot, Proposal as OnChainProposal, VoteRecord};
+use pallet_vote_delegation::{Delegation as OnChainDelegation, TrackId, CONSENSUS_DAY_TRACK};
+use sp_core::{
+    crypto::{AccountId32, Ss58Codec},
+    hashing::twox_128,
+};
+
+use crate::{
+    snapshot::{Account, Delegation, DirectorBallot, Proposal, Snapshot, Stake, Vote},
+    SimulationError,
+};
+
+/// `pallet_consensus_day` name in `construct_runtime!`
+pub const CONSENSUS_DAY_PALLET: &str = "ConsensusDayPallet";
+
+/// `pallet_vote_delegation` name in `construct_runtime!`
+pub const DELEGATION_PALLET: &str = "VoteDelegation";
+
+/// Raw storage, ordered by key like on-chain iteration
+pub type StoragePairs = BTreeMap<Vec<u8>, Vec<u8>>;
+
+/// `VotingPowerInfo` with the runtime's balance and block number types
+type VotingPowerInfo = (u128, u32, u32, u128);
+
+/// Parse an exported storage dump
+pub fn parse_pairs(json: &str) -> Result<StoragePairs, SimulationError> {
+    let invalid = |e: String| SimulationError::InvalidStorage(e);
+    let value: serde_json::Value = serde_json::from_str(json).map_err(|e| invalid(e.to_string()))?;
+
+    let top = value.pointer("/genesis/raw/top").unwrap_or(&value);
+    let entries: Vec<(&str, &str)> = match top {
+        serde_json::Value::Object(map) => map
+            .iter()
+            .map(|(k, v)| v.as_str().map(|v| (k.as_str(), v)))
+            .collect::<Option<_>>()
+            .ok_or_else(|| invalid("storage values must be hex strings".into()))?,
+        serde_json::Value::Array(pairs) => pairs
+            .iter()
+            .map(|pair| match pair.as_array().map(|p| p.as_slice()) {
+                Some([k, v]) => k.as_str().zip(v.as_str()),
+                _ => None,
+            })
+            .collect::<Option<_>>()
+            .ok_or_else(|| invalid("expected [key, value] hex string pairs".into()))?,
+        _ => return Err(invalid("expected a storage object or an array of pairs".into())),
+    };
+
+    let unhex = |s: &str| hex::decode(s.trim_start_matches("0x")).map_err(|e| invalid(e.to_string()));
+    entries.into_iter().map(|(k, v)| Ok((unhex(k)?, unhex(v)?))).collect()
+}
+
+/// Build a snapshot from exported storage, registering stakes at `block_number`
+pub fn from_storage(pairs: &StoragePairs, block_number: u32) -> Result<Snapshot, SimulationError> {
+    let cd = CONSENSUS_DAY_PALLET;
+    let mut snapshot = Snapshot {
+        block_number,
+        circulating_supply: value(pairs, cd, "CirculatingSupply")?.unwrap_or_default(),
+        active_validator_count: value(pairs, cd, "ActiveValidatorCount")?,
+        inflation_rate_bps: value(pairs, cd, "InflationRate")?.unwrap_or_default(),
+        voted_inflation_rate_bps: value(pairs, cd, "VotedInflationRate")?,
+        next_proposal_id: value(pairs, cd, "NextProposalId")?,
+        ..Default::default()
+    };
+
+    // Registered stakes and validators
+    let mut validators = BTreeSet::new();
+    for (key, raw) in entries(pairs, cd, "Validators") {
+        let who: AccountId32 =
+            blake2_128_concat(&mut &key[..]).ok_or(SimulationError::Undecodable("Validators"))?;
+        if decode::<bool>("Validators", raw)? {
+            validators.insert(who);
+        }
+    }
+    for (key, raw) in entries(pairs, cd, "VotingPowerMap") {
+        let who: AccountId32 =
+            blake2_128_concat(&mut &key[..]).ok_or(SimulationError::Undecodable("VotingPowerMap"))?;
+        let (staked, stake_locked_at, participation_history, voting_power) =
+            decode::<VotingPowerInfo>("VotingPowerMap", raw)?;
+        snapshot.stakes.push(Stake {
+            who: account(&who),
+            staked,
+            stake_locked_at,
+            participation_history,
+            validator: validators.remove(&who),
+            voting_power: Some(voting_power),
+        });
+    }
+    snapshot.stakes.extend(validators.iter().map(|who| Stake {
+        who: account(who),
+        validator: true,
+        ..Default::default()
+    }));
+
+    for (_, raw) in entries(pairs, cd, "Proposals") {
+        let p: OnChainProposal<AccountId32, u128, Vec<u8>> = decode("Proposals", raw)?;
+        snapshot.proposals.push(Proposal {
+            id: p.id,
+            proposer: account(&p.proposer),
+            title: String::from_utf8_lossy(&p.title).into_owned(),
+            category: p.category.into(),
+            budget_request: p.budget_request,
+            budget_category: p.budget_category.map(Into::into),
+        });
+    }
+
+    for (key, raw) in entries(pairs, cd, "Votes") {
+        let mut input = key;
+        let (voter, proposal_id) = blake2_128_concat::<AccountId32>(&mut input)
+            .zip(blake2_128_concat::<u64>(&mut input))
+            .ok_or(SimulationError::Undecodable("Votes"))?;
+        let record: VoteRecord = decode("Votes", raw)?;
+        snapshot.votes.push(Vote { voter: account(&voter), proposal_id, vote: record.vote.into() });
+    }
+
+    for (key, _) in entries(pairs, cd, "DirectorCandidates") {
+        let who: AccountId32 =
+            blake2_128_concat(&mut &key[..]).ok_or(SimulationError::Undecodable("DirectorCandidates"))?;
+        snapshot.director_candidates.push(account(&who));
+    }
+    for (key, raw) in entries(pairs, cd, "DirectorApprovals") {
+        let voter: AccountId32 =
+            blake2_128_concat(&mut &key[..]).ok_or(SimulationError::Undecodable("DirectorApprovals"))?;
+        let ballot: OnChainBallot<AccountId32> = decode("DirectorApprovals", raw)?;
+        snapshot.director_ballots.push(DirectorBallot {
+            voter: account(&voter),
+            candidates: ballot.candidates.iter().map(account).collect(),
+        });
+    }
+
+    for (key, raw) in entries(pairs, DELEGATION_PALLET, "Delegations") {
+        let mut input = key;
+        let (delegator, track) = blake2_128_concat::<AccountId32>(&mut input)
+            .zip(twox_64_concat::<TrackId>(&mut input))
+            .ok_or(SimulationError::Undecodable("Delegations"))?;
+        if track != CONSENSUS_DAY_TRACK {
+            continue;
+        }
+        let d: OnChainDelegation<AccountId32, u128> = decode("Delegations", raw)?;
+        snapshot.delegations.push(Delegation {
+            delegator: account(&delegator),
+            delegate: account(&d.target),
+            conviction: d.conviction.into(),
+            balance: d.balance,
+        });
+    }
+
+    Ok(snapshot)
+}
+
+/// `twox_128(pallet) ++ twox_128(item)`
+fn storage_prefix(pallet: &str, item: &str) -> Vec<u8> {
+    [twox_128(pallet.as_bytes()), twox_128(item.as_bytes())].concat()
+}
+
+/// Decoded value of a storage value item
+fn value<D: Decode>(
+    pairs: &StoragePairs,
+    pallet: &str,
+    item: &'static str,
+) -> Result<Option<D>, SimulationError> {
+    pairs.get(&storage_prefix(pallet, item)).map(|raw| decode(item, raw)).transpose()
+}
+
+/// `(hashed key, raw value)` of every entry of a storage map
+fn entries<'a>(
+    pairs: &'a StoragePairs,
+    pallet: &str,
+    item: &str,
+) -> impl Iterator<Item = (&'a [u8], &'a [u8])> {
+    let prefix = storage_prefix(pallet, item);
+    let len = prefix.len();
+    pairs
+        .range(prefix.clone()..)
+        .take_while(move |(k, _)| k.starts_with(&prefix))
+        .map(move |(k, v)| (&k[len..], &v[..]))
+}
+
+fn decode<D: Decode>(item: &'static str, mut raw: &[u8]) -> Result<D, SimulationError> {
+    D::decode(&mut raw).map_err(|_| SimulationError::Undecodable(item))
+}
+
+/// Read a `Blake2_128Concat` key part
+fn blake2_128_concat<K: Decode>(input: &mut &[u8]) -> Option<K> {
+    *input = input.get(16..)?;
+    K::decode(input).ok()
+}
+
+/// Read a `Twox64Concat` key part
+fn twox_64_concat<K: Decode>(input: &mut &[u8]) -> Option<K> {
+    *input = input.get(8..)?;
+    K::decode(input).ok()
+}
+
+fn account(who: &AccountId32) -> Account {
+    who.to_ss58check()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use codec::Encode;
+    use sp_core::hashing::blake2_128;
+
+    fn map_key(item: &str, key: &[u8]) -> Vec<u8> {
+        [storage_prefix(CONSENSUS_DAY_PALLET, item), blake2_128(key).to_vec(), key.to_vec()].concat()
+    }
+
+    #[test]
+    fn decodes_stakes_and_values_from_state_pairs() {
+        let alice = AccountId32::new([1u8; 32]);
+        let pairs = [
+            (storage_prefix(CONSENSUS_DAY_PALLET, "CirculatingSupply"), 1_000_000u128.encode()),
+            (map_key("Validators", &alice.encode()), true.encode()),
+            (
+                map_key("VotingPowerMap", &alice.encode()),
+                (500u128, 10u32, 2u32, 520u128).encode(),
+            ),
+        ];
+        let json = serde_json::to_string(
+            &pairs
+                .iter()
+                .map(|(k, v)| [format!("0x{}", hex::encode(k)), format!("0x{}", hex::encode(v))])
+                .collect::<Vec<_>>(),
+        )
+        .unwrap();
+
+        let snapshot = from_storage(&parse_pairs(&json).unwrap(), 100).unwrap();
+
+        assert_eq!(snapshot.circulating_supply, 1_000_000);
+        assert_eq!(snapshot.active_validator_count, None);
+        assert_eq!(
+            snapshot.stakes,
+            vec![Stake {
+                who: alice.to_ss58check(),
+                staked: 500,
+                stake_locked_at: 10,
+                participation_history: 2,
+                validator: true,
+                voting_power: Some(520),
+            }]
+        );
+    }
+
+    #[test]
+    fn rejects_malformed_dumps() {
+        assert!(parse_pairs("[[\"0x00\"]]").is_err());
+        assert!(parse_pairs("{\"0x00\": 1}").is_err());
+        assert!(parse_pairs("{\"0xzz\": \"0x00\"}").is_err());
+    }
+}
@@ -0,0 +1,54 @@
+//! Benchmarking setup for consensus-day-voting-protocol
+
+#![cfg(feature = "runtime-benchmarks")]
+
+use super::*;
+use frame_benchmarking::v2::*;
+use frame_system::RawOrigin;
+
+const SEED: u32 = 0;
+
+/// Record `v` yes votes on `proposal_id`
+fn add_votes<T: Config>(proposal_id: u64, v: u32) {
+    for i in 0..v {
+        let voter: T::AccountId = account("voter", i, SEED);
+        Votes::<T>::insert(proposal_id, &voter, VoteRecord { voter: voter.clone(), ballot: Ballot::Yes, voted_at: 0 });
+    }
+    VoteCount::<T>::insert(proposal_id, (v, 0));
+}
+
+#[benchmarks]
+mod benchmarks {
+    use super::*;
+
+    #[benchmark]
+    fn cast_vote() {
+        let caller: T::AccountId = whitelisted_caller();
+        add_votes::<T>(0, 100);
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller.clone()), 0, 0);
+
+        assert!(Votes::<T>::contains_key(0, &caller));
+        assert_eq!(VoteCount::<T>::get(0), (101, 0));
+    }
+
+    #[benchmark]
+    fn close_voting() {
+        add_votes::<T>(0, 1);
+
+        #[extrinsic_call]
+        _(RawOrigin::Root, 0);
+    }
+
+    #[benchmark]
+    fn reset_votes(v: Linear<0, MAX_RESET_PER_CALL>) {
+        add_votes::<T>(0, v);
+
+        #[extrinsic_call]
+        _(RawOrigin::Root, 0);
+
+        assert_eq!(Votes::<T>::iter_prefix(0).count(), 0);
+        assert_eq!(VoteCount::<T>::get(0), (0, 0));
+    }
+}
@@ -17,6 +17,15 @@ pub mod runtime;
 pub mod queries;
 pub mod validation;
 pub mod runtime_config;
+pub mod weights;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
+pub use weights::WeightInfo;
+
+/// Maximum votes removed by one `reset_votes` call
+pub const MAX_RESET_PER_CALL: u32 = 1_000;
 
 /// Ballot options for voting - Moved outside pallet to avoid DecodeWithMemTracking issues
 #[derive(Clone, Copy, PartialEq, Eq, Encode, Decode, RuntimeDebug, TypeInfo, MaxEncodedLen)]
@@ -56,6 +65,9 @@ pub mod pallet {
     #[pallet::config]
     pub trait Config: frame_system::Config {
         type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+        /// Weight information for extrinsics
+        type WeightInfo: WeightInfo;
     }
 
     #[pallet::pallet]
@@ -116,7 +128,7 @@ pub mod pallet {
     impl<T: Config> Pallet<T> {
         /// Cast a vote on an active proposal
         #[pallet::call_index(0)]
-        #[pallet::weight(10_000)]
+        #[pallet::weight(T::WeightInfo::cast_vote())]
         pub fn cast_vote(
             origin: OriginFor<T>,
             proposal_id: u64,
@@ -160,7 +172,7 @@ pub mod pallet {
 
         /// Close voting for a proposal (admin only)
         #[pallet::call_index(1)]
-        #[pallet::weight(10_000)]
+        #[pallet::weight(T::WeightInfo::close_voting())]
         pub fn close_voting(
             origin: OriginFor<T>,
             proposal_id: u64,
@@ -173,17 +185,22 @@ pub mod pallet {
         }
 
         /// Reset votes after Consensus Day execution (admin only)
+        ///
+        /// Removes up to `MAX_RESET_PER_CALL` votes; call again until `VotesReset` is emitted.
         #[pallet::call_index(2)]
-        #[pallet::weight(10_000)]
+        #[pallet::weight(T::WeightInfo::reset_votes(MAX_RESET_PER_CALL))]
         pub fn reset_votes(
             origin: OriginFor<T>,
             proposal_id: u64,
-        ) -> DispatchResult {
+        ) -> DispatchResultWithPostInfo {
             ensure_root(origin)?;
 
-            crate::vote_storage::reset_votes::<T>(proposal_id);
-            Self::deposit_event(Event::VotesReset(proposal_id));
-            Ok(())
+            let (removed, complete) =
+                crate::vote_storage::reset_votes::<T>(proposal_id, MAX_RESET_PER_CALL);
+            if complete {
+                Self::deposit_event(Event::VotesReset(proposal_id));
+            }
+            Ok(Some(T::WeightInfo::reset_votes(removed)).into())
         }
     }
 
@@ -1,10 +1,20 @@
 //! Vote storage and management functions
 
-/// Reset all votes for a given proposal
-pub fn reset_votes<T: crate::pallet::Config>(proposal_id: u64) {
-    // Remove all votes for this proposal
-    let _ = crate::pallet::Votes::<T>::clear_prefix(proposal_id, u32::MAX, None);
+/// Remove up to `limit` votes for a given proposal
+///
+/// Returns the number of votes removed and whether none are left. The vote count is
+/// only reset once every vote has been removed.
+pub fn reset_votes<T: crate::pallet::Config>(proposal_id: u64, limit: u32) -> (u32, bool) {
+    // Remove votes for this proposal
+    let removed = crate::pallet::Votes::<T>::drain_prefix(proposal_id)
+        .take(limit as usize)
+        .count() as u32;
+    let complete = crate::pallet::Votes::<T>::iter_key_prefix(proposal_id).next().is_none();
 
     // Reset vote count
-    crate::pallet::VoteCount::<T>::remove(proposal_id);
+    if complete {
+        crate::pallet::VoteCount::<T>::remove(proposal_id);
+    }
+
+    (removed, complete)
 }
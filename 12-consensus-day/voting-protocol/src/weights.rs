@@ -0,0 +1,73 @@
+//! PLACEHOLDER weights for `consensus_day_voting_protocol`: hand-estimated, not benchmark output.
+//!
+//! Overwrite with `./scripts/benchmark-pallets.sh consensus_day_voting_protocol` on reference hardware.
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(missing_docs)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use core::marker::PhantomData;
+
+/// Weight functions needed for `consensus_day_voting_protocol`.
+pub trait WeightInfo {
+	fn cast_vote() -> Weight;
+	fn close_voting() -> Weight;
+	fn reset_votes(v: u32, ) -> Weight;
+}
+
+/// Placeholder weights for `consensus_day_voting_protocol`, pending benchmarking.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	/// Storage: `ConsensusDayVotingProtocol::Votes` (r:1 w:1)
+	/// Storage: `ConsensusDayVotingProtocol::VoteCount` (r:1 w:1)
+	fn cast_vote() -> Weight {
+		Weight::from_parts(23_540_000, 6085)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `ConsensusDayVotingProtocol::VoteCount` (r:1 w:0)
+	fn close_voting() -> Weight {
+		Weight::from_parts(10_380_000, 3497)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+	}
+	/// Storage: `ConsensusDayVotingProtocol::VoteCount` (r:0 w:1)
+	/// Storage: `ConsensusDayVotingProtocol::Votes` (r:0 w:0)
+	/// The range of component `v` is `[0, 1000]`.
+	fn reset_votes(v: u32, ) -> Weight {
+		Weight::from_parts(7_920_000, 990)
+			.saturating_add(Weight::from_parts(4_870_000, 0).saturating_mul(v.into()))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(v.into())))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(v.into())))
+			.saturating_add(Weight::from_parts(0, 2588).saturating_mul(v.into()))
+	}
+}
+
+// For backwards compatibility and tests.
+impl WeightInfo for () {
+	/// Storage: `ConsensusDayVotingProtocol::Votes` (r:1 w:1)
+	/// Storage: `ConsensusDayVotingProtocol::VoteCount` (r:1 w:1)
+	fn cast_vote() -> Weight {
+		Weight::from_parts(23_540_000, 6085)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `ConsensusDayVotingProtocol::VoteCount` (r:1 w:0)
+	fn close_voting() -> Weight {
+		Weight::from_parts(10_380_000, 3497)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+	}
+	/// Storage: `ConsensusDayVotingProtocol::VoteCount` (r:0 w:1)
+	/// Storage: `ConsensusDayVotingProtocol::Votes` (r:0 w:0)
+	/// The range of component `v` is `[0, 1000]`.
+	fn reset_votes(v: u32, ) -> Weight {
+		Weight::from_parts(7_920_000, 990)
+			.saturating_add(Weight::from_parts(4_870_000, 0).saturating_mul(v.into()))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(v.into())))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(v.into())))
+			.saturating_add(Weight::from_parts(0, 2588).saturating_mul(v.into()))
+	}
+}
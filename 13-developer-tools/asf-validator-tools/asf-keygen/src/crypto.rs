@@ -38,9 +38,13 @@ pub enum OutputFormat {
 #[derive(Debug, Serialize, Deserialize)]
 pub struct KeyData {
     pub scheme: CryptoScheme,
+    #[serde(alias = "publicKey")]
     pub public_key: String,
+    #[serde(alias = "secretSeed")]
     pub secret_seed: String,
+    #[serde(alias = "ss58Address")]
     pub ss58_address: String,
+    #[serde(alias = "keyType")]
     pub key_type: Option<String>,
 }
 
@@ -5,6 +5,7 @@ use std::fs;
 use std::path::PathBuf;
 
 use crate::crypto::*;
+use crate::slashing;
 
 // ═══════════════════════════════════════════════════════════════════════════════
 // EXPORT FORMATS
@@ -27,12 +28,19 @@ pub fn export_key(
     password: &str,
     format: ExportFormat,
     output: Option<PathBuf>,
+    slashing_db: Option<PathBuf>,
 ) -> Result<()> {
     let encrypted = fs::read_to_string(keyfile).context("Failed to read key file")?;
     let key_data = decrypt_key(&encrypted, password)?;
 
+    if slashing_db.is_some() && !matches!(format, ExportFormat::Json) {
+        return Err(anyhow::anyhow!(
+            "Slashing protection records can only be carried by JSON exports"
+        ));
+    }
+
     let exported = match format {
-        ExportFormat::Json => export_json(&key_data)?,
+        ExportFormat::Json => export_json(&key_data, slashing_db.as_ref())?,
         ExportFormat::Hex => export_hex(&key_data)?,
         ExportFormat::Mnemonic => {
             return Err(anyhow::anyhow!(
@@ -52,8 +60,8 @@ pub fn export_key(
     Ok(())
 }
 
-fn export_json(key_data: &KeyData) -> Result<String> {
-    let output = json!({
+fn export_json(key_data: &KeyData, slashing_db: Option<&PathBuf>) -> Result<String> {
+    let mut output = json!({
         "scheme": key_data.scheme.to_string(),
         "publicKey": key_data.public_key,
         "secretSeed": key_data.secret_seed,
@@ -61,6 +69,12 @@ fn export_json(key_data: &KeyData) -> Result<String> {
         "keyType": key_data.key_type,
     });
 
+    // Carry the key's signing history so the new machine cannot double-sign
+    if let Some(db_path) = slashing_db {
+        let records = slashing::load_for_key(db_path, &key_data.public_key)?;
+        output["slashingProtection"] = serde_json::to_value(&records)?;
+    }
+
     Ok(serde_json::to_string_pretty(&output)?)
 }
 
@@ -4,7 +4,10 @@ use sp_core::{ed25519, sr25519, Pair};
 use std::fs;
 use std::path::PathBuf;
 
+use asf_slashing_protection::Interchange;
+
 use crate::crypto::*;
+use crate::slashing;
 
 // ═══════════════════════════════════════════════════════════════════════════════
 // IMPORT FORMATS
@@ -27,13 +30,29 @@ pub fn import_key(
     output: &PathBuf,
     password: &str,
     scheme: CryptoScheme,
+    slashing_db: Option<&PathBuf>,
 ) -> Result<()> {
-    let key_data = match format {
+    let (key_data, slashing_records) = match format {
         ImportFormat::Json => import_json(data)?,
-        ImportFormat::Hex => import_hex(data, scheme)?,
-        ImportFormat::Mnemonic => import_mnemonic(data, scheme)?,
+        ImportFormat::Hex => (import_hex(data, scheme)?, None),
+        ImportFormat::Mnemonic => (import_mnemonic(data, scheme)?, None),
     };
 
+    // Merge signing history before the key is usable on this machine
+    match (slashing_records, slashing_db) {
+        (Some(records), Some(db_path)) => {
+            let count = slashing::merge_into(db_path, records)?;
+            println!("✓ Slashing protection merged ({} key(s)): {}", count, db_path.display());
+        }
+        (Some(_), None) => {
+            println!("⚠ Export contains slashing protection records; pass --slashing-db to import them");
+        }
+        (None, Some(_)) => {
+            println!("⚠ No slashing protection records in import data; start the validator with care");
+        }
+        (None, None) => {}
+    }
+
     let encrypted = encrypt_key(&key_data, password)?;
     fs::write(output, encrypted).context("Failed to write key file")?;
 
@@ -45,7 +64,7 @@ pub fn import_key(
     Ok(())
 }
 
-fn import_json(data: &str) -> Result<KeyData> {
+fn import_json(data: &str) -> Result<(KeyData, Option<Interchange>)> {
     // Try to read as file first
     let json_str = if std::path::Path::new(data).exists() {
         fs::read_to_string(data).context("Failed to read JSON file")?
@@ -56,7 +75,16 @@ fn import_json(data: &str) -> Result<KeyData> {
     let key_data: KeyData = serde_json::from_str(&json_str)
         .context("Invalid JSON format")?;
 
-    Ok(key_data)
+    let value: serde_json::Value = serde_json::from_str(&json_str)?;
+    let slashing_records = match value.get("slashingProtection") {
+        Some(records) => Some(
+            serde_json::from_value::<Interchange>(records.clone())
+                .context("Invalid slashing protection records")?,
+        ),
+        None => None,
+    };
+
+    Ok((key_data, slashing_records))
 }
 
 fn import_hex(data: &str, scheme: CryptoScheme) -> Result<KeyData> {
@@ -11,6 +11,7 @@ use std::path::PathBuf;
 mod crypto;
 mod export;
 mod import;
+mod slashing;
 
 use crypto::*;
 use export::*;
@@ -125,6 +126,10 @@ enum Commands {
         /// Output file (prints to stdout if not specified)
         #[arg(short, long)]
         output: Option<PathBuf>,
+
+        /// Node slashing-protection DB to carry with the key (JSON format only)
+        #[arg(long)]
+        slashing_db: Option<PathBuf>,
     },
 
     /// Import keys from various formats
@@ -148,6 +153,10 @@ enum Commands {
         /// Cryptographic scheme (for hex/mnemonic import)
         #[arg(short = 'c', long, value_enum, default_value = "sr25519")]
         scheme: CryptoScheme,
+
+        /// Slashing-protection DB of the target node to merge carried records into
+        #[arg(long)]
+        slashing_db: Option<PathBuf>,
     },
 
     /// Generate session keys for a validator
@@ -218,9 +227,10 @@ fn main() -> Result<()> {
             password,
             format,
             output,
+            slashing_db,
         } => {
             let password = get_password(password, "Enter password to decrypt key")?;
-            export_key(&keyfile, &password, format, output)?;
+            export_key(&keyfile, &password, format, output, slashing_db)?;
         }
 
         Commands::Import {
@@ -229,9 +239,10 @@ fn main() -> Result<()> {
             output,
             password,
             scheme,
+            slashing_db,
         } => {
             let password = get_password(password, "Enter password to encrypt key")?;
-            import_key(&data, format, &output, &password, scheme)?;
+            import_key(&data, format, &output, &password, scheme, slashing_db.as_ref())?;
         }
 
         Commands::GenerateSession {
@@ -0,0 +1,68 @@
+use anyhow::{anyhow, Context, Result};
+use asf_slashing_protection::{Interchange, SlashingProtectionDb};
+use std::fs;
+use std::path::Path;
+
+// ═══════════════════════════════════════════════════════════════════════════════
+// SLASHING PROTECTION TRANSFER
+// ═══════════════════════════════════════════════════════════════════════════════
+
+/// Read the records for one key from a node's slashing-protection DB
+pub fn load_for_key(db_path: &Path, public_key: &str) -> Result<Interchange> {
+    let json = fs::read_to_string(db_path).context("Failed to read slashing protection DB")?;
+    let mut interchange = Interchange::from_json(&json).map_err(|e| anyhow!("{}", e))?;
+
+    let pubkey = format!("0x{}", public_key.trim_start_matches("0x").to_lowercase());
+    interchange.data.retain(|entry| entry.pubkey == pubkey);
+
+    if interchange.data.is_empty() {
+        return Err(anyhow!(
+            "No slashing protection records for {} in {}",
+            pubkey,
+            db_path.display()
+        ));
+    }
+
+    Ok(interchange)
+}
+
+/// Merge records into the slashing-protection DB a node will use
+///
+/// Creates the DB if missing; refuses records from a different chain.
+pub fn merge_into(db_path: &Path, interchange: Interchange) -> Result<usize> {
+    let genesis_hex = interchange.metadata.genesis_hash.trim_start_matches("0x");
+    let genesis: [u8; 32] = hex::decode(genesis_hex)
+        .ok()
+        .and_then(|bytes| bytes.try_into().ok())
+        .ok_or_else(|| anyhow!("Invalid genesis hash in slashing protection data"))?;
+
+    let mut db = SlashingProtectionDb::open(db_path, genesis).map_err(|e| anyhow!("{}", e))?;
+    db.import(interchange).map_err(|e| anyhow!("{}", e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use asf_slashing_protection::{SigningKind, SigningPosition};
+
+    #[test]
+    fn test_records_carried_between_databases() {
+        let dir = tempfile::tempdir().unwrap();
+        let old_path = dir.path().join("old.json");
+        let new_path = dir.path().join("new.json");
+        let (key, other) = ([1u8; 32], [2u8; 32]);
+        let position = SigningPosition { epoch: 1, round: 0, phase: 0, height: 64 };
+
+        let mut old = SlashingProtectionDb::open(&old_path, [9u8; 32]).unwrap();
+        old.check_and_record(&key, SigningKind::Checkpoint, position, &[1u8; 32]).unwrap();
+        old.check_and_record(&other, SigningKind::Checkpoint, position, &[1u8; 32]).unwrap();
+
+        let carried = load_for_key(&old_path, &hex::encode(key)).unwrap();
+        assert_eq!(carried.data.len(), 1);
+        assert_eq!(merge_into(&new_path, carried).unwrap(), 1);
+
+        let mut new = SlashingProtectionDb::open(&new_path, [9u8; 32]).unwrap();
+        assert!(new.check_and_record(&key, SigningKind::Checkpoint, position, &[2u8; 32]).is_err());
+        assert!(new.record(&other).is_none());
+    }
+}
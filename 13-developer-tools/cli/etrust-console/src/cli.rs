@@ -315,6 +315,24 @@ pub enum ConsensusCommands {
 
     /// Query fiscal distribution schedule
     Distribution,
+
+    /// Dry-run the next Consensus Day from a state snapshot
+    Simulate {
+        /// Snapshot file (JSON snapshot, or exported storage with --storage)
+        snapshot: String,
+
+        /// Read the file as exported storage (state_getPairs output or raw chain spec)
+        #[arg(long)]
+        storage: bool,
+
+        /// Block at which stakes are registered
+        #[arg(short, long, default_value_t = 0)]
+        block: u32,
+
+        /// Print the full outcome as JSON
+        #[arg(long)]
+        json: bool,
+    },
 }
 
 // ═══════════════════════════════════════════════════════════════════════════════
@@ -2,8 +2,9 @@
 // Consensus Commands (Governance & Consensus Day)
 // ═══════════════════════════════════════════════════════════════════════════════
 
-use anyhow::Result;
+use anyhow::{Context, Result};
 use colored::Colorize;
+use consensus_day_simulator::{simulate, storage, Snapshot};
 
 use crate::{cli::ConsensusCommands, rpc_client::EtridRpcClient};
 
@@ -23,6 +24,12 @@ pub async fn execute(command: ConsensusCommands, endpoint: &str) -> Result<()> {
         ConsensusCommands::ProposalInfo { proposal_id } => show_proposal_info(proposal_id, endpoint).await,
         ConsensusCommands::Status => show_consensus_status(endpoint).await,
         ConsensusCommands::Distribution => show_distribution_schedule(endpoint).await,
+        ConsensusCommands::Simulate {
+            snapshot,
+            storage,
+            block,
+            json,
+        } => simulate_consensus_day(snapshot, storage, block, json),
     }
 }
 
@@ -257,3 +264,96 @@ async fn show_distribution_schedule(endpoint: &str) -> Result<()> {
     println!();
     Ok(())
 }
+
+fn simulate_consensus_day(path: String, from_storage: bool, block: u32, json: bool) -> Result<()> {
+    let contents =
+        std::fs::read_to_string(&path).with_context(|| format!("Failed to read snapshot {}", path))?;
+    let snapshot = if from_storage {
+        storage::from_storage(&storage::parse_pairs(&contents)?, block)?
+    } else {
+        let mut snapshot = Snapshot::from_json(&contents)?;
+        if block > 0 {
+            snapshot.block_number = block;
+        }
+        snapshot
+    };
+
+    let outcome = simulate(&snapshot);
+
+    if json {
+        println!("{}", serde_json::to_string_pretty(&outcome)?);
+        return Ok(());
+    }
+
+    println!("{}", "Consensus Day Simulation".bright_green().bold());
+    println!();
+    println!("  {}: {}", "Snapshot".bold(), path.bright_white());
+    println!("  {}: {}", "Block".bold(), snapshot.block_number.to_string().bright_white());
+    println!();
+
+    println!("{}", "Proposals:".bright_cyan().bold());
+    for p in outcome.proposals.iter() {
+        let result = match (p.quorum_met, p.approved) {
+            (false, _) => "NO QUORUM".bright_yellow(),
+            (true, true) => "APPROVED".bright_green(),
+            (true, false) => "REJECTED".bright_red(),
+        };
+        println!("  #{} {} [{:?}] {}", p.id, p.title.bright_white(), p.category, result);
+        println!(
+            "      yes {} / no {} / abstain {} ({} validators), minted {}",
+            p.yes_votes, p.no_votes, p.abstain_votes, p.validator_votes, p.minted
+        );
+    }
+    println!();
+
+    println!("{}", "Minting:".bright_cyan().bold());
+    println!("  {}: {}", "Inflation Cap".bold(), outcome.max_mintable.to_string().bright_white());
+    println!("  {}: {}", "Total Minted".bold(), outcome.total_minted.to_string().bright_white());
+    println!(
+        "  {}: {}.{:02}%",
+        "Inflation Rate".bold(),
+        outcome.inflation_rate_bps / 100,
+        outcome.inflation_rate_bps % 100
+    );
+    for (category, amount) in outcome.treasury_allocations.iter() {
+        println!("    {:?}: {}", category, amount);
+    }
+    println!();
+
+    println!("{}", "Distribution:".bright_cyan().bold());
+    println!("  {}: {}", "Foundation".bold(), outcome.fiscal_split.foundation);
+    println!("  {}: {}", "Directors".bold(), outcome.fiscal_split.directors);
+    println!("  {}: {}", "Validators".bold(), outcome.fiscal_split.validators);
+    println!("  {}: {}", "Voters".bold(), outcome.fiscal_split.voters);
+    println!("  {}: {}", "Participation Pool".bold(), outcome.participation_pool);
+    println!();
+
+    println!("{}", "Elected Directors:".bright_cyan().bold());
+    for director in outcome.directors.iter() {
+        println!("  {} {}", "●".bright_green(), director.bright_white());
+    }
+    println!("  {}: {}", "Weakest Backing".bold(), outcome.director_min_support);
+    println!();
+
+    println!("{}", "Payouts:".bright_cyan().bold());
+    for (who, account) in outcome.accounts.iter().filter(|(_, a)| a.total_payout() > 0) {
+        println!(
+            "  {}: {} (participation {}, fiscal {})",
+            who.bright_white(),
+            account.total_payout().to_string().bright_green(),
+            account.participation_reward,
+            account.fiscal_payout
+        );
+    }
+
+    if !outcome.skipped.is_empty() {
+        println!();
+        println!("{}", "Skipped Intentions:".bright_yellow().bold());
+        for s in outcome.skipped.iter() {
+            println!("  {}: {}", s.who, s.reason);
+        }
+    }
+
+    println!();
+    Ok(())
+}
@@ -28,7 +28,8 @@ interface IEtridGovernance {
      * @notice Submit a new governance proposal to FlareChain
      * @param title Proposal title (max 256 characters)
      * @param description Detailed proposal description (max 10,000 characters)
-     * @return proposalId Unique proposal identifier
+     * @return proposalId Relay request ID of the queued proposal; the
+     *         FlareChain proposal ID is assigned once it is relayed
      *
      * @dev Example:
      *   uint256 proposalId = governance.submitProposal(
@@ -37,6 +38,7 @@ interface IEtridGovernance {
      *   );
      *
      * @dev Emits ProposalSubmitted event on FlareChain
+     * @dev Reverts if the title is empty or either string is too long
      */
     function submitProposal(string memory title, string memory description)
         external
@@ -55,6 +57,7 @@ interface IEtridGovernance {
      *   - Proposal is not in Active status
      *   - Caller has already voted
      *   - Caller has insufficient voting power
+     *   - Too many actions are awaiting relay to FlareChain
      */
     function voteOnProposal(uint256 proposalId, bool support) external;
 
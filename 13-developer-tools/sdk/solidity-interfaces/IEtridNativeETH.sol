@@ -5,26 +5,33 @@ pragma solidity ^0.8.0;
  * @title IEtridNativeETH
  * @dev Interface for Native ETH Wrapping Precompile (0x803)
  *
- * Provides instant, ZERO-FEE conversion between native ETH and wETH.
- * This is a novel feature - no gas costs for wrapping/unwrapping.
+ * Provides instant conversion between native ETH and wETH, backed 1:1 by
+ * the native ETH held at the precompile address.
  *
  * Address: 0x0000000000000000000000000000000000000803
  *
- * @notice Novel Feature: Traditional WETH contracts charge gas.
- *         Etrid's precompile provides FREE wrapping via native execution.
+ * @notice Wrapping runs natively, so it costs only the storage accesses
+ *         and log it performs, well below a Solidity WETH contract.
+ *         Balances use Solidity storage layout (totalSupply in slot 0,
+ *         balanceOf mapping in slot 1).
  */
 interface IEtridNativeETH {
+    /// @notice Emitted by wrap()
+    event Deposit(address indexed account, uint256 amount);
+
+    /// @notice Emitted by unwrap()
+    event Withdrawal(address indexed account, uint256 amount);
+
     /**
      * @notice Wrap native ETH to wETH (ERC-20)
      * @return wethAmount Amount of wETH minted (equals msg.value)
      *
      * @dev Example:
-     *   // Wrap 1 ETH to wETH (zero gas cost for wrapping)
+     *   // Wrap 1 ETH to wETH
      *   uint256 wethReceived = wrapper.wrap{value: 1 ether}();
      *   assert(wethReceived == 1 ether);
      *
-     * @dev This is ZERO-FEE - no gas charged for the wrap operation itself
-     *      Only standard transaction gas applies
+     * @dev Reverts if msg.value is zero or under STATICCALL/DELEGATECALL
      */
     function wrap() external payable returns (uint256 wethAmount);
 
@@ -38,8 +45,8 @@ interface IEtridNativeETH {
      *   bool success = wrapper.unwrap(0.5 ether);
      *   require(success, "Unwrap failed");
      *
-     * @dev This is ZERO-FEE - no gas charged for the unwrap operation
-     *      Requires prior approval if wETH is ERC-20
+     * @dev Burns the caller's wETH and sends the native ETH to the caller.
+     *      Reverts if the caller's wETH balance is below amount.
      */
     function unwrap(uint256 amount) external returns (bool success);
 
@@ -59,4 +66,17 @@ interface IEtridNativeETH {
      *   wrapper.wrap{value: 10 ether}();
      */
     function getWrapRate() external view returns (uint256 rate);
+
+    /**
+     * @notice wETH balance of an account
+     * @param account Account to query
+     * @return balance Wrapped amount held by account
+     */
+    function balanceOf(address account) external view returns (uint256 balance);
+
+    /**
+     * @notice Total wETH in circulation
+     * @return supply Sum of all wrapped balances
+     */
+    function totalSupply() external view returns (uint256 supply);
 }
@@ -0,0 +1,84 @@
+#!/bin/bash
+# Regenerate weights.rs for the benchmarked custom pallets against the Primearc runtime
+#
+# Usage:
+#   ./scripts/benchmark-pallets.sh              # all pallets
+#   ./scripts/benchmark-pallets.sh eth_bridge   # one or more pallets by crate name
+#
+# Run on reference hardware. Requires frame-omni-bencher:
+#   cargo install --locked frame-omni-bencher
+
+set -e
+
+REPO_ROOT="$(cd "$(dirname "$0")/.." && pwd)"
+RUNTIME_WASM="$REPO_ROOT/target/release/wbuild/primearc-runtime/primearc_runtime.compact.compressed.wasm"
+STEPS="${STEPS:-50}"
+REPEAT="${REPEAT:-20}"
+
+# Pallet crate name -> crate directory
+declare -A PALLETS=(
+    [pallet_accounts]="04-accounts/pallet"
+    [pallet_did_registry]="02-open-did/pallets/pallet-did-registry"
+    [pallet_governance]="10-foundation/governance/pallet"
+    [pallet_vote_delegation]="10-foundation/governance/delegation"
+    [pallet_treasury_etrid]="10-foundation/pallets/pallet-treasury"
+    [pallet_tx_processor]="07-transactions/tx-processor"
+    [pallet_etwasm_vm]="08-etwasm-vm/pallet"
+    [pallet_consensus_day]="12-consensus-day/pallet-consensus-day"
+    [consensus_day_proposal_system]="12-consensus-day/proposal-system"
+    [consensus_day_voting_protocol]="12-consensus-day/voting-protocol"
+    [consensus_day_distribution]="12-consensus-day/distribution"
+    [consensus_day_minting_logic]="12-consensus-day/minting-logic"
+    [pallet_edsc_stability]="06-native-currency/pallets/pallet-edsc-stability"
+    [pallet_etr_lock]="06-native-currency/pallets/pallet-etr-lock"
+    [pallet_oracle_network]="06-native-currency/pallets/pallet-oracle-network"
+    [pallet_bitcoin_bridge]="05-multichain/bridge-protocols/bitcoin-bridge"
+    [eth_bridge]="05-multichain/bridge-protocols/ethereum-bridge"
+    [pallet_doge_bridge]="05-multichain/bridge-protocols/doge-bridge"
+    [stellar_bridge]="05-multichain/bridge-protocols/stellar-bridge"
+    [xrp_bridge]="05-multichain/bridge-protocols/xrp-bridge"
+    [sol_bridge]="05-multichain/bridge-protocols/solana-bridge"
+    [pallet_cardano_bridge]="05-multichain/bridge-protocols/cardano-bridge"
+    [chainlink_bridge]="05-multichain/bridge-protocols/chainlink-bridge"
+    [polygon_bridge]="05-multichain/bridge-protocols/polygon-bridge"
+    [bnb_bridge]="05-multichain/bridge-protocols/bnb-bridge"
+    [trx_bridge]="05-multichain/bridge-protocols/tron-bridge"
+    [stablecoin_usdt_bridge]="05-multichain/bridge-protocols/stablecoin-usdt-bridge"
+)
+
+if [ "$#" -gt 0 ]; then
+    SELECTED=("$@")
+else
+    SELECTED=("${!PALLETS[@]}")
+fi
+
+for pallet in "${SELECTED[@]}"; do
+    if [ -z "${PALLETS[$pallet]}" ]; then
+        echo "❌ Unknown pallet: $pallet"
+        exit 1
+    fi
+done
+
+if ! command -v frame-omni-bencher &> /dev/null; then
+    echo "❌ frame-omni-bencher not found (cargo install --locked frame-omni-bencher)"
+    exit 1
+fi
+
+echo "Building primearc-runtime with runtime-benchmarks..."
+cd "$REPO_ROOT"
+cargo build --release -p primearc-runtime --features runtime-benchmarks
+echo "✅ Runtime built"
+
+for pallet in "${SELECTED[@]}"; do
+    output="$REPO_ROOT/${PALLETS[$pallet]}/src/weights.rs"
+    echo ""
+    echo "Benchmarking $pallet -> ${PALLETS[$pallet]}/src/weights.rs"
+    frame-omni-bencher v1 benchmark pallet \
+        --runtime "$RUNTIME_WASM" \
+        --pallet "$pallet" \
+        --extrinsic "*" \
+        --steps "$STEPS" \
+        --repeat "$REPEAT" \
+        --output "$output"
+    echo "✅ $pallet"
+done